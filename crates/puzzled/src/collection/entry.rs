@@ -0,0 +1,122 @@
+use std::{
+    cell::OnceCell,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::collection::{CollectionPuzzle, Error, PuzzleFormat, Result};
+
+/// Puzzle file in a [collection](super::Collection) that is parsed on first access
+pub struct CollectionEntry<P> {
+    name: PathBuf,
+    format: PuzzleFormat,
+
+    /// Bytes the entry was loaded from, which are cleared once the puzzle is modified
+    bytes: Option<Vec<u8>>,
+    puzzle: OnceCell<Result<P>>,
+}
+
+impl<P> CollectionEntry<P> {
+    /// Create an entry from the bytes of a file, which are parsed on first access
+    pub fn from_bytes<N: Into<PathBuf>>(name: N, format: PuzzleFormat, bytes: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            format,
+            bytes: Some(bytes),
+            puzzle: OnceCell::new(),
+        }
+    }
+
+    /// Create an entry from a puzzle, which is written in the format on saving
+    pub fn from_puzzle<N: Into<PathBuf>>(name: N, format: PuzzleFormat, puzzle: P) -> Self {
+        Self {
+            name: name.into(),
+            format,
+            bytes: None,
+            puzzle: OnceCell::from(Ok(puzzle)),
+        }
+    }
+
+    /// Path of the entry relative to the root of its collection
+    pub fn name(&self) -> &Path {
+        &self.name
+    }
+
+    pub fn format(&self) -> PuzzleFormat {
+        self.format
+    }
+
+    /// Whether the puzzle has already been parsed
+    pub fn is_parsed(&self) -> bool {
+        self.puzzle.get().is_some()
+    }
+
+    /// Whether the puzzle was changed after it was loaded, such that it is written out rather than its original bytes
+    pub fn is_modified(&self) -> bool {
+        self.bytes.is_none()
+    }
+}
+
+impl<P: CollectionPuzzle> CollectionEntry<P> {
+    /// Puzzle of the entry, which is parsed on the first call
+    pub fn puzzle(&self) -> std::result::Result<&P, &Error> {
+        self.puzzle
+            .get_or_init(|| {
+                let bytes = self.bytes.as_deref().unwrap_or_default();
+
+                P::read_format(self.format, bytes).map_err(|err| Error::Read {
+                    path: self.name.clone(),
+                    err,
+                })
+            })
+            .as_ref()
+    }
+
+    /// Mutable access to the puzzle, which marks the entry as [modified](Self::is_modified)
+    pub fn puzzle_mut(&mut self) -> std::result::Result<&mut P, &Error> {
+        let _ = self.puzzle();
+
+        match self.puzzle.get_mut() {
+            Some(Ok(puzzle)) => {
+                self.bytes = None;
+                Ok(puzzle)
+            }
+            Some(Err(err)) => Err(err),
+            None => unreachable!("Puzzle is parsed above"),
+        }
+    }
+
+    /// Take the puzzle out of the entry, parsing it if needed
+    pub fn into_puzzle(self) -> Result<P> {
+        let _ = self.puzzle();
+        self.puzzle.into_inner().expect("Puzzle is parsed above")
+    }
+
+    /// Bytes to save the entry with, which are the original bytes if the puzzle was not [modified](Self::is_modified)
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        if let Some(bytes) = &self.bytes {
+            return Ok(bytes.clone());
+        }
+
+        match self.puzzle.get() {
+            Some(Ok(puzzle)) => puzzle
+                .write_format(self.format)
+                .map_err(|err| Error::Write {
+                    path: self.name.clone(),
+                    err,
+                }),
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+impl<P> fmt::Debug for CollectionEntry<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CollectionEntry")
+            .field("name", &self.name)
+            .field("format", &self.format)
+            .field("is_parsed", &self.is_parsed())
+            .field("is_modified", &self.is_modified())
+            .finish()
+    }
+}
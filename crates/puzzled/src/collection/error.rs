@@ -0,0 +1,21 @@
+use std::{io, path::PathBuf};
+
+use puzzled_io::{ReadError, WriteError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Could not read '{}': {err}", path.display())]
+    Read { path: PathBuf, err: ReadError },
+
+    #[error("Could not write '{}': {err}", path.display())]
+    Write { path: PathBuf, err: WriteError },
+
+    #[cfg(feature = "zip")]
+    #[error("Zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,48 @@
+use std::{fmt, path::Path};
+
+/// Format of a puzzle file in a [collection](super::Collection), which is derived from its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PuzzleFormat {
+    /// [Across Lite](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki) `*.puz` files
+    Puz,
+
+    /// Plain text `*.txt` files
+    Txt,
+
+    /// [ipuz](http://www.ipuz.org/) `*.ipuz` files
+    Ipuz,
+}
+
+impl PuzzleFormat {
+    /// Every format in the order they are checked
+    pub const ALL: [PuzzleFormat; 3] = [Self::Puz, Self::Txt, Self::Ipuz];
+
+    /// Find the format from the extension of a path, ignoring its casing
+    /// ```
+    /// use puzzled::collection::PuzzleFormat;
+    ///
+    /// assert_eq!(PuzzleFormat::from_path("nyt/2026-03-07.PUZ"), Some(PuzzleFormat::Puz));
+    /// assert_eq!(PuzzleFormat::from_path("README.md"), None);
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let ext = path.as_ref().extension()?.to_str()?;
+
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extension().eq_ignore_ascii_case(ext))
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Puz => "puz",
+            Self::Txt => "txt",
+            Self::Ipuz => "ipuz",
+        }
+    }
+}
+
+impl fmt::Display for PuzzleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "*.{}", self.extension())
+    }
+}
@@ -0,0 +1,467 @@
+//! Defines a [`Collection`] of puzzles, such as a folder or zip bundle of puzzle files
+//!
+//! A collection keeps the raw bytes of every file and only parses a puzzle once it is requested.
+//! This keeps loading large libraries cheap, while files that are never touched are saved back exactly as they were read.
+//! ```no_run
+//! use puzzled::{collection::Collection, crossword::Crossword};
+//!
+//! let collection: Collection<Crossword> = Collection::load_dir("puzzles/nyt")?;
+//!
+//! for entry in collection.by_author("Will Shortz") {
+//!     println!("{}", entry.name().display());
+//! }
+//!
+//! collection.save_dir("puzzles/backup")?;
+//! # Ok::<(), puzzled::collection::Error>(())
+//! ```
+
+mod entry;
+mod error;
+mod format;
+mod puzzle;
+mod search;
+
+pub use entry::*;
+pub use error::*;
+pub use format::*;
+pub use puzzle::*;
+pub use search::*;
+
+use std::{
+    cell::OnceCell,
+    collections::{HashMap, hash_map::Entry},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use puzzled_core::{Metadata, Size};
+
+/// Collection of puzzles of the same type that are stored in possibly different [formats](PuzzleFormat)
+#[derive(Debug)]
+pub struct Collection<P> {
+    entries: Vec<CollectionEntry<P>>,
+
+    /// Index of the words of every entry, which is built on the first search
+    index: OnceCell<SearchIndex>,
+}
+
+impl<P> Default for Collection<P> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: OnceCell::new(),
+        }
+    }
+}
+
+impl<P> Collection<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CollectionEntry<P>> {
+        self.entries.iter()
+    }
+
+    /// Mutable iterator over the entries, which clears the search index as the puzzles may be modified
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut CollectionEntry<P>> {
+        self.index.take();
+        self.entries.iter_mut()
+    }
+
+    /// Find the entry with the given name
+    pub fn get<N: AsRef<Path>>(&self, name: N) -> Option<&CollectionEntry<P>> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name() == name.as_ref())
+    }
+
+    /// Add an entry, replacing the entry with the same name if there is any
+    pub fn insert(&mut self, entry: CollectionEntry<P>) -> Option<CollectionEntry<P>> {
+        self.index.take();
+
+        match self
+            .entries
+            .iter_mut()
+            .find(|existing| existing.name() == entry.name())
+        {
+            Some(existing) => Some(std::mem::replace(existing, entry)),
+            None => {
+                self.entries.push(entry);
+                None
+            }
+        }
+    }
+
+    /// Remove the entry with the given name
+    pub fn remove<N: AsRef<Path>>(&mut self, name: N) -> Option<CollectionEntry<P>> {
+        let idx = self
+            .entries
+            .iter()
+            .position(|entry| entry.name() == name.as_ref())?;
+        self.index.take();
+
+        Some(self.entries.remove(idx))
+    }
+}
+
+impl<P: CollectionPuzzle> Collection<P> {
+    /// Load every puzzle file in a directory and its subdirectories
+    ///
+    /// Files are not parsed until their [puzzle](CollectionEntry::puzzle) is requested.
+    /// Files without a known [format](PuzzleFormat) are skipped.
+    pub fn load_dir<D: AsRef<Path>>(dir: D) -> Result<Self> {
+        let dir = dir.as_ref();
+        let mut paths = Vec::new();
+        collect_files(dir, &mut paths)?;
+        paths.sort();
+
+        let mut collection = Self::new();
+
+        for path in paths {
+            let Some(format) = PuzzleFormat::from_path(&path) else {
+                continue;
+            };
+
+            let bytes = fs::read(&path)?;
+            let name = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+
+            collection.insert(CollectionEntry::from_bytes(name, format, bytes));
+        }
+
+        Ok(collection)
+    }
+
+    /// Save every entry to a directory, creating subdirectories as needed
+    ///
+    /// Entries that were not modified are written back with their original bytes
+    pub fn save_dir<D: AsRef<Path>>(&self, dir: D) -> Result<()> {
+        let dir = dir.as_ref();
+
+        for entry in &self.entries {
+            let path = dir.join(entry.name());
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(path, entry.to_bytes()?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parsed puzzles of the collection, skipping entries that could not be parsed
+    pub fn puzzles(&self) -> impl Iterator<Item = &P> {
+        self.entries.iter().filter_map(|entry| entry.puzzle().ok())
+    }
+
+    /// Entries whose puzzle satisfies the predicate, which parses every entry
+    pub fn filter<F>(&self, mut predicate: F) -> impl Iterator<Item = &CollectionEntry<P>>
+    where
+        F: FnMut(&P) -> bool,
+    {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.puzzle().is_ok_and(&mut predicate))
+    }
+
+    /// Entries whose puzzle [metadata](Metadata) satisfies the predicate
+    pub fn filter_meta<F>(&self, mut predicate: F) -> impl Iterator<Item = &CollectionEntry<P>>
+    where
+        F: FnMut(&Metadata) -> bool,
+    {
+        self.filter(move |puzzle| predicate(puzzle.meta()))
+    }
+
+    /// Entries by the given author, ignoring casing
+    pub fn by_author<'a>(
+        &'a self,
+        author: &'a str,
+    ) -> impl Iterator<Item = &'a CollectionEntry<P>> {
+        self.filter_meta(move |meta| {
+            meta.author()
+                .is_some_and(|a| a.eq_ignore_ascii_case(author))
+        })
+    }
+
+    /// Entries whose puzzle has the same [fingerprint](CollectionPuzzle::fingerprint) as an earlier entry, together with that earlier entry
+    pub fn duplicates(&self) -> impl Iterator<Item = (&CollectionEntry<P>, &CollectionEntry<P>)> {
+        let mut originals = HashMap::new();
+
+        self.entries.iter().filter_map(move |entry| {
+            let fingerprint = entry.puzzle().ok()?.fingerprint();
+
+            match originals.entry(fingerprint) {
+                Entry::Occupied(original) => Some((*original.get(), entry)),
+                Entry::Vacant(vacant) => {
+                    vacant.insert(entry);
+                    None
+                }
+            }
+        })
+    }
+
+    /// Entries with a puzzle of the given size
+    pub fn by_size(&self, size: Size) -> impl Iterator<Item = &CollectionEntry<P>> {
+        self.filter(move |puzzle| puzzle.size() == size)
+    }
+
+    /// Entries with a puzzle that was published within the range of dates
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn by_date<R>(&self, dates: R) -> impl Iterator<Item = &CollectionEntry<P>>
+    where
+        R: std::ops::RangeBounds<chrono::NaiveDate>,
+    {
+        self.filter_meta(move |meta| meta.date().is_some_and(|date| dates.contains(&date)))
+    }
+}
+
+#[cfg(feature = "zip")]
+mod zip_impl {
+    use std::{
+        fs::File,
+        io::{Read, Seek, Write},
+        path::Path,
+    };
+
+    use zip::{ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+    use crate::collection::{Collection, CollectionEntry, CollectionPuzzle, PuzzleFormat, Result};
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip")))]
+    impl<P: CollectionPuzzle> Collection<P> {
+        /// Load every puzzle file in a zip archive, skipping files without a known [format](PuzzleFormat)
+        pub fn load_zip<Z: AsRef<Path>>(path: Z) -> Result<Self> {
+            Self::read_zip(File::open(path)?)
+        }
+
+        /// Read every puzzle file from a reader of zip data
+        pub fn read_zip<R: Read + Seek>(reader: R) -> Result<Self> {
+            let mut archive = ZipArchive::new(reader)?;
+            let mut collection = Self::new();
+
+            for idx in 0..archive.len() {
+                let mut file = archive.by_index(idx)?;
+
+                // Skip directories and paths that would escape the archive
+                let Some(name) = file.enclosed_name().filter(|_| file.is_file()) else {
+                    continue;
+                };
+                let Some(format) = PuzzleFormat::from_path(&name) else {
+                    continue;
+                };
+
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)?;
+
+                collection.insert(CollectionEntry::from_bytes(name, format, bytes));
+            }
+
+            Ok(collection)
+        }
+
+        /// Save every entry to a zip archive
+        pub fn save_zip<Z: AsRef<Path>>(&self, path: Z) -> Result<()> {
+            self.write_zip(File::create(path)?)
+        }
+
+        /// Write every entry as zip data to a writer
+        pub fn write_zip<W: Write + Seek>(&self, writer: W) -> Result<()> {
+            let mut zip = ZipWriter::new(writer);
+            let options = SimpleFileOptions::default();
+
+            for entry in self.iter() {
+                let name = entry.name().to_string_lossy().replace('\\', "/");
+
+                zip.start_file(name, options)?;
+                zip.write_all(&entry.to_bytes()?)?;
+            }
+
+            zip.finish()?;
+            Ok(())
+        }
+    }
+}
+
+impl<P> IntoIterator for Collection<P> {
+    type Item = CollectionEntry<P>;
+    type IntoIter = std::vec::IntoIter<CollectionEntry<P>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, P> IntoIterator for &'a Collection<P> {
+    type Item = &'a CollectionEntry<P>;
+    type IntoIter = std::slice::Iter<'a, CollectionEntry<P>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl<P> FromIterator<CollectionEntry<P>> for Collection<P> {
+    fn from_iter<I: IntoIterator<Item = CollectionEntry<P>>>(iter: I) -> Self {
+        let mut collection = Self::new();
+        for entry in iter {
+            collection.insert(entry);
+        }
+
+        collection
+    }
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "crossword", feature = "puz"))]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use puzzled_core::Size;
+    use puzzled_crossword::Crossword;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Library of puzzles in a temporary directory, which is removed when it is dropped
+    fn library() -> TempDir {
+        let temp = TempDir::new().expect("temporary directory is created");
+        let dir = temp.path();
+        let puzzles =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../puzzled_crossword/puzzles/ok");
+
+        fs::copy(puzzles.join("mini.puz"), dir.join("mini.puz")).unwrap();
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::copy(
+            puzzles.join("out-of-the-frying-pan.puz"),
+            dir.join("sub/frying-pan.puz"),
+        )
+        .unwrap();
+        fs::write(dir.join("sub/later.ipuz"), "{}").unwrap();
+        fs::write(dir.join("README.md"), "Not a puzzle").unwrap();
+
+        temp
+    }
+
+    #[test]
+    fn load_dir() {
+        let library = library();
+        let collection: Collection<Crossword> = Collection::load_dir(library.path()).unwrap();
+        let names: Vec<_> = collection
+            .iter()
+            .map(|entry| entry.name().to_path_buf())
+            .collect();
+
+        assert_eq!(
+            names,
+            ["mini.puz", "sub/frying-pan.puz", "sub/later.ipuz"].map(PathBuf::from)
+        );
+        assert!(collection.iter().all(|entry| !entry.is_parsed()));
+
+        // Only the entries that can be parsed are returned
+        assert_eq!(collection.puzzles().count(), 2);
+        assert!(collection.get("sub/later.ipuz").unwrap().puzzle().is_err());
+
+        assert_eq!(collection.duplicates().count(), 0);
+
+        let mini = collection.get("mini.puz").unwrap().puzzle().unwrap();
+        let size = Size {
+            rows: mini.rows(),
+            cols: mini.cols(),
+        };
+        assert_eq!(collection.by_size(size).count(), 1);
+    }
+
+    #[test]
+    fn duplicates() {
+        let library = library();
+        let dir = library.path();
+        fs::copy(dir.join("mini.puz"), dir.join("sub/mini-copy.puz")).unwrap();
+
+        let collection: Collection<Crossword> = Collection::load_dir(dir).unwrap();
+        let duplicates: Vec<_> = collection
+            .duplicates()
+            .map(|(original, copy)| (original.name(), copy.name()))
+            .collect();
+
+        assert_eq!(
+            duplicates,
+            [(Path::new("mini.puz"), Path::new("sub/mini-copy.puz"))]
+        );
+    }
+
+    #[test]
+    fn save_dir() {
+        let library = library();
+        let dir = library.path();
+        let mut collection: Collection<Crossword> = Collection::load_dir(dir).unwrap();
+
+        let modified = collection
+            .iter_mut()
+            .find(|entry| entry.name() == Path::new("mini.puz"))
+            .unwrap();
+        modified.puzzle_mut().expect("puzzle is parsed");
+        assert!(modified.is_modified());
+
+        let out = TempDir::new().unwrap();
+        let out = out.path();
+        collection.save_dir(out).unwrap();
+
+        // Untouched entries are saved with their original bytes
+        assert_eq!(
+            fs::read(out.join("sub/frying-pan.puz")).unwrap(),
+            fs::read(dir.join("sub/frying-pan.puz")).unwrap()
+        );
+
+        let saved: Collection<Crossword> = Collection::load_dir(out).unwrap();
+        assert_eq!(saved.len(), collection.len());
+        assert!(saved.get("mini.puz").unwrap().puzzle().is_ok());
+    }
+
+    #[test]
+    #[cfg(all(feature = "text", feature = "ipuz"))]
+    fn formats() {
+        let puzzle = puzzled_crossword::crossword!(
+            [C A .]
+            [A G E]
+            [. O W]
+            - A: "Is able to"
+            - A: "Length of life"
+            - A: "Stick (out)"
+            - D: "Automobile"
+            - D: "Gone"
+            - D: "Female sheep"
+            title: "Mini"
+            author: "Eric"
+        );
+
+        for format in PuzzleFormat::ALL {
+            let bytes = puzzle.write_format(format).unwrap();
+            let read = Crossword::read_format(format, &bytes).unwrap();
+
+            assert_eq!(read, puzzle, "{format} round trip");
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use puzzled_core::{Metadata, Size};
+use puzzled_io::{ReadError, WriteError};
+
+use crate::collection::{PuzzleFormat, SearchWord};
+
+/// Puzzle that can be stored in a [collection](super::Collection)
+///
+/// Formats that a puzzle does not support, or that are disabled through features, result in an [unsupported format](ReadError::UnsupportedFormat) error.
+pub trait CollectionPuzzle: Sized {
+    /// Read the puzzle from the bytes of a file in the given format
+    fn read_format(format: PuzzleFormat, bytes: &[u8]) -> Result<Self, ReadError>;
+
+    /// Write the puzzle to the bytes of a file in the given format
+    fn write_format(&self, format: PuzzleFormat) -> Result<Vec<u8>, WriteError>;
+
+    fn meta(&self) -> &Metadata;
+
+    fn size(&self) -> Size;
+
+    /// Stable hash of the puzzle definition, such that copies of the same puzzle have the same fingerprint
+    fn fingerprint(&self) -> u64;
+
+    /// Words of the puzzle that can be [searched](super::Collection::search), which puzzles without answers or clues leave empty
+    fn words(&self) -> Vec<SearchWord> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "crossword")]
+mod crossword_impl {
+    use puzzled_core::{Metadata, Size};
+    use puzzled_crossword::Crossword;
+    use puzzled_io::{ReadError, WriteError};
+
+    use crate::collection::{CollectionPuzzle, PuzzleFormat, SearchWord};
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "crossword")))]
+    impl CollectionPuzzle for Crossword {
+        fn read_format(format: PuzzleFormat, bytes: &[u8]) -> Result<Self, ReadError> {
+            match format {
+                #[cfg(feature = "puz")]
+                PuzzleFormat::Puz => {
+                    use puzzled_crossword::CrosswordState;
+                    use puzzled_io::PuzReader;
+
+                    let (puzzle, _): (Crossword, CrosswordState) =
+                        PuzReader::default().read(&mut &bytes[..])?;
+                    Ok(puzzle)
+                }
+
+                #[cfg(feature = "text")]
+                PuzzleFormat::Txt => {
+                    use puzzled_io::TxtPuzzle;
+
+                    let text = String::from_utf8_lossy(bytes);
+                    Ok(Crossword::read_text(&text)?)
+                }
+
+                #[cfg(feature = "ipuz")]
+                PuzzleFormat::Ipuz => {
+                    let json = String::from_utf8_lossy(bytes);
+                    let (puzzle, _) =
+                        Crossword::from_ipuz(&json).map_err(|err| ReadError::InvalidFormat {
+                            format: format.to_string(),
+                            reason: err.to_string(),
+                        })?;
+                    Ok(puzzle)
+                }
+
+                // Only reachable when some of the formats are disabled
+                #[allow(unreachable_patterns)]
+                format => Err(ReadError::UnsupportedFormat {
+                    format: format.to_string(),
+                }),
+            }
+        }
+
+        fn write_format(&self, format: PuzzleFormat) -> Result<Vec<u8>, WriteError> {
+            match format {
+                #[cfg(feature = "puz")]
+                PuzzleFormat::Puz => {
+                    use puzzled_crossword::CrosswordState;
+                    use puzzled_io::PuzWriter;
+
+                    let mut bytes = Vec::new();
+                    let state = CrosswordState::from(self);
+                    PuzWriter::new().write(&mut bytes, self, &state)?;
+
+                    Ok(bytes)
+                }
+
+                #[cfg(feature = "text")]
+                PuzzleFormat::Txt => {
+                    use puzzled_io::TxtPuzzle;
+
+                    Ok(self.write_text().into_bytes())
+                }
+
+                #[cfg(feature = "ipuz")]
+                PuzzleFormat::Ipuz => Ok(self.to_ipuz().to_string().into_bytes()),
+
+                // Only reachable when some of the formats are disabled
+                #[allow(unreachable_patterns)]
+                format => Err(WriteError::UnsupportedFormat {
+                    format: format.to_string(),
+                }),
+            }
+        }
+
+        fn meta(&self) -> &Metadata {
+            Crossword::meta(self)
+        }
+
+        fn size(&self) -> Size {
+            Size {
+                rows: self.rows(),
+                cols: self.cols(),
+            }
+        }
+
+        fn fingerprint(&self) -> u64 {
+            Crossword::fingerprint(self)
+        }
+
+        /// Clues of the crossword, where clues with a square without solution have no answer
+        fn words(&self) -> Vec<SearchWord> {
+            self.clues()
+                .values()
+                .map(|clue| {
+                    let answer = clue
+                        .positions()
+                        .map(|pos| {
+                            let cell = self.squares().get_fill(pos)?;
+                            cell.solution
+                                .as_ref()
+                                .map(|solution| solution.primary().to_string())
+                        })
+                        .collect::<Option<String>>();
+
+                    SearchWord {
+                        label: clue.id().to_string(),
+                        answer: answer.unwrap_or_default(),
+                        clue: clue.text().clone(),
+                    }
+                })
+                .collect()
+        }
+    }
+}
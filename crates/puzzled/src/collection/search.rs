@@ -0,0 +1,303 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::collection::{Collection, CollectionEntry, CollectionPuzzle};
+
+/// Wildcard that matches exactly one letter of an answer
+const ANY_LETTER: char = '?';
+
+/// Wildcard that matches any number of letters of an answer
+const ANY_LETTERS: char = '*';
+
+/// Word of a puzzle that can be [searched](Collection::search), such as the clue of a crossword together with its answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchWord {
+    /// Label that identifies the word within its puzzle, such as the clue `1-A`
+    pub label: String,
+
+    /// Answer of the word, which is empty if it is not (fully) known
+    pub answer: String,
+
+    /// Clue text of the word
+    pub clue: String,
+}
+
+/// Word of an entry that matches a [search](Collection::search)
+#[derive(Debug)]
+pub struct Match<'a, P> {
+    pub entry: &'a CollectionEntry<P>,
+    pub word: &'a SearchWord,
+
+    /// Whether the answer of the word matches the pattern
+    pub in_answer: bool,
+
+    /// Whether the clue of the word contains every term of the pattern
+    pub in_clue: bool,
+}
+
+/// Location of a word in the index, as the position of its entry and the position of the word in that entry
+type Posting = (usize, usize);
+
+/// Inverted index over the answers and clue terms of every entry of a collection
+#[derive(Debug, Default)]
+pub(crate) struct SearchIndex {
+    words: Vec<Vec<SearchWord>>,
+    answers: BTreeMap<String, Vec<Posting>>,
+    terms: HashMap<String, Vec<Posting>>,
+}
+
+impl SearchIndex {
+    fn new<P: CollectionPuzzle>(entries: &[CollectionEntry<P>]) -> Self {
+        let mut index = Self::default();
+
+        for (entry_idx, entry) in entries.iter().enumerate() {
+            let words = entry.puzzle().map(P::words).unwrap_or_default();
+
+            for (word_idx, word) in words.iter().enumerate() {
+                let posting = (entry_idx, word_idx);
+
+                let answer = normalize(&word.answer);
+                if !answer.is_empty() {
+                    index.answers.entry(answer).or_default().push(posting);
+                }
+
+                for term in terms(&word.clue) {
+                    let postings = index.terms.entry(term).or_default();
+                    if postings.last() != Some(&posting) {
+                        postings.push(posting);
+                    }
+                }
+            }
+
+            index.words.push(words);
+        }
+
+        index
+    }
+
+    /// Words whose answer matches the normalized pattern
+    fn find_answers(&self, pattern: &str) -> Vec<Posting> {
+        if pattern.is_empty() {
+            return Vec::new();
+        }
+
+        let prefix: String = pattern
+            .chars()
+            .take_while(|&char| char != ANY_LETTER && char != ANY_LETTERS)
+            .collect();
+
+        // Without wildcards, the pattern is the answer itself
+        if prefix.len() == pattern.len() {
+            return self.answers.get(pattern).cloned().unwrap_or_default();
+        }
+
+        self.answers
+            .range(prefix.clone()..)
+            .take_while(|(answer, _)| answer.starts_with(&prefix))
+            .filter(|(answer, _)| matches_glob(pattern, answer))
+            .flat_map(|(_, postings)| postings.iter().copied())
+            .collect()
+    }
+
+    /// Words whose clue contains every term
+    fn find_clues(&self, terms: &[String]) -> Vec<Posting> {
+        let Some((first, rest)) = terms.split_first() else {
+            return Vec::new();
+        };
+
+        let mut postings = self.terms.get(first).cloned().unwrap_or_default();
+        for term in rest {
+            let Some(other) = self.terms.get(term) else {
+                return Vec::new();
+            };
+            postings.retain(|posting| other.binary_search(posting).is_ok());
+        }
+
+        postings
+    }
+}
+
+impl<P: CollectionPuzzle> Collection<P> {
+    /// Words of the entries whose answer matches the pattern or whose clue contains it
+    ///
+    /// Answers are matched ignoring casing, spaces and punctuation, where `?` matches a single letter and `*` matches any number of letters.
+    /// Patterns without wildcards are also searched for in the clue text, matching clues that contain each of its words.
+    /// Matches are ordered by entry and then by the position of the word in its puzzle.
+    ///
+    /// The index that is searched is built on the first search, which parses every entry.
+    /// Modifying the collection through [`insert`](Self::insert), [`remove`](Self::remove) or [`iter_mut`](Self::iter_mut) causes it to be rebuilt on the next search.
+    pub fn search(&self, pattern: &str) -> Vec<Match<'_, P>> {
+        let index = self.index.get_or_init(|| SearchIndex::new(&self.entries));
+
+        let mut found: BTreeMap<Posting, (bool, bool)> = BTreeMap::new();
+
+        for posting in index.find_answers(&normalize(pattern)) {
+            found.entry(posting).or_default().0 = true;
+        }
+
+        if !pattern.contains([ANY_LETTER, ANY_LETTERS]) {
+            let terms: Vec<_> = terms(pattern).collect();
+
+            for posting in index.find_clues(&terms) {
+                found.entry(posting).or_default().1 = true;
+            }
+        }
+
+        found
+            .into_iter()
+            .map(|((entry, word), (in_answer, in_clue))| Match {
+                entry: &self.entries[entry],
+                word: &index.words[entry][word],
+                in_answer,
+                in_clue,
+            })
+            .collect()
+    }
+}
+
+/// Uppercase letters and digits of the text, keeping any wildcards
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|&char| char.is_alphanumeric() || char == ANY_LETTER || char == ANY_LETTERS)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Lowercase words of the text
+fn terms(text: &str) -> impl Iterator<Item = String> {
+    text.split(|char: char| !char::is_alphanumeric(char))
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Whether the answer matches the pattern with `?` and `*` wildcards
+fn matches_glob(pattern: &str, answer: &str) -> bool {
+    let pattern: Vec<_> = pattern.chars().collect();
+    let answer: Vec<_> = answer.chars().collect();
+
+    let (mut p, mut a) = (0, 0);
+    let mut backtrack = None;
+
+    while a < answer.len() {
+        match pattern.get(p) {
+            Some(&ANY_LETTERS) => {
+                backtrack = Some((p, a));
+                p += 1;
+            }
+            Some(&char) if char == ANY_LETTER || char == answer[a] => {
+                p += 1;
+                a += 1;
+            }
+            // Let the last `*` match one more letter
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    p = star + 1;
+                    a = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&char| char == ANY_LETTERS)
+}
+
+#[cfg(all(test, feature = "crossword"))]
+mod tests {
+    use puzzled_crossword::{Crossword, crossword};
+
+    use super::*;
+    use crate::collection::PuzzleFormat;
+
+    fn library() -> Collection<Crossword> {
+        let first = crossword!(
+            [O R E O]
+            [. . . .]
+            - A: "Twist-off cookie"
+        );
+        let second = crossword!(
+            [A R E A]
+            [O R E O]
+            - A: "Region"
+            - A: "Black-and-white cookie"
+            - D: "Letters"
+            - D: "Letters"
+            - D: "Letters"
+            - D: "Letters"
+        );
+
+        [("first.puz", first), ("second.puz", second)]
+            .into_iter()
+            .map(|(name, puzzle)| CollectionEntry::from_puzzle(name, PuzzleFormat::Puz, puzzle))
+            .collect()
+    }
+
+    fn found(matches: &[Match<'_, Crossword>]) -> Vec<(String, String)> {
+        matches
+            .iter()
+            .map(|found| {
+                let name = found.entry.name().display().to_string();
+                (name, found.word.label.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn answer() {
+        let library = library();
+        let matches = library.search("oreo");
+
+        assert_eq!(
+            found(&matches),
+            [
+                ("first.puz".into(), "1-A".into()),
+                ("second.puz".into(), "5-A".into())
+            ]
+        );
+        assert!(
+            matches
+                .iter()
+                .all(|found| found.in_answer && !found.in_clue)
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        let library = library();
+
+        assert_eq!(library.search("?RE?").len(), 3);
+        assert_eq!(library.search("A*").len(), 3);
+        assert_eq!(library.search("*O").len(), 4);
+        assert!(library.search("?").is_empty());
+    }
+
+    #[test]
+    fn clue() {
+        let library = library();
+        let matches = library.search("WHITE cookie");
+
+        assert_eq!(found(&matches), [("second.puz".into(), "5-A".into())]);
+        assert!(matches[0].in_clue && !matches[0].in_answer);
+
+        assert_eq!(library.search("cookie").len(), 2);
+        assert!(library.search("cookie jar").is_empty());
+    }
+
+    #[test]
+    fn rebuild() {
+        let mut library = library();
+        assert_eq!(library.search("oreo").len(), 2);
+
+        library.remove("first.puz");
+        assert_eq!(library.search("oreo").len(), 1);
+    }
+
+    #[test]
+    fn glob() {
+        assert!(matches_glob("O*O", "OREO"));
+        assert!(matches_glob("O**", "O"));
+        assert!(matches_glob("*RE*", "AREA"));
+        assert!(!matches_glob("O?O", "OREO"));
+        assert!(!matches_glob("*E", "OREO"));
+    }
+}
@@ -1,9 +1,29 @@
 //! Puzzled
 //!
+//! # API stability
+//! The public API consists of the items that are documented on this crate and its modules:
+//! - [`core`] defines the shared building blocks, such as [grids](core::Grid), [cells](core::Cell) and the [solving traits](core::Solve)
+//! - [`io`] defines the readers and writers for the supported formats, of which the stable `*.puz` items are collected in [`io::puz::api`]
+//! - Every puzzle module, such as `crossword`, `nonogram` and `sudoku`, defines its puzzle type and re-exports the [`core`] items it is built on
+//!
+//! Items that are hidden from the documentation are shared between the puzzle crates, e.g. to implement [`BinaryPuzzle`](io::puz::BinaryPuzzle).
+//! They are not part of the stable API and may change in any release.
+//! Extension traits such as [`PuzRead`](io::puz::PuzRead) are sealed, such that new methods can be added without breaking changes.
+//!
 //! # Features
 #![doc = document_features::document_features!()]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod collection;
+
+#[cfg(feature = "progress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "progress")))]
+pub mod progress;
+
+#[cfg(feature = "crossword")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossword")))]
+pub mod view;
+
 #[doc(inline)]
 pub use puzzled_core as core;
 
@@ -24,3 +44,8 @@ pub use puzzled_crossword as crossword;
 #[cfg(feature = "nonogram")]
 #[cfg_attr(docsrs, doc(cfg(feature = "nonogram")))]
 pub use puzzled_nonogram as nonogram;
+
+#[doc(inline)]
+#[cfg(feature = "sudoku")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sudoku")))]
+pub use puzzled_sudoku as sudoku;
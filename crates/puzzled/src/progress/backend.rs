@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use crate::progress::{PuzzleId, Result};
+
+/// Storage of the serialized progress of a [progress store](super::Progress)
+pub trait ProgressBackend {
+    fn load(&self, id: PuzzleId) -> Result<Option<Value>>;
+
+    fn save(&mut self, id: PuzzleId, state: Value) -> Result<()>;
+
+    /// Remove the progress of the puzzle, returning whether there was any
+    fn remove(&mut self, id: PuzzleId) -> Result<bool>;
+
+    /// Identities of all puzzles with progress, in ascending order
+    fn ids(&self) -> Result<Vec<PuzzleId>>;
+}
+
+/// Backend that keeps progress in memory, such that it is lost when dropped
+#[derive(Debug, Default, Clone)]
+pub struct MemoryBackend {
+    states: BTreeMap<PuzzleId, Value>,
+}
+
+impl ProgressBackend for MemoryBackend {
+    fn load(&self, id: PuzzleId) -> Result<Option<Value>> {
+        Ok(self.states.get(&id).cloned())
+    }
+
+    fn save(&mut self, id: PuzzleId, state: Value) -> Result<()> {
+        self.states.insert(id, state);
+        Ok(())
+    }
+
+    fn remove(&mut self, id: PuzzleId) -> Result<bool> {
+        Ok(self.states.remove(&id).is_some())
+    }
+
+    fn ids(&self) -> Result<Vec<PuzzleId>> {
+        Ok(self.states.keys().copied().collect())
+    }
+}
+
+/// Backend that keeps progress in a single JSON file, which is rewritten on every change
+///
+/// The file maps the [identity](PuzzleId) of every puzzle to its progress.
+#[derive(Debug, Clone)]
+pub struct JsonFileBackend {
+    path: PathBuf,
+    states: MemoryBackend,
+}
+
+impl JsonFileBackend {
+    /// Open the progress in the file, which is created on the first save if it does not exist yet
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let states = match fs::read(&path) {
+            Ok(bytes) => {
+                let file: BTreeMap<String, Value> = serde_json::from_slice(&bytes)?;
+
+                file.into_iter()
+                    .filter_map(|(id, state)| Some((id.parse().ok()?, state)))
+                    .collect()
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self {
+            path,
+            states: MemoryBackend { states },
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn flush(&self) -> Result<()> {
+        let file: BTreeMap<String, &Value> = self
+            .states
+            .states
+            .iter()
+            .map(|(id, state)| (id.to_string(), state))
+            .collect();
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write to a sibling file first, such that a crash cannot leave the progress half written
+        let mut temp = self.path.clone().into_os_string();
+        temp.push(".tmp");
+
+        fs::write(&temp, serde_json::to_vec_pretty(&file)?)?;
+        fs::rename(&temp, &self.path)?;
+        Ok(())
+    }
+}
+
+impl ProgressBackend for JsonFileBackend {
+    fn load(&self, id: PuzzleId) -> Result<Option<Value>> {
+        self.states.load(id)
+    }
+
+    fn save(&mut self, id: PuzzleId, state: Value) -> Result<()> {
+        self.states.save(id, state)?;
+        self.flush()
+    }
+
+    fn remove(&mut self, id: PuzzleId) -> Result<bool> {
+        let removed = self.states.remove(id)?;
+        if removed {
+            self.flush()?;
+        }
+
+        Ok(removed)
+    }
+
+    fn ids(&self) -> Result<Vec<PuzzleId>> {
+        self.states.ids()
+    }
+}
+
+#[cfg(feature = "sled")]
+mod sled_impl {
+    use std::path::Path;
+
+    use serde_json::Value;
+
+    use crate::progress::{ProgressBackend, PuzzleId, Result};
+
+    /// Backend that keeps progress in a [`sled`] database, keyed by the big-endian [hash](PuzzleId::hash) of the puzzle
+    #[cfg_attr(docsrs, doc(cfg(feature = "sled")))]
+    #[derive(Debug, Clone)]
+    pub struct SledBackend {
+        tree: sled::Tree,
+    }
+
+    impl SledBackend {
+        pub fn new(tree: sled::Tree) -> Self {
+            Self { tree }
+        }
+
+        /// Open the database at the path, keeping progress in its default tree
+        pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+            let db = sled::open(path)?;
+            Ok(Self::new(sled::Tree::clone(&db)))
+        }
+    }
+
+    impl ProgressBackend for SledBackend {
+        fn load(&self, id: PuzzleId) -> Result<Option<Value>> {
+            let Some(bytes) = self.tree.get(id.hash().to_be_bytes())? else {
+                return Ok(None);
+            };
+
+            Ok(Some(serde_json::from_slice(&bytes)?))
+        }
+
+        fn save(&mut self, id: PuzzleId, state: Value) -> Result<()> {
+            self.tree
+                .insert(id.hash().to_be_bytes(), serde_json::to_vec(&state)?)?;
+            self.tree.flush()?;
+
+            Ok(())
+        }
+
+        fn remove(&mut self, id: PuzzleId) -> Result<bool> {
+            let removed = self.tree.remove(id.hash().to_be_bytes())?.is_some();
+            self.tree.flush()?;
+
+            Ok(removed)
+        }
+
+        /// Identifiers of the stored progress, skipping keys that are not a hash and were therefore not saved by the backend
+        fn ids(&self) -> Result<Vec<PuzzleId>> {
+            let mut ids = vec![];
+
+            for key in self.tree.iter().keys() {
+                if let Ok(bytes) = key?.as_ref().try_into() {
+                    ids.push(PuzzleId::new(u64::from_be_bytes(bytes)));
+                }
+            }
+
+            Ok(ids)
+        }
+    }
+}
+
+#[cfg(feature = "sled")]
+pub use sled_impl::*;
@@ -0,0 +1,16 @@
+use std::io;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Invalid progress data: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[cfg(feature = "sled")]
+    #[error("Database error: {0}")]
+    Sled(#[from] sled::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,103 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use puzzled_core::{Size, StableHasher};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Identity of a puzzle that is derived from its title, size and solutions
+///
+/// The identity is a [stable hash](StableHasher), such that the same puzzle has the same identity on every platform and in every release.
+/// ```
+/// use puzzled::{core::Size, progress::PuzzleId};
+///
+/// let size = Size { rows: 1, cols: 2 };
+/// let id = PuzzleId::from_solutions(Some("Mini"), size, [Some("A"), None]);
+///
+/// assert_eq!(id, PuzzleId::from_solutions(Some("Mini"), size, [Some("A"), None]));
+/// assert_ne!(id, PuzzleId::from_solutions(Some("Maxi"), size, [Some("A"), None]));
+/// assert_eq!(id.to_string().parse(), Ok(id));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId(u64);
+
+impl PuzzleId {
+    pub fn new(hash: u64) -> Self {
+        Self(hash)
+    }
+
+    pub fn hash(&self) -> u64 {
+        self.0
+    }
+
+    /// Identity of a puzzle from its title, size and the solutions of its squares in row-major order, where blocks have no solution
+    pub fn from_solutions<I, S>(title: Option<&str>, size: Size, solutions: I) -> Self
+    where
+        I: IntoIterator<Item = Option<S>>,
+        S: fmt::Display,
+    {
+        let mut hasher = StableHasher::new();
+        hasher.write_str(title.unwrap_or_default());
+        hasher.write_u64(size.rows as u64);
+        hasher.write_u64(size.cols as u64);
+
+        for solution in solutions {
+            match solution {
+                Some(solution) => hasher.write_str(&solution.to_string()),
+                None => hasher.write_block(),
+            }
+        }
+
+        Self(hasher.finish())
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s, 16).map(Self)
+    }
+}
+
+/// Puzzle whose progress can be kept in a [progress store](super::Progress)
+pub trait ProgressPuzzle {
+    /// Progress of a player on the puzzle, which does not contain the solution of the puzzle itself
+    type State: Serialize + DeserializeOwned;
+
+    fn puzzle_id(&self) -> PuzzleId;
+}
+
+#[cfg(feature = "crossword")]
+mod crossword_impl {
+    use puzzled_core::Size;
+    use puzzled_crossword::{Crossword, PuzzleState};
+
+    use crate::progress::{ProgressPuzzle, PuzzleId};
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "crossword")))]
+    impl ProgressPuzzle for Crossword {
+        type State = PuzzleState;
+
+        fn puzzle_id(&self) -> PuzzleId {
+            let size = Size {
+                rows: self.rows(),
+                cols: self.cols(),
+            };
+            let solutions = self.squares().iter().map(|square| {
+                square.as_ref().map(|cell| {
+                    cell.solution
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default()
+                })
+            });
+
+            PuzzleId::from_solutions(self.meta().title(), size, solutions)
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Defines a [`Progress`] store that keeps the saved state of players for many puzzles
+//!
+//! Puzzles are identified by a [`PuzzleId`] that is derived from their contents, so progress is found again regardless of where the puzzle file lives.
+//! Where the progress is kept is decided by its [backend](ProgressBackend), such as [memory](MemoryBackend) or a [JSON file](JsonFileBackend).
+//! ```no_run
+//! use puzzled::{crossword::{crossword, CrosswordState, Position, Solve}, progress::{JsonFileBackend, Progress}};
+//!
+//! let mut puzzle = crossword!([A B] [C D]);
+//! let mut progress = Progress::new(JsonFileBackend::open("progress.json")?);
+//!
+//! // Resume where the player left off, or start over
+//! let mut state = match progress.load(&puzzle)? {
+//!     Some(snapshot) => puzzle.restore_state(snapshot).expect("progress belongs to the puzzle"),
+//!     None => CrosswordState::from(&puzzle),
+//! };
+//!
+//! state.reveal(&Position::new(0, 0));
+//! progress.save(&puzzle, &puzzle.snapshot(&state))?;
+//! # Ok::<(), puzzled::progress::Error>(())
+//! ```
+
+mod backend;
+mod error;
+mod id;
+
+pub use backend::*;
+pub use error::*;
+pub use id::*;
+
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Saved progress of players on any number of puzzles, which is kept by a [backend](ProgressBackend)
+#[derive(Debug, Default)]
+pub struct Progress<B = MemoryBackend> {
+    backend: B,
+}
+
+impl<B: ProgressBackend> Progress<B> {
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    pub fn into_backend(self) -> B {
+        self.backend
+    }
+
+    /// Load the saved progress on the puzzle, if any
+    pub fn load<P: ProgressPuzzle>(&self, puzzle: &P) -> Result<Option<P::State>> {
+        self.load_id(puzzle.puzzle_id())
+    }
+
+    /// Load the saved progress on the puzzle with the given identity, if any
+    pub fn load_id<S: DeserializeOwned>(&self, id: PuzzleId) -> Result<Option<S>> {
+        let Some(value) = self.backend.load(id)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(serde_json::from_value(value)?))
+    }
+
+    /// Save the progress on the puzzle, replacing any earlier progress
+    pub fn save<P: ProgressPuzzle>(&mut self, puzzle: &P, state: &P::State) -> Result<()> {
+        self.save_id(puzzle.puzzle_id(), state)
+    }
+
+    /// Save the progress on the puzzle with the given identity, replacing any earlier progress
+    pub fn save_id<S: Serialize>(&mut self, id: PuzzleId, state: &S) -> Result<()> {
+        let value = serde_json::to_value(state)?;
+        self.backend.save(id, value)
+    }
+
+    /// Remove the saved progress on the puzzle, returning whether there was any
+    pub fn remove<P: ProgressPuzzle>(&mut self, puzzle: &P) -> Result<bool> {
+        self.backend.remove(puzzle.puzzle_id())
+    }
+
+    /// Whether there is saved progress on the puzzle
+    pub fn contains<P: ProgressPuzzle>(&self, puzzle: &P) -> Result<bool> {
+        Ok(self.backend.load(puzzle.puzzle_id())?.is_some())
+    }
+
+    /// Identities of all puzzles with saved progress
+    pub fn ids(&self) -> Result<Vec<PuzzleId>> {
+        self.backend.ids()
+    }
+}
+
+#[cfg(all(test, feature = "crossword"))]
+mod tests {
+    use std::fs;
+
+    use puzzled_crossword::{CrosswordState, Position, Solve, crossword};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn play<B: ProgressBackend>(mut progress: Progress<B>) -> Progress<B> {
+        let puzzle = crossword!([A B] [C D]);
+        let other = crossword!([A B] [C E]);
+        assert_eq!(progress.load(&puzzle).unwrap().map(|_| ()), None);
+
+        let mut state = CrosswordState::from(&puzzle);
+        state.clear(&Position::new(1, 1));
+        progress.save(&puzzle, &state.snapshot()).unwrap();
+
+        assert!(progress.contains(&puzzle).unwrap());
+        assert!(!progress.contains(&other).unwrap());
+        assert_eq!(progress.ids().unwrap(), vec![puzzle.puzzle_id()]);
+
+        let snapshot = progress.load(&puzzle).unwrap().expect("progress is saved");
+        let restored = puzzle.apply_state(snapshot).unwrap();
+        assert!(restored.diff_state(&state).is_empty());
+
+        progress
+    }
+
+    #[test]
+    fn memory() {
+        let mut progress = play(Progress::<MemoryBackend>::default());
+        let puzzle = crossword!([A B] [C D]);
+
+        assert!(progress.remove(&puzzle).unwrap());
+        assert!(!progress.remove(&puzzle).unwrap());
+        assert!(progress.ids().unwrap().is_empty());
+    }
+
+    #[test]
+    fn json_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("progress.json");
+
+        play(Progress::new(JsonFileBackend::open(&path).unwrap()));
+
+        // Progress is kept after reopening the file, without leaving temporary files behind
+        let progress = Progress::new(JsonFileBackend::open(&path).unwrap());
+        let puzzle = crossword!([A B] [C D]);
+        assert!(progress.contains(&puzzle).unwrap());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[cfg(feature = "sled")]
+    #[test]
+    fn sled() {
+        let dir = TempDir::new().unwrap();
+
+        // Keys that were not saved by the backend are skipped
+        let db = sled::open(dir.path().join("progress.sled")).unwrap();
+        db.insert(b"not an id", b"{}".as_slice()).unwrap();
+
+        play(Progress::new(SledBackend::new(sled::Tree::clone(&db))));
+    }
+}
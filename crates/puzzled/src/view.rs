@@ -0,0 +1,257 @@
+//! Defines a [`GridViewModel`] that computes what every square of a [crossword](Crossword) should display, independent of the UI framework that draws it
+//!
+//! The view model binds a [state](CrosswordState) and a [navigator](Navigator) to a grid of [cell views](CellView):
+//! - UIs mutate the state as usual and pass its [drained events](CrosswordState::drain_events) to [`apply_events`](GridViewModel::apply_events)
+//! - Moving the cursor goes through [`set_navigator`](GridViewModel::set_navigator)
+//!
+//! Both only recompute the affected squares and return their positions, such that only those have to be redrawn.
+//! ```
+//! use puzzled::{
+//!     crossword::{crossword, CrosswordState, Navigator, Position, Solution, Solve},
+//!     view::GridViewModel,
+//! };
+//!
+//! let puzzle = crossword!(
+//!     [A B]
+//!     [C .]
+//!     - A: "AB"
+//!     - D: "AC"
+//! );
+//! let mut state = CrosswordState::from(&puzzle);
+//! state.clear(&Position::new(0, 0));
+//! state.drain_events(&puzzle);
+//!
+//! let mut view = GridViewModel::new(&puzzle, &state, Navigator::new(&puzzle));
+//! assert_eq!(view[Position::new(0, 0)].number, Some(1));
+//! assert!(view[Position::new(0, 1)].is_selected);
+//!
+//! // Type a letter and move on to the next square
+//! state.enter(&Position::new(0, 0), Solution::Letter('A'));
+//! let events = state.drain_events(&puzzle);
+//! let changed = view.apply_events(&puzzle, &state, &events);
+//! assert_eq!(changed, vec![Position::new(0, 0)]);
+//! assert_eq!(view[Position::new(0, 0)].glyph.as_deref(), Some("A"));
+//!
+//! let mut nav = view.navigator();
+//! nav.advance(&puzzle);
+//! let changed = view.set_navigator(&puzzle, nav);
+//! assert_eq!(changed, vec![Position::new(0, 0), Position::new(0, 1)]);
+//! assert!(view[Position::new(0, 1)].is_cursor);
+//! ```
+
+use std::{collections::BTreeSet, ops::Index};
+
+use crate::{
+    core::{CellDecoration, CellShape, CellStyle, Grid, Position, Solve},
+    crossword::{Crossword, CrosswordState, Navigator, PuzzleEvent},
+};
+
+/// Everything that a UI needs to draw a single square of a [crossword](Crossword)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CellView {
+    /// Entry of the square, or its solution once it is revealed
+    pub glyph: Option<String>,
+
+    /// Number of the clues that start at the square
+    pub number: Option<u8>,
+
+    /// Whether the square is a block, in which case it displays nothing else
+    pub is_block: bool,
+
+    /// Style of the entry, such as whether it is circled, revealed or incorrect
+    pub style: CellStyle,
+
+    /// Shape in the background of the square
+    pub shape: Option<CellShape>,
+
+    /// Decoration of the square, such as its colors and bars
+    pub decoration: CellDecoration,
+
+    /// Whether the cursor of the [navigator](Navigator) is on the square
+    pub is_cursor: bool,
+
+    /// Whether the square is part of the current clue of the [navigator](Navigator)
+    pub is_selected: bool,
+}
+
+/// Grid of [cell views](CellView) that is kept up to date with a [state](CrosswordState) and [navigator](Navigator), see the [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridViewModel {
+    cells: Grid<CellView>,
+    navigator: Navigator,
+}
+
+impl GridViewModel {
+    pub fn new(crossword: &Crossword, state: &CrosswordState, navigator: Navigator) -> Self {
+        let mut view = Self {
+            cells: crossword.squares().map_ref(|_| CellView::default()),
+            navigator,
+        };
+
+        for pos in crossword.squares().positions() {
+            view.refresh(crossword, state, pos);
+        }
+
+        view
+    }
+
+    pub fn cells(&self) -> &Grid<CellView> {
+        &self.cells
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&CellView> {
+        self.cells.get(pos)
+    }
+
+    pub fn navigator(&self) -> Navigator {
+        self.navigator
+    }
+
+    /// Recompute the squares that the events changed, returning the positions of the squares whose view changed
+    ///
+    /// Events that do not belong to a single square, such as [completed clues](PuzzleEvent::ClueCompleted), do not change the view.
+    pub fn apply_events(
+        &mut self,
+        crossword: &Crossword,
+        state: &CrosswordState,
+        events: &[PuzzleEvent],
+    ) -> Vec<Position> {
+        let positions: BTreeSet<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                PuzzleEvent::CellEntered { pos, .. }
+                | PuzzleEvent::CellCleared { pos }
+                | PuzzleEvent::CellRevealed { pos } => Some(*pos),
+                _ => None,
+            })
+            .collect();
+
+        positions
+            .into_iter()
+            .filter(|&pos| self.refresh(crossword, state, pos))
+            .collect()
+    }
+
+    /// Move the cursor and selection to the navigator, returning the positions of the squares whose view changed
+    pub fn set_navigator(&mut self, crossword: &Crossword, navigator: Navigator) -> Vec<Position> {
+        let mut positions = self.selection(crossword);
+        positions.insert(self.navigator.position());
+
+        self.navigator = navigator;
+        let selection = self.selection(crossword);
+        positions.extend(&selection);
+        positions.insert(navigator.position());
+
+        positions
+            .into_iter()
+            .filter(|&pos| {
+                // Blocks are never drawn with a cursor or selection, as in `compute`
+                let Some(cell) = self.cells.get_mut(pos).filter(|cell| !cell.is_block) else {
+                    return false;
+                };
+
+                let flags = (pos == navigator.position(), selection.contains(&pos));
+                let changed = flags != (cell.is_cursor, cell.is_selected);
+                (cell.is_cursor, cell.is_selected) = flags;
+
+                changed
+            })
+            .collect()
+    }
+
+    /// Recompute the square at the position, e.g. after it was [checked](Solve::check) which does not emit an event.
+    /// Returns whether its view changed
+    pub fn refresh(
+        &mut self,
+        crossword: &Crossword,
+        state: &CrosswordState,
+        pos: Position,
+    ) -> bool {
+        let view = self.compute(crossword, state, pos);
+        let Some(cell) = self.cells.get_mut(pos) else {
+            return false;
+        };
+
+        let changed = *cell != view;
+        *cell = view;
+
+        changed
+    }
+
+    /// Squares of the current clue, which are [selected](CellView::is_selected)
+    fn selection(&self, crossword: &Crossword) -> BTreeSet<Position> {
+        let clue = self.navigator.clue(crossword);
+
+        clue.into_iter().flat_map(|clue| clue.positions()).collect()
+    }
+
+    fn compute(&self, crossword: &Crossword, state: &CrosswordState, pos: Position) -> CellView {
+        let (Some(cell), Some(entry)) = (
+            crossword.squares().get_fill(pos),
+            state.entries.get_fill(pos),
+        ) else {
+            return CellView {
+                is_block: true,
+                ..CellView::default()
+            };
+        };
+
+        let glyph = match entry.is_revealed() || entry.is_initially_revealed() {
+            true => state.solution(&pos),
+            false => entry.entry(),
+        };
+        let is_selected = self
+            .navigator
+            .clue(crossword)
+            .is_some_and(|clue| clue.positions().any(|clue_pos| clue_pos == pos));
+
+        CellView {
+            glyph: glyph.map(ToString::to_string),
+            number: crossword.clues().get_num(pos),
+            is_block: false,
+            style: entry.style(),
+            shape: cell.shape(),
+            decoration: cell.decoration,
+            is_cursor: pos == self.navigator.position(),
+            is_selected,
+        }
+    }
+}
+
+impl Index<Position> for GridViewModel {
+    type Output = CellView;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        &self.cells[pos]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::crossword::{ClueDirection, crossword};
+
+    #[rstest]
+    #[case::clue(Navigator::at(Position::new(0, 0), ClueDirection::Down))]
+    #[case::without_clue(Navigator::at(Position::new(1, 0), ClueDirection::Across))]
+    #[case::block(Navigator::at(Position::new(1, 1), ClueDirection::Across))]
+    fn set_navigator(#[case] navigator: Navigator) {
+        let puzzle = crossword!(
+            [A B]
+            [C .]
+            - A: "AB"
+            - D: "AC"
+        );
+        let state = CrosswordState::from(&puzzle);
+        let start = Navigator::new(&puzzle);
+
+        let mut view = GridViewModel::new(&puzzle, &state, start);
+        view.set_navigator(&puzzle, navigator);
+        assert_eq!(view, GridViewModel::new(&puzzle, &state, navigator));
+
+        view.set_navigator(&puzzle, start);
+        assert_eq!(view, GridViewModel::new(&puzzle, &state, start));
+    }
+}
@@ -76,7 +76,7 @@ impl BinaryPuzzle<BinarioState> for Binario {
 
         let binario = Binario::new(cells, meta);
 
-        let timer = extras.ltim.unwrap_or_default();
+        let timer = extras.ltim.clone().unwrap_or_default();
         let state = BinarioState::new(solutions, entries, timer);
 
         Ok((binario, state))
@@ -5,8 +5,9 @@ use chumsky::{
 };
 use puzzled_core::Metadata;
 use puzzled_io::text::{
-    TxtPuzzle,
-    read::{self, ParseError, cell, grid, ignore_case_keyword},
+    FormatConfig, TxtPuzzle,
+    read::{self, ParseError, cell_with, grid, ignore_case_keyword},
+    write::cell_text,
 };
 
 use crate::{Binario, Bit};
@@ -25,7 +26,7 @@ pub fn bit<'a>() -> impl Parser<'a, &'a str, Bit, Err<ParseError<'a>>> + Clone {
 }
 
 impl TxtPuzzle for Binario {
-    fn read_text<'a>(input: &str) -> read::Result<Binario> {
+    fn read_text_with(input: &str, config: &FormatConfig) -> read::Result<Binario> {
         // let (cells, entries) =
         //     cell_entry_grids(bit())
         //         .parse(input)
@@ -44,19 +45,19 @@ impl TxtPuzzle for Binario {
         //
         // Ok((puzzle, state))
 
-        let cells = grid(cell(bit()))
+        let cells = grid(cell_with(bit(), *config))
             .parse(input)
             .into_result()
-            .map_err(|errs| {
-                read::Error::Parse(errs.into_iter().map(|err| err.to_string()).collect())
-            })?;
+            .map_err(|errs| read::Error::parse(input, errs))?;
         let meta = Metadata::default();
 
         Ok(Binario::new(cells, meta))
     }
 
-    fn write_text(&self) -> String {
-        self.to_string()
+    fn write_text_with(&self, config: &FormatConfig) -> String {
+        let cells = self.cells().map_ref(|cell| cell_text(cell, config));
+
+        format!("{cells}\n{}\n", self.meta())
     }
 }
 
@@ -34,6 +34,10 @@ impl Binario {
     pub fn meta(&self) -> &Metadata {
         &self.meta
     }
+
+    pub fn meta_mut(&mut self) -> &mut Metadata {
+        &mut self.meta
+    }
 }
 
 impl fmt::Display for Binario {
@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::{Format, RenderFormat};
+
+/// Convert, validate, inspect and render crossword files
+#[derive(Debug, Parser)]
+#[command(version, about, long_about = None)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Convert a puzzle between formats, which are inferred from the file extensions by default
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+
+        /// Format to read the input as
+        #[arg(long)]
+        from: Option<Format>,
+
+        /// Format to write the output as
+        #[arg(long)]
+        to: Option<Format>,
+    },
+
+    /// Parse a puzzle strictly and report every issue in it
+    Validate {
+        input: PathBuf,
+
+        #[arg(long)]
+        format: Option<Format>,
+    },
+
+    /// Show the metadata of a puzzle and statistics about its grid
+    Info {
+        input: PathBuf,
+
+        #[arg(long)]
+        format: Option<Format>,
+    },
+
+    /// Render the grid of a puzzle as an image
+    Render {
+        input: PathBuf,
+        output: PathBuf,
+
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Image format, which is inferred from the output extension by default
+        #[arg(long)]
+        to: Option<RenderFormat>,
+
+        /// Size of a square in pixels, which should be at least 2 to fit the shapes of cells
+        #[arg(long, default_value_t = 32, value_parser = clap::value_parser!(u32).range(2..))]
+        scale: u32,
+
+        /// Fill in the solution, which is only drawn in SVG images since PNG images contain no text
+        #[arg(long)]
+        solution: bool,
+    },
+}
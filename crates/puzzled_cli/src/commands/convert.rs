@@ -0,0 +1,9 @@
+use std::path::Path;
+
+use crate::{Format, Result, read_crossword, write_crossword};
+
+/// Convert the puzzle at the input path into the output format
+pub fn convert(input: &Path, from: Format, output: &Path, to: Format) -> Result<()> {
+    let (puzzle, state) = read_crossword(input, from)?;
+    write_crossword(output, to, &puzzle, &state)
+}
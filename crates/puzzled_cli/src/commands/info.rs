@@ -0,0 +1,111 @@
+use std::fmt;
+
+use puzzled_core::CellStyle;
+use puzzled_crossword::{ClueDirection, Crossword};
+
+/// Statistics about the grid of a crossword
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridStats {
+    pub rows: usize,
+    pub cols: usize,
+    pub blocks: usize,
+    pub across: usize,
+    pub down: usize,
+    pub average_len: f64,
+    pub rebuses: usize,
+    pub circles: usize,
+}
+
+impl GridStats {
+    pub fn new(puzzle: &Crossword) -> Self {
+        let squares = puzzle.squares();
+        let cells: Vec<_> = squares
+            .iter()
+            .filter_map(|square| square.as_ref())
+            .collect();
+
+        let clues = puzzle.clues();
+        let total_len: usize = clues.values().map(|clue| clue.len() as usize).sum();
+        let average_len = match clues.len() {
+            0 => 0.0,
+            count => total_len as f64 / count as f64,
+        };
+
+        Self {
+            rows: puzzle.rows(),
+            cols: puzzle.cols(),
+            blocks: squares.area() - cells.len(),
+            across: clues.iter_direction(ClueDirection::Across).count(),
+            down: clues.iter_direction(ClueDirection::Down).count(),
+            average_len,
+            rebuses: cells
+                .iter()
+                .filter(|cell| cell.solution.as_ref().is_some_and(|sol| !sol.is_letter()))
+                .count(),
+            circles: cells
+                .iter()
+                .filter(|cell| cell.style.contains(CellStyle::CIRCLED))
+                .count(),
+        }
+    }
+
+    /// Share of the squares that are blocks, as a percentage
+    pub fn block_percentage(&self) -> f64 {
+        match self.rows * self.cols {
+            0 => 0.0,
+            area => 100.0 * self.blocks as f64 / area as f64,
+        }
+    }
+}
+
+impl fmt::Display for GridStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "size: {}x{}", self.rows, self.cols)?;
+        writeln!(
+            f,
+            "blocks: {} ({:.1}%)",
+            self.blocks,
+            self.block_percentage()
+        )?;
+        writeln!(
+            f,
+            "words: {} ({} across, {} down)",
+            self.across + self.down,
+            self.across,
+            self.down
+        )?;
+        writeln!(f, "average word length: {:.2}", self.average_len)?;
+        writeln!(f, "rebuses: {}", self.rebuses)?;
+        writeln!(f, "circles: {}", self.circles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_crossword::crossword;
+
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let puzzle = crossword!(
+            [C A .]
+            [A G E]
+            [. O W]
+            - A: "Is able to"
+            - A: "Length of life"
+            - A: "Stick (out)"
+            - D: "Automobile"
+            - D: "Gone"
+            - D: "Female sheep"
+        );
+
+        let stats = GridStats::new(&puzzle);
+
+        assert_eq!((stats.rows, stats.cols, stats.blocks), (3, 3, 2));
+        assert_eq!((stats.across, stats.down), (3, 3));
+        assert_eq!(stats.average_len, 14.0 / 6.0);
+        assert_eq!((stats.rebuses, stats.circles), (0, 0));
+        assert_eq!(format!("{:.1}", stats.block_percentage()), "22.2");
+    }
+}
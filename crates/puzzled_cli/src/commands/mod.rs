@@ -0,0 +1,54 @@
+mod convert;
+mod info;
+mod render;
+mod validate;
+
+pub use convert::*;
+pub use info::*;
+pub use render::*;
+pub use validate::*;
+
+use crate::{Command, Format, RenderFormat, Result, read_crossword};
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Convert {
+            input,
+            output,
+            from,
+            to,
+        } => {
+            let from = Format::resolve(from, &input)?;
+            let to = Format::resolve(to, &output)?;
+
+            convert(&input, from, &output, to)
+        }
+        Command::Validate { input, format } => {
+            let format = Format::resolve(format, &input)?;
+            validate(&input, format)
+        }
+        Command::Info { input, format } => {
+            let format = Format::resolve(format, &input)?;
+            let (puzzle, _) = read_crossword(&input, format)?;
+
+            print!("{}", puzzle.meta());
+            print!("{}", GridStats::new(&puzzle));
+            Ok(())
+        }
+        Command::Render {
+            input,
+            output,
+            format,
+            to,
+            scale,
+            solution,
+        } => {
+            let format = Format::resolve(format, &input)?;
+            let to = RenderFormat::resolve(to, &output)?;
+            let (puzzle, _) = read_crossword(&input, format)?;
+
+            let options = RenderOptions { scale, solution };
+            render(&puzzle, &output, to, &options)
+        }
+    }
+}
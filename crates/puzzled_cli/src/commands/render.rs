@@ -0,0 +1,326 @@
+use std::{fmt::Write, fs, path::Path};
+
+use image::{Rgba, RgbaImage};
+use puzzled_core::{CellBorders, CellShape, Position};
+use puzzled_crossword::Crossword;
+
+use crate::{RenderFormat, Result};
+
+const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Size of a square in pixels, which should be at least 2 to fit the shapes of cells
+    pub scale: u32,
+
+    /// Whether to fill in the solution
+    pub solution: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            scale: 32,
+            solution: false,
+        }
+    }
+}
+
+/// Render the grid of the puzzle into an image at the given path
+pub fn render(
+    puzzle: &Crossword,
+    output: &Path,
+    format: RenderFormat,
+    options: &RenderOptions,
+) -> Result<()> {
+    match format {
+        RenderFormat::Svg => fs::write(output, render_svg(puzzle, options))?,
+        RenderFormat::Png => render_png(puzzle, options).save(output)?,
+    }
+
+    Ok(())
+}
+
+/// Render the grid as an SVG document with the numbers of its clues, the [decorations](puzzled_core::CellDecoration) of its cells and optionally its solution
+///
+/// Right-to-left puzzles are rendered with their numbers in the top right corner of the squares and with their text laid out from right to left.
+pub fn render_svg(puzzle: &Crossword, options: &RenderOptions) -> String {
+    let scale = options.scale as usize;
+    let (width, height) = (puzzle.cols() * scale, puzzle.rows() * scale);
+    let rtl = puzzle.writing().is_rtl();
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}"{}>"#,
+        if rtl { r#" direction="rtl""# } else { "" }
+    );
+
+    for (pos, square) in puzzle.squares().iter_indexed() {
+        let (x, y) = (pos.col * scale, pos.row * scale);
+
+        let Some(cell) = square.as_ref() else {
+            let _ = writeln!(
+                svg,
+                r#"  <rect x="{x}" y="{y}" width="{scale}" height="{scale}" fill="black"/>"#
+            );
+            continue;
+        };
+
+        let fill = cell
+            .decoration
+            .background
+            .map_or("white".to_string(), |color| color.to_hex());
+        let _ = writeln!(
+            svg,
+            r#"  <rect x="{x}" y="{y}" width="{scale}" height="{scale}" fill="{fill}" stroke="black"/>"#
+        );
+
+        let (center, radius) = (scale / 2, scale / 2 - 1);
+        let (cx, cy) = (x + center, y + center);
+
+        match cell.shape() {
+            Some(CellShape::Circle) => {
+                let _ = writeln!(
+                    svg,
+                    r#"  <circle cx="{cx}" cy="{cy}" r="{radius}" fill="none" stroke="black"/>"#
+                );
+            }
+            Some(CellShape::Diamond) => {
+                let _ = writeln!(
+                    svg,
+                    r#"  <polygon points="{cx},{} {},{cy} {cx},{} {},{cy}" fill="none" stroke="black"/>"#,
+                    cy - radius,
+                    cx + radius,
+                    cy + radius,
+                    cx - radius
+                );
+            }
+            Some(CellShape::Triangle) => {
+                let _ = writeln!(
+                    svg,
+                    r#"  <polygon points="{cx},{} {},{} {},{}" fill="none" stroke="black"/>"#,
+                    cy - radius,
+                    cx + radius,
+                    cy + radius,
+                    cx - radius,
+                    cy + radius
+                );
+            }
+            None => {}
+        }
+
+        for (side, _) in CellBorders::SIDES {
+            if !cell.decoration.bars.contains(side) {
+                continue;
+            }
+
+            let (x1, y1, x2, y2) = match side {
+                CellBorders::TOP => (x, y, x + scale, y),
+                CellBorders::RIGHT => (x + scale, y, x + scale, y + scale),
+                CellBorders::BOTTOM => (x, y + scale, x + scale, y + scale),
+                _ => (x, y, x, y + scale),
+            };
+            let _ = writeln!(
+                svg,
+                r#"  <line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="black" stroke-width="{}"/>"#,
+                (scale / 8).max(2)
+            );
+        }
+
+        if let Some(label) = puzzle.numbering().square_label(puzzle.clues(), pos) {
+            // Text starts at the right of its anchor when laid out from right to left
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{}" y="{}" font-size="{}" font-family="sans-serif">{label}</text>"#,
+                if rtl { x + scale - 2 } else { x + 2 },
+                y + scale / 3,
+                scale / 3
+            );
+        }
+
+        if options.solution
+            && let Some(solution) = &cell.solution
+        {
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{}" y="{}" font-size="{}" font-family="sans-serif" text-anchor="middle">{}</text>"#,
+                x + scale / 2,
+                y + scale * 7 / 8,
+                scale * 3 / 5,
+                escape(&solution.to_string())
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Render the grid as a PNG image with its blocks and the [decorations](puzzled_core::CellDecoration) of its cells
+///
+/// Numbers and solutions are not drawn, as the image is rasterized without any fonts
+pub fn render_png(puzzle: &Crossword, options: &RenderOptions) -> RgbaImage {
+    let scale = options.scale.max(1);
+    let width = puzzle.cols() as u32 * scale + 1;
+    let height = puzzle.rows() as u32 * scale + 1;
+
+    let mut image = RgbaImage::from_pixel(width, height, WHITE);
+    let squares = puzzle.squares();
+    let bar = (scale / 8).max(2);
+
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let (col, row) = ((x / scale) as usize, (y / scale) as usize);
+        let (dx, dy) = (x % scale, y % scale);
+
+        // Grid lines are drawn on the top and left edge of every square, including the outer border
+        if dx == 0 || dy == 0 {
+            *pixel = BLACK;
+            continue;
+        }
+
+        let Some(square) = squares.get(Position { row, col }) else {
+            continue;
+        };
+
+        let Some(cell) = square.as_ref() else {
+            *pixel = BLACK;
+            continue;
+        };
+
+        let bars = cell.decoration.bars;
+        let on_bar = (bars.contains(CellBorders::TOP) && dy < bar)
+            || (bars.contains(CellBorders::RIGHT) && dx + bar >= scale)
+            || (bars.contains(CellBorders::BOTTOM) && dy + bar >= scale)
+            || (bars.contains(CellBorders::LEFT) && dx < bar);
+
+        let center = scale as f64 / 2.0;
+        let (px, py) = (dx as f64 - center, dy as f64 - center);
+        let on_shape = match cell.shape() {
+            Some(CellShape::Circle) => (px.hypot(py) - (center - 1.0)).abs() < 0.5,
+            Some(CellShape::Diamond) => (px.abs() + py.abs() - (center - 1.0)).abs() < 0.75,
+            Some(CellShape::Triangle) => {
+                // Base at the bottom and apex at the top center of the square
+                let half_width = (py + center - 1.0) / 2.0;
+                let on_base = (py - (center - 1.0)).abs() < 0.5 && px.abs() <= center - 1.0;
+                let on_side = py >= 1.0 - center && (px.abs() - half_width).abs() < 0.75;
+
+                on_base || on_side
+            }
+            None => false,
+        };
+
+        if on_bar || on_shape {
+            *pixel = BLACK;
+        } else if let Some(color) = cell.decoration.background {
+            *pixel = Rgba([color.red, color.green, color.blue, color.alpha]);
+        }
+    }
+
+    image
+}
+
+/// Escape the characters that have a meaning in XML
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::{CellDecoration, Color};
+    use puzzled_crossword::{WritingDirection, crossword};
+
+    use super::*;
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [A B]
+            [C .]
+            - A: "The first two letters of the alphabet"
+            - D: "Keep it short, but cool"
+        )
+    }
+
+    #[test]
+    fn svg() {
+        let options = RenderOptions {
+            scale: 10,
+            solution: true,
+        };
+        let svg = render_svg(&puzzle(), &options);
+
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20""#)
+        );
+        assert!(svg.contains(r#"<rect x="10" y="10" width="10" height="10" fill="black"/>"#));
+        assert!(svg.contains(">1</text>"));
+        assert!(svg.contains(">C</text>"));
+    }
+
+    #[test]
+    fn decorations() {
+        let mut puzzle = puzzle();
+        let decoration = CellDecoration::default()
+            .with_background(Color::rgb(255, 204, 0))
+            .with_shape(CellShape::Diamond)
+            .with_bars(CellBorders::RIGHT);
+
+        if let Some(cell) = puzzle.squares_mut()[Position::new(0, 0)].as_mut() {
+            cell.decoration = decoration;
+        }
+
+        let options = RenderOptions {
+            scale: 10,
+            solution: false,
+        };
+        let svg = render_svg(&puzzle, &options);
+
+        assert!(svg.contains(
+            r##"<rect x="0" y="0" width="10" height="10" fill="#FFCC00" stroke="black"/>"##
+        ));
+        assert!(svg.contains(r#"<polygon points="5,1 9,5 5,9 1,5" fill="none" stroke="black"/>"#));
+        assert!(
+            svg.contains(
+                r#"<line x1="10" y1="0" x2="10" y2="10" stroke="black" stroke-width="2"/>"#
+            )
+        );
+
+        let image = render_png(&puzzle, &RenderOptions::default());
+        assert_eq!(image.get_pixel(8, 8), &Rgba([255, 204, 0, 255]));
+        assert_eq!(image.get_pixel(31, 8), &BLACK);
+    }
+
+    #[test]
+    fn rtl() {
+        let puzzle = crossword!(
+            [חה מ ש]
+            - A: "Greeting"
+        )
+        .with_writing(WritingDirection::RightToLeft);
+
+        let options = RenderOptions {
+            scale: 10,
+            solution: true,
+        };
+        let svg = render_svg(&puzzle, &options);
+
+        assert!(svg.contains(r#"viewBox="0 0 30 10" direction="rtl">"#));
+        assert!(
+            svg.contains(r#"<text x="28" y="3" font-size="3" font-family="sans-serif">1</text>"#)
+        );
+        assert!(svg.contains(">חה</text>"));
+    }
+
+    #[test]
+    fn png() {
+        let image = render_png(&puzzle(), &RenderOptions::default());
+
+        assert_eq!(image.dimensions(), (65, 65));
+        assert_eq!(image.get_pixel(16, 16), &WHITE);
+        assert_eq!(image.get_pixel(48, 48), &BLACK);
+        assert_eq!(image.get_pixel(32, 16), &BLACK);
+    }
+}
@@ -0,0 +1,64 @@
+use std::{fs, path::Path};
+
+use puzzled_crossword::{Crossword, CrosswordState};
+use puzzled_io::{Diagnostic, PuzReader, ReadError, Severity};
+
+use crate::{Error, Format, Result, read_crossword};
+
+/// Parse the puzzle and print every issue that was found in it
+///
+/// Fails if any issue is at least a [warning](Severity::Warning), such that it would abort a strict parse
+pub fn validate(input: &Path, format: Format) -> Result<()> {
+    let diagnostics = match format {
+        Format::Puz => puz_diagnostics(input)?,
+        Format::Jpz => jpz_diagnostics(input)?,
+        format => {
+            read_crossword(input, format)?;
+            Vec::new()
+        }
+    };
+
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+
+    let issues = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.is_at_least(Severity::Warning))
+        .count();
+
+    match issues {
+        0 => {
+            println!("{} is valid", input.display());
+            Ok(())
+        }
+        issues => Err(Error::Invalid(issues)),
+    }
+}
+
+/// Read a `*.puz` file leniently, collecting every issue rather than stopping at the first one
+fn puz_diagnostics(input: &Path) -> Result<Vec<Diagnostic>> {
+    let bytes = fs::read(input)?;
+
+    let (_, _, diagnostics): (Crossword, CrosswordState, _) = PuzReader::new(false)
+        .read_with_diagnostics(&mut &bytes[..])
+        .map_err(|err| Error::Read {
+            path: input.to_path_buf(),
+            err: ReadError::from(err).into(),
+        })?;
+
+    Ok(diagnostics)
+}
+
+/// Read a `*.jpz` file, collecting the features of the puzzle that had to be left out
+fn jpz_diagnostics(input: &Path) -> Result<Vec<Diagnostic>> {
+    let bytes = fs::read(input)?;
+    let xml = String::from_utf8_lossy(&bytes);
+
+    let (_, diagnostics) = Crossword::from_crossword_xml(&xml).map_err(|err| Error::Read {
+        path: input.to_path_buf(),
+        err: err.into(),
+    })?;
+
+    Ok(diagnostics)
+}
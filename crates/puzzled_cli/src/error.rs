@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use puzzled_crossword::CrosswordXmlError;
+use puzzled_io::{ReadError, WriteError};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not read {}: {err}", path.display())]
+    Read { path: PathBuf, err: ParseError },
+
+    #[error("Could not write {}: {err}", path.display())]
+    Write { path: PathBuf, err: WriteError },
+
+    #[error(transparent)]
+    Image(#[from] image::ImageError),
+
+    #[error("Cannot infer the format of {}, specify it explicitly", .0.display())]
+    UnknownFormat(PathBuf),
+
+    #[error("Found {0} issue(s) in the puzzle")]
+    Invalid(usize),
+}
+
+/// Reasons why the contents of a puzzle file could not be read
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Read(#[from] ReadError),
+
+    #[error(transparent)]
+    Xml(#[from] CrosswordXmlError),
+}
@@ -0,0 +1,114 @@
+use std::{fmt, path::Path};
+
+use clap::ValueEnum;
+
+use crate::{Error, Result};
+
+/// Format of a puzzle file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Across Lite `*.puz` files
+    Puz,
+
+    /// Plain text `*.txt` files
+    Txt,
+
+    /// [ipuz](http://ipuz.org/) `*.ipuz` files, which can only be written
+    Ipuz,
+
+    /// Crossword Compiler `*.jpz` files, which can only be read from their uncompressed XML
+    Jpz,
+}
+
+impl Format {
+    pub const ALL: [Format; 4] = [Self::Puz, Self::Txt, Self::Ipuz, Self::Jpz];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Puz => "puz",
+            Self::Txt => "txt",
+            Self::Ipuz => "ipuz",
+            Self::Jpz => "jpz",
+        }
+    }
+
+    /// Use the given format, or infer it from the extension of the path
+    pub fn resolve(format: Option<Format>, path: &Path) -> Result<Format> {
+        format
+            .or_else(|| Self::from_path(path))
+            .ok_or_else(|| Error::UnknownFormat(path.to_path_buf()))
+    }
+
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?;
+
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extension().eq_ignore_ascii_case(extension))
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "*.{}", self.extension())
+    }
+}
+
+/// Format of a rendered image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RenderFormat {
+    Svg,
+    Png,
+}
+
+impl RenderFormat {
+    /// Use the given format, or infer it from the extension of the path
+    pub fn resolve(format: Option<RenderFormat>, path: &Path) -> Result<RenderFormat> {
+        if let Some(format) = format {
+            return Ok(format);
+        }
+
+        let extension = path.extension().and_then(|ext| ext.to_str());
+
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("svg") => Ok(Self::Svg),
+            Some("png") => Ok(Self::Png),
+            _ => Err(Error::UnknownFormat(path.to_path_buf())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::puz("daily.puz", Some(Format::Puz))]
+    #[case::uppercase("DAILY.IPUZ", Some(Format::Ipuz))]
+    #[case::nested("puzzles/daily.txt", Some(Format::Txt))]
+    #[case::unknown("daily.json", None)]
+    #[case::missing("daily", None)]
+    fn from_path(#[case] path: &str, #[case] expected: Option<Format>) {
+        assert_eq!(Format::from_path(Path::new(path)), expected);
+    }
+
+    #[test]
+    fn resolve() {
+        let path = Path::new("daily.json");
+
+        assert_eq!(
+            Format::resolve(Some(Format::Puz), path).unwrap(),
+            Format::Puz
+        );
+        assert!(matches!(
+            Format::resolve(None, path),
+            Err(Error::UnknownFormat(_))
+        ));
+        assert_eq!(
+            RenderFormat::resolve(None, Path::new("grid.PNG")).unwrap(),
+            RenderFormat::Png
+        );
+    }
+}
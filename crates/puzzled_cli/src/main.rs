@@ -0,0 +1,26 @@
+mod args;
+mod commands;
+mod error;
+mod format;
+mod puzzle;
+
+pub use args::*;
+pub use error::*;
+pub use format::*;
+pub use puzzle::*;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match commands::run(args.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
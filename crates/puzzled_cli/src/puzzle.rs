@@ -0,0 +1,140 @@
+use std::{fs, path::Path};
+
+use puzzled_crossword::{Crossword, CrosswordState};
+use puzzled_io::{PuzReader, PuzWriter, ReadError, TxtPuzzle, WriteError};
+
+use crate::{Error, Format, ParseError, Result};
+
+/// Read a crossword together with the progress that is stored in the file
+pub fn read_crossword(path: &Path, format: Format) -> Result<(Crossword, CrosswordState)> {
+    let bytes = fs::read(path)?;
+
+    parse_crossword(&bytes, format).map_err(|err| Error::Read {
+        path: path.to_path_buf(),
+        err,
+    })
+}
+
+pub fn parse_crossword(
+    bytes: &[u8],
+    format: Format,
+) -> std::result::Result<(Crossword, CrosswordState), ParseError> {
+    match format {
+        Format::Puz => Ok(PuzReader::default()
+            .read(&mut &bytes[..])
+            .map_err(ReadError::from)?),
+        Format::Txt => {
+            let text = String::from_utf8_lossy(bytes);
+            let puzzle = Crossword::read_text(&text).map_err(ReadError::from)?;
+            let state = CrosswordState::from(&puzzle);
+
+            Ok((puzzle, state))
+        }
+        Format::Jpz => {
+            let xml = String::from_utf8_lossy(bytes);
+            let (puzzle, _) = Crossword::from_crossword_xml(&xml)?;
+            let state = CrosswordState::from(&puzzle);
+
+            Ok((puzzle, state))
+        }
+        format => Err(ReadError::UnsupportedFormat {
+            format: format.to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Write a crossword, including its progress if the format supports it
+pub fn write_crossword(
+    path: &Path,
+    format: Format,
+    puzzle: &Crossword,
+    state: &CrosswordState,
+) -> Result<()> {
+    let bytes = format_crossword(format, puzzle, state).map_err(|err| Error::Write {
+        path: path.to_path_buf(),
+        err,
+    })?;
+
+    fs::write(path, bytes)?;
+    Ok(())
+}
+
+pub fn format_crossword(
+    format: Format,
+    puzzle: &Crossword,
+    state: &CrosswordState,
+) -> std::result::Result<Vec<u8>, WriteError> {
+    match format {
+        Format::Puz => {
+            let mut bytes = Vec::new();
+            PuzWriter::new().write(&mut bytes, puzzle, state)?;
+
+            Ok(bytes)
+        }
+        Format::Txt => Ok(puzzle.write_text().into_bytes()),
+        Format::Ipuz => Ok(format!("{:#}", puzzle.to_ipuz()).into_bytes()),
+        format => Err(WriteError::UnsupportedFormat {
+            format: format.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn mini() -> (Crossword, CrosswordState) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../puzzled_crossword/puzzles/ok/mini.puz");
+
+        read_crossword(&path, Format::Puz).unwrap()
+    }
+
+    #[rstest]
+    #[case::puz(Format::Puz)]
+    #[case::txt(Format::Txt)]
+    fn round_trip(#[case] format: Format) {
+        let (puzzle, state) = mini();
+
+        let bytes = format_crossword(format, &puzzle, &state).unwrap();
+        let (converted, _) = parse_crossword(&bytes, format).unwrap();
+
+        assert_eq!(converted.squares(), puzzle.squares());
+        assert_eq!(converted.clues(), puzzle.clues());
+    }
+
+    #[test]
+    fn parse_jpz() {
+        let xml = r#"<rectangular-puzzle>
+            <crossword>
+                <grid width="2" height="1">
+                    <cell x="1" y="1" solution="A" number="1"/>
+                    <cell x="2" y="1" solution="B"/>
+                </grid>
+                <clues><title>Across</title><clue number="1">A and B</clue></clues>
+            </crossword>
+        </rectangular-puzzle>"#;
+
+        let (puzzle, _) = parse_crossword(xml.as_bytes(), Format::Jpz).unwrap();
+
+        assert_eq!(puzzle.cols(), 2);
+        assert_eq!(puzzle.clues().len(), 1);
+        assert!(matches!(
+            parse_crossword(b"<sudoku/>", Format::Jpz),
+            Err(ParseError::Xml(_))
+        ));
+    }
+
+    #[test]
+    fn unsupported() {
+        let (puzzle, state) = mini();
+
+        assert!(format_crossword(Format::Jpz, &puzzle, &state).is_err());
+        assert!(parse_crossword(b"{}", Format::Ipuz).is_err());
+    }
+}
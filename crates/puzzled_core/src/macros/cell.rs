@@ -49,12 +49,22 @@ macro_rules! cell_style {
         | $crate::cell_style!($($rest)*)
     };
 
+    (! $($rest:tt)*) => {
+        $crate::CellStyle::INCORRECT
+        | $crate::cell_style!($($rest)*)
+    };
+
+    (? $($rest:tt)*) => {
+        $crate::CellStyle::PENCIL
+        | $crate::cell_style!($($rest)*)
+    };
+
     ($invalid:tt $($rest:tt)*) => {
         compile_error!(
             concat!(
                 "Unknown style suffix: '",
                 stringify!($invalid),
-                "' (only ~, * and @ allowed)"
+                "' (only ~, *, @, ! and ? allowed)"
             )
         );
     };
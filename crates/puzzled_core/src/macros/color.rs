@@ -11,9 +11,9 @@ macro_rules! color {
         $crate::Color::rgb($r, $g, $b)
     };
 
-    // hex string literal
-    ($hex:literal) => {{
-        match $crate::Color::hex($hex) {
+    // hex, rgb(...) or named string literal
+    ($color:literal) => {{
+        match <$crate::Color as ::core::str::FromStr>::from_str($color) {
             Ok(color) => color,
             Err(err) => panic!("{}", err),
         }
@@ -27,7 +27,8 @@ macro_rules! color {
             "', use one of:\n\
              color!(r, g, b)\n\
              color!(r, g, b, a)\n\
-             color!(\"#RRGGBB\" | \"#RGB\" | \"#RRGGBBAA\" | \"#RGBA\")"
+             color!(\"#RRGGBB\" | \"#RGB\" | \"#RRGGBBAA\" | \"#RGBA\")\n\
+             color!(\"rgb(r, g, b)\" | \"rgba(r, g, b, a)\" | \"name\")"
         ));
     };
 }
@@ -24,13 +24,17 @@ macro_rules! metadata {
         $meta = $meta.with_notes($value.into())
     };
 
+    (@transform $meta:ident, theme_note, $value:literal) => {
+        $meta = $meta.with_theme_note($value.into())
+    };
+
     (@transform $meta:ident, title, $value:literal) => {
         $meta = $meta.with_title($value.into())
     };
 
     // Version parsing
     (@transform $meta:ident, version, $value:literal) => {
-        match $crate::Version::from_bytes($value.as_bytes()) {
+        match $value.parse::<$crate::Version>() {
             Ok(v) => $meta = $meta.with_version(v),
             Err(_) => panic!("Invalid version string"),
         }
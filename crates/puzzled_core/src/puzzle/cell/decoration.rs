@@ -0,0 +1,152 @@
+use bitflags::bitflags;
+
+use crate::Color;
+
+/// Decoration of a [cell](crate::Cell) that goes beyond its [style](crate::CellStyle)
+///
+/// Formats such as [ipuz](http://ipuz.org/) can give cells a background color, a shape in the background and thick borders (bars) between them.
+/// None of these change how the puzzle is solved, they only change how it is displayed.
+/// ```
+/// use puzzled_core::{CellBorders, CellDecoration, CellShape, Color};
+///
+/// let decoration = CellDecoration::default()
+///     .with_background(Color::rgb(255, 255, 0))
+///     .with_shape(CellShape::Diamond)
+///     .with_bars(CellBorders::RIGHT | CellBorders::BOTTOM);
+///
+/// assert!(!decoration.is_empty());
+/// assert!(CellDecoration::default().is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellDecoration {
+    /// Color to fill the background of the cell with
+    pub background: Option<Color>,
+
+    /// Shape that is drawn in the background of the cell
+    pub shape: Option<CellShape>,
+
+    /// Borders of the cell that are drawn as thick bars
+    pub bars: CellBorders,
+}
+
+impl CellDecoration {
+    /// Background of shaded cells, for formats that only record whether a cell is [shaded](Self::is_shaded)
+    pub const SHADED: Color = Color::rgb(192, 192, 192);
+
+    /// Decoration of a cell that is [shaded](Self::SHADED)
+    /// ```
+    /// use puzzled_core::CellDecoration;
+    ///
+    /// assert!(CellDecoration::shaded().is_shaded());
+    /// assert!(!CellDecoration::default().is_shaded());
+    /// ```
+    pub fn shaded() -> Self {
+        Self::default().with_background(Self::SHADED)
+    }
+
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn with_shape(mut self, shape: CellShape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    pub fn with_bars(mut self, bars: CellBorders) -> Self {
+        self.bars = bars;
+        self
+    }
+
+    /// Whether the cell has a background color of any kind
+    pub fn is_shaded(&self) -> bool {
+        self.background.is_some()
+    }
+
+    /// Whether the decoration does not change how the cell is displayed
+    pub fn is_empty(&self) -> bool {
+        self.background.is_none() && self.shape.is_none() && self.bars.is_empty()
+    }
+}
+
+/// Shape that is drawn in the background of a [cell](crate::Cell)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum CellShape {
+    Circle,
+    Diamond,
+    Triangle,
+}
+
+impl CellShape {
+    /// Name of the shape in the `shapebg` style of [ipuz](http://ipuz.org/) cells
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Circle => "circle",
+            Self::Diamond => "diamond",
+            Self::Triangle => "triangle",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "circle" => Some(Self::Circle),
+            "diamond" => Some(Self::Diamond),
+            "triangle" => Some(Self::Triangle),
+            _ => None,
+        }
+    }
+}
+
+bitflags! {
+    /// Sides of a [cell](crate::Cell)
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct CellBorders: u8 {
+        const TOP    = 1 << 0;
+        const RIGHT  = 1 << 1;
+        const BOTTOM = 1 << 2;
+        const LEFT   = 1 << 3;
+    }
+}
+
+impl CellBorders {
+    /// Sides in the order of the `barred` style of [ipuz](http://ipuz.org/) cells, together with their letter
+    pub const SIDES: [(CellBorders, char); 4] = [
+        (Self::TOP, 'T'),
+        (Self::RIGHT, 'R'),
+        (Self::BOTTOM, 'B'),
+        (Self::LEFT, 'L'),
+    ];
+
+    /// Letters of the sides, such as `"RB"` for the right and bottom side
+    /// ```
+    /// use puzzled_core::CellBorders;
+    ///
+    /// let borders = CellBorders::RIGHT | CellBorders::BOTTOM;
+    /// assert_eq!(borders.letters(), "RB");
+    /// assert_eq!(CellBorders::from_letters("BR"), borders);
+    /// ```
+    pub fn letters(&self) -> String {
+        Self::SIDES
+            .into_iter()
+            .filter(|(side, _)| self.contains(*side))
+            .map(|(_, letter)| letter)
+            .collect()
+    }
+
+    /// Sides from their letters, ignoring letters that are no side
+    pub fn from_letters(letters: &str) -> Self {
+        letters
+            .chars()
+            .filter_map(|ch| {
+                Self::SIDES
+                    .into_iter()
+                    .find(|(_, letter)| letter.eq_ignore_ascii_case(&ch))
+            })
+            .fold(Self::empty(), |borders, (side, _)| borders | side)
+    }
+}
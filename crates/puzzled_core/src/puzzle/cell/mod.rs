@@ -1,7 +1,9 @@
+mod decoration;
 mod style;
 
 use std::fmt::{self, Debug};
 
+pub use decoration::*;
 pub use style::CellStyle;
 
 use crate::Value;
@@ -11,6 +13,7 @@ pub const MISSING_ENTRY_CHAR: char = '-';
 pub struct Cell<T> {
     pub solution: Option<T>,
     pub style: CellStyle,
+    pub decoration: CellDecoration,
 }
 
 impl<T> Cell<T> {
@@ -20,13 +23,27 @@ impl<T> Cell<T> {
     }
 
     pub fn new_with_style(solution: Option<T>, style: CellStyle) -> Self {
-        Self { solution, style }
+        Self {
+            solution,
+            style,
+            decoration: CellDecoration::default(),
+        }
     }
 
     pub fn default_with_style(style: CellStyle) -> Self {
-        Self {
-            solution: None,
-            style,
+        Self::new_with_style(None, style)
+    }
+
+    pub fn with_decoration(mut self, decoration: CellDecoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
+    /// Shape in the background of the cell, where [circled](CellStyle::CIRCLED) cells have a [circle](CellShape::Circle) unless their [decoration](CellDecoration) defines another shape
+    pub fn shape(&self) -> Option<CellShape> {
+        match self.decoration.shape {
+            None if self.style.contains(CellStyle::CIRCLED) => Some(CellShape::Circle),
+            shape => shape,
         }
     }
 }
@@ -40,6 +57,10 @@ where
         cell.field("solution", &self.solution);
         cell.field("style", &self.style);
 
+        if !self.decoration.is_empty() {
+            cell.field("decoration", &self.decoration);
+        }
+
         cell.finish()
     }
 }
@@ -76,6 +97,7 @@ impl<T> Default for Cell<T> {
         Self {
             solution: None,
             style: CellStyle::empty(),
+            decoration: CellDecoration::default(),
         }
     }
 }
@@ -99,6 +121,7 @@ where
         Self {
             solution: self.solution.clone(),
             style: self.style,
+            decoration: self.decoration,
         }
     }
 }
@@ -107,12 +130,15 @@ where
 mod serde_impl {
     use serde::{Deserialize, Serialize, ser::SerializeStruct};
 
-    use crate::{Cell, CellStyle};
+    use crate::{Cell, CellDecoration, CellStyle};
 
     #[derive(Deserialize)]
     struct SerdeCell<T> {
         value: T,
         style: CellStyle,
+
+        #[serde(default)]
+        decoration: CellDecoration,
     }
 
     impl<T> Serialize for Cell<T>
@@ -123,10 +149,15 @@ mod serde_impl {
         where
             S: serde::Serializer,
         {
-            let mut cell = serializer.serialize_struct("Cell", 2)?;
+            let mut cell = serializer.serialize_struct("Cell", 3)?;
             cell.serialize_field("value", &self.solution)?;
             cell.serialize_field("style", &self.style)?;
 
+            match self.decoration.is_empty() {
+                true => cell.skip_field("decoration")?,
+                false => cell.serialize_field("decoration", &self.decoration)?,
+            }
+
             cell.end()
         }
     }
@@ -143,9 +174,50 @@ mod serde_impl {
             let cell = Cell {
                 solution: cell.value,
                 style: cell.style,
+                decoration: cell.decoration,
             };
 
             Ok(cell)
         }
     }
 }
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use std::fmt::Debug;
+
+    use proptest::{
+        arbitrary::{Arbitrary, any_with},
+        bool,
+        strategy::{BoxedStrategy, Strategy},
+    };
+
+    use crate::{Cell, CellStyle};
+
+    /// Probability that an [arbitrary](Arbitrary) cell is circled
+    const CIRCLED_PROBABILITY: f64 = 0.1;
+
+    /// Arbitrary cells always have a solution and are occasionally [circled](CellStyle::CIRCLED),
+    /// which is the only style that a puzzle defines before it is played
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl<T> Arbitrary for Cell<T>
+    where
+        T: Arbitrary + Debug + 'static,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+            (any_with::<T>(params), bool::weighted(CIRCLED_PROBABILITY))
+                .prop_map(|(solution, circled)| {
+                    let style = match circled {
+                        true => CellStyle::CIRCLED,
+                        false => CellStyle::empty(),
+                    };
+
+                    Cell::new_with_style(Some(solution), style)
+                })
+                .boxed()
+        }
+    }
+}
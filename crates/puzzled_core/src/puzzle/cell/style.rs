@@ -6,13 +6,17 @@ bitflags! {
     /// Style that changes the way a [cell](Cell) is displayed
     ///
     /// The style is represented as *bit flags* such that multiple styles can simultaneously be set.
-    /// Currently, the 4 styles that are defined are
+    /// Currently, the styles that are defined are
+    /// - [`PENCIL`](CellStyle::PENCIL) (`0x02`) for cells that contain a tentative guess
+    /// - [`CORRECT`](CellStyle::CORRECT) (`0x04`) for cells that were checked to contain a [correct](Cell::is_correct) guess
+    /// - [`INITIALLY_REVEALED`](CellStyle::INITIALLY_REVEALED) (`0x08`) for cells that show their solution from the start
     /// - [`PREVIOUSLY_INCORRECT`](CellStyle::PREVIOUSLY_INCORRECT) (`0x10`) for cells that previously contained an [incorrect](Cell::is_correct) guess
     /// - [`INCORRECT`](CellStyle::INCORRECT) (`0x20`) for cells that currently contain an [incorrect](Cell::is_correct) guess
     /// - [`REVEALED`](CellStyle::REVEALED) (`0x40`) for cells that are manually [revealed](Cell::reveal) by the user to show their solution
     /// - [`CIRCLED`](CellStyle::CIRCLED) (`0x80`) for cells that are circled
     ///
-    /// The definitions derive from the **GEXT data section** of the [*.puz spefication](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki).
+    /// The last 4 definitions derive from the **GEXT data section** of the [*.puz spefication](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki).
+    /// Only these [GEXT](Self::GEXT) bits are read from and written to files as styles, as the other styles are tracked by the crate itself.
     ///
     /// ```rust
     /// use puzzled::crossword::{Cell, CellStyle, Solution, Reveal};
@@ -29,6 +33,9 @@ bitflags! {
     #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
     #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
     pub struct CellStyle: u8 {
+        /// [Cell] contains a tentative guess that was [pencilled in](crate::Entry::enter_pencil)
+        const PENCIL               = 1 << 1; // ?
+
         const CORRECT = 1 << 2;
 
         /// [Cell] is initially revealed
@@ -54,6 +61,20 @@ bitflags! {
 }
 
 impl CellStyle {
+    /// Styles that are stored in the **GEXT data section** of `*.puz` files
+    ///
+    /// Files may set other bits as well, but these should not be mistaken for the styles that share their bit
+    /// ```
+    /// use puzzled::crossword::CellStyle;
+    ///
+    /// let style = CellStyle::from_bits_retain(0x88) & CellStyle::GEXT;
+    /// assert_eq!(style, CellStyle::CIRCLED);
+    /// ```
+    pub const GEXT: Self = Self::PREVIOUSLY_INCORRECT
+        .union(Self::INCORRECT)
+        .union(Self::REVEALED)
+        .union(Self::CIRCLED);
+
     pub fn initial(&self) -> Self {
         let mut style = *self;
 
@@ -61,6 +82,7 @@ impl CellStyle {
         style -= CellStyle::PREVIOUSLY_INCORRECT;
         style -= CellStyle::INCORRECT;
         style -= CellStyle::CORRECT;
+        style -= CellStyle::PENCIL;
 
         style
     }
@@ -74,6 +96,7 @@ impl fmt::Display for CellStyle {
             (CellStyle::REVEALED, '*'),
             (CellStyle::INCORRECT, '!'),
             (CellStyle::PREVIOUSLY_INCORRECT, '~'),
+            (CellStyle::PENCIL, '?'),
         ];
 
         for (style, ch) in styles {
@@ -98,6 +121,7 @@ impl FromStr for CellStyle {
                 '*' => style |= CellStyle::REVEALED,
                 '!' => style |= CellStyle::INCORRECT,
                 '~' => style |= CellStyle::PREVIOUSLY_INCORRECT,
+                '?' => style |= CellStyle::PENCIL,
                 ch if ch.is_whitespace() => {}
                 _ => return Err(()),
             }
@@ -0,0 +1,73 @@
+/// Hasher for fingerprints of puzzles, which are kept across runs and therefore need to be stable
+///
+/// The [hasher](std::hash::DefaultHasher) of the standard library may change between releases and [`Hash`] implementations may differ between platforms.
+/// This hasher instead uses the [64-bit FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hash over explicitly written bytes.
+/// ```
+/// use puzzled_core::StableHasher;
+///
+/// let mut hasher = StableHasher::new();
+/// hasher.write_str("AB");
+/// hasher.write_str("C");
+///
+/// // Strings are terminated, such that moving characters between them changes the hash
+/// let mut other = StableHasher::new();
+/// other.write_str("A");
+/// other.write_str("BC");
+///
+/// assert_ne!(hasher.finish(), other.finish());
+/// assert_eq!(StableHasher::new().finish(), 0xcbf2_9ce4_8422_2325);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Byte that terminates [strings](Self::write_str), which never occurs in UTF-8
+    const TERMINATOR: u8 = 0xFF;
+
+    /// Byte that marks a [block](Self::write_block), which never occurs in UTF-8 either
+    const BLOCK: u8 = 0xFE;
+
+    pub fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub fn write_u8(&mut self, n: u8) {
+        self.write(&[n]);
+    }
+
+    /// Write the number as little-endian bytes, regardless of the platform
+    pub fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    /// Write the bytes of the string followed by a terminator
+    pub fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+        self.write_u8(Self::TERMINATOR);
+    }
+
+    /// Write a marker for a block, such that it differs from any [string](Self::write_str) that is written in its place
+    pub fn write_block(&mut self) {
+        self.write_u8(Self::BLOCK);
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
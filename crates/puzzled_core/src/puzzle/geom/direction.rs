@@ -73,16 +73,7 @@ impl ops::Add<Direction> for Position {
     type Output = Option<Self>;
 
     fn add(self, direction: Direction) -> Self::Output {
-        let row = self.row;
-        let col = self.col;
-
-        let pos = match direction {
-            Direction::Up => Position::new(row.checked_sub(1)?, col),
-            Direction::Right => Position::new(row, col + 1),
-            Direction::Down => Position::new(row + 1, col),
-            Direction::Left => Position::new(row, col.checked_sub(1)?),
-        };
-        Some(pos)
+        self.step(direction, 1)
     }
 }
 
@@ -0,0 +1,273 @@
+use std::fmt;
+
+use crate::{Grid, GridError, Line, Position, Size};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Compact grid of booleans packed into [`u64`] words
+///
+/// Every row starts at a new word, such that the mask of a row can be borrowed directly with [`row_words`](Self::row_words).
+/// Masks of other [lines](Line) are packed on request with [`line_mask`](Self::line_mask), where bit `i` of the mask corresponds to the `i`-th cell of the line.
+/// ```
+/// use puzzled_core::{BitGrid, Line, Position};
+///
+/// let mut bits = BitGrid::new(3, 4).unwrap();
+/// bits.set(Position::new(0, 1), true);
+/// bits.set(Position::new(2, 1), true);
+///
+/// assert_eq!(bits.line_mask(Line::Row(0)), Some(vec![0b0010]));
+/// assert_eq!(bits.line_mask(Line::Col(1)), Some(vec![0b101]));
+/// assert_eq!(bits.count_ones(), 2);
+/// ```
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    rows: usize,
+    cols: usize,
+    row_words: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Create a grid of the given size with all bits unset
+    pub fn new(rows: usize, cols: usize) -> Result<Self, GridError> {
+        let row_words = cols.div_ceil(WORD_BITS);
+        let len = rows
+            .checked_mul(row_words)
+            .ok_or(GridError::SizeOverflow { rows, cols })?;
+
+        Ok(Self {
+            rows,
+            cols,
+            row_words,
+            words: vec![0; len],
+        })
+    }
+
+    /// Create a grid of the given size where the bit at every position is determined by `f`
+    /// ```
+    /// use puzzled_core::{BitGrid, Position};
+    ///
+    /// let diagonal = BitGrid::from_fn(3, 3, |pos| pos.row == pos.col).unwrap();
+    /// assert_eq!(diagonal.count_ones(), 3);
+    /// assert_eq!(diagonal.get(Position::new(1, 1)), Some(true));
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Result<Self, GridError>
+    where
+        F: FnMut(Position) -> bool,
+    {
+        let mut bits = Self::new(rows, cols)?;
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let pos = Position::new(row, col);
+                bits.set(pos, f(pos));
+            }
+        }
+
+        Ok(bits)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn size(&self) -> Size {
+        Size {
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    pub fn is_in_bounds(&self, pos: Position) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    /// Whether the bit at the given position is set
+    ///
+    /// Returns [`None`] if the position is out of bounds
+    pub fn get(&self, pos: Position) -> Option<bool> {
+        let (word, bit) = self.locate(pos)?;
+
+        Some(self.words[word] >> bit & 1 == 1)
+    }
+
+    /// Set the bit at the given position and return its previous value
+    ///
+    /// Returns [`None`] if the position is out of bounds
+    pub fn set(&mut self, pos: Position, value: bool) -> Option<bool> {
+        let (word, bit) = self.locate(pos)?;
+        let prev = self.words[word] >> bit & 1 == 1;
+
+        match value {
+            true => self.words[word] |= 1 << bit,
+            false => self.words[word] &= !(1 << bit),
+        }
+
+        Some(prev)
+    }
+
+    /// Unset all bits in the grid
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Borrow the packed words of a row, where the unused bits of the last word are always unset
+    pub fn row_words(&self, row: usize) -> Option<&[u64]> {
+        (row < self.rows).then(|| &self.words[row * self.row_words..(row + 1) * self.row_words])
+    }
+
+    /// Pack the bits of a [line](Line) into words, starting from the least significant bit of the first word
+    ///
+    /// Returns [`None`] if the line is out of bounds
+    pub fn line_mask(&self, line: Line) -> Option<Vec<u64>> {
+        match line {
+            Line::Row(row) => self.row_words(row).map(<[u64]>::to_vec),
+            Line::Col(col) if col < self.cols => {
+                let (word, bit) = (col / WORD_BITS, col % WORD_BITS);
+                let mut mask = vec![0; self.rows.div_ceil(WORD_BITS)];
+
+                for row in 0..self.rows {
+                    let set = self.words[row * self.row_words + word] >> bit & 1;
+                    mask[row / WORD_BITS] |= set << (row % WORD_BITS);
+                }
+
+                Some(mask)
+            }
+            Line::Col(_) => None,
+        }
+    }
+
+    /// Number of set bits in a [line](Line), which is 0 if the line is out of bounds
+    pub fn count_line(&self, line: Line) -> usize {
+        match line {
+            Line::Row(row) => self.row_words(row).map_or(0, |words| {
+                words.iter().map(|w| w.count_ones() as usize).sum()
+            }),
+            Line::Col(col) => (0..self.rows)
+                .filter(|&row| self.get(Position::new(row, col)) == Some(true))
+                .count(),
+        }
+    }
+
+    /// Whether every bit in an in-bounds [line](Line) is set
+    pub fn is_line_full(&self, line: Line) -> bool {
+        let len = match line {
+            Line::Row(row) if row < self.rows => self.cols,
+            Line::Col(col) if col < self.cols => self.rows,
+            _ => return false,
+        };
+
+        self.count_line(line) == len
+    }
+
+    /// Number of set bits in the grid
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Creates an iterator over the positions of the set bits in [row-major](crate::Order::Rows) order
+    pub fn iter_ones(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.rows).flat_map(move |row| {
+            (0..self.cols)
+                .map(move |col| Position::new(row, col))
+                .filter(|&pos| self.get(pos) == Some(true))
+        })
+    }
+
+    fn locate(&self, pos: Position) -> Option<(usize, usize)> {
+        self.is_in_bounds(pos).then(|| {
+            let word = pos.row * self.row_words + pos.col / WORD_BITS;
+
+            (word, pos.col % WORD_BITS)
+        })
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.rows(), grid.cols()).expect("Grid has a valid size");
+
+        for (pos, &value) in grid.iter_indexed() {
+            bits.set(pos, value);
+        }
+
+        bits
+    }
+}
+
+impl fmt::Debug for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "BitGrid {}x{}", self.rows, self.cols)?;
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let bit = self.get(Position::new(row, col)) == Some(true);
+                write!(f, "{}", if bit { '1' } else { '0' })?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::single_word(3, 5)]
+    #[case::word_boundary(2, 64)]
+    #[case::multiple_words(70, 130)]
+    fn line_masks(#[case] rows: usize, #[case] cols: usize) {
+        let mut bits = BitGrid::new(rows, cols).unwrap();
+        let diagonal: Vec<_> = (0..rows.min(cols)).map(|i| Position::new(i, i)).collect();
+
+        for &pos in &diagonal {
+            assert_eq!(bits.set(pos, true), Some(false));
+        }
+        bits.set(Position::new(rows - 1, cols - 1), true);
+
+        for &pos in &diagonal {
+            let row = bits.line_mask(Line::Row(pos.row)).unwrap();
+            let col = bits.line_mask(Line::Col(pos.col)).unwrap();
+
+            assert_eq!(row[pos.col / 64] >> (pos.col % 64) & 1, 1);
+            assert_eq!(col[pos.row / 64] >> (pos.row % 64) & 1, 1);
+        }
+
+        assert_eq!(bits.iter_ones().count(), bits.count_ones());
+        assert!(bits.iter_ones().all(|pos| bits.get(pos) == Some(true)));
+        assert_eq!(
+            bits.count_line(Line::Col(cols - 1)),
+            bits.line_mask(Line::Col(cols - 1))
+                .unwrap()
+                .iter()
+                .map(|w| w.count_ones() as usize)
+                .sum()
+        );
+        assert_eq!(bits.line_mask(Line::Row(rows)), None);
+        assert_eq!(bits.line_mask(Line::Col(cols)), None);
+    }
+
+    #[test]
+    fn full_line() {
+        let mut bits = BitGrid::new(2, 3).unwrap();
+        (0..3).for_each(|col| _ = bits.set(Position::new(1, col), true));
+
+        assert!(bits.is_line_full(Line::Row(1)));
+        assert!(!bits.is_line_full(Line::Row(0)));
+        assert!(!bits.is_line_full(Line::Col(0)));
+        assert!(!bits.is_line_full(Line::Row(2)));
+    }
+}
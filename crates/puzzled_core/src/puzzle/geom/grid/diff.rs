@@ -0,0 +1,74 @@
+use crate::{Grid, Position};
+
+impl<T> Grid<T> {
+    /// Find the cells that differ from the other grid, together with their value in both grids
+    ///
+    /// Only positions that exist in both grids are compared, so compare the [sizes](Self::size) of the grids first if they may differ.
+    /// The differences are ordered by their position in [row-major](crate::Order::Rows) order.
+    /// ```
+    /// use puzzled_core::{grid, Position};
+    ///
+    /// let grid = grid![[1, 2], [3, 4]];
+    /// let other = grid![[1, 2], [5, 4]];
+    ///
+    /// assert_eq!(grid.diff(&other), vec![(Position::new(1, 0), &3, &5)]);
+    /// assert!(grid.diff(&grid).is_empty());
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Self) -> Vec<(Position, &'a T, &'a T)>
+    where
+        T: PartialEq,
+    {
+        self.diff_by(other, |value, other_value| value == other_value)
+    }
+
+    /// Same as [`diff`](Self::diff), but decides whether cells are equal with the given function
+    ///
+    /// This is useful when the [`PartialEq`] of the cells ignores details that matter, such as the style of a [cell](crate::Cell).
+    /// ```
+    /// use puzzled_core::{grid, Position};
+    ///
+    /// let grid = grid![["a", "B"]];
+    /// let other = grid![["A", "b"]];
+    ///
+    /// assert_eq!(grid.diff(&other).len(), 2);
+    /// assert!(grid.diff_by(&other, |lhs, rhs| lhs.eq_ignore_ascii_case(rhs)).is_empty());
+    /// ```
+    pub fn diff_by<'a, F>(&'a self, other: &'a Self, mut eq: F) -> Vec<(Position, &'a T, &'a T)>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.iter_indexed()
+            .filter_map(|(pos, value)| {
+                let other_value = other.get(pos)?;
+                (!eq(value, other_value)).then_some((pos, value, other_value))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{BitGrid, Grid, Position, grid};
+
+    #[rstest]
+    #[case::smaller(grid![[1, 0]], vec![Position::new(0, 1)])]
+    #[case::larger(grid![[1, 2, 3], [0, 5, 6]], vec![Position::new(1, 0)])]
+    fn sizes(#[case] other: Grid<u8>, #[case] expected: Vec<Position>) {
+        let grid = grid![[1, 2], [4, 5]];
+        let positions: Vec<_> = grid.diff(&other).into_iter().map(|(pos, ..)| pos).collect();
+
+        assert_eq!(positions, expected);
+    }
+
+    #[test]
+    fn omitted() {
+        let mask = BitGrid::from_fn(1, 3, |pos| pos.col != 1).unwrap();
+        let grid = grid![[1, 2, 3]].with_mask(mask).unwrap();
+        let other = grid![[1, 0, 0]];
+
+        assert_eq!(grid.diff(&other), vec![(Position::new(0, 2), &3, &0)]);
+        assert_eq!(other.diff(&grid), vec![(Position::new(0, 2), &0, &3)]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Size;
+use crate::{Position, Size};
 
 #[derive(Debug, thiserror::Error, Clone)]
 pub enum Error {
@@ -25,6 +25,22 @@ pub enum Error {
     #[error("Size overflow from trying to construct grid with {rows} rows and {cols} cols")]
     SizeOverflow { rows: usize, cols: usize },
 
+    #[error("Cannot insert at index {index} into a grid with {len} lines")]
+    IndexOutOfBounds { index: usize, len: usize },
+
+    #[error("Position ({}, {}) is out of bounds of a grid with {rows} rows and {cols} columns", pos.row, pos.col)]
+    PositionOutOfBounds {
+        pos: Position,
+        rows: usize,
+        cols: usize,
+    },
+
+    #[error("Cell at ({}, {}) is omitted from the grid", pos.row, pos.col)]
+    OmittedCell { pos: Position },
+
+    #[error("The line has length {found}, expected {expected}")]
+    InvalidLength { found: usize, expected: usize },
+
     #[error("The {side} side has length {found}, expected {expected}")]
     InvalidSide {
         side: String,
@@ -1,10 +1,23 @@
 use std::ops;
 
-use crate::{Grid, Offset, Position};
+use crate::{Grid, GridError, Offset, Position};
 
 impl<T> Grid<T> {
-    pub fn get(&self, pos: Position) -> Option<&T> {
-        let idx = self.index(pos)?;
+    /// Entry at the given position, which can also be given as a `(row, col)` tuple
+    /// ```
+    /// use puzzled_core::{grid, Position};
+    ///
+    /// let grid = grid![[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(grid.get(Position::new(1, 0)), Some(&3));
+    /// assert_eq!(grid.get((1, 1)), Some(&4));
+    /// assert_eq!(grid.get((2, 0)), None);
+    /// ```
+    pub fn get<P>(&self, pos: P) -> Option<&T>
+    where
+        P: Into<Position>,
+    {
+        let idx = self.index(pos.into())?;
         unsafe { Some(self.data.get_unchecked(idx)) }
     }
 
@@ -16,8 +29,78 @@ impl<T> Grid<T> {
         unsafe { Some(self.data.get_unchecked_mut(idx)) }
     }
 
+    /// Entry at the given position, or an [error](GridError) that explains why there is none
+    ///
+    /// Unlike indexing, this does not panic on positions that are out of bounds, which makes it suitable for positions that are read from untrusted data
+    /// ```
+    /// use puzzled_core::{grid, GridError, Position};
+    ///
+    /// let grid = grid![[1, 2], [3, 4]];
+    /// assert_eq!(grid.get_checked((1, 0)).ok(), Some(&3));
+    ///
+    /// let err = grid.get_checked((0, 2)).unwrap_err();
+    /// assert!(matches!(err, GridError::PositionOutOfBounds { rows: 2, cols: 2, .. }));
+    /// assert_eq!(err.to_string(), "Position (0, 2) is out of bounds of a grid with 2 rows and 2 columns");
+    /// ```
+    pub fn get_checked<P>(&self, pos: P) -> Result<&T, GridError>
+    where
+        P: Into<Position>,
+    {
+        let pos = pos.into();
+
+        match self.index(pos) {
+            Some(idx) => Ok(&self.data[idx]),
+            None => Err(self.missing_error(pos)),
+        }
+    }
+
+    /// Mutable entry at the given position, or an [error](GridError) that explains why there is none
+    pub fn get_checked_mut<P>(&mut self, pos: P) -> Result<&mut T, GridError>
+    where
+        P: Into<Position>,
+    {
+        let pos = pos.into();
+
+        match self.index(pos) {
+            Some(idx) => Ok(&mut self.data[idx]),
+            None => Err(self.missing_error(pos)),
+        }
+    }
+
+    /// Replace the entry at the given position, or return an [error](GridError) if there is none
+    /// ```
+    /// use puzzled_core::{grid, GridError, Position};
+    ///
+    /// let mut grid = grid![[1, 2], [3, 4]];
+    ///
+    /// assert!(grid.set((0, 1), 5).is_ok());
+    /// assert_eq!(grid[(0, 1)], 5);
+    /// assert!(grid.set(Position::new(2, 0), 6).is_err());
+    /// ```
+    pub fn set<P>(&mut self, pos: P, value: T) -> Result<(), GridError>
+    where
+        P: Into<Position>,
+    {
+        *self.get_checked_mut(pos)? = value;
+        Ok(())
+    }
+
+    fn missing_error(&self, pos: Position) -> GridError {
+        match self.is_in_bounds(pos) {
+            true => GridError::OmittedCell { pos },
+            false => GridError::PositionOutOfBounds {
+                pos,
+                rows: self.rows,
+                cols: self.cols,
+            },
+        }
+    }
+
+    /// Index of the given position in the underlying [data](Self::data)
+    ///
+    /// Returns [`None`] if the position is out of bounds or its cell is [omitted](Self::has_cell)
     pub fn index(&self, pos: Position) -> Option<usize> {
-        if pos.row >= self.rows || pos.col >= self.cols {
+        if !self.has_cell(pos) {
             return None;
         }
 
@@ -99,8 +182,22 @@ impl<T> Grid<T> {
 /// assert_eq!(grid[Position::new(1, 0)], 3);
 /// assert_eq!(grid[Position::new(1, 1)], 4);
 /// ```
+///
+/// For simple access, the position can be given as a `(row, col)` tuple as well
+/// ```
+/// use puzzled_core::grid;
+///
+/// let mut grid = grid! (
+///    [1, 2],
+///    [3, 4],
+/// );
+/// grid[(1, 0)] = 5;
+///
+/// assert_eq!(grid[(0, 1)], 2);
+/// assert_eq!(grid[(1, 0)], 5);
+/// ```
 /// # Panics
-/// Panics if the given `pos` is out of bounds, i.e. `pos.row >= puzzle.rows() || pos.col >= puzzle.cols()`.
+/// Panics if the given `pos` is out of bounds, i.e. `pos.row >= puzzle.rows() || pos.col >= puzzle.cols()`, or its cell is [omitted](Grid::has_cell).
 /// ```should_panic
 /// use puzzled_core::{grid, Position};
 ///
@@ -119,14 +216,7 @@ where
     type Output = T;
 
     fn index(&self, pos: P) -> &Self::Output {
-        let pos: Position = pos.into();
-
-        self.get(pos).unwrap_or_else(|| {
-            let (row, col) = (pos.row, pos.col);
-            let (rows, cols) = (self.rows, self.cols);
-
-            panic!("Position is out of bounds: ({row}, {col}) >= ({rows}, {cols})")
-        })
+        self.get_checked(pos).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
@@ -149,7 +239,7 @@ where
     /// assert_eq!(grid[Position::new(1, 1)], 4);
     /// ```
     /// # Panics
-    /// Panics if the given `pos` is out of bounds, i.e. `pos.row >= puzzle.rows() || pos.col >= puzzle.cols()`.
+    /// Panics if the given `pos` is out of bounds, i.e. `pos.row >= puzzle.rows() || pos.col >= puzzle.cols()`, or its cell is [omitted](Grid::has_cell).
     /// ```should_panic
     /// use puzzled_core::{grid, Position};
     ///
@@ -162,14 +252,7 @@ where
     /// let num = &grid[pos];
     /// ```
     fn index_mut(&mut self, pos: P) -> &mut Self::Output {
-        let pos: Position = pos.into();
-        let rows = self.rows;
-        let cols = self.cols;
-
-        self.get_mut(pos).unwrap_or_else(|| {
-            let (row, col) = (pos.row, pos.col);
-
-            panic!("Position is out of bounds: ({row}, {col}) >= ({rows}, {cols})")
-        })
+        self.get_checked_mut(pos)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 }
@@ -0,0 +1,138 @@
+use std::{iter::Enumerate, slice};
+
+use crate::{BitGrid, Grid, Position};
+
+/// Iterator over the [positions](Position) and entries of a [grid](Grid), see [`Grid::enumerate_positions`]
+#[derive(Debug, Clone)]
+pub struct GridEnumerate<'a, T> {
+    inner: Enumerate<slice::Iter<'a, T>>,
+    cols: usize,
+    mask: Option<&'a BitGrid>,
+}
+
+/// Mutable iterator over the [positions](Position) and entries of a [grid](Grid), see [`Grid::enumerate_positions_mut`]
+#[derive(Debug)]
+pub struct GridEnumerateMut<'a, T> {
+    inner: Enumerate<slice::IterMut<'a, T>>,
+    cols: usize,
+    mask: Option<&'a BitGrid>,
+}
+
+/// Position of the entry at `idx` if it exists in the grid
+fn existing_position(idx: usize, cols: usize, mask: Option<&BitGrid>) -> Option<Position> {
+    let pos = Position::new(idx / cols, idx % cols);
+    mask.is_none_or(|mask| mask.get(pos) == Some(true))
+        .then_some(pos)
+}
+
+macro_rules! impl_enumerate {
+    ($iter:ident, $item:ty) => {
+        impl<'a, T> Iterator for $iter<'a, T> {
+            type Item = (Position, $item);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let (cols, mask) = (self.cols, self.mask);
+
+                self.inner
+                    .by_ref()
+                    .find_map(|(idx, item)| Some((existing_position(idx, cols, mask)?, item)))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let (lower, upper) = self.inner.size_hint();
+
+                match self.mask {
+                    Some(_) => (0, upper),
+                    None => (lower, upper),
+                }
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $iter<'a, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let (cols, mask) = (self.cols, self.mask);
+
+                self.inner
+                    .by_ref()
+                    .rev()
+                    .find_map(|(idx, item)| Some((existing_position(idx, cols, mask)?, item)))
+            }
+        }
+    };
+}
+
+impl_enumerate!(GridEnumerate, &'a T);
+impl_enumerate!(GridEnumerateMut, &'a mut T);
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = (Position, &'a T);
+    type IntoIter = GridEnumerate<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.enumerate_positions()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = (Position, &'a mut T);
+    type IntoIter = GridEnumerateMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.enumerate_positions_mut()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates an iterator over the [positions](Position) of the grid together with their entries
+    ///
+    /// The entries are traversed in [row-major](crate::Order::Rows) order, which is also how a reference to the grid is iterated.
+    /// ```
+    /// use puzzled_core::{grid, Position};
+    ///
+    /// let grid = grid![
+    ///    ['A', 'B'],
+    ///    ['C', 'D']
+    /// ];
+    /// let mut iter = grid.enumerate_positions();
+    /// assert_eq!(iter.next(), Some((Position::new(0, 0), &'A')));
+    /// assert_eq!(iter.next(), Some((Position::new(0, 1), &'B')));
+    /// assert_eq!(iter.next_back(), Some((Position::new(1, 1), &'D')));
+    /// assert_eq!(iter.next(), Some((Position::new(1, 0), &'C')));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// for (pos, letter) in &grid {
+    ///     assert_eq!(grid[pos], *letter);
+    /// }
+    /// ```
+    pub fn enumerate_positions(&self) -> GridEnumerate<'_, T> {
+        GridEnumerate {
+            inner: self.data.iter().enumerate(),
+            cols: self.cols,
+            mask: self.mask.as_ref(),
+        }
+    }
+
+    /// Creates a mutable iterator over the [positions](Position) of the grid together with their entries
+    ///
+    /// The entries are traversed in [row-major](crate::Order::Rows) order, which is also how a mutable reference to the grid is iterated.
+    /// ```
+    /// use puzzled_core::{grid, Position};
+    ///
+    /// let mut grid = grid![
+    ///    [0, 0],
+    ///    [0, 0]
+    /// ];
+    /// for (pos, val) in &mut grid {
+    ///     *val = 10 * pos.row + pos.col;
+    /// }
+    ///
+    /// assert_eq!(grid, grid![[0, 1], [10, 11]]);
+    /// ```
+    pub fn enumerate_positions_mut(&mut self) -> GridEnumerateMut<'_, T> {
+        GridEnumerateMut {
+            inner: self.data.iter_mut().enumerate(),
+            cols: self.cols,
+            mask: self.mask.as_ref(),
+        }
+    }
+}
@@ -28,33 +28,33 @@ impl<'a, T> Iterator for GridIndexedIter<'a, T> {
     type Item = (Position, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.inner.len() == 0 {
-            return None;
+        match &mut self.inner {
+            GridIter::Linear(iter) => iter.next_indexed(),
+            GridIter::Positions(iter) => {
+                let pos = *iter.positions.get(iter.front)?;
+                let item = iter.next()?;
+
+                Some((pos, item))
+            }
         }
-
-        let pos = match &self.inner {
-            GridIter::Linear(iter) => iter.front,
-            GridIter::Positions(iter) => iter.positions[iter.front],
-        };
-        let item = self.inner.next()?;
-
-        Some((pos, item))
     }
 }
 
 impl<'a, T> DoubleEndedIterator for GridIndexedIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.inner.len() == 0 {
-            return None;
+        match &mut self.inner {
+            GridIter::Linear(iter) => iter.next_back_indexed(),
+            GridIter::Positions(iter) => {
+                if iter.len() == 0 {
+                    return None;
+                }
+
+                let pos = iter.positions[iter.back - 1];
+                let item = iter.next_back()?;
+
+                Some((pos, item))
+            }
         }
-
-        let pos = match &self.inner {
-            GridIter::Linear(iter) => iter.back,
-            GridIter::Positions(iter) => iter.positions[iter.back.checked_sub(1)?],
-        };
-        let item = self.inner.next_back()?;
-
-        Some((pos, item))
     }
 }
 
@@ -78,14 +78,7 @@ impl<'a, T> Iterator for GridIndexedIterMut<'a, T> {
     type Item = (Position, &'a mut T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.inner.remaining == 0 {
-            return None;
-        }
-
-        let pos = self.inner.front;
-        let item = self.inner.next()?;
-
-        Some((pos, item))
+        self.inner.next_indexed()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -95,14 +88,7 @@ impl<'a, T> Iterator for GridIndexedIterMut<'a, T> {
 
 impl<'a, T> DoubleEndedIterator for GridIndexedIterMut<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.inner.remaining == 0 {
-            return None;
-        }
-
-        let pos = self.inner.front;
-        let item = self.inner.next_back()?;
-
-        Some((pos, item))
+        self.inner.next_back_indexed()
     }
 }
 
@@ -131,10 +117,7 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn iter_indexed(&self) -> impl Iterator<Item = (Position, &T)> {
-        self.data
-            .iter()
-            .enumerate()
-            .map(|(idx, item)| (self.position(idx).expect("Only taking valid indices"), item))
+        self.enumerate_positions()
     }
 
     /// Creates a mutable indexed iterator over the grid
@@ -155,13 +138,7 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn iter_indexed_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
-        self.data.iter_mut().enumerate().map(|(idx, item)| {
-            let row = idx / self.cols;
-            let col = idx % self.cols;
-            let pos = Position::new(row, col);
-
-            (pos, item)
-        })
+        self.enumerate_positions_mut()
     }
 
     /// Creates an indexed iterator over a specified row of the grid
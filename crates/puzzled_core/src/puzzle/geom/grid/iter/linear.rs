@@ -38,7 +38,7 @@ impl<'a, T> GridLinearIter<'a, T> {
             offset,
             front: start,
             back: end,
-            remaining,
+            remaining: count_cells(grid, start, offset, remaining),
         }
     }
 
@@ -58,7 +58,7 @@ impl<'a, T> GridLinearIter<'a, T> {
             front: single,
             back: single,
             offset: Offset::default(),
-            remaining: 1,
+            remaining: usize::from(grid.has_cell(single)),
         }
     }
 
@@ -78,29 +78,85 @@ impl<'a, T> GridLinearIter<'a, T> {
             offset,
             front: start,
             back: end,
-            remaining,
+            remaining: count_cells(grid, start, offset, remaining),
         }
     }
 
+    /// Create an iterator over the given row, which is empty if the row is out of bounds
     pub fn new_row(grid: &'a Grid<T>, row: usize) -> Self {
+        if row >= grid.rows() || grid.cols() == 0 {
+            return Self::new_empty(grid);
+        }
+
+        let front = Position::new(row, 0);
+
         Self {
             grid,
             offset: Offset::RIGHT,
-            front: Position::new(row, 0),
+            front,
             back: Position::new(row, grid.cols() - 1),
-            remaining: grid.cols(),
+            remaining: count_cells(grid, front, Offset::RIGHT, grid.cols()),
         }
     }
 
+    /// Create an iterator over the given column, which is empty if the column is out of bounds
     pub fn new_col(grid: &'a Grid<T>, col: usize) -> Self {
+        if col >= grid.cols() || grid.rows() == 0 {
+            return Self::new_empty(grid);
+        }
+
+        let front = Position::new(0, col);
+
         Self {
             grid,
             offset: Offset::DOWN,
-            front: Position::new(0, col),
+            front,
             back: Position::new(grid.rows() - 1, col),
-            remaining: grid.rows(),
+            remaining: count_cells(grid, front, Offset::DOWN, grid.rows()),
+        }
+    }
+
+    /// Advance the front of the iterator to the next existing cell and return it together with its position
+    pub(crate) fn next_indexed(&mut self) -> Option<(Position, &'a T)> {
+        while self.remaining > 0 {
+            let pos = self.front;
+            self.front = self.front.offset(self.offset).unwrap_or(self.front);
+
+            if let Some(item) = self.grid.get(pos) {
+                self.remaining -= 1;
+                return Some((pos, item));
+            }
+        }
+
+        None
+    }
+
+    /// Advance the back of the iterator to the previous existing cell and return it together with its position
+    pub(crate) fn next_back_indexed(&mut self) -> Option<(Position, &'a T)> {
+        while self.remaining > 0 {
+            let pos = self.back;
+            self.back = self.back.offset(-self.offset).unwrap_or(self.back);
+
+            if let Some(item) = self.grid.get(pos) {
+                self.remaining -= 1;
+                return Some((pos, item));
+            }
         }
+
+        None
+    }
+}
+
+/// Number of existing cells among the `len` positions from `start` in steps of `offset`
+fn count_cells<T>(grid: &Grid<T>, start: Position, offset: Offset, len: usize) -> usize {
+    if grid.mask().is_none() {
+        return len;
     }
+
+    (0..len)
+        .filter_map(|step| start.offset(offset * step as isize))
+        .filter(|&pos| grid.has_cell(pos))
+        .count()
 }
 
 fn steps_to_edge(start: usize, step: isize, max: usize) -> usize {
@@ -117,15 +173,7 @@ impl<'a, T> Iterator for GridLinearIter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
-        }
-
-        let pos = self.front;
-        self.front = self.front.offset(self.offset).unwrap_or(self.front);
-        self.remaining -= 1;
-
-        self.grid.get(pos)
+        self.next_indexed().map(|(_, item)| item)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -135,15 +183,7 @@ impl<'a, T> Iterator for GridLinearIter<'a, T> {
 
 impl<'a, T> DoubleEndedIterator for GridLinearIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
-        }
-
-        let pos = self.back;
-        self.back = self.back.offset(-self.offset).unwrap_or(self.back);
-        self.remaining -= 1;
-
-        self.grid.get(pos)
+        self.next_back_indexed().map(|(_, item)| item)
     }
 }
 
@@ -188,35 +228,49 @@ impl<'a, T> GridLinearIterMut<'a, T> {
         };
 
         Self {
+            remaining: count_cells(grid, start, offset, remaining),
             grid,
             offset,
             front: start,
             back: end,
-            remaining,
         }
     }
 
+    /// Create an iterator over the given row, which is empty if the row is out of bounds
     pub fn new_row(grid: &'a mut Grid<T>, row: usize) -> Self {
         let cols = grid.cols();
 
+        if row >= grid.rows() || cols == 0 {
+            return Self::new_empty(grid);
+        }
+
+        let front = Position::new(row, 0);
+
         Self {
+            remaining: count_cells(grid, front, Offset::RIGHT, cols),
             grid,
             offset: Offset::RIGHT,
-            front: Position::new(row, 0),
+            front,
             back: Position::new(row, cols - 1),
-            remaining: cols,
         }
     }
 
+    /// Create an iterator over the given column, which is empty if the column is out of bounds
     pub fn new_col(grid: &'a mut Grid<T>, col: usize) -> Self {
         let rows = grid.rows();
 
+        if col >= grid.cols() || rows == 0 {
+            return Self::new_empty(grid);
+        }
+
+        let front = Position::new(0, col);
+
         Self {
+            remaining: count_cells(grid, front, Offset::DOWN, rows),
             grid,
             offset: Offset::DOWN,
-            front: Position::new(0, col),
+            front,
             back: Position::new(rows - 1, col),
-            remaining: rows,
         }
     }
 
@@ -235,11 +289,11 @@ impl<'a, T> GridLinearIterMut<'a, T> {
         };
 
         Self {
+            remaining: count_cells(grid, start, offset, remaining),
             grid,
             offset,
             front: start,
             back: end,
-            remaining,
         }
     }
 
@@ -254,20 +308,47 @@ impl<'a, T> GridLinearIterMut<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for GridLinearIterMut<'a, T> {
-    type Item = &'a mut T;
+impl<'a, T> GridLinearIterMut<'a, T> {
+    /// Advance the front of the iterator to the next existing cell and return it together with its position
+    pub(crate) fn next_indexed(&mut self) -> Option<(Position, &'a mut T)> {
+        while self.remaining > 0 {
+            let pos = self.front;
+            self.front = self.front.offset(self.offset).unwrap_or(self.front);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
+            if let Some(item) = self.grid.get_mut(pos) {
+                self.remaining -= 1;
+
+                // SAFETY: we should never yield overlapping positions
+                return unsafe { Some((pos, &mut *(item as *mut T))) };
+            }
+        }
+
+        None
+    }
+
+    /// Advance the back of the iterator to the previous existing cell and return it together with its position
+    pub(crate) fn next_back_indexed(&mut self) -> Option<(Position, &'a mut T)> {
+        while self.remaining > 0 {
+            let pos = self.back;
+            self.back = self.back.offset(-self.offset).unwrap_or(self.back);
+
+            if let Some(item) = self.grid.get_mut(pos) {
+                self.remaining -= 1;
+
+                // SAFETY: we should never yield overlapping positions
+                return unsafe { Some((pos, &mut *(item as *mut T))) };
+            }
         }
 
-        let pos = self.front;
-        self.front = self.front.offset(self.offset).unwrap_or(self.front);
-        self.remaining -= 1;
+        None
+    }
+}
+
+impl<'a, T> Iterator for GridLinearIterMut<'a, T> {
+    type Item = &'a mut T;
 
-        // SAFETY: we should never yield overlapping positions
-        unsafe { Some(&mut *(&mut self.grid[pos] as *mut T)) }
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indexed().map(|(_, item)| item)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -277,16 +358,7 @@ impl<'a, T> Iterator for GridLinearIterMut<'a, T> {
 
 impl<'a, T> DoubleEndedIterator for GridLinearIterMut<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
-        }
-
-        let pos = self.back;
-        self.back = self.back.offset(-self.offset).unwrap_or(self.back);
-        self.remaining -= 1;
-
-        // SAFETY: we should never yield overlapping positions
-        unsafe { Some(&mut *(&mut self.grid[pos] as *mut T)) }
+        self.next_back_indexed().map(|(_, item)| item)
     }
 }
 
@@ -483,6 +555,132 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Borrow a row of the grid as a contiguous slice
+    ///
+    /// Returns [`None`] if the row is out of bounds or some of its cells are [omitted](Self::has_cell)
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    /// assert_eq!(grid.row(2), None);
+    /// ```
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        self.is_row_complete(row)
+            .then(|| &self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    /// Mutably borrow a row of the grid as a contiguous slice
+    ///
+    /// Returns [`None`] if the row is out of bounds or some of its cells are [omitted](Self::has_cell)
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let mut grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// grid.row_mut(0).unwrap().reverse();
+    /// assert_eq!(grid, grid![[3, 2, 1], [4, 5, 6]]);
+    /// ```
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        self.is_row_complete(row)
+            .then(|| &mut self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    /// Borrow a row of the grid as a contiguous slice, e.g. to render it at once
+    ///
+    /// Use [`row`](Self::row) to borrow rows that may be out of bounds
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    ['C', 'A', 'T'],
+    ///    ['D', 'O', 'G']
+    /// ];
+    /// let line: String = grid.row_slice(1).iter().collect();
+    /// assert_eq!(line, "DOG");
+    /// ```
+    /// # Panics
+    /// Panics if the row is out of bounds or some of its cells are [omitted](Self::has_cell)
+    pub fn row_slice(&self, row: usize) -> &[T] {
+        let rows = self.rows;
+
+        self.row(row)
+            .unwrap_or_else(|| panic!("Row {row} is out of bounds or incomplete ({rows} rows)"))
+    }
+
+    /// Mutably borrow a row of the grid as a contiguous slice
+    ///
+    /// # Panics
+    /// Panics if the row is out of bounds or some of its cells are [omitted](Self::has_cell)
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let mut grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// grid.row_slice_mut(1).copy_from_slice(&[7, 8, 9]);
+    /// assert_eq!(grid, grid![[1, 2, 3], [7, 8, 9]]);
+    /// ```
+    pub fn row_slice_mut(&mut self, row: usize) -> &mut [T] {
+        let rows = self.rows;
+
+        self.row_mut(row)
+            .unwrap_or_else(|| panic!("Row {row} is out of bounds or incomplete ({rows} rows)"))
+    }
+
+    fn is_row_complete(&self, row: usize) -> bool {
+        match &self.mask {
+            Some(mask) => mask.is_line_full(Line::Row(row)),
+            None => row < self.rows,
+        }
+    }
+
+    /// Creates an iterator over a column of the grid by stepping through its underlying data
+    ///
+    /// Unlike [`iter_col`](Self::iter_col), no [positions](Position) are computed for every item.
+    /// As a consequence, the placeholders of [omitted](Self::has_cell) cells are included as well.
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.col_strided(1).collect::<Vec<_>>(), vec![&2, &5]);
+    /// assert_eq!(grid.col_strided(3).count(), 0);
+    /// ```
+    pub fn col_strided(
+        &self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        let data = match col < self.cols {
+            true => &self.data[col..],
+            false => &[],
+        };
+
+        data.iter().step_by(self.cols.max(1))
+    }
+
+    /// Creates a mutable iterator over a column of the grid by stepping through its underlying data, see [`col_strided`](Self::col_strided)
+    pub fn col_strided_mut(
+        &mut self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        let step = self.cols.max(1);
+        let data = match col < self.cols {
+            true => &mut self.data[col..],
+            false => &mut [],
+        };
+
+        data.iter_mut().step_by(step)
+    }
+
     /// Creates an iterator over the [lines](Line) of the grid in the specified [order](Order)
     /// ```
     /// use puzzled_core::{grid, Order};
@@ -582,7 +780,7 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::resolve_range;
-    use crate::{Direction, Grid, grid};
+    use crate::{Direction, Grid, Line, grid};
     use crate::{Direction::*, LineSegment};
 
     #[fixture]
@@ -601,12 +799,40 @@ mod tests {
         )
     }
 
+    #[rstest]
+    #[case::row(Line::Row(2), vec![21, 22, 23, 24, 25, 26, 27, 28, 29, 30])]
+    #[case::col(Line::Col(1), vec![2, 12, 22, 32, 42, 52, 62, 72, 82, 92])]
+    #[case::row_out_of_bounds(Line::Row(10), vec![])]
+    #[case::col_out_of_bounds(Line::Col(10), vec![])]
+    fn iter_line(grid: Grid<usize>, #[case] line: Line, #[case] expected: Vec<usize>) {
+        let iter = grid.iter_line(line);
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.copied().collect::<Vec<_>>(), expected);
+
+        let strided: Vec<_> = match line {
+            Line::Row(row) => grid.row(row).unwrap_or_default().to_vec(),
+            Line::Col(col) => grid.col_strided(col).copied().collect(),
+        };
+        assert_eq!(strided, expected);
+
+        let mut grid = grid;
+        let reversed: Vec<_> = grid.iter_line_mut(line).rev().map(|val| *val).collect();
+        assert_eq!(reversed, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iter_line_empty_grid() {
+        let grid: Grid<usize> = Grid::from_vec(vec![], 3).expect("Empty grid");
+
+        assert_eq!(grid.iter_row(0).len(), 0);
+        assert_eq!(grid.iter_col(0).len(), 0);
+        assert_eq!(grid.col_strided(0).len(), 0);
+    }
+
     #[rstest]
     #[case(33, Right, vec![33, 34, 35, 36, 37, 38, 39, 40])]
     #[case(33, Up, vec![33, 23, 13, 3])]
     fn iter_segment(#[case] num: usize, #[case] dir: Direction, #[case] expected: Vec<usize>) {
-        use crate::Line;
-
         let grid = grid();
         let pos = grid.position(num - 1).expect("Specified valid number");
         let segment = LineSegment::from((pos, dir));
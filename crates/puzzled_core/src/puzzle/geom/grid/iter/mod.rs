@@ -1,7 +1,10 @@
+mod enumerate;
 mod indexed;
 mod linear;
 mod positions;
+mod runs;
 
+pub use enumerate::*;
 pub use indexed::*;
 pub use linear::*;
 pub use positions::*;
@@ -55,7 +58,20 @@ impl<T> IntoIterator for Grid<T> {
     type IntoIter = <Vec<T> as IntoIterator>::IntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.into_iter()
+        if self.mask.is_none() {
+            return self.data.into_iter();
+        }
+
+        let exists: Vec<_> = (0..self.data.len())
+            .map(|idx| self.has_cell_at(idx))
+            .collect();
+
+        self.data
+            .into_iter()
+            .zip(exists)
+            .filter_map(|(val, exists)| exists.then_some(val))
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 }
 
@@ -78,7 +94,9 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn positions(&self) -> impl Iterator<Item = Position> {
-        (0..self.data.len()).map(move |idx| self.position(idx).expect("Position should be valid"))
+        (0..self.data.len())
+            .filter(|&idx| self.has_cell_at(idx))
+            .map(move |idx| self.position(idx).expect("Position should be valid"))
     }
 
     /// Creates an iterator over the grid
@@ -99,7 +117,11 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data.iter()
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.has_cell_at(*idx))
+            .map(|(_, val)| val)
     }
 
     /// Creates a mutable iterator over the grid
@@ -120,6 +142,17 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.data.iter_mut()
+        let exists: Vec<_> = match self.mask {
+            Some(_) => (0..self.data.len())
+                .map(|idx| self.has_cell_at(idx))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        self.data
+            .iter_mut()
+            .enumerate()
+            .filter(move |(idx, _)| exists.get(*idx).is_none_or(|exists| *exists))
+            .map(|(_, val)| val)
     }
 }
@@ -13,7 +13,10 @@ pub struct GridPositionsIter<'a, T> {
 }
 
 impl<'a, T> GridPositionsIter<'a, T> {
-    pub fn new(grid: &'a Grid<T>, positions: Vec<Position>) -> Self {
+    /// Create an iterator over the given positions, skipping those without a [cell](Grid::has_cell)
+    pub fn new(grid: &'a Grid<T>, mut positions: Vec<Position>) -> Self {
+        positions.retain(|&pos| grid.has_cell(pos));
+
         Self {
             grid,
             front: 0,
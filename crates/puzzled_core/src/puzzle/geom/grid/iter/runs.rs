@@ -0,0 +1,59 @@
+use crate::{Grid, Line, LinePosition, LineRun, LineRuns};
+
+impl<T> Grid<T> {
+    /// Creates an iterator over the runs of a line, where cells belong to the same run when their keys are equal
+    /// ```
+    /// use puzzled_core::{grid, Line, LineRun};
+    ///
+    /// let grid = grid![
+    ///    [1, 3, 5, 2],
+    ///    [2, 4, 6, 8]
+    /// ];
+    /// let odd: Vec<_> = grid.iter_line_runs_by(Line::Row(0), |num| num % 2 == 1).collect();
+    /// assert_eq!(odd, vec![LineRun::new(true, 0, 3), LineRun::new(false, 3, 1)]);
+    /// ```
+    ///
+    /// Unlike [`iter_line`](Self::iter_line), the placeholders of [omitted](Self::has_cell) cells are keyed as well, such that the runs keep their indices within the line
+    pub fn iter_line_runs_by<K, F>(&self, line: Line, key: F) -> LineRuns<impl Iterator<Item = K>>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        let items: Box<dyn Iterator<Item = &T>> = match line {
+            Line::Row(row) => Box::new(
+                self.data
+                    .chunks(self.cols.max(1))
+                    .nth(row)
+                    .into_iter()
+                    .flatten(),
+            ),
+            Line::Col(col) => Box::new(self.col_strided(col)),
+        };
+
+        LineRuns::new(items.map(key))
+    }
+
+    /// Find the run of a line that contains the given position, where cells belong to the same run when their keys are equal
+    /// ```
+    /// use puzzled_core::{grid, Line, LinePosition, LineRun};
+    ///
+    /// let grid = grid![
+    ///    ['A', '.', 'B', 'C']
+    /// ];
+    /// let letter = |ch: &char| ch.is_alphabetic();
+    ///
+    /// let pos = LinePosition::new(Line::Row(0), 3);
+    /// assert_eq!(grid.line_run_at(pos, letter), Some(LineRun::new(true, 2, 2)));
+    ///
+    /// let pos = LinePosition::new(Line::Row(0), 4);
+    /// assert_eq!(grid.line_run_at(pos, letter), None);
+    /// ```
+    pub fn line_run_at<K, F>(&self, pos: LinePosition, key: F) -> Option<LineRun<K>>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        self.iter_line_runs_by(pos.line, key)
+            .find(|run| run.contains(pos.pos))
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{BitGrid, Grid, GridError, Position};
+
+/// Character that is used to display a cell that is omitted from a [grid](Grid)
+pub const OMITTED_CELL_CHAR: char = '_';
+
+impl<T> Grid<T> {
+    /// Restrict the grid to the cells that are set in the mask, such that all other cells are omitted
+    ///
+    /// Omitted cells simply do not exist, which makes it possible to represent circular, heart-shaped or staircase puzzles.
+    /// They cannot be [indexed](Self::get) and are skipped by all iterators, but keep their value as a placeholder for when the mask is [removed](Self::take_mask).
+    /// ```
+    /// use puzzled_core::{BitGrid, Position, grid};
+    ///
+    /// let mask = BitGrid::from_fn(2, 3, |pos| pos.col >= pos.row).unwrap();
+    /// let grid = grid![[1, 2, 3], [4, 5, 6]].with_mask(mask).unwrap();
+    ///
+    /// assert_eq!(grid.get(Position::new(1, 0)), None);
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &5, &6]);
+    /// assert_eq!(grid.to_string(), "[ 1 2 3 ]\n[ _ 5 6 ]");
+    /// ```
+    pub fn with_mask(mut self, mask: BitGrid) -> Result<Self, GridError> {
+        if mask.size() != self.size() {
+            return Err(GridError::InvalidSize {
+                found: mask.size(),
+                expected: self.size(),
+            });
+        }
+
+        self.mask = (mask.count_ones() < self.area()).then_some(mask);
+        Ok(self)
+    }
+
+    /// Mask of the cells that exist in the grid, which is [`None`] if no cells are omitted
+    pub fn mask(&self) -> Option<&BitGrid> {
+        self.mask.as_ref()
+    }
+
+    /// Remove the mask from the grid, such that the placeholders of the omitted cells become regular cells again
+    pub fn take_mask(&mut self) -> Option<BitGrid> {
+        self.mask.take()
+    }
+
+    /// Whether a cell exists at the given position, i.e. it is in bounds and not omitted
+    pub fn has_cell(&self, pos: Position) -> bool {
+        match &self.mask {
+            Some(mask) => mask.get(pos) == Some(true),
+            None => self.is_in_bounds(pos),
+        }
+    }
+
+    /// Number of cells that exist in the grid
+    ///
+    /// Unlike the [area](Self::area), this does not count omitted cells
+    pub fn cell_count(&self) -> usize {
+        match &self.mask {
+            Some(mask) => mask.count_ones(),
+            None => self.area(),
+        }
+    }
+
+    pub(crate) fn has_cell_at(&self, idx: usize) -> bool {
+        match &self.mask {
+            Some(_) => self.position(idx).is_some_and(|pos| self.has_cell(pos)),
+            None => idx < self.data.len(),
+        }
+    }
+
+    /// Move the mask into a grid of the given size, where `source` finds the original position of every new position
+    ///
+    /// New positions without a source always exist
+    pub(crate) fn remap_mask<F>(&mut self, rows: usize, cols: usize, source: F)
+    where
+        F: Fn(Position) -> Option<Position>,
+    {
+        let Some(mask) = self.mask.take() else {
+            return;
+        };
+
+        let mask = BitGrid::from_fn(rows, cols, |pos| {
+            source(pos).is_none_or(|pos| mask.get(pos) == Some(true))
+        })
+        .expect("Grid has a valid size");
+
+        self.mask = (mask.count_ones() < rows * cols).then_some(mask);
+    }
+
+    /// Map every position of the grid into a new grid without mask, where omitted cells are given as [`None`]
+    ///
+    /// This is useful for formats that have no notion of omitted cells and need to write a value for every position
+    /// ```
+    /// use puzzled_core::{Grid, Position};
+    ///
+    /// let grid = Grid::from_cells(vec![Some('A'), None, Some('B'), Some('C')], 2).unwrap();
+    /// let chars = grid.map_unmasked(|_, cell| cell.copied().unwrap_or('_'));
+    ///
+    /// assert_eq!(chars.mask(), None);
+    /// assert_eq!(chars[Position::new(0, 1)], '_');
+    /// assert_eq!(chars.iter().collect::<String>(), "A_BC");
+    /// ```
+    pub fn map_unmasked<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Position, Option<&T>) -> U,
+    {
+        let data = (0..self.data.len())
+            .map(|idx| {
+                let pos = Position::from_row_order(idx, self.cols);
+                f(pos, self.get(pos))
+            })
+            .collect();
+
+        Grid {
+            data,
+            cols: self.cols,
+            rows: self.rows,
+            mask: None,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Default,
+{
+    /// Create a grid from optional values and the number of columns to use, where [`None`] values are [omitted](Self::with_mask)
+    /// ```
+    /// use puzzled_core::{Grid, Position};
+    ///
+    /// let grid = Grid::from_cells(vec![Some('A'), None, Some('B'), Some('C')], 2).unwrap();
+    ///
+    /// assert!(!grid.has_cell(Position::new(0, 1)));
+    /// assert_eq!(grid.cell_count(), 3);
+    /// assert_eq!(grid.iter().collect::<String>(), "ABC");
+    /// ```
+    pub fn from_cells(cells: Vec<Option<T>>, cols: usize) -> Result<Self, GridError> {
+        let exists = Grid::from_vec(cells.iter().map(Option::is_some).collect(), cols)?;
+        let data = cells.into_iter().map(Option::unwrap_or_default).collect();
+
+        Grid::from_vec(data, cols)?.with_mask(BitGrid::from(&exists))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{Direction, Grid, GridError, Line, Position, grid};
+
+    /// Staircase of 3 rows, where row `i` only has its first `i + 1` cells
+    fn staircase() -> Grid<u8> {
+        let cells = vec![
+            Some(1),
+            None,
+            None,
+            Some(2),
+            Some(3),
+            None,
+            Some(4),
+            Some(5),
+            Some(6),
+        ];
+
+        Grid::from_cells(cells, 3).unwrap()
+    }
+
+    #[rstest]
+    #[case::row(Line::Row(1), vec![2, 3])]
+    #[case::col(Line::Col(1), vec![3, 5])]
+    #[case::empty_col(Line::Col(2), vec![6])]
+    fn lines(#[case] line: Line, #[case] expected: Vec<u8>) {
+        let grid = staircase();
+
+        let forwards: Vec<_> = grid.iter_line(line).copied().collect();
+        let backwards: Vec<_> = grid.iter_line(line).rev().copied().collect();
+
+        assert_eq!(forwards, expected);
+        assert_eq!(backwards, expected.into_iter().rev().collect::<Vec<_>>());
+        assert_eq!(grid.iter_line(line).len(), forwards.len());
+    }
+
+    #[test]
+    fn row_slices() {
+        let grid = staircase();
+
+        assert_eq!(grid.row_slice(2), &[4, 5, 6]);
+        assert_eq!(grid[(2, 1)], 5);
+        assert_eq!(grid.get((1, 2)), None);
+        assert!(matches!(
+            grid.get_checked((1, 2)),
+            Err(GridError::OmittedCell { pos }) if pos == Position::new(1, 2)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 1 is out of bounds or incomplete")]
+    fn row_slice_incomplete() {
+        staircase().row_slice(1);
+    }
+
+    #[test]
+    fn enumerate() {
+        let mut grid = staircase();
+
+        let positions: Vec<_> = grid.enumerate_positions().map(|(pos, _)| pos).collect();
+        assert_eq!(positions, grid.positions().collect::<Vec<_>>());
+
+        let values: Vec<_> = grid
+            .enumerate_positions()
+            .rev()
+            .map(|(_, &val)| val)
+            .collect();
+        assert_eq!(values, [6, 5, 4, 3, 2, 1]);
+
+        for (pos, val) in &mut grid {
+            *val += pos.row as u8 * 10;
+        }
+        assert!(grid.iter().copied().eq([1, 12, 13, 24, 25, 26]));
+    }
+
+    #[test]
+    fn iterators() {
+        let mut grid = staircase();
+
+        assert_eq!(grid.cell_count(), 6);
+        assert_eq!(grid.positions().count(), 6);
+        assert!(grid.iter_indexed().all(|(pos, _)| grid.has_cell(pos)));
+        assert!(grid.clone().into_iter().eq(1..=6));
+
+        grid.iter_mut().for_each(|val| *val *= 10);
+        assert!(grid.iter().copied().eq((1..=6).map(|val| val * 10)));
+
+        let segment: Vec<_> = grid
+            .iter_indexed_segment(Position::new(0, 0), Direction::Down)
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(segment.len(), 3);
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = staircase();
+        let omitted = Position::new(0, 2);
+
+        assert_eq!(grid.get(omitted), None);
+        assert_eq!(grid.get_mut(omitted), None);
+        assert_eq!(grid.swap(omitted, Position::ORIGIN), None);
+        assert!(grid.is_in_bounds(omitted));
+    }
+
+    #[test]
+    fn transform() {
+        let grid = staircase();
+
+        let transposed = grid.clone().transpose();
+        assert!(transposed.has_cell(Position::new(0, 2)));
+        assert!(!transposed.has_cell(Position::new(2, 0)));
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut grid = staircase();
+
+        grid.insert_row(0, vec![7, 8, 9]).unwrap();
+        assert_eq!(grid.cell_count(), 9);
+        assert!(!grid.has_cell(Position::new(1, 1)));
+
+        grid.remove_col(0);
+        assert_eq!(grid.cell_count(), 5);
+        assert!(!grid.has_cell(Position::new(1, 0)));
+
+        grid.remove_col(1);
+        grid.remove_row(1);
+        assert_eq!(grid.mask(), None);
+    }
+
+    #[test]
+    fn equality() {
+        let grid = staircase();
+        let mut unmasked = grid.clone();
+        unmasked.take_mask();
+
+        assert_ne!(grid, unmasked);
+        assert_ne!(grid, grid![[1, 0, 0], [2, 3, 0], [4, 5, 6]]);
+
+        // Placeholders of omitted cells are not compared
+        unmasked.data[1] = 9;
+        let remasked = unmasked.with_mask(grid.mask().cloned().unwrap()).unwrap();
+        assert_eq!(grid, remasked);
+    }
+}
@@ -1,23 +1,37 @@
+mod bit;
+mod diff;
 mod error;
 mod index;
 mod iter;
+mod mask;
 mod sided;
 mod square;
+mod transform;
+mod view;
 
+pub use bit::*;
 pub use iter::*;
+pub use mask::*;
 pub use sided::*;
 pub use square::*;
+pub use view::*;
 
 pub use error::Error as GridError;
 
 use crate::{Line, Position, Size};
-use std::fmt::{self, Debug};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+};
 
 #[derive(Debug, Default)]
 pub struct Grid<T> {
     cols: usize,
     rows: usize,
     data: Vec<T>,
+
+    /// Cells that exist in the grid, where [`None`] means every cell exists
+    mask: Option<BitGrid>,
 }
 
 impl<T> Grid<T> {
@@ -34,8 +48,13 @@ impl<T> Grid<T> {
         };
 
         let mut data = Vec::with_capacity(size);
-        data.fill_with(value_fn);
-        Ok(Self { rows, cols, data })
+        data.resize_with(size, value_fn);
+        Ok(Self {
+            rows,
+            cols,
+            data,
+            mask: None,
+        })
     }
 
     /// Create a grid from a data [`Vec<T>`] and the number of columns to use
@@ -48,7 +67,12 @@ impl<T> Grid<T> {
         }
 
         let rows = data.len() / cols;
-        Ok(Self { cols, rows, data })
+        Ok(Self {
+            cols,
+            rows,
+            data,
+            mask: None,
+        })
     }
 
     /// Number of columns in the grid
@@ -73,11 +97,15 @@ impl<T> Grid<T> {
     }
 
     /// Reference the underlying data [`Vec`]
+    ///
+    /// The data also contains the placeholder values of cells that are [omitted](Self::has_cell) from the grid
     pub fn data(&self) -> &Vec<T> {
         &self.data
     }
 
     /// Map each entry in the grid to create a new grid
+    ///
+    /// The placeholder values of [omitted](Self::has_cell) cells are mapped as well, such that the new grid has the same shape
     pub fn map<U, F>(self, f: F) -> Grid<U>
     where
         F: FnMut(T) -> U,
@@ -86,6 +114,7 @@ impl<T> Grid<T> {
             data: self.data.into_iter().map(f).collect(),
             cols: self.cols,
             rows: self.rows,
+            mask: self.mask,
         }
     }
 
@@ -98,6 +127,7 @@ impl<T> Grid<T> {
             data: self.data.iter().map(f).collect(),
             cols: self.cols,
             rows: self.rows,
+            mask: self.mask.clone(),
         }
     }
 
@@ -121,6 +151,7 @@ impl<T> Grid<T> {
             data,
             cols,
             rows: self.rows,
+            mask: self.mask,
         }
     }
 
@@ -144,6 +175,7 @@ impl<T> Grid<T> {
             data,
             cols,
             rows: self.rows,
+            mask: self.mask.clone(),
         }
     }
 
@@ -173,6 +205,7 @@ impl<T> Grid<T> {
             data,
             cols: self.cols,
             rows: self.rows,
+            mask: self.mask.clone(),
         })
     }
 
@@ -215,6 +248,227 @@ impl<T> Grid<T> {
             Line::Col(_) => self.rows,
         }
     }
+
+    /// Insert a row of values before the given row, shifting all rows after it down
+    ///
+    /// Inserting into an empty grid, i.e. one without rows or columns, sets its number of columns to the length of the row.
+    /// ```
+    /// use puzzled_core::{Grid, grid};
+    ///
+    /// let mut grid = grid![
+    ///     [1, 2],
+    ///     [5, 6],
+    /// ];
+    ///
+    /// grid.insert_row(1, vec![3, 4]).unwrap();
+    /// assert_eq!(grid, grid![[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert!(grid.insert_row(1, vec![0]).is_err());
+    /// assert!(grid.insert_row(4, vec![7, 8]).is_err());
+    ///
+    /// // Failed insertions leave the grid unchanged
+    /// let mut empty = Grid::<u8>::new(0, 0).unwrap();
+    /// assert!(empty.insert_row(1, vec![1, 2]).is_err());
+    /// assert_eq!((empty.rows(), empty.cols()), (0, 0));
+    /// ```
+    pub fn insert_row(&mut self, row: usize, values: Vec<T>) -> Result<(), GridError> {
+        // Validate before changing any dimension, such that a failed insertion leaves the grid as is
+        let (rows, cols) = match self.rows == 0 || self.cols == 0 {
+            true => (0, values.len()),
+            false => (self.rows, self.cols),
+        };
+        self.check_insert(row, rows, values.len(), cols)?;
+
+        self.rows = rows;
+        self.cols = cols;
+        let idx = row * self.cols;
+        self.data.splice(idx..idx, values);
+        self.rows += 1;
+
+        self.remap_mask(self.rows, self.cols, |pos| match pos.row.cmp(&row) {
+            Ordering::Less => Some(pos),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(Position::new(pos.row - 1, pos.col)),
+        });
+
+        Ok(())
+    }
+
+    /// Insert a column of values before the given column, shifting all columns after it right
+    ///
+    /// Inserting into an empty grid, i.e. one without rows or columns, sets its number of rows to the length of the column.
+    /// ```
+    /// use puzzled_core::{Grid, grid};
+    ///
+    /// let mut grid = grid![
+    ///     [1, 3],
+    ///     [4, 6],
+    /// ];
+    ///
+    /// grid.insert_col(1, vec![2, 5]).unwrap();
+    /// assert_eq!(grid, grid![[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let mut empty = Grid::<u8>::new(0, 0).unwrap();
+    /// assert!(empty.insert_col(1, vec![1, 2]).is_err());
+    /// assert_eq!((empty.rows(), empty.cols()), (0, 0));
+    /// ```
+    pub fn insert_col(&mut self, col: usize, values: Vec<T>) -> Result<(), GridError> {
+        let (rows, cols) = match self.rows == 0 || self.cols == 0 {
+            true => (values.len(), 0),
+            false => (self.rows, self.cols),
+        };
+        self.check_insert(col, cols, values.len(), rows)?;
+
+        self.rows = rows;
+        self.cols = cols;
+        let cols = self.cols + 1;
+        let mut values = values.into_iter();
+        let mut old = std::mem::take(&mut self.data).into_iter();
+
+        self.data = (0..self.rows * cols)
+            .map(|idx| match idx % cols == col {
+                true => values.next(),
+                false => old.next(),
+            })
+            .map(|val| val.expect("Checked the number of values"))
+            .collect();
+        self.cols = cols;
+
+        self.remap_mask(self.rows, self.cols, |pos| match pos.col.cmp(&col) {
+            Ordering::Less => Some(pos),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(Position::new(pos.row, pos.col - 1)),
+        });
+
+        Ok(())
+    }
+
+    /// Remove the given row from the grid and return its values, including the placeholders of [omitted](Self::has_cell) cells
+    ///
+    /// Returns [`None`] if the row is out of bounds
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let mut grid = grid![
+    ///     [1, 2],
+    ///     [3, 4],
+    /// ];
+    ///
+    /// assert_eq!(grid.remove_row(0), Some(vec![1, 2]));
+    /// assert_eq!(grid.remove_row(1), None);
+    /// assert_eq!(grid, grid![[3, 4]]);
+    /// ```
+    pub fn remove_row(&mut self, row: usize) -> Option<Vec<T>> {
+        if row >= self.rows {
+            return None;
+        }
+
+        let start = row * self.cols;
+        let values = self.data.drain(start..start + self.cols).collect();
+        self.rows -= 1;
+
+        self.remap_mask(self.rows, self.cols, |pos| match pos.row < row {
+            true => Some(pos),
+            false => Some(Position::new(pos.row + 1, pos.col)),
+        });
+
+        Some(values)
+    }
+
+    /// Remove the given column from the grid and return its values, including the placeholders of [omitted](Self::has_cell) cells
+    ///
+    /// Returns [`None`] if the column is out of bounds
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let mut grid = grid![
+    ///     [1, 2],
+    ///     [3, 4],
+    /// ];
+    ///
+    /// assert_eq!(grid.remove_col(1), Some(vec![2, 4]));
+    /// assert_eq!(grid, grid![[1], [3]]);
+    /// ```
+    pub fn remove_col(&mut self, col: usize) -> Option<Vec<T>> {
+        if col >= self.cols {
+            return None;
+        }
+
+        let cols = self.cols;
+        let (values, data) = std::mem::take(&mut self.data)
+            .into_iter()
+            .enumerate()
+            .partition::<Vec<_>, _>(|(idx, _)| idx % cols == col);
+
+        self.data = data.into_iter().map(|(_, val)| val).collect();
+        self.cols -= 1;
+
+        self.remap_mask(self.rows, self.cols, |pos| match pos.col < col {
+            true => Some(pos),
+            false => Some(Position::new(pos.row, pos.col + 1)),
+        });
+
+        Some(values.into_iter().map(|(_, val)| val).collect())
+    }
+
+    fn check_insert(
+        &self,
+        idx: usize,
+        max_idx: usize,
+        len: usize,
+        expected_len: usize,
+    ) -> Result<(), GridError> {
+        if idx > max_idx {
+            return Err(GridError::IndexOutOfBounds {
+                index: idx,
+                len: max_idx,
+            });
+        }
+        if len != expected_len {
+            return Err(GridError::InvalidLength {
+                found: len,
+                expected: expected_len,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<A, B> Grid<(A, B)> {
+    /// Split a grid of pairs into a pair of grids, which share the [mask](Self::mask) of the original grid
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let (nums, chars) = grid![[(1, 'A'), (2, 'B')]].unzip();
+    ///
+    /// assert_eq!(nums, grid![[1, 2]]);
+    /// assert_eq!(chars, grid![['A', 'B']]);
+    /// ```
+    pub fn unzip(self) -> (Grid<A>, Grid<B>) {
+        let Grid {
+            rows,
+            cols,
+            data,
+            mask,
+        } = self;
+        let (first, second) = data.into_iter().unzip();
+
+        let first = Grid {
+            rows,
+            cols,
+            data: first,
+            mask: mask.clone(),
+        };
+        let second = Grid {
+            rows,
+            cols,
+            data: second,
+            mask,
+        };
+
+        (first, second)
+    }
 }
 
 impl<T> fmt::Display for Grid<T>
@@ -228,9 +482,12 @@ where
         let mut max_widths = vec![0; cols];
         let mut displays = Vec::with_capacity(size);
 
-        for (idx, cell) in self.iter().enumerate() {
+        for (idx, cell) in self.data.iter().enumerate() {
             let col = idx % cols;
-            let display = cell.to_string();
+            let display = match self.has_cell_at(idx) {
+                true => cell.to_string(),
+                false => OMITTED_CELL_CHAR.to_string(),
+            };
 
             max_widths[col] = max_widths[col].max(display.len());
 
@@ -270,8 +527,17 @@ where
         if self.cols != other.cols {
             return false;
         }
+        if self.mask.is_none() && other.mask.is_none() {
+            return self.data == other.data;
+        }
 
-        self.data == other.data
+        // Only compare the cells that exist, ignoring the placeholders of omitted cells
+        (0..self.data.len()).all(
+            |idx| match (self.has_cell_at(idx), other.has_cell_at(idx)) {
+                (true, true) => self.data[idx] == other.data[idx],
+                (exists, other_exists) => exists == other_exists,
+            },
+        )
     }
 }
 
@@ -286,6 +552,7 @@ where
             cols: self.cols,
             rows: self.rows,
             data: self.data.clone(),
+            mask: self.mask.clone(),
         }
     }
 }
@@ -301,7 +568,12 @@ where
         let size = rows.checked_mul(cols)?;
 
         let data = vec![value; size];
-        Some(Self { rows, cols, data })
+        Some(Self {
+            rows,
+            cols,
+            data,
+            mask: None,
+        })
     }
 }
 
@@ -324,30 +596,65 @@ mod serde_impl {
     use serde::{
         Deserialize, Serialize,
         de::{self, Visitor},
-        ser::SerializeSeq,
+        ser::{SerializeMap, SerializeSeq, SerializeTuple},
     };
 
-    use crate::Grid;
+    use crate::{BitGrid, Grid, OMITTED_CELL_CHAR, Position};
 
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    impl<T: Serialize> Serialize for Grid<T> {
+    /// Character that marks an existing cell in a serialized mask
+    const CELL_CHAR: char = '#';
+
+    struct Rows<'a, T>(&'a Grid<T>);
+
+    impl<T: Serialize> Serialize for Rows<'_, T> {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: serde::Serializer,
         {
-            let mut seq = serializer.serialize_seq(Some(self.rows))?;
+            let grid = self.0;
+            let mut seq = serializer.serialize_seq(Some(grid.rows))?;
 
-            for row in 0..self.rows {
-                let start = row * self.cols;
-                let end = start + self.cols;
+            for row in 0..grid.rows {
+                let start = row * grid.cols;
+                let end = start + grid.cols;
 
-                seq.serialize_element(&self.data[start..end])?;
+                seq.serialize_element(&grid.data[start..end])?;
             }
 
             seq.end()
         }
     }
 
+    /// Serializes as a list of rows, or as a map of the `cells` rows and `mask` rows if some cells are omitted.
+    /// Every row of the mask is a string in which `#` marks an existing cell and `_` an omitted one.
+    ///
+    /// Formats that are not [human-readable](serde::Serializer::is_human_readable) always serialize a pair of the rows and optional mask instead.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    impl<T: Serialize> Serialize for Grid<T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            // Formats that are not self-describing cannot tell both shapes apart, so they always get the rows with an optional mask
+            if !serializer.is_human_readable() {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&Rows(self))?;
+                tuple.serialize_element(&self.mask.as_ref().map(|mask| mask_rows(self, mask)))?;
+                return tuple.end();
+            }
+
+            let Some(mask) = &self.mask else {
+                return Rows(self).serialize(serializer);
+            };
+
+            let mask = mask_rows(self, mask);
+            let mut map = serializer.serialize_map(Some(2))?;
+            map.serialize_entry("cells", &Rows(self))?;
+            map.serialize_entry("mask", &mask)?;
+            map.end()
+        }
+    }
+
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
     impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -380,26 +687,261 @@ mod serde_impl {
                         rows.push(row);
                     }
 
-                    let row_count = rows.len();
-                    let col_count = rows.first().map(|row| row.len()).unwrap_or(0);
+                    from_rows(rows)
+                }
 
-                    if rows.iter().any(|row| row.len() != col_count) {
-                        return Err(de::Error::custom("Each row should have the same width"));
+                fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: serde::de::MapAccess<'de>,
+                {
+                    let mut cells = None;
+                    let mut mask = None;
+
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "cells" => cells = Some(map.next_value::<Vec<Vec<T>>>()?),
+                            "mask" => mask = Some(map.next_value::<Vec<String>>()?),
+                            key => return Err(de::Error::unknown_field(key, &["cells", "mask"])),
+                        }
                     }
 
-                    let data = rows.into_iter().flatten().collect();
+                    let cells = cells.ok_or_else(|| de::Error::missing_field("cells"))?;
+                    let mask = mask.ok_or_else(|| de::Error::missing_field("mask"))?;
 
-                    Ok(Grid {
-                        rows: row_count,
-                        cols: col_count,
-                        data,
-                    })
+                    with_mask_rows(from_rows(cells)?, mask)
+                }
+            }
+
+            struct CompactGridVisitor<T> {
+                marker: PhantomData<T>,
+            }
+
+            impl<'de, T> Visitor<'de> for CompactGridVisitor<T>
+            where
+                T: Deserialize<'de>,
+            {
+                type Value = Grid<T>;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "A pair of the rows of a 2-dimensional grid and its optional mask"
+                    )
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: serde::de::SeqAccess<'de>,
+                {
+                    let cells = seq
+                        .next_element::<Vec<Vec<T>>>()?
+                        .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                    let mask = seq
+                        .next_element::<Option<Vec<String>>>()?
+                        .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                    let grid = from_rows(cells)?;
+                    match mask {
+                        Some(mask) => with_mask_rows(grid, mask),
+                        None => Ok(grid),
+                    }
                 }
             }
 
-            deserializer.deserialize_seq(GridVisitor {
-                marker: PhantomData,
+            match deserializer.is_human_readable() {
+                true => deserializer.deserialize_any(GridVisitor {
+                    marker: PhantomData,
+                }),
+                false => deserializer.deserialize_tuple(
+                    2,
+                    CompactGridVisitor {
+                        marker: PhantomData,
+                    },
+                ),
+            }
+        }
+    }
+
+    /// Rows of the mask as strings, in which existing and omitted cells are marked by their characters
+    fn mask_rows<T>(grid: &Grid<T>, mask: &BitGrid) -> Vec<String> {
+        (0..grid.rows)
+            .map(|row| {
+                (0..grid.cols)
+                    .map(|col| match mask.get(Position::new(row, col)) {
+                        Some(true) => CELL_CHAR,
+                        _ => OMITTED_CELL_CHAR,
+                    })
+                    .collect()
             })
+            .collect()
+    }
+
+    fn from_rows<T, E: de::Error>(rows: Vec<Vec<T>>) -> Result<Grid<T>, E> {
+        let row_count = rows.len();
+        let col_count = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        if rows.iter().any(|row| row.len() != col_count) {
+            return Err(de::Error::custom("Each row should have the same width"));
         }
+
+        let data = rows.into_iter().flatten().collect();
+
+        Ok(Grid {
+            rows: row_count,
+            cols: col_count,
+            data,
+            mask: None,
+        })
+    }
+
+    /// Omit the cells of the grid that the rows of the mask mark as omitted
+    fn with_mask_rows<T, E: de::Error>(grid: Grid<T>, mask: Vec<String>) -> Result<Grid<T>, E> {
+        if mask.len() != grid.rows || mask.iter().any(|row| row.chars().count() != grid.cols) {
+            return Err(de::Error::custom(
+                "The mask should have the size of the grid",
+            ));
+        }
+
+        let bits = BitGrid::from_fn(grid.rows, grid.cols, |pos| {
+            mask[pos.row].chars().nth(pos.col) != Some(OMITTED_CELL_CHAR)
+        })
+        .map_err(de::Error::custom)?;
+
+        grid.with_mask(bits).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use std::fmt::Debug;
+
+    use proptest::{
+        arbitrary::{Arbitrary, any_with},
+        collection::{self, SizeRange},
+        strategy::{BoxedStrategy, Strategy},
+    };
+
+    use crate::Grid;
+
+    /// Largest number of rows and columns of an [arbitrary](Arbitrary) grid
+    const MAX_ARBITRARY_LEN: usize = 8;
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl<T: Debug> Grid<T> {
+        /// Strategy that generates grids within the given number of rows and columns, filled by the element strategy
+        ///
+        /// Empty rows or columns are never generated, such that `0` bounds are raised to `1`
+        /// ```
+        /// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+        /// use puzzled_core::Grid;
+        ///
+        /// let strategy = Grid::strategy(2..=3, 4, any::<bool>());
+        /// let grid = strategy.new_tree(&mut TestRunner::default()).unwrap().current();
+        ///
+        /// assert!((2..=3).contains(&grid.rows()));
+        /// assert_eq!(grid.cols(), 4);
+        /// ```
+        pub fn strategy<S>(
+            rows: impl Into<SizeRange>,
+            cols: impl Into<SizeRange>,
+            element: S,
+        ) -> impl Strategy<Value = Self>
+        where
+            S: Strategy<Value = T> + Clone,
+        {
+            let len = |range: SizeRange| range.start().max(1)..=range.end_incl().max(1);
+            let (rows, cols) = (len(rows.into()), len(cols.into()));
+
+            (rows, cols).prop_flat_map(move |(rows, cols)| {
+                collection::vec(element.clone(), rows * cols).prop_map(move |data| Grid {
+                    rows,
+                    cols,
+                    data,
+                    mask: None,
+                })
+            })
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl<T> Arbitrary for Grid<T>
+    where
+        T: Arbitrary + 'static,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+            let len = 1..=MAX_ARBITRARY_LEN;
+            Grid::strategy(len.clone(), len, any_with::<T>(params).boxed()).boxed()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use crate::Grid;
+
+        proptest! {
+            #[test]
+            fn dimensions(grid in any::<Grid<u8>>()) {
+                prop_assert!(grid.rows() > 0 && grid.cols() > 0);
+                prop_assert_eq!(grid.iter().count(), grid.rows() * grid.cols());
+            }
+
+            #[test]
+            fn bounds(grid in Grid::strategy(0..3, 5..=6, any::<u8>())) {
+                prop_assert!((1..3).contains(&grid.rows()));
+                prop_assert!((5..=6).contains(&grid.cols()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    #[cfg(feature = "serde")]
+    use crate::{BitGrid, Position};
+    use crate::{Grid, grid};
+
+    #[rstest]
+    #[case::no_rows(0, 2)]
+    #[case::no_cols(2, 0)]
+    fn insert_row_degenerate(#[case] rows: usize, #[case] cols: usize) {
+        let mut grid = Grid::<u8>::new(rows, cols).unwrap();
+
+        grid.insert_row(0, vec![7, 8, 9]).unwrap();
+        assert_eq!(grid, grid![[7, 8, 9]]);
+    }
+
+    #[rstest]
+    #[case::no_rows(0, 2)]
+    #[case::no_cols(2, 0)]
+    fn insert_col_degenerate(#[case] rows: usize, #[case] cols: usize) {
+        let mut grid = Grid::<u8>::new(rows, cols).unwrap();
+
+        grid.insert_col(0, vec![9]).unwrap();
+        assert_eq!(grid, grid![[9]]);
+        assert_eq!(grid[(0, 0)], 9);
+    }
+
+    #[rstest]
+    #[case::plain(None)]
+    #[case::masked(Some(BitGrid::from_fn(2, 2, |pos| pos != Position::new(1, 0)).unwrap()))]
+    #[cfg(feature = "serde")]
+    fn serde_compact(#[case] mask: Option<BitGrid>) {
+        let mut grid = grid![[1, 2], [3, 4]];
+        if let Some(mask) = mask {
+            grid = grid.with_mask(mask).unwrap();
+        }
+
+        // Formats that are not self-describing cannot deserialize any shape
+        let bytes = bincode::serialize(&grid).unwrap();
+        let read: Grid<u8> = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(read, grid);
     }
 }
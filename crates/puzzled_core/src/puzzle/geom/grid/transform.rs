@@ -0,0 +1,138 @@
+use crate::{Grid, Position};
+
+impl<T> Grid<T> {
+    /// Mirror the grid along its main diagonal, such that rows become columns and vice versa
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.transpose(), grid![[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    pub fn transpose(self) -> Self {
+        let (rows, cols) = (self.cols, self.rows);
+
+        self.remap(rows, cols, |pos| Position::new(pos.col, pos.row))
+    }
+
+    /// Rotate the grid a quarter turn clockwise
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.rotate_cw(), grid![[4, 1], [5, 2], [6, 3]]);
+    /// ```
+    pub fn rotate_cw(self) -> Self {
+        let (rows, cols) = (self.cols, self.rows);
+
+        self.remap(rows, cols, |pos| Position::new(cols - 1 - pos.col, pos.row))
+    }
+
+    /// Rotate the grid a quarter turn counterclockwise
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.rotate_ccw(), grid![[3, 6], [2, 5], [1, 4]]);
+    /// ```
+    pub fn rotate_ccw(self) -> Self {
+        let (rows, cols) = (self.cols, self.rows);
+
+        self.remap(rows, cols, |pos| Position::new(pos.col, rows - 1 - pos.row))
+    }
+
+    /// Mirror the grid horizontally, i.e. reverse the order of its columns
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.flip_horizontal(), grid![[3, 2, 1], [6, 5, 4]]);
+    /// ```
+    pub fn flip_horizontal(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+
+        self.remap(rows, cols, |pos| Position::new(pos.row, cols - 1 - pos.col))
+    }
+
+    /// Mirror the grid vertically, i.e. reverse the order of its rows
+    /// ```
+    /// use puzzled_core::grid;
+    ///
+    /// let grid = grid![
+    ///    [1, 2, 3],
+    ///    [4, 5, 6]
+    /// ];
+    /// assert_eq!(grid.flip_vertical(), grid![[4, 5, 6], [1, 2, 3]]);
+    /// ```
+    pub fn flip_vertical(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+
+        self.remap(rows, cols, |pos| Position::new(rows - 1 - pos.row, pos.col))
+    }
+
+    /// Rearrange the grid into one of the given size, where `source` finds the original position of every new position
+    fn remap<F>(mut self, rows: usize, cols: usize, source: F) -> Self
+    where
+        F: Fn(Position) -> Position,
+    {
+        self.remap_mask(rows, cols, |pos| Some(source(pos)));
+
+        let old_cols = self.cols;
+        let mut old: Vec<_> = self.data.into_iter().map(Some).collect();
+
+        let data = (0..rows * cols)
+            .map(|idx| {
+                let pos = source(Position::new(idx / cols, idx % cols));
+
+                old[pos.row * old_cols + pos.col]
+                    .take()
+                    .expect("Every position is mapped exactly once")
+            })
+            .collect();
+
+        Self {
+            rows,
+            cols,
+            data,
+            mask: self.mask,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{Grid, grid};
+
+    #[rstest]
+    #[case::transpose(Grid::transpose, Grid::transpose)]
+    #[case::rotate(Grid::rotate_cw, Grid::rotate_ccw)]
+    #[case::flip_horizontal(Grid::flip_horizontal, Grid::flip_horizontal)]
+    #[case::flip_vertical(Grid::flip_vertical, Grid::flip_vertical)]
+    fn inverse(#[case] apply: fn(Grid<u8>) -> Grid<u8>, #[case] undo: fn(Grid<u8>) -> Grid<u8>) {
+        let grid = grid![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+        assert_eq!(undo(apply(grid.clone())), grid);
+    }
+
+    #[test]
+    fn rotate_full_turn() {
+        let grid = grid![[1, 2, 3], [4, 5, 6]];
+        let rotated = grid.clone().rotate_cw().rotate_cw();
+
+        assert_eq!(rotated, grid![[6, 5, 4], [3, 2, 1]]);
+        assert_eq!(rotated.rotate_cw().rotate_cw(), grid);
+    }
+}
@@ -0,0 +1,277 @@
+use std::ops;
+
+use crate::{BitGrid, Grid, Position, Rect, Size};
+
+/// Borrowed rectangular [area](Rect) of a [grid](Grid)
+///
+/// Positions within the view are relative to its top-left corner, such that the view behaves like a smaller grid.
+/// ```
+/// use puzzled_core::{grid, Position, Rect, Size};
+///
+/// let grid = grid![
+///     [1, 2, 3],
+///     [4, 5, 6],
+///     [7, 8, 9],
+/// ];
+///
+/// let view = grid.view(Rect::new(Position::new(1, 1), Size { rows: 2, cols: 2 }));
+/// assert_eq!(view[Position::new(0, 0)], 5);
+/// assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![5, 6, 8, 9]);
+/// ```
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rect: Rect,
+}
+
+/// Mutably borrowed rectangular [area](Rect) of a [grid](Grid), see [`GridView`]
+/// ```
+/// use puzzled_core::{grid, Position, Rect, Size};
+///
+/// let mut grid = grid![
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// ];
+///
+/// let mut view = grid.view_mut(Rect::new(Position::new(0, 1), Size { rows: 2, cols: 2 }));
+/// view.iter_mut().for_each(|val| *val = 0);
+///
+/// assert_eq!(grid, grid![[1, 0, 0], [4, 0, 0]]);
+/// ```
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    rect: Rect,
+}
+
+impl<T> Grid<T> {
+    fn bounds(&self) -> Rect {
+        Rect::new(Position::ORIGIN, self.size())
+    }
+
+    /// Borrow the given area of the grid as a [view](GridView)
+    ///
+    /// The area is clipped to the bounds of the grid
+    pub fn view(&self, rect: Rect) -> GridView<'_, T> {
+        let rect = rect.intersection(self.bounds());
+
+        GridView { grid: self, rect }
+    }
+
+    /// Mutably borrow the given area of the grid as a [view](GridViewMut)
+    ///
+    /// The area is clipped to the bounds of the grid
+    pub fn view_mut(&mut self, rect: Rect) -> GridViewMut<'_, T> {
+        let rect = rect.intersection(self.bounds());
+
+        GridViewMut { grid: self, rect }
+    }
+}
+
+/// Find the range within the grid data of a row of the view
+fn row_range<T>(grid: &Grid<T>, rect: Rect, row: usize) -> ops::Range<usize> {
+    let start = (rect.start.row + row) * grid.cols + rect.start.col;
+
+    start..start + rect.size.cols
+}
+
+/// Translate a position relative to the view into a position within the grid
+fn absolute(rect: Rect, pos: Position) -> Option<Position> {
+    (pos.row < rect.size.rows && pos.col < rect.size.cols)
+        .then(|| Position::new(rect.start.row + pos.row, rect.start.col + pos.col))
+}
+
+macro_rules! impl_view {
+    ($view:ident) => {
+        impl<'a, T> $view<'a, T> {
+            /// Area of the underlying grid that is viewed
+            pub fn rect(&self) -> Rect {
+                self.rect
+            }
+
+            pub fn rows(&self) -> usize {
+                self.rect.size.rows
+            }
+
+            pub fn cols(&self) -> usize {
+                self.rect.size.cols
+            }
+
+            pub fn size(&self) -> Size {
+                self.rect.size
+            }
+
+            pub fn is_in_bounds(&self, pos: Position) -> bool {
+                absolute(self.rect, pos).is_some()
+            }
+
+            /// Translate a position relative to the view into a position within the underlying grid
+            pub fn absolute(&self, pos: Position) -> Option<Position> {
+                absolute(self.rect, pos)
+            }
+
+            pub fn get(&self, pos: Position) -> Option<&T> {
+                self.grid.get(absolute(self.rect, pos)?)
+            }
+
+            /// Creates an iterator over the (relative) [positions](Position) of the view in [row-major](crate::Order::Rows) order
+            ///
+            /// Positions of [omitted](Grid::has_cell) cells are skipped
+            pub fn positions(&self) -> impl Iterator<Item = Position> + use<'a, T> {
+                let rect = self.rect;
+                let positions = Rect::new(Position::ORIGIN, rect.size).positions();
+
+                match &self.grid.mask {
+                    Some(mask) => positions
+                        .filter(|&pos| {
+                            absolute(rect, pos).is_some_and(|pos| mask.get(pos) == Some(true))
+                        })
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                    None => positions.collect::<Vec<_>>().into_iter(),
+                }
+            }
+
+            /// Creates an iterator over the view in [row-major](crate::Order::Rows) order
+            pub fn iter(&self) -> impl Iterator<Item = &T> {
+                let mask = self.grid.mask.as_ref();
+
+                (0..self.rect.size.rows)
+                    .flat_map(move |row| {
+                        let range = row_range(self.grid, self.rect, row);
+                        range.clone().zip(self.grid.data[range].iter())
+                    })
+                    .filter(move |(idx, _)| mask.is_none_or(|_| self.grid.has_cell_at(*idx)))
+                    .map(|(_, item)| item)
+            }
+
+            /// Copy the viewed area into a new grid
+            pub fn to_grid(&self) -> Grid<T>
+            where
+                T: Clone,
+            {
+                let rect = self.rect;
+                let mask = self.grid.mask.as_ref().map(|mask| {
+                    BitGrid::from_fn(rect.size.rows, rect.size.cols, |pos| {
+                        absolute(rect, pos).is_some_and(|pos| mask.get(pos) == Some(true))
+                    })
+                    .expect("View has a valid size")
+                });
+
+                // Copy the placeholders of omitted cells as well, such that the mask can be applied afterwards
+                let data = (0..rect.size.rows)
+                    .flat_map(|row| self.grid.data[row_range(self.grid, rect, row)].iter())
+                    .cloned()
+                    .collect();
+                let grid = Grid {
+                    rows: self.rows(),
+                    cols: self.cols(),
+                    data,
+                    mask: None,
+                };
+
+                match mask {
+                    Some(mask) => grid.with_mask(mask).expect("Mask has the size of the view"),
+                    None => grid,
+                }
+            }
+        }
+
+        impl<'a, T> ops::Index<Position> for $view<'a, T> {
+            type Output = T;
+
+            fn index(&self, pos: Position) -> &Self::Output {
+                self.get(pos).expect("Position should be within the view")
+            }
+        }
+    };
+}
+
+impl_view!(GridView);
+impl_view!(GridViewMut);
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+impl<'a, T> GridViewMut<'a, T> {
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        let pos = absolute(self.rect, pos)?;
+        self.grid.get_mut(pos)
+    }
+
+    /// Creates a mutable iterator over the view in [row-major](crate::Order::Rows) order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let Rect { start, size } = self.rect;
+        let cols = self.grid.cols;
+        let mask = self.grid.mask.as_ref();
+
+        self.grid
+            .data
+            .chunks_mut(cols.max(1))
+            .enumerate()
+            .skip(start.row)
+            .take(size.rows)
+            .flat_map(move |(row, items)| {
+                (start.col..start.col + size.cols)
+                    .zip(items[start.col..start.col + size.cols].iter_mut())
+                    .map(move |(col, item)| (Position::new(row, col), item))
+            })
+            .filter(move |(pos, _)| mask.is_none_or(|mask| mask.get(*pos) == Some(true)))
+            .map(|(_, item)| item)
+    }
+
+    /// Reborrow the mutable view as an immutable [view](GridView)
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            rect: self.rect,
+        }
+    }
+}
+
+impl<'a, T> ops::IndexMut<Position> for GridViewMut<'a, T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .expect("Position should be within the view")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::grid;
+
+    #[rstest]
+    #[case::inner((1, 1), (2, 2), vec![5, 6, 8, 9])]
+    #[case::row((2, 0), (1, 3), vec![7, 8, 9])]
+    #[case::clipped((1, 2), (5, 5), vec![6, 9])]
+    #[case::outside((3, 3), (1, 1), vec![])]
+    fn view(
+        #[case] start: (usize, usize),
+        #[case] size: (usize, usize),
+        #[case] expected: Vec<u8>,
+    ) {
+        let grid = grid![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let rect = Rect::new(
+            start.into(),
+            Size {
+                rows: size.0,
+                cols: size.1,
+            },
+        );
+
+        let view = grid.view(rect);
+        let values: Vec<_> = view.iter().copied().collect();
+        let by_position: Vec<_> = view.positions().map(|pos| view[pos]).collect();
+
+        assert_eq!(values, expected);
+        assert_eq!(by_position, expected);
+    }
+}
@@ -1,7 +1,9 @@
 mod position;
+mod run;
 mod segment;
 
 pub use position::*;
+pub use run::*;
 pub use segment::*;
 
 use std::{cmp::Ordering, fmt, ops};
@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+/// Maximal sequence of equal values on a line, such as a run of filled cells in a nonogram or the squares of a crossword entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineRun<T> {
+    /// Value that every cell of the run shares
+    pub value: T,
+
+    /// Index of the first cell of the run on its line
+    pub start: usize,
+
+    /// Number of cells in the run
+    pub len: usize,
+}
+
+impl<T> LineRun<T> {
+    pub fn new(value: T, start: usize, len: usize) -> Self {
+        Self { value, start, len }
+    }
+
+    /// Index right after the last cell of the run
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        self.range().contains(&idx)
+    }
+}
+
+/// Iterator that run-length encodes the values of another iterator
+/// ```
+/// use puzzled_core::{LineRun, LineRuns};
+///
+/// let runs: Vec<_> = LineRuns::new("aabccc".chars()).collect();
+/// assert_eq!(
+///     runs,
+///     vec![
+///         LineRun::new('a', 0, 2),
+///         LineRun::new('b', 2, 1),
+///         LineRun::new('c', 3, 3),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineRuns<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    curr: Option<LineRun<I::Item>>,
+}
+
+impl<I> LineRuns<I>
+where
+    I: Iterator,
+{
+    pub fn new(mut iter: I) -> Self {
+        let curr = iter.next().map(|value| LineRun::new(value, 0, 1));
+
+        Self { iter, curr }
+    }
+}
+
+impl<I> Iterator for LineRuns<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = LineRun<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut curr = self.curr.take()?;
+
+        for value in self.iter.by_ref() {
+            // Continue the current run..
+            if value == curr.value {
+                curr.len += 1;
+            }
+            // .. or start a new one from the next value
+            else {
+                self.curr = Some(LineRun::new(value, curr.end(), 1));
+                return Some(curr);
+            }
+        }
+
+        Some(curr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("", vec![])]
+    #[case("a", vec![('a', 0, 1)])]
+    #[case("aa", vec![('a', 0, 2)])]
+    #[case("abba", vec![('a', 0, 1), ('b', 1, 2), ('a', 3, 1)])]
+    fn runs(#[case] values: &str, #[case] expected: Vec<(char, usize, usize)>) {
+        let runs: Vec<_> = LineRuns::new(values.chars()).collect();
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(value, start, len)| LineRun::new(value, start, len))
+            .collect();
+
+        assert_eq!(runs, expected);
+    }
+}
@@ -5,6 +5,7 @@ mod line;
 mod offset;
 mod order;
 mod position;
+mod rect;
 mod size;
 
 pub use direction::*;
@@ -14,6 +15,7 @@ pub use line::*;
 pub use offset::*;
 pub use order::*;
 pub use position::*;
+pub use rect::*;
 pub use size::*;
 
 pub(crate) fn clamped_add(lhs: usize, rhs: isize) -> usize {
@@ -43,6 +43,7 @@ impl Position {
         }
     }
 
+    /// Move the position by the given offset, if it does not end up before the first row or column
     pub fn offset(&self, offset: Offset) -> Option<Self> {
         let row = (self.row as isize).checked_add(offset.rows)?;
         let col = (self.col as isize).checked_add(offset.cols)?;
@@ -57,6 +58,63 @@ impl Position {
         })
     }
 
+    /// Move the position `n` cells in the given direction, if it does not end up before the first row or column
+    /// ```
+    /// use puzzled::core::{Direction, Position};
+    ///
+    /// let pos = Position::new(2, 3);
+    /// assert_eq!(pos.step(Direction::Right, 2), Some(Position::new(2, 5)));
+    /// assert_eq!(pos.step(Direction::Up, 2), Some(Position::new(0, 3)));
+    /// assert_eq!(pos.step(Direction::Up, 3), None);
+    /// ```
+    pub fn step(&self, direction: Direction, n: usize) -> Option<Self> {
+        let n = isize::try_from(n).ok()?;
+        self.offset(n * direction)
+    }
+
+    /// Move the position `n` cells in the given direction, stopping at the edges of a grid with the given dimensions
+    /// ```
+    /// use puzzled::core::{Direction, Position};
+    ///
+    /// let pos = Position::new(2, 3);
+    /// assert_eq!(pos.step_clamped(Direction::Down, 5, 4, 4), Position::new(3, 3));
+    /// assert_eq!(pos.step_clamped(Direction::Left, 5, 4, 4), Position::new(2, 0));
+    /// ```
+    pub fn step_clamped(&self, direction: Direction, n: usize, rows: usize, cols: usize) -> Self {
+        let max_row = rows.saturating_sub(1);
+        let max_col = cols.saturating_sub(1);
+
+        match direction {
+            Direction::Up => Self::new(self.row.saturating_sub(n), self.col),
+            Direction::Down => Self::new(self.row.saturating_add(n).min(max_row), self.col),
+            Direction::Left => Self::new(self.row, self.col.saturating_sub(n)),
+            Direction::Right => Self::new(self.row, self.col.saturating_add(n).min(max_col)),
+        }
+    }
+
+    /// Number of horizontal and vertical steps needed to move from one position to the other
+    /// ```
+    /// use puzzled::core::Position;
+    ///
+    /// let pos = Position::new(1, 4);
+    /// assert_eq!(pos.manhattan_distance(Position::new(3, 1)), 5);
+    /// assert_eq!(pos.manhattan_distance(pos), 0);
+    /// ```
+    pub fn manhattan_distance(&self, other: Position) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Whether the position lies inside a grid with the given dimensions
+    /// ```
+    /// use puzzled::core::Position;
+    ///
+    /// assert!(Position::new(1, 2).within(2, 3));
+    /// assert!(!Position::new(2, 2).within(2, 3));
+    /// ```
+    pub fn within(&self, rows: usize, cols: usize) -> bool {
+        self.row < rows && self.col < cols
+    }
+
     pub fn lines(&self) -> (Line, Line) {
         (Line::Row(self.row), Line::Col(self.col))
     }
@@ -116,7 +174,7 @@ impl ops::Add<Offset> for Position {
 
     /// Moves the position by the given offset.
     ///
-    /// Values that would move the position outside the `u8` range are clamped
+    /// Returns [`None`] if the position would end up before the first row or column
     fn add(self, offset: Offset) -> Option<Self> {
         self.offset(offset)
     }
@@ -127,7 +185,7 @@ impl ops::Sub<Offset> for Position {
 
     /// Moves the position by the inverse of the given offset.
     ///
-    /// Values that would move the position outside the `u8` range are clamped
+    /// Returns [`None`] if the position would end up before the first row or column
     fn sub(self, offset: Offset) -> Option<Self> {
         self.offset(-offset)
     }
@@ -136,7 +194,7 @@ impl ops::Sub<Offset> for Position {
 impl ops::AddAssign<Offset> for Position {
     /// Moves the position in place by the given offset.
     ///
-    /// Values that would move the position outside the `u8` range are clamped
+    /// The position is left unchanged if it would end up before the first row or column
     fn add_assign(&mut self, offset: Offset) {
         if let Some(pos) = *self + offset {
             *self = pos;
@@ -145,9 +203,9 @@ impl ops::AddAssign<Offset> for Position {
 }
 
 impl ops::SubAssign<Offset> for Position {
-    /// Moves the position in place by the given offset.
+    /// Moves the position in place by the inverse of the given offset.
     ///
-    /// Values that would move the position outside the `u8` range are clamped
+    /// The position is left unchanged if it would end up before the first row or column
     fn sub_assign(&mut self, offset: Offset) {
         if let Some(pos) = *self - offset {
             *self = pos;
@@ -155,6 +213,18 @@ impl ops::SubAssign<Offset> for Position {
     }
 }
 
+impl ops::Sub<Position> for Position {
+    type Output = Offset;
+
+    /// Offset that moves the other position onto this one
+    fn sub(self, other: Position) -> Offset {
+        Offset {
+            rows: self.row as isize - other.row as isize,
+            cols: self.col as isize - other.col as isize,
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 mod serde {
     use serde::{Deserialize, Serialize};
@@ -185,3 +255,36 @@ mod serde {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Position::new(0, 0), Position::new(3, 2))]
+    #[case(Position::new(4, 1), Position::new(0, 5))]
+    #[case(Position::new(2, 2), Position::new(2, 2))]
+    fn offset_round_trip(#[case] from: Position, #[case] to: Position) {
+        let offset = to - from;
+
+        assert_eq!(from + offset, Some(to));
+        assert_eq!(to - offset, Some(from));
+        assert_eq!(
+            from.manhattan_distance(to),
+            offset.rows.unsigned_abs() + offset.cols.unsigned_abs()
+        );
+    }
+
+    #[test]
+    fn step_matches_direction() {
+        let pos = Position::new(1, 1);
+
+        for direction in Direction::ALL {
+            assert_eq!(pos.step(direction, 1), pos + direction);
+            assert_eq!(pos.step(direction, 0), Some(pos));
+            assert_eq!(pos.step(direction, 1), pos + Offset::from(direction));
+        }
+    }
+}
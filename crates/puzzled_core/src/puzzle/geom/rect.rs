@@ -0,0 +1,90 @@
+use crate::{Position, Size};
+
+/// Rectangular area within a [grid](crate::Grid), spanning from its [start](Self::start) over a given [size](Size)
+///
+/// ```
+/// use puzzled_core::{Position, Rect, Size};
+///
+/// let rect = Rect::new(Position::new(1, 1), Size { rows: 2, cols: 3 });
+///
+/// assert!(rect.contains(Position::new(2, 3)));
+/// assert!(!rect.contains(Position::new(3, 1)));
+/// assert_eq!(rect.end(), Position::new(3, 4));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    /// Top-left position within the area
+    pub start: Position,
+
+    /// Number of rows and columns in the area
+    pub size: Size,
+}
+
+impl Rect {
+    pub fn new(start: Position, size: Size) -> Self {
+        Self { start, size }
+    }
+
+    /// Create an area from its top-left position up to (excluding) its bottom-right position
+    pub fn from_corners(start: Position, end: Position) -> Self {
+        let size = Size {
+            rows: end.row.saturating_sub(start.row),
+            cols: end.col.saturating_sub(start.col),
+        };
+
+        Self { start, size }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.size.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.size.cols
+    }
+
+    pub fn area(&self) -> usize {
+        self.size.rows * self.size.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    /// Position right after the bottom-right corner of the area
+    pub fn end(&self) -> Position {
+        Position::new(
+            self.start.row + self.size.rows,
+            self.start.col + self.size.cols,
+        )
+    }
+
+    /// Whether the position lies within the area
+    pub fn contains(&self, pos: Position) -> bool {
+        let end = self.end();
+
+        (self.start.row..end.row).contains(&pos.row) && (self.start.col..end.col).contains(&pos.col)
+    }
+
+    /// Find the area that both rectangles cover
+    pub fn intersection(&self, other: Rect) -> Rect {
+        let (end, other_end) = (self.end(), other.end());
+
+        let start = Position::new(
+            self.start.row.max(other.start.row),
+            self.start.col.max(other.start.col),
+        );
+        let end = Position::new(end.row.min(other_end.row), end.col.min(other_end.col));
+
+        Rect::from_corners(start, end)
+    }
+
+    /// Iterate over the positions in the area in [row-major](crate::Order::Rows) order
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<> {
+        let Rect { start, size } = *self;
+
+        (0..size.rows).flat_map(move |row| {
+            (0..size.cols).map(move |col| Position::new(start.row + row, start.col + col))
+        })
+    }
+}
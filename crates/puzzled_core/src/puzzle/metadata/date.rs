@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+
+use crate::Metadata;
+
+/// Date formats that are recognized in titles and copyright notices, tried in order
+const DATE_FORMATS: [&str; 4] = ["%Y-%m-%d", "%B %d, %Y", "%B %d %Y", "%m/%d/%Y"];
+
+/// # Date
+impl Metadata {
+    /// Fill in the [date](Self::date) from the title or copyright of the puzzle, if it is not defined yet
+    ///
+    /// Dates are recognized in the styles that publishers commonly use, such as
+    /// - `NY Times, Wed, Jan 5, 2022`
+    /// - `January 5, 2022`
+    /// - `2022-01-05`
+    /// - `1/5/2022`
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use puzzled_core::Metadata;
+    ///
+    /// let meta = Metadata::default()
+    ///     .with_title("NY Times, Wed, Jan 5, 2022".to_string())
+    ///     .with_inferred_date();
+    ///
+    /// assert_eq!(meta.date(), NaiveDate::from_ymd_opt(2022, 1, 5));
+    /// ```
+    pub fn with_inferred_date(mut self) -> Self {
+        if self.date.is_none() {
+            let date = [self.title(), self.copyright()]
+                .into_iter()
+                .flatten()
+                .find_map(find_date);
+
+            self.date = date;
+        }
+
+        self
+    }
+}
+
+/// Find the first date in the text in any of the [recognized formats](DATE_FORMATS)
+pub fn find_date(text: &str) -> Option<NaiveDate> {
+    // Dates can only start at the beginning of a word
+    let mut starts = text.char_indices().filter_map(|(idx, c)| {
+        let is_start = text[..idx]
+            .chars()
+            .next_back()
+            .is_none_or(|prev| !prev.is_alphanumeric());
+
+        (c.is_alphanumeric() && is_start).then_some(idx)
+    });
+
+    starts.find_map(|start| {
+        DATE_FORMATS.iter().find_map(|format| {
+            NaiveDate::parse_and_remainder(&text[start..], format)
+                .ok()
+                .map(|(date, _)| date)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::nyt("NY Times, Wed, Jan 5, 2022", Some((2022, 1, 5)))]
+    #[case::full_month("Sunday Puzzle - January 16, 2022", Some((2022, 1, 16)))]
+    #[case::without_comma("LA Times Mar 03 2024", Some((2024, 3, 3)))]
+    #[case::iso("Daily 2026-03-07", Some((2026, 3, 7)))]
+    #[case::slashes("© 1/5/2022 Universal", Some((2022, 1, 5)))]
+    #[case::year_only("© 2022 The New York Times", None)]
+    #[case::none("Gold Rush", None)]
+    fn dates(#[case] text: &str, #[case] expected: Option<(i32, u32, u32)>) {
+        let expected = expected.and_then(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d));
+
+        assert_eq!(find_date(text), expected);
+    }
+}
@@ -1,9 +1,17 @@
+#[cfg(feature = "chrono")]
+mod date;
+mod notepad;
 mod timer;
 mod version;
 
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
 
-pub use timer::{Error as TimerError, Timer, TimerState};
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[cfg(feature = "chrono")]
+pub use date::find_date;
+pub use timer::{Error as TimerError, Timer, TimerSegment, TimerState};
 pub use version::{Error as VersionError, Version};
 
 #[derive(Debug, PartialEq, Eq, Default, Clone)]
@@ -14,14 +22,24 @@ pub struct Metadata {
     /// Copyright of the puzzle
     copyright: Option<String>,
 
+    /// Date the puzzle was published
+    #[cfg(feature = "chrono")]
+    date: Option<NaiveDate>,
+
     /// Notes on the puzzle
     notes: Option<String>,
 
+    /// Note that explains the theme of the puzzle
+    theme_note: Option<String>,
+
     /// Title of the puzzle
     title: Option<String>,
 
     /// Version of the puzzle
     version: Option<Version>,
+
+    /// Custom properties of the puzzle, such as its editor or difficulty
+    extra: BTreeMap<String, String>,
 }
 
 impl Metadata {
@@ -35,11 +53,23 @@ impl Metadata {
         self.copyright.as_deref()
     }
 
+    /// Date the puzzle was published
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
     /// Notes on the puzzle
     pub fn notes(&self) -> Option<&str> {
         self.notes.as_deref()
     }
 
+    /// Note that explains the theme of the puzzle, separate from the general [notes](Self::notes)
+    pub fn theme_note(&self) -> Option<&str> {
+        self.theme_note.as_deref()
+    }
+
     /// Title on the puzzle
     pub fn title(&self) -> Option<&str> {
         self.title.as_deref()
@@ -50,6 +80,42 @@ impl Metadata {
         self.version
     }
 
+    /// Version of the puzzle written as `<major>.<minor>`
+    pub fn version_str(&self) -> Option<String> {
+        self.version.map(|version| version.to_string())
+    }
+
+    /// Custom properties of the puzzle, ordered by their key
+    pub fn extra(&self) -> &BTreeMap<String, String> {
+        &self.extra
+    }
+
+    /// Value of a custom property of the puzzle
+    /// ```
+    /// use puzzled_core::Metadata;
+    ///
+    /// let meta = Metadata::default()
+    ///     .with_extra("editor".to_string(), "Will Shortz".to_string())
+    ///     .with_extra("difficulty".to_string(), "Easy".to_string());
+    ///
+    /// assert_eq!(meta.get_extra("editor"), Some("Will Shortz"));
+    /// assert_eq!(meta.get_extra("publisher"), None);
+    /// assert_eq!(meta.extra().keys().collect::<Vec<_>>(), ["difficulty", "editor"]);
+    /// ```
+    pub fn get_extra(&self, key: &str) -> Option<&str> {
+        self.extra.get(key).map(String::as_str)
+    }
+
+    /// Set a custom property of the puzzle, returning its previous value
+    pub fn insert_extra(&mut self, key: String, value: String) -> Option<String> {
+        self.extra.insert(key, value)
+    }
+
+    /// Remove a custom property of the puzzle, returning its value
+    pub fn remove_extra(&mut self, key: &str) -> Option<String> {
+        self.extra.remove(key)
+    }
+
     /// Define the author of the puzzle
     pub fn with_author(mut self, author: String) -> Self {
         self.author = Some(author);
@@ -62,12 +128,26 @@ impl Metadata {
         self
     }
 
+    /// Define the date the puzzle was published
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
     /// Define notes on the puzzle
     pub fn with_notes(mut self, notes: String) -> Self {
         self.notes = Some(notes);
         self
     }
 
+    /// Define a note that explains the theme of the puzzle
+    pub fn with_theme_note(mut self, theme_note: String) -> Self {
+        self.theme_note = Some(theme_note);
+        self
+    }
+
     /// Define the author of the puzzle
     pub fn with_title(mut self, title: String) -> Self {
         self.title = Some(title);
@@ -79,6 +159,12 @@ impl Metadata {
         self.version = Some(version);
         self
     }
+
+    /// Define a custom property of the puzzle
+    pub fn with_extra(mut self, key: String, value: String) -> Self {
+        self.extra.insert(key, value);
+        self
+    }
 }
 
 impl fmt::Display for Metadata {
@@ -92,12 +178,22 @@ impl fmt::Display for Metadata {
         if let Some(version) = self.version() {
             writeln!(f, "version: {version}")?;
         }
+        #[cfg(feature = "chrono")]
+        if let Some(date) = self.date() {
+            writeln!(f, "date: {date}")?;
+        }
         if let Some(notes) = self.notes() {
             writeln!(f, "notes: {notes}")?;
         }
+        if let Some(theme_note) = self.theme_note() {
+            writeln!(f, "theme_note: {theme_note}")?;
+        }
         if let Some(copyright) = self.copyright() {
             writeln!(f, "copyright: {copyright}")?;
         }
+        for (key, value) in self.extra() {
+            writeln!(f, "{key}: {value}")?;
+        }
 
         Ok(())
     }
@@ -105,6 +201,8 @@ impl fmt::Display for Metadata {
 
 #[cfg(feature = "serde")]
 mod serde_impl {
+    use std::collections::BTreeMap;
+
     use crate::{Metadata, Version};
     use serde::{Deserialize, Serialize};
 
@@ -114,13 +212,21 @@ mod serde_impl {
         author: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         copyright: Option<String>,
+        #[cfg(feature = "chrono")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        date: Option<chrono::NaiveDate>,
         #[serde(skip_serializing_if = "Option::is_none")]
         notes: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
+        theme_note: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         title: Option<String>,
 
         #[serde(skip_serializing_if = "Option::is_none")]
         version: Option<Version>,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        extra: BTreeMap<String, String>,
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
@@ -132,17 +238,25 @@ mod serde_impl {
             let Metadata {
                 author,
                 copyright,
+                #[cfg(feature = "chrono")]
+                date,
                 notes,
+                theme_note,
                 title,
                 version,
+                extra,
             } = self.clone();
 
             SerdeMetadata {
                 author,
                 copyright,
+                #[cfg(feature = "chrono")]
+                date,
                 notes,
+                theme_note,
                 title,
                 version,
+                extra,
             }
             .serialize(serializer)
         }
@@ -157,17 +271,25 @@ mod serde_impl {
             let SerdeMetadata {
                 author,
                 copyright,
+                #[cfg(feature = "chrono")]
+                date,
                 notes,
+                theme_note,
                 title,
                 version,
+                extra,
             } = SerdeMetadata::deserialize(deserializer)?;
 
             Ok(Metadata {
                 author,
                 copyright,
+                #[cfg(feature = "chrono")]
+                date,
                 notes,
+                theme_note,
                 title,
                 version,
+                extra,
             })
         }
     }
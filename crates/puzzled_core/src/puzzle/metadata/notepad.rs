@@ -0,0 +1,181 @@
+use crate::Metadata;
+
+/// Prefixes that introduce a theme note, which are matched case-insensitively
+const THEME_NOTE_PREFIXES: [&str; 2] = ["NOTEPAD:", "NOTE:"];
+
+/// Theme notes in titles that only point to the notepad, rather than containing the note itself
+const NOTEPAD_POINTERS: [&str; 3] = ["SEE NOTEPAD", "SEE NOTES", "SEE NOTE"];
+
+/// # Notepad
+impl Metadata {
+    /// Separate a theme note from the title and notes, following the conventions of the Across Lite notepad
+    ///
+    /// - A paragraph of the notes that starts with `NOTE:` or `Notepad:` is taken as the theme note
+    /// - A title that ends in `NOTE: ...` is cut off, where the remainder becomes the theme note unless it only refers to the notepad
+    ///
+    /// Metadata that already defines a [theme note](Self::theme_note) is left as is.
+    /// ```
+    /// use puzzled_core::Metadata;
+    ///
+    /// let meta = Metadata::default()
+    ///     .with_title("Gold Rush NOTE: See Notepad".to_string())
+    ///     .with_notes("NOTE: The circled squares spell GOLD.\n\nThanks for solving!".to_string())
+    ///     .with_notepad_conventions();
+    ///
+    /// assert_eq!(meta.title(), Some("Gold Rush"));
+    /// assert_eq!(meta.theme_note(), Some("The circled squares spell GOLD."));
+    /// assert_eq!(meta.notes(), Some("Thanks for solving!"));
+    /// ```
+    pub fn with_notepad_conventions(mut self) -> Self {
+        if self.theme_note.is_some() {
+            return self;
+        }
+
+        if let Some(notes) = self.notes.take() {
+            let (notes, theme_note) = split_notes(&notes);
+
+            self.notes = notes;
+            self.theme_note = theme_note;
+        }
+
+        if let Some(title) = self.title.take() {
+            let (title, theme_note) = split_title(&title);
+
+            self.title = Some(title);
+            self.theme_note = self.theme_note.take().or(theme_note);
+        }
+
+        self
+    }
+
+    /// Notes as written to the notepad of formats that do not store a theme note separately
+    ///
+    /// The theme note is put first and prefixed by `NOTE:`, such that [reading the conventions](Self::with_notepad_conventions) separates it again
+    /// ```
+    /// use puzzled_core::Metadata;
+    ///
+    /// let meta = Metadata::default()
+    ///     .with_theme_note("Every answer is a palindrome".to_string())
+    ///     .with_notes("Edited by Will".to_string());
+    ///
+    /// let notepad = meta.notepad();
+    /// assert_eq!(notepad.as_deref(), Some("NOTE: Every answer is a palindrome\n\nEdited by Will"));
+    ///
+    /// let read = Metadata::default().with_notes(notepad.unwrap()).with_notepad_conventions();
+    /// assert_eq!(read.theme_note(), meta.theme_note());
+    /// assert_eq!(read.notes(), meta.notes());
+    /// ```
+    pub fn notepad(&self) -> Option<String> {
+        match (self.theme_note(), self.notes()) {
+            (Some(theme_note), Some(notes)) => Some(format!("NOTE: {theme_note}\n\n{notes}")),
+            (Some(theme_note), None) => Some(format!("NOTE: {theme_note}")),
+            (None, notes) => notes.map(str::to_string),
+        }
+    }
+}
+
+fn strip_theme_note_prefix(text: &str) -> Option<&str> {
+    THEME_NOTE_PREFIXES.iter().find_map(|prefix| {
+        let head = text.get(..prefix.len())?;
+        head.eq_ignore_ascii_case(prefix)
+            .then(|| text[prefix.len()..].trim())
+    })
+}
+
+fn split_notes(notes: &str) -> (Option<String>, Option<String>) {
+    let mut theme_note = None;
+    let mut paragraphs = Vec::new();
+
+    for paragraph in notes.split("\n\n").map(str::trim) {
+        match strip_theme_note_prefix(paragraph) {
+            Some(note) if theme_note.is_none() => theme_note = Some(note.to_string()),
+            _ if paragraph.is_empty() => {}
+            _ => paragraphs.push(paragraph),
+        }
+    }
+
+    let notes = (!paragraphs.is_empty()).then(|| paragraphs.join("\n\n"));
+    (notes, theme_note)
+}
+
+fn split_title(title: &str) -> (String, Option<String>) {
+    // Prefixes are ASCII, such that byte offsets in the uppercased title agree with the original
+    let upper = title.to_ascii_uppercase();
+
+    // Prefixes only count as whole words, such that e.g. `FOOTNOTE:` is part of the title
+    let Some(start) = THEME_NOTE_PREFIXES
+        .iter()
+        .flat_map(|prefix| upper.match_indices(prefix))
+        .map(|(start, _)| start)
+        .filter(|&start| {
+            upper[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|ch| !ch.is_alphanumeric())
+        })
+        .min()
+    else {
+        return (title.to_string(), None);
+    };
+
+    let (title, note) = title.split_at(start);
+    let title = title
+        .trim_end()
+        .trim_end_matches(['(', '[', '-'])
+        .trim_end();
+    let note = strip_theme_note_prefix(note)
+        .unwrap_or(note)
+        .trim_end_matches([')', ']'])
+        .trim();
+
+    let is_pointer = NOTEPAD_POINTERS
+        .iter()
+        .any(|pointer| note.trim_end_matches('.').eq_ignore_ascii_case(pointer));
+    let note = (!note.is_empty() && !is_pointer).then(|| note.to_string());
+
+    (title.to_string(), note)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::plain("Gold Rush", "Gold Rush", None)]
+    #[case::inside_word("Footnote: Gold", "Footnote: Gold", None)]
+    #[case::pointer("Gold Rush NOTE: See Notepad", "Gold Rush", None)]
+    #[case::parenthesized("Gold Rush (Note: see notes.)", "Gold Rush", None)]
+    #[case::inline(
+        "Gold Rush NOTE: Circles spell GOLD",
+        "Gold Rush",
+        Some("Circles spell GOLD")
+    )]
+    fn titles(#[case] title: &str, #[case] expected: &str, #[case] note: Option<&str>) {
+        assert_eq!(
+            split_title(title),
+            (expected.to_string(), note.map(str::to_string))
+        );
+    }
+
+    #[rstest]
+    #[case::plain("Thanks for solving", Some("Thanks for solving"), None)]
+    #[case::only_note("Note: Read the circles", None, Some("Read the circles"))]
+    #[case::notepad(
+        "NOTEPAD: Read the circles\n\nBy Ed",
+        Some("By Ed"),
+        Some("Read the circles")
+    )]
+    #[case::later(
+        "By Ed\n\nNOTE: Read the circles",
+        Some("By Ed"),
+        Some("Read the circles")
+    )]
+    fn paragraphs(#[case] notes: &str, #[case] expected: Option<&str>, #[case] note: Option<&str>) {
+        assert_eq!(
+            split_notes(notes),
+            (expected.map(str::to_string), note.map(str::to_string))
+        );
+    }
+}
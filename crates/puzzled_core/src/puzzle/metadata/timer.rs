@@ -1,7 +1,10 @@
-use std::{
-    str::FromStr,
-    time::{Duration, Instant},
-};
+use std::{str::FromStr, time::Duration};
+
+// The standard clocks panic in browsers, where the clocks of the page are used instead
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::{Instant, SystemTime};
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use web_time::{Instant, SystemTime};
 
 #[derive(Debug, thiserror::Error)]
 #[error("Invalid timer: {reason}")]
@@ -28,11 +31,45 @@ pub struct Error {
 /// timer.toggle();
 /// assert_eq!(timer.state(), TimerState::Running);
 /// ```
-#[derive(Debug, Clone, Copy)]
+///
+/// Every time the timer is paused, the time it ran for is recorded as a [segment](TimerSegment).
+/// Apps can additionally [penalize](Timer::add_penalty) the user, e.g. for checking or revealing squares, which is accounted for in the [total](Timer::total) time:
+/// ```
+/// use puzzled_core::{Timer, TimerState};
+/// use std::time::Duration;
+///
+/// let mut timer = Timer::new(Duration::from_secs(60), TimerState::Stopped)
+///     .with_penalty(Duration::from_secs(10));
+///
+/// timer.add_penalty(Duration::from_secs(5));
+///
+/// assert_eq!(timer.elapsed(), Duration::from_secs(60));
+/// assert_eq!(timer.penalty(), Duration::from_secs(15));
+/// assert_eq!(timer.total(), Duration::from_secs(75));
+///
+/// timer.start();
+/// timer.pause();
+/// assert_eq!(timer.segments().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
 pub struct Timer {
     elapsed: Duration,
     start: Instant,
+    started_at: SystemTime,
     state: TimerState,
+
+    penalty: Duration,
+    segments: Vec<TimerSegment>,
+}
+
+/// Period of time in which a [timer](Timer) was [running](TimerState::Running)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerSegment {
+    /// Moment the timer was started
+    pub started_at: SystemTime,
+
+    /// How long the timer ran for before it was paused
+    pub duration: Duration,
 }
 
 impl Timer {
@@ -42,9 +79,18 @@ impl Timer {
             elapsed,
             state,
             start: Instant::now(),
+            started_at: SystemTime::now(),
+            penalty: Duration::ZERO,
+            segments: Vec::new(),
         }
     }
 
+    /// Start the timer with an initial penalty
+    pub fn with_penalty(mut self, penalty: Duration) -> Self {
+        self.penalty = penalty;
+        self
+    }
+
     /// Retrieve the current [state](TimerState) of the timer
     pub fn state(&self) -> TimerState {
         self.state
@@ -54,15 +100,22 @@ impl Timer {
     pub fn start(&mut self) {
         if matches!(self.state, TimerState::Stopped) {
             self.state = TimerState::Running;
-            self.start = Instant::now()
+            self.start = Instant::now();
+            self.started_at = SystemTime::now();
         }
     }
 
     /// Pause the timer if it is currently [running](TimerState::Running)
     pub fn pause(&mut self) {
         if matches!(self.state, TimerState::Running) {
-            self.elapsed += self.start.elapsed();
+            let duration = self.start.elapsed();
+
+            self.elapsed += duration;
             self.state = TimerState::Stopped;
+            self.segments.push(TimerSegment {
+                started_at: self.started_at,
+                duration,
+            });
         }
     }
 
@@ -75,12 +128,36 @@ impl Timer {
     }
 
     /// Determine the [duration](Duration) that has elapsed since the timer creation
+    ///
+    /// Note that this excludes the [penalty](Self::penalty), see [`total`](Self::total) to include it
     pub fn elapsed(&self) -> Duration {
         match self.state {
             TimerState::Stopped => self.elapsed,
             TimerState::Running => self.elapsed + self.start.elapsed(),
         }
     }
+
+    /// Add a penalty to the timer, which is counted in the [total](Self::total) time
+    pub fn add_penalty(&mut self, penalty: Duration) {
+        self.penalty += penalty;
+    }
+
+    /// Retrieve the accumulated penalty of the timer
+    pub fn penalty(&self) -> Duration {
+        self.penalty
+    }
+
+    /// Determine the [elapsed](Self::elapsed) time together with the [penalty](Self::penalty)
+    pub fn total(&self) -> Duration {
+        self.elapsed() + self.penalty
+    }
+
+    /// Retrieve all segments in which the timer was running
+    ///
+    /// Note that the segment of a currently [running](TimerState::Running) timer is only included after it is [paused](Self::pause)
+    pub fn segments(&self) -> &[TimerSegment] {
+        &self.segments
+    }
 }
 
 impl Default for Timer {
@@ -91,7 +168,9 @@ impl Default for Timer {
 
 impl PartialEq for Timer {
     fn eq(&self, other: &Self) -> bool {
-        self.state == other.state && self.elapsed() == other.elapsed()
+        self.state == other.state
+            && self.elapsed() == other.elapsed()
+            && self.penalty == other.penalty
     }
 }
 
@@ -190,8 +269,6 @@ impl FromStr for Timer {
 
 #[cfg(feature = "serde")]
 mod serde_impl {
-    use std::time::{Duration, Instant};
-
     use serde::{Deserialize, Serialize, de};
 
     use super::*;
@@ -200,6 +277,46 @@ mod serde_impl {
     pub struct TimerData {
         elapsed: u64,
         state: TimerState,
+
+        #[serde(default, skip_serializing_if = "is_zero")]
+        penalty: u64,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        segments: Vec<SegmentData>,
+    }
+
+    /// Segment with its start as seconds since the [Unix epoch](SystemTime::UNIX_EPOCH) and its duration in milliseconds
+    #[derive(Serialize, Deserialize)]
+    pub struct SegmentData {
+        started_at: u64,
+        duration_ms: u64,
+    }
+
+    fn is_zero(secs: &u64) -> bool {
+        *secs == 0
+    }
+
+    impl From<&TimerSegment> for SegmentData {
+        fn from(segment: &TimerSegment) -> Self {
+            let started_at = segment
+                .started_at
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default();
+
+            Self {
+                started_at: started_at.as_secs(),
+                duration_ms: segment.duration.as_millis() as u64,
+            }
+        }
+    }
+
+    impl From<SegmentData> for TimerSegment {
+        fn from(segment: SegmentData) -> Self {
+            Self {
+                started_at: SystemTime::UNIX_EPOCH + Duration::from_secs(segment.started_at),
+                duration: Duration::from_millis(segment.duration_ms),
+            }
+        }
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
@@ -208,11 +325,7 @@ mod serde_impl {
         where
             S: serde::Serializer,
         {
-            match self {
-                TimerState::Running => 0,
-                TimerState::Stopped => 0,
-            }
-            .serialize(serializer)
+            u8::from(*self).serialize(serializer)
         }
     }
 
@@ -225,6 +338,8 @@ mod serde_impl {
             TimerData {
                 elapsed: self.elapsed().as_secs(),
                 state: self.state,
+                penalty: self.penalty.as_secs(),
+                segments: self.segments.iter().map(SegmentData::from).collect(),
             }
             .serialize(serializer)
         }
@@ -257,7 +372,12 @@ mod serde_impl {
         where
             D: serde::Deserializer<'de>,
         {
-            let TimerData { elapsed, state } = TimerData::deserialize(deserializer)?;
+            let TimerData {
+                elapsed,
+                state,
+                penalty,
+                segments,
+            } = TimerData::deserialize(deserializer)?;
             let elapsed = Duration::from_secs(elapsed);
 
             Ok(Timer {
@@ -265,8 +385,11 @@ mod serde_impl {
                     TimerState::Running => Instant::now() - elapsed,
                     TimerState::Stopped => Instant::now(),
                 },
+                started_at: SystemTime::now(),
                 elapsed,
                 state,
+                penalty: Duration::from_secs(penalty),
+                segments: segments.into_iter().map(TimerSegment::from).collect(),
             })
         }
     }
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 #[derive(Debug, thiserror::Error)]
 #[error("{0}")]
@@ -6,10 +6,27 @@ pub enum Error {
     #[error("Expected to construct version from 3 bytes, found {found}")]
     InvalidByteCount { found: usize },
 
-    #[error("Version should be written as `<major>.<minor>` where <major>, <minor> are u8")]
+    #[error(
+        "Version should be written as `<major>.<minor>` where <major>, <minor> are single digits"
+    )]
     InvalidFormat,
 }
 
+/// Version of a puzzle, written as `<major>.<minor>`
+///
+/// Versions are ordered by their major and then their minor component, such that features can be checked against the version that introduced them
+/// ```
+/// use puzzled_core::Version;
+///
+/// let version: Version = "1.3".parse().unwrap();
+/// assert_eq!(version, Version::new(1, 3));
+/// assert_eq!(version.to_string(), "1.3");
+///
+/// assert!(version >= Version::new(1, 3));
+/// assert!(version < Version::new(2, 0));
+/// assert!(!version.supports_utf8());
+/// assert!("2.0".parse::<Version>().unwrap().supports_utf8());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Version {
     major: u8,
@@ -17,7 +34,10 @@ pub struct Version {
 }
 
 impl Version {
-    pub fn new(major: u8, minor: u8) -> Self {
+    /// Largest major or minor component, as every component is [encoded](Self::as_bytes) as a single digit
+    pub const MAX_COMPONENT: u8 = 9;
+
+    pub const fn new(major: u8, minor: u8) -> Self {
         Self { major, minor }
     }
 
@@ -57,9 +77,37 @@ impl Version {
         }
     }
 
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+
     pub fn as_bytes(&self) -> [u8; 4] {
         [self.major + b'0', b'.', self.minor + b'0', b'\0']
     }
+
+    /// Whether strings of puzzles with this version are encoded as UTF-8, which was introduced in version `2.0`
+    pub fn supports_utf8(&self) -> bool {
+        *self >= Self::new(2, 0)
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (major, minor) = s.trim().split_once('.').ok_or(Error::InvalidFormat)?;
+        // Every component should be encodable as a single digit
+        let component = |str: &str| match str.as_bytes() {
+            &[digit] if digit.is_ascii_digit() => Ok(digit - b'0'),
+            _ => Err(Error::InvalidFormat),
+        };
+
+        Ok(Self::new(component(major)?, component(minor)?))
+    }
 }
 
 impl Default for Version {
@@ -107,9 +155,37 @@ mod serde_impl {
             D: serde::Deserializer<'de>,
         {
             let SerdeVersion { major, minor } = SerdeVersion::deserialize(deserializer)?;
-            let version = Version { major, minor };
+            if major > Version::MAX_COMPONENT || minor > Version::MAX_COMPONENT {
+                return Err(serde::de::Error::custom(super::Error::InvalidFormat));
+            }
 
-            Ok(version)
+            Ok(Version { major, minor })
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::windows_1252("1.3", Some(Version::new(1, 3)))]
+    #[case::utf8(" 2.0 ", Some(Version::new(2, 0)))]
+    #[case::two_digits("1.12", None)]
+    #[case::no_minor("1", None)]
+    #[case::negative("1.-3", None)]
+    #[case::letters("a.b", None)]
+    #[case::overflow("256.0", None)]
+    fn parse(#[case] input: &str, #[case] expected: Option<Version>) {
+        assert_eq!(input.parse::<Version>().ok(), expected);
+    }
+
+    #[test]
+    fn bytes() {
+        let version = Version::new(1, 4);
+
+        assert_eq!(Version::from_bytes(&version.as_bytes()).ok(), Some(version));
+    }
+}
@@ -1,5 +1,6 @@
 mod cell;
 mod constraints;
+mod fingerprint;
 mod geom;
 mod metadata;
 mod square;
@@ -10,6 +11,7 @@ use std::fmt::Debug;
 
 pub use cell::*;
 pub use constraints::*;
+pub use fingerprint::*;
 pub use geom::*;
 pub use metadata::*;
 pub use square::*;
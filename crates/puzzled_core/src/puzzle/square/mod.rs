@@ -112,3 +112,35 @@ mod serde_impl {
         }
     }
 }
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use std::fmt::Debug;
+
+    use proptest::{
+        arbitrary::{Arbitrary, any_with},
+        option,
+        strategy::{BoxedStrategy, Strategy},
+    };
+
+    use crate::Square;
+
+    /// Probability that an [arbitrary](Arbitrary) square is playable
+    const PLAYABLE_PROBABILITY: f64 = 0.8;
+
+    /// Arbitrary squares are mostly playable, resembling the density of blocks in real puzzles
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl<T> Arbitrary for Square<T>
+    where
+        T: Arbitrary + Debug + 'static,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+            option::weighted(PLAYABLE_PROBABILITY, any_with::<T>(params))
+                .prop_map(Square)
+                .boxed()
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 pub type ColorId = u32;
 
@@ -12,13 +12,42 @@ pub struct Color {
     pub alpha: ColorValue,
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
 #[error("Color error: {0}")]
 pub enum Error {
     #[error("Invalid hex string '{found}' found: {reason}")]
     HexError { found: String, reason: String },
+
+    #[error("Invalid color '{found}' found: {reason}")]
+    ParseError { found: String, reason: &'static str },
 }
 
+/// Colors that can be [parsed](Color::from_str) by their name, following the [CSS color keywords](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color)
+const NAMED_COLORS: [(&str, Color); 22] = [
+    ("black", Color::rgb(0, 0, 0)),
+    ("silver", Color::rgb(192, 192, 192)),
+    ("gray", Color::rgb(128, 128, 128)),
+    ("grey", Color::rgb(128, 128, 128)),
+    ("white", Color::rgb(255, 255, 255)),
+    ("maroon", Color::rgb(128, 0, 0)),
+    ("red", Color::rgb(255, 0, 0)),
+    ("purple", Color::rgb(128, 0, 128)),
+    ("fuchsia", Color::rgb(255, 0, 255)),
+    ("magenta", Color::rgb(255, 0, 255)),
+    ("green", Color::rgb(0, 128, 0)),
+    ("lime", Color::rgb(0, 255, 0)),
+    ("olive", Color::rgb(128, 128, 0)),
+    ("yellow", Color::rgb(255, 255, 0)),
+    ("navy", Color::rgb(0, 0, 128)),
+    ("blue", Color::rgb(0, 0, 255)),
+    ("teal", Color::rgb(0, 128, 128)),
+    ("aqua", Color::rgb(0, 255, 255)),
+    ("cyan", Color::rgb(0, 255, 255)),
+    ("orange", Color::rgb(255, 165, 0)),
+    ("pink", Color::rgb(255, 192, 203)),
+    ("brown", Color::rgb(165, 42, 42)),
+];
+
 impl Color {
     pub const fn rgba(
         red: ColorValue,
@@ -91,6 +120,88 @@ impl Color {
 }
 
 impl Color {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+
+    /// Color with the given [CSS name](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color), ignoring case
+    pub fn named(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(color_name, _)| color_name.eq_ignore_ascii_case(name))
+            .map(|(_, color)| *color)
+    }
+
+    /// Name of the color, if it has one
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED_COLORS
+            .iter()
+            .find(|(_, color)| color == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// [Relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance) of the color, from `0.0` for black to `1.0` for white
+    pub fn luminance(&self) -> f64 {
+        let linear = |value: ColorValue| {
+            let value = value as f64 / ColorValue::MAX as f64;
+
+            match value <= 0.04045 {
+                true => value / 12.92,
+                false => ((value + 0.055) / 1.055).powf(2.4),
+            }
+        };
+
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    /// [Contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between the colors, from `1.0` for equal colors to `21.0` for black and white
+    /// ```
+    /// use puzzled_core::Color;
+    ///
+    /// assert_eq!(Color::BLACK.contrast_ratio(&Color::WHITE), 21.0);
+    /// assert_eq!(Color::WHITE.contrast_ratio(&Color::BLACK), 21.0);
+    /// assert_eq!(Color::BLACK.contrast_ratio(&Color::BLACK), 1.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        let (lhs, rhs) = (self.luminance(), other.luminance());
+        let (lighter, darker) = (lhs.max(rhs), lhs.min(rhs));
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Either [black](Self::BLACK) or [white](Self::WHITE), whichever is most readable as text on top of the color
+    /// ```
+    /// use puzzled_core::Color;
+    ///
+    /// assert_eq!(Color::rgb(255, 255, 0).text_color(), Color::BLACK);
+    /// assert_eq!(Color::rgb(0, 0, 128).text_color(), Color::WHITE);
+    /// ```
+    pub fn text_color(&self) -> Self {
+        match self.contrast_ratio(&Self::BLACK) >= self.contrast_ratio(&Self::WHITE) {
+            true => Self::BLACK,
+            false => Self::WHITE,
+        }
+    }
+
+    /// Largest difference between the channels of the colors, including their alpha
+    /// ```
+    /// use puzzled_core::Color;
+    ///
+    /// let red = Color::rgb(255, 0, 0);
+    /// assert_eq!(red.distance(&Color::rgb(250, 3, 0)), 5);
+    /// assert_eq!(red.distance(&red), 0);
+    /// ```
+    pub fn distance(&self, other: &Self) -> ColorValue {
+        [
+            self.red.abs_diff(other.red),
+            self.green.abs_diff(other.green),
+            self.blue.abs_diff(other.blue),
+            self.alpha.abs_diff(other.alpha),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default()
+    }
+
     pub fn to_hex(&self) -> String {
         if self.alpha == u8::MAX {
             format!("#{:02X}{:02X}{:02X}", self.red, self.green, self.blue)
@@ -103,6 +214,58 @@ impl Color {
     }
 }
 
+/// Parse a color from its [name](Color::named), its [hex](Color::hex) value or as `rgb(r, g, b)` or `rgba(r, g, b, a)`
+/// ```
+/// use puzzled_core::Color;
+///
+/// let orange = Color::rgb(255, 165, 0);
+///
+/// assert_eq!("Orange".parse(), Ok(orange));
+/// assert_eq!("#FFA500".parse(), Ok(orange));
+/// assert_eq!("rgb(255, 165, 0)".parse(), Ok(orange));
+/// assert_eq!("rgba(255, 165, 0, 128)".parse(), Ok(Color::rgba(255, 165, 0, 128)));
+/// assert!("rgb(256, 0, 0)".parse::<Color>().is_err());
+/// ```
+impl FromStr for Color {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(color) = Self::named(s) {
+            return Ok(color);
+        }
+
+        let error = |reason| Error::ParseError {
+            found: s.to_string(),
+            reason,
+        };
+
+        let lower = s.to_ascii_lowercase();
+        let (function, args) = match lower.split_once('(') {
+            None => return Self::hex(s),
+            Some((function @ ("rgb" | "rgba"), args)) => (function, args),
+            Some(_) => return Err(error("Expected rgb(...) or rgba(...)")),
+        };
+
+        let args = args
+            .strip_suffix(')')
+            .ok_or_else(|| error("Missing closing parenthesis"))?;
+        let values = args
+            .split(',')
+            .map(|value| value.trim().parse::<ColorValue>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error("Channels should be numbers from 0 to 255"))?;
+
+        match (function, &values[..]) {
+            ("rgb", &[red, green, blue]) => Ok(Self::rgb(red, green, blue)),
+            ("rgba", &[red, green, blue, alpha]) => Ok(Self::rgba(red, green, blue, alpha)),
+            ("rgb", _) => Err(error("Expected 3 channels")),
+            _ => Err(error("Expected 4 channels")),
+        }
+    }
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_hex())
@@ -1,3 +1,5 @@
 mod color;
+mod palette;
 
 pub use color::{Color, ColorId, Error as ColorError};
+pub use palette::Palette;
@@ -0,0 +1,162 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Color, ColorError};
+
+/// Ordered collection of distinct [colors](Color), which puzzles refer to by their index
+///
+/// Colors that are imported from images rarely match exactly, e.g. because of compression artifacts.
+/// [`insert_similar`](Self::insert_similar) reuses a color that is within a given [distance](Color::distance) rather than adding a new one.
+/// ```
+/// use puzzled_core::{Color, Palette};
+///
+/// let mut palette = Palette::default();
+/// assert_eq!(palette.insert_similar(Color::rgb(255, 0, 0), 8), 0);
+/// assert_eq!(palette.insert_similar(Color::rgb(0, 0, 255), 8), 1);
+///
+/// // Close enough to red to be the same color
+/// assert_eq!(palette.insert_similar(Color::rgb(250, 4, 0), 8), 0);
+/// assert_eq!(palette.len(), 2);
+///
+/// // Palettes are written with a color per line
+/// assert_eq!(palette.to_string(), "#FF0000\n#0000FF\n");
+/// assert_eq!(palette.to_string().parse(), Ok(palette));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Palette(Vec<Color>);
+
+impl Palette {
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self(colors)
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&Color> {
+        self.0.get(idx)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Color> {
+        self.0.iter()
+    }
+
+    /// Add the color to the end of the palette, returning its index
+    pub fn push(&mut self, color: Color) -> usize {
+        self.0.push(color);
+        self.0.len() - 1
+    }
+
+    /// Index of the color that is closest to the given color, as long as it is within the tolerance
+    pub fn find_similar(&self, color: &Color, tolerance: u8) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, other)| (idx, other.distance(color)))
+            .filter(|&(_, distance)| distance <= tolerance)
+            .min_by_key(|&(_, distance)| distance)
+            .map(|(idx, _)| idx)
+    }
+
+    /// Index of a [similar](Self::find_similar) color, adding the color if the palette has none
+    pub fn insert_similar(&mut self, color: Color, tolerance: u8) -> usize {
+        self.find_similar(&color, tolerance)
+            .unwrap_or_else(|| self.push(color))
+    }
+
+    /// Palette without colors that are similar to an earlier color
+    /// ```
+    /// use puzzled_core::{Color, Palette};
+    ///
+    /// let palette = Palette::new(vec![Color::BLACK, Color::rgb(2, 2, 2), Color::WHITE]);
+    ///
+    /// assert_eq!(palette.dedup(0), palette);
+    /// assert_eq!(palette.dedup(2), Palette::new(vec![Color::BLACK, Color::WHITE]));
+    /// ```
+    pub fn dedup(&self, tolerance: u8) -> Self {
+        let mut palette = Self::default();
+
+        for &color in &self.0 {
+            palette.insert_similar(color, tolerance);
+        }
+
+        palette
+    }
+}
+
+impl From<Vec<Color>> for Palette {
+    fn from(colors: Vec<Color>) -> Self {
+        Self(colors)
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<I: IntoIterator<Item = Color>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = &'a Color;
+    type IntoIter = std::slice::Iter<'a, Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for Palette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for color in &self.0 {
+            writeln!(f, "{color}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a palette with a [color](Color::from_str) on every line, skipping empty lines
+impl FromStr for Palette {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Color::from_str)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::exact(Color::rgb(0, 0, 250), 0, None)]
+    #[case::within(Color::rgb(0, 0, 250), 5, Some(1))]
+    #[case::closest(Color::rgb(128, 0, 128), 255, Some(0))]
+    fn find_similar(#[case] color: Color, #[case] tolerance: u8, #[case] expected: Option<usize>) {
+        let palette = Palette::new(vec![Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)]);
+
+        assert_eq!(palette.find_similar(&color, tolerance), expected);
+    }
+
+    #[rstest]
+    #[case::named("navy", Ok(Color::rgb(0, 0, 128)))]
+    #[case::short_hex("#0F0", Ok(Color::rgb(0, 255, 0)))]
+    #[case::spaced(" rgb( 1 , 2 , 3 ) ", Ok(Color::rgb(1, 2, 3)))]
+    #[case::channels("rgb(1, 2)", Err(()))]
+    #[case::unclosed("rgb(1, 2, 3", Err(()))]
+    #[case::unknown("hsl(1, 2, 3)", Err(()))]
+    fn parse_color(#[case] text: &str, #[case] expected: Result<Color, ()>) {
+        assert_eq!(text.parse::<Color>().map_err(|_| ()), expected);
+    }
+}
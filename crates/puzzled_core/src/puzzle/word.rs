@@ -1,6 +1,14 @@
 // Trait
 pub trait Word {
     fn is_word(&self) -> bool;
+
+    /// Whether the word is accepted where the `solution` is expected, which is the case when they are equal unless the solution accepts several words
+    fn matches(&self, solution: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self == solution
+    }
 }
 
 macro_rules! impl_word {
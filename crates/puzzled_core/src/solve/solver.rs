@@ -10,6 +10,46 @@ where
     fn solve(&mut self, puzzle: &P, state: &mut S) -> Result<P::Solution, Self::Error>;
 
     fn try_finalize(&self, state: &S) -> Result<P::Solution, Self::Error>;
+
+    /// Statistics about the work the solver has done so far
+    ///
+    /// Solvers that do not keep track of their work report empty statistics
+    fn stats(&self) -> SolverStats {
+        SolverStats::default()
+    }
+}
+
+/// Statistics that a [solver](Solver) collects while solving, e.g. to tune its performance
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SolverStats {
+    /// Number of search nodes visited
+    pub nodes: usize,
+
+    /// Number of times a part of the puzzle (e.g. a line) was propagated
+    pub propagations: usize,
+
+    /// Number of propagations whose result was found in a cache
+    pub cache_hits: usize,
+
+    /// Number of propagations whose result had to be computed and was then added to a cache
+    pub cache_misses: usize,
+}
+
+impl SolverStats {
+    /// Fraction of cache lookups that were hits, which is 0 if the cache was never used
+    /// ```
+    /// use puzzled_core::SolverStats;
+    ///
+    /// let stats = SolverStats { cache_hits: 3, cache_misses: 1, ..Default::default() };
+    /// assert_eq!(stats.cache_hit_rate(), 0.75);
+    /// assert_eq!(SolverStats::default().cache_hit_rate(), 0.0);
+    /// ```
+    pub fn cache_hit_rate(&self) -> f64 {
+        match self.cache_hits + self.cache_misses {
+            0 => 0.0,
+            lookups => self.cache_hits as f64 / lookups as f64,
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
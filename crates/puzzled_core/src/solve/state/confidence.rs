@@ -0,0 +1,12 @@
+/// How sure the user is of an [entry](crate::Entry), like writing in pen or pencil on a paper puzzle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Confidence {
+    /// The entry is the final guess of the user
+    #[default]
+    Pen,
+
+    /// The entry is a tentative guess, which is [styled](crate::CellStyle::PENCIL) differently until it is [confirmed](crate::Entry::confirm)
+    Pencil,
+}
@@ -1,4 +1,4 @@
-use crate::{CellStyle, Value, Word, check_style};
+use crate::{CellStyle, Confidence, Value, Word, check_style};
 use std::fmt::{self, Debug};
 
 /// Playable square that the user can enter their solution into
@@ -11,6 +11,10 @@ use std::fmt::{self, Debug};
 ///
 /// When calling these methods, the square [style](CellStyle) is updated to match the current correctness.
 /// The correctness of the entry can be checked with [`is_correct`](Self::is_correct)
+///
+/// Guesses that the user is unsure of can be [pencilled in](Self::enter_pencil) instead, after which they can be [confirmed](Self::confirm) once the user is sure.
+///
+/// An entry can be [locked](Self::lock) to prevent it from being [entered](Self::enter) or [cleared](Self::clear), e.g. when another solver owns it or the host has frozen it.
 /// ```
 /// use puzzled::core::{cell, Cell, CellStyle, Reveal};
 ///
@@ -51,6 +55,7 @@ use std::fmt::{self, Debug};
 pub struct Entry<E> {
     entry: Option<E>,
     style: CellStyle,
+    locked: bool,
 }
 
 impl<E> Entry<E> {
@@ -59,6 +64,7 @@ impl<E> Entry<E> {
     check_style!(CellStyle::INCORRECT, style, is_incorrect());
     check_style!(CellStyle::CORRECT, style, is_correct());
     check_style!(CellStyle::PREVIOUSLY_INCORRECT, style, was_incorrect());
+    check_style!(CellStyle::PENCIL, style, is_pencil());
     check_style!(
         CellStyle::INITIALLY_REVEALED,
         style,
@@ -69,7 +75,11 @@ impl<E> Entry<E> {
     check_style!(CellStyle::CIRCLED, style, is_circled());
 
     pub fn default_with_style(style: CellStyle) -> Self {
-        Self { entry: None, style }
+        Self {
+            entry: None,
+            style,
+            locked: false,
+        }
     }
 
     pub fn new(entry: Option<E>) -> Self {
@@ -80,7 +90,11 @@ impl<E> Entry<E> {
     }
 
     pub fn new_with_style(entry: Option<E>, style: CellStyle) -> Self {
-        Self { entry, style }
+        Self {
+            entry,
+            style,
+            locked: false,
+        }
     }
 
     /// Retrieve the current entry in the cell
@@ -101,6 +115,44 @@ impl<E> Entry<E> {
         self.style
     }
 
+    /// How sure the user is of the current entry
+    pub fn confidence(&self) -> Confidence {
+        match self.is_pencil() {
+            true => Confidence::Pencil,
+            false => Confidence::Pen,
+        }
+    }
+
+    /// Whether the entry is locked from being [entered](Self::enter) or [cleared](Self::clear)
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Lock the entry such that it can no longer be [entered](Self::enter) or [cleared](Self::clear)
+    ///
+    /// ```
+    /// use puzzled::core::Entry;
+    ///
+    /// let mut entry = Entry::new(Some('A'));
+    /// entry.lock();
+    ///
+    /// assert!(!entry.enter('B'));
+    /// assert!(!entry.clear());
+    /// assert_eq!(entry.entry(), Some(&'A'));
+    ///
+    /// entry.unlock();
+    /// assert!(entry.enter('B'));
+    /// assert_eq!(entry.entry(), Some(&'B'));
+    /// ```
+    pub fn lock(&mut self) {
+        self.locked = true;
+    }
+
+    /// Unlock the entry such that it can be [entered](Self::enter) or [cleared](Self::clear) again
+    pub fn unlock(&mut self) {
+        self.locked = false;
+    }
+
     pub fn map<U, F>(self, f: F) -> Entry<U>
     where
         F: FnOnce(E) -> U,
@@ -108,6 +160,7 @@ impl<E> Entry<E> {
         Entry {
             entry: self.entry.map(f),
             style: self.style,
+            locked: self.locked,
         }
     }
 
@@ -118,14 +171,17 @@ impl<E> Entry<E> {
         Entry {
             entry: self.entry.as_ref().map(f),
             style: self.style,
+            locked: self.locked,
         }
     }
 
     /// Enter a new guess to solve the cell
     /// This updates the cell [style](CellStyle) based on the [current](CellStyle::INCORRECT) and [previous](CellStyle::PREVIOUSLY_INCORRECT) correctness.
+    ///
+    /// Returns whether the guess was entered, which is not the case for revealed or [locked](Self::lock) entries
     pub fn enter<T: Into<E>>(&mut self, entry: T) -> bool {
-        // Never overwrite revealed solution
-        if self.is_revealed() || self.is_initially_revealed() {
+        // Never overwrite revealed solution or locked entries
+        if self.is_revealed() || self.is_initially_revealed() || self.locked {
             return false;
         }
 
@@ -134,16 +190,54 @@ impl<E> Entry<E> {
 
         // Clear correctness status as we can no longer be sure of it after a new entry
         self.reset_correctness();
+        self.style -= CellStyle::PENCIL;
 
         true
     }
 
+    /// Enter a new guess like [`enter`](Self::enter), but mark it as [tentative](Confidence::Pencil)
+    ///
+    /// ```
+    /// use puzzled::core::{Confidence, Entry};
+    ///
+    /// let mut entry = Entry::<char>::default();
+    /// assert!(entry.enter_pencil('A'));
+    /// assert_eq!(entry.confidence(), Confidence::Pencil);
+    ///
+    /// assert!(entry.confirm());
+    /// assert_eq!(entry.confidence(), Confidence::Pen);
+    /// assert!(!entry.confirm());
+    ///
+    /// entry.enter_pencil('B');
+    /// entry.enter('C');
+    /// assert_eq!(entry.confidence(), Confidence::Pen);
+    /// ```
+    pub fn enter_pencil<T: Into<E>>(&mut self, entry: T) -> bool {
+        let entered = self.enter(entry);
+        if entered {
+            self.style |= CellStyle::PENCIL;
+        }
+
+        entered
+    }
+
+    /// Turn a [pencilled in](Self::enter_pencil) guess into a regular one
+    ///
+    /// Returns whether the entry was pencilled in
+    pub fn confirm(&mut self) -> bool {
+        let was_pencil = self.is_pencil();
+        self.style -= CellStyle::PENCIL;
+
+        was_pencil
+    }
+
     pub fn reveal(&mut self) -> bool {
         if self.is_revealed() | self.is_initially_revealed() {
             return false;
         }
 
         self.style |= CellStyle::REVEALED;
+        self.style -= CellStyle::PENCIL;
         true
     }
 
@@ -186,12 +280,17 @@ impl<E> Entry<E> {
 
     /// Clear the current entry.
     ///
-    /// Note that this does not apply to revealed solutions
-    pub fn clear(&mut self) {
-        if !self.is_revealed() && !self.is_initially_revealed() {
-            self.entry = None;
-            self.reset_correctness();
+    /// Note that this does not apply to revealed solutions and [locked](Self::lock) entries
+    pub fn clear(&mut self) -> bool {
+        if self.is_revealed() || self.is_initially_revealed() || self.locked {
+            return false;
         }
+
+        self.entry = None;
+        self.reset_correctness();
+        self.style -= CellStyle::PENCIL;
+
+        true
     }
 }
 
@@ -219,6 +318,7 @@ impl<E> Default for Entry<E> {
         Self {
             entry: None,
             style: CellStyle::empty(),
+            locked: false,
         }
     }
 }
@@ -231,6 +331,7 @@ where
         Self {
             entry: self.entry.clone(),
             style: self.style,
+            locked: self.locked,
         }
     }
 }
@@ -270,8 +371,11 @@ mod serde_impl {
             #[serde(skip_serializing_if = "Option::is_none")]
             entry: Option<E>,
 
-            #[serde(skip_serializing_if = "CellStyle::is_empty")]
+            #[serde(default, skip_serializing_if = "CellStyle::is_empty")]
             style: CellStyle,
+
+            #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+            locked: bool,
         },
     }
 
@@ -283,12 +387,14 @@ mod serde_impl {
         pub fn to_serde(&self) -> SerdeEntry<E> {
             if let Some(ref entry) = self.entry
                 && self.style.is_empty()
+                && !self.locked
             {
                 SerdeEntry::Simple(entry.clone())
             } else {
                 SerdeEntry::Full {
                     entry: self.entry.to_owned(),
                     style: self.style,
+                    locked: self.locked,
                 }
             }
         }
@@ -302,7 +408,15 @@ mod serde_impl {
 
                     entry
                 }
-                SerdeEntry::Full { entry, style } => Self { entry, style },
+                SerdeEntry::Full {
+                    entry,
+                    style,
+                    locked,
+                } => Self {
+                    entry,
+                    style,
+                    locked,
+                },
             }
         }
     }
@@ -1,6 +1,6 @@
 use std::fmt::{self, Display};
 
-use crate::{Entry, Grid, Position, Puzzle, SolutionEntry, Solve, Square, Timer};
+use crate::{Entry, Grid, Position, Puzzle, SolutionEntry, Solve, Square, Timer, Word};
 
 #[derive(Debug)]
 pub struct GridState<P>
@@ -28,6 +28,28 @@ where
         }
     }
 
+    /// [Lock](Entry::lock) the entry at the given position.
+    /// Returns whether an entry exists at the position
+    pub fn lock(&mut self, pos: &Position) -> bool {
+        let Some(entry) = self.entries.get_mut(*pos) else {
+            return false;
+        };
+
+        entry.lock();
+        true
+    }
+
+    /// [Unlock](Entry::unlock) the entry at the given position.
+    /// Returns whether an entry exists at the position
+    pub fn unlock(&mut self, pos: &Position) -> bool {
+        let Some(entry) = self.entries.get_mut(*pos) else {
+            return false;
+        };
+
+        entry.unlock();
+        true
+    }
+
     pub fn to_merged(&self) -> Grid<SolutionEntry<'_, P::Value>> {
         self.solutions
             .join_ref(&self.entries, |solution, entry| SolutionEntry {
@@ -96,8 +118,7 @@ where
             return false;
         };
 
-        entry.enter(value);
-        true
+        entry.enter(value)
     }
 
     fn clear(&mut self, pos: &Position) -> bool {
@@ -105,8 +126,7 @@ where
             return false;
         };
 
-        entry.clear();
-        true
+        entry.clear()
     }
 
     fn reveal(&mut self, pos: &Position) -> bool {
@@ -124,7 +144,7 @@ where
         };
 
         let entry = self.entries.get_mut(*pos)?;
-        let is_correct = entry.entry().map(|s| s == solution)?;
+        let is_correct = entry.entry().map(|s| s.matches(solution))?;
 
         if is_correct {
             entry.mark_correct();
@@ -177,6 +197,28 @@ where
         }
     }
 
+    /// [Lock](Entry::lock) the entry at the given position.
+    /// Returns whether a playable entry exists at the position
+    pub fn lock(&mut self, pos: &Position) -> bool {
+        let Some(entry) = self.entries.get_fill_mut(*pos) else {
+            return false;
+        };
+
+        entry.lock();
+        true
+    }
+
+    /// [Unlock](Entry::unlock) the entry at the given position.
+    /// Returns whether a playable entry exists at the position
+    pub fn unlock(&mut self, pos: &Position) -> bool {
+        let Some(entry) = self.entries.get_fill_mut(*pos) else {
+            return false;
+        };
+
+        entry.unlock();
+        true
+    }
+
     pub fn to_merged(&self) -> Grid<Square<SolutionEntry<'_, P::Value>>> {
         let data: Vec<_> = self
             .solutions
@@ -259,8 +301,7 @@ where
             return false;
         };
 
-        entry.enter(value);
-        true
+        entry.enter(value)
     }
 
     fn clear(&mut self, pos: &Position) -> bool {
@@ -268,8 +309,7 @@ where
             return false;
         };
 
-        entry.clear();
-        true
+        entry.clear()
     }
 
     fn reveal(&mut self, pos: &Position) -> bool {
@@ -287,7 +327,7 @@ where
         };
 
         let entry = self.entries.get_fill_mut(*pos)?;
-        let is_correct = entry.entry().map(|s| s == solution)?;
+        let is_correct = entry.entry().map(|s| s.matches(solution))?;
 
         if is_correct {
             entry.mark_correct();
@@ -1,9 +1,11 @@
+mod confidence;
 mod entry;
 mod grid;
 mod lattice;
 
 use std::fmt::{self, Display};
 
+pub use confidence::*;
 pub use entry::*;
 pub use grid::*;
 
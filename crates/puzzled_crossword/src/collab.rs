@@ -0,0 +1,275 @@
+//! Conflict-free collaborative solving of [crosswords](crate::Crossword)
+//!
+//! Every client keeps an [operation log](OperationLog) to which it [records](OperationLog::record) its own operations and [receives](OperationLog::receive) the operations of others, over whatever transport it likes.
+//! Operations are ordered by their [Lamport timestamp](https://en.wikipedia.org/wiki/Lamport_timestamp) and actor, such that all clients that have seen the same operations [apply](CrosswordState::apply_log) them to the same state:
+//! - The latest entry or clear of a square wins
+//! - Reveals are permanent, regardless of when they happened
+//! ```
+//! use puzzled::crossword::{
+//!     crossword,
+//!     collab::{OperationKind, OperationLog},
+//!     CrosswordState, Position, Solution, Solve,
+//! };
+//!
+//! let puzzle = crossword!([A B]);
+//! let pos = Position::new(0, 0);
+//!
+//! let mut alice = OperationLog::new(1);
+//! let mut bob = OperationLog::new(2);
+//!
+//! // Both actors enter a letter into the same square before syncing
+//! let op = alice.record(pos, OperationKind::Enter(Solution::Letter('A')));
+//! bob.record(pos, OperationKind::Enter(Solution::Letter('X')));
+//! bob.receive(op);
+//! alice.merge(&bob);
+//!
+//! let mut alice_state = CrosswordState::from(&puzzle);
+//! let mut bob_state = CrosswordState::from(&puzzle);
+//! alice_state.apply_log(&alice);
+//! bob_state.apply_log(&bob);
+//!
+//! assert_eq!(alice_state.entry(&pos), Some(&Solution::Letter('X')));
+//! assert_eq!(alice_state.entry(&pos), bob_state.entry(&pos));
+//! ```
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+};
+
+use puzzled_core::{Position, Solve};
+
+use crate::{CrosswordState, Solution};
+
+/// Identifier of a client that collaborates on a [crossword](crate::Crossword)
+pub type ActorId = u32;
+
+/// Change to a single square that was made by an actor
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OperationKind {
+    /// [Enter](Solve::enter) a solution into the square
+    Enter(Solution),
+
+    /// [Clear](Solve::clear) the entry of the square
+    Clear,
+
+    /// [Reveal](Solve::reveal) the solution of the square
+    Reveal,
+}
+
+/// [Operation](OperationKind) on a square, together with who made it and when
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub pos: Position,
+    pub kind: OperationKind,
+    pub actor: ActorId,
+
+    /// Lamport timestamp of the operation, which exceeds the timestamps of all operations the actor had seen
+    pub timestamp: u64,
+}
+
+impl Operation {
+    /// Key that totally orders all operations, where ties in timestamps are broken by actor
+    pub fn order(&self) -> (u64, ActorId) {
+        (self.timestamp, self.actor)
+    }
+}
+
+/// Log of the [operations](Operation) that an actor made and received, see the [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationLog {
+    actor: ActorId,
+    clock: u64,
+    operations: BTreeMap<(u64, ActorId), Operation>,
+}
+
+impl OperationLog {
+    pub fn new(actor: ActorId) -> Self {
+        Self {
+            actor,
+            clock: 0,
+            operations: BTreeMap::new(),
+        }
+    }
+
+    /// Actor that records operations into the log
+    pub fn actor(&self) -> ActorId {
+        self.actor
+    }
+
+    /// Highest timestamp that the log has seen
+    pub fn clock(&self) -> u64 {
+        self.clock
+    }
+
+    /// Record an operation of the actor of the log, returning it so it can be sent to the other actors
+    pub fn record(&mut self, pos: Position, kind: OperationKind) -> Operation {
+        self.clock += 1;
+
+        let op = Operation {
+            pos,
+            kind,
+            actor: self.actor,
+            timestamp: self.clock,
+        };
+        self.operations.insert(op.order(), op.clone());
+
+        op
+    }
+
+    /// Receive an operation from another actor, returning whether it was not seen before
+    pub fn receive(&mut self, op: Operation) -> bool {
+        self.clock = self.clock.max(op.timestamp);
+
+        match self.operations.contains_key(&op.order()) {
+            true => false,
+            false => {
+                self.operations.insert(op.order(), op);
+                true
+            }
+        }
+    }
+
+    /// Receive all operations of another log
+    pub fn merge(&mut self, other: &OperationLog) {
+        for op in other.operations() {
+            self.receive(op.clone());
+        }
+    }
+
+    /// Returns an iterator over all operations in the order they are applied
+    pub fn operations(&self) -> impl Iterator<Item = &Operation> {
+        self.operations.values()
+    }
+
+    /// Returns an iterator over the operations that happened after the given timestamp, e.g. to only send those that another actor has not seen yet
+    pub fn operations_since(&self, timestamp: u64) -> impl Iterator<Item = &Operation> {
+        self.operations
+            .range((Bound::Excluded((timestamp, ActorId::MAX)), Bound::Unbounded))
+            .map(|(_, op)| op)
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+impl CrosswordState {
+    /// Apply the operations of a log, such that every state that applies the same operations ends up with the same entries
+    ///
+    /// Every square keeps the latest entry or clear and is revealed if any actor revealed it.
+    /// The state should only be solved through the log, as other changes to the affected squares are overwritten.
+    pub fn apply_log(&mut self, log: &OperationLog) {
+        let mut latest: BTreeMap<Position, &Solution> = BTreeMap::new();
+        let mut cleared = BTreeSet::new();
+        let mut revealed = BTreeSet::new();
+
+        for op in log.operations() {
+            match &op.kind {
+                OperationKind::Enter(solution) => {
+                    cleared.remove(&op.pos);
+                    latest.insert(op.pos, solution);
+                }
+                OperationKind::Clear => {
+                    latest.remove(&op.pos);
+                    cleared.insert(op.pos);
+                }
+                OperationKind::Reveal => {
+                    revealed.insert(op.pos);
+                }
+            }
+        }
+
+        for pos in cleared {
+            self.clear(&pos);
+        }
+        for (pos, solution) in latest {
+            if self.entry(&pos) != Some(solution) {
+                self.enter(&pos, solution.clone());
+            }
+        }
+        for pos in revealed {
+            self.reveal(&pos);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Crossword, crossword};
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [C A T]
+            [A . O]
+        )
+    }
+
+    fn entries(state: &CrosswordState, puzzle: &Crossword) -> Vec<Option<Solution>> {
+        puzzle
+            .squares()
+            .positions()
+            .map(|pos| state.entry(&pos).cloned())
+            .collect()
+    }
+
+    #[test]
+    fn converges() {
+        let puzzle = puzzle();
+        let (first, second) = (Position::new(0, 0), Position::new(0, 2));
+
+        let mut alice = OperationLog::new(1);
+        let mut bob = OperationLog::new(2);
+
+        alice.record(first, OperationKind::Enter(Solution::Letter('X')));
+        alice.record(second, OperationKind::Reveal);
+        bob.record(first, OperationKind::Clear);
+        bob.record(second, OperationKind::Enter(Solution::Letter('Y')));
+        bob.record(first, OperationKind::Enter(Solution::Letter('Z')));
+
+        let mut left = alice.clone();
+        left.merge(&bob);
+        let mut right = bob.clone();
+        right.merge(&alice);
+
+        assert!(left.operations().eq(right.operations()));
+
+        let mut left_state = CrosswordState::from(&puzzle);
+        let mut right_state = CrosswordState::from(&puzzle);
+        left_state.apply_log(&left);
+        right_state.apply_log(&right);
+
+        assert_eq!(
+            entries(&left_state, &puzzle),
+            entries(&right_state, &puzzle)
+        );
+        assert_eq!(left_state.entry(&first), Some(&Solution::Letter('Z')));
+    }
+
+    #[test]
+    fn clock() {
+        let pos = Position::new(1, 0);
+
+        let mut alice = OperationLog::new(1);
+        let mut bob = OperationLog::new(2);
+
+        for _ in 0..3 {
+            alice.record(pos, OperationKind::Clear);
+        }
+        let op = bob.record(pos, OperationKind::Reveal);
+        assert_eq!(op.timestamp, 1);
+
+        bob.merge(&alice);
+        assert!(!bob.receive(op));
+        assert_eq!(bob.record(pos, OperationKind::Clear).timestamp, 4);
+        assert_eq!(bob.operations_since(3).count(), 1);
+        assert_eq!(bob.operations_since(u64::MAX).count(), 0);
+        assert_eq!(bob.len(), 5);
+    }
+}
@@ -0,0 +1,211 @@
+//! Fill-in puzzles, where words from a list are fitted into a grid instead of being clued
+//!
+//! A [crisscross](Crisscross), also known as a skeleton or fill-in puzzle, shares its [squares](Squares) with a [crossword](crate::Crossword).
+//! Rather than clues, the solver is given every word of the solution, which it should place such that all crossing words agree:
+//! ```
+//! use puzzled::crossword::{crisscross::Crisscross, patterns::Pattern, Metadata, Position};
+//!
+//! let squares = Pattern::from_rows(["---", "-.-", "---"]).unwrap().to_squares();
+//! let mut puzzle = Crisscross::new(squares, ["CAT", "TOE", "COB", "BEE"], Metadata::default());
+//!
+//! puzzle.fill()?;
+//!
+//! let letter = |row, col| puzzle.squares().get_fill(Position::new(row, col)).and_then(|cell| cell.solution.clone());
+//! assert_eq!(letter(0, 0), Some("C".into()));
+//! assert_eq!(letter(2, 2), Some("E".into()));
+//! # Ok::<(), puzzled::crossword::crisscross::CrisscrossError>(())
+//! ```
+mod solve;
+
+pub use solve::*;
+
+use std::fmt;
+
+use puzzled_core::{Grid, Metadata, Position, Puzzle, Square};
+
+use crate::{ClueDirection, CrosswordSquares, Solution, Squares};
+
+/// Fill-in puzzle of a grid together with the words that should be placed in it
+///
+/// Squares may already have a [solution](crate::CrosswordCell::solution), which is given to the solver as a starting point.
+/// Every character of a word fills a single square, such that words should not contain spaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crisscross {
+    squares: Squares,
+    words: Vec<String>,
+    meta: Metadata,
+}
+
+impl Puzzle for Crisscross {
+    const NAME: &'static str = "Crisscross";
+
+    type Solution = Grid<Square<Solution>>;
+    type Position = Position;
+    type Value = Solution;
+}
+
+impl Crisscross {
+    pub fn new<I, S>(squares: Squares, words: I, meta: Metadata) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let words = words.into_iter().map(Into::into).collect();
+
+        Self {
+            squares,
+            words,
+            meta,
+        }
+    }
+
+    pub fn squares(&self) -> &Squares {
+        &self.squares
+    }
+
+    pub fn squares_mut(&mut self) -> &mut Squares {
+        &mut self.squares
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    pub fn meta(&self) -> &Metadata {
+        &self.meta
+    }
+
+    pub fn meta_mut(&mut self) -> &mut Metadata {
+        &mut self.meta
+    }
+
+    /// Every run of at least two white squares, which should each be filled by one of the [words](Self::words)
+    ///
+    /// Slots are ordered by the position of their first square and then by their direction.
+    pub fn slots(&self) -> Vec<Slot> {
+        let mut slots = Vec::new();
+
+        for start in self.squares.positions() {
+            for direction in [ClueDirection::Across, ClueDirection::Down] {
+                if !self.squares.can_clue_start_in_dir(start, direction) {
+                    continue;
+                }
+
+                let len = self.squares.find_clue_len(start, direction) as usize;
+                if len >= Slot::MIN_LEN {
+                    slots.push(Slot {
+                        start,
+                        direction,
+                        len,
+                    });
+                }
+            }
+        }
+
+        slots
+    }
+}
+
+impl fmt::Display for Crisscross {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.squares)?;
+        for word in &self.words {
+            writeln!(f, "{word}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{}", self.meta)?;
+
+        Ok(())
+    }
+}
+
+/// Run of white squares in a [crisscross](Crisscross) that is filled by a single word
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Slot {
+    pub start: Position,
+    pub direction: ClueDirection,
+    pub len: usize,
+}
+
+impl Slot {
+    /// Smallest number of squares that form a slot, as single squares are only part of crossing words
+    pub const MIN_LEN: usize = 2;
+
+    /// Every position that the slot covers
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.len).map(move |offset| {
+            self.start
+                .step(self.direction.into(), offset)
+                .expect("Slots run to the right or down")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_io::TxtPuzzle;
+
+    use super::*;
+    use crate::patterns::Pattern;
+
+    #[test]
+    fn slots() {
+        let squares = Pattern::from_rows(["---.", "-.--", "--.-"])
+            .unwrap()
+            .to_squares();
+        let puzzle = Crisscross::new(squares, Vec::<String>::new(), Metadata::default());
+
+        let slots: Vec<_> = puzzle
+            .slots()
+            .into_iter()
+            .map(|slot| (slot.start, slot.direction, slot.len))
+            .collect();
+
+        assert_eq!(
+            slots,
+            [
+                (Position::new(0, 0), ClueDirection::Across, 3),
+                (Position::new(0, 0), ClueDirection::Down, 3),
+                (Position::new(0, 2), ClueDirection::Down, 2),
+                (Position::new(1, 2), ClueDirection::Across, 2),
+                (Position::new(1, 3), ClueDirection::Down, 2),
+                (Position::new(2, 0), ClueDirection::Across, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn text() {
+        let squares = Pattern::from_rows(["---", "-.-", "---"])
+            .unwrap()
+            .to_squares();
+        let meta = Metadata::default().with_title("Skeleton".into());
+        let mut puzzle = Crisscross::new(squares, ["CAT", "TOE", "COB", "BEE"], meta);
+
+        let text = puzzle.write_text();
+        assert!(text.contains("- \"COB\""));
+        assert_eq!(Crisscross::read_text(&text).ok(), Some(puzzle.clone()));
+
+        puzzle.fill().unwrap();
+        assert_eq!(
+            Crisscross::read_text(&puzzle.write_text()).ok(),
+            Some(puzzle)
+        );
+    }
+
+    #[test]
+    fn text_words() {
+        let squares = Pattern::from_rows(["---"]).unwrap().to_squares();
+        let meta = Metadata::default().with_extra("editor".into(), "Ada".into());
+        let puzzle = Crisscross::new(squares, ["A\"B\\C"], meta);
+
+        let text = puzzle.write_text();
+        assert!(text.contains(r#"- "A\"B\\C""#));
+        assert!(text.contains("editor: \"Ada\""));
+        assert_eq!(Crisscross::read_text(&text).ok(), Some(puzzle));
+
+        let text = "[A B C]\n- \"abc\"";
+        let puzzle = Crisscross::read_text(text).unwrap();
+        assert_eq!(puzzle.words(), ["ABC"]);
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::{BTreeMap, HashMap, hash_map::Entry};
+
+use puzzled_core::Position;
+
+use crate::{
+    Solution,
+    crisscross::{Crisscross, Slot},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CrisscrossError {
+    #[error(
+        "{words} words of length {len} were given, but the grid has {slots} slots of that length"
+    )]
+    LengthMismatch {
+        len: usize,
+        words: usize,
+        slots: usize,
+    },
+
+    #[error("Words cannot be placed in the grid such that all crossing words agree")]
+    Unsolvable,
+}
+
+/// Word of a [crisscross](Crisscross) that was placed in one of its [slots](Slot)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub slot: Slot,
+    pub word: String,
+}
+
+impl Crisscross {
+    /// Fit every word into a slot, such that the letters of crossing words and the solutions already in the grid agree
+    ///
+    /// Words are placed by backtracking over the slot with the fewest fitting words, which quickly settles well-constrained grids.
+    /// Placements are ordered like the [slots](Self::slots) they fill.
+    /// If the words can be placed in multiple ways, only the first solution is returned.
+    pub fn solve(&self) -> Result<Vec<Placement>, CrisscrossError> {
+        let slots = self.slots();
+        let words: Vec<Vec<Solution>> = self
+            .words()
+            .iter()
+            .map(|word| word.chars().map(Solution::Letter).collect())
+            .collect();
+
+        // Every slot should be filled by exactly one word of the same length
+        let mut lens: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+        for word in &words {
+            lens.entry(word.len()).or_default().0 += 1;
+        }
+        for slot in &slots {
+            lens.entry(slot.len).or_default().1 += 1;
+        }
+        if let Some((&len, &(words, slots))) =
+            lens.iter().find(|(_, (words, slots))| words != slots)
+        {
+            return Err(CrisscrossError::LengthMismatch { len, words, slots });
+        }
+
+        let letters = self
+            .squares()
+            .positions()
+            .filter_map(|pos| {
+                let solution = self.squares().get_fill(pos)?.solution.as_ref()?;
+                Some((pos, solution.primary().clone()))
+            })
+            .collect();
+
+        let mut solver = Solver {
+            slots: &slots,
+            words: &words,
+            letters,
+            filled: vec![None; slots.len()],
+            used: vec![false; words.len()],
+        };
+
+        if !solver.solve() {
+            return Err(CrisscrossError::Unsolvable);
+        }
+
+        let placements = slots
+            .iter()
+            .zip(solver.filled)
+            .map(|(slot, word)| {
+                let word = word.expect("Solved slots are all filled");
+
+                Placement {
+                    slot: *slot,
+                    word: self.words()[word].clone(),
+                }
+            })
+            .collect();
+
+        Ok(placements)
+    }
+
+    /// [Solve](Self::solve) the puzzle and write the letters of the placed words into the squares
+    pub fn fill(&mut self) -> Result<(), CrisscrossError> {
+        let placements = self.solve()?;
+
+        for Placement { slot, word } in placements {
+            for (pos, letter) in slot.positions().zip(word.chars()) {
+                if let Some(cell) = self.squares_mut().get_fill_mut(pos) {
+                    cell.solution = Some(Solution::Letter(letter));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// State of the search for a placement of every word
+struct Solver<'a> {
+    slots: &'a [Slot],
+    words: &'a [Vec<Solution>],
+
+    /// Letters in the grid, from the placed words and the initial solutions
+    letters: HashMap<Position, Solution>,
+
+    /// Index of the word that fills every slot
+    filled: Vec<Option<usize>>,
+    used: Vec<bool>,
+}
+
+impl Solver<'_> {
+    fn solve(&mut self) -> bool {
+        // Continue with the slot that the fewest words fit into
+        let next = (0..self.slots.len())
+            .filter(|&slot| self.filled[slot].is_none())
+            .map(|slot| (slot, self.candidates(slot)))
+            .min_by_key(|(_, candidates)| candidates.len());
+
+        let Some((slot, candidates)) = next else {
+            return true;
+        };
+
+        for word in candidates {
+            let placed = self.place(slot, word);
+
+            if self.solve() {
+                return true;
+            }
+
+            for pos in placed {
+                self.letters.remove(&pos);
+            }
+            self.filled[slot] = None;
+            self.used[word] = false;
+        }
+
+        false
+    }
+
+    /// Unused words that fit into the slot, skipping duplicates of the same word
+    fn candidates(&self, slot: usize) -> Vec<usize> {
+        let slot = &self.slots[slot];
+        let mut candidates: Vec<usize> = Vec::new();
+
+        for (idx, word) in self.words.iter().enumerate() {
+            if self.used[idx] || word.len() != slot.len {
+                continue;
+            }
+            if candidates.iter().any(|&other| self.words[other] == *word) {
+                continue;
+            }
+
+            let fits = slot
+                .positions()
+                .zip(word)
+                .all(|(pos, letter)| self.letters.get(&pos).is_none_or(|found| found == letter));
+
+            if fits {
+                candidates.push(idx);
+            }
+        }
+
+        candidates
+    }
+
+    /// Place the word into the slot, returning the positions of the letters that were added to the grid
+    fn place(&mut self, slot: usize, word: usize) -> Vec<Position> {
+        self.filled[slot] = Some(word);
+        self.used[word] = true;
+
+        let mut placed = Vec::new();
+        for (pos, letter) in self.slots[slot].positions().zip(&self.words[word]) {
+            if let Entry::Vacant(entry) = self.letters.entry(pos) {
+                entry.insert(letter.clone());
+                placed.push(pos);
+            }
+        }
+
+        placed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Metadata;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection, patterns::Pattern};
+
+    fn puzzle(rows: &[&str], words: &[&str]) -> Crisscross {
+        let squares = Pattern::from_rows(rows).unwrap().to_squares();
+        Crisscross::new(squares, words.iter().copied(), Metadata::default())
+    }
+
+    fn grid(puzzle: &Crisscross) -> Vec<String> {
+        let squares = puzzle.squares();
+
+        (0..squares.rows())
+            .map(|row| {
+                (0..squares.cols())
+                    .map(|col| match squares.get_fill(Position::new(row, col)) {
+                        Some(cell) => cell.solution.as_ref().map_or('-', Solution::first_letter),
+                        None => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fill() {
+        let mut puzzle = puzzle(
+            &["---.", "----", "---."],
+            &["BET", "ORES", "COW", "CAB", "WET", "ARE"],
+        );
+
+        puzzle.fill().unwrap();
+
+        assert_eq!(grid(&puzzle), ["CAB.", "ORES", "WET."]);
+    }
+
+    #[test]
+    fn givens() {
+        // Without the given letter, the words could be swapped
+        let mut puzzle = puzzle(&["---", "-.-", "---"], &["CAT", "TOE", "COB", "BEE"]);
+        if let Some(cell) = puzzle.squares_mut().get_fill_mut(Position::new(0, 1)) {
+            cell.solution = Some(Solution::Letter('O'));
+        }
+
+        let placements = puzzle.solve().unwrap();
+        let across: Vec<_> = placements
+            .iter()
+            .filter(|placement| placement.slot.direction == ClueDirection::Across)
+            .map(|placement| placement.word.as_str())
+            .collect();
+
+        assert_eq!(across, ["COB", "TOE"]);
+    }
+
+    #[rstest]
+    #[case::count(&["CAT", "TOE", "COB"], CrisscrossError::LengthMismatch { len: 3, words: 3, slots: 4 })]
+    #[case::length(&["CAT", "TOE", "COB", "BE"], CrisscrossError::LengthMismatch { len: 2, words: 1, slots: 0 })]
+    #[case::crossing(&["CAT", "TOE", "COB", "EEL"], CrisscrossError::Unsolvable)]
+    fn errors(#[case] words: &[&str], #[case] expected: CrisscrossError) {
+        let puzzle = puzzle(&["---", "-.-", "---"], words);
+
+        assert_eq!(puzzle.solve(), Err(expected));
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::BTreeMap;
+
+use puzzled_core::{Cell, CellStyle, Grid, Metadata, Position, Square};
+
+use crate::{ClueSpec, Crossword, Solution};
+
+/// Header of the original Across Lite text format
+const HEADER_V1: &str = "<ACROSS PUZZLE>";
+
+/// Header of the second version of the format, which adds rebus squares and circle markup
+const HEADER_V2: &str = "<ACROSS PUZZLE V2>";
+
+/// Square that is blocked in the grid
+const BLOCK: char = '.';
+
+/// Flag in the rebus section that marks lowercase letters in the grid as circled squares
+const MARK: &str = "MARK;";
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AcrossLiteError {
+    #[error("Across Lite text should start with {HEADER_V1} or {HEADER_V2}, found '{found}'")]
+    InvalidHeader { found: String },
+
+    #[error("Section <{name}> is not part of the Across Lite text format")]
+    UnknownSection { name: String },
+
+    #[error("Section <{name}> is required, but is missing")]
+    MissingSection { name: &'static str },
+
+    #[error("Size '{found}' should be written as <cols>x<rows>")]
+    InvalidSize { found: String },
+
+    #[error(
+        "Grid has size {rows}x{cols}, but its size is given as {expected_rows}x{expected_cols}"
+    )]
+    SizeMismatch {
+        rows: usize,
+        cols: usize,
+        expected_rows: usize,
+        expected_cols: usize,
+    },
+
+    #[error("Row {row} of the grid has {found} squares, but the first row has {expected}")]
+    RaggedRow {
+        row: usize,
+        found: usize,
+        expected: usize,
+    },
+
+    #[error("Square '{found}' at {pos:?} is neither a letter, block nor rebus")]
+    InvalidSquare { pos: Position, found: char },
+
+    #[error("Rebus '{found}' should be written as <key>:<rebus>:<letter>")]
+    InvalidRebus { found: String },
+
+    #[error("{count} clue(s) could not be placed in the grid")]
+    UnplacedClues { count: usize },
+}
+
+impl Crossword {
+    /// Read a crossword from the [Across Lite text format](https://www.litsoft.com/across/docs/AcrossTextFormat.pdf)
+    ///
+    /// The text is split into sections that start with a `<NAME>` tag, such as `<TITLE>`, `<GRID>`, `<ACROSS>` and `<DOWN>`.
+    /// Only `<GRID>` is required, while the grid is checked against `<SIZE>` if it is given.
+    /// Puzzles in the `V2` format can define a `<REBUS>` section, which maps symbols in the grid to their rebus and can `MARK;` lowercase letters as circled squares.
+    /// ```
+    /// use puzzled::crossword::{crossword, Crossword, CellStyle, Position, Solution};
+    ///
+    /// let text = "
+    /// <ACROSS PUZZLE V2>
+    /// <TITLE>
+    ///     Alphabet soup
+    /// <AUTHOR>
+    ///     Eric
+    /// <SIZE>
+    ///     2x2
+    /// <GRID>
+    ///     a1
+    ///     C.
+    /// <REBUS>
+    ///     MARK;
+    ///     1:BC:B
+    /// <ACROSS>
+    ///     The first letters of the alphabet
+    /// <DOWN>
+    ///     Keep it short, but cool
+    ///     The second and third letter
+    /// <NOTEPAD>
+    ///     Have fun!
+    /// ";
+    ///
+    /// let puzzle = Crossword::from_across_lite(text)?;
+    /// assert_eq!(puzzle.meta().title(), Some("Alphabet soup"));
+    /// assert_eq!(puzzle.clues().len(), 3);
+    ///
+    /// let cell = puzzle.squares()[Position::new(0, 0)].as_ref().unwrap();
+    /// assert!(cell.style.contains(CellStyle::CIRCLED));
+    ///
+    /// let cell = puzzle.squares()[Position::new(0, 1)].as_ref().unwrap();
+    /// assert_eq!(cell.solution, Some(Solution::Rebus("BC".into())));
+    /// # Ok::<(), puzzled::crossword::AcrossLiteError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "text")))]
+    pub fn from_across_lite(text: &str) -> Result<Crossword, AcrossLiteError> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let header = lines.next().unwrap_or_default();
+        if header != HEADER_V1 && header != HEADER_V2 {
+            return Err(AcrossLiteError::InvalidHeader {
+                found: header.to_string(),
+            });
+        }
+
+        // Gather the lines of every section
+        let mut sections: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        let mut section = None;
+
+        for line in lines {
+            match line
+                .strip_prefix('<')
+                .and_then(|line| line.strip_suffix('>'))
+            {
+                Some(name) => {
+                    section = Some(name);
+                    sections.entry(name).or_default();
+                }
+                None => {
+                    if let Some(name) = section {
+                        sections.entry(name).or_default().push(line);
+                    }
+                }
+            }
+        }
+
+        if let Some(name) = sections.keys().find(|name| !SECTIONS.contains(name)) {
+            return Err(AcrossLiteError::UnknownSection {
+                name: name.to_string(),
+            });
+        }
+
+        let section = |name: &str| sections.get(name).map(Vec::as_slice).unwrap_or_default();
+
+        // Read the grid, replacing symbols by their rebus
+        let rebus = read_rebus(section("REBUS"))?;
+        let squares = read_grid(section("GRID"), &rebus)?;
+
+        if let Some(size) = section("SIZE").first() {
+            let (cols, rows) = read_size(size)?;
+
+            if (rows, cols) != (squares.rows(), squares.cols()) {
+                return Err(AcrossLiteError::SizeMismatch {
+                    rows: squares.rows(),
+                    cols: squares.cols(),
+                    expected_rows: rows,
+                    expected_cols: cols,
+                });
+            }
+        }
+
+        // Read the metadata and place the clues
+        let mut meta = Metadata::default();
+        if let Some(title) = read_line(section("TITLE")) {
+            meta = meta.with_title(title);
+        }
+        if let Some(author) = read_line(section("AUTHOR")) {
+            meta = meta.with_author(author);
+        }
+        if let Some(copyright) = read_line(section("COPYRIGHT")) {
+            meta = meta.with_copyright(copyright);
+        }
+        if !section("NOTEPAD").is_empty() {
+            meta = meta.with_notes(section("NOTEPAD").join("\n"));
+        }
+
+        let across = section("ACROSS").iter().map(|text| ClueSpec::across(*text));
+        let down = section("DOWN").iter().map(|text| ClueSpec::down(*text));
+
+        let mut puzzle = Crossword::from_squares(squares, meta);
+        let unplaced = puzzle.insert_clues(across.chain(down));
+
+        match unplaced.len() {
+            0 => Ok(puzzle),
+            count => Err(AcrossLiteError::UnplacedClues { count }),
+        }
+    }
+}
+
+/// Sections that can be defined in the format
+const SECTIONS: [&str; 9] = [
+    "TITLE",
+    "AUTHOR",
+    "COPYRIGHT",
+    "SIZE",
+    "GRID",
+    "REBUS",
+    "ACROSS",
+    "DOWN",
+    "NOTEPAD",
+];
+
+/// Squares of the rebus section and whether lowercase letters are circled
+struct Rebus {
+    marked: bool,
+    solutions: BTreeMap<char, String>,
+}
+
+fn read_rebus(lines: &[&str]) -> Result<Rebus, AcrossLiteError> {
+    let mut rebus = Rebus {
+        marked: false,
+        solutions: BTreeMap::new(),
+    };
+
+    for &line in lines {
+        if line.eq_ignore_ascii_case(MARK) {
+            rebus.marked = true;
+            continue;
+        }
+
+        let invalid = || AcrossLiteError::InvalidRebus {
+            found: line.to_string(),
+        };
+
+        // The fallback letter is only used by solvers that do not support rebuses
+        let mut parts = line.splitn(3, ':');
+        let (Some(key), Some(solution), Some(_)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+
+        let mut key_chars = key.chars();
+        let (Some(key), None) = (key_chars.next(), key_chars.next()) else {
+            return Err(invalid());
+        };
+        if solution.is_empty() {
+            return Err(invalid());
+        }
+
+        rebus.solutions.insert(key, solution.to_uppercase());
+    }
+
+    Ok(rebus)
+}
+
+fn read_grid(
+    lines: &[&str],
+    rebus: &Rebus,
+) -> Result<Grid<Square<Cell<Solution>>>, AcrossLiteError> {
+    let Some(first) = lines.first() else {
+        return Err(AcrossLiteError::MissingSection { name: "GRID" });
+    };
+
+    let cols = first.chars().count();
+    let mut squares = Vec::with_capacity(lines.len() * cols);
+
+    for (row, line) in lines.iter().enumerate() {
+        let found = line.chars().count();
+        if found != cols {
+            return Err(AcrossLiteError::RaggedRow {
+                row,
+                found,
+                expected: cols,
+            });
+        }
+
+        for (col, char) in line.chars().enumerate() {
+            let square = match char {
+                BLOCK => Square::new_empty(),
+                char if rebus.solutions.contains_key(&char) => {
+                    let solution = Solution::from(rebus.solutions[&char].as_str());
+                    Square::new(Cell::new(Some(solution)))
+                }
+                char if char.is_alphabetic() => {
+                    let style = match rebus.marked && char.is_lowercase() {
+                        true => CellStyle::CIRCLED,
+                        false => CellStyle::default(),
+                    };
+                    let letter = char.to_uppercase().next().unwrap_or(char);
+
+                    Square::new(Cell::new_with_style(Some(Solution::Letter(letter)), style))
+                }
+                found => {
+                    let pos = Position::new(row, col);
+                    return Err(AcrossLiteError::InvalidSquare { pos, found });
+                }
+            };
+
+            squares.push(square);
+        }
+    }
+
+    Ok(Grid::from_vec(squares, cols).expect("Rows have the same number of squares"))
+}
+
+fn read_size(size: &str) -> Result<(usize, usize), AcrossLiteError> {
+    let invalid = || AcrossLiteError::InvalidSize {
+        found: size.to_string(),
+    };
+
+    let (cols, rows) = size.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let cols = cols.trim().parse().map_err(|_| invalid())?;
+    let rows = rows.trim().parse().map_err(|_| invalid())?;
+
+    Ok((cols, rows))
+}
+
+fn read_line(lines: &[&str]) -> Option<String> {
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection::*, crossword};
+
+    const MINI: &str = "
+        <ACROSS PUZZLE>
+        <TITLE>
+            Mini
+        <AUTHOR>
+            Eric
+        <COPYRIGHT>
+            2026
+        <SIZE>
+            3x2
+        <GRID>
+            CAT
+            O.O
+        <ACROSS>
+            Pet
+        <DOWN>
+            Soda
+            Also
+        <NOTEPAD>
+            First line
+            Second line
+    ";
+
+    #[test]
+    fn mini() {
+        let puzzle = Crossword::from_across_lite(MINI).unwrap();
+        let expected = crossword!(
+            [C A T]
+            [O . O]
+            - A: "Pet"
+            - D: "Soda"
+            - D: "Also"
+        );
+
+        assert_eq!(puzzle.squares(), expected.squares());
+        assert_eq!(puzzle.clues(), expected.clues());
+        assert_eq!(puzzle.clues()[&(2, Down).into()].text(), "Also");
+
+        let meta = puzzle.meta();
+        assert_eq!(meta.title(), Some("Mini"));
+        assert_eq!(meta.author(), Some("Eric"));
+        assert_eq!(meta.copyright(), Some("2026"));
+        assert_eq!(meta.notes(), Some("First line\nSecond line"));
+    }
+
+    #[test]
+    fn rebus() {
+        let text = "
+            <ACROSS PUZZLE V2>
+            <GRID>
+                h1
+            <REBUS>
+                MARK;
+                1:eart:E
+            <ACROSS>
+                Organ
+        ";
+        let puzzle = Crossword::from_across_lite(text).unwrap();
+
+        let first = puzzle.squares()[Position::new(0, 0)].as_ref().unwrap();
+        assert_eq!(first.solution, Some(Solution::Letter('H')));
+        assert!(first.style.contains(CellStyle::CIRCLED));
+
+        let second = puzzle.squares()[Position::new(0, 1)].as_ref().unwrap();
+        assert_eq!(second.solution, Some(Solution::Rebus("EART".into())));
+        assert!(!second.style.contains(CellStyle::CIRCLED));
+    }
+
+    #[rstest]
+    #[case::header("<PUZZLE>\n<GRID>\nA", AcrossLiteError::InvalidHeader { found: "<PUZZLE>".into() })]
+    #[case::section("<ACROSS PUZZLE>\n<GRID>\nA\n<HINTS>", AcrossLiteError::UnknownSection { name: "HINTS".into() })]
+    #[case::no_grid("<ACROSS PUZZLE>\n<TITLE>\nEmpty", AcrossLiteError::MissingSection { name: "GRID" })]
+    #[case::size("<ACROSS PUZZLE>\n<SIZE>\n3\n<GRID>\nABC", AcrossLiteError::InvalidSize { found: "3".into() })]
+    #[case::size_mismatch(
+        "<ACROSS PUZZLE>\n<SIZE>\n2x1\n<GRID>\nABC",
+        AcrossLiteError::SizeMismatch { rows: 1, cols: 3, expected_rows: 1, expected_cols: 2 }
+    )]
+    #[case::ragged("<ACROSS PUZZLE>\n<GRID>\nAB\nC", AcrossLiteError::RaggedRow { row: 1, found: 1, expected: 2 })]
+    #[case::square("<ACROSS PUZZLE>\n<GRID>\nA1", AcrossLiteError::InvalidSquare { pos: Position::new(0, 1), found: '1' })]
+    #[case::rebus("<ACROSS PUZZLE V2>\n<GRID>\nA1\n<REBUS>\n1:B", AcrossLiteError::InvalidRebus { found: "1:B".into() })]
+    #[case::unplaced("<ACROSS PUZZLE>\n<GRID>\nAB\n<ACROSS>\nAB\nCD", AcrossLiteError::UnplacedClues { count: 1 })]
+    fn errors(#[case] text: &str, #[case] err: AcrossLiteError) {
+        assert_eq!(Crossword::from_across_lite(text), Err(err));
+    }
+}
@@ -0,0 +1,380 @@
+use puzzled_core::MISSING_ENTRY_CHAR;
+
+use crate::{Clue, ClueDirection, ClueId, Crossword};
+
+/// Columns of every row in a clue list, which is also written as its header
+const HEADER: [&str; 4] = ["number", "direction", "answer", "clue"];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ClueListError {
+    #[error("Quoted field starting on line {line} is never closed")]
+    UnterminatedQuote { line: usize },
+
+    #[error("Row on line {line} has {found} fields, but should have {}", HEADER.len())]
+    InvalidRow { line: usize, found: usize },
+
+    #[error("Clue number '{found}' on line {line} is not a valid number")]
+    InvalidNumber { line: usize, found: String },
+
+    #[error("Direction '{found}' on line {line} should be A(cross) or D(own)")]
+    InvalidDirection { line: usize, found: String },
+
+    #[error("Clue {id} on line {line} is not part of the crossword")]
+    UnknownClue { line: usize, id: ClueId },
+}
+
+/// Row of a clue list, which identifies a clue together with its answer and text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClueRow {
+    pub id: ClueId,
+    pub answer: String,
+    pub text: String,
+}
+
+/// Writer that exports the clues of a [crossword](Crossword) as a list of `number, direction, answer, clue` rows
+///
+/// By default, the list is written as CSV with a header row, such that it can be edited in a spreadsheet and read back with a [`ClueListReader`].
+/// Fields are quoted when they contain the delimiter, a quote or a line break.
+/// ```
+/// use puzzled::crossword::{crossword, ClueListWriter};
+///
+/// let puzzle = crossword!(
+///     [A B]
+///     [C .]
+///     - A: "The first two letters, e.g."
+///     - D: "Keep it short"
+/// );
+///
+/// let csv = ClueListWriter::new().write(&puzzle);
+/// assert_eq!(csv, "number,direction,answer,clue\n1,A,AB,\"The first two letters, e.g.\"\n1,D,AC,Keep it short\n");
+///
+/// let tsv = ClueListWriter::tsv().write(&puzzle);
+/// assert!(tsv.ends_with("1\tD\tAC\tKeep it short\n"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClueListWriter {
+    delimiter: char,
+}
+
+impl Default for ClueListWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClueListWriter {
+    /// Create a writer for comma-separated values
+    pub fn new() -> Self {
+        Self { delimiter: ',' }
+    }
+
+    /// Create a writer for tab-separated values
+    pub fn tsv() -> Self {
+        Self { delimiter: '\t' }
+    }
+
+    /// Define the character that separates the fields of a row
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Write the clues in order of their [identifier](ClueId), where squares without a solution are written as `-` in the answer
+    pub fn write(&self, crossword: &Crossword) -> String {
+        let mut list = self.write_row(HEADER.map(String::from));
+
+        for clue in crossword.clues().values() {
+            let row = [
+                clue.num().to_string(),
+                clue.direction().to_string(),
+                answer(crossword, clue),
+                clue.text().clone(),
+            ];
+
+            list += &self.write_row(row);
+        }
+
+        list
+    }
+
+    fn write_row(&self, fields: [String; 4]) -> String {
+        let fields: Vec<_> = fields
+            .iter()
+            .map(|field| {
+                let needs_quotes = field
+                    .chars()
+                    .any(|char| char == self.delimiter || matches!(char, '"' | '\n' | '\r'));
+
+                match needs_quotes {
+                    true => format!("\"{}\"", field.replace('"', "\"\"")),
+                    false => field.clone(),
+                }
+            })
+            .collect();
+
+        fields.join(&self.delimiter.to_string()) + "\n"
+    }
+}
+
+/// Reader that imports a list of `number, direction, answer, clue` rows, such as one written by a [`ClueListWriter`]
+///
+/// The header row and empty rows are skipped, while the direction can be written as `A`/`D` or `Across`/`Down` in any case.
+/// ```
+/// use puzzled::crossword::{crossword, ClueDirection::*, ClueListReader};
+///
+/// let mut puzzle = crossword!(
+///     [A B]
+///     [C .]
+///     - A: "AB"
+///     - D: "AC"
+/// );
+///
+/// let csv = "number,direction,answer,clue
+/// 1,Across,AB,\"Start of the alphabet, \"\"in short\"\"\"
+/// 1,down,AC,Keep it short
+/// ";
+///
+/// let changed = ClueListReader::new().apply(csv, &mut puzzle)?;
+/// assert_eq!(changed, 2);
+/// assert_eq!(puzzle.clues()[&(1, Across).into()].text(), "Start of the alphabet, \"in short\"");
+/// # Ok::<(), puzzled::crossword::ClueListError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClueListReader {
+    delimiter: char,
+}
+
+impl Default for ClueListReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClueListReader {
+    /// Create a reader for comma-separated values
+    pub fn new() -> Self {
+        Self { delimiter: ',' }
+    }
+
+    /// Create a reader for tab-separated values
+    pub fn tsv() -> Self {
+        Self { delimiter: '\t' }
+    }
+
+    /// Define the character that separates the fields of a row
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Read the rows of the clue list
+    pub fn read(&self, text: &str) -> Result<Vec<ClueRow>, ClueListError> {
+        let rows = self.read_rows(text)?;
+        Ok(rows.into_iter().map(|(_, row)| row).collect())
+    }
+
+    /// Replace the text of the clues of the crossword by the text of the rows, returning how many clues changed
+    ///
+    /// Rows are matched with clues by their number and direction, where the answer is only informative and is not read back.
+    /// If any row does not match a clue, no clue is changed.
+    pub fn apply(&self, text: &str, crossword: &mut Crossword) -> Result<usize, ClueListError> {
+        let rows = self.read_rows(text)?;
+
+        if let Some((line, row)) = rows
+            .iter()
+            .find(|(_, row)| !crossword.clues().contains_key(&row.id))
+        {
+            return Err(ClueListError::UnknownClue {
+                line: *line,
+                id: row.id,
+            });
+        }
+
+        let mut changed = 0;
+        for (_, row) in rows {
+            if crossword.clues()[&row.id].text() != &row.text {
+                crossword.clues_mut().retext(row.id, row.text);
+                changed += 1;
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Read the rows of the clue list, together with the line each row starts on
+    fn read_rows(&self, text: &str) -> Result<Vec<(usize, ClueRow)>, ClueListError> {
+        let mut rows = Vec::new();
+
+        for (idx, (line, fields)) in self.records(text)?.into_iter().enumerate() {
+            if fields.iter().all(String::is_empty) {
+                continue;
+            }
+            if idx == 0 && fields[0].trim().eq_ignore_ascii_case(HEADER[0]) {
+                continue;
+            }
+
+            let found = fields.len();
+            let Ok([num, direction, answer, text]) = <[String; 4]>::try_from(fields) else {
+                return Err(ClueListError::InvalidRow { line, found });
+            };
+
+            let num = num
+                .trim()
+                .parse()
+                .map_err(|_| ClueListError::InvalidNumber { line, found: num })?;
+
+            let direction = match direction.trim().to_lowercase().as_str() {
+                "a" | "across" => ClueDirection::Across,
+                "d" | "down" => ClueDirection::Down,
+                _ => {
+                    return Err(ClueListError::InvalidDirection {
+                        line,
+                        found: direction,
+                    });
+                }
+            };
+
+            let id = ClueId { num, direction };
+            rows.push((line, ClueRow { id, answer, text }));
+        }
+
+        Ok(rows)
+    }
+
+    /// Split the text into records of fields, together with the line each record starts on
+    fn records(&self, text: &str) -> Result<Vec<(usize, Vec<String>)>, ClueListError> {
+        let mut records = Vec::new();
+        let mut fields = Vec::new();
+        let mut field = String::new();
+
+        let mut chars = text.chars().peekable();
+        let mut line = 1;
+        let mut start = 1;
+        let mut quoted = false;
+
+        while let Some(char) = chars.next() {
+            match char {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' if quoted => quoted = false,
+                '"' if field.is_empty() => quoted = true,
+                '\n' if quoted => {
+                    field.push(char);
+                    line += 1;
+                }
+                '\n' => {
+                    fields.push(std::mem::take(&mut field));
+                    records.push((start, std::mem::take(&mut fields)));
+
+                    line += 1;
+                    start = line;
+                }
+                '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+                char if char == self.delimiter && !quoted => {
+                    fields.push(std::mem::take(&mut field));
+                }
+                char => field.push(char),
+            }
+        }
+
+        if quoted {
+            return Err(ClueListError::UnterminatedQuote { line: start });
+        }
+        if !field.is_empty() || !fields.is_empty() {
+            fields.push(field);
+            records.push((start, fields));
+        }
+
+        Ok(records)
+    }
+}
+
+/// Answer of the clue as spelled out by the solutions of its squares
+fn answer(crossword: &Crossword, clue: &Clue) -> String {
+    clue.positions()
+        .map(|pos| {
+            let solution = crossword
+                .squares()
+                .get_fill(pos)
+                .and_then(|cell| cell.solution.as_ref());
+
+            match solution {
+                Some(solution) => solution.primary().to_string(),
+                None => MISSING_ENTRY_CHAR.to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Position;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection::*, crossword};
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [C A T]
+            [A . O]
+            - A: "Pet"
+            - D: "Taxi"
+            - D: "Also"
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut edited = puzzle();
+        edited
+            .clues_mut()
+            .retext((1, Down), "Yellow \"ride\",\nfor short");
+
+        let csv = ClueListWriter::new().write(&edited);
+        assert!(csv.contains("1,D,CA,\"Yellow \"\"ride\"\",\nfor short\"\n"));
+
+        let mut puzzle = puzzle();
+        let changed = ClueListReader::new().apply(&csv, &mut puzzle).unwrap();
+
+        assert_eq!(changed, 1);
+        assert_eq!(puzzle.clues(), edited.clues());
+    }
+
+    #[test]
+    fn tsv() {
+        let tsv = "1\tA\tCAT\tFeline\r\n\r\n2\tD\tTO\tToward\r\n";
+        let rows = ClueListReader::tsv().read(tsv).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].id, (2, Down).into());
+        assert_eq!(rows[1].answer, "TO");
+        assert_eq!(rows[1].text, "Toward");
+    }
+
+    #[test]
+    fn missing_answer() {
+        let mut puzzle = puzzle();
+        if let Some(cell) = puzzle.squares_mut()[Position::new(0, 1)].as_mut() {
+            cell.solution = None;
+        }
+
+        let csv = ClueListWriter::new().write(&puzzle);
+        assert!(csv.contains("1,A,C-T,Pet\n"));
+    }
+
+    #[rstest]
+    #[case::quote("1,A,CAT,\"Pet", ClueListError::UnterminatedQuote { line: 1 })]
+    #[case::row("number,direction\n1,A,CAT", ClueListError::InvalidRow { line: 2, found: 3 })]
+    #[case::number("one,A,CAT,Pet", ClueListError::InvalidNumber { line: 1, found: "one".into() })]
+    #[case::direction("1,Diagonal,CAT,Pet", ClueListError::InvalidDirection { line: 1, found: "Diagonal".into() })]
+    #[case::unknown("1,A,CAT,Feline\n3,A,O,Letter", ClueListError::UnknownClue { line: 2, id: (3, Across).into() })]
+    fn errors(#[case] text: &str, #[case] err: ClueListError) {
+        let mut puzzle = puzzle();
+
+        assert_eq!(ClueListReader::new().apply(text, &mut puzzle), Err(err));
+        assert_eq!(puzzle.clues()[&(1, Across).into()].text(), "Pet");
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Write;
+
+use puzzled_core::{CellBorders, CellStyle, Position};
+
+use crate::{ClueDirection, Crossword, Solution};
+
+/// Square that is blocked (or omitted) from the grid
+const BLOCK: char = '.';
+
+/// Square of which the solution is not known
+const UNKNOWN: char = '0';
+
+impl Crossword {
+    /// Write the crossword as an [Exolve](https://github.com/viresh-ratnakar/exolve) block, which can be embedded in a web page to solve the puzzle interactively
+    ///
+    /// The metadata is written to the prelude, with the notes as the preamble of the puzzle.
+    /// Squares are separated by spaces and followed by their decorators: `|` for a bar on the right, `_` for a bar below, `+` for both and `@` for a circle.
+    /// As Exolve squares hold a single letter, a rebus is written as its first letter.
+    /// ```
+    /// use puzzled::crossword::crossword;
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "The first two letters of the alphabet"
+    ///     - D: "Keep it short, but cool"
+    ///     title: "Alphabet"
+    /// );
+    ///
+    /// let exolve = puzzle.to_exolve();
+    /// assert!(exolve.starts_with("exolve-begin\n"));
+    /// assert!(exolve.contains("  exolve-title: Alphabet\n"));
+    /// assert!(exolve.contains("  exolve-grid:\n    A B\n    C .\n"));
+    /// assert!(exolve.contains("  exolve-across:\n    1 The first two letters of the alphabet (2)\n"));
+    /// assert!(exolve.ends_with("exolve-end\n"));
+    /// ```
+    pub fn to_exolve(&self) -> String {
+        let squares = self.squares();
+        let meta = self.meta();
+
+        let mut exolve = String::from("exolve-begin\n");
+        let fields = [
+            ("title", meta.title()),
+            ("setter", meta.author()),
+            ("copyright", meta.copyright()),
+        ];
+
+        for (key, value) in fields {
+            if let Some(value) = value {
+                let _ = writeln!(exolve, "  exolve-{key}: {}", single_line(value));
+            }
+        }
+
+        let _ = writeln!(exolve, "  exolve-width: {}", squares.cols());
+        let _ = writeln!(exolve, "  exolve-height: {}", squares.rows());
+
+        if let Some(notes) = meta.notes() {
+            exolve += "  exolve-preamble:\n";
+            for line in notes.lines() {
+                let _ = writeln!(exolve, "    {line}");
+            }
+        }
+
+        exolve += "  exolve-grid:\n";
+        for row in 0..squares.rows() {
+            let row: Vec<_> = (0..squares.cols())
+                .map(|col| self.exolve_square(Position { row, col }))
+                .collect();
+
+            let _ = writeln!(exolve, "    {}", row.join(" "));
+        }
+
+        for (direction, name) in [
+            (ClueDirection::Across, "across"),
+            (ClueDirection::Down, "down"),
+        ] {
+            let _ = writeln!(exolve, "  exolve-{name}:");
+
+            for clue in self.clues().iter_direction(direction) {
+                let text = single_line(clue.text());
+                let _ = writeln!(exolve, "    {} {text} ({})", clue.num(), clue.len());
+            }
+        }
+
+        exolve += "exolve-end\n";
+        exolve
+    }
+
+    /// Square of the Exolve grid at the position, together with its decorators
+    fn exolve_square(&self, pos: Position) -> String {
+        let squares = self.squares();
+        let Some(cell) = squares.get_fill(pos) else {
+            return BLOCK.to_string();
+        };
+
+        let mut square = match cell.solution.as_ref().map(Solution::primary) {
+            Some(Solution::Letter(letter)) => letter.to_string(),
+            Some(Solution::Rebus(rebus)) => rebus.chars().take(1).collect(),
+            _ => UNKNOWN.to_string(),
+        };
+
+        // Bars on the left and top of a square are drawn by its neighbor
+        let has_bar = |pos: Position, border: CellBorders| {
+            squares
+                .get_fill(pos)
+                .is_some_and(|cell| cell.decoration.bars.contains(border))
+        };
+        let right = Position::new(pos.row, pos.col + 1);
+        let below = Position::new(pos.row + 1, pos.col);
+
+        let bar_right = has_bar(pos, CellBorders::RIGHT) || has_bar(right, CellBorders::LEFT);
+        let bar_below = has_bar(pos, CellBorders::BOTTOM) || has_bar(below, CellBorders::TOP);
+
+        match (bar_right, bar_below) {
+            (true, true) => square.push('+'),
+            (true, false) => square.push('|'),
+            (false, true) => square.push('_'),
+            (false, false) => {}
+        }
+
+        if cell.style.contains(CellStyle::CIRCLED) {
+            square.push('@');
+        }
+
+        square
+    }
+}
+
+/// Join the lines of the text, as Exolve fields and clues span a single line
+fn single_line(text: &str) -> String {
+    text.lines().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::{CellBorders, CellDecoration, CellStyle, Position};
+
+    use crate::crossword;
+
+    #[test]
+    fn prelude() {
+        let puzzle = crossword!(
+            [A B]
+            - A: "AB"
+            title: "Mini"
+            author: "Eric"
+            notes: "First line\nSecond line"
+        );
+
+        let exolve = puzzle.to_exolve();
+
+        assert!(exolve.contains("  exolve-setter: Eric\n"));
+        assert!(exolve.contains("  exolve-width: 2\n  exolve-height: 1\n"));
+        assert!(exolve.contains("  exolve-preamble:\n    First line\n    Second line\n"));
+        assert!(!exolve.contains("exolve-copyright"));
+    }
+
+    #[test]
+    fn decorators() {
+        let mut puzzle = crossword!(
+            [A B]
+            [C D]
+        );
+
+        let squares = puzzle.squares_mut();
+        if let Some(cell) = squares[Position::new(0, 0)].as_mut() {
+            cell.decoration = CellDecoration::default().with_bars(CellBorders::BOTTOM);
+            cell.style |= CellStyle::CIRCLED;
+        }
+        if let Some(cell) = squares[Position::new(0, 1)].as_mut() {
+            cell.decoration = CellDecoration::default().with_bars(CellBorders::LEFT);
+        }
+        if let Some(cell) = squares[Position::new(1, 1)].as_mut() {
+            cell.decoration = CellDecoration::default().with_bars(CellBorders::TOP);
+        }
+
+        let exolve = puzzle.to_exolve();
+
+        assert!(exolve.contains("  exolve-grid:\n    A+@ B_\n    C D\n"));
+    }
+
+    #[test]
+    fn clues() {
+        let puzzle = crossword!(
+            [C A .]
+            [A G E]
+            - A: "Is able to"
+            - A: "Length of life"
+            - D: "Automobile"
+            - D: "Gone"
+        );
+
+        let exolve = puzzle.to_exolve();
+
+        assert!(
+            exolve.contains("  exolve-across:\n    1 Is able to (2)\n    3 Length of life (3)\n")
+        );
+        assert!(exolve.contains("  exolve-down:\n    1 Automobile (2)\n    2 Gone (2)\n"));
+    }
+}
@@ -0,0 +1,792 @@
+use puzzled_core::{
+    BitGrid, Cell, CellBorders, CellDecoration, CellShape, CellStyle, Color, Grid, Metadata,
+    Position, Square,
+};
+use puzzled_io::{Diagnostic, DiagnosticCode, Severity};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    ClueDirection, ClueSpec, Crossword, NumberingScheme, Solution, ThemeEntry, io::grid_squares,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IpuzError {
+    #[error("JSON is not well-formed at line {line}, column {column}")]
+    InvalidJson { line: usize, column: usize },
+
+    #[error("Puzzle of kind {kind:?} is not a crossword")]
+    UnsupportedPuzzle { kind: Vec<String> },
+
+    #[error("Field '{field}' of the puzzle is missing")]
+    MissingField { field: String },
+
+    #[error("Field '{field}' of the puzzle is not valid")]
+    InvalidField { field: String },
+
+    #[error("Grid of {rows}x{cols} squares is too large to be read")]
+    GridTooLarge { rows: usize, cols: usize },
+}
+
+const VERSION: &str = "http://ipuz.org/v2";
+const KIND: &str = "http://ipuz.org/crossword#1";
+
+/// Value of blocks in the puzzle and solution grids
+const BLOCK: &str = "#";
+
+/// Extension field that holds the [theme entries](crate::ThemeEntry), named after the domain of the project as the ipuz spec requires
+const THEME_ENTRIES: &str = "io.github.eevdriet.puzzled:themeentries";
+
+/// Fields of the document that are read into the puzzle, such that they are not kept as [custom properties](puzzled_core::Metadata::extra)
+const FIELDS: [&str; 14] = [
+    "version",
+    "kind",
+    "dimensions",
+    "puzzle",
+    "solution",
+    "clues",
+    "block",
+    "empty",
+    "title",
+    "author",
+    "copyright",
+    "notes",
+    "date",
+    THEME_ENTRIES,
+];
+
+impl Crossword {
+    /// Convert the crossword into an [ipuz](http://ipuz.org/) document
+    ///
+    /// Squares are written with their number (or `0` if they start no clue) and [decorated](puzzled_core::CellDecoration) squares are given a style with their shape, background color and bars.
+    /// How squares and clues are labeled follows the [numbering scheme](NumberingScheme): separately numbered squares that start two clues are labeled as `across/down` and with coordinate labels all squares are written as `0`.
+    /// Squares that are [omitted](puzzled_core::Grid::has_cell) from the grid are written as `null`.
+    /// [Theme entries](crate::ThemeEntry) are written to an extension field, with their squares as `[col, row]` coordinates starting from 1 like the clues of ipuz.
+    /// [Custom properties](puzzled_core::Metadata::extra) are written as top-level fields, unless their key is already used by the document.
+    /// ```
+    /// use puzzled::crossword::crossword;
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "The first two letters of the alphabet"
+    ///     - D: "Keep it short, but cool"
+    /// );
+    ///
+    /// let ipuz = puzzle.to_ipuz();
+    /// assert_eq!(ipuz["puzzle"][1][1], "#");
+    /// assert_eq!(ipuz["clues"]["Across"][0][1], "The first two letters of the alphabet");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipuz")))]
+    pub fn to_ipuz(&self) -> Value {
+        let squares = self.squares();
+        let clues = self.clues();
+        let numbering = self.numbering();
+
+        let mut puzzle = Vec::with_capacity(squares.rows());
+        let mut solution = Vec::with_capacity(squares.rows());
+
+        for row in 0..squares.rows() {
+            let mut puzzle_row = Vec::with_capacity(squares.cols());
+            let mut solution_row = Vec::with_capacity(squares.cols());
+
+            for col in 0..squares.cols() {
+                let pos = Position { row, col };
+
+                let Some(square) = squares.get(pos) else {
+                    puzzle_row.push(Value::Null);
+                    solution_row.push(Value::Null);
+                    continue;
+                };
+
+                let Some(cell) = square.as_ref() else {
+                    puzzle_row.push(json!(BLOCK));
+                    solution_row.push(json!(BLOCK));
+                    continue;
+                };
+
+                let num = match numbering.square_label(clues, pos) {
+                    Some(label) => label.parse::<u8>().map_or(json!(label), |num| json!(num)),
+                    None => json!(0),
+                };
+                puzzle_row.push(match style(cell) {
+                    Some(style) => json!({ "cell": num, "style": style }),
+                    None => num,
+                });
+
+                let letters = cell.solution.as_ref().map(|solution| solution.to_string());
+                solution_row.push(json!(letters));
+            }
+
+            puzzle.push(puzzle_row);
+            solution.push(solution_row);
+        }
+
+        let clue_list = |direction: ClueDirection| -> Vec<Value> {
+            clues
+                .iter_direction(direction)
+                .map(|clue| match numbering {
+                    NumberingScheme::Coordinates => json!([numbering.label(clue), clue.text()]),
+                    _ => json!([clue.num(), clue.text()]),
+                })
+                .collect()
+        };
+
+        let mut ipuz = Map::new();
+        ipuz.insert("version".into(), json!(VERSION));
+        ipuz.insert("kind".into(), json!([KIND]));
+        ipuz.insert(
+            "dimensions".into(),
+            json!({ "width": squares.cols(), "height": squares.rows() }),
+        );
+        ipuz.insert("puzzle".into(), json!(puzzle));
+        ipuz.insert("solution".into(), json!(solution));
+        ipuz.insert(
+            "clues".into(),
+            json!({
+                "Across": clue_list(ClueDirection::Across),
+                "Down": clue_list(ClueDirection::Down),
+            }),
+        );
+
+        if !self.theme_entries().is_empty() {
+            let entries: Vec<_> = self
+                .theme_entries()
+                .iter()
+                .map(|entry| {
+                    let cells: Vec<_> = entry
+                        .positions()
+                        .iter()
+                        .map(|pos| json!([pos.col + 1, pos.row + 1]))
+                        .collect();
+
+                    json!({ "name": entry.name(), "cells": cells })
+                })
+                .collect();
+
+            ipuz.insert(THEME_ENTRIES.into(), json!(entries));
+        }
+
+        let meta = self.meta();
+        let fields = [
+            ("title", meta.title()),
+            ("author", meta.author()),
+            ("copyright", meta.copyright()),
+            ("notes", meta.notes()),
+        ];
+
+        for (key, value) in fields {
+            if let Some(value) = value {
+                ipuz.insert(key.into(), json!(value));
+            }
+        }
+
+        // Dates are written as `MM/DD/YYYY`, as the ipuz spec requires
+        #[cfg(feature = "chrono")]
+        if let Some(date) = meta.date() {
+            ipuz.insert("date".into(), json!(date.format("%m/%d/%Y").to_string()));
+        }
+
+        for (key, value) in meta.extra() {
+            ipuz.entry(key.as_str()).or_insert_with(|| json!(value));
+        }
+
+        Value::Object(ipuz)
+    }
+
+    /// Read a crossword from an [ipuz](http://ipuz.org/) document, as it is written by [`Crossword::to_ipuz`]
+    ///
+    /// Squares that are `null` are [omitted](puzzled_core::Grid::has_cell) from the grid and the style of a square gives its shape, background color and bars.
+    /// Clues are placed in the order of their lists, where the numbers and labels of the document decide the [numbering scheme](NumberingScheme) of the crossword.
+    /// Top-level fields that the reader does not know are kept as [custom properties](puzzled_core::Metadata::extra).
+    ///
+    /// Features that cannot be represented, such as named styles and clue lists in other directions, are left out with a [warning](Severity::Warning).
+    /// ```
+    /// use puzzled::crossword::{Crossword, crossword};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "The first two letters of the alphabet"
+    ///     - D: "Keep it short, but cool"
+    /// );
+    ///
+    /// let ipuz = puzzle.to_ipuz().to_string();
+    /// let (read, diagnostics) = Crossword::from_ipuz(&ipuz)?;
+    ///
+    /// assert!(diagnostics.is_empty());
+    /// assert_eq!(read, puzzle);
+    /// # Ok::<(), puzzled::crossword::IpuzError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipuz")))]
+    pub fn from_ipuz(json: &str) -> Result<(Crossword, Vec<Diagnostic>), IpuzError> {
+        let ipuz: Value = serde_json::from_str(json).map_err(|err| IpuzError::InvalidJson {
+            line: err.line(),
+            column: err.column(),
+        })?;
+        let mut diagnostics = Vec::new();
+
+        let kind: Vec<String> = ipuz
+            .get("kind")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|kind| kind.as_str().map(str::to_string))
+            .collect();
+        if !kind
+            .iter()
+            .any(|kind| kind.starts_with("http://ipuz.org/crossword"))
+        {
+            return Err(IpuzError::UnsupportedPuzzle { kind });
+        }
+
+        let dimension = |name: &str| {
+            let dimension =
+                field(&ipuz, "dimensions")?
+                    .get(name)
+                    .ok_or_else(|| IpuzError::MissingField {
+                        field: format!("dimensions.{name}"),
+                    })?;
+
+            dimension
+                .as_u64()
+                .and_then(|dimension| usize::try_from(dimension).ok())
+                .ok_or_else(|| invalid("dimensions"))
+        };
+        let cols = dimension("width")?;
+        let rows = dimension("height")?;
+        let len = grid_squares(rows, cols).ok_or(IpuzError::GridTooLarge { rows, cols })?;
+
+        let block = ipuz.get("block").and_then(Value::as_str).unwrap_or(BLOCK);
+        let puzzle = field(&ipuz, "puzzle")?;
+        let solution = ipuz.get("solution");
+
+        let mut squares = Vec::with_capacity(len);
+        let mut mask = BitGrid::new(rows, cols).map_err(|_| invalid("dimensions"))?;
+        let mut is_separate = false;
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let pos = Position { row, col };
+                let value = puzzle
+                    .get(row)
+                    .and_then(|puzzle_row| puzzle_row.get(col))
+                    .ok_or_else(|| invalid("puzzle"))?;
+
+                let (label, style) = match value {
+                    Value::Object(cell) => {
+                        (cell.get("cell").unwrap_or(&Value::Null), cell.get("style"))
+                    }
+                    value => (value, None),
+                };
+
+                if value.is_null() {
+                    squares.push(Square::new_empty());
+                    continue;
+                }
+                mask.set(pos, true);
+
+                if label.as_str() == Some(block) {
+                    squares.push(Square::new_empty());
+                    continue;
+                }
+                is_separate |= label.as_str().is_some_and(|label| label.contains('/'));
+
+                let letters = solution
+                    .and_then(|solution| solution.get(row)?.get(col))
+                    .and_then(|letters| match letters {
+                        Value::Object(letters) => letters.get("value")?.as_str(),
+                        letters => letters.as_str(),
+                    })
+                    .filter(|&letters| !letters.is_empty() && letters != block);
+
+                let mut cell = Cell::new(letters.map(Solution::from));
+                if let Some(style) = style {
+                    read_style(&mut cell, style, pos, &mut diagnostics)?;
+                }
+
+                squares.push(Square::new(cell));
+            }
+        }
+
+        let mut squares = Grid::from_vec(squares, cols).map_err(|_| invalid("puzzle"))?;
+        if mask.count_ones() < len {
+            squares = squares.with_mask(mask).map_err(|_| invalid("puzzle"))?;
+        }
+        let mut puzzle = Crossword::from_squares(squares, read_meta(&ipuz)?);
+
+        // Clues are listed with their number, or their coordinate label when the grid has no numbers
+        let mut specs = Vec::new();
+        let mut labels = Vec::new();
+
+        if let Some(lists) = ipuz.get("clues") {
+            let lists = lists.as_object().ok_or_else(|| invalid("clues"))?;
+
+            for (name, list) in lists {
+                let direction = match name.split(':').next() {
+                    Some("Across") => ClueDirection::Across,
+                    Some("Down") => ClueDirection::Down,
+                    _ => {
+                        diagnostics.push(unsupported(format!("Clue list '{name}' is left out")));
+                        continue;
+                    }
+                };
+
+                for clue in list.as_array().ok_or_else(|| invalid("clues"))? {
+                    let (label, text) = match clue {
+                        Value::Array(clue) => (clue.first(), clue.get(1)),
+                        Value::Object(clue) => (clue.get("number"), clue.get("clue")),
+                        text => (None, Some(text)),
+                    };
+                    let text = text
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| invalid("clues"))?;
+
+                    labels.push((direction, label.cloned()));
+                    specs.push(ClueSpec::new(direction, text));
+                }
+            }
+        }
+
+        for spec in puzzle.insert_clues(specs) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                DiagnosticCode::IpuzMissingClue,
+                format!(
+                    "{} clue '{}' could not be placed",
+                    spec.direction(),
+                    spec.text()
+                ),
+            ));
+        }
+
+        // Separately numbered grids only label squares with both numbers when they differ, so the clue numbers are compared as well
+        let is_coordinates = labels.iter().any(|(_, label)| {
+            label
+                .as_ref()
+                .and_then(Value::as_str)
+                .is_some_and(|label| label.parse::<u8>().is_err())
+        });
+        let is_separate = is_separate || {
+            let numbers = |direction| -> Option<Vec<u64>> {
+                labels
+                    .iter()
+                    .filter(|(dir, _)| *dir == direction)
+                    .map(|(_, label)| label.as_ref()?.as_u64())
+                    .collect()
+            };
+            let placed = |direction| -> Vec<u64> {
+                puzzle
+                    .clues()
+                    .iter_direction(direction)
+                    .map(|clue| u64::from(clue.num()))
+                    .collect()
+            };
+
+            [ClueDirection::Across, ClueDirection::Down]
+                .into_iter()
+                .any(|direction| {
+                    numbers(direction).is_some_and(|numbers| numbers != placed(direction))
+                })
+        };
+
+        if is_coordinates {
+            puzzle.set_numbering(NumberingScheme::Coordinates);
+        } else if is_separate {
+            puzzle.set_numbering(NumberingScheme::Separate);
+        }
+
+        if let Some(entries) = ipuz.get(THEME_ENTRIES) {
+            for entry in entries.as_array().ok_or_else(|| invalid(THEME_ENTRIES))? {
+                let entry = read_theme_entry(entry).ok_or_else(|| invalid(THEME_ENTRIES))?;
+                puzzle
+                    .add_theme_entry(entry)
+                    .map_err(|_| invalid(THEME_ENTRIES))?;
+            }
+        }
+
+        Ok((puzzle, diagnostics))
+    }
+}
+
+/// Field of the document that the puzzle cannot be read without
+fn field<'a>(ipuz: &'a Value, name: &str) -> Result<&'a Value, IpuzError> {
+    ipuz.get(name).ok_or_else(|| IpuzError::MissingField {
+        field: name.to_string(),
+    })
+}
+
+fn invalid(field: &str) -> IpuzError {
+    IpuzError::InvalidField {
+        field: field.to_string(),
+    }
+}
+
+fn unsupported(message: String) -> Diagnostic {
+    Diagnostic::new(
+        Severity::Warning,
+        DiagnosticCode::IpuzUnsupportedFeature,
+        message,
+    )
+}
+
+/// Metadata of the puzzle from the fields of the document, where unknown fields with text become [custom properties](puzzled_core::Metadata::extra)
+fn read_meta(ipuz: &Value) -> Result<Metadata, IpuzError> {
+    let text = |name: &str| ipuz.get(name).and_then(Value::as_str).map(str::to_string);
+    let mut meta = Metadata::default();
+
+    if let Some(title) = text("title") {
+        meta = meta.with_title(title);
+    }
+    if let Some(author) = text("author") {
+        meta = meta.with_author(author);
+    }
+    if let Some(copyright) = text("copyright") {
+        meta = meta.with_copyright(copyright);
+    }
+    if let Some(notes) = text("notes") {
+        meta = meta.with_notes(notes);
+    }
+
+    #[cfg(feature = "chrono")]
+    if let Some(date) = text("date") {
+        let date = puzzled_core::find_date(&date).ok_or_else(|| invalid("date"))?;
+        meta = meta.with_date(date);
+    }
+
+    let fields = ipuz.as_object().into_iter().flatten();
+    for (key, value) in fields.filter(|(key, _)| !FIELDS.contains(&key.as_str())) {
+        if let Some(value) = value.as_str() {
+            meta.insert_extra(key.clone(), value.to_string());
+        }
+    }
+
+    Ok(meta)
+}
+
+/// Apply the style of a square to its cell, as it is written by [`style`]
+fn read_style(
+    cell: &mut Cell<Solution>,
+    style: &Value,
+    pos: Position,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), IpuzError> {
+    let Some(style) = style.as_object() else {
+        diagnostics.push(unsupported(format!("Named style at {pos:?} is left out")));
+        return Ok(());
+    };
+
+    let mut decoration = CellDecoration::default();
+
+    match style.get("shapebg").and_then(Value::as_str) {
+        None => {}
+        Some("circle") => cell.style |= CellStyle::CIRCLED,
+        Some(shape) => match CellShape::from_name(shape) {
+            Some(shape) => decoration = decoration.with_shape(shape),
+            None => diagnostics.push(unsupported(format!(
+                "Background shape '{shape}' at {pos:?} is left out"
+            ))),
+        },
+    }
+
+    if let Some(color) = style.get("color").and_then(Value::as_str) {
+        let color = Color::hex(color).map_err(|_| invalid("puzzle"))?;
+        decoration = decoration.with_background(color);
+    }
+
+    if let Some(bars) = style.get("barred").and_then(Value::as_str) {
+        decoration = decoration.with_bars(CellBorders::from_letters(bars));
+    }
+
+    cell.decoration = decoration;
+    Ok(())
+}
+
+/// Theme entry from its name and `[col, row]` coordinates starting from 1, as they are written by [`Crossword::to_ipuz`]
+fn read_theme_entry(entry: &Value) -> Option<ThemeEntry> {
+    let name = entry.get("name")?.as_str()?;
+    let positions = entry
+        .get("cells")?
+        .as_array()?
+        .iter()
+        .map(|coord| {
+            let col = usize::try_from(coord.get(0)?.as_u64()?).ok()?;
+            let row = usize::try_from(coord.get(1)?.as_u64()?).ok()?;
+
+            Some(Position::new(row.checked_sub(1)?, col.checked_sub(1)?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ThemeEntry::new(name, positions))
+}
+
+/// Style of a cell, if it is displayed differently from plain cells
+fn style(cell: &Cell<Solution>) -> Option<Value> {
+    let mut style = Map::new();
+
+    if let Some(shape) = cell.shape() {
+        style.insert("shapebg".into(), json!(shape.name()));
+    }
+
+    if let Some(color) = cell.decoration.background {
+        let hex = color.to_hex();
+        style.insert("color".into(), json!(hex.trim_start_matches('#')));
+    }
+
+    if !cell.decoration.bars.is_empty() {
+        style.insert("barred".into(), json!(cell.decoration.bars.letters()));
+    }
+
+    (!style.is_empty()).then_some(Value::Object(style))
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::{
+        BitGrid, CellBorders, CellDecoration, CellShape, CellStyle, Color, Position,
+    };
+    use puzzled_io::DiagnosticCode;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+
+    use crate::{Crossword, IpuzError, NumberingScheme, ThemeEntry, crossword};
+
+    #[test]
+    fn grids() {
+        let puzzle = crossword!(
+            [C A .]
+            [A G E]
+            [. O W]
+            - A: "Is able to"
+            - A: "Length of life"
+            - A: "Stick (out)"
+            - D: "Automobile"
+            - D: "Gone"
+            - D: "Female sheep"
+        );
+
+        let ipuz = puzzle.to_ipuz();
+
+        assert_eq!(ipuz["dimensions"], json!({ "width": 3, "height": 3 }));
+        assert_eq!(ipuz["puzzle"][0], json!([1, 2, "#"]));
+        assert_eq!(ipuz["puzzle"][1], json!([3, 0, 4]));
+        assert_eq!(ipuz["solution"][2], json!(["#", "O", "W"]));
+        assert_eq!(ipuz["clues"]["Down"][2], json!([4, "Female sheep"]));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn date() {
+        let date = puzzled_core::find_date("Jan 5, 2022").expect("Date is valid");
+        let puzzle = crossword!([A B]);
+        let meta = puzzle.meta().clone().with_date(date);
+        let puzzle = Crossword::new(puzzle.squares().clone(), puzzle.clues().clone(), meta);
+
+        assert_eq!(puzzle.to_ipuz()["date"], json!("01/05/2022"));
+    }
+
+    #[test]
+    fn numbering() {
+        let puzzle = crossword!(
+            [A B]
+            [C .]
+            [D .]
+            [. E]
+            - A: "AB"
+            - A: "C"
+            - A: "D"
+            - A: "E"
+            - D: "ACD"
+            - D: "B"
+            - D: "E"
+        );
+
+        let ipuz = puzzle
+            .clone()
+            .with_numbering(NumberingScheme::Separate)
+            .to_ipuz();
+        assert_eq!(
+            ipuz["puzzle"],
+            json!([[1, 2], [2, "#"], [3, "#"], ["#", "4/3"]])
+        );
+        assert_eq!(ipuz["clues"]["Down"][2], json!([3, "E"]));
+
+        let ipuz = puzzle
+            .with_numbering(NumberingScheme::Coordinates)
+            .to_ipuz();
+        assert_eq!(ipuz["puzzle"][0], json!([0, 0]));
+        assert_eq!(ipuz["clues"]["Across"][3], json!(["B4", "E"]));
+    }
+
+    #[test]
+    fn omitted() {
+        let puzzle = crossword!(
+            [C A T]
+            [A . O]
+            [B E E]
+        );
+
+        let mask = BitGrid::from_fn(3, 3, |pos| pos.row != 1 || pos.col != 0).unwrap();
+        let squares = puzzle.squares().clone().with_mask(mask).unwrap();
+
+        let ipuz = Crossword::from_squares(squares, puzzle.meta().clone()).to_ipuz();
+
+        assert_eq!(ipuz["puzzle"][1], json!([Value::Null, "#", 0]));
+        assert_eq!(ipuz["solution"][1], json!([Value::Null, "#", "O"]));
+    }
+
+    #[test]
+    fn decorations() {
+        let mut puzzle = crossword!([A B C]);
+
+        let squares = puzzle.squares_mut();
+        let decoration = CellDecoration::default()
+            .with_background(Color::rgb(255, 204, 0))
+            .with_bars(CellBorders::RIGHT);
+
+        if let Some(cell) = squares[Position::new(0, 1)].as_mut() {
+            cell.decoration = decoration.with_shape(CellShape::Diamond);
+        }
+        if let Some(cell) = squares[Position::new(0, 2)].as_mut() {
+            cell.style |= CellStyle::CIRCLED;
+        }
+
+        let ipuz = puzzle.to_ipuz();
+
+        assert_eq!(ipuz["puzzle"][0][0], json!(0));
+        assert_eq!(
+            ipuz["puzzle"][0][1],
+            json!({ "cell": 0, "style": { "shapebg": "diamond", "color": "FFCC00", "barred": "R" } })
+        );
+        assert_eq!(
+            ipuz["puzzle"][0][2],
+            json!({ "cell": 0, "style": { "shapebg": "circle" } })
+        );
+    }
+
+    #[test]
+    fn theme_entries() {
+        let mut puzzle = crossword!(
+            [C A T]
+            [O W L]
+        );
+
+        let entry = ThemeEntry::new("Pet", [Position::new(1, 0), Position::new(0, 2)]);
+        puzzle.add_theme_entry(entry).unwrap();
+
+        let ipuz = puzzle.to_ipuz();
+
+        assert_eq!(
+            ipuz["io.github.eevdriet.puzzled:themeentries"],
+            json!([{ "name": "Pet", "cells": [[1, 2], [3, 1]] }])
+        );
+        assert!(
+            crossword!([A B])
+                .to_ipuz()
+                .get("io.github.eevdriet.puzzled:themeentries")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn extra() {
+        let mut puzzle = crossword!([A B]);
+        let meta = puzzle.meta_mut();
+        meta.insert_extra("editor".into(), "Will Shortz".into());
+        meta.insert_extra("version".into(), "3".into());
+
+        let ipuz = puzzle.to_ipuz();
+
+        assert_eq!(ipuz["editor"], "Will Shortz");
+        assert_eq!(ipuz["version"], "http://ipuz.org/v2");
+    }
+
+    #[rstest]
+    #[case::standard(NumberingScheme::Standard)]
+    #[case::separate(NumberingScheme::Separate)]
+    #[case::coordinates(NumberingScheme::Coordinates)]
+    fn round_trip(#[case] numbering: NumberingScheme) {
+        let mut puzzle = crossword!(
+            [A B]
+            [C .]
+            [D .]
+            [. E]
+            - A: "AB"
+            - A: "C"
+            - A: "D"
+            - A: "E"
+            - D: "ACD"
+            - D: "B"
+            - D: "E"
+            title: "Letters"
+            author: "Eric"
+        )
+        .with_numbering(numbering);
+
+        if let Some(cell) = puzzle.squares_mut()[Position::new(0, 1)].as_mut() {
+            cell.decoration = CellDecoration::default()
+                .with_shape(CellShape::Diamond)
+                .with_background(Color::rgb(255, 204, 0))
+                .with_bars(CellBorders::RIGHT);
+        }
+        if let Some(cell) = puzzle.squares_mut()[Position::new(1, 0)].as_mut() {
+            cell.style |= CellStyle::CIRCLED;
+        }
+
+        let entry = ThemeEntry::new("Start", [Position::new(0, 0), Position::new(0, 1)]);
+        puzzle.add_theme_entry(entry).unwrap();
+        puzzle
+            .meta_mut()
+            .insert_extra("editor".into(), "Will Shortz".into());
+
+        let (read, diagnostics) = Crossword::from_ipuz(&puzzle.to_ipuz().to_string()).unwrap();
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(read.numbering(), numbering);
+        assert_eq!(read, puzzle);
+    }
+
+    #[test]
+    fn read_omitted() {
+        let ipuz = json!({
+            "kind": ["http://ipuz.org/crossword#1"],
+            "dimensions": { "width": 2, "height": 2 },
+            "puzzle": [[1, null], [2, "#"]],
+            "solution": [["A", null], ["B", "#"]],
+            "clues": { "Down": [[1, "AB"]], "Diagonal": [[1, "A"]] },
+        });
+
+        let (puzzle, diagnostics) = Crossword::from_ipuz(&ipuz.to_string()).unwrap();
+
+        assert!(!puzzle.squares().has_cell(Position::new(0, 1)));
+        assert!(puzzle.squares()[Position::new(1, 1)].is_none());
+        assert_eq!(puzzle.clues().values().next().unwrap().text(), "AB");
+
+        let codes: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect();
+        assert_eq!(codes, [DiagnosticCode::IpuzUnsupportedFeature]);
+    }
+
+    #[rstest]
+    #[case::json("{", IpuzError::InvalidJson { line: 1, column: 1 })]
+    #[case::sudoku(
+        r#"{ "kind": ["http://ipuz.org/sudoku#1"] }"#,
+        IpuzError::UnsupportedPuzzle { kind: vec!["http://ipuz.org/sudoku#1".into()] }
+    )]
+    #[case::dimensions(
+        r#"{ "kind": ["http://ipuz.org/crossword#1"] }"#,
+        IpuzError::MissingField { field: "dimensions".into() }
+    )]
+    #[case::too_large(
+        r#"{ "kind": ["http://ipuz.org/crossword#1"], "dimensions": { "width": 1000, "height": 1000 } }"#,
+        IpuzError::GridTooLarge { rows: 1000, cols: 1000 }
+    )]
+    #[case::puzzle(
+        r#"{ "kind": ["http://ipuz.org/crossword#1"], "dimensions": { "width": 2, "height": 1 }, "puzzle": [[0]] }"#,
+        IpuzError::InvalidField { field: "puzzle".into() }
+    )]
+    fn read_error(#[case] json: &str, #[case] err: IpuzError) {
+        assert_eq!(Crossword::from_ipuz(json).map(|_| ()), Err(err));
+    }
+}
@@ -6,6 +6,12 @@
 //! |------------|--------|------|
 //! | Binary | [`PuzReader`] | [`PuzWriter`] |
 //! | Text | [`TxtReader`] | |
+//! | [ipuz](http://ipuz.org/) | [`Crossword::from_ipuz`] | [`Crossword::to_ipuz`] |
+//! | Across Lite text | [`Crossword::from_across_lite`] | |
+//! | [Exolve](https://github.com/viresh-ratnakar/exolve) | | [`Crossword::to_exolve`] |
+//! | [Qxw](https://www.quinapalus.com/qxw.html) | [`Crossword::from_qxw`] | |
+//! | Crossword Compiler XML | [`Crossword::from_crossword_xml`] | |
+//! | CSV/TSV clue list | [`ClueListReader`] | [`ClueListWriter`] |
 //!
 //! ## Binary
 //! This crate tries to following the [Across Lite format][PUZ google spec] as closely as possible to handle binary data.
@@ -39,7 +45,43 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+#[cfg(feature = "text")]
+mod across_lite;
+
+mod clue_list;
+mod exolve;
+
+#[cfg(feature = "ipuz")]
+mod ipuz;
+
 #[cfg(feature = "puz")]
 mod puz;
 
+#[cfg(feature = "qxw")]
+mod qxw;
+
+#[cfg(feature = "xml")]
+mod xml;
+
 mod text;
+
+#[cfg(feature = "text")]
+pub use across_lite::AcrossLiteError;
+pub use clue_list::{ClueListError, ClueListReader, ClueListWriter, ClueRow};
+#[cfg(feature = "ipuz")]
+pub use ipuz::IpuzError;
+#[cfg(feature = "qxw")]
+pub use qxw::QxwError;
+#[cfg(feature = "xml")]
+pub use xml::CrosswordXmlError;
+
+/// Largest number of squares in a grid that readers of text formats accept, such that a malformed size cannot allocate a huge grid
+#[cfg(any(feature = "ipuz", feature = "qxw", feature = "xml"))]
+const MAX_SQUARES: usize = 255 * 255;
+
+/// Number of squares of a grid with the given size, or [`None`] if it is larger than [`MAX_SQUARES`]
+#[cfg(any(feature = "ipuz", feature = "qxw", feature = "xml"))]
+fn grid_squares(rows: usize, cols: usize) -> Option<usize> {
+    rows.checked_mul(cols)
+        .filter(|&squares| squares <= MAX_SQUARES)
+}
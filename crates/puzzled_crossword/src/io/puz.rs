@@ -4,8 +4,8 @@ use puzzled_core::{Cell, Grid, MISSING_ENTRY_CHAR, Metadata, NON_PLAYABLE_CHAR,
 use puzzled_io::{
     Context,
     puz::{
-        BinaryPuzzle, ByteStr, Extras, Grids, Header, PuzSizeCheck, Span, Strings, WriteStateGrid,
-        check_puz_size,
+        BinaryPuzzle, ChecksumCache, Extras, Grids, Header, PuzChecksums, PuzSidecar, PuzSizeCheck,
+        PuzWriter, Span, Strings, WriteStateGrid, check_puz_size,
         read::{self, read_metadata},
         windows_1252_to_char,
         write::{self, WriteStyleGrid},
@@ -13,8 +13,8 @@ use puzzled_io::{
 };
 
 use crate::{
-    Clue, ClueDirection, Clues, Crossword, CrosswordSquares, CrosswordState, Entry, Solution,
-    Squares,
+    Clue, ClueDirection, Clues, Crossword, CrosswordSquares, CrosswordState, Entry, LockState,
+    Solution, Squares,
 };
 
 impl PuzSizeCheck for Crossword {
@@ -41,13 +41,10 @@ impl BinaryPuzzle<CrosswordState> for Crossword {
         self.squares().rows()
     }
 
-    fn clues(&self) -> Vec<ByteStr> {
+    fn clues(&self) -> Vec<String> {
         self.clues()
             .values()
-            .map(|clue| {
-                let bytes = clue.text().as_bytes();
-                ByteStr::new(bytes)
-            })
+            .map(|clue| clue.text().clone())
             .collect()
     }
 
@@ -66,6 +63,25 @@ impl BinaryPuzzle<CrosswordState> for Crossword {
         Some(self.meta())
     }
 
+    fn puz_sidecar(&self) -> Option<&PuzSidecar> {
+        self.puz_sidecar.as_ref()
+    }
+
+    fn set_puz_sidecar(&mut self, sidecar: PuzSidecar) {
+        self.puz_sidecar = Some(sidecar);
+    }
+
+    fn checksum_cache(&self) -> Option<&ChecksumCache> {
+        Some(&self.puz_checksums)
+    }
+
+    fn scrambled_checksum(&self) -> Option<u16> {
+        match self.lock_state() {
+            LockState::Locked { checksum } => Some(checksum),
+            LockState::Unlocked => None,
+        }
+    }
+
     fn extras(&self, state: &CrosswordState) -> write::Result<Extras> {
         let squares = self.squares();
         squares.check_puz_size()?;
@@ -75,15 +91,21 @@ impl BinaryPuzzle<CrosswordState> for Crossword {
         let mut extras = Extras::default();
 
         // GRBS / RTBL
-        if squares
-            .iter_fills()
-            .any(|cell| cell.solution.as_ref().is_some_and(|sol| sol.is_rebus()))
-        {
+        if squares.iter_fills().any(|cell| {
+            cell.solution
+                .as_ref()
+                .is_some_and(|sol| sol.primary().is_rebus())
+        }) {
             let mut rebuses: BTreeMap<u8, String> = BTreeMap::new();
             let mut num = 0;
 
             let grbs = squares.map_ref(|square| {
-                match square.as_ref().and_then(|cell| cell.solution.as_ref()) {
+                // Only the primary solution can be stored, as *.puz files have no notion of alternatives
+                match square
+                    .as_ref()
+                    .and_then(|cell| cell.solution.as_ref())
+                    .map(Solution::primary)
+                {
                     Some(solution) if solution.is_rebus() => {
                         num += 1;
                         rebuses.insert(num, solution.to_string());
@@ -120,8 +142,53 @@ impl BinaryPuzzle<CrosswordState> for Crossword {
         let clues = read_clues(&squares, &strings)?;
         let meta = read_metadata(&header, &strings);
 
-        let crossword = Crossword::new(squares, clues, meta);
-        Ok((crossword, state))
+        // Scrambled puzzles that were read without their key can still be played, but not checked
+        let lock = match header.scrambled_tag {
+            0 => LockState::Unlocked,
+            _ => LockState::Locked {
+                checksum: header.scrambled_checksum,
+            },
+        };
+
+        let crossword = Crossword::new(squares, clues, meta).with_lock_state(lock);
+        Ok((crossword, state.with_lock_state(lock)))
+    }
+}
+
+/// # Checksums
+impl Crossword {
+    /// Compute the [checksums](puzzled_io::puz#validating-checksums) that the puzzle is written to `*.puz` data with
+    ///
+    /// The checksums of the strings are cached, such that they are only computed again once the clues have changed.
+    /// Writing the puzzle with a [`PuzWriter`] reuses the cached checksums as well.
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*, CrosswordState};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [A B]
+    ///     - A: "First letters"
+    /// );
+    /// let state = CrosswordState::from(&puzzle);
+    /// assert!(puzzle.has_dirty_checksums());
+    ///
+    /// let checksums = puzzle.recompute_checksums(&state).unwrap();
+    /// assert!(!puzzle.has_dirty_checksums());
+    ///
+    /// puzzle.clues_mut().retext((1, Across), "Start of the alphabet");
+    /// assert!(puzzle.has_dirty_checksums());
+    /// assert_ne!(puzzle.recompute_checksums(&state).unwrap(), checksums);
+    /// ```
+    pub fn recompute_checksums(&mut self, state: &CrosswordState) -> write::Result<PuzChecksums> {
+        let mut cache = std::mem::take(&mut self.puz_checksums);
+        let checksums = PuzWriter::new().checksums(self, state, &mut cache);
+        self.puz_checksums = cache;
+
+        checksums
+    }
+
+    /// Whether the clues changed since the checksums were last [computed](Self::recompute_checksums)
+    pub fn has_dirty_checksums(&self) -> bool {
+        !self.puz_checksums.is_cached()
     }
 }
 
@@ -131,9 +198,9 @@ fn read_state(grids: &Grids, extras: &Extras) -> read::Result<(Squares, Crosswor
 
     let (squares, entries) = grids
         .solution
-        .iter_indexed()
-        .zip(grids.state.iter())
-        .map(|((pos, &solution), &state)| {
+        .enumerate_positions()
+        .map(|(pos, &solution)| {
+            let state = *grids.state.get_checked(pos).context("State grid")?;
             let style = extras.get_style(pos);
 
             let square = match windows_1252_to_char(solution) {
@@ -169,8 +236,10 @@ fn read_state(grids: &Grids, extras: &Extras) -> read::Result<(Squares, Crosswor
                 }
             };
 
-            (square, entry)
+            Ok((square, entry))
         })
+        .collect::<read::Result<Vec<_>>>()?
+        .into_iter()
         .unzip();
 
     let squares = Grid::from_vec(squares, cols).expect("Read correct length squares");
@@ -178,7 +247,7 @@ fn read_state(grids: &Grids, extras: &Extras) -> read::Result<(Squares, Crosswor
 
     let entries = Grid::from_vec(entries, cols).expect("Read correct lenght entries");
 
-    let timer = extras.ltim.unwrap_or_default();
+    let timer = extras.ltim.clone().unwrap_or_default();
     let state = CrosswordState::new(solutions, entries, timer);
 
     Ok((squares, state))
@@ -199,7 +268,7 @@ fn read_clues(squares: &Squares, strings: &Strings) -> read::Result<Clues> {
         // No more clues to parse
         let text = match clues_iter.next() {
             None => return false,
-            Some((_, clue)) => clue.to_string(),
+            Some((_, clue)) => strings.decode(clue),
         };
         let len = squares.find_clue_len(start, direction);
 
@@ -224,7 +293,7 @@ fn read_clues(squares: &Squares, strings: &Strings) -> read::Result<Clues> {
             span: Span::default(),
             kind: read::ErrorKind::MissingClue {
                 id,
-                clue: clue.to_string(),
+                clue: strings.decode(clue),
             },
             context: "Clues".to_string(),
         });
@@ -235,20 +304,31 @@ fn read_clues(squares: &Squares, strings: &Strings) -> read::Result<Clues> {
 
 #[cfg(all(test, feature = "puz"))]
 mod tests {
-    use crate::{Crossword, CrosswordState};
-    use puzzled_io::puz::{PuzReader, read};
+    use crate::{Clue, Crossword, CrosswordState, crossword};
+    use puzzled_core::{BitGrid, CellStyle, Grid, Metadata, Position, Solve, Version};
+    use puzzled_io::{
+        Diagnostic, DiagnosticCode, Severity,
+        puz::{
+            PuzChecksums, PuzReader, PuzWriter, RawSection, SCRAMBLED_TAG, ScrambleKey,
+            SectionHandler, SectionLayout,
+            read::{self, ReadOptions, Strictness},
+            unscramble_solution,
+            write::WriteOptions,
+        },
+    };
     use rstest::rstest;
     use std::fs::File;
     use std::path::PathBuf;
+    use std::sync::Arc;
 
     fn parse_puz(
         path: PathBuf,
         strict: bool,
-    ) -> read::Result<(Crossword, CrosswordState, Vec<read::Warning>)> {
+    ) -> read::Result<(Crossword, CrosswordState, Vec<Diagnostic>)> {
         let mut file = File::open(path).expect("puzzle file exists");
         let parser = PuzReader::new(strict);
 
-        parser.read_with_warnings(&mut file)
+        parser.read_with_diagnostics(&mut file)
     }
 
     #[rstest]
@@ -271,8 +351,430 @@ mod tests {
     #[rstest]
     fn parse_warn(#[files("puzzles/warn/*.puz")] path: PathBuf) {
         let result = parse_puz(path, false);
-        let (_, _, warnings) = result.expect("puzzle is parsed correctly");
+        let (_, _, diagnostics) = result.expect("puzzle is parsed correctly");
+
+        assert!(!diagnostics.is_empty());
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.severity == Severity::Warning)
+        );
+    }
+
+    #[rstest]
+    #[case::strict_sections("ok/mini.puz", ReadOptions::strict().with_checksums(Strictness::Ignore), Some(0))]
+    #[case::ignore_all(
+        "warn/unfinished-section.puz",
+        ReadOptions::all(Strictness::Ignore),
+        Some(0)
+    )]
+    #[case::ignore_checksums("warn/unfinished-section.puz", ReadOptions::default().with_checksums(Strictness::Ignore), Some(1))]
+    #[case::strict_checksums("warn/unfinished-section.puz", ReadOptions::default().with_checksums(Strictness::Error), None)]
+    #[case::lenient_sections("warn/unfinished-section.puz", ReadOptions::strict().with_checksums(Strictness::Warn).with_extra_sections(Strictness::Ignore), Some(3))]
+    fn read_options(
+        #[case] path: &str,
+        #[case] options: ReadOptions,
+        #[case] expected: Option<usize>,
+    ) {
+        let path = PathBuf::from("puzzles").join(path);
+        let mut file = File::open(path).expect("puzzle file exists");
+
+        let result: read::Result<(Crossword, CrosswordState, _)> =
+            PuzReader::with_options(options).read_with_diagnostics(&mut file);
+        let diagnostics = result.ok().map(|(_, _, diagnostics)| diagnostics.len());
+
+        assert_eq!(diagnostics, expected);
+    }
+
+    #[rstest]
+    #[case::windows_1252(Version::new(1, 3), "“Café” – €5")]
+    #[case::utf8(Version::new(2, 0), "日本の“Café”")]
+    fn string_encoding(#[case] version: Version, #[case] text: &str) {
+        let puzzle = crossword!(
+            [A B]
+            - A: "AB"
+        );
+        let meta = Metadata::default().with_version(version);
+        let mut puzzle = Crossword::new(puzzle.squares().clone(), puzzle.clues().clone(), meta);
+        puzzle.clues_mut().iter_across_mut().for_each(|clue| {
+            *clue = Clue::new(clue.num(), clue.direction(), text, clue.start(), clue.len())
+        });
+
+        let mut bytes = Vec::new();
+        let state = CrosswordState::from(&puzzle);
+        PuzWriter::new()
+            .write(&mut bytes, &puzzle, &state)
+            .expect("puzzle is written correctly");
+
+        let reader =
+            PuzReader::with_options(ReadOptions::default().with_checksums(Strictness::Ignore));
+        let result: read::Result<(Crossword, CrosswordState, _)> =
+            reader.read_with_diagnostics(&mut bytes.as_slice());
+        let (read, _, diagnostics) = result.expect("puzzle is read correctly");
+
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        assert_eq!(
+            read.clues()
+                .values()
+                .next()
+                .map(Clue::text)
+                .map(String::as_str),
+            Some(text)
+        );
+    }
+
+    #[test]
+    fn recompute_checksums() {
+        let (mut puzzle, state, _) =
+            parse_puz(PathBuf::from("puzzles/ok/out-of-the-frying-pan.puz"), false).unwrap();
+        let written_checksums = |puzzle: &Crossword| {
+            let mut bytes = Vec::new();
+            PuzWriter::new().write(&mut bytes, puzzle, &state).unwrap();
+
+            let puz = PuzReader::new(false).read_borrowed(&bytes).unwrap();
+            PuzChecksums::from(&puz.header)
+        };
+
+        let checksums = puzzle.recompute_checksums(&state).unwrap();
+        assert_eq!(checksums, written_checksums(&puzzle));
+        assert_eq!(puzzle.recompute_checksums(&state).unwrap(), checksums);
+
+        let id = *puzzle.clues().keys().next().unwrap();
+        puzzle.clues_mut().retext(id, "Changed clue");
+        assert!(puzzle.has_dirty_checksums());
+
+        let changed = puzzle.recompute_checksums(&state).unwrap();
+        assert_ne!(changed, checksums);
+        assert_eq!(changed, written_checksums(&puzzle));
+    }
+
+    #[rstest]
+    #[case::mini("ok/mini.puz")]
+    #[case::cryptic("ok/out-of-the-frying-pan.puz")]
+    fn lossless(#[case] path: &str) {
+        let path = PathBuf::from("puzzles").join(path);
+        let data = std::fs::read(path).expect("puzzle file exists");
+
+        let reader = PuzReader::with_options(ReadOptions::default().with_lossless(true));
+        let (puzzle, state): (Crossword, CrosswordState) = reader
+            .read(&mut data.as_slice())
+            .expect("puzzle is read correctly");
+
+        let mut written = Vec::new();
+        PuzWriter::new()
+            .write(&mut written, &puzzle, &state)
+            .expect("puzzle is written correctly");
+
+        assert_eq!(written, data);
+    }
+
+    #[rstest]
+    #[case::lossy(false)]
+    #[case::lossless(true)]
+    fn lossless_unknown_data(#[case] lossless: bool) {
+        let mut data = std::fs::read("puzzles/ok/mini.puz").expect("puzzle file exists");
+
+        // Fill the reserved header bytes and add an unknown section
+        data[28..42].copy_from_slice(b"uninitialized!");
+        data.extend(b"ZZZZsome unknown data\0");
+
+        let options = ReadOptions::default().with_lossless(lossless);
+        let (puzzle, state): (Crossword, CrosswordState) = PuzReader::with_options(options)
+            .read(&mut data.as_slice())
+            .expect("puzzle is read correctly");
+
+        let mut written = Vec::new();
+        PuzWriter::new()
+            .write(&mut written, &puzzle, &state)
+            .expect("puzzle is written correctly");
+
+        assert_eq!(written == data, lossless);
+    }
+
+    /// Handler that only accepts sections of zeroes
+    struct Zeroes;
+
+    impl SectionHandler for Zeroes {
+        fn layout(&self) -> SectionLayout {
+            SectionLayout::Fixed(2)
+        }
+
+        fn read(&self, bytes: &[u8]) -> Result<(), String> {
+            match bytes.iter().all(|&byte| byte == 0) {
+                true => Ok(()),
+                false => Err(format!("expected only zeroes, found {bytes:?}")),
+            }
+        }
+
+        fn write(&self) -> Option<Vec<u8>> {
+            Some(vec![0, 0])
+        }
+    }
+
+    #[rstest]
+    #[case::unhandled(vec![0, 0], false, Some(1))]
+    #[case::valid(vec![0, 0], true, Some(0))]
+    #[case::invalid(vec![0, 1], true, None)]
+    fn section_handler(
+        #[case] bytes: Vec<u8>,
+        #[case] handled: bool,
+        #[case] expected: Option<usize>,
+    ) {
+        let puzzle = crossword!([A B]);
+        let section = Arc::new(RawSection::new(SectionLayout::Fixed(2)));
+        section.set_bytes(bytes);
+
+        let options = WriteOptions::default().with_section_handler("ZERO", section);
+        let data = write_bytes(&puzzle, &options);
+
+        let options = ReadOptions::strict().with_version(Strictness::Ignore);
+        let options = match handled {
+            true => options.with_section_handler("ZERO", Arc::new(Zeroes)),
+            false => options.with_extra_sections(Strictness::Warn),
+        };
+
+        let result: read::Result<(Crossword, CrosswordState, _)> =
+            PuzReader::with_options(options).read_with_diagnostics(&mut data.as_slice());
+        let diagnostics = result.ok().map(|(_, _, diagnostics)| diagnostics.len());
+
+        assert_eq!(diagnostics, expected);
+    }
+
+    #[rstest]
+    #[case::known(0x80, CellStyle::CIRCLED, false)]
+    #[case::unknown(0x81, CellStyle::CIRCLED, true)]
+    #[case::marked(0x08, CellStyle::empty(), false)]
+    fn gext_style_bits(#[case] mask: u8, #[case] expected: CellStyle, #[case] warns: bool) {
+        let puzzle = crossword!([A B]);
+        let mut data = write_bytes(&puzzle, &WriteOptions::default());
+
+        let gext = data
+            .windows(4)
+            .position(|name| name == b"GEXT")
+            .expect("GEXT is written")
+            + 4;
+        data[gext] = mask;
+
+        let read_with = |options: ReadOptions| {
+            let options = options.with_version(Strictness::Ignore);
+            let result: read::Result<(Crossword, CrosswordState, _)> =
+                PuzReader::with_options(options).read_with_diagnostics(&mut data.as_slice());
+
+            result.expect("puzzle is read correctly")
+        };
+        let write = |puzzle: &Crossword, state: &CrosswordState| {
+            let mut written = Vec::new();
+            PuzWriter::new()
+                .write(&mut written, puzzle, state)
+                .expect("puzzle is written correctly");
+
+            written
+        };
+
+        // Other bits are left out of the style
+        let (read, state, diagnostics) = read_with(ReadOptions::strict());
+        let style = read.squares()[Position::new(0, 0)]
+            .as_ref()
+            .map(|cell| cell.style)
+            .expect("square is filled");
+
+        assert_eq!(style, expected);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .any(|diagnostic| diagnostic.code == DiagnosticCode::PuzInvalidCellStyle),
+            warns
+        );
+        assert_eq!(write(&read, &state)[gext], expected.bits());
+
+        // Lossless readers still write them back as they were
+        let (read, state, _) = read_with(ReadOptions::strict().with_lossless(true));
+        assert_eq!(write(&read, &state), data);
+    }
+
+    fn write_bytes(puzzle: &Crossword, options: &WriteOptions) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let state = CrosswordState::from(puzzle);
+
+        PuzWriter::new()
+            .write_with_options(&mut bytes, puzzle, &state, options)
+            .expect("puzzle is written correctly");
+
+        bytes
+    }
+
+    #[rstest]
+    #[case::defaults(WriteOptions::default(), true)]
+    #[case::without_rebus(WriteOptions::default().with_rebus(false), false)]
+    fn write_sections(#[case] options: WriteOptions, #[case] has_rebus: bool) {
+        let puzzle = crossword!(
+            [C  REBUS Y]
+            [A  .     S]
+            [RR O     W]
+            - A: "C"
+            - D: "C"
+        );
+        let bytes = write_bytes(&puzzle, &options);
+        let contains = |section: &[u8]| bytes.windows(4).any(|window| window == section);
+
+        assert_eq!(contains(b"GRBS"), has_rebus);
+        assert_eq!(contains(b"RTBL"), has_rebus);
+    }
+
+    #[test]
+    fn write_masked() {
+        let puzzle = crossword!(
+            [C A T]
+            [A . O]
+            [B E E]
+        );
+        let mask = BitGrid::from_fn(3, 3, |pos| pos != Position::new(1, 0)).unwrap();
+        let squares = puzzle.squares().clone().with_mask(mask).unwrap();
+        let puzzle = Crossword::from_squares(squares, puzzle.meta().clone());
+
+        let bytes = write_bytes(&puzzle, &WriteOptions::default());
+        let (read, _): (Crossword, CrosswordState) =
+            PuzReader::new(false).read(&mut &bytes[..]).unwrap();
+
+        // Omitted squares cannot be represented, so they are written as blocks
+        assert_eq!(read.squares().mask(), None);
+        assert!(read.squares()[Position::new(1, 0)].is_none());
+        assert_eq!(
+            read.squares()[Position::new(2, 2)],
+            puzzle.squares()[Position::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn write_version() {
+        let puzzle = crossword!([A B]);
+        let options = WriteOptions::default().with_version(Version::new(2, 0));
+        let bytes = write_bytes(&puzzle, &options);
+
+        assert_eq!(&bytes[0x18..0x1C], b"2.0\0");
+    }
+
+    #[test]
+    fn write_scrambled() {
+        let puzzle = crossword!(
+            [C A T]
+            [O . R]
+            [W E E]
+        );
+        let key = ScrambleKey::new(1234).unwrap();
+
+        let plain = write_bytes(&puzzle, &WriteOptions::default());
+        let scrambled = write_bytes(&puzzle, &WriteOptions::default().with_scramble_key(key));
+
+        // Scrambled tag is the last component of the CIB region, right before the solution
+        let tag = u16::from_le_bytes([scrambled[0x32], scrambled[0x33]]);
+        assert_eq!(tag, SCRAMBLED_TAG);
+
+        let solution = |bytes: &[u8]| Grid::from_vec(bytes[0x34..0x34 + 9].to_vec(), 3).unwrap();
+        let mut unscrambled = solution(&scrambled);
+        assert_ne!(unscrambled, solution(&plain));
+
+        unscramble_solution(&mut unscrambled, key).expect("solution is unscrambled");
+        assert_eq!(unscrambled, solution(&plain));
+    }
+
+    #[rstest]
+    #[case::without_key(None, Some(false))]
+    #[case::with_key(Some(1234), Some(true))]
+    #[case::wrong_key(Some(4321), None)]
+    fn read_scrambled(#[case] key: Option<u16>, #[case] expected_unlocked: Option<bool>) {
+        let puzzle = crossword!(
+            [C A T]
+            [O . R]
+            [W E E]
+        );
+        let options = WriteOptions::default().with_scramble_key(ScrambleKey::new(1234).unwrap());
+        let bytes = write_bytes(&puzzle, &options);
+
+        let mut options = ReadOptions::default();
+        if let Some(key) = key.and_then(ScrambleKey::new) {
+            options = options.with_scramble_key(key);
+        }
+        let result: read::Result<(Crossword, CrosswordState)> =
+            PuzReader::with_options(options).read(&mut bytes.as_slice());
+
+        let Some(expected_unlocked) = expected_unlocked else {
+            assert!(result.is_err());
+            return;
+        };
+        let (read, mut state) = result.expect("puzzle is read correctly");
+
+        assert_eq!(read.lock_state().is_unlocked(), expected_unlocked);
+        assert_eq!(read.squares() == puzzle.squares(), expected_unlocked);
+        assert_eq!(
+            state.check(&Position::new(0, 0)).is_some(),
+            expected_unlocked
+        );
+    }
+
+    #[test]
+    fn solve_locked() {
+        let puzzle = crossword!(
+            [C A T]
+            [O . R]
+            [W E E]
+        );
+        let key = ScrambleKey::new(1234).unwrap();
+        let bytes = write_bytes(&puzzle, &WriteOptions::default().with_scramble_key(key));
+
+        let result: read::Result<(Crossword, CrosswordState)> =
+            PuzReader::default().read(&mut bytes.as_slice());
+        let (locked, mut state) = result.expect("puzzle is read correctly");
+
+        // Scrambled solution is kept when the puzzle is written again
+        let rewritten = write_bytes(&locked, &WriteOptions::default());
+        assert_eq!(&rewritten[0x32..0x34], &bytes[0x32..0x34]);
+        assert_eq!(&rewritten[0x34..0x34 + 9], &bytes[0x34..0x34 + 9]);
+
+        // Entering the real solution solves the puzzle
+        for (pos, cell) in puzzle.squares().iter_fills_indexed() {
+            let solution = cell.solution.clone().expect("cell has a solution");
+            state.enter(&pos, solution);
+        }
+        assert!(state.is_solved(&locked));
+    }
+
+    #[test]
+    fn theme_note() {
+        let puzzle = crossword!(
+            [A B]
+            notes: "Edited by Will"
+            theme_note: "Every answer is a palindrome"
+        );
+        let bytes = write_bytes(&puzzle, &WriteOptions::default());
+
+        let reader =
+            PuzReader::with_options(ReadOptions::default().with_checksums(Strictness::Ignore));
+        let result: read::Result<(Crossword, CrosswordState, _)> =
+            reader.read_with_diagnostics(&mut bytes.as_slice());
+        let (read, _, _) = result.expect("puzzle is read correctly");
+
+        assert_eq!(read.theme_note(), puzzle.theme_note());
+        assert_eq!(read.meta().notes(), puzzle.meta().notes());
+    }
 
-        assert!(!warnings.is_empty());
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn inferred_date() {
+        let puzzle = crossword!(
+            [A B]
+            title: "NY Times, Wed, Jan 5, 2022"
+        );
+        let bytes = write_bytes(&puzzle, &WriteOptions::default());
+
+        let reader =
+            PuzReader::with_options(ReadOptions::default().with_checksums(Strictness::Ignore));
+        let result: read::Result<(Crossword, CrosswordState)> = reader.read(&mut bytes.as_slice());
+        let (read, _) = result.expect("puzzle is read correctly");
+
+        assert_eq!(
+            read.meta().date().map(|date| date.to_string()).as_deref(),
+            Some("2022-01-05")
+        );
     }
 }
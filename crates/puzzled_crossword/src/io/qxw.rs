@@ -0,0 +1,297 @@
+use puzzled_core::{Cell, CellBorders, CellDecoration, Grid, Metadata, Position, Square};
+use puzzled_io::{Diagnostic, DiagnosticCode, Severity};
+
+use crate::{Crossword, CrosswordBuilder, Solution, io::grid_squares};
+
+/// Start of the first line of files saved by Qxw
+const MAGIC: &str = "#QXW2";
+
+/// Grid type of plain rectangular grids, which is the only type that can be read
+const RECTANGULAR: u32 = 0;
+
+/// Flag of a square that is blocked
+const FLAG_BLOCK: u32 = 1 << 0;
+
+/// Flag of a square that is cut out of the grid
+const FLAG_CUTOUT: u32 = 1 << 3;
+
+/// Bar after the square in the across direction
+const BAR_RIGHT: u32 = 1 << 0;
+
+/// Bar after the square in the down direction
+const BAR_BOTTOM: u32 = 1 << 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum QxwError {
+    #[error("Qxw files should start with {MAGIC}, found '{found}'")]
+    InvalidHeader { found: String },
+
+    #[error("Grid properties (GP) are required, but are missing")]
+    MissingGrid,
+
+    #[error("Grid of type {kind} is not rectangular and cannot be read")]
+    UnsupportedGrid { kind: u32 },
+
+    #[error("Grid of {rows}x{cols} squares is too large to be read")]
+    GridTooLarge { rows: usize, cols: usize },
+
+    #[error("Record '{record}' on line {line} is not valid")]
+    InvalidRecord { line: usize, record: String },
+
+    #[error("Square at {pos:?} is outside of the {rows}x{cols} grid")]
+    OutOfBounds {
+        pos: Position,
+        rows: usize,
+        cols: usize,
+    },
+}
+
+impl Crossword {
+    /// Read a crossword from a file saved by the [Qxw](https://www.quinapalus.com/qxw.html) crossword editor
+    ///
+    /// Only plain rectangular grids are read, from their grid properties (`GP`), title (`TTL`), author (`AUT`) and squares (`SQ`).
+    /// Squares keep their blocks, bars and letters, where a square with multiple letters is read as a rebus.
+    /// Qxw does not save clues, so the clues are [numbered](CrosswordBuilder::renumber) from the grid without any text.
+    ///
+    /// Features that cannot be represented, such as merged and cut-out squares, are left out with a [warning](Severity::Warning).
+    /// Records that are not used by the reader are reported as [info](Severity::Info).
+    /// ```
+    /// use puzzled::crossword::{Crossword, Position, Solution};
+    /// use puzzled::io::Severity;
+    ///
+    /// let qxw = "#QXW2v4 http://www.quinapalus.com
+    /// GP 0 2 2 0 0 0
+    /// TTL
+    /// +Mini
+    /// SQ 0 0 0 0 0 A
+    /// SQ 1 0 0 0 0 B
+    /// SQ 0 1 0 0 0 C
+    /// SQ 1 1 0 0 1
+    /// GLP 1 0 1 0 0
+    /// ";
+    ///
+    /// let (puzzle, diagnostics) = Crossword::from_qxw(qxw)?;
+    /// assert_eq!(puzzle.meta().title(), Some("Mini"));
+    /// assert!(puzzle.squares()[Position::new(1, 1)].is_none());
+    ///
+    /// let cell = puzzle.squares()[Position::new(0, 1)].as_ref().unwrap();
+    /// assert_eq!(cell.solution, Some(Solution::Letter('B')));
+    ///
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].severity, Severity::Info);
+    /// # Ok::<(), puzzled::crossword::QxwError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "qxw")))]
+    pub fn from_qxw(text: &str) -> Result<(Crossword, Vec<Diagnostic>), QxwError> {
+        let mut lines = text.lines().enumerate();
+
+        let header = lines
+            .next()
+            .map(|(_, line)| line.trim())
+            .unwrap_or_default();
+        if !header.starts_with(MAGIC) {
+            return Err(QxwError::InvalidHeader {
+                found: header.to_string(),
+            });
+        }
+
+        let mut squares: Option<Grid<Square<Cell<Solution>>>> = None;
+        let mut meta = Metadata::default();
+        let mut diagnostics = Vec::new();
+
+        while let Some((idx, line)) = lines.next() {
+            let mut fields = line.split_whitespace();
+            let Some(record) = fields.next() else {
+                continue;
+            };
+
+            let invalid = || QxwError::InvalidRecord {
+                line: idx + 1,
+                record: record.to_string(),
+            };
+
+            // Strings are given on the next line, prefixed by a `+`
+            let mut string = || {
+                lines
+                    .next()
+                    .and_then(|(_, line)| line.strip_prefix('+'))
+                    .map(str::to_string)
+                    .ok_or_else(invalid)
+            };
+
+            match record {
+                "GP" => {
+                    let [kind, cols, rows] = read_numbers(&mut fields).ok_or_else(invalid)?;
+                    if kind != RECTANGULAR {
+                        return Err(QxwError::UnsupportedGrid { kind });
+                    }
+
+                    let (rows, cols) = (rows as usize, cols as usize);
+                    let len =
+                        grid_squares(rows, cols).ok_or(QxwError::GridTooLarge { rows, cols })?;
+                    let cells = vec![Square::new(Cell::new(None)); len];
+                    squares = Some(Grid::from_vec(cells, cols).map_err(|_| invalid())?);
+                }
+                "TTL" => meta = meta.with_title(string()?),
+                "AUT" => meta = meta.with_author(string()?),
+                "SQ" => {
+                    let Some(squares) = squares.as_mut() else {
+                        return Err(QxwError::MissingGrid);
+                    };
+
+                    let [col, row, bars, merge, flags] =
+                        read_numbers(&mut fields).ok_or_else(invalid)?;
+                    let pos = Position::new(row as usize, col as usize);
+
+                    let Some(square) = squares.get_checked_mut(pos).ok() else {
+                        return Err(QxwError::OutOfBounds {
+                            pos,
+                            rows: squares.rows(),
+                            cols: squares.cols(),
+                        });
+                    };
+
+                    if flags & FLAG_CUTOUT != 0 {
+                        diagnostics.push(unsupported(format!(
+                            "Square at {pos:?} is cut out and is read as a block"
+                        )));
+                    }
+                    if merge != 0 {
+                        diagnostics.push(unsupported(format!(
+                            "Square at {pos:?} is merged, which is not supported"
+                        )));
+                    }
+                    if flags & (FLAG_BLOCK | FLAG_CUTOUT) != 0 {
+                        *square = Square::new_empty();
+                        continue;
+                    }
+
+                    let mut borders = CellBorders::empty();
+                    borders.set(CellBorders::RIGHT, bars & BAR_RIGHT != 0);
+                    borders.set(CellBorders::BOTTOM, bars & BAR_BOTTOM != 0);
+
+                    let solution = fields
+                        .next()
+                        .filter(|entry| entry.chars().all(char::is_alphanumeric))
+                        .map(|entry| Solution::from(entry.to_uppercase()));
+
+                    let decoration = CellDecoration::default().with_bars(borders);
+                    *square = Square::new(Cell::new(solution).with_decoration(decoration));
+                }
+                record => diagnostics.push(Diagnostic::new(
+                    Severity::Info,
+                    DiagnosticCode::QxwIgnoredRecord,
+                    format!("Record '{record}' on line {} is ignored", idx + 1),
+                )),
+            }
+        }
+
+        let squares = squares.ok_or(QxwError::MissingGrid)?;
+
+        let mut builder = CrosswordBuilder::from(Crossword::from_squares(squares, meta));
+        builder.renumber();
+
+        Ok((builder.build(), diagnostics))
+    }
+}
+
+fn unsupported(message: String) -> Diagnostic {
+    Diagnostic::new(
+        Severity::Warning,
+        DiagnosticCode::QxwUnsupportedFeature,
+        message,
+    )
+}
+
+fn read_numbers<'a, const N: usize>(
+    fields: &mut impl Iterator<Item = &'a str>,
+) -> Option<[u32; N]> {
+    let mut numbers = [0; N];
+    for number in &mut numbers {
+        *number = fields.next()?.parse().ok()?;
+    }
+
+    Some(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::crossword;
+
+    #[test]
+    fn grid() {
+        let qxw = "#QXW2v4 http://www.quinapalus.com
+GP 0 3 2 0 0 0
+TTL
++Mini
+AUT
++Eric
+SQ 0 0 1 0 0 c
+SQ 1 0 0 0 0 A
+SQ 2 0 0 0 0 T
+SQ 0 1 2 0 0 O
+SQ 1 1 0 0 1
+SQ 2 1 0 0 0 OW
+";
+        let (puzzle, diagnostics) = Crossword::from_qxw(qxw).unwrap();
+        let expected = crossword!(
+            [C A T]
+            [O . OW]
+        );
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(puzzle.meta().author(), Some("Eric"));
+        assert_eq!(
+            puzzle
+                .squares()
+                .map_ref(|square| square.as_ref().map(|cell| cell.solution.clone())),
+            expected
+                .squares()
+                .map_ref(|square| square.as_ref().map(|cell| cell.solution.clone()))
+        );
+        assert_eq!(puzzle.clues().len(), 6);
+
+        let bars = |row, col| {
+            let cell = puzzle.squares()[Position::new(row, col)].as_ref().unwrap();
+            cell.decoration.bars
+        };
+        assert_eq!(bars(0, 0), CellBorders::RIGHT);
+        assert_eq!(bars(1, 0), CellBorders::BOTTOM);
+    }
+
+    #[test]
+    fn unsupported() {
+        let qxw = "#QXW2v4
+GP 0 2 1 0 0 0
+SQ 0 0 0 1 0 A
+SQ 1 0 0 0 8
+";
+        let (puzzle, diagnostics) = Crossword::from_qxw(qxw).unwrap();
+        let codes: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect();
+
+        assert_eq!(codes, [DiagnosticCode::QxwUnsupportedFeature; 2]);
+        assert!(puzzle.squares()[Position::new(0, 1)].is_none());
+    }
+
+    #[rstest]
+    #[case::header("<ACROSS PUZZLE>", QxwError::InvalidHeader { found: "<ACROSS PUZZLE>".into() })]
+    #[case::no_grid("#QXW2v4\nTTL\n+Mini", QxwError::MissingGrid)]
+    #[case::square_before_grid("#QXW2v4\nSQ 0 0 0 0 0 A", QxwError::MissingGrid)]
+    #[case::hexagonal("#QXW2v4\nGP 1 3 3 0 0 0", QxwError::UnsupportedGrid { kind: 1 })]
+    #[case::too_large("#QXW2v4\nGP 0 4294967295 4294967295 0 0 0", QxwError::GridTooLarge { rows: 4_294_967_295, cols: 4_294_967_295 })]
+    #[case::record("#QXW2v4\nGP 0 x 3", QxwError::InvalidRecord { line: 2, record: "GP".into() })]
+    #[case::title("#QXW2v4\nTTL\nMini", QxwError::InvalidRecord { line: 2, record: "TTL".into() })]
+    #[case::bounds(
+        "#QXW2v4\nGP 0 1 1 0 0 0\nSQ 2 0 0 0 0 A",
+        QxwError::OutOfBounds { pos: Position::new(0, 2), rows: 1, cols: 1 }
+    )]
+    fn errors(#[case] text: &str, #[case] err: QxwError) {
+        assert_eq!(Crossword::from_qxw(text).map(|_| ()), Err(err));
+    }
+}
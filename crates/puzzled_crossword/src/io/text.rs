@@ -3,18 +3,62 @@ use std::str::FromStr;
 use chumsky::{
     IterParser, Parser,
     extra::Err,
-    prelude::{group, just, one_of},
+    prelude::{any, group, just, one_of},
     text,
 };
+use puzzled_core::Grid;
 use puzzled_io::{
     TxtPuzzle,
-    text::read::{self, ParseError, grid, metadata_with_timer, quoted_string, square},
+    text::{
+        FormatConfig,
+        read::{
+            self, ParseError, escaped_string, grid, metadata_with_timer, quoted_string,
+            square_entry_with, square_with,
+        },
+        write::{metadata_text, quoted},
+    },
 };
 
-use crate::{ClueDirection, ClueSpec, Crossword, Solution};
+use crate::{
+    ClueDirection, ClueSpec, Crossword, CrosswordEntry, CrosswordState, Entry, NumberingScheme,
+    Solution, Squares, WritingDirection, crisscross::Crisscross,
+};
+
+/// Property that holds the [numbering scheme](NumberingScheme) if it is not the standard one
+const NUMBERING: &str = "numbering";
+
+/// Property that holds the [writing direction](WritingDirection) if it is not left to right
+const WRITING: &str = "writing";
 
 pub fn solution<'a>() -> impl Parser<'a, &'a str, Solution, Err<ParseError<'a>>> + Clone {
-    text::ident().map(Solution::from)
+    text::ident()
+        .map(Solution::from)
+        .separated_by(just(Solution::ALTERNATIVES_SEPARATOR))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(Solution::alternatives)
+}
+
+/// Parse a solution, which may be surrounded by the [configured](FormatConfig) rebus delimiters
+pub fn solution_with<'a>(
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, Solution, Err<ParseError<'a>>> + Clone {
+    let (open, close) = config.rebus_delimiters().unzip();
+    let delimiter = move |delimiter: Option<char>| {
+        any()
+            .filter(move |ch: &char| Some(*ch) == delimiter)
+            .or_not()
+    };
+
+    group((delimiter(open), solution(), delimiter(close))).try_map(
+        |(open, solution, close), span| match open.is_some() == close.is_some() {
+            true => Ok(solution),
+            false => Err(ParseError::custom(
+                span,
+                "Rebus is not delimited on both sides",
+            )),
+        },
+    )
 }
 
 pub fn clue<'a>() -> impl Parser<'a, &'a str, ClueSpec, Err<ParseError<'a>>> + Clone {
@@ -29,10 +73,15 @@ pub fn clue<'a>() -> impl Parser<'a, &'a str, ClueSpec, Err<ParseError<'a>>> + C
 
             Ok(ClueSpec::new(dir, clue))
         })
+        .labelled("clue")
+        .as_context()
 }
 
-pub fn clues<'a>() -> impl Parser<'a, &'a str, Vec<ClueSpec>, Err<ParseError<'a>>> + Clone {
-    just("-")
+/// Parse the clues, which each start with the [configured](FormatConfig) bullet
+pub fn clues<'a>(
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, Vec<ClueSpec>, Err<ParseError<'a>>> + Clone {
+    just(config.bullet())
         .padded()
         .ignore_then(clue())
         .padded() // allow spaces/newlines after each clue
@@ -40,43 +89,369 @@ pub fn clues<'a>() -> impl Parser<'a, &'a str, Vec<ClueSpec>, Err<ParseError<'a>
         .collect()
 }
 
-impl TxtPuzzle for Crossword {
-    fn read_text<'a>(input: &str) -> read::Result<Crossword> {
-        let (squares, clues, (meta, _)) = group((
-            grid(square(solution())).padded(),
-            clues().padded(),
+/// Parse the words, which each start with the [configured](FormatConfig) bullet and may escape their quotes
+pub fn words<'a>(
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, Vec<String>, Err<ParseError<'a>>> + Clone {
+    just(config.bullet())
+        .padded()
+        .ignore_then(escaped_string())
+        .padded()
+        .repeated()
+        .collect()
+}
+
+/// Squares as written in the text, marked as [configured](FormatConfig)
+///
+/// Squares with an entry are followed by the entry between parentheses, and are styled by the entry rather than their cell.
+fn squares_text(
+    squares: &Squares,
+    entries: Option<&Grid<CrosswordEntry>>,
+    config: &FormatConfig,
+) -> Grid<String> {
+    squares.map_ref_indexed(|pos, square| {
+        let Some(cell) = square.as_ref() else {
+            return config.block().to_string();
+        };
+
+        let solution = match &cell.solution {
+            Some(solution) => solution_text(solution, config),
+            None => config.missing().to_string(),
+        };
+
+        let entry = entries.and_then(|entries| entries.get_fill(pos));
+        let style = entry.map_or(cell.style, Entry::style);
+        let shading = if cell.decoration.is_shaded() { "%" } else { "" };
+
+        match entry.and_then(Entry::entry) {
+            Some(value) => format!(
+                "{solution}{style}{shading} ({})",
+                solution_text(value, config)
+            ),
+            None => format!("{solution}{style}{shading}"),
+        }
+    })
+}
+
+fn solution_text(solution: &Solution, config: &FormatConfig) -> String {
+    match (solution, config.rebus_delimiters()) {
+        (Solution::Rebus(rebus), Some((open, close))) => format!("{open}{rebus}{close}"),
+        (solution, _) => solution.to_string(),
+    }
+}
+
+/// # Text
+impl Crossword {
+    /// Read the puzzle from text together with the state of its solver
+    ///
+    /// Squares can be followed by the entry of the solver between parentheses, and their [style](crate::CellStyle) markers such as `@` for circled squares also apply to the entry.
+    /// Only the initial styles (circled and initially revealed) are given to the puzzle itself, while a trailing `%` marks the square as [shaded](crate::CellDecoration::shaded).
+    /// The time spent solving is read from the `timer` property as the elapsed seconds and whether the timer is stopped (`1`) or running (`0`).
+    /// ```
+    /// use puzzled::core::CellStyle;
+    /// use puzzled::crossword::{Crossword, Position, Solution, Solve};
+    /// use puzzled::io::text::FormatConfig;
+    ///
+    /// let text = r#"
+    ///     [A@ (A) B]
+    ///     [C! (X) .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     timer: 42,1
+    /// "#;
+    ///
+    /// let (puzzle, state) = Crossword::read_text_state(text, &FormatConfig::default()).unwrap();
+    ///
+    /// assert_eq!(state.entry(&Position::new(0, 0)), Some(&Solution::Letter('A')));
+    /// assert_eq!(state.entry(&Position::new(0, 1)), None);
+    /// let style = state.entries.get_fill(Position::new(1, 0)).unwrap().style();
+    /// assert!(style.contains(CellStyle::INCORRECT));
+    /// assert_eq!(state.timer.elapsed().as_secs(), 42);
+    ///
+    /// assert_eq!(puzzle.write_text_state(&state, &FormatConfig::default()), "\
+    /// [ A@ (A) B ]
+    /// [ C! (X) . ]
+    /// - A: \"AB\"
+    /// - D: \"AC\"
+    ///
+    /// timer: 42,1
+    /// ");
+    /// ```
+    pub fn read_text_state(
+        input: &str,
+        config: &FormatConfig,
+    ) -> read::Result<(Crossword, CrosswordState)> {
+        let ((squares, entries), clues, (mut meta, timer)) = group((
+            grid(square_entry_with(solution_with(*config), *config))
+                .map(Grid::unzip)
+                .padded(),
+            clues(*config).padded(),
             metadata_with_timer().padded(),
         ))
         .parse(input)
         .into_result()
-        .map_err(|errs| {
-            read::Error::Parse(errs.into_iter().map(|err| format!("{err:#}")).collect())
-        })?;
+        .map_err(|errs| read::Error::parse(input, errs))?;
+
+        let numbering = match meta.remove_extra(NUMBERING) {
+            Some(found) => NumberingScheme::from_str(&found)
+                .map_err(|reason| read::Error::InvalidMetaProperty { found, reason })?,
+            None => NumberingScheme::default(),
+        };
+        let writing = match meta.remove_extra(WRITING) {
+            Some(found) => WritingDirection::from_str(&found)
+                .map_err(|reason| read::Error::InvalidMetaProperty { found, reason })?,
+            None => WritingDirection::default(),
+        };
 
-        let mut puzzle = Crossword::from_squares(squares, meta);
+        let solutions =
+            squares.map_ref(|square| square.map_ref(|cell| Some(cell.solution.clone())));
+
+        let mut puzzle = Crossword::from_squares(squares, meta)
+            .with_numbering(numbering)
+            .with_writing(writing);
         puzzle.insert_clues(clues);
 
-        Ok(puzzle)
-
-        // let ((squares, entries), clues, (metadata, timer)) =
-        //     parser.parse(input).into_result().map_err(|errs| {
-        //         read::Error::Parse(errs.into_iter().map(|err| format!("{err:#}")).collect())
-        //     })?;
-        //
-        // let solutions =
-        //     squares.map_ref(|square| square.map_ref(|cell| Some(cell.solution.clone())));
-        //
-        // let timer = timer.unwrap_or_default();
-        //
-        // let mut puzzle = Crossword::from_squares(squares, metadata);
-        // puzzle.insert_clues(clues);
-        //
-        // let state = CrosswordState::new(solutions, entries, timer);
-        //
-        // Ok((puzzle, state))
-    }
-
-    fn write_text(&self) -> String {
-        self.to_string()
+        let state = CrosswordState::new(solutions, entries, timer.unwrap_or_default())
+            .with_lock_state(puzzle.lock_state())
+            .with_check_policy(puzzle.check_policy());
+
+        Ok((puzzle, state))
+    }
+
+    /// Write the puzzle as text together with the entries and timer of the state, such that it can be [read back](Self::read_text_state)
+    pub fn write_text_state(&self, state: &CrosswordState, config: &FormatConfig) -> String {
+        let mut text = self.text(Some(&state.entries), config);
+
+        let timer = &state.timer;
+        text += &format!(
+            "timer: {},{}\n",
+            timer.elapsed().as_secs(),
+            u8::from(timer.state())
+        );
+
+        text
+    }
+
+    fn text(&self, entries: Option<&Grid<CrosswordEntry>>, config: &FormatConfig) -> String {
+        let mut text = format!("{}\n", squares_text(self.squares(), entries, config));
+        let bullet = config.bullet();
+
+        for clue in self.clues().values() {
+            text += &format!("{bullet} {}: \"{}\"\n", clue.direction(), clue.text());
+        }
+
+        // Crossword properties are stored as extra metadata, which is how they are read back
+        let mut meta = self.meta().clone();
+        if !self.numbering().is_standard() {
+            meta.insert_extra(NUMBERING.to_string(), self.numbering().to_string());
+        }
+        if self.writing().is_rtl() {
+            meta.insert_extra(WRITING.to_string(), self.writing().to_string());
+        }
+
+        text.push('\n');
+        text += &metadata_text(&meta);
+
+        text
+    }
+}
+
+impl TxtPuzzle for Crossword {
+    /// Read the puzzle from text, leaving out any entries of the solver
+    fn read_text_with(input: &str, config: &FormatConfig) -> read::Result<Crossword> {
+        Self::read_text_state(input, config).map(|(puzzle, _)| puzzle)
+    }
+
+    fn write_text_with(&self, config: &FormatConfig) -> String {
+        self.text(None, config)
+    }
+}
+
+impl TxtPuzzle for Crisscross {
+    fn read_text_with(input: &str, config: &FormatConfig) -> read::Result<Crisscross> {
+        let (squares, words, (meta, _)) = group((
+            grid(square_with(solution_with(*config), *config)).padded(),
+            words(*config).padded(),
+            metadata_with_timer().padded(),
+        ))
+        .parse(input)
+        .into_result()
+        .map_err(|errs| read::Error::parse(input, errs))?;
+
+        let words = words.iter().map(|word| word.to_uppercase());
+        Ok(Crisscross::new(squares, words, meta))
+    }
+
+    fn write_text_with(&self, config: &FormatConfig) -> String {
+        let mut text = format!("{}\n", squares_text(self.squares(), None, config));
+        let bullet = config.bullet();
+
+        for word in self.words() {
+            text += &format!("{bullet} {}\n", quoted(word));
+        }
+
+        text.push('\n');
+        text += &metadata_text(self.meta());
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::{CellStyle, Position, Solve};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::crossword;
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [A B .]
+            [C DEF G]
+            - A: "AB"
+            - A: "CDEFG"
+            - D: "AC"
+            - D: "BDEF"
+            title: "Mini"
+            theme_note: "Rebus in the middle"
+        )
+    }
+
+    #[rstest]
+    #[case::default(FormatConfig::default(), "[ A B   . ]\n[ C DEF G ]\n- A: \"AB\"")]
+    #[case::custom(
+        FormatConfig::default()
+            .with_block('#')
+            .with_bullet('*')
+            .with_rebus_delimiters('{', '}'),
+        "[ A B     # ]\n[ C {DEF} G ]\n* A: \"AB\""
+    )]
+    fn write_read_with(#[case] config: FormatConfig, #[case] start: &str) {
+        let text = puzzle().write_text_with(&config);
+        assert!(text.starts_with(start), "{text}");
+
+        let read = Crossword::read_text_with(&text, &config).expect("Text should be readable");
+        assert_eq!(read, puzzle());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn write_read_date() {
+        let date = puzzled_core::find_date("Jan 5, 2022").expect("Date is valid");
+        let puzzle = puzzle();
+        let puzzle = Crossword::new(
+            puzzle.squares().clone(),
+            puzzle.clues().clone(),
+            puzzle.meta().clone().with_date(date),
+        );
+
+        let text = puzzle.write_text();
+        assert!(text.contains("date: 2022-01-05\n"), "{text}");
+
+        let read = Crossword::read_text(&text).expect("Text should be readable");
+        assert_eq!(read.meta().date(), Some(date));
+    }
+
+    #[test]
+    fn read_blocks() {
+        let config = FormatConfig::default().with_block('#').with_missing('+');
+        let puzzle = Crossword::read_text_with("[A +]\n[# C]", &config).expect("Valid text");
+
+        assert_eq!(puzzle.squares().get_fill((1, 0).into()), None);
+        assert_eq!(
+            puzzle
+                .squares()
+                .get_fill((0, 1).into())
+                .map(|cell| &cell.solution),
+            Some(&None)
+        );
+    }
+
+    #[rstest]
+    #[case::empty(vec![], "[ A B     . ]")]
+    #[case::letter(vec![((0, 1), Solution::Letter('X'))], "[ A B (X) . ]")]
+    #[case::rebus(vec![((1, 1), Solution::Rebus("DE".into()))], "[ C {DEF} ({DE}) G ]")]
+    fn write_read_state(#[case] entries: Vec<((usize, usize), Solution)>, #[case] line: &str) {
+        let config = FormatConfig::default().with_rebus_delimiters('{', '}');
+        let puzzle = puzzle();
+        let mut state = CrosswordState::from(&puzzle);
+        for pos in puzzle.squares().positions() {
+            state.clear(&pos);
+        }
+        for (pos, entry) in entries {
+            state.enter(&pos.into(), entry);
+        }
+
+        let text = puzzle.write_text_state(&state, &config);
+        assert!(text.lines().any(|l| l == line), "{text}");
+
+        let (read, read_state) =
+            Crossword::read_text_state(&text, &config).expect("Text should be readable");
+        assert_eq!(read, puzzle);
+        assert_eq!(read_state.entries, state.entries);
+    }
+
+    #[test]
+    fn write_read_styles() {
+        let config = FormatConfig::default();
+        let (puzzle, state) = Crossword::read_text_state("[A@% B! (X)]\n[C* (C) D~ (D)]", &config)
+            .expect("Valid text");
+
+        // Player flags are kept out of the puzzle
+        let cell = puzzle.squares().get_fill((0, 1).into()).unwrap();
+        assert_eq!(cell.style, CellStyle::empty());
+
+        let text = puzzle.write_text_state(&state, &config);
+        let (read, read_state) =
+            Crossword::read_text_state(&text, &config).expect("Text should be readable");
+
+        for pos in puzzle.squares().positions() {
+            let cell_style = |puzzle: &Crossword| {
+                let cell = puzzle.squares().get_fill(pos);
+                cell.map(|c| (c.style, c.decoration))
+            };
+            let entry_style =
+                |state: &CrosswordState| state.entries.get_fill(pos).map(|e| e.style());
+
+            assert_eq!(cell_style(&read), cell_style(&puzzle), "{pos:?}");
+            assert_eq!(entry_style(&read_state), entry_style(&state), "{pos:?}");
+        }
+    }
+
+    #[test]
+    fn read_styles() {
+        let (puzzle, state) =
+            Crossword::read_text_state("[A@ B* (B)]\n[C? (C) .]", &FormatConfig::default())
+                .expect("Valid text");
+
+        let cell = puzzle.squares().get_fill((0, 0).into()).unwrap();
+        assert!(cell.style.contains(CellStyle::CIRCLED));
+
+        let entry = state.entries.get_fill((1, 0).into()).unwrap();
+        assert_eq!(entry.entry(), Some(&Solution::Letter('C')));
+        assert!(entry.style().contains(CellStyle::PENCIL));
+        assert!(
+            state
+                .entries
+                .get_fill((0, 1).into())
+                .unwrap()
+                .style()
+                .contains(CellStyle::REVEALED)
+        );
+    }
+
+    #[test]
+    fn masked_round_trip() {
+        let text = "[A B _]\n[C D E]\n- A: \"AB\"\n- A: \"CDE\"\n- D: \"AC\"\n- D: \"BD\"\n";
+        let puzzle = Crossword::read_text(text).expect("Valid text");
+        assert!(!puzzle.squares().has_cell(Position::new(0, 2)));
+
+        let written = puzzle.write_text();
+        assert!(written.starts_with("[ A B _ ]"), "{written}");
+
+        let read = Crossword::read_text(&written).expect("Text should be readable");
+        assert_eq!(read.squares().mask(), puzzle.squares().mask());
+        assert_eq!(read, puzzle);
     }
 }
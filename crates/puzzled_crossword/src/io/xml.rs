@@ -0,0 +1,614 @@
+use puzzled_core::{
+    Cell, CellBorders, CellDecoration, CellShape, CellStyle, Color, Grid, Metadata, Position,
+    Square,
+};
+use puzzled_io::{Diagnostic, DiagnosticCode, Severity};
+
+use crate::{ClueDirection, ClueSpec, Crossword, Solution, io::grid_squares};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CrosswordXmlError {
+    #[error("XML is not well-formed at byte {offset}")]
+    InvalidXml { offset: usize },
+
+    #[error("Closing tag </{found}> does not match the open element")]
+    MismatchedTag { found: String },
+
+    #[error("Puzzle of type <{kind}> is not a crossword")]
+    UnsupportedPuzzle { kind: String },
+
+    #[error("Grid of the crossword is missing")]
+    MissingGrid,
+
+    #[error("Grid of {rows}x{cols} squares is too large to be read")]
+    GridTooLarge { rows: usize, cols: usize },
+
+    #[error("Attribute {name}=\"{value}\" of <{element}> is not valid")]
+    InvalidAttribute {
+        element: String,
+        name: String,
+        value: String,
+    },
+
+    #[error("Cell at {pos:?} is outside of the {rows}x{cols} grid")]
+    OutOfBounds {
+        pos: Position,
+        rows: usize,
+        cols: usize,
+    },
+}
+
+/// Puzzle types of the format that are not crosswords
+const OTHER_PUZZLES: [&str; 5] = ["acrostic", "coded", "sudoku", "word-search", "jigsaw"];
+
+impl Crossword {
+    /// Read a crossword from the `rectangular-puzzle` XML format of Crossword Compiler, which is also used by its `*.jpz` files
+    ///
+    /// The metadata is read from the `<title>`, `<creator>`, `<copyright>` and `<description>` of the puzzle.
+    /// Cells of the `<grid>` keep their solution, blocks, bars, background color and shape, where their `x` and `y` coordinates start from 1.
+    /// Clues are placed at the square that their `number` gives them, where the direction of their `<clues>` list is found from its title.
+    /// Clues that do not match the numbering of the grid are left out with a [warning](Severity::Warning), and lists without numbers are placed in order instead.
+    ///
+    /// Features that cannot be represented, such as void cells and clue lists in other directions, are left out with a [warning](Severity::Warning).
+    /// ```
+    /// use puzzled::crossword::{CellStyle, Crossword, Position};
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <crossword-compiler xmlns="http://crossword.info/xml/crossword-compiler">
+    ///   <rectangular-puzzle xmlns="http://crossword.info/xml/rectangular-puzzle">
+    ///     <metadata><title>Mini</title><creator>Eric</creator></metadata>
+    ///     <crossword>
+    ///       <grid width="2" height="2">
+    ///         <cell x="1" y="1" solution="A" number="1" background-shape="circle"/>
+    ///         <cell x="2" y="1" solution="B" number="2"/>
+    ///         <cell x="1" y="2" solution="C" number="3"/>
+    ///         <cell x="2" y="2" type="block"/>
+    ///       </grid>
+    ///       <clues><title><b>Across</b></title><clue number="1" format="2">A &amp; B</clue><clue number="3">C</clue></clues>
+    ///       <clues><title><b>Down</b></title><clue number="1" format="2">A, then C</clue><clue number="2">B</clue></clues>
+    ///     </crossword>
+    ///   </rectangular-puzzle>
+    /// </crossword-compiler>"#;
+    ///
+    /// let (puzzle, diagnostics) = Crossword::from_crossword_xml(xml)?;
+    /// assert!(diagnostics.is_empty());
+    /// assert_eq!(puzzle.meta().author(), Some("Eric"));
+    /// assert_eq!(puzzle.clues().values().next().unwrap().text(), "A & B");
+    ///
+    /// let cell = puzzle.squares()[Position::new(0, 0)].as_ref().unwrap();
+    /// assert!(cell.style.contains(CellStyle::CIRCLED));
+    /// # Ok::<(), puzzled::crossword::CrosswordXmlError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "xml")))]
+    pub fn from_crossword_xml(
+        xml: &str,
+    ) -> Result<(Crossword, Vec<Diagnostic>), CrosswordXmlError> {
+        let tokens = tokenize(xml)?;
+
+        let mut squares: Option<Grid<Square<Cell<Solution>>>> = None;
+        let mut meta = Metadata::default();
+        let mut specs = Vec::new();
+        let mut diagnostics = Vec::new();
+
+        let mut path: Vec<&str> = Vec::new();
+        let mut text = String::new();
+        let mut direction = None;
+        let mut clue_num = None;
+        let mut root = None;
+        let mut is_crossword = false;
+
+        for token in tokens {
+            match token {
+                Token::Open {
+                    name,
+                    attrs,
+                    closed,
+                } => {
+                    let element = Element {
+                        name,
+                        attrs: &attrs,
+                    };
+                    root.get_or_insert(name);
+
+                    match name {
+                        "crossword" => is_crossword = true,
+                        name if OTHER_PUZZLES.contains(&name) => {
+                            return Err(CrosswordXmlError::UnsupportedPuzzle {
+                                kind: name.to_string(),
+                            });
+                        }
+                        "grid" => {
+                            let cols = element.number("width")?;
+                            let rows = element.number("height")?;
+                            let len = grid_squares(rows, cols)
+                                .ok_or(CrosswordXmlError::GridTooLarge { rows, cols })?;
+                            let cells = vec![Square::new(Cell::new(None)); len];
+
+                            squares = Some(
+                                Grid::from_vec(cells, cols)
+                                    .map_err(|_| element.invalid("width"))?,
+                            );
+                        }
+                        "cell" => {
+                            let squares = squares.as_mut().ok_or(CrosswordXmlError::MissingGrid)?;
+                            read_cell(&element, squares, &mut diagnostics)?;
+                        }
+                        "clues" => direction = None,
+                        "clue" => {
+                            clue_num = element
+                                .get("number")
+                                .and_then(|num| num.trim().parse::<u8>().ok());
+                        }
+                        _ => {}
+                    }
+
+                    if !closed {
+                        if !is_formatted(&path) {
+                            text.clear();
+                        }
+                        path.push(name);
+                    }
+                }
+                Token::Text(str) => text += &str,
+                Token::Close(name) => {
+                    if path.pop() != Some(name) {
+                        return Err(CrosswordXmlError::MismatchedTag {
+                            found: name.to_string(),
+                        });
+                    }
+
+                    let parent = path.last().copied();
+                    let value = text.trim().to_string();
+
+                    match (parent, name) {
+                        (Some("metadata"), "title") => meta = meta.with_title(value),
+                        (Some("metadata"), "creator") => meta = meta.with_author(value),
+                        (Some("metadata"), "copyright") => meta = meta.with_copyright(value),
+                        (Some("metadata"), "description") => meta = meta.with_notes(value),
+                        (Some("clues"), "title") => {
+                            let title = value.to_lowercase();
+                            direction = if title.contains("across") {
+                                Some(ClueDirection::Across)
+                            } else if title.contains("down") {
+                                Some(ClueDirection::Down)
+                            } else {
+                                diagnostics.push(unsupported(format!(
+                                    "Clue list '{value}' is neither across nor down and is left out"
+                                )));
+                                None
+                            };
+                        }
+                        (Some("clues"), "clue") => {
+                            if let Some(direction) = direction {
+                                specs.push((clue_num, ClueSpec::new(direction, value)));
+                            }
+                        }
+                        _ if is_formatted(&path) => continue,
+                        _ => {}
+                    }
+
+                    text.clear();
+                }
+            }
+        }
+
+        if !is_crossword {
+            return Err(CrosswordXmlError::UnsupportedPuzzle {
+                kind: root.unwrap_or_default().to_string(),
+            });
+        }
+
+        let squares = squares.ok_or(CrosswordXmlError::MissingGrid)?;
+        let mut puzzle = Crossword::from_squares(squares, meta);
+
+        // Clues are placed at their number, unless the list does not number every clue
+        let numbered: Option<Vec<_>> = specs
+            .iter()
+            .map(|(num, spec)| num.map(|num| (num, spec.clone())))
+            .collect();
+
+        match numbered {
+            Some(numbered) if !numbered.is_empty() => {
+                for mismatch in puzzle.insert_numbered_clues(numbered) {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Warning,
+                        DiagnosticCode::XmlNumberingMismatch,
+                        mismatch.to_string(),
+                    ));
+                }
+            }
+            _ => {
+                for spec in puzzle.insert_clues(specs.into_iter().map(|(_, spec)| spec)) {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Warning,
+                        DiagnosticCode::XmlMissingClue,
+                        format!(
+                            "{} clue '{}' could not be placed",
+                            spec.direction(),
+                            spec.text()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok((puzzle, diagnostics))
+    }
+}
+
+fn read_cell(
+    element: &Element,
+    squares: &mut Grid<Square<Cell<Solution>>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), CrosswordXmlError> {
+    let col = element.number("x")?;
+    let row = element.number("y")?;
+    let (rows, cols) = (squares.rows(), squares.cols());
+
+    let pos = Position::new(row.wrapping_sub(1), col.wrapping_sub(1));
+    let square = squares
+        .get_checked_mut(pos)
+        .map_err(|_| CrosswordXmlError::OutOfBounds { pos, rows, cols })?;
+
+    match element.get("type") {
+        Some("block") => {
+            *square = Square::new_empty();
+            return Ok(());
+        }
+        Some(kind @ ("void" | "clue")) => {
+            diagnostics.push(unsupported(format!(
+                "Cell of type '{kind}' at {pos:?} is read as a block"
+            )));
+            *square = Square::new_empty();
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let solution = element
+        .get("solution")
+        .filter(|solution| !solution.is_empty())
+        .map(|solution| Solution::from(solution.to_uppercase()));
+
+    let mut cell = Cell::new(solution);
+    let mut decoration = CellDecoration::default();
+
+    match element.get("background-shape") {
+        None => {}
+        Some("circle") => cell.style |= CellStyle::CIRCLED,
+        Some(shape) => match CellShape::from_name(shape) {
+            Some(shape) => decoration = decoration.with_shape(shape),
+            None => diagnostics.push(unsupported(format!(
+                "Background shape '{shape}' at {pos:?} is left out"
+            ))),
+        },
+    }
+
+    if let Some(color) = element.get("background-color") {
+        let color = color
+            .parse::<Color>()
+            .map_err(|_| element.invalid("background-color"))?;
+        decoration = decoration.with_background(color);
+    }
+
+    let mut bars = CellBorders::empty();
+    for (side, border) in [
+        ("top-bar", CellBorders::TOP),
+        ("right-bar", CellBorders::RIGHT),
+        ("bottom-bar", CellBorders::BOTTOM),
+        ("left-bar", CellBorders::LEFT),
+    ] {
+        bars.set(border, element.get(side) == Some("true"));
+    }
+
+    *square = Square::new(cell.with_decoration(decoration.with_bars(bars)));
+    Ok(())
+}
+
+/// Whether the element is inside a clue or title, where formatting is kept as plain text
+fn is_formatted(path: &[&str]) -> bool {
+    path.iter().any(|&name| name == "clue" || name == "title")
+}
+
+fn unsupported(message: String) -> Diagnostic {
+    Diagnostic::new(
+        Severity::Warning,
+        DiagnosticCode::XmlUnsupportedFeature,
+        message,
+    )
+}
+
+/// Opening tag of an element together with its attributes
+struct Element<'a, 'b> {
+    name: &'a str,
+    attrs: &'b [(&'a str, String)],
+}
+
+impl Element<'_, '_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(attr, _)| *attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn number(&self, name: &str) -> Result<usize, CrosswordXmlError> {
+        self.get(name)
+            .and_then(|value| value.trim().parse().ok())
+            .ok_or_else(|| self.invalid(name))
+    }
+
+    fn invalid(&self, name: &str) -> CrosswordXmlError {
+        CrosswordXmlError::InvalidAttribute {
+            element: self.name.to_string(),
+            name: name.to_string(),
+            value: self.get(name).unwrap_or_default().to_string(),
+        }
+    }
+}
+
+/// Part of an XML document that the reader is interested in
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open {
+        name: &'a str,
+        attrs: Vec<(&'a str, String)>,
+        closed: bool,
+    },
+    Close(&'a str),
+    Text(String),
+}
+
+/// Split the document into tags and text, skipping declarations, comments and namespaces prefixes
+fn tokenize(xml: &str) -> Result<Vec<Token<'_>>, CrosswordXmlError> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+
+    while offset < xml.len() {
+        let rest = &xml[offset..];
+        let invalid = move || CrosswordXmlError::InvalidXml { offset };
+
+        let Some(rest) = rest.strip_prefix('<') else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(unescape(&rest[..end])));
+            offset += end;
+            continue;
+        };
+
+        if let Some(data) = rest.strip_prefix("![CDATA[") {
+            let end = data.find("]]>").ok_or_else(invalid)?;
+            tokens.push(Token::Text(data[..end].to_string()));
+            offset += "<![CDATA[".len() + end + "]]>".len();
+            continue;
+        }
+
+        let terminator = match rest.chars().next() {
+            Some('?') => "?>",
+            Some('!') if rest.starts_with("!--") => "-->",
+            _ => ">",
+        };
+        let end = rest.find(terminator).ok_or_else(invalid)?;
+        let tag = &rest[..end];
+        offset += 1 + end + terminator.len();
+
+        if tag.starts_with(['?', '!']) {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(local_name(name.trim())));
+            continue;
+        }
+
+        let (tag, closed) = match tag.strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let (name, mut attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+        let mut parsed = Vec::new();
+        while let Some((attr, value)) = attrs.split_once('=') {
+            let value = value.trim_start();
+            let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'');
+            let quote = quote.ok_or_else(invalid)?;
+
+            let value = &value[1..];
+            let end = value.find(quote).ok_or_else(invalid)?;
+
+            parsed.push((local_name(attr.trim()), unescape(&value[..end])));
+            attrs = &value[end + 1..];
+        }
+
+        tokens.push(Token::Open {
+            name: local_name(name),
+            attrs: parsed,
+            closed,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Name of an element or attribute without its namespace prefix
+fn local_name(name: &str) -> &str {
+    name.rsplit_once(':').map_or(name, |(_, name)| name)
+}
+
+/// Replace the predefined entities and character references of XML
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped += &rest[..start];
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+
+        let char = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            entity => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+
+        match char {
+            Some(char) => {
+                unescaped.push(char);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped += rest;
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ClueDirection::*;
+
+    fn puzzle(grid: &str, clues: &str) -> String {
+        format!(
+            r#"<crossword-compiler><rectangular-puzzle><crossword>{grid}{clues}</crossword></rectangular-puzzle></crossword-compiler>"#
+        )
+    }
+
+    #[test]
+    fn cells() {
+        let grid = r##"<grid width="3" height="1">
+            <cell x="1" y="1" solution="c" right-bar="true" background-color="#FFCC00"/>
+            <cell x="2" y="1" solution="AT" background-shape="diamond"/>
+            <cell x="3" y="1"/>
+        </grid>"##;
+
+        let (puzzle, diagnostics) = Crossword::from_crossword_xml(&puzzle(grid, "")).unwrap();
+        assert!(diagnostics.is_empty());
+
+        let cell = |col| puzzle.squares()[Position::new(0, col)].as_ref().unwrap();
+        assert_eq!(cell(0).solution, Some(Solution::Letter('C')));
+        assert_eq!(cell(0).decoration.bars, CellBorders::RIGHT);
+        assert_eq!(cell(0).decoration.background, Some(Color::rgb(255, 204, 0)));
+        assert_eq!(cell(1).solution, Some(Solution::Rebus("AT".into())));
+        assert_eq!(cell(1).shape(), Some(CellShape::Diamond));
+        assert_eq!(cell(2).solution, None);
+    }
+
+    #[test]
+    fn clues() {
+        let grid = r#"<grid width="2" height="2">
+            <cell x="1" y="1" solution="A"/><cell x="2" y="1" solution="B"/>
+            <cell x="1" y="2" solution="C"/><cell x="2" y="2" type="block"/>
+        </grid>"#;
+        let clues = r#"
+            <clues><title><b>Across</b></title><clue number="1"><i>Ab</i> initio</clue></clues>
+            <clues><title>Down</title><clue number="1">AC</clue><clue number="2">B</clue><clue>Extra</clue></clues>
+            <clues><title>Diagonal</title><clue>Ignored</clue></clues>
+        "#;
+
+        let (puzzle, diagnostics) = Crossword::from_crossword_xml(&puzzle(grid, clues)).unwrap();
+
+        assert_eq!(puzzle.clues()[&(1, Across).into()].text(), "Ab initio");
+        assert_eq!(puzzle.clues()[&(2, Down).into()].text(), "B");
+
+        let codes: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                DiagnosticCode::XmlUnsupportedFeature,
+                DiagnosticCode::XmlMissingClue
+            ]
+        );
+    }
+
+    #[test]
+    fn numbered_clues() {
+        let grid = r#"<grid width="2" height="2">
+            <cell x="1" y="1" solution="A"/><cell x="2" y="1" solution="B"/>
+            <cell x="1" y="2" solution="C"/><cell x="2" y="2" type="block"/>
+        </grid>"#;
+        let clues = r#"
+            <clues><title>Across</title><clue number="1">AB</clue><clue number="4">Unknown</clue></clues>
+            <clues><title>Down</title><clue number="2">B</clue><clue number="1">AC</clue></clues>
+        "#;
+
+        let (puzzle, diagnostics) = Crossword::from_crossword_xml(&puzzle(grid, clues)).unwrap();
+
+        // Clues are never renumbered to fit the grid
+        assert_eq!(puzzle.clues()[&(1, Down).into()].text(), "AC");
+        assert_eq!(puzzle.clues()[&(2, Down).into()].text(), "B");
+        assert_eq!(puzzle.clues().len(), 3);
+
+        let messages: Vec<_> = diagnostics
+            .iter()
+            .inspect(|diagnostic| assert_eq!(diagnostic.code, DiagnosticCode::XmlNumberingMismatch))
+            .map(|diagnostic| diagnostic.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Clue 4-A 'Unknown' does not start in the grid",
+                "Clue 3-A starting at Position { row: 1, col: 0 } was not given"
+            ]
+        );
+    }
+
+    #[test]
+    fn void() {
+        let grid = r#"<grid width="1" height="1"><cell x="1" y="1" type="void"/></grid>"#;
+        let (puzzle, diagnostics) = Crossword::from_crossword_xml(&puzzle(grid, "")).unwrap();
+
+        assert!(puzzle.squares()[Position::new(0, 0)].is_none());
+        assert_eq!(diagnostics[0].code, DiagnosticCode::XmlUnsupportedFeature);
+    }
+
+    #[rstest]
+    #[case::plain("Plain text", "Plain text")]
+    #[case::entities("&lt;A &amp; B&gt;", "<A & B>")]
+    #[case::references("&#65;&#x42;", "AB")]
+    #[case::unknown("&nbsp; & more", "&nbsp; & more")]
+    fn unescape_text(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(unescape(text), expected);
+    }
+
+    #[rstest]
+    #[case::malformed("<crossword><grid width=\"2\"", CrosswordXmlError::InvalidXml { offset: 11 })]
+    #[case::acrostic("<rectangular-puzzle><acrostic/></rectangular-puzzle>", CrosswordXmlError::UnsupportedPuzzle { kind: "acrostic".into() })]
+    #[case::mismatched("<crossword><metadata></crossword>", CrosswordXmlError::MismatchedTag { found: "crossword".into() })]
+    #[case::word_search("<puzzle><grid width=\"1\" height=\"1\"/></puzzle>", CrosswordXmlError::UnsupportedPuzzle { kind: "puzzle".into() })]
+    #[case::no_grid("<crossword></crossword>", CrosswordXmlError::MissingGrid)]
+    #[case::too_large(
+        "<crossword><grid width=\"18446744073709551615\" height=\"2\"/></crossword>",
+        CrosswordXmlError::GridTooLarge { rows: 2, cols: usize::MAX }
+    )]
+    #[case::attribute(
+        "<crossword><grid width=\"x\" height=\"1\"/></crossword>",
+        CrosswordXmlError::InvalidAttribute { element: "grid".into(), name: "width".into(), value: "x".into() }
+    )]
+    #[case::bounds(
+        "<crossword><grid width=\"1\" height=\"1\"><cell x=\"2\" y=\"1\"/></grid></crossword>",
+        CrosswordXmlError::OutOfBounds { pos: Position::new(0, 1), rows: 1, cols: 1 }
+    )]
+    fn errors(#[case] xml: &str, #[case] err: CrosswordXmlError) {
+        assert_eq!(Crossword::from_crossword_xml(xml).map(|_| ()), Err(err));
+    }
+}
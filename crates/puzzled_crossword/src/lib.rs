@@ -38,14 +38,19 @@
 //! [serde]: https://docs.rs/serde
 //! [thiserror]: https://docs.rs/serde
 
+pub mod collab;
+pub mod crisscross;
 mod io;
+pub mod lint;
+pub mod patterns;
 mod puzzle;
+pub mod solve;
 
 #[doc(hidden)]
 pub use puzzled_core::*;
 
 #[doc(inline)]
-pub use puzzle::*;
+pub use {io::*, puzzle::*, solve::*};
 
 #[cfg(feature = "macros")]
 mod macros;
@@ -0,0 +1,703 @@
+//! Checks that editors run on a [crossword](crate::Crossword) before publishing it
+//!
+//! [Checking](check) a puzzle reports every [finding](LintFinding) of the rules below, each with its own [code](LintCode) and the squares or clues it [spans](LintSpan):
+//! - The same answer is used twice, or an answer appears in its own clue or in another clue
+//! - A multi-word answer, as hinted by a clue like `(2 wds.)`, has no enumeration, such as `(3,4)`, or an enumeration does not add up to the length of its answer
+//! - The black squares do not follow the [symmetry](crate::Symmetry) of the grid, or there are too many of them
+//! - A black square is a cheater, i.e. it does not change the number of words
+//! - A word is shorter than the minimum length
+//!
+//! Words that should not appear in the fill at all, such as profanity, are found by [screening](screen) the grid against a [word set](WordScreen).
+//!
+//! ```
+//! use puzzled::crossword::{crossword, lint::{self, LintCode, LintConfig}};
+//!
+//! let puzzle = crossword!(
+//!     [C A B]
+//!     [O R E]
+//!     [T E N]
+//!     - A: "Taxi"
+//!     - A: "Mined rock"
+//!     - A: "Number of fingers"
+//!     - D: "Small bed"
+//!     - D: "Unit of land, or the size of a cab's floor"
+//!     - D: "Mountain, in Scotland"
+//! );
+//!
+//! let findings = lint::check(&puzzle, LintConfig::new());
+//!
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].code, LintCode::AnswerInOtherClue);
+//! assert_eq!(findings[0].to_string(), "LINT002_ANSWER_IN_OTHER_CLUE: Answer CAB of 1-A appears in the clue of 2-D");
+//! ```
+mod screen;
+
+pub use screen::*;
+
+use std::{collections::BTreeMap, fmt};
+
+use puzzled_core::Position;
+
+use crate::{Clue, ClueId, Crossword, Squares, SymmetricSquares, Symmetry};
+
+/// Rule of the [linter](check) that a [finding](LintFinding) breaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintCode {
+    /// Same answer is used by multiple clues
+    DuplicateAnswer,
+
+    /// Answer of a clue appears as a word in the clue of another
+    AnswerInOtherClue,
+
+    /// Answer of a clue appears as a word in its own clue
+    AnswerInClue,
+
+    /// Multi-word answer has no enumeration, or the puzzle uses enumerations and the clue does not
+    MissingEnumeration,
+
+    /// Enumeration of a clue does not add up to the length of its answer
+    EnumerationMismatch,
+
+    /// Black squares do not follow the symmetry of the grid
+    NonSymmetric,
+
+    /// Grid has more black squares than allowed
+    TooManyBlocks,
+
+    /// Black square does not change the number of words in the grid
+    CheaterSquare,
+
+    /// Word is shorter than the minimum length
+    ShortWord,
+}
+
+impl LintCode {
+    /// Every lint code in order
+    pub const ALL: [LintCode; 9] = [
+        Self::DuplicateAnswer,
+        Self::AnswerInOtherClue,
+        Self::AnswerInClue,
+        Self::MissingEnumeration,
+        Self::EnumerationMismatch,
+        Self::NonSymmetric,
+        Self::TooManyBlocks,
+        Self::CheaterSquare,
+        Self::ShortWord,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DuplicateAnswer => "LINT001_DUPLICATE_ANSWER",
+            Self::AnswerInOtherClue => "LINT002_ANSWER_IN_OTHER_CLUE",
+            Self::AnswerInClue => "LINT003_ANSWER_IN_CLUE",
+            Self::MissingEnumeration => "LINT004_MISSING_ENUMERATION",
+            Self::EnumerationMismatch => "LINT005_ENUMERATION_MISMATCH",
+            Self::NonSymmetric => "LINT006_NON_SYMMETRIC",
+            Self::TooManyBlocks => "LINT007_TOO_MANY_BLOCKS",
+            Self::CheaterSquare => "LINT008_CHEATER_SQUARE",
+            Self::ShortWord => "LINT009_SHORT_WORD",
+        }
+    }
+}
+
+impl fmt::Display for LintCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Part of the puzzle that a [finding](LintFinding) points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LintSpan {
+    /// Grid as a whole
+    Grid,
+
+    /// Squares at the positions
+    Squares(Vec<Position>),
+
+    /// Clues with the identifiers
+    Clues(Vec<ClueId>),
+}
+
+/// Rule that the puzzle breaks, together with the part of the puzzle that breaks it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFinding {
+    pub code: LintCode,
+    pub span: LintSpan,
+    pub message: String,
+}
+
+impl LintFinding {
+    pub fn new<S: Into<String>>(code: LintCode, span: LintSpan, message: S) -> Self {
+        Self {
+            code,
+            span,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+/// Configuration of the grid rules of the [linter](check)
+///
+/// By default, grids should have [180° rotational](Symmetry::Rotational180) symmetry, at most a sixth of black squares and words of at least 3 letters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LintConfig {
+    symmetry: Option<Symmetry>,
+    max_block_ratio: f32,
+    min_word_len: u8,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LintConfig {
+    pub fn new() -> Self {
+        Self {
+            symmetry: Some(Symmetry::Rotational180),
+            max_block_ratio: 1.0 / 6.0,
+            min_word_len: 3,
+        }
+    }
+
+    /// Define the symmetry that the black squares should follow, or [`None`] to allow any grid
+    pub fn with_symmetry(mut self, symmetry: Option<Symmetry>) -> Self {
+        self.symmetry = symmetry;
+        self
+    }
+
+    /// Define the largest fraction of the squares that can be black
+    pub fn with_max_block_ratio(mut self, max_block_ratio: f32) -> Self {
+        self.max_block_ratio = max_block_ratio;
+        self
+    }
+
+    /// Define the least number of letters of every word
+    pub fn with_min_word_len(mut self, min_word_len: u8) -> Self {
+        self.min_word_len = min_word_len;
+        self
+    }
+}
+
+/// Find every rule that the crossword breaks, ordered by [code](LintCode)
+///
+/// Rules on answers only consider clues of which every square has a solution.
+pub fn check(crossword: &Crossword, config: LintConfig) -> Vec<LintFinding> {
+    let answers: Vec<_> = crossword
+        .clues()
+        .values()
+        .filter_map(|clue| answer(crossword, clue).map(|answer| (clue, answer)))
+        .collect();
+
+    let mut findings = Vec::new();
+    check_answers(&answers, &mut findings);
+    check_enumerations(crossword, &answers, &mut findings);
+
+    let squares = crossword.squares();
+    check_symmetry(squares, config, &mut findings);
+    check_blocks(squares, config, &mut findings);
+    check_word_len(crossword, config, &mut findings);
+
+    findings.sort_by_key(|finding| finding.code);
+    findings
+}
+
+/// Find duplicate answers and answers that appear in clues
+fn check_answers(answers: &[(&Clue, String)], findings: &mut Vec<LintFinding>) {
+    let mut by_word: BTreeMap<String, Vec<ClueId>> = BTreeMap::new();
+    for (clue, answer) in answers {
+        by_word.entry(letters(answer)).or_default().push(clue.id());
+    }
+
+    for (word, ids) in &by_word {
+        if ids.len() > 1 {
+            let list = join(ids);
+            findings.push(LintFinding::new(
+                LintCode::DuplicateAnswer,
+                LintSpan::Clues(ids.clone()),
+                format!("Answer {word} is used by {list}"),
+            ));
+        }
+    }
+
+    for (clue, _) in answers {
+        for term in clue.text().split(|char: char| !char.is_alphanumeric()) {
+            let Some(ids) = by_word.get(&letters(term)) else {
+                continue;
+            };
+
+            for &id in ids {
+                let (code, span) = match id == clue.id() {
+                    true => (LintCode::AnswerInClue, vec![id]),
+                    false => (LintCode::AnswerInOtherClue, vec![id, clue.id()]),
+                };
+
+                let finding = LintFinding::new(
+                    code,
+                    LintSpan::Clues(span),
+                    format!(
+                        "Answer {} of {id} appears in the clue of {}",
+                        letters(term),
+                        clue.id()
+                    ),
+                );
+                if !findings.contains(&finding) {
+                    findings.push(finding);
+                }
+            }
+        }
+    }
+}
+
+/// Find clues that should have an enumeration, or whose enumeration does not fit their answer
+fn check_enumerations(
+    crossword: &Crossword,
+    answers: &[(&Clue, String)],
+    findings: &mut Vec<LintFinding>,
+) {
+    let uses_enumerations = crossword
+        .clues()
+        .values()
+        .any(|clue| enumeration(clue.text()).is_some());
+
+    for (clue, answer) in answers {
+        // Answers in the grid have no spaces, so words are only split by rebus squares or a hint in the clue
+        let multi_word =
+            answer.contains([' ', '-']) || word_count(clue.text()).is_some_and(|count| count > 1);
+        let span = LintSpan::Clues(vec![clue.id()]);
+
+        match enumeration(clue.text()) {
+            None if multi_word => findings.push(LintFinding::new(
+                LintCode::MissingEnumeration,
+                span,
+                format!(
+                    "Clue {} has multi-word answer {answer}, but no enumeration",
+                    clue.id()
+                ),
+            )),
+            None if uses_enumerations => findings.push(LintFinding::new(
+                LintCode::MissingEnumeration,
+                span,
+                format!("Clue {} has no enumeration, unlike other clues", clue.id()),
+            )),
+            Some(lengths) if lengths.iter().sum::<usize>() != letters(answer).chars().count() => {
+                findings.push(LintFinding::new(
+                    LintCode::EnumerationMismatch,
+                    span,
+                    format!(
+                        "Enumeration of clue {} does not add up to the length of {answer}",
+                        clue.id()
+                    ),
+                ))
+            }
+            Some(lengths) if multi_word && lengths.len() == 1 => findings.push(LintFinding::new(
+                LintCode::MissingEnumeration,
+                span,
+                format!(
+                    "Enumeration of clue {} does not split the words of {answer}",
+                    clue.id()
+                ),
+            )),
+            _ => {}
+        }
+    }
+}
+
+/// Find the black squares whose partner is not black
+fn check_symmetry(squares: &Squares, config: LintConfig, findings: &mut Vec<LintFinding>) {
+    let Some(symmetry) = config.symmetry else {
+        return;
+    };
+
+    if !symmetry.supports(squares.rows(), squares.cols()) {
+        findings.push(LintFinding::new(
+            LintCode::NonSymmetric,
+            LintSpan::Grid,
+            format!(
+                "Grid of {}x{} cannot have {symmetry} symmetry",
+                squares.rows(),
+                squares.cols()
+            ),
+        ));
+        return;
+    }
+
+    let asymmetric: Vec<_> = squares
+        .positions()
+        .filter(|&pos| {
+            !squares.is_fill(pos)
+                && squares
+                    .symmetric_partner(pos, symmetry)
+                    .is_some_and(|partner| squares.is_fill(partner))
+        })
+        .collect();
+
+    if !asymmetric.is_empty() {
+        findings.push(LintFinding::new(
+            LintCode::NonSymmetric,
+            LintSpan::Squares(asymmetric),
+            format!("Black squares do not follow {symmetry} symmetry"),
+        ));
+    }
+}
+
+/// Find whether there are too many black squares and which of them are cheaters
+fn check_blocks(squares: &Squares, config: LintConfig, findings: &mut Vec<LintFinding>) {
+    let blocks: Vec<_> = squares
+        .positions()
+        .filter(|&pos| !squares.is_fill(pos))
+        .collect();
+
+    let total = squares.rows() * squares.cols();
+    if total > 0 && blocks.len() as f32 / total as f32 > config.max_block_ratio {
+        findings.push(LintFinding::new(
+            LintCode::TooManyBlocks,
+            LintSpan::Grid,
+            format!("Grid has {} black squares out of {total}", blocks.len()),
+        ));
+    }
+
+    let cheaters: Vec<_> = blocks
+        .into_iter()
+        .filter(|&pos| is_cheater(squares, pos))
+        .collect();
+
+    if !cheaters.is_empty() {
+        findings.push(LintFinding::new(
+            LintCode::CheaterSquare,
+            LintSpan::Squares(cheaters),
+            "Black squares do not change the number of words",
+        ));
+    }
+}
+
+/// Find the words that are shorter than the minimum length
+fn check_word_len(crossword: &Crossword, config: LintConfig, findings: &mut Vec<LintFinding>) {
+    let short: Vec<_> = crossword
+        .clues()
+        .values()
+        .filter(|clue| clue.len() < config.min_word_len)
+        .map(Clue::id)
+        .collect();
+
+    if !short.is_empty() {
+        findings.push(LintFinding::new(
+            LintCode::ShortWord,
+            LintSpan::Clues(short.clone()),
+            format!(
+                "Words of {} are shorter than {} letters",
+                join(&short),
+                config.min_word_len
+            ),
+        ));
+    }
+}
+
+/// Whether the black square at the position could be filled in without changing the number of words
+///
+/// Black squares that are surrounded by other black squares do not separate any words, so they are never cheaters.
+fn is_cheater(squares: &Squares, pos: Position) -> bool {
+    // Number of fill squares in a line from the position, excluding the position itself
+    let run = |d_row: isize, d_col: isize| {
+        (1..)
+            .map(|step| {
+                let row = pos.row.checked_add_signed(d_row * step)?;
+                let col = pos.col.checked_add_signed(d_col * step)?;
+                Some(Position::new(row, col))
+            })
+            .take_while(|pos| pos.is_some_and(|pos| squares.is_fill(pos)))
+            .count()
+    };
+
+    // Change in the number of words of a line when the square would be filled in
+    let words = |before: usize, after: usize| {
+        let is_word = |len: usize| isize::from(len >= 2);
+        is_word(before + after + 1) - is_word(before) - is_word(after)
+    };
+
+    let (left, right) = (run(0, -1), run(0, 1));
+    let (up, down) = (run(-1, 0), run(1, 0));
+
+    left + right + up + down > 0 && words(left, right) + words(up, down) == 0
+}
+
+/// Answer that the solutions of the squares of the clue spell out, if every square has a solution
+fn answer(crossword: &Crossword, clue: &Clue) -> Option<String> {
+    clue.positions()
+        .map(|pos| {
+            let cell = crossword.squares().get_fill(pos)?;
+            cell.solution
+                .as_ref()
+                .map(|solution| solution.primary().to_string())
+        })
+        .collect()
+}
+
+/// Enumeration at the end of the clue text, such as `(3,4)` or `(5-3)`
+fn enumeration(text: &str) -> Option<Vec<usize>> {
+    let inner = text.trim_end().strip_suffix(')')?;
+    let (_, inner) = inner.rsplit_once('(')?;
+
+    inner
+        .split([',', '-', ' '])
+        .filter(|len| !len.is_empty())
+        .map(|len| len.parse().ok())
+        .collect::<Option<Vec<_>>>()
+        .filter(|lengths| !lengths.is_empty())
+}
+
+/// Number of words that the clue text hints at, such as `(2 wds.)` or `(3 words)`
+fn word_count(text: &str) -> Option<usize> {
+    let inner = text.trim_end().strip_suffix(')')?;
+    let (_, inner) = inner.rsplit_once('(')?;
+    let (count, unit) = inner.trim().split_once(' ')?;
+
+    matches!(
+        unit.trim(),
+        "wd" | "wd." | "wds" | "wds." | "word" | "words"
+    )
+    .then(|| count.parse().ok())
+    .flatten()
+}
+
+/// Uppercase letters and digits of the text
+fn letters(text: &str) -> String {
+    text.chars()
+        .filter(|char| char.is_alphanumeric())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+fn join(ids: &[ClueId]) -> String {
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection::*, crossword};
+
+    fn codes(crossword: &Crossword, config: LintConfig) -> Vec<LintCode> {
+        check(crossword, config)
+            .into_iter()
+            .map(|finding| finding.code)
+            .collect()
+    }
+
+    #[test]
+    fn clean() {
+        let puzzle = crossword!(
+            [C A B]
+            [O R E]
+            [T E N]
+            - A: "Taxi"
+            - A: "Mined rock"
+            - A: "Number of fingers"
+            - D: "Small bed"
+            - D: "Unit of land"
+            - D: "Mountain, in Scotland"
+        );
+
+        assert!(check(&puzzle, LintConfig::new()).is_empty());
+    }
+
+    #[test]
+    fn answers() {
+        let puzzle = crossword!(
+            [C A T]
+            [. . .]
+            [C A T]
+            - A: "Pet"
+            - A: "Cat's meow, e.g."
+        );
+
+        let findings = check(&puzzle, LintConfig::new().with_max_block_ratio(1.0));
+        let spans: Vec<_> = findings
+            .iter()
+            .map(|finding| (finding.code, finding.span.clone()))
+            .collect();
+
+        assert_eq!(
+            spans,
+            [
+                (
+                    LintCode::DuplicateAnswer,
+                    LintSpan::Clues(vec![(1, Across).into(), (2, Across).into()])
+                ),
+                (
+                    LintCode::AnswerInOtherClue,
+                    LintSpan::Clues(vec![(1, Across).into(), (2, Across).into()])
+                ),
+                (
+                    LintCode::AnswerInClue,
+                    LintSpan::Clues(vec![(2, Across).into()])
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::none(["Feline", "Top card"], vec![])]
+    #[case::all(["Feline (3)", "Top card (4)"], vec![LintCode::EnumerationMismatch])]
+    #[case::some(["Feline (3)", "Top card"], vec![LintCode::MissingEnumeration])]
+    fn enumerations(#[case] texts: [&str; 2], #[case] expected: Vec<LintCode>) {
+        let mut puzzle = crossword!(
+            [C A T]
+            [. . .]
+            [A C E]
+            - A: "Feline"
+            - A: "Top card"
+        );
+        puzzle.clues_mut().retext((1, Across), texts[0]);
+        puzzle.clues_mut().retext((2, Across), texts[1]);
+
+        let config = LintConfig::new().with_max_block_ratio(1.0);
+        assert_eq!(codes(&puzzle, config), expected);
+    }
+
+    #[rstest]
+    #[case::hint("Cold treat (2 wds.)", vec![LintCode::MissingEnumeration])]
+    #[case::single_word_hint("Cold treat (1 wd.)", vec![])]
+    #[case::enumeration("Cold treat (3,5)", vec![])]
+    #[case::unsplit_enumeration("Cold treat (8)", vec![])]
+    fn multi_word_hint(#[case] text: &str, #[case] expected: Vec<LintCode>) {
+        let mut puzzle = crossword!(
+            [I C E C R E A M]
+            - A: "Cold treat"
+        );
+        puzzle.clues_mut().retext((1, Across), text);
+
+        assert_eq!(codes(&puzzle, LintConfig::new()), expected);
+    }
+
+    #[test]
+    fn multi_word() {
+        let mut puzzle = crossword!(
+            [A B]
+            - A: "Ice cream (3,5)"
+        );
+        if let Some(cell) = puzzle.squares_mut()[Position::new(0, 0)].as_mut() {
+            cell.solution = Some("ICE CREA".into());
+        }
+
+        let config = LintConfig::new().with_min_word_len(2);
+        assert!(codes(&puzzle, config).is_empty());
+
+        puzzle.clues_mut().retext((1, Across), "Ice cream (8)");
+        assert_eq!(codes(&puzzle, config), [LintCode::MissingEnumeration]);
+
+        puzzle.clues_mut().retext((1, Across), "Ice cream");
+        assert_eq!(codes(&puzzle, config), [LintCode::MissingEnumeration]);
+    }
+
+    #[test]
+    fn grid() {
+        let puzzle = crossword!(
+            [. . C A T]
+            [. A R E A]
+            [S E E D S]
+            [T R A D E]
+            [O O T . .]
+        );
+
+        let findings = check(&puzzle, LintConfig::new().with_min_word_len(0));
+        let spans: Vec<_> = findings
+            .iter()
+            .map(|finding| (finding.code, finding.span.clone()))
+            .collect();
+
+        assert_eq!(
+            spans,
+            [
+                (
+                    LintCode::NonSymmetric,
+                    LintSpan::Squares(vec![Position::new(1, 0)])
+                ),
+                (LintCode::TooManyBlocks, LintSpan::Grid),
+                (
+                    LintCode::CheaterSquare,
+                    LintSpan::Squares(vec![
+                        Position::new(0, 1),
+                        Position::new(1, 0),
+                        Position::new(4, 3),
+                        Position::new(4, 4)
+                    ])
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::rot90(Symmetry::Rotational90, LintSpan::Grid)]
+    #[case::mirror(Symmetry::MirrorLeftRight, LintSpan::Squares(vec![Position::new(0, 0)]))]
+    fn asymmetric(#[case] symmetry: Symmetry, #[case] span: LintSpan) {
+        let puzzle = crossword!(
+            [. A B]
+            [C D E]
+        );
+
+        let config = LintConfig::new()
+            .with_symmetry(Some(symmetry))
+            .with_max_block_ratio(1.0)
+            .with_min_word_len(0);
+        let findings = check(&puzzle, config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].span, span);
+    }
+
+    #[test]
+    fn short_words() {
+        let puzzle = crossword!(
+            [A B .]
+            [C D E]
+            - A: "First two letters"
+            - A: "Next three letters"
+            - D: "Air conditioning"
+            - D: "Doctoral degree"
+        );
+
+        let config = LintConfig::new()
+            .with_symmetry(None)
+            .with_max_block_ratio(1.0);
+        let findings = check(&puzzle, config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, LintCode::ShortWord);
+        assert_eq!(
+            findings[0].span,
+            LintSpan::Clues(vec![(1, Across).into(), (1, Down).into(), (2, Down).into()])
+        );
+    }
+
+    #[rstest]
+    #[case::comma("Pet (3,4)", Some(vec![3, 4]))]
+    #[case::hyphen("Pet (5-3) ", Some(vec![5, 3]))]
+    #[case::words("Pet (not a dog)", None)]
+    #[case::none("Pet", None)]
+    fn enumeration(#[case] text: &str, #[case] expected: Option<Vec<usize>>) {
+        assert_eq!(super::enumeration(text), expected);
+    }
+
+    #[rstest]
+    #[case::abbreviated("Pet (2 wds.)", Some(2))]
+    #[case::words("Pet (3 words)", Some(3))]
+    #[case::enumeration("Pet (3,4)", None)]
+    #[case::none("Pet", None)]
+    fn word_count(#[case] text: &str, #[case] expected: Option<usize>) {
+        assert_eq!(super::word_count(text), expected);
+    }
+}
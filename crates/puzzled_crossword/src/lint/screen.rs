@@ -0,0 +1,264 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::BuildHasher,
+};
+
+use puzzled_core::Position;
+
+use crate::{ClueId, Crossword};
+
+/// Set of words that should not appear in a [crossword](Crossword), such as words that fail the breakfast test
+///
+/// The screen is given every string of letters in the fill as uppercase letters, such that it decides which of them to flag.
+/// It is implemented for sets of uppercase words and for slices of words in any casing, but can be implemented for anything else, e.g. to look words up in a dictionary service.
+pub trait WordScreen {
+    /// Whether the word, given in uppercase letters, should be flagged
+    fn is_flagged(&self, word: &str) -> bool;
+}
+
+impl<H: BuildHasher> WordScreen for HashSet<String, H> {
+    fn is_flagged(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+}
+
+impl WordScreen for BTreeSet<String> {
+    fn is_flagged(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+}
+
+impl<S: AsRef<str>> WordScreen for [S] {
+    fn is_flagged(&self, word: &str) -> bool {
+        self.iter()
+            .any(|flagged| flagged.as_ref().eq_ignore_ascii_case(word))
+    }
+}
+
+impl<S: AsRef<str>> WordScreen for Vec<S> {
+    fn is_flagged(&self, word: &str) -> bool {
+        self.as_slice().is_flagged(word)
+    }
+}
+
+/// Direction in which a [screened](screen) string reads through the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScreenDirection {
+    /// Left to right
+    Across,
+
+    /// Top to bottom
+    Down,
+
+    /// Top left to bottom right
+    DownRight,
+
+    /// Top right to bottom left
+    DownLeft,
+}
+
+impl ScreenDirection {
+    pub const ALL: [ScreenDirection; 4] =
+        [Self::Across, Self::Down, Self::DownRight, Self::DownLeft];
+
+    /// Change in row and column from one square to the next
+    fn step(&self) -> (isize, isize) {
+        match self {
+            Self::Across => (0, 1),
+            Self::Down => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::DownLeft => (1, -1),
+        }
+    }
+}
+
+/// String of the fill that was flagged by a [screen](WordScreen)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenMatch {
+    /// Flagged word in uppercase letters
+    pub word: String,
+
+    /// Positions of the squares that spell out the word
+    pub positions: Vec<Position>,
+    pub direction: ScreenDirection,
+
+    /// Clue whose answer is exactly the word, or [`None`] if the word is hidden in the fill
+    pub clue: Option<ClueId>,
+}
+
+/// Find every answer and every string in the fill that the screen flags
+///
+/// Strings are read across, down and along both diagonals, where every string of consecutive squares with a solution is screened.
+/// Hence a flagged word is found when it is an answer, when it is hidden inside an answer, and when it appears by accident along a diagonal.
+/// Matches are ordered by direction and then by the positions of their squares.
+/// ```
+/// use puzzled::crossword::{crossword, lint::{self, ScreenDirection}, Position};
+///
+/// let puzzle = crossword!(
+///     [A C E]
+///     [O R E]
+///     [N E T]
+/// );
+///
+/// let matches = lint::screen(&puzzle, &["net", "art"][..]);
+/// let found: Vec<_> = matches.iter().map(|found| (found.word.as_str(), found.direction)).collect();
+///
+/// assert_eq!(found, [("NET", ScreenDirection::Across), ("ART", ScreenDirection::DownRight)]);
+/// assert_eq!(matches[1].positions, [Position::new(0, 0), Position::new(1, 1), Position::new(2, 2)]);
+/// ```
+pub fn screen<S: WordScreen + ?Sized>(crossword: &Crossword, screen: &S) -> Vec<ScreenMatch> {
+    let squares = crossword.squares();
+    let mut matches = Vec::new();
+
+    for direction in ScreenDirection::ALL {
+        let (d_row, d_col) = direction.step();
+
+        // Every line starts at a square whose predecessor is outside of the grid
+        let starts = squares.positions().filter(|pos| {
+            let prev_row = pos.row.checked_add_signed(-d_row);
+            let prev_col = pos.col.checked_add_signed(-d_col);
+
+            match (prev_row, prev_col) {
+                (Some(row), Some(col)) => row >= squares.rows() || col >= squares.cols(),
+                _ => true,
+            }
+        });
+
+        for start in starts {
+            let line = (0..).map_while(|step| {
+                let row = start.row.checked_add_signed(d_row * step)?;
+                let col = start.col.checked_add_signed(d_col * step)?;
+                let pos = Position::new(row, col);
+
+                (row < squares.rows() && col < squares.cols()).then_some(pos)
+            });
+
+            // Split the line into runs of squares that have a solution
+            let mut run: Vec<(Position, String)> = Vec::new();
+            for pos in line {
+                let letters = squares
+                    .get_fill(pos)
+                    .and_then(|cell| cell.solution.as_ref())
+                    .map(|solution| solution.primary().to_string().to_uppercase());
+
+                match letters {
+                    Some(letters) => run.push((pos, letters)),
+                    None => {
+                        screen_run(crossword, screen, &run, direction, &mut matches);
+                        run.clear();
+                    }
+                }
+            }
+            screen_run(crossword, screen, &run, direction, &mut matches);
+        }
+    }
+
+    matches.sort_by(|a, b| (a.direction, &a.positions).cmp(&(b.direction, &b.positions)));
+    matches
+}
+
+/// Screen every string of consecutive squares in the run
+fn screen_run<S: WordScreen + ?Sized>(
+    crossword: &Crossword,
+    screen: &S,
+    run: &[(Position, String)],
+    direction: ScreenDirection,
+    matches: &mut Vec<ScreenMatch>,
+) {
+    for start in 0..run.len() {
+        let mut word = String::new();
+
+        for (end, (_, letters)) in run.iter().enumerate().skip(start) {
+            word += letters;
+            if !screen.is_flagged(&word) {
+                continue;
+            }
+
+            let positions: Vec<_> = run[start..=end].iter().map(|(pos, _)| *pos).collect();
+            let clue = crossword
+                .clues()
+                .values()
+                .find(|clue| clue.positions().eq(positions.iter().copied()))
+                .map(|clue| clue.id());
+
+            matches.push(ScreenMatch {
+                word: word.clone(),
+                positions,
+                direction,
+                clue,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection::*, crossword};
+
+    fn found(matches: &[ScreenMatch]) -> Vec<(&str, ScreenDirection)> {
+        matches
+            .iter()
+            .map(|found| (found.word.as_str(), found.direction))
+            .collect()
+    }
+
+    #[test]
+    fn answers() {
+        let puzzle = crossword!(
+            [S C A T]
+            [O . . O]
+            [D A T E]
+            - A: "Shoo!"
+            - A: "Calendar square"
+            - D: "Lawn piece"
+            - D: "Foot part"
+        );
+
+        let words = HashSet::from(["CAT".to_string(), "SOD".to_string(), "AT".to_string()]);
+        let matches = screen(&puzzle, &words);
+
+        assert_eq!(
+            found(&matches),
+            [
+                ("CAT", ScreenDirection::Across),
+                ("AT", ScreenDirection::Across),
+                ("AT", ScreenDirection::Across),
+                ("SOD", ScreenDirection::Down)
+            ]
+        );
+        assert_eq!(matches[0].clue, None);
+        assert_eq!(matches[3].clue, Some((1, Down).into()));
+    }
+
+    #[rstest]
+    #[case::down_right(&["SAT"], vec![("SAT", ScreenDirection::DownRight)])]
+    #[case::down_left(&["AAD"], vec![("AAD", ScreenDirection::DownLeft)])]
+    #[case::blocked(&["TA", "CE"], vec![])]
+    #[case::casing(&["tOE"], vec![("TOE", ScreenDirection::Down)])]
+    fn fill(#[case] words: &[&str], #[case] expected: Vec<(&str, ScreenDirection)>) {
+        let puzzle = crossword!(
+            [S C A T]
+            [O A . O]
+            [D A T E]
+        );
+
+        assert_eq!(found(&screen(&puzzle, words)), expected);
+    }
+
+    #[test]
+    fn rebus() {
+        let puzzle = crossword!(
+            [B "ACO" N]
+            [. . .]
+        );
+
+        let words = BTreeSet::from(["BACON".to_string()]);
+        let matches = screen(&puzzle, &words);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].positions.len(), 3);
+    }
+}
@@ -0,0 +1,31 @@
+/// Assert that two [crosswords](crate::Crossword) are equal, showing their [differences](crate::PuzzleDiff) side by side if they are not
+///
+/// Unlike [`assert_eq!`], which prints both puzzles in full, only the squares, clues and properties that differ are shown.
+/// Squares are compared including their style, which [`PartialEq`] ignores.
+/// ```
+/// use puzzled::crossword::{assert_puzzles_eq, crossword};
+///
+/// let puzzle = crossword!([A B] - A: "AB");
+/// assert_puzzles_eq!(puzzle, puzzle.clone());
+/// ```
+/// ```should_panic
+/// use puzzled::crossword::{assert_puzzles_eq, crossword};
+///
+/// assert_puzzles_eq!(crossword!([A B]), crossword!([A C]), "puzzles should match");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! assert_puzzles_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let diff = $crate::compare(&$left, &$right);
+        if !diff.is_empty() {
+            panic!("assertion `left == right` failed\n{diff}");
+        }
+    }};
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        let diff = $crate::compare(&$left, &$right);
+        if !diff.is_empty() {
+            panic!("assertion `left == right` failed: {}\n{diff}", format_args!($($arg)+));
+        }
+    }};
+}
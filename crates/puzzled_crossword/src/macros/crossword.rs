@@ -4,7 +4,8 @@
 /// 1. [Squares](crate::Squares) grid
 ///
 ///    It contains the [solution](crate::Solution) to each [square](crate::Square).
-///    The syntax for constructing the squares is analoguous to that of using [`grid!`](crate::grid) where each entry is a [`square!`](crate::square)
+///    The syntax for constructing the squares is analoguous to that of using [`grid!`](crate::grid) where each entry is a [`square!`](crate::square).
+///    Every solution can be followed by the [style](crate::CellStyle) markers of the text format, e.g. `A@` for a circled square or `A%` for a shaded square, and by an entry between parentheses, e.g. `A (B)`, which is only kept by [`crossword_state!`](crate::crossword_state)
 /// 2. **(Optionally)** [Clues](crate::Clues) list
 ///
 ///    Each [clue](crate::Clue) is placed in the crossword using [`Crossword::place_clues`](crate::Crossword::place_clues), which does not require a strict order among the clues.
@@ -51,7 +52,7 @@
 macro_rules! crossword {
     (
         // Grid definition
-        [$($x0:tt)+] $( [$($x:tt)+])*
+        $( [$($x:tt)+] )+
 
         // Clue definitions
         $(- $dir:ident : $clue:literal )*
@@ -60,10 +61,7 @@ macro_rules! crossword {
         $( $meta_key:ident : $meta_value:literal )*
     ) => {{
         // Add squares
-        let squares = $crate::grid![
-            [$( $crate::square!($x0) ),+]
-            $(, [$( $crate::square!($x) ),+] )*
-        ];
+        let (squares, _entries) = $crate::__crossword_grid!($( [$($x)+] )+).unzip();
 
         // Add clues
         let clues = vec![$($crate::clue_spec!($dir : $clue)),*];
@@ -83,53 +81,175 @@ macro_rules! crossword {
     }};
 }
 
+/// Macro for constructing a [`Crossword`](crate::Crossword) inline together with the [state](crate::CrosswordState) of its solver
+///
+/// The syntax is that of [`crossword!`](crate::crossword), where the entries between parentheses are entered in the state.
+/// Squares without an entry are empty, and every entry has the style of its square.
+/// ```
+/// use puzzled::core::CellStyle;
+/// use puzzled::crossword::{crossword_state, Position, Solution, Solve};
+///
+/// let (puzzle, state) = crossword_state!(
+///     [A@ (A) B]
+///     [C (X) .]
+///     - A: "AB"
+///     - D: "AC"
+/// );
+///
+/// let cell = puzzle[Position::new(0, 0)].as_ref().unwrap();
+/// assert!(cell.style.contains(CellStyle::CIRCLED));
+/// assert_eq!(state.entry(&Position::new(0, 0)), Some(&Solution::Letter('A')));
+/// assert_eq!(state.entry(&Position::new(0, 1)), None);
+/// assert_eq!(state.entry(&Position::new(1, 0)), Some(&Solution::Letter('X')));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! crossword_state {
+    (
+        // Grid definition
+        $( [$($x:tt)+] )+
+
+        // Clue definitions
+        $(- $dir:ident : $clue:literal )*
+
+        // Metadata
+        $( $meta_key:ident : $meta_value:literal )*
+    ) => {{
+        // Add squares and entries
+        let (squares, entries) = $crate::__crossword_grid!($( [$($x)+] )+).unzip();
+        let solutions =
+            squares.map_ref(|square| square.map_ref(|cell| Some(cell.solution.clone())));
+
+        // Add clues
+        let clues = vec![$($crate::clue_spec!($dir : $clue)),*];
+
+        // Add metadata
+        let meta = $crate::metadata!( $( $meta_key : $meta_value),*);
+
+        // Create puzzle and state
+        let mut puzzle = $crate::Crossword::from_squares(squares, meta);
+        puzzle.insert_clues(clues);
+
+        let state = $crate::CrosswordState::new(solutions, entries, $crate::Timer::default())
+            .with_lock_state(puzzle.lock_state())
+            .with_check_policy(puzzle.check_policy());
+
+        (puzzle, state)
+    }};
+
+    ($($invalid:tt)*) => {{
+        $crate::__error($($invalid)*, "crossword_state!")
+    }};
+}
+
 #[doc(hidden)]
 #[macro_export]
-macro_rules! __crossword_square {
-    // CONTINUE: * adds to the current style
-    (@case * $($tail:tt)* [$sol:expr] [$($styles:tt)*] [$($out:expr),*]) => {
-        $crate::__crossword_square!(
-            @case $($tail)* [$sol] [$($styles)* *] [$($out),*]
-        )
+macro_rules! __crossword_grid {
+    ($( [$($x:tt)+] )+) => {{
+        let rows = vec![$( $crate::__crossword_row!([] $($x)+) ),+];
+        let cols = rows[0].len();
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "Every row of the crossword should have the same number of squares"
+        );
+
+        $crate::Grid::from_vec(rows.into_iter().flatten().collect(), cols).unwrap()
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __crossword_row {
+    // END: no squares left -> collect the row
+    ([$($out:expr),*]) => {
+        vec![$($out),*]
     };
 
-    // CONTINUE: ~ adds to the current style
-    (@case ~ $($tail:tt)* [$sol:expr] [$($styles:tt)*] [$($out:expr),*]) => {
-        $crate::__crossword_square!(
-            @case $($tail)* [$sol] [$($styles)* ~] [$($out),*]
+    // CONTINUE: . adds a black square
+    ([$($out:expr),*] . $($tail:tt)*) => {
+        $crate::__crossword_row!(
+            [$($out,)* ($crate::Square::new_empty(), $crate::Square::new_empty())] $($tail)*
         )
     };
 
-    // CONTINUE: @ adds to the current style
-    (@case @ $($tail:tt)* [$sol:expr] [$($styles:tt)*] [$($out:expr),*]) => {
-        $crate::__crossword_square!(
-            @case $($tail)* [$sol] [$($styles)* @] [$($out),*]
+    // CONTINUE: solution starts a white square
+    ([$($out:expr),*] $sol:tt $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [] $($tail)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __crossword_square {
+    // CONTINUE: style marker adds to the current style
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] * $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [$($styles)* *] $($tail)*)
+    };
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] ~ $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [$($styles)* ~] $($tail)*)
+    };
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] @ $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [$($styles)* @] $($tail)*)
+    };
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] ! $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [$($styles)* !] $($tail)*)
+    };
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] ? $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [$($styles)* ?] $($tail)*)
+    };
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] % $($tail:tt)*) => {
+        $crate::__crossword_square!([$($out),*] [$sol] [$($styles)* %] $($tail)*)
+    };
+
+    // END: entry -> create solution and entry cells -> add to $out and continue with row
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] ( $entry:tt ) $($tail:tt)*) => {
+        $crate::__crossword_row!(
+            [$($out,)* $crate::__crossword_square!(@create $sol [$($styles)*] Some($crate::__solution(stringify!($entry))))]
+            $($tail)*
         )
     };
 
-    // END: entry -> create solution + entry cell -> add to $out and continue with row
-    (@case ( $entry:tt ) $($tail:tt)* [$sol:expr] [$($styles:tt)*] [$($out:expr),*]) => {
+    // END: no entry -> create solution cell -> add to $out and continue with row
+    ([$($out:expr),*] [$sol:tt] [$($styles:tt)*] $($tail:tt)*) => {
         $crate::__crossword_row!(
-            @case $($tail)* [$($out),*, Some({
-                let entry = $crate::__solution(stringify!($entry));
-                let style = $crate::cell_style!($($styles)*);
+            [$($out,)* $crate::__crossword_square!(@create $sol [$($styles)*] None)]
+            $($tail)*
+        )
+    };
 
-                let mut cell = $crate::Cell::new_styled($sol, style);
-                cell.enter(entry);
+    (@create $sol:tt [$($styles:tt)*] $entry:expr) => {{
+        let solution = Some($crate::__solution(stringify!($sol)));
+        let (style, decoration) = $crate::__crossword_markers!([] false $($styles)*);
+        let cell = $crate::Cell::new_with_style(solution, style.initial()).with_decoration(decoration);
 
-                cell
-            })]
+        (
+            $crate::Square::new(cell),
+            $crate::Square::new($crate::Entry::<$crate::Solution>::new_with_style($entry, style)),
+        )
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __crossword_markers {
+    // END: no markers left -> create the style and decoration
+    ([$($styles:tt)*] $shaded:tt) => {
+        (
+            $crate::cell_style!($($styles)*),
+            match $shaded {
+                true => $crate::CellDecoration::shaded(),
+                false => $crate::CellDecoration::default(),
+            },
         )
     };
 
-    // END: ? -> create solution cell -> add to $out and continue with row
-    (@case $($tail:tt)* [$sol:expr] [$($styles:tt)*] [$($out:expr),*]) => {
-        $crate::__crossword_row!(
-            @case $($tail)* [$($out),*, Some({
-                let style = $crate::cell_style!($($styles)*);
+    // CONTINUE: % shades the square
+    ([$($styles:tt)*] $shaded:tt % $($tail:tt)*) => {
+        $crate::__crossword_markers!([$($styles)*] true $($tail)*)
+    };
 
-                $crate::Cell::new_styled($sol, style)
-            })]
-        )
+    // CONTINUE: other markers add to the style
+    ([$($styles:tt)*] $shaded:tt $style:tt $($tail:tt)*) => {
+        $crate::__crossword_markers!([$($styles)* $style] $shaded $($tail)*)
     };
 }
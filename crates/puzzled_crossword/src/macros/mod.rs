@@ -1,3 +1,4 @@
+mod assert;
 mod clue;
 mod crossword;
 mod square;
@@ -45,8 +46,9 @@ mod tests {
     use rstest::rstest;
 
     use crate::{
+        CrosswordState, Position,
         Solution::{self, *},
-        square,
+        crossword_state, square,
     };
 
     type CrosswordCell = Cell<Solution>;
@@ -61,6 +63,7 @@ mod tests {
     #[case(square!(A), Letter('A'), _E)]
     #[case(square!(A@), Letter('A'), _C)]
     #[case(square!(A*), Letter('A'), _R)]
+    #[case(square!(A!), Letter('A'), _I)]
     fn test_cell(
         #[case] square: Square<CrosswordCell>,
         #[case] solution: Solution,
@@ -74,4 +77,30 @@ mod tests {
             _ => unreachable!("No test cases produce an empty square"),
         }
     }
+
+    fn state() -> CrosswordState {
+        let (_, state) = crossword_state!(
+            [A@ (B) B* (B) G]
+            [C!~ DEF (DE) .]
+        );
+
+        state
+    }
+
+    #[rstest]
+    #[case::circled((0, 0), Some(Letter('B')), _C)]
+    #[case::revealed((0, 1), Some(Letter('B')), _R)]
+    #[case::incorrect((1, 0), None, _I | _P)]
+    #[case::rebus((1, 1), Some(Rebus("DE".into())), _E)]
+    fn test_state(
+        #[case] pos: (usize, usize),
+        #[case] entry: Option<Solution>,
+        #[case] style: CellStyle,
+    ) {
+        let state = state();
+        let square = state.entries.get_fill(Position::from(pos)).unwrap();
+
+        assert_eq!(square.entry(), entry.as_ref());
+        assert_eq!(square.style(), style);
+    }
 }
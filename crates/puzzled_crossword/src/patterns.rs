@@ -0,0 +1,570 @@
+//! Layouts of black squares to construct a [crossword](crate::Crossword) from
+//!
+//! A [pattern](Pattern) is either one of the standard templates, read from rows of text or [generated](PatternGenerator) to have a target number of words.
+//! It can then be turned into a [builder](crate::CrosswordBuilder) to fill in its squares and clues:
+//! ```
+//! use puzzled::crossword::patterns::{Pattern, PatternGenerator};
+//! use puzzled::crossword::Symmetry;
+//!
+//! let themeless = Pattern::themeless_15();
+//! assert_eq!(themeless.word_count(), 70);
+//! assert!(themeless.is_valid(3));
+//!
+//! let pattern = PatternGenerator::new(9, 9, 24).with_seed(7).generate()?;
+//! assert_eq!(pattern.word_count(), 24);
+//! assert!(pattern.is_symmetric(Symmetry::Rotational180));
+//!
+//! let builder = pattern.to_builder();
+//! assert_eq!(builder.clues().len(), 24);
+//! # Ok::<(), puzzled::crossword::patterns::PatternError>(())
+//! ```
+use std::fmt;
+
+use puzzled_core::{
+    BitGrid, Cell, Grid, MISSING_ENTRY_CHAR, Metadata, NON_PLAYABLE_CHAR, Position, Square,
+};
+
+use crate::{Crossword, CrosswordBuilder, Squares, Symmetry};
+
+/// Themeless 15x15 grid with 70 words and 32 black squares
+const THEMELESS_15: [&str; 15] = [
+    "-------.-------",
+    "-------.-------",
+    "-------.-------",
+    "---.-----.-----",
+    "----.-----.----",
+    ".----.-----.---",
+    "..------.------",
+    "...---------...",
+    "------.------..",
+    "---.-----.----.",
+    "----.-----.----",
+    "-----.-----.---",
+    "-------.-------",
+    "-------.-------",
+    "-------.-------",
+];
+
+/// Sunday 21x21 grid with 148 words and 74 black squares
+const SUNDAY_21: [&str; 21] = [
+    "------.-------.------",
+    "------.-------.------",
+    "------.-------.------",
+    "---.-----.----.------",
+    "----.-----.---.---...",
+    "...----.-----.-------",
+    "-------.----.----.---",
+    "-----.-----.----.----",
+    "---.-----.-----.-----",
+    "---.----.----.----...",
+    "---.----.---.----.---",
+    "...----.----.----.---",
+    "-----.-----.-----.---",
+    "----.----.-----.-----",
+    "---.----.----.-------",
+    "-------.-----.----...",
+    "...---.---.-----.----",
+    "------.----.-----.---",
+    "------.-------.------",
+    "------.-------.------",
+    "------.-------.------",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatternError {
+    #[error("Pattern should have at least one row and one column")]
+    Empty,
+
+    #[error("Row {row} has {found} squares, but should have {expected}")]
+    RaggedRow {
+        row: usize,
+        found: usize,
+        expected: usize,
+    },
+
+    #[error(
+        "Square '{found}' at {pos:?} should be '{NON_PLAYABLE_CHAR}' (black) or '{MISSING_ENTRY_CHAR}' (white)"
+    )]
+    InvalidSquare { pos: Position, found: char },
+
+    #[error("{symmetry} symmetry requires a square grid, found {rows}x{cols}")]
+    UnsupportedSize {
+        symmetry: Symmetry,
+        rows: usize,
+        cols: usize,
+    },
+
+    #[error("No pattern with {words} words was found in {attempts} attempts")]
+    NotFound { words: usize, attempts: usize },
+}
+
+/// Layout of the black squares of a crossword grid
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    blocks: BitGrid,
+}
+
+impl Pattern {
+    /// Create a pattern of the given size with only white squares
+    ///
+    /// # Panics
+    /// Panics if the size overflows
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let blocks = BitGrid::new(rows, cols).expect("Pattern size should not overflow");
+        Self { blocks }
+    }
+
+    /// Read a pattern from its rows, where `.` is a black square and `-` is a white square
+    /// ```
+    /// use puzzled::crossword::{patterns::Pattern, Position};
+    ///
+    /// let pattern = Pattern::from_rows(["--.", "---", ".--"])?;
+    ///
+    /// assert!(pattern.is_black(Position::new(0, 2)));
+    /// assert_eq!(pattern.block_count(), 2);
+    /// # Ok::<(), puzzled::crossword::patterns::PatternError>(())
+    /// ```
+    pub fn from_rows<I, S>(rows: I) -> Result<Self, PatternError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let rows: Vec<Vec<char>> = rows
+            .into_iter()
+            .map(|row| row.as_ref().chars().collect())
+            .collect();
+
+        let cols = rows.first().map(Vec::len).unwrap_or_default();
+        if cols == 0 {
+            return Err(PatternError::Empty);
+        }
+
+        let mut pattern = Self::new(rows.len(), cols);
+
+        for (row, squares) in rows.iter().enumerate() {
+            if squares.len() != cols {
+                return Err(PatternError::RaggedRow {
+                    row,
+                    found: squares.len(),
+                    expected: cols,
+                });
+            }
+
+            for (col, &square) in squares.iter().enumerate() {
+                let pos = Position::new(row, col);
+
+                match square {
+                    NON_PLAYABLE_CHAR => pattern.set_black(pos, true),
+                    MISSING_ENTRY_CHAR => pattern.set_black(pos, false),
+                    found => return Err(PatternError::InvalidSquare { pos, found }),
+                };
+            }
+        }
+
+        Ok(pattern)
+    }
+
+    /// Standard 15x15 grid for themeless puzzles, with 70 words
+    pub fn themeless_15() -> Self {
+        Self::from_rows(THEMELESS_15).expect("Template is valid")
+    }
+
+    /// Standard 21x21 grid for Sunday-sized puzzles, with 148 words
+    pub fn sunday_21() -> Self {
+        Self::from_rows(SUNDAY_21).expect("Template is valid")
+    }
+
+    pub fn rows(&self) -> usize {
+        self.blocks.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.blocks.cols()
+    }
+
+    /// Whether the square at the position is black, where squares outside of the grid are considered black
+    pub fn is_black(&self, pos: Position) -> bool {
+        self.blocks.get(pos).unwrap_or(true)
+    }
+
+    /// Make the square at the position black or white, returning whether it was black before
+    ///
+    /// Returns [`None`] if the position is outside of the grid
+    pub fn set_black(&mut self, pos: Position, black: bool) -> Option<bool> {
+        self.blocks.set(pos, black)
+    }
+
+    /// Positions of the black squares in row-major order
+    pub fn blocks(&self) -> impl Iterator<Item = Position> + '_ {
+        self.blocks.iter_ones()
+    }
+
+    pub fn block_count(&self) -> usize {
+        self.blocks.count_ones()
+    }
+
+    /// Lengths of the words in the grid, i.e. runs of at least 2 white squares, first across and then down
+    pub fn word_lens(&self) -> Vec<usize> {
+        self.runs().filter(|&len| len >= 2).collect()
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.runs().filter(|&len| len >= 2).count()
+    }
+
+    /// Whether every white square can be reached from every other white square without crossing a black square
+    pub fn is_connected(&self) -> bool {
+        let mut whites = self.positions().filter(|&pos| !self.is_black(pos));
+        let Some(start) = whites.next() else {
+            return true;
+        };
+
+        let mut seen = BitGrid::new(self.rows(), self.cols()).expect("Size is valid");
+        seen.set(start, true);
+
+        let mut stack = vec![start];
+        let mut reached = 1;
+
+        while let Some(pos) = stack.pop() {
+            let neighbors = [
+                pos.row
+                    .checked_sub(1)
+                    .map(|row| Position::new(row, pos.col)),
+                pos.col
+                    .checked_sub(1)
+                    .map(|col| Position::new(pos.row, col)),
+                Some(Position::new(pos.row + 1, pos.col)),
+                Some(Position::new(pos.row, pos.col + 1)),
+            ];
+
+            for next in neighbors.into_iter().flatten() {
+                if !self.is_black(next) && seen.get(next) == Some(false) {
+                    seen.set(next, true);
+                    stack.push(next);
+                    reached += 1;
+                }
+            }
+        }
+
+        reached == self.rows() * self.cols() - self.block_count()
+    }
+
+    /// Whether the black squares follow the given [symmetry](Symmetry)
+    pub fn is_symmetric(&self, symmetry: Symmetry) -> bool {
+        let (rows, cols) = (self.rows(), self.cols());
+
+        self.positions().all(|pos| {
+            symmetry
+                .partner(pos, rows, cols)
+                .is_some_and(|partner| self.is_black(pos) == self.is_black(partner))
+        })
+    }
+
+    /// Whether the pattern can be filled in as a crossword, i.e. the white squares are [connected](Self::is_connected) and each of them is part of an across and a down word of at least `min_word_len` letters
+    pub fn is_valid(&self, min_word_len: usize) -> bool {
+        self.runs().all(|len| len >= min_word_len.max(2)) && self.is_connected()
+    }
+
+    /// Empty squares of a crossword with the layout of the pattern
+    pub fn to_squares(&self) -> Squares {
+        let squares = self
+            .positions()
+            .map(|pos| match self.is_black(pos) {
+                true => Square::new_empty(),
+                false => Square::new(Cell::new(None)),
+            })
+            .collect();
+
+        Grid::from_vec(squares, self.cols()).expect("Squares fill all columns")
+    }
+
+    /// Builder for a crossword with the layout of the pattern, of which every word has an empty clue
+    pub fn to_builder(&self) -> CrosswordBuilder {
+        let crossword = Crossword::from_squares(self.to_squares(), Metadata::default());
+        CrosswordBuilder::from(crossword)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Position> + use<> {
+        let cols = self.cols();
+        (0..self.rows() * cols).map(move |idx| Position::new(idx / cols, idx % cols))
+    }
+
+    /// Lengths of the runs of white squares, first across and then down
+    fn runs(&self) -> impl Iterator<Item = usize> + '_ {
+        let across = (0..self.rows()).map(|row| {
+            (0..self.cols())
+                .map(move |col| Position::new(row, col))
+                .collect::<Vec<_>>()
+        });
+        let down = (0..self.cols()).map(|col| {
+            (0..self.rows())
+                .map(move |row| Position::new(row, col))
+                .collect::<Vec<_>>()
+        });
+
+        across.chain(down).flat_map(|line| {
+            line.split(|&pos| self.is_black(pos))
+                .map(<[Position]>::len)
+                .filter(|&len| len > 0)
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows() {
+            for col in 0..self.cols() {
+                let square = match self.is_black(Position::new(row, col)) {
+                    true => NON_PLAYABLE_CHAR,
+                    false => MISSING_ENTRY_CHAR,
+                };
+                write!(f, "{square}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Generator of [valid](Pattern::is_valid), [symmetric](Symmetry) patterns with a target number of words
+///
+/// Black squares are placed at random (together with their symmetric partners) until the target is reached, starting over whenever no square can be placed without making the pattern invalid.
+/// The generator is deterministic, such that the same [seed](Self::with_seed) always generates the same pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternGenerator {
+    rows: usize,
+    cols: usize,
+    words: usize,
+
+    symmetry: Symmetry,
+    min_word_len: usize,
+    max_word_len: Option<usize>,
+    seed: u64,
+    attempts: usize,
+}
+
+impl PatternGenerator {
+    /// Create a generator for patterns of the given size with the given number of words
+    ///
+    /// By default, patterns have [180° rotational](Symmetry::Rotational180) symmetry and words of at least 3 letters.
+    pub fn new(rows: usize, cols: usize, words: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            words,
+            symmetry: Symmetry::default(),
+            min_word_len: 3,
+            max_word_len: None,
+            seed: 0,
+            attempts: 1000,
+        }
+    }
+
+    pub fn with_symmetry(mut self, symmetry: Symmetry) -> Self {
+        self.symmetry = symmetry;
+        self
+    }
+
+    /// Define the least number of letters of every word
+    pub fn with_min_word_len(mut self, min_word_len: usize) -> Self {
+        self.min_word_len = min_word_len;
+        self
+    }
+
+    /// Define the most number of letters of every word
+    pub fn with_max_word_len(mut self, max_word_len: usize) -> Self {
+        self.max_word_len = Some(max_word_len);
+        self
+    }
+
+    /// Define the seed from which black squares are chosen
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Define how many times to start over before giving up
+    pub fn with_attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// Generate a pattern with exactly the target number of words
+    pub fn generate(&self) -> Result<Pattern, PatternError> {
+        let (rows, cols) = (self.rows, self.cols);
+        if rows == 0 || cols == 0 {
+            return Err(PatternError::Empty);
+        }
+        if !self.symmetry.supports(rows, cols) {
+            return Err(PatternError::UnsupportedSize {
+                symmetry: self.symmetry,
+                rows,
+                cols,
+            });
+        }
+
+        let mut rng = Rng::new(self.seed);
+
+        for _ in 0..self.attempts {
+            if let Some(pattern) = self.attempt(&mut rng) {
+                return Ok(pattern);
+            }
+        }
+
+        Err(PatternError::NotFound {
+            words: self.words,
+            attempts: self.attempts,
+        })
+    }
+
+    /// Place black squares until the target is reached, or no more squares can be placed
+    fn attempt(&self, rng: &mut Rng) -> Option<Pattern> {
+        let mut pattern = Pattern::new(self.rows, self.cols);
+        let mut words = pattern.word_count();
+
+        while words < self.words {
+            let mut candidates: Vec<_> = pattern
+                .positions()
+                .filter(|&pos| !pattern.is_black(pos))
+                .collect();
+            rng.shuffle(&mut candidates);
+
+            let placed = candidates.into_iter().find_map(|pos| {
+                let mut next = pattern.clone();
+                for partner in self.symmetry.orbit(pos, self.rows, self.cols) {
+                    next.set_black(partner, true);
+                }
+
+                let count = next.word_count();
+                (count <= self.words && next.is_valid(self.min_word_len)).then_some((next, count))
+            });
+
+            (pattern, words) = placed?;
+        }
+
+        let fits = self
+            .max_word_len
+            .is_none_or(|max| pattern.word_lens().into_iter().all(|len| len <= max));
+
+        (words == self.words && fits && pattern.is_valid(self.min_word_len)).then_some(pattern)
+    }
+}
+
+/// Small [xorshift](https://en.wikipedia.org/wiki/Xorshift) generator, which is random enough to choose black squares
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Xorshift gets stuck on a state of zero
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Shuffle the values with the Fisher-Yates algorithm
+    fn shuffle<T>(&mut self, values: &mut [T]) {
+        for idx in (1..values.len()).rev() {
+            let other = (self.next() % (idx as u64 + 1)) as usize;
+            values.swap(idx, other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::themeless(Pattern::themeless_15(), 15, 70, 32)]
+    #[case::sunday(Pattern::sunday_21(), 21, 148, 74)]
+    fn templates(
+        #[case] pattern: Pattern,
+        #[case] size: usize,
+        #[case] words: usize,
+        #[case] blocks: usize,
+    ) {
+        assert_eq!((pattern.rows(), pattern.cols()), (size, size));
+        assert_eq!(pattern.word_count(), words);
+        assert_eq!(pattern.block_count(), blocks);
+
+        assert!(pattern.is_valid(3));
+        assert!(pattern.is_symmetric(Symmetry::Rotational180));
+        assert!(pattern.word_lens().into_iter().all(|len| len <= 15));
+    }
+
+    #[test]
+    fn round_trip() {
+        let pattern = Pattern::themeless_15();
+        let text = pattern.to_string();
+
+        assert_eq!(Pattern::from_rows(text.lines()), Ok(pattern));
+    }
+
+    #[rstest]
+    #[case::open(&["---", "---", "---"], true, true)]
+    #[case::unchecked(&["---", "-.-", "---"], true, false)]
+    #[case::disconnected(&["--.--", "--.--"], false, false)]
+    fn validity(#[case] rows: &[&str], #[case] connected: bool, #[case] valid: bool) {
+        let pattern = Pattern::from_rows(rows).unwrap();
+
+        assert_eq!(pattern.is_connected(), connected);
+        assert_eq!(pattern.is_valid(3), valid);
+    }
+
+    #[test]
+    fn builder() {
+        let pattern = Pattern::from_rows(["---.", "----", ".---"]).unwrap();
+        let crossword = pattern.to_builder().build();
+
+        for pos in pattern.positions() {
+            assert_eq!(crossword.squares().is_fill(pos), !pattern.is_black(pos));
+        }
+        assert_eq!(crossword.clues().len(), pattern.word_count());
+    }
+
+    #[rstest]
+    #[case::empty(&[], PatternError::Empty)]
+    #[case::ragged(&["---", "--"], PatternError::RaggedRow { row: 1, found: 2, expected: 3 })]
+    #[case::square(&["-#-"], PatternError::InvalidSquare { pos: Position::new(0, 1), found: '#' })]
+    fn errors(#[case] rows: &[&str], #[case] err: PatternError) {
+        assert_eq!(Pattern::from_rows(rows), Err(err));
+    }
+
+    #[rstest]
+    fn generate(
+        #[values(0, 1, 2)] seed: u64,
+        #[values(Symmetry::Rotational180, Symmetry::MirrorLeftRight)] symmetry: Symmetry,
+    ) {
+        let generator = PatternGenerator::new(9, 9, 24)
+            .with_symmetry(symmetry)
+            .with_max_word_len(7)
+            .with_seed(seed);
+        let pattern = generator.generate().unwrap();
+
+        assert_eq!(pattern.word_count(), 24);
+        assert!(pattern.is_valid(3));
+        assert!(pattern.is_symmetric(symmetry));
+        assert!(pattern.word_lens().into_iter().all(|len| len <= 7));
+
+        assert_eq!(generator.generate(), Ok(pattern));
+    }
+
+    #[rstest]
+    #[case::impossible(PatternGenerator::new(5, 5, 12), PatternError::NotFound { words: 12, attempts: 10 })]
+    #[case::size(
+        PatternGenerator::new(5, 6, 10).with_symmetry(Symmetry::Diagonal),
+        PatternError::UnsupportedSize { symmetry: Symmetry::Diagonal, rows: 5, cols: 6 }
+    )]
+    fn generate_errors(#[case] generator: PatternGenerator, #[case] err: PatternError) {
+        assert_eq!(generator.with_attempts(10).generate(), Err(err));
+    }
+}
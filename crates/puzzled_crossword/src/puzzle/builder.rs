@@ -0,0 +1,408 @@
+use std::collections::BTreeMap;
+
+use puzzled_core::{Cell, Grid, Metadata, Position, Square};
+
+use crate::{
+    ClueDirection, ClueSpec, Clues, Crossword, CrosswordSquares, NumberingScheme, Solution,
+    Squares, SymmetricSquares, Symmetry, WritingDirection,
+};
+
+/// Builder to iteratively construct the layout of a [crossword](Crossword)
+///
+/// Unlike [`Crossword::from_squares`] followed by [`Crossword::insert_clues`], the builder keeps its [clues](Clues) numbered while [squares](Square) are blacked out or the grid is resized.
+/// Existing clues are moved along with the squares they occupy, such that their text is kept whenever possible.
+///
+/// ```
+/// use puzzled::crossword::{ClueDirection, CrosswordBuilder, Position};
+///
+/// let mut builder = CrosswordBuilder::new(3, 3);
+/// builder.set_clue(Position::new(0, 0), ClueDirection::Across, "Top row");
+/// builder.set_clue(Position::new(0, 2), ClueDirection::Down, "Right column");
+///
+/// // Blacking out the first square moves both clues to start one square further
+/// builder.set_black(Position::new(0, 0));
+///
+/// let crossword = builder.build();
+/// let clues = crossword.clues();
+///
+/// let across = clues.get_clue(Position::new(0, 1), ClueDirection::Across).unwrap();
+/// assert_eq!((across.num(), across.text().as_str()), (1, "Top row"));
+///
+/// let down = clues.get_clue(Position::new(0, 2), ClueDirection::Down).unwrap();
+/// assert_eq!((down.num(), down.text().as_str()), (2, "Right column"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CrosswordBuilder {
+    squares: Squares,
+    clues: Clues,
+    meta: Metadata,
+
+    numbering: NumberingScheme,
+    writing: WritingDirection,
+    symmetry: Option<Symmetry>,
+    unplaced: Vec<ClueSpec>,
+}
+
+impl CrosswordBuilder {
+    /// Create a builder for a grid of the given size with only empty white squares
+    ///
+    /// # Panics
+    /// Panics if `cols == 0`
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let squares = white_squares(rows, cols);
+        let mut builder = Self {
+            squares,
+            clues: Clues::default(),
+            meta: Metadata::default(),
+            numbering: NumberingScheme::default(),
+            writing: WritingDirection::default(),
+            symmetry: None,
+            unplaced: Vec::new(),
+        };
+
+        builder.renumber();
+        builder
+    }
+
+    /// Keep the black squares [symmetric](Symmetry) while editing the layout
+    ///
+    /// Note that the current layout is not changed until the next edit, use [`enforce_symmetry`](SymmetricSquares::enforce_symmetry) to do so
+    pub fn with_symmetry(mut self, symmetry: Symmetry) -> Self {
+        self.symmetry = Some(symmetry);
+        self
+    }
+
+    /// Number the clues according to the given scheme, which renumbers the current clues
+    pub fn with_numbering(mut self, numbering: NumberingScheme) -> Self {
+        self.numbering = numbering;
+        self.renumber();
+        self
+    }
+
+    /// Read the across clues in the given direction, which renumbers the current clues while keeping them on their squares
+    pub fn with_writing(mut self, writing: WritingDirection) -> Self {
+        self.writing = writing;
+        self.renumber();
+        self
+    }
+
+    pub fn with_meta(mut self, meta: Metadata) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    pub fn squares(&self) -> &Squares {
+        &self.squares
+    }
+
+    pub fn clues(&self) -> &Clues {
+        &self.clues
+    }
+
+    /// Clues that could no longer be placed after editing the layout, e.g. because all of their squares were blacked out
+    pub fn unplaced(&self) -> &[ClueSpec] {
+        &self.unplaced
+    }
+
+    /// Black out the square at the given position (and its symmetric partners)
+    ///
+    /// Returns whether any square was changed
+    pub fn set_black(&mut self, pos: Position) -> bool {
+        self.set_squares(pos, false)
+    }
+
+    /// Turn the square at the given position (and its symmetric partners) into an empty white square
+    ///
+    /// Returns whether any square was changed
+    pub fn set_white(&mut self, pos: Position) -> bool {
+        self.set_squares(pos, true)
+    }
+
+    /// Toggle the square at the given position (and its symmetric partners) between black and white
+    ///
+    /// Returns whether any square was changed
+    pub fn toggle_black(&mut self, pos: Position) -> bool {
+        match self.squares.get(pos) {
+            Some(square) if square.is_some() => self.set_black(pos),
+            Some(_) => self.set_white(pos),
+            None => false,
+        }
+    }
+
+    /// Set the solution of the white square at the given position
+    ///
+    /// Returns whether the square exists and is white
+    pub fn set_solution<S>(&mut self, pos: Position, solution: S) -> bool
+    where
+        S: Into<Solution>,
+    {
+        let Some(cell) = self.squares.get_fill_mut(pos) else {
+            return false;
+        };
+
+        cell.solution = Some(solution.into());
+        true
+    }
+
+    /// Set the text of the clue that covers the given position in the given direction
+    ///
+    /// Returns whether such a clue exists
+    pub fn set_clue<S>(&mut self, pos: Position, direction: ClueDirection, text: S) -> bool
+    where
+        S: Into<String>,
+    {
+        let Some(clue) = self.clues.get_clue(pos, direction) else {
+            return false;
+        };
+
+        let id = clue.id();
+        let clue = ClueSpec::new(direction, text).place(clue.num(), clue.start(), clue.len());
+        self.clues.insert(id, clue);
+
+        true
+    }
+
+    /// Resize the grid, keeping the squares that fit within the new size
+    ///
+    /// Newly added squares are empty white squares.
+    /// Returns whether the size changed
+    ///
+    /// # Panics
+    /// Panics if `cols == 0`
+    pub fn resize(&mut self, rows: usize, cols: usize) -> bool {
+        if rows == self.squares.rows() && cols == self.squares.cols() {
+            return false;
+        }
+
+        let mut squares = white_squares(rows, cols);
+
+        for pos in squares.positions().collect::<Vec<_>>() {
+            if let Some(square) = self.squares.get(pos) {
+                squares[pos] = Square(square.0.clone());
+            }
+        }
+
+        self.squares = squares;
+        self.renumber();
+
+        true
+    }
+
+    /// Number all clues in the grid from its current layout
+    ///
+    /// Clues are numbered in reading order according to the [numbering scheme](NumberingScheme), similar to [`Crossword::place_clues`].
+    /// Every existing clue text is moved to the clue that now covers the first of its remaining white squares.
+    /// The specifications of clues whose text could not be moved are returned and recorded in [`unplaced`](Self::unplaced).
+    pub fn renumber(&mut self) -> Vec<ClueSpec> {
+        let old = std::mem::take(&mut self.clues);
+
+        // Place empty clues on every start position of the current layout
+        let writing = self.writing;
+        let starts = self.squares.clue_starts(self.numbering, writing);
+
+        for (id, (start, len)) in starts {
+            let clue = ClueSpec::new(id.direction, "")
+                .place(id.num, start, len)
+                .with_writing(writing);
+
+            self.clues.insert(clue.id(), clue);
+        }
+
+        // Move the text of the previous clues to their new position
+        let mut texts: BTreeMap<_, String> = BTreeMap::new();
+        let mut unplaced = vec![];
+
+        for clue in old.values().filter(|clue| !clue.text().is_empty()) {
+            let direction = clue.direction();
+            let id = clue
+                .positions()
+                .find(|&pos| self.squares.is_fill(pos))
+                .and_then(|pos| self.clues.get_clue(pos, direction))
+                .map(|clue| clue.id());
+
+            match id {
+                Some(id) if !texts.contains_key(&id) => {
+                    texts.insert(id, clue.text().clone());
+                }
+                _ => unplaced.push(clue.spec()),
+            }
+        }
+
+        for (id, text) in texts {
+            let clue = &self.clues[&id];
+            let clue = ClueSpec::new(id.direction, text)
+                .place(id.num, clue.start(), clue.len())
+                .with_writing(writing);
+
+            self.clues.insert(id, clue);
+        }
+
+        self.unplaced.extend(unplaced.iter().cloned());
+        unplaced
+    }
+
+    /// Finish building the [crossword](Crossword)
+    pub fn build(self) -> Crossword {
+        let mut crossword = Crossword::new(self.squares, self.clues, self.meta);
+        crossword.numbering = self.numbering;
+        crossword.writing = self.writing;
+
+        crossword
+    }
+
+    fn set_squares(&mut self, pos: Position, white: bool) -> bool {
+        if !self.squares.is_in_bounds(pos) {
+            return false;
+        }
+
+        let positions = match self.symmetry {
+            Some(symmetry) if self.squares.symmetric_partner(pos, symmetry).is_some() => {
+                symmetry.orbit(pos, self.squares.rows(), self.squares.cols())
+            }
+            _ => vec![pos],
+        };
+
+        let mut changed = false;
+
+        for pos in positions {
+            if self.squares.is_fill(pos) == white {
+                continue;
+            }
+
+            self.squares[pos] = if white {
+                Square::new(Cell::new(None))
+            } else {
+                Square::new_empty()
+            };
+            changed = true;
+        }
+
+        if changed {
+            self.renumber();
+        }
+
+        changed
+    }
+}
+
+impl From<Crossword> for CrosswordBuilder {
+    fn from(crossword: Crossword) -> Self {
+        let Crossword {
+            squares,
+            clues,
+            numbering,
+            writing,
+            meta,
+            ..
+        } = crossword;
+
+        let mut builder = Self {
+            squares,
+            clues,
+            meta,
+            numbering,
+            writing,
+            symmetry: None,
+            unplaced: Vec::new(),
+        };
+
+        builder.renumber();
+        builder
+    }
+}
+
+fn white_squares(rows: usize, cols: usize) -> Squares {
+    assert!(cols > 0, "Crossword should have at least one column");
+
+    let squares = (0..rows * cols)
+        .map(|_| Square::new(Cell::new(None)))
+        .collect();
+
+    Grid::from_vec(squares, cols).expect("Squares fill all columns")
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Position;
+
+    use super::*;
+
+    fn clue_texts(builder: &CrosswordBuilder) -> Vec<(String, &str)> {
+        builder
+            .clues()
+            .values()
+            .map(|clue| (clue.id().to_string(), clue.text().as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn numbering() {
+        let mut builder = CrosswordBuilder::new(3, 3);
+        builder.set_black(Position::new(1, 1));
+
+        let ids: Vec<_> = builder.clues().keys().map(|id| id.to_string()).collect();
+
+        assert_eq!(
+            ids,
+            ["1-A", "1-D", "2-D", "3-D", "4-A", "5-A", "6-A", "7-D"]
+        );
+    }
+
+    #[test]
+    fn toggle_black_with_symmetry() {
+        let mut builder = CrosswordBuilder::new(3, 3).with_symmetry(Symmetry::Rotational180);
+
+        assert!(builder.toggle_black(Position::new(0, 0)));
+        assert!(!builder.squares().is_fill(Position::new(2, 2)));
+        assert!(builder.squares().is_symmetric(Symmetry::Rotational180));
+
+        assert!(builder.toggle_black(Position::new(2, 2)));
+        assert!(builder.squares().is_fill(Position::new(0, 0)));
+        assert!(builder.squares().is_fill(Position::new(2, 2)));
+    }
+
+    #[test]
+    fn clues_follow_layout() {
+        let mut builder = CrosswordBuilder::new(2, 3);
+        builder.set_clue(Position::new(0, 0), ClueDirection::Across, "Top");
+        builder.set_clue(Position::new(1, 0), ClueDirection::Across, "Bottom");
+        builder.set_clue(Position::new(0, 0), ClueDirection::Down, "Left");
+
+        // Blacking out the first row moves its clue along and drops the left column clue
+        builder.set_black(Position::new(0, 0));
+        builder.set_black(Position::new(1, 0));
+
+        assert_eq!(
+            clue_texts(&builder),
+            [
+                ("1-A".to_string(), "Top"),
+                ("1-D".to_string(), ""),
+                ("2-D".to_string(), ""),
+                ("3-A".to_string(), "Bottom"),
+            ]
+        );
+        assert_eq!(builder.unplaced(), [ClueSpec::down("Left")]);
+    }
+
+    #[test]
+    fn resize() {
+        let mut builder = CrosswordBuilder::new(2, 2);
+        builder.set_solution(Position::new(0, 0), "A");
+        builder.set_black(Position::new(1, 1));
+
+        assert!(builder.resize(3, 3));
+        assert_eq!(builder.squares().rows(), 3);
+        assert_eq!(builder.squares().cols(), 3);
+
+        assert!(!builder.squares().is_fill(Position::new(1, 1)));
+        assert_eq!(
+            builder
+                .squares()
+                .get_fill(Position::new(0, 0))
+                .unwrap()
+                .solution,
+            Some(Solution::from("A"))
+        );
+        assert!(builder.squares().is_fill(Position::new(2, 2)));
+    }
+}
@@ -0,0 +1,33 @@
+/// When the correctness of entries in a [crossword](crate::Crossword) is shown to the player
+///
+/// The policy decides whether [entering](puzzled_core::Solve::enter) a square marks it as [correct](puzzled_core::CellStyle::CORRECT) or [incorrect](puzzled_core::CellStyle::INCORRECT),
+/// such that interfaces can offer a "check as you type" mode as well as a mode without any spoilers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CheckPolicy {
+    /// Squares are never checked, so entries are not marked as correct or incorrect
+    Never,
+
+    /// Squares are only marked once the player [checks](puzzled_core::Solve::check) them
+    #[default]
+    OnDemand,
+
+    /// Every entry is checked as soon as it is entered
+    Immediate,
+}
+
+impl CheckPolicy {
+    /// Whether squares can be [checked](puzzled_core::Solve::check) at all
+    pub fn allows_checks(&self) -> bool {
+        !matches!(self, Self::Never)
+    }
+
+    pub fn is_on_demand(&self) -> bool {
+        matches!(self, Self::OnDemand)
+    }
+
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, Self::Immediate)
+    }
+}
@@ -1,15 +1,22 @@
-use std::{collections::BTreeMap, fmt};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    ops::Bound::{Excluded, Unbounded},
+};
 
 use derive_more::{Deref, DerefMut};
-use puzzled_core::{Offset, Position};
+use puzzled_core::Position;
 
-#[cfg(feature = "serde")]
-use crate::SerdeClue;
 use crate::{Clue, ClueDirection, ClueId};
+#[cfg(feature = "serde")]
+use crate::{SerdeClue, WritingDirection};
 
 /// Collection type of all [clues](Clue) in a [puzzle](crate::Crossword)
 ///
 /// By using [`BTreeMap`] with a [`ClueId`] as key type, clues are easily traversed in order by number, then [`ClueDirection`].
+///
+/// # Order
+/// Clues are ordered by ascending number, which is the order of their [identifiers](ClueId).
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deref, DerefMut)]
 pub struct Clues {
     #[deref]
@@ -39,24 +46,255 @@ impl Clues {
         self.entries.insert(id, clue)
     }
 
+    /// Replace the text of the identified clue, returning its previous text
+    ///
+    /// The placement of the clue is left untouched, such that no other clues are affected
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    ///
+    /// let mut puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    /// );
+    /// let clues = puzzle.clues_mut();
+    ///
+    /// assert_eq!(clues.retext((1, Down), "First letters"), Some("AC".to_string()));
+    /// assert_eq!(clues[&(1, Down).into()].text(), "First letters");
+    /// assert_eq!(clues.retext((2, Down), "B"), None);
+    /// ```
+    pub fn retext<I, S>(&mut self, id: I, text: S) -> Option<String>
+    where
+        I: Into<ClueId>,
+        S: Into<String>,
+    {
+        let clue = self.entries.get_mut(&id.into())?;
+        Some(std::mem::replace(&mut clue.text, text.into()))
+    }
+
+    /// Set the [note](Clue::note) of the identified clue, or remove it if the note is empty
+    ///
+    /// Returns the previous note of the clue, or `None` if the clue is not in the collection
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [A B]
+    ///     - A: "First letters"
+    /// );
+    /// let clues = puzzle.clues_mut();
+    ///
+    /// assert_eq!(clues.annotate((1, Across), "Maybe AB?"), Some(None));
+    /// assert_eq!(clues[&(1, Across).into()].note(), Some("Maybe AB?"));
+    ///
+    /// assert_eq!(clues.annotate((1, Across), ""), Some(Some("Maybe AB?".to_string())));
+    /// assert_eq!(clues[&(1, Across).into()].note(), None);
+    /// assert_eq!(clues.annotate((1, Down), "No clue"), None);
+    /// ```
+    pub fn annotate<I, S>(&mut self, id: I, note: S) -> Option<Option<String>>
+    where
+        I: Into<ClueId>,
+        S: Into<String>,
+    {
+        let clue = self.entries.get_mut(&id.into())?;
+        let note = Some(note.into()).filter(|note| !note.is_empty());
+
+        Some(std::mem::replace(&mut clue.note, note))
+    }
+
+    /// Remove the identified clue together with its placement
+    ///
+    /// The number of the clue is only removed from its start when no clue in the other direction shares it
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    /// use puzzled_core::Position;
+    ///
+    /// let mut puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    /// );
+    /// let clues = puzzle.clues_mut();
+    ///
+    /// assert!(clues.remove((1, Across)).is_some());
+    /// assert_eq!(clues.get_clue(Position::new(0, 1), Across), None);
+    /// assert_eq!(clues.get_num(Position::new(0, 0)), Some(1));
+    ///
+    /// assert!(clues.remove((2, Down)).is_some());
+    /// assert_eq!(clues.get_num(Position::new(0, 1)), None);
+    /// ```
+    pub fn remove<I>(&mut self, id: I) -> Option<Clue>
+    where
+        I: Into<ClueId>,
+    {
+        let id = id.into();
+        let clue = self.entries.remove(&id)?;
+
+        let clues = match id.direction {
+            ClueDirection::Across => &mut self.across,
+            ClueDirection::Down => &mut self.down,
+        };
+        for pos in clue.positions() {
+            if clues.get(&pos) == Some(&id) {
+                clues.remove(&pos);
+            }
+        }
+
+        // Number the start by any other clue that starts there
+        if self.numbers.get(&clue.start) == Some(&id.num) {
+            match self
+                .entries
+                .values()
+                .find(|other| other.start == clue.start)
+            {
+                Some(other) => self.numbers.insert(clue.start, other.num),
+                None => self.numbers.remove(&clue.start),
+            };
+        }
+
+        Some(clue)
+    }
+
     fn insert_clue_positions(&mut self, id: &ClueId, clue: &Clue) {
-        // Insert the clue number at its start
-        let mut pos = clue.start;
-        self.numbers.insert(pos, id.num);
+        // Insert the clue number at its start, preferring the across number when numbered separately
+        if id.direction == ClueDirection::Across || !self.numbers.contains_key(&clue.start) {
+            self.numbers.insert(clue.start, id.num);
+        }
 
         // Determine which direction to insert the clue positions for
-        let (clues, offset) = match id.direction {
-            ClueDirection::Across => (&mut self.across, Offset::RIGHT),
-            ClueDirection::Down => (&mut self.down, Offset::DOWN),
+        let clues = match id.direction {
+            ClueDirection::Across => &mut self.across,
+            ClueDirection::Down => &mut self.down,
         };
 
         // Insert the clue identifier for each position of the clue
-        for _ in 0..clue.len {
+        for pos in clue.positions() {
             clues.insert(pos, *id);
-            pos += offset;
         }
     }
 
+    /// Retrieve the clue with the given identifier
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    ///
+    /// let puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    ///     - A: "C"
+    /// );
+    /// let clues = puzzle.clues();
+    ///
+    /// assert_eq!(clues.get((2, Down)).map(|clue| clue.text().as_str()), Some("B"));
+    /// assert_eq!(clues.get((2, Across)), None);
+    /// ```
+    pub fn get<I>(&self, id: I) -> Option<&Clue>
+    where
+        I: Into<ClueId>,
+    {
+        self.entries.get(&id.into())
+    }
+
+    /// Mutably retrieve the clue with the given identifier
+    ///
+    /// Only the text of the clue should be changed, as its placement is also tracked by the collection
+    pub fn get_mut<I>(&mut self, id: I) -> Option<&mut Clue>
+    where
+        I: Into<ClueId>,
+    {
+        self.entries.get_mut(&id.into())
+    }
+
+    /// Returns an iterator over the clues with the given number, where the across clue precedes the down clue
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    ///
+    /// let puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    ///     - A: "C"
+    /// );
+    /// let clues = puzzle.clues();
+    ///
+    /// let ids: Vec<_> = clues.by_number(1).map(|clue| clue.direction()).collect();
+    /// assert_eq!(ids, vec![Across, Down]);
+    /// assert_eq!(clues.by_number(4).count(), 0);
+    /// ```
+    pub fn by_number(&self, num: u8) -> impl Iterator<Item = &Clue> {
+        let first = ClueId::from((num, ClueDirection::Across));
+        let last = ClueId::from((num, ClueDirection::Down));
+
+        self.entries.range(first..=last).map(|(_, clue)| clue)
+    }
+
+    /// Find the first clue in the given direction that comes after the identified clue
+    ///
+    /// Clues are ordered by their [identifier](ClueId), i.e. by number and then by direction.
+    /// The identified clue does not need to be part of the collection, nor have the given direction, which makes it possible to switch directions while navigating.
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    ///
+    /// let puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    ///     - A: "C"
+    /// );
+    /// let clues = puzzle.clues();
+    ///
+    /// assert_eq!(clues.next_after((1, Across), Across).map(|clue| clue.num()), Some(3));
+    /// assert_eq!(clues.next_after((1, Across), Down).map(|clue| clue.num()), Some(1));
+    /// assert_eq!(clues.next_after((3, Across), Across), None);
+    /// ```
+    pub fn next_after<I>(&self, id: I, direction: ClueDirection) -> Option<&Clue>
+    where
+        I: Into<ClueId>,
+    {
+        self.entries
+            .range((Excluded(id.into()), Unbounded))
+            .map(|(_, clue)| clue)
+            .find(|clue| clue.direction() == direction)
+    }
+
+    /// Find the last clue in the given direction that comes before the identified clue, see [`next_after`](Self::next_after)
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*};
+    ///
+    /// let puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    ///     - A: "C"
+    /// );
+    /// let clues = puzzle.clues();
+    ///
+    /// assert_eq!(clues.prev_before((3, Across), Down).map(|clue| clue.num()), Some(2));
+    /// assert_eq!(clues.prev_before((1, Down), Across).map(|clue| clue.num()), Some(1));
+    /// assert_eq!(clues.prev_before((1, Across), Across), None);
+    /// ```
+    pub fn prev_before<I>(&self, id: I, direction: ClueDirection) -> Option<&Clue>
+    where
+        I: Into<ClueId>,
+    {
+        self.entries
+            .range(..id.into())
+            .rev()
+            .map(|(_, clue)| clue)
+            .find(|clue| clue.direction() == direction)
+    }
+
     pub fn get_clues(&self, pos: Position) -> Option<(&Clue, &Clue)> {
         let across = self.entries.get(self.across.get(&pos)?)?;
         let down = self.entries.get(self.down.get(&pos)?)?;
@@ -78,8 +316,7 @@ impl Clues {
         self.numbers.get(&pos).cloned()
     }
 
-    /// Returns an iterator over just the across entries of the puzzle.
-    /// The order is defined by the [`Ord`] implementation on [`Clue`].
+    /// Returns an iterator over just the across entries of the puzzle, in [order](Clues#order).
     /// ```
     /// use puzzled::crossword::{crossword, clue, ClueDirection::*};
     ///
@@ -107,8 +344,7 @@ impl Clues {
             .filter(|clue| matches!(clue.direction(), ClueDirection::Across))
     }
 
-    /// Returns a mutable iterator over just the across entries of the puzzle.
-    /// The order is defined by the [`Ord`] implementation on [`Clue`].
+    /// Returns a mutable iterator over just the across entries of the puzzle, in [order](Clues#order).
     /// ```
     /// use puzzled::crossword::{crossword, clue, ClueDirection::*};
     ///
@@ -136,8 +372,7 @@ impl Clues {
             .filter(|clue| matches!(clue.direction(), ClueDirection::Across))
     }
 
-    /// Returns an iterator over just the down entries of the puzzle.
-    /// The order is defined by the [`Ord`] implementation on [`Clue`].
+    /// Returns an iterator over just the down entries of the puzzle, in [order](Clues#order).
     /// ```
     /// use puzzled::crossword::{crossword, clue, ClueDirection::*};
     ///
@@ -165,8 +400,7 @@ impl Clues {
             .filter(|clue| matches!(clue.direction(), ClueDirection::Down))
     }
 
-    /// Returns a mutable iterator over just the down entries of the puzzle.
-    /// The order is defined by the [`Ord`] implementation on [`Clue`].
+    /// Returns a mutable iterator over just the down entries of the puzzle, in [order](Clues#order).
     /// ```
     /// use puzzled::crossword::{crossword, clue, ClueDirection::*};
     ///
@@ -194,6 +428,7 @@ impl Clues {
             .filter(|clue| matches!(clue.direction(), ClueDirection::Down))
     }
 
+    /// Returns an iterator over the entries of the puzzle in the given direction, in [order](Clues#order)
     pub fn iter_direction(&self, dir: ClueDirection) -> impl Iterator<Item = &Clue> {
         self.entries
             .values()
@@ -213,29 +448,23 @@ impl fmt::Display for Clues {
 
 #[cfg(feature = "serde")]
 impl Clues {
-    pub(crate) fn from_serde(data: SerdeClues) -> Result<Self, String> {
+    pub(crate) fn from_serde(data: SerdeClues, writing: WritingDirection) -> Result<Self, String> {
         let mut clues = BTreeMap::new();
 
         for (key, val) in data {
-            use std::str::FromStr;
-
             // Try to parse the clue number and direction from the key
-            let (num_str, dir_str) = key.split_once('-').ok_or(format!(
-                "Key '{key}' must be formatted as '<num> : <dir>' where <dir> ::= A | D"
-            ))?;
-            let num: u8 = num_str
-                .parse()
-                .map_err(|_| format!("Expected number, found '{num_str}'"))?;
-            let direction = ClueDirection::from_str(dir_str)?;
+            let id: ClueId = key.parse()?;
+            let ClueId { num, direction } = id;
 
             // Then construct the clue and insert it into the clues
-            let id: ClueId = (num, direction).into();
             let clue = Clue {
                 num,
                 direction,
                 text: val.text,
                 start: val.start,
                 len: val.len,
+                writing,
+                note: val.note,
             };
 
             clues.insert(id, clue);
@@ -251,6 +480,7 @@ impl Clues {
                     text: clue.text().clone(),
                     start: clue.start,
                     len: clue.len,
+                    note: clue.note.clone(),
                 };
 
                 (id.to_string(), val)
@@ -99,3 +99,24 @@ mod serde_impl {
         }
     }
 }
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::{
+        arbitrary::Arbitrary,
+        prop_oneof,
+        strategy::{BoxedStrategy, Just, Strategy},
+    };
+
+    use crate::ClueDirection;
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl Arbitrary for ClueDirection {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            prop_oneof![Just(ClueDirection::Across), Just(ClueDirection::Down)].boxed()
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt, ops};
+use std::{fmt, ops, str::FromStr};
 
 use crate::{Clue, ClueDirection, Crossword};
 
@@ -18,6 +18,23 @@ impl fmt::Display for ClueId {
     }
 }
 
+impl FromStr for ClueId {
+    type Err = String;
+
+    /// Parse the identifier from its [display](fmt::Display) form, such as `"4-A"` for "4 across"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (num_str, dir_str) = s.split_once('-').ok_or(format!(
+            "Key '{s}' must be formatted as '<num> : <dir>' where <dir> ::= A | D"
+        ))?;
+        let num: u8 = num_str
+            .parse()
+            .map_err(|_| format!("Expected number, found '{num_str}'"))?;
+        let direction = ClueDirection::from_str(dir_str)?;
+
+        Ok(Self { num, direction })
+    }
+}
+
 impl From<(u8, ClueDirection)> for ClueId {
     fn from((num, direction): (u8, ClueDirection)) -> Self {
         Self { num, direction }
@@ -2,18 +2,20 @@ mod clues;
 mod direction;
 mod grid;
 mod id;
+mod numbered;
 mod specification;
 
 pub use clues::*;
 pub use direction::*;
 pub use grid::*;
 pub use id::*;
+pub use numbered::*;
 pub use specification::*;
 
 use puzzled_core::Position;
 use std::{cmp::Ordering, fmt};
 
-use crate::{Crossword, CrosswordSquares};
+use crate::{ClueNumbers, Crossword, CrosswordSquares, WritingDirection};
 
 /// Clue
 ///
@@ -28,6 +30,10 @@ pub struct Clue {
     num: u8,
     start: Position,
     len: u8,
+    writing: WritingDirection,
+
+    // Annotation of the player
+    note: Option<String>,
 }
 
 impl Clue {
@@ -50,28 +56,39 @@ impl Clue {
             direction,
             start,
             len,
+            writing: WritingDirection::default(),
+            note: None,
         }
     }
 
     /// Returns an iterator over every [position](Position) that the clue covers in the [puzzle grid](crate::Squares)
+    ///
+    /// Positions are given in reading order, such that across clues of a [right-to-left](WritingDirection::RightToLeft) crossword run to the left.
     pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
-        (0..self.len).map(move |offset| match self.direction {
-            ClueDirection::Across => Position {
-                row: self.start.row,
-                col: self.start.col + offset as usize,
-            },
-            ClueDirection::Down => Position {
-                row: self.start.row + offset as usize,
-                col: self.start.col,
-            },
+        let step = self.writing.step(self.direction);
+
+        (0..self.len).map(move |offset| {
+            self.start
+                .step(step, offset as usize)
+                .expect("Clues run within the grid")
         })
     }
 
+    /// Direction in which the clue is read, which is only relevant for across clues
+    pub fn writing(&self) -> WritingDirection {
+        self.writing
+    }
+
     /// Clue text
     pub fn text(&self) -> &String {
         &self.text
     }
 
+    /// Note that the player made on the clue, which is set through [`Clues::annotate`]
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
     /// [Direction] of the clue within the puzzle
     pub fn direction(&self) -> ClueDirection {
         self.direction
@@ -106,6 +123,30 @@ impl Clue {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Same clue with a different number, e.g. after changing the [numbering scheme](crate::NumberingScheme)
+    pub(crate) fn renumbered(self, num: u8) -> Self {
+        Self { num, ..self }
+    }
+
+    /// Same clue read in the given writing direction, where its start is already the first square in that direction
+    pub(crate) fn with_writing(self, writing: WritingDirection) -> Self {
+        Self { writing, ..self }
+    }
+
+    /// Same clue covering the same squares, but read in the given writing direction
+    pub(crate) fn written(self, writing: WritingDirection) -> Self {
+        if self.direction == ClueDirection::Down || self.writing == writing || self.len == 0 {
+            return Self { writing, ..self };
+        }
+
+        let start = self.positions().last().unwrap_or(self.start);
+        Self {
+            start,
+            writing,
+            ..self
+        }
+    }
 }
 
 impl fmt::Display for Clue {
@@ -129,8 +170,34 @@ impl PartialOrd for Clue {
 }
 
 impl Crossword {
+    /// Clue in the direction that the square at the position is part of, e.g. to highlight the clue of a selected square
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*, Position};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B .]
+    ///     [C D E]
+    ///     [. F G]
+    ///     - A: "AB"
+    ///     - A: "CDE"
+    ///     - A: "FG"
+    ///     - D: "AC"
+    ///     - D: "BDF"
+    ///     - D: "EG"
+    /// );
+    /// let pos = Position::new(1, 1);
+    ///
+    /// assert_eq!(puzzle.clue_at(pos, Across).map(|clue| clue.id()), Some((3, Across).into()));
+    /// assert_eq!(puzzle.clue_at(pos, Down).map(|clue| clue.text().as_str()), Some("BDF"));
+    /// assert_eq!(puzzle.clue_at(Position::new(0, 2), Down), None);
+    /// ```
+    pub fn clue_at(&self, pos: Position, direction: ClueDirection) -> Option<&Clue> {
+        self.clues.get_clue(pos, direction)
+    }
+
     pub fn insert_clues(&mut self, clues: impl IntoIterator<Item = ClueSpec>) -> Vec<ClueSpec> {
         let (positioned, unpositioned) = self.place_clues(clues);
+        self.mark_dirty();
 
         for clue in positioned {
             let id = (clue.num, clue.direction).into();
@@ -140,6 +207,58 @@ impl Crossword {
         unpositioned
     }
 
+    /// Replace the clues of the puzzle by the given specifications, returning the ones that could not be placed
+    ///
+    /// In every direction, the specifications are matched with the existing clues in order.
+    /// Matched clues only have their text replaced, such that placement is only re-run for the remaining specifications.
+    /// ```
+    /// use puzzled::crossword::{crossword, clue_spec, ClueDirection::*};
+    ///
+    /// let mut puzzle = crossword! (
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    ///     - A: "C"
+    /// );
+    ///
+    /// let unplaced = puzzle.replace_clues([
+    ///     clue_spec!(A: "Start of the alphabet"),
+    ///     clue_spec!(D: "Also the start of the alphabet"),
+    ///     clue_spec!(A: "Third letter"),
+    /// ]);
+    ///
+    /// assert!(unplaced.is_empty());
+    /// assert_eq!(puzzle.clues().len(), 3);
+    /// assert_eq!(puzzle.clues().get((3, Across)).map(|clue| clue.text().as_str()), Some("Third letter"));
+    /// assert_eq!(puzzle.clues().get((2, Down)), None);
+    /// ```
+    pub fn replace_clues(&mut self, specs: impl IntoIterator<Item = ClueSpec>) -> Vec<ClueSpec> {
+        let (across, down): (Vec<_>, Vec<_>) = specs
+            .into_iter()
+            .partition(|spec| spec.direction() == ClueDirection::Across);
+        let mut remaining = Vec::new();
+        self.mark_dirty();
+
+        for (direction, specs) in [(ClueDirection::Across, across), (ClueDirection::Down, down)] {
+            let mut ids: Vec<_> = self.clues.iter_direction(direction).map(Clue::id).collect();
+            let unmatched = ids.split_off(specs.len().min(ids.len()));
+
+            let mut specs = specs.into_iter();
+            for (id, spec) in ids.into_iter().zip(specs.by_ref()) {
+                self.clues.retext(id, spec.text().clone());
+            }
+
+            for id in unmatched {
+                self.clues.remove(id);
+            }
+            remaining.extend(specs);
+        }
+
+        self.insert_clues(remaining)
+    }
+
     pub fn place_clues(
         &self,
         clues: impl IntoIterator<Item = ClueSpec>,
@@ -152,44 +271,46 @@ impl Crossword {
         let mut across_iter = across.into_iter();
         let mut down_iter = down.into_iter();
 
-        // Determine all positions past the last entry
-        let last = match self.clues.values().last() {
-            Some(clue) => clue.clone(),
-            None => Clue::default(),
+        // Determine all positions past the last entry in either direction
+        let last_start = |direction| {
+            self.clues
+                .iter_direction(direction)
+                .map(|clue| clue.start)
+                .max_by(|&a, &b| self.writing.cmp_positions(a, b))
         };
-        let pos_iter: Vec<_> = self
-            .squares
-            .positions()
-            .skip_while(|pos| *pos != last.start)
+        let last = [ClueDirection::Across, ClueDirection::Down].map(last_start);
+        let writing = self.writing;
+        let first = last
+            .into_iter()
+            .flatten()
+            .min_by(|&a, &b| writing.cmp_positions(a, b));
+
+        let pos_iter: Vec<_> = writing
+            .positions(self.squares.rows(), self.squares.cols())
+            .skip_while(|&pos| first.is_some_and(|first| writing.cmp_positions(pos, first).is_lt()))
             .collect();
 
         // Keep track of positioned clues and their number
         let mut positioned = Vec::new();
-        let mut num = last.num() + 1;
+        let mut numbers = ClueNumbers::after(self.numbering, &self.clues);
 
         for start in pos_iter {
-            let mut started = false;
-
-            // Try to position the clue directed across
-            if let Some(clue) =
-                self.try_clue_position(num, start, ClueDirection::Across, &last, &mut across_iter)
-            {
-                positioned.push(clue);
-                started = true;
+            for (direction, last, iter) in [
+                (ClueDirection::Across, last[0], &mut across_iter),
+                (ClueDirection::Down, last[1], &mut down_iter),
+            ] {
+                let Some(num) = numbers.peek(direction) else {
+                    continue;
+                };
+
+                if let Some(clue) = self.try_clue_position(num, start, direction, last, iter) {
+                    numbers.take(direction);
+                    positioned.push(clue);
+                }
             }
 
-            // Try to position the clue directed down
-            if let Some(clue) =
-                self.try_clue_position(num, start, ClueDirection::Down, &last, &mut down_iter)
-            {
-                positioned.push(clue);
-                started = true;
-            }
-
-            // If successful, move to the next clue
-            if started {
-                num += 1;
-            }
+            // Move to the next number if any clue started here
+            numbers.next_square();
         }
 
         // Collect the remaining clues that could not be positioned within the puzzle
@@ -204,16 +325,19 @@ impl Crossword {
         num: u8,
         start: Position,
         direction: ClueDirection,
-        last: &Clue,
+        last: Option<Position>,
         iter: &mut impl Iterator<Item = ClueSpec>,
     ) -> Option<Clue> {
-        // Cannot position clue at the same start as the last clue in the same direction
-        if num > 1 && last.start == start && last.direction() == direction {
+        // Cannot position clue at or before the start of the last clue in the same direction
+        if last.is_some_and(|last| self.writing.cmp_positions(start, last).is_le()) {
             return None;
         }
 
         // Cannot start the clue in the given direction from the given start
-        if !self.squares.can_clue_start_in_dir(start, direction) {
+        if !self
+            .squares
+            .can_clue_start_written(start, direction, self.writing)
+        {
             return None;
         }
 
@@ -226,7 +350,11 @@ impl Crossword {
             direction,
             start,
             text: clue.text().clone(),
-            len: self.squares.find_clue_len(start, direction),
+            len: self
+                .squares
+                .find_clue_len_written(start, direction, self.writing),
+            writing: self.writing,
+            note: None,
         })
     }
 }
@@ -237,4 +365,61 @@ pub(crate) struct SerdeClue {
     text: String,
     start: Position,
     len: u8,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::{
+        arbitrary::{Arbitrary, any},
+        strategy::{BoxedStrategy, Strategy},
+    };
+    use puzzled_core::Position;
+
+    use crate::{Clue, ClueDirection};
+
+    /// Largest length of an [arbitrary](Arbitrary) clue, which is also the largest row and column it starts at
+    const MAX_ARBITRARY_LEN: u8 = 15;
+
+    /// Arbitrary clues are placed independently of any puzzle grid
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl Arbitrary for Clue {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            let max = MAX_ARBITRARY_LEN as usize;
+
+            (
+                1..=99u8,
+                any::<ClueDirection>(),
+                "[A-Z][a-z]{0,9}( [a-z]{1,10}){0,5}",
+                (0..max, 0..max),
+                1..=MAX_ARBITRARY_LEN,
+            )
+                .prop_map(|(num, direction, text, (row, col), len)| {
+                    Clue::new(num, direction, text, Position { row, col }, len)
+                })
+                .boxed()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use crate::Clue;
+
+        proptest! {
+            #[test]
+            fn positions(clue in any::<Clue>()) {
+                let positions: Vec<_> = clue.positions().collect();
+
+                prop_assert_eq!(positions.len(), clue.len() as usize);
+                prop_assert_eq!(positions.first(), Some(&clue.start()));
+            }
+        }
+    }
 }
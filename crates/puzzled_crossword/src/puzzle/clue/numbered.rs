@@ -0,0 +1,193 @@
+use std::{collections::BTreeMap, fmt};
+
+use puzzled_core::Position;
+
+use crate::{Clue, ClueId, ClueSpec, Crossword, CrosswordSquares};
+
+/// Difference between the numbers that were given for clues and the numbers that the grid derives for them
+///
+/// Returned by [`Crossword::place_numbered_clues`] for every clue that could not be matched, instead of renumbering it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberingMismatch {
+    /// No clue with the number starts in the direction in the grid
+    UnknownClue { id: ClueId, text: String },
+
+    /// Another clue was already given with the number and direction
+    DuplicateClue { id: ClueId, text: String },
+
+    /// Grid has a clue that was not given
+    MissingClue { id: ClueId, start: Position },
+}
+
+impl NumberingMismatch {
+    /// Identifier of the clue that the mismatch is about
+    pub fn id(&self) -> ClueId {
+        match self {
+            Self::UnknownClue { id, .. }
+            | Self::DuplicateClue { id, .. }
+            | Self::MissingClue { id, .. } => *id,
+        }
+    }
+}
+
+impl fmt::Display for NumberingMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownClue { id, text } => {
+                write!(f, "Clue {id} '{text}' does not start in the grid")
+            }
+            Self::DuplicateClue { id, text } => {
+                write!(f, "Clue {id} '{text}' was given more than once")
+            }
+            Self::MissingClue { id, start } => {
+                write!(f, "Clue {id} starting at {start:?} was not given")
+            }
+        }
+    }
+}
+
+impl Crossword {
+    /// Place clues that come with their own number, as in formats such as ipuz and JPZ, verifying them against the numbering of the grid
+    ///
+    /// Rather than [placing](Self::place_clues) the clues in order, every clue is placed at the square that the [numbering scheme](crate::NumberingScheme) gives its number in its direction.
+    /// Clues that do not match the grid are never renumbered, but reported as a [mismatch](NumberingMismatch), as are the clues of the grid that were not given.
+    /// ```
+    /// use puzzled::crossword::{crossword, clue_spec, ClueDirection::*, NumberingMismatch};
+    ///
+    /// let puzzle = crossword!(
+    ///     [C A B]
+    ///     [O R E]
+    ///     [T E N]
+    /// );
+    ///
+    /// let (clues, mismatches) = puzzle.place_numbered_clues([
+    ///     (1, clue_spec!(A: "Taxi")),
+    ///     (4, clue_spec!(A: "Mineral")),
+    ///     (6, clue_spec!(A: "Number of toes")),
+    ///     (1, clue_spec!(D: "Baby bed")),
+    ///     (2, clue_spec!(D: "Exist")),
+    ///     (3, clue_spec!(D: "Wading bird")),
+    /// ]);
+    ///
+    /// assert_eq!(clues.len(), 5);
+    /// assert_eq!(mismatches.len(), 2);
+    /// assert_eq!(mismatches[0].to_string(), "Clue 6-A 'Number of toes' does not start in the grid");
+    /// assert!(matches!(mismatches[1], NumberingMismatch::MissingClue { .. }));
+    /// assert_eq!(mismatches[1].id(), (5, Across).into());
+    /// ```
+    pub fn place_numbered_clues(
+        &self,
+        clues: impl IntoIterator<Item = (u8, ClueSpec)>,
+    ) -> (Vec<Clue>, Vec<NumberingMismatch>) {
+        let mut starts = self.squares.clue_starts(self.numbering, self.writing);
+        let mut placed = BTreeMap::new();
+        let mut mismatches = Vec::new();
+
+        for (num, spec) in clues {
+            let id = ClueId::from((num, spec.direction()));
+
+            if placed.contains_key(&id) {
+                let text = spec.text().clone();
+                mismatches.push(NumberingMismatch::DuplicateClue { id, text });
+                continue;
+            }
+
+            match starts.remove(&id) {
+                Some((start, len)) => {
+                    let clue = spec.place(num, start, len).with_writing(self.writing);
+                    placed.insert(id, clue);
+                }
+                None => {
+                    let text = spec.text().clone();
+                    mismatches.push(NumberingMismatch::UnknownClue { id, text });
+                }
+            }
+        }
+
+        mismatches.extend(
+            starts
+                .into_iter()
+                .map(|(id, (start, _))| NumberingMismatch::MissingClue { id, start }),
+        );
+
+        (placed.into_values().collect(), mismatches)
+    }
+
+    /// Insert clues that come with their own number, returning the [mismatches](NumberingMismatch) with the numbering of the grid
+    ///
+    /// Clues are placed as with [`place_numbered_clues`](Self::place_numbered_clues) and replace any existing clue with the same identifier.
+    pub fn insert_numbered_clues(
+        &mut self,
+        clues: impl IntoIterator<Item = (u8, ClueSpec)>,
+    ) -> Vec<NumberingMismatch> {
+        let (placed, mismatches) = self.place_numbered_clues(clues);
+        self.mark_dirty();
+
+        for clue in placed {
+            self.clues.insert(clue.id(), clue);
+        }
+
+        mismatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection, ClueDirection::*, NumberingScheme, crossword};
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [A B .]
+            [C D E]
+            [. F G]
+        )
+    }
+
+    #[rstest]
+    #[case::matching(NumberingScheme::Standard, vec![(1, Across), (3, Across), (5, Across), (1, Down), (2, Down), (4, Down)], vec![])]
+    #[case::separate(NumberingScheme::Separate, vec![(1, Across), (2, Across), (3, Across), (1, Down), (2, Down), (3, Down)], vec![])]
+    #[case::wrong_scheme(NumberingScheme::Separate, vec![(1, Across), (3, Across), (5, Across), (1, Down), (2, Down), (4, Down)], vec![(5, Across), (4, Down), (2, Across), (3, Down)])]
+    #[case::wrong_direction(NumberingScheme::Standard, vec![(1, Across), (3, Down), (3, Across), (5, Across), (1, Down), (2, Down), (4, Down)], vec![(3, Down)])]
+    #[case::duplicate(NumberingScheme::Standard, vec![(1, Across), (1, Across), (3, Across), (5, Across), (1, Down), (2, Down), (4, Down)], vec![(1, Across)])]
+    #[case::missing(NumberingScheme::Standard, vec![(1, Across), (1, Down)], vec![(2, Down), (3, Across), (4, Down), (5, Across)])]
+    fn place_numbered_clues(
+        #[case] numbering: NumberingScheme,
+        #[case] ids: Vec<(u8, ClueDirection)>,
+        #[case] expected: Vec<(u8, ClueDirection)>,
+    ) {
+        let puzzle = puzzle().with_numbering(numbering);
+        let specs = ids
+            .into_iter()
+            .map(|(num, direction)| (num, ClueSpec::new(direction, "")));
+
+        let (_, mismatches) = puzzle.place_numbered_clues(specs);
+        let ids: Vec<_> = mismatches.iter().map(NumberingMismatch::id).collect();
+        let expected: Vec<ClueId> = expected.into_iter().map(ClueId::from).collect();
+
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn insert_numbered_clues() {
+        let mut puzzle = puzzle();
+        let mismatches = puzzle.insert_numbered_clues([
+            (4, ClueSpec::down("EG")),
+            (3, ClueSpec::across("CDE")),
+            (2, ClueSpec::down("BDF")),
+        ]);
+
+        assert_eq!(mismatches.len(), 3);
+
+        let clue = &puzzle.clues()[&(3, Across).into()];
+        assert_eq!(clue.start(), Position::new(1, 0));
+        assert_eq!(clue.len(), 3);
+        assert_eq!(clue.text(), "CDE");
+
+        let clue = &puzzle.clues()[&(2, Down).into()];
+        assert_eq!(clue.start(), Position::new(0, 1));
+        assert_eq!(clue.len(), 3);
+    }
+}
@@ -52,6 +52,8 @@ impl ClueSpec {
             len,
             text: self.text,
             direction: self.direction,
+            writing: Default::default(),
+            note: None,
         }
     }
 }
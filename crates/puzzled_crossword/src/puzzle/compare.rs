@@ -0,0 +1,528 @@
+use std::{collections::BTreeSet, fmt};
+
+use puzzled_core::{Grid, Metadata, Position};
+
+use crate::{Clue, ClueId, Crossword, CrosswordSquare};
+
+/// Single way in which two [crosswords](Crossword) differ, as found by [`compare`]
+///
+/// Values are described as text, where [`None`] means that the value is only present in the other puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleDifference {
+    /// Grids have a different number of rows or columns, in which case their squares are not compared
+    Size {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+
+    /// Square at the position has a different solution, style or decoration
+    Square {
+        pos: Position,
+        left: String,
+        right: String,
+    },
+
+    /// Clue with the identifier has a different text or placement
+    Clue {
+        id: ClueId,
+        left: Option<String>,
+        right: Option<String>,
+    },
+
+    /// Property of the puzzle differs, such as its title, numbering scheme, lock state or the note on a cell
+    Property {
+        name: String,
+        left: Option<String>,
+        right: Option<String>,
+    },
+}
+
+impl PuzzleDifference {
+    /// Name of the part of the puzzle that differs, e.g. `square 2,3` or `clue 1-A`
+    pub fn label(&self) -> String {
+        match self {
+            Self::Size { .. } => "size".to_string(),
+            Self::Square { pos, .. } => format!("square {pos}"),
+            Self::Clue { id, .. } => format!("clue {id}"),
+            Self::Property { name, .. } => name.clone(),
+        }
+    }
+
+    /// Description of both values, where a missing value is shown as `-`
+    fn values(&self) -> (String, String) {
+        let or_missing = |value: &Option<String>| value.clone().unwrap_or("-".to_string());
+
+        match self {
+            Self::Size { left, right } => (
+                format!("{}x{}", left.0, left.1),
+                format!("{}x{}", right.0, right.1),
+            ),
+            Self::Square { left, right, .. } => (left.clone(), right.clone()),
+            Self::Clue { left, right, .. } | Self::Property { left, right, .. } => {
+                (or_missing(left), or_missing(right))
+            }
+        }
+    }
+}
+
+/// Every difference between two [crosswords](Crossword), which [displays](fmt::Display) them side by side
+///
+/// Use [`assert_puzzles_eq!`](crate::assert_puzzles_eq) in tests to show the differences when the puzzles are not equal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleDiff {
+    left: Grid<String>,
+    right: Grid<String>,
+    differences: Vec<PuzzleDifference>,
+}
+
+impl PuzzleDiff {
+    /// Whether the puzzles are the same, including details that [`PartialEq`] ignores such as the style of the squares
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.differences.len()
+    }
+
+    pub fn differences(&self) -> &[PuzzleDifference] {
+        &self.differences
+    }
+
+    /// Whether the squares of the puzzles differ, in which case both grids are shown
+    fn has_grid_differences(&self) -> bool {
+        self.differences.iter().any(|difference| {
+            matches!(
+                difference,
+                PuzzleDifference::Size { .. } | PuzzleDifference::Square { .. }
+            )
+        })
+    }
+}
+
+impl fmt::Display for PuzzleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "Puzzles are the same");
+        }
+
+        writeln!(f, "Puzzles differ in {} place(s)", self.len())?;
+
+        if self.has_grid_differences() {
+            let left = grid_lines(&self.left);
+            let right = grid_lines(&self.right);
+            let width = left.iter().map(|line| line.chars().count()).max();
+
+            writeln!(f)?;
+            for row in 0..left.len().max(right.len()) {
+                let left = left.get(row).map_or("", String::as_str);
+                let right = right.get(row).map_or("", String::as_str);
+                let line = format!("  {left:<width$} | {right}", width = width.unwrap_or(0));
+                writeln!(f, "{}", line.trim_end())?;
+            }
+        }
+
+        let rows: Vec<_> = self
+            .differences
+            .iter()
+            .map(|difference| (difference.label(), difference.values()))
+            .collect();
+        let label_width = rows.iter().map(|(label, _)| label.chars().count()).max();
+        let left_width = rows.iter().map(|(_, (left, _))| left.chars().count()).max();
+        let (label_width, left_width) = (label_width.unwrap_or(0), left_width.unwrap_or(0));
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "  {:<label_width$}   {:<left_width$} | right",
+            "", "left"
+        )?;
+        for (label, (left, right)) in rows {
+            writeln!(f, "  {label:<label_width$}   {left:<left_width$} | {right}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Find every difference between the puzzles, e.g. to find out why a puzzle that was written and read back is not the same
+///
+/// Differences are ordered by the part of the puzzle they are in: the grid first, then the clues and lastly the other properties.
+/// ```
+/// use puzzled::crossword::{compare, crossword, PuzzleDifference, Position};
+///
+/// let puzzle = crossword!(
+///     [A B]
+///     [C D]
+///     - A: "AB"
+///     - A: "CD"
+///     - D: "AC"
+///     - D: "BD"
+///     title: "Mini"
+/// );
+/// let other = crossword!(
+///     [A B]
+///     [C E]
+///     - A: "AB"
+///     - A: "CE"
+///     - D: "AC"
+///     - D: "BE"
+///     title: "Mini"
+/// );
+///
+/// let diff = compare(&puzzle, &other);
+///
+/// assert_eq!(diff.len(), 3);
+/// assert_eq!(diff.differences()[0], PuzzleDifference::Square {
+///     pos: Position::new(1, 1),
+///     left: "D".to_string(),
+///     right: "E".to_string(),
+/// });
+/// assert!(compare(&puzzle, &puzzle).is_empty());
+/// ```
+pub fn compare(left: &Crossword, right: &Crossword) -> PuzzleDiff {
+    let mut differences = Vec::new();
+    let (left_squares, right_squares) = (&left.squares, &right.squares);
+
+    if left_squares.size() != right_squares.size() {
+        differences.push(PuzzleDifference::Size {
+            left: (left_squares.rows(), left_squares.cols()),
+            right: (right_squares.rows(), right_squares.cols()),
+        });
+    } else {
+        let squares = left_squares.diff_by(right_squares, |left, right| {
+            describe_square(left) == describe_square(right)
+        });
+        differences.extend(squares.into_iter().map(|(pos, left, right)| {
+            PuzzleDifference::Square {
+                pos,
+                left: describe_square(left),
+                right: describe_square(right),
+            }
+        }));
+    }
+
+    let ids: BTreeSet<ClueId> = left
+        .clues
+        .keys()
+        .chain(right.clues.keys())
+        .copied()
+        .collect();
+    for id in ids {
+        let (left, right) = (left.clues.get(id), right.clues.get(id));
+
+        if left != right {
+            differences.push(PuzzleDifference::Clue {
+                id,
+                left: left.map(describe_clue),
+                right: right.map(describe_clue),
+            });
+        }
+    }
+
+    let property = |name: &str, left: Option<String>, right: Option<String>| {
+        (left != right).then(|| PuzzleDifference::Property {
+            name: name.to_string(),
+            left,
+            right,
+        })
+    };
+
+    differences.extend(
+        [
+            (
+                "numbering",
+                left.numbering.to_string(),
+                right.numbering.to_string(),
+            ),
+            (
+                "writing",
+                left.writing.to_string(),
+                right.writing.to_string(),
+            ),
+            (
+                "lock",
+                format!("{:?}", left.lock),
+                format!("{:?}", right.lock),
+            ),
+            (
+                "check policy",
+                format!("{:?}", left.check),
+                format!("{:?}", right.check),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(name, left, right)| property(name, Some(left), Some(right))),
+    );
+
+    differences.extend(
+        meta_properties(&left.meta, &right.meta)
+            .into_iter()
+            .filter_map(|(name, left, right)| property(&name, left, right)),
+    );
+
+    let positions: BTreeSet<_> = (left.cell_notes())
+        .chain(right.cell_notes())
+        .map(|(pos, _)| pos)
+        .collect();
+    differences.extend(positions.into_iter().filter_map(|pos| {
+        let note = |puzzle: &Crossword| puzzle.cell_note(*pos).map(str::to_string);
+        property(&format!("note {pos}"), note(left), note(right))
+    }));
+
+    let names: BTreeSet<_> = (left.theme_entries.iter())
+        .chain(right.theme_entries.iter())
+        .map(|entry| entry.name())
+        .collect();
+    differences.extend(names.into_iter().filter_map(|name| {
+        let positions = |puzzle: &Crossword| {
+            let entry = puzzle
+                .theme_entries
+                .iter()
+                .find(|entry| entry.name() == name)?;
+            let positions: Vec<_> = entry.positions().iter().map(ToString::to_string).collect();
+            Some(positions.join(" "))
+        };
+        property(
+            &format!("theme entry {name}"),
+            positions(left),
+            positions(right),
+        )
+    }));
+
+    PuzzleDiff {
+        left: left_squares.map_ref(glyph),
+        right: right_squares.map_ref(glyph),
+        differences,
+    }
+}
+
+/// Solution of the square as shown in the grid, where black squares are shown as `.` and unknown solutions as `_`
+fn glyph(square: &CrosswordSquare) -> String {
+    match square.as_ref() {
+        Some(cell) => cell
+            .solution
+            .as_ref()
+            .map_or("_".to_string(), ToString::to_string),
+        None => ".".to_string(),
+    }
+}
+
+/// Glyph of the square, followed by its style and decoration if it has any
+fn describe_square(square: &CrosswordSquare) -> String {
+    let mut description = glyph(square);
+    let Some(cell) = square.as_ref() else {
+        return description;
+    };
+
+    if !cell.style.is_empty() {
+        description += &format!(" {:?}", cell.style);
+    }
+    if cell.decoration != Default::default() {
+        description += &format!(" {:?}", cell.decoration);
+    }
+
+    description
+}
+
+/// Text and placement of the clue, followed by its writing direction if it is not left to right and its note if it has one
+fn describe_clue(clue: &Clue) -> String {
+    let mut description = format!("\"{}\" at {} ({})", clue.text(), clue.start(), clue.len());
+
+    if clue.writing().is_rtl() {
+        description += &format!(" {}", clue.writing());
+    }
+
+    if let Some(note) = clue.note() {
+        description += &format!(" [{note}]");
+    }
+
+    description
+}
+
+/// Name and value of every metadata property that is set in either puzzle
+fn meta_properties(
+    left: &Metadata,
+    right: &Metadata,
+) -> Vec<(String, Option<String>, Option<String>)> {
+    let fields = |meta: &Metadata| {
+        let owned = |value: Option<&str>| value.map(ToString::to_string);
+
+        [
+            ("title", owned(meta.title())),
+            ("author", owned(meta.author())),
+            ("copyright", owned(meta.copyright())),
+            ("notes", owned(meta.notes())),
+            ("theme_note", owned(meta.theme_note())),
+            ("version", meta.version_str()),
+            #[cfg(feature = "chrono")]
+            ("date", meta.date().map(|date| date.to_string())),
+        ]
+    };
+
+    let mut properties: Vec<_> = fields(left)
+        .into_iter()
+        .zip(fields(right))
+        .map(|((name, left), (_, right))| (name.to_string(), left, right))
+        .collect();
+
+    let keys: BTreeSet<_> = left.extra().keys().chain(right.extra().keys()).collect();
+    properties.extend(keys.into_iter().map(|key| {
+        let value = |meta: &Metadata| meta.get_extra(key).map(ToString::to_string);
+        (key.clone(), value(left), value(right))
+    }));
+
+    properties
+}
+
+/// Rows of the grid with the squares of every column padded to the same width
+fn grid_lines(grid: &Grid<String>) -> Vec<String> {
+    let width = |col| {
+        (0..grid.rows())
+            .filter_map(|row| grid.get(Position::new(row, col)))
+            .map(|square| square.chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    let widths: Vec<_> = (0..grid.cols()).map(width).collect();
+
+    (0..grid.rows())
+        .map(|row| {
+            let squares: Vec<_> = widths
+                .iter()
+                .enumerate()
+                .map(|(col, &width)| format!("{:<width$}", grid[Position::new(row, col)]))
+                .collect();
+
+            squares.join(" ").trim_end().to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::CellStyle;
+
+    use super::*;
+    use crate::{CheckPolicy, ClueDirection::*, LockState, NumberingScheme, crossword};
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [A B .]
+            [C D E]
+            - A: "AB"
+            - A: "CDE"
+            - D: "AC"
+            - D: "BD"
+            title: "Mini"
+        )
+    }
+
+    #[test]
+    fn differences() {
+        let mut other = puzzle().with_numbering(NumberingScheme::Separate);
+        let meta = std::mem::take(other.meta_mut());
+        *other.meta_mut() = meta.with_author("Me".to_string());
+        other.squares[Position::new(1, 2)]
+            .as_mut()
+            .expect("Square is white")
+            .style = CellStyle::CIRCLED;
+        other.clues_mut().retext((1, Down), "Not AC".to_string());
+
+        let labels: Vec<_> = compare(&puzzle(), &other)
+            .differences()
+            .iter()
+            .map(PuzzleDifference::label)
+            .collect();
+
+        assert_eq!(
+            labels,
+            [
+                "square 2,3",
+                "clue 1-D",
+                "clue 2-A",
+                "clue 3-A",
+                "numbering",
+                "author"
+            ]
+        );
+    }
+
+    #[test]
+    fn lock_and_check() {
+        let other = puzzle()
+            .with_lock_state(LockState::Locked { checksum: 1 })
+            .with_check_policy(CheckPolicy::Never);
+
+        let labels: Vec<_> = compare(&puzzle(), &other)
+            .differences()
+            .iter()
+            .map(PuzzleDifference::label)
+            .collect();
+
+        assert_eq!(labels, ["lock", "check policy"]);
+    }
+
+    #[test]
+    fn lock_eq() {
+        let other = puzzle().with_lock_state(LockState::Locked { checksum: 1 });
+
+        assert_ne!(puzzle(), other);
+    }
+
+    #[test]
+    fn check_eq() {
+        let other = puzzle().with_check_policy(CheckPolicy::Never);
+
+        assert_ne!(puzzle(), other);
+    }
+
+    #[test]
+    fn assert_as_expression() {
+        let others = [puzzle(), puzzle()];
+        others
+            .iter()
+            .for_each(|other| crate::assert_puzzles_eq!(puzzle(), *other));
+    }
+
+    #[test]
+    fn display() {
+        let other = crossword!(
+            [A B .]
+            [C X E]
+            - A: "AB"
+            - A: "CXE"
+            - D: "AC"
+            - D: "BX"
+        );
+
+        let expected = "\
+Puzzles differ in 4 place(s)
+
+  A B . | A B .
+  C D E | C X E
+
+               left             | right
+  square 2,2   D                | X
+  clue 2-D     \"BD\" at 1,2 (2)  | \"BX\" at 1,2 (2)
+  clue 3-A     \"CDE\" at 2,1 (3) | \"CXE\" at 2,1 (3)
+  title        Mini             | -
+";
+        assert_eq!(compare(&puzzle(), &other).to_string(), expected);
+    }
+
+    #[test]
+    fn sizes() {
+        let other = crossword!([A B]);
+        let diff = compare(&puzzle(), &other);
+
+        assert_eq!(
+            diff.differences()[0],
+            PuzzleDifference::Size {
+                left: (2, 3),
+                right: (1, 2)
+            }
+        );
+        assert!(diff.to_string().contains("  C D E |\n"));
+    }
+}
@@ -0,0 +1,24 @@
+use puzzled_core::Position;
+
+use crate::{ClueId, Solution};
+
+/// Change to the [state](crate::CrosswordState) of a crossword that a user interface may want to react to
+///
+/// Events are collected while the state is mutated and can be [drained](crate::CrosswordState::drain_events) afterwards, e.g. once per frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleEvent {
+    /// An entry was entered into the square at the position
+    CellEntered { pos: Position, entry: Solution },
+
+    /// The entry of the square at the position was cleared
+    CellCleared { pos: Position },
+
+    /// The solution of the square at the position was revealed
+    CellRevealed { pos: Position },
+
+    /// Every square of the clue holds its solution
+    ClueCompleted(ClueId),
+
+    /// Every square of the puzzle holds its solution
+    PuzzleSolved,
+}
@@ -0,0 +1,52 @@
+/// Whether the real solution of a [crossword](crate::Crossword) is known
+///
+/// Puzzles can be distributed with a scrambled solution, such as [*.puz](crate::io) files that are scrambled with a key.
+/// Without the key, the puzzle can still be played, but its squares cannot be checked or revealed.
+/// Only once every square is entered, the entries are [verified](Self::verify) against the checksum of the real solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LockState {
+    /// The solution of the puzzle is its real solution
+    #[default]
+    Unlocked,
+
+    /// The solution of the puzzle is scrambled, such that only the checksum of the real solution is known
+    Locked { checksum: u16 },
+}
+
+impl LockState {
+    pub fn is_locked(&self) -> bool {
+        matches!(self, Self::Locked { .. })
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        !self.is_locked()
+    }
+
+    /// Whether the letters form the real solution, when read column by column while skipping blocks
+    ///
+    /// Unlocked puzzles have no checksum to verify against, so their letters are never verified
+    /// ```
+    /// use puzzled::crossword::LockState;
+    ///
+    /// let lock = LockState::Locked { checksum: 0xa88b };
+    ///
+    /// assert!(lock.verify(b"CATRED"));
+    /// assert!(!lock.verify(b"CATROD"));
+    /// assert!(!LockState::Unlocked.verify(b"CATRED"));
+    /// ```
+    pub fn verify(&self, letters: &[u8]) -> bool {
+        match self {
+            Self::Unlocked => false,
+            Self::Locked { checksum } => letters_checksum(letters) == *checksum,
+        }
+    }
+}
+
+/// Checksum of the letters in the same way as *.puz files checksum their regions
+fn letters_checksum(letters: &[u8]) -> u16 {
+    letters.iter().fold(0u16, |checksum, &letter| {
+        checksum.rotate_right(1).wrapping_add(letter as u16)
+    })
+}
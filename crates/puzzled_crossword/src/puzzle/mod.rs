@@ -1,16 +1,42 @@
 /// Defines all functionality for solving and interacting with [puzzles](Crossword)
 ///
 ///
+mod builder;
+mod check;
 mod clue;
+mod compare;
+mod event;
+mod lock;
+mod notes;
+mod numbering;
+mod render;
+mod snapshot;
 mod square;
 mod state;
+mod symmetry;
+mod theme;
+mod writing;
 
+pub use builder::*;
+pub use check::*;
 pub use clue::*;
+pub use compare::*;
+pub use event::*;
+pub use lock::*;
+pub use notes::*;
+pub use numbering::*;
+pub use render::*;
+pub use snapshot::*;
 pub use square::*;
 pub use state::*;
+pub use symmetry::*;
+pub use theme::*;
+pub use writing::*;
 
-use puzzled_core::{Cell, Grid, Metadata, Position, Puzzle, Square};
-use std::fmt;
+use puzzled_core::{Cell, Grid, Metadata, Position, Puzzle, Square, StableHasher, Version};
+#[cfg(feature = "puz")]
+use puzzled_io::puz::{ChecksumCache, PuzSidecar};
+use std::{collections::BTreeMap, fmt};
 
 /// A [crossword](https://en.wikipedia.org/wiki/Crossword) puzzle
 ///
@@ -46,14 +72,40 @@ use std::fmt;
 ///
 /// [PUZ spec]: https://gist.github.com/sliminality/dab21fa834eae0a70193c7cd69c356d5
 /// [PUZ google spec]: https://code.google.com/archive/p/puz/wikis/FileFormat.wiki
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Crossword {
     // State
     squares: Grid<Square<Cell<Solution>>>,
     clues: Clues,
 
+    // How the clues are numbered
+    numbering: NumberingScheme,
+
+    // Direction in which the across clues are read
+    writing: WritingDirection,
+
     // Metadata
     meta: Metadata,
+
+    // Whether the solution is scrambled
+    lock: LockState,
+
+    // Whether entries are checked as they are entered
+    check: CheckPolicy,
+
+    // Notes that the player made on the cells
+    cell_notes: BTreeMap<Position, String>,
+
+    // Groups of squares that form the answers of the theme
+    theme_entries: Vec<ThemeEntry>,
+
+    // Data of the *.puz file that the crossword was losslessly read from
+    #[cfg(feature = "puz")]
+    pub(crate) puz_sidecar: Option<PuzSidecar>,
+
+    // Checksums of the *.puz strings, which are invalidated when the clues change
+    #[cfg(feature = "puz")]
+    pub(crate) puz_checksums: ChecksumCache,
 }
 
 impl Puzzle for Crossword {
@@ -71,7 +123,17 @@ impl Crossword {
         Self {
             squares,
             clues,
+            numbering: NumberingScheme::default(),
+            writing: WritingDirection::default(),
             meta,
+            lock: LockState::Unlocked,
+            check: CheckPolicy::default(),
+            cell_notes: BTreeMap::new(),
+            theme_entries: Vec::new(),
+            #[cfg(feature = "puz")]
+            puz_sidecar: None,
+            #[cfg(feature = "puz")]
+            puz_checksums: ChecksumCache::default(),
         }
     }
 
@@ -87,7 +149,9 @@ impl Crossword {
         &self.squares
     }
 
+    /// Mutable access to the squares, which clears the [notes](Self::cell_note) on cells that were turned into blocks
     pub fn squares_mut(&mut self) -> &mut Grid<Square<Cell<Solution>>> {
+        self.clear_block_notes();
         &mut self.squares
     }
 
@@ -96,6 +160,7 @@ impl Crossword {
     }
 
     pub fn clues_mut(&mut self) -> &mut Clues {
+        self.mark_dirty();
         &mut self.clues
     }
 
@@ -103,6 +168,143 @@ impl Crossword {
         &self.meta
     }
 
+    /// Mutable access to the metadata, which invalidates the cached `*.puz` checksums as the metadata strings are checksummed
+    /// ```
+    /// use puzzled::crossword::crossword;
+    ///
+    /// let mut puzzle = crossword!([A B]);
+    /// let meta = std::mem::take(puzzle.meta_mut());
+    /// *puzzle.meta_mut() = meta.with_title("Mini".to_string());
+    ///
+    /// assert_eq!(puzzle.meta().title(), Some("Mini"));
+    /// ```
+    pub fn meta_mut(&mut self) -> &mut Metadata {
+        self.mark_dirty();
+        &mut self.meta
+    }
+
+    /// Whether the [solution](Cell::solution) of the squares is the real solution or a scrambled one
+    /// ```
+    /// use puzzled::crossword::{crossword, LockState};
+    ///
+    /// let puzzle = crossword!([A B]);
+    /// assert_eq!(puzzle.lock_state(), LockState::Unlocked);
+    ///
+    /// let puzzle = puzzle.with_lock_state(LockState::Locked { checksum: 0x1234 });
+    /// assert!(puzzle.lock_state().is_locked());
+    /// ```
+    pub fn lock_state(&self) -> LockState {
+        self.lock
+    }
+
+    pub fn with_lock_state(mut self, lock: LockState) -> Self {
+        self.lock = lock;
+        self
+    }
+
+    /// When the entries of the puzzle are [checked](CheckPolicy), which is inherited by every [state](CrosswordState) created from it
+    /// ```
+    /// use puzzled::crossword::{crossword, CheckPolicy, CrosswordState, Position, Solution, Solve};
+    ///
+    /// let puzzle = crossword!([A B]).with_check_policy(CheckPolicy::Immediate);
+    /// let mut state = CrosswordState::from(&puzzle);
+    ///
+    /// state.enter(&Position::new(0, 0), Solution::Letter('C'));
+    /// assert!(state.entries.get_fill(Position::new(0, 0)).unwrap().is_incorrect());
+    /// ```
+    pub fn check_policy(&self) -> CheckPolicy {
+        self.check
+    }
+
+    pub fn with_check_policy(mut self, check: CheckPolicy) -> Self {
+        self.check = check;
+        self
+    }
+
+    /// Stable hash of the solutions and clues, which identifies the puzzle regardless of its metadata and state
+    ///
+    /// Solutions are compared without casing and clues without differences in their whitespace.
+    /// ```
+    /// use puzzled::crossword::crossword;
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     - A: "First  letters"
+    /// );
+    /// let other = crossword!(
+    ///     [a b]
+    ///     - A: "First letters "
+    ///     title: "Copy"
+    /// );
+    ///
+    /// assert_eq!(puzzle.fingerprint(), other.fingerprint());
+    /// assert_ne!(puzzle.fingerprint(), crossword!([A B] - A: "Last letters").fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        hasher.write_u64(self.rows() as u64);
+        hasher.write_u64(self.cols() as u64);
+
+        for square in self.squares.iter() {
+            match square.as_ref() {
+                Some(cell) => {
+                    let solution = cell.solution.as_ref().map(ToString::to_string);
+                    hasher.write_str(&solution.unwrap_or_default().to_uppercase());
+                }
+                None => hasher.write_block(),
+            }
+        }
+
+        for (id, clue) in self.clues.iter() {
+            hasher.write_u8(id.num);
+            hasher.write_u8(match id.direction {
+                ClueDirection::Across => b'A',
+                ClueDirection::Down => b'D',
+            });
+
+            let text: Vec<_> = clue.text().split_whitespace().collect();
+            hasher.write_str(&text.join(" "));
+        }
+
+        hasher.finish()
+    }
+
+    /// Note that explains the theme of the puzzle, separate from its general [notes](Metadata::notes)
+    /// ```
+    /// use puzzled::crossword::crossword;
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     theme_note: "Every answer is a palindrome"
+    /// );
+    ///
+    /// assert_eq!(puzzle.theme_note(), Some("Every answer is a palindrome"));
+    /// ```
+    pub fn theme_note(&self) -> Option<&str> {
+        self.meta.theme_note()
+    }
+
+    /// [Version](Metadata::version) of the puzzle
+    /// ```
+    /// use puzzled::crossword::{crossword, Version};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     version: "1.3"
+    /// );
+    ///
+    /// assert_eq!(puzzle.version(), Some(Version::new(1, 3)));
+    /// assert_eq!(puzzle.version_str().as_deref(), Some("1.3"));
+    /// ```
+    pub fn version(&self) -> Option<Version> {
+        self.meta.version()
+    }
+
+    /// Version of the puzzle written as `<major>.<minor>`
+    pub fn version_str(&self) -> Option<String> {
+        self.meta.version_str()
+    }
+
     /// Number of rows (height) in the puzzle.
     ///
     /// Note that this includes blank squares
@@ -136,12 +338,45 @@ impl Crossword {
     pub fn cols(&self) -> usize {
         self.squares.cols()
     }
+
+    /// Mark the clues or metadata as changed, such that cached data derived from them is computed again
+    pub(crate) fn mark_dirty(&mut self) {
+        #[cfg(feature = "puz")]
+        self.puz_checksums.invalidate();
+    }
+}
+
+impl PartialEq for Crossword {
+    /// Crosswords are compared without the data of the file they were read from
+    fn eq(&self, other: &Self) -> bool {
+        self.squares == other.squares
+            && self.clues == other.clues
+            && self.numbering == other.numbering
+            && self.writing == other.writing
+            && self.meta == other.meta
+            && self.lock == other.lock
+            && self.check == other.check
+            && self.cell_notes().eq(other.cell_notes())
+            && self.theme_entries == other.theme_entries
+    }
 }
 
+impl Eq for Crossword {}
+
 impl fmt::Display for Crossword {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "{}", self.squares)?;
-        writeln!(f, "{}", self.clues)?;
+
+        if self.numbering.is_standard() {
+            writeln!(f, "{}", self.clues)?;
+        } else {
+            for clue in self.clues.values() {
+                let label = self.numbering.label(clue);
+                writeln!(f, "{label}-{}: {}", clue.direction(), clue.text())?;
+            }
+            writeln!(f)?;
+        }
+
         writeln!(f, "{}", self.meta)?;
 
         Ok(())
@@ -150,10 +385,13 @@ impl fmt::Display for Crossword {
 
 #[cfg(feature = "serde")]
 mod serde_impl {
-    use puzzled_core::Metadata;
+    use puzzled_core::{Metadata, Position};
     use serde::{Deserialize, Serialize, de::Error};
 
-    use crate::{Clues, Crossword, SerdeClues, Squares};
+    use crate::{
+        CheckPolicy, Clues, Crossword, LockState, NumberingScheme, SerdeClues, Squares, ThemeEntry,
+        WritingDirection,
+    };
 
     #[derive(Serialize, Deserialize)]
     struct SerdeCrossword {
@@ -163,9 +401,27 @@ mod serde_impl {
         squares: Squares,
         clues: Option<SerdeClues>,
 
+        #[serde(default, skip_serializing_if = "NumberingScheme::is_standard")]
+        numbering: NumberingScheme,
+
+        #[serde(default, skip_serializing_if = "WritingDirection::is_ltr")]
+        writing: WritingDirection,
+
         // Metadata
         #[serde(flatten)]
         meta: Metadata,
+
+        #[serde(default, skip_serializing_if = "LockState::is_unlocked")]
+        lock: LockState,
+
+        #[serde(default, skip_serializing_if = "CheckPolicy::is_on_demand")]
+        check: CheckPolicy,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        cell_notes: Vec<(Position, String)>,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        theme_entries: Vec<ThemeEntry>,
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
@@ -188,7 +444,15 @@ mod serde_impl {
                 cols: self.squares().cols(),
                 squares,
                 clues,
+                numbering: self.numbering,
+                writing: self.writing,
                 meta,
+                lock: self.lock,
+                check: self.check,
+                cell_notes: (self.cell_notes())
+                    .map(|(pos, note)| (*pos, note.clone()))
+                    .collect(),
+                theme_entries: self.theme_entries.clone(),
             }
             .serialize(serializer)
         }
@@ -203,17 +467,33 @@ mod serde_impl {
             let SerdeCrossword {
                 squares,
                 clues: clues_data,
+                numbering,
+                writing,
                 meta,
+                lock,
+                check,
+                cell_notes,
+                theme_entries,
                 ..
             } = SerdeCrossword::deserialize(deserializer)?;
 
-            let clues = Clues::from_serde(clues_data.unwrap_or_default()).map_err(Error::custom)?;
+            let clues = Clues::from_serde(clues_data.unwrap_or_default(), writing)
+                .map_err(Error::custom)?;
+
+            // Clues are stored with their numbers, such that they should not be renumbered
+            let mut puzzle = Crossword::new(squares, clues, meta)
+                .with_lock_state(lock)
+                .with_check_policy(check);
+            puzzle.numbering = numbering;
+            puzzle.writing = writing;
+            for (pos, note) in cell_notes {
+                puzzle.set_cell_note(pos, note);
+            }
+            for entry in theme_entries {
+                puzzle.add_theme_entry(entry).map_err(Error::custom)?;
+            }
 
-            Ok(Crossword {
-                squares,
-                clues,
-                meta,
-            })
+            Ok(puzzle)
         }
     }
 }
@@ -240,4 +520,25 @@ mod test {
 
         assert!(json.len() == 150);
     }
+
+    #[test]
+    fn serde_theme_entries() {
+        use crate::{Crossword, Position, ThemeEntry};
+
+        let mut crossword = crossword!(
+            [C A T]
+            [O W L]
+        );
+        let entry = ThemeEntry::new("Pet", [Position::new(1, 0), Position::new(0, 2)]);
+        crossword.add_theme_entry(entry).unwrap();
+
+        let json = serde_json::to_string(&crossword).unwrap();
+        let restored: Crossword = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, crossword);
+        assert_eq!(
+            restored.theme_entries()[0].answer(&restored),
+            Some("OT".to_string())
+        );
+    }
 }
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use puzzled_core::Position;
+
+use crate::{ClueId, Crossword};
+
+/// Annotations that a player made on the cells and [clues](crate::Clue) of a [crossword](Crossword), such as pencil notes or flags
+///
+/// The notes are kept on the crossword itself, which can [collect](Crossword::notes) them to store them next to the progress of the player.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Notes {
+    pub cells: BTreeMap<Position, String>,
+    pub clues: BTreeMap<ClueId, String>,
+}
+
+impl Notes {
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty() && self.clues.is_empty()
+    }
+}
+
+/// # Notes
+impl Crossword {
+    /// Note that the player made on the cell at the given position
+    ///
+    /// Squares that were turned into blocks no longer have a note
+    pub fn cell_note(&self, pos: Position) -> Option<&str> {
+        self.cell_notes
+            .get(&pos)
+            .filter(|_| self.squares.is_fill(pos))
+            .map(String::as_str)
+    }
+
+    /// Notes on the cells that are still playable, as squares can be turned into blocks through [`squares_mut`](Self::squares_mut)
+    pub(crate) fn cell_notes(&self) -> impl Iterator<Item = (&Position, &String)> {
+        self.cell_notes
+            .iter()
+            .filter(|(pos, _)| self.squares.is_fill(**pos))
+    }
+
+    /// Remove the notes on cells that were turned into blocks
+    pub(crate) fn clear_block_notes(&mut self) {
+        let squares = &self.squares;
+        self.cell_notes.retain(|pos, _| squares.is_fill(*pos));
+    }
+
+    /// Set the note of the cell at the given position, or remove it if the note is empty
+    ///
+    /// Returns the previous note of the cell. Notes can only be made on playable squares, so others are left without a note
+    /// ```
+    /// use puzzled::crossword::{crossword, Position};
+    ///
+    /// let mut puzzle = crossword!([A .]);
+    ///
+    /// assert_eq!(puzzle.set_cell_note(Position::new(0, 0), "Vowel?"), None);
+    /// assert_eq!(puzzle.cell_note(Position::new(0, 0)), Some("Vowel?"));
+    ///
+    /// puzzle.set_cell_note(Position::new(0, 1), "Block");
+    /// assert_eq!(puzzle.cell_note(Position::new(0, 1)), None);
+    ///
+    /// assert_eq!(puzzle.set_cell_note(Position::new(0, 0), ""), Some("Vowel?".to_string()));
+    /// assert_eq!(puzzle.cell_note(Position::new(0, 0)), None);
+    /// ```
+    pub fn set_cell_note<S: Into<String>>(&mut self, pos: Position, note: S) -> Option<String> {
+        let note = note.into();
+
+        if note.is_empty() || self.squares.get_fill(pos).is_none() {
+            return self.cell_notes.remove(&pos);
+        }
+
+        self.cell_notes.insert(pos, note)
+    }
+
+    /// Set the [note](crate::Clue::note) of the identified clue, or remove it if the note is empty
+    ///
+    /// Returns whether the clue exists in the puzzle
+    pub fn set_clue_note<I, S>(&mut self, id: I, note: S) -> bool
+    where
+        I: Into<ClueId>,
+        S: Into<String>,
+    {
+        self.clues.annotate(id, note).is_some()
+    }
+
+    /// Collect the notes on all cells and clues
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*, Position};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [A B]
+    ///     - A: "First letters"
+    /// );
+    /// puzzle.set_cell_note(Position::new(0, 1), "B or D");
+    /// puzzle.set_clue_note((1, Across), "Check later");
+    ///
+    /// let notes = puzzle.notes();
+    /// let mut other = crossword!(
+    ///     [A B]
+    ///     - A: "First letters"
+    /// );
+    /// other.apply_notes(notes.clone());
+    ///
+    /// assert_eq!(other.notes(), notes);
+    /// assert_eq!(other.cell_note(Position::new(0, 1)), Some("B or D"));
+    /// ```
+    pub fn notes(&self) -> Notes {
+        let clues = self
+            .clues
+            .iter()
+            .filter_map(|(id, clue)| Some((*id, clue.note()?.to_string())))
+            .collect();
+
+        Notes {
+            cells: self
+                .cell_notes()
+                .map(|(pos, note)| (*pos, note.clone()))
+                .collect(),
+            clues,
+        }
+    }
+
+    /// Replace the notes on all cells and clues, skipping the notes of cells and clues that are not in the puzzle
+    pub fn apply_notes(&mut self, notes: Notes) {
+        self.cell_notes.clear();
+        for (pos, note) in notes.cells {
+            self.set_cell_note(pos, note);
+        }
+
+        let ids: Vec<_> = self.clues.keys().copied().collect();
+        for id in ids {
+            self.clues.annotate(id, "");
+        }
+        for (id, note) in notes.clues {
+            self.clues.annotate(id, note);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use std::collections::BTreeMap;
+
+    use puzzled_core::Position;
+    use serde::{Deserialize, Serialize, de::Error};
+
+    use crate::{ClueId, Notes};
+
+    #[derive(Serialize, Deserialize)]
+    struct SerdeNotes {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        cells: Vec<(Position, String)>,
+
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        clues: BTreeMap<String, String>,
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    impl Serialize for Notes {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            SerdeNotes {
+                cells: self.cells.clone().into_iter().collect(),
+                clues: self
+                    .clues
+                    .iter()
+                    .map(|(id, note)| (id.to_string(), note.clone()))
+                    .collect(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Notes {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let SerdeNotes { cells, clues } = SerdeNotes::deserialize(deserializer)?;
+
+            let clues = clues
+                .into_iter()
+                .map(|(id, note)| Ok((id.parse::<ClueId>().map_err(Error::custom)?, note)))
+                .collect::<Result<_, D::Error>>()?;
+
+            Ok(Notes {
+                cells: cells.into_iter().collect(),
+                clues,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Square;
+
+    use crate::crossword;
+
+    use super::*;
+
+    #[test]
+    fn block_notes() {
+        let mut puzzle = crossword!([A B] [C D]);
+        let pos = Position::new(1, 1);
+        let square = puzzle.squares()[pos].clone();
+        puzzle.set_cell_note(pos, "Not E");
+        puzzle.set_cell_note(Position::new(0, 0), "Vowel");
+
+        puzzle.squares_mut()[pos] = Square(None);
+        assert_eq!(puzzle.cell_note(pos), None);
+        assert_eq!(puzzle.notes().cells.len(), 1);
+
+        // Turning the block back into a cell does not bring back its note
+        puzzle.squares_mut()[pos] = square;
+        assert_eq!(puzzle.cell_note(pos), None);
+        assert_eq!(puzzle.cell_note(Position::new(0, 0)), Some("Vowel"));
+    }
+}
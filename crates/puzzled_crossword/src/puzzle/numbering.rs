@@ -0,0 +1,464 @@
+use std::{fmt, str::FromStr};
+
+use puzzled_core::Position;
+
+use crate::{Clue, ClueDirection, Clues, Crossword};
+
+/// Way in which the [clues](Clue) of a [crossword](crate::Crossword) are numbered
+///
+/// Regardless of the scheme, clues are identified by the [number](Clue::num) they were given together with their direction.
+/// The scheme determines how these numbers are assigned and how clues are [labeled](Self::label) for the solver.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum NumberingScheme {
+    /// Every square that starts a clue gets the next number, which is shared by its across and down clue
+    #[default]
+    Standard,
+
+    /// Across and down clues are numbered separately, both starting from 1
+    Separate,
+
+    /// Clues have no numbers in the grid and are labeled by the coordinate of their first square, e.g. `B3` for the second column of the third row
+    ///
+    /// Internally, clues are still numbered as with [standard](Self::Standard) numbering.
+    Coordinates,
+}
+
+impl NumberingScheme {
+    pub const ALL: [NumberingScheme; 3] = [Self::Standard, Self::Separate, Self::Coordinates];
+
+    pub fn is_standard(&self) -> bool {
+        matches!(self, Self::Standard)
+    }
+
+    /// Label of the clue as shown to the solver
+    /// ```
+    /// use puzzled::crossword::{clue, NumberingScheme};
+    ///
+    /// let clue = clue!(4 A: "Pet" @ (2, 1) + 3);
+    ///
+    /// assert_eq!(NumberingScheme::Standard.label(&clue), "4");
+    /// assert_eq!(NumberingScheme::Coordinates.label(&clue), "B3");
+    /// ```
+    pub fn label(&self, clue: &Clue) -> String {
+        match self {
+            Self::Standard | Self::Separate => clue.num().to_string(),
+            Self::Coordinates => coordinate(clue.start()),
+        }
+    }
+
+    /// Label of the square at the position as shown in the grid, if it starts any clue
+    ///
+    /// With [separate](Self::Separate) numbering, a square that starts clues with different numbers is labeled as `across/down`.
+    pub fn square_label(&self, clues: &Clues, pos: Position) -> Option<String> {
+        let across = clues
+            .get_clue(pos, ClueDirection::Across)
+            .filter(|clue| clue.start() == pos);
+        let down = clues
+            .get_clue(pos, ClueDirection::Down)
+            .filter(|clue| clue.start() == pos);
+
+        match (self, across, down) {
+            (Self::Coordinates, ..) | (_, None, None) => None,
+            (Self::Separate, Some(across), Some(down)) if across.num() != down.num() => {
+                Some(format!("{}/{}", across.num(), down.num()))
+            }
+            (_, Some(clue), _) | (_, None, Some(clue)) => Some(clue.num().to_string()),
+        }
+    }
+}
+
+impl fmt::Display for NumberingScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Standard => "standard",
+            Self::Separate => "separate",
+            Self::Coordinates => "coordinates",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for NumberingScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|scheme| scheme.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or(format!(
+                "Numbering scheme '{s}' should be standard, separate or coordinates"
+            ))
+    }
+}
+
+/// # Numbering
+impl Crossword {
+    pub fn numbering(&self) -> NumberingScheme {
+        self.numbering
+    }
+
+    pub fn with_numbering(mut self, numbering: NumberingScheme) -> Self {
+        self.set_numbering(numbering);
+        self
+    }
+
+    /// Change the numbering scheme, which renumbers every clue according to its start in [reading order](crate::WritingDirection) and its direction
+    ///
+    /// The text and notes of the clues are kept, only their [identifiers](crate::ClueId) change.
+    /// Clues that would be numbered past 255, the largest clue number, are removed.
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*, NumberingScheme};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [C A B]
+    ///     [O R E]
+    ///     [T E N]
+    ///     - A: "Taxi"
+    ///     - A: "Mineral"
+    ///     - A: "Number of toes"
+    ///     - D: "Baby bed"
+    ///     - D: "Exist"
+    ///     - D: "Wading bird"
+    /// );
+    /// assert_eq!(puzzle.clues().get((4, Across)).map(|clue| clue.text().as_str()), Some("Mineral"));
+    ///
+    /// puzzle.set_numbering(NumberingScheme::Separate);
+    /// assert_eq!(puzzle.clues().get((2, Across)).map(|clue| clue.text().as_str()), Some("Mineral"));
+    /// assert_eq!(puzzle.clues().get((3, Down)).map(|clue| clue.text().as_str()), Some("Wading bird"));
+    /// ```
+    pub fn set_numbering(&mut self, numbering: NumberingScheme) {
+        self.numbering = numbering;
+        self.mark_dirty();
+
+        let writing = self.writing;
+        let mut clues: Vec<_> = self.clues.values().cloned().collect();
+        clues.sort_by(|a, b| {
+            writing
+                .cmp_positions(a.start(), b.start())
+                .then(a.direction().cmp(&b.direction()))
+        });
+        self.clues = Clues::default();
+
+        let mut numbers = ClueNumbers::new(numbering);
+        let mut prev = None;
+
+        for clue in clues {
+            if prev.is_some_and(|start| start != clue.start()) {
+                numbers.next_square();
+            }
+            prev = Some(clue.start());
+
+            let Some(num) = numbers.take(clue.direction()) else {
+                continue;
+            };
+            let clue = clue.renumbered(num);
+            self.clues.insert(clue.id(), clue);
+        }
+    }
+}
+
+/// Counter that hands out the numbers of clues while going through the grid in row-major order
+#[derive(Debug, Clone, Copy)]
+///
+/// Counting goes on past the largest clue number, such that clues past it do not get a number.
+pub(crate) struct ClueNumbers {
+    scheme: NumberingScheme,
+    across: u16,
+    down: u16,
+    started: bool,
+}
+
+impl ClueNumbers {
+    /// Start numbering from 1
+    pub(crate) fn new(scheme: NumberingScheme) -> Self {
+        Self {
+            scheme,
+            across: 1,
+            down: 1,
+            started: false,
+        }
+    }
+
+    /// Continue numbering after the existing clues
+    pub(crate) fn after(scheme: NumberingScheme, clues: &Clues) -> Self {
+        let mut numbers = Self::new(scheme);
+        let next = |clue: Option<&Clue>| clue.map_or(1, |clue| u16::from(clue.num()) + 1);
+
+        match scheme {
+            NumberingScheme::Standard | NumberingScheme::Coordinates => {
+                let num = next(clues.values().last());
+                (numbers.across, numbers.down) = (num, num);
+            }
+            NumberingScheme::Separate => {
+                numbers.across = next(clues.iter_across().last());
+                numbers.down = next(clues.iter_down().last());
+            }
+        }
+
+        numbers
+    }
+
+    /// Number that the next clue in the direction gets, or [`None`] if it is past the largest clue number
+    pub(crate) fn peek(&self, direction: ClueDirection) -> Option<u8> {
+        let num = match direction {
+            ClueDirection::Across => self.across,
+            ClueDirection::Down => self.down,
+        };
+
+        u8::try_from(num).ok()
+    }
+
+    /// Hand out the number of the next clue in the direction, or [`None`] if it is past the largest clue number
+    pub(crate) fn take(&mut self, direction: ClueDirection) -> Option<u8> {
+        let num = self.peek(direction);
+
+        match self.scheme {
+            NumberingScheme::Standard | NumberingScheme::Coordinates => self.started = true,
+            NumberingScheme::Separate => match direction {
+                ClueDirection::Across => self.across += 1,
+                ClueDirection::Down => self.down += 1,
+            },
+        }
+
+        num
+    }
+
+    /// Move on to the next square, which gets a new number if the current square started a clue
+    pub(crate) fn next_square(&mut self) {
+        if std::mem::take(&mut self.started) {
+            self.across += 1;
+            self.down += 1;
+        }
+    }
+}
+
+/// Coordinate of the position as a column letter and a row number starting from 1, e.g. `AA12`
+fn coordinate(pos: Position) -> String {
+    let mut col = pos.col + 1;
+    let mut letters = Vec::new();
+
+    while col > 0 {
+        col -= 1;
+        letters.push(char::from(b'A' + (col % 26) as u8));
+        col /= 26;
+    }
+
+    letters.iter().rev().collect::<String>() + &(pos.row + 1).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_io::TxtPuzzle;
+    use rstest::rstest;
+
+    use super::*;
+    use puzzled_core::Metadata;
+
+    use crate::{
+        ClueDirection::*, ClueSpec, CrosswordBuilder, CrosswordSquares, WritingDirection, clue,
+        crossword, patterns::Pattern,
+    };
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [C A B]
+            [O R E]
+            [T E N]
+        )
+    }
+
+    fn ids(crossword: &Crossword) -> Vec<(u8, ClueDirection, Position)> {
+        crossword
+            .clues()
+            .values()
+            .map(|clue| (clue.num(), clue.direction(), clue.start()))
+            .collect()
+    }
+
+    #[test]
+    fn separate() {
+        let puzzle = puzzle();
+        let mut puzzle = Crossword::from_squares(puzzle.squares().clone(), puzzle.meta().clone())
+            .with_numbering(NumberingScheme::Separate);
+
+        // Clues placed later continue the numbering of their own direction
+        puzzle.insert_clues([ClueSpec::across("Taxi"), ClueSpec::down("Baby bed")]);
+        puzzle.insert_clues([
+            ClueSpec::across("Mineral"),
+            ClueSpec::across("Number of toes"),
+            ClueSpec::down("Exist"),
+            ClueSpec::down("Wading bird"),
+        ]);
+
+        assert_eq!(
+            ids(&puzzle),
+            [
+                (1, Across, Position::new(0, 0)),
+                (1, Down, Position::new(0, 0)),
+                (2, Across, Position::new(1, 0)),
+                (2, Down, Position::new(0, 1)),
+                (3, Across, Position::new(2, 0)),
+                (3, Down, Position::new(0, 2)),
+            ]
+        );
+        assert_eq!(puzzle.clues().get_num(Position::new(1, 0)), Some(2));
+    }
+
+    #[test]
+    fn renumber() {
+        let mut puzzle = puzzle();
+        puzzle.insert_clues([
+            ClueSpec::across("Taxi"),
+            ClueSpec::across("Mineral"),
+            ClueSpec::down("Baby bed"),
+            ClueSpec::down("Exist"),
+        ]);
+        let standard = ids(&puzzle);
+
+        puzzle.set_numbering(NumberingScheme::Separate);
+        assert_eq!(
+            puzzle.clues().get((2, Down)).map(Clue::start),
+            Some(Position::new(0, 1))
+        );
+
+        puzzle.set_numbering(NumberingScheme::Coordinates);
+        assert_eq!(ids(&puzzle), standard);
+    }
+
+    #[test]
+    fn past_largest_number() {
+        // Every other row holds 16 across words of two squares, whose squares each also start a down word
+        let rows = (0..40).map(|row| match row % 2 {
+            0 => "--.".repeat(16),
+            _ => ".".repeat(48),
+        });
+        let squares = Pattern::from_rows(rows).unwrap().to_squares();
+
+        let numbering = squares.numbering();
+        assert_eq!(numbering.iter().flatten().max(), Some(&255));
+
+        let starts = squares.clue_starts(NumberingScheme::Standard, WritingDirection::LeftToRight);
+        assert_eq!(starts.keys().last().map(|id| id.num), Some(255));
+
+        let mut puzzle = Crossword::from_squares(squares, Metadata::default());
+        let unpositioned = puzzle.insert_clues((0..320).map(|_| ClueSpec::across("Word")));
+        assert_eq!(unpositioned.len(), 320 - 255);
+
+        puzzle.set_numbering(NumberingScheme::Separate);
+        assert_eq!(puzzle.clues().len(), 255);
+    }
+
+    #[rstest]
+    #[case::standard(NumberingScheme::Standard, [Some("3"), Some("1"), None])]
+    #[case::separate(NumberingScheme::Separate, [Some("3/1"), Some("1"), None])]
+    #[case::coordinates(NumberingScheme::Coordinates, [None, None, None])]
+    fn square_labels(#[case] scheme: NumberingScheme, #[case] expected: [Option<&str>; 3]) {
+        let clues = Clues::new(
+            [
+                clue!(1 A: "First" @ (0, 0) + 2),
+                clue!(2 D: "Second" @ (0, 1) + 2),
+                clue!(3 A: "Third" @ (1, 2) + 1),
+                clue!(1 D: "Fourth" @ (1, 2) + 1),
+            ]
+            .into_iter()
+            .map(|clue| (clue.id(), clue))
+            .collect(),
+        );
+
+        let labels = [(1, 2), (0, 0), (1, 0)].map(|pos| scheme.square_label(&clues, pos.into()));
+
+        assert_eq!(labels.each_ref().map(Option::as_deref), expected);
+    }
+
+    #[test]
+    fn display() {
+        let puzzle = crossword!(
+            [C A B]
+            [O R E]
+            [T E N]
+            - A: "Taxi"
+            - A: "Mineral"
+        )
+        .with_numbering(NumberingScheme::Coordinates);
+
+        let text = puzzle.to_string();
+
+        assert!(text.contains("A1-A: Taxi"));
+        assert!(text.contains("A2-A: Mineral"));
+    }
+
+    #[test]
+    fn builder() {
+        let mut builder = CrosswordBuilder::new(2, 3).with_numbering(NumberingScheme::Separate);
+        builder.set_black(Position::new(0, 0));
+
+        let crossword = builder.build();
+
+        assert_eq!(crossword.numbering(), NumberingScheme::Separate);
+        assert_eq!(
+            ids(&crossword),
+            [
+                (1, Across, Position::new(0, 1)),
+                (1, Down, Position::new(0, 1)),
+                (2, Across, Position::new(1, 0)),
+                (2, Down, Position::new(0, 2)),
+                (3, Down, Position::new(1, 0)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn text(
+        #[values(
+            NumberingScheme::Standard,
+            NumberingScheme::Separate,
+            NumberingScheme::Coordinates
+        )]
+        scheme: NumberingScheme,
+    ) {
+        let puzzle = crossword!(
+            [C A B]
+            [O R E]
+            [T E N]
+            - A: "Taxi"
+            - A: "Mineral"
+            - A: "Number of toes"
+            - D: "Baby bed"
+            - D: "Exist"
+            - D: "Wading bird"
+        )
+        .with_numbering(scheme);
+
+        let text = puzzle.write_text();
+        assert_eq!(text.contains("numbering:"), !scheme.is_standard());
+        assert_eq!(Crossword::read_text(&text).ok(), Some(puzzle));
+    }
+
+    #[test]
+    fn invalid_text() {
+        let text = "[A B]\n- A: \"Letters\"\nnumbering: \"european\"\n";
+
+        assert!(Crossword::read_text(text).is_err());
+    }
+
+    #[rstest]
+    #[case::first((0, 0), "A1")]
+    #[case::last_letter((9, 25), "Z10")]
+    #[case::double((0, 26), "AA1")]
+    #[case::double_last((4, 701), "ZZ5")]
+    #[case::triple((4, 702), "AAA5")]
+    fn coordinates(#[case] pos: (usize, usize), #[case] expected: &str) {
+        assert_eq!(coordinate(pos.into()), expected);
+    }
+
+    #[test]
+    fn parse() {
+        for scheme in NumberingScheme::ALL {
+            assert_eq!(scheme.to_string().to_uppercase().parse(), Ok(scheme));
+        }
+        assert!("european".parse::<NumberingScheme>().is_err());
+    }
+}
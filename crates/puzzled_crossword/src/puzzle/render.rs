@@ -0,0 +1,218 @@
+use puzzled_core::Position;
+
+use crate::{Crossword, CrosswordState};
+
+/// Number of characters that every square is drawn with by [`Crossword::render_ascii`]
+const SQUARE_WIDTH: usize = 3;
+
+/// Options that decide what [`Crossword::render_ascii`] draws inside the squares
+///
+/// By default, squares show their clue number and are otherwise empty.
+/// ```
+/// use puzzled::crossword::{crossword, CrosswordState, RenderOptions};
+///
+/// let puzzle = crossword!([A B] [C .]);
+/// let state = CrosswordState::from(&puzzle);
+///
+/// let options = RenderOptions::new()
+///     .with_state(&state)
+///     .with_numbers(false);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions<'a> {
+    state: Option<&'a CrosswordState>,
+    solution: bool,
+    numbers: bool,
+}
+
+impl Default for RenderOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> RenderOptions<'a> {
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            solution: false,
+            numbers: true,
+        }
+    }
+
+    /// Draw the entries of the state in the squares
+    pub fn with_state(mut self, state: &'a CrosswordState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Draw the solution in the squares, which takes precedence over the entries of any [state](Self::with_state)
+    pub fn with_solution(mut self, solution: bool) -> Self {
+        self.solution = solution;
+        self
+    }
+
+    /// Draw the label of every square that starts a clue on a separate line above its entry
+    pub fn with_numbers(mut self, numbers: bool) -> Self {
+        self.numbers = numbers;
+        self
+    }
+}
+
+/// # Rendering
+impl Crossword {
+    /// Draw the grid with box-drawing characters, e.g. for command line tools, snapshot tests and bug reports
+    ///
+    /// Squares are 3 characters wide, such that longer labels and rebuses are cut off, and black squares are filled in.
+    /// ```
+    /// use puzzled::crossword::{crossword, RenderOptions};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "B"
+    ///     - A: "C"
+    /// );
+    ///
+    /// let grid = puzzle.render_ascii(RenderOptions::new().with_solution(true));
+    /// let lines: Vec<_> = grid.lines().collect();
+    ///
+    /// assert_eq!(lines[0], "┌───┬───┐");
+    /// assert_eq!(lines[1], "│1  │2  │");
+    /// assert_eq!(lines[2], "│ A │ B │");
+    /// assert_eq!(lines[5], "│ C │███│");
+    /// ```
+    pub fn render_ascii(&self, options: RenderOptions) -> String {
+        let (rows, cols) = (self.squares.rows(), self.squares.cols());
+        let border = |left: char, mid: char, right: char| {
+            let squares = vec!["─".repeat(SQUARE_WIDTH); cols];
+            format!("{left}{}{right}\n", squares.join(&mid.to_string()))
+        };
+
+        let mut grid = border('┌', '┬', '┐');
+
+        for row in 0..rows {
+            if row > 0 {
+                grid += &border('├', '┼', '┤');
+            }
+
+            if options.numbers {
+                grid += &self.render_line(row, |pos| {
+                    let label = self.numbering.square_label(&self.clues, pos);
+                    format!("{:<SQUARE_WIDTH$}", fit(&label.unwrap_or_default()))
+                });
+            }
+
+            grid += &self.render_line(row, |pos| {
+                let glyph = self.render_glyph(pos, &options);
+                format!("{:^SQUARE_WIDTH$}", fit(&glyph.unwrap_or_default()))
+            });
+        }
+
+        grid + &border('└', '┴', '┘')
+    }
+
+    /// Line of the squares in the row, of which the white squares are drawn by the given function
+    fn render_line(&self, row: usize, square: impl Fn(Position) -> String) -> String {
+        let squares: Vec<_> = (0..self.squares.cols())
+            .map(|col| Position::new(row, col))
+            .map(|pos| match self.squares.get_fill(pos) {
+                Some(_) => square(pos),
+                None => "█".repeat(SQUARE_WIDTH),
+            })
+            .collect();
+
+        format!("│{}│\n", squares.join("│"))
+    }
+
+    fn render_glyph(&self, pos: Position, options: &RenderOptions) -> Option<String> {
+        if options.solution {
+            let cell = self.squares.get_fill(pos)?;
+            return cell.solution.as_ref().map(ToString::to_string);
+        }
+
+        let entry = options.state?.entries.get_fill(pos)?;
+        entry.entry().map(ToString::to_string)
+    }
+}
+
+/// Cut the text off at the width of a square, marking that it was cut off
+fn fit(text: &str) -> String {
+    match text.chars().count() > SQUARE_WIDTH {
+        true => text.chars().take(SQUARE_WIDTH - 1).chain(['…']).collect(),
+        false => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Solve;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{NumberingScheme, Solution, crossword};
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [A B .]
+            [C DEF E]
+            [. F G]
+            - A: "AB"
+            - A: "CDEFE"
+            - A: "FG"
+            - D: "AC"
+            - D: "BDEFF"
+            - D: "EG"
+        )
+    }
+
+    #[rstest]
+    #[case::empty(
+        RenderOptions::new(),
+        "
+┌───┬───┬───┐
+│1  │2  │███│
+│   │   │███│
+├───┼───┼───┤
+│3  │   │4  │
+│   │   │   │
+├───┼───┼───┤
+│███│5  │   │
+│███│   │   │
+└───┴───┴───┘
+"
+    )]
+    #[case::solution(RenderOptions::new().with_solution(true).with_numbers(false), "
+┌───┬───┬───┐
+│ A │ B │███│
+├───┼───┼───┤
+│ C │DEF│ E │
+├───┼───┼───┤
+│███│ F │ G │
+└───┴───┴───┘
+")]
+    fn render_ascii(#[case] options: RenderOptions, #[case] expected: &str) {
+        assert_eq!(puzzle().render_ascii(options), expected.trim_start());
+    }
+
+    #[test]
+    fn render_entries() {
+        let puzzle = puzzle().with_numbering(NumberingScheme::Coordinates);
+        let mut state = CrosswordState::from(&puzzle);
+        for pos in puzzle.squares().positions() {
+            state.clear(&pos);
+        }
+
+        state.enter(&Position::new(1, 1), Solution::Rebus("DEFG".into()));
+        state.enter(&Position::new(2, 2), Solution::Letter('X'));
+
+        let grid = puzzle.render_ascii(RenderOptions::new().with_state(&state));
+        let lines: Vec<_> = grid.lines().collect();
+
+        assert_eq!(lines[4], "│   │   │   │");
+        assert_eq!(lines[5], "│   │DE…│   │");
+        assert_eq!(lines[8], "│███│   │ X │");
+    }
+}
@@ -0,0 +1,240 @@
+use puzzled_core::{Grid, Position, Size, Timer};
+
+use crate::{Crossword, CrosswordEntry, CrosswordState, Notes};
+
+/// Progress of a player on a [crossword](Crossword), without the solution of the puzzle
+///
+/// The snapshot holds the [entries](CrosswordEntry) of the player together with their styles and the timer.
+/// The [notes](Notes) of the player are kept on the crossword, so they are only part of the snapshot when it is taken from [the crossword](Crossword::snapshot).
+/// This makes it possible to store the progress of every player separately, while sharing a single definition of the puzzle.
+/// ```
+/// use puzzled::crossword::{crossword, CrosswordState, Position, Solution, Solve};
+///
+/// let puzzle = crossword!([A B] [C D]);
+/// let mut state = CrosswordState::from(&puzzle);
+/// state.clear(&Position::new(0, 0));
+/// state.reveal(&Position::new(1, 1));
+///
+/// // Store the progress of the player...
+/// let snapshot = state.snapshot();
+///
+/// // ...and continue playing later on
+/// let restored = puzzle.apply_state(snapshot).unwrap();
+/// assert!(restored.diff_state(&state).is_empty());
+/// assert_eq!(restored.solution(&Position::new(0, 0)), Some(&Solution::Letter('A')));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzzleState {
+    pub entries: Grid<CrosswordEntry>,
+    pub timer: Timer,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Notes::is_empty")
+    )]
+    pub notes: Notes,
+}
+
+impl PuzzleState {
+    pub fn with_notes(mut self, notes: Notes) -> Self {
+        self.notes = notes;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PuzzleStateError {
+    #[error("State has size {found:?}, but the crossword has size {expected:?}")]
+    InvalidSize { found: Size, expected: Size },
+
+    #[error("State and crossword disagree on whether the square at {pos:?} is playable")]
+    MismatchedSquare { pos: Position },
+}
+
+impl CrosswordState {
+    /// Take a [snapshot](PuzzleState) of the progress of the player, without the notes that are kept on the crossword
+    ///
+    /// Use [`Crossword::snapshot`] to include the notes as well.
+    pub fn snapshot(&self) -> PuzzleState {
+        PuzzleState {
+            entries: self.entries.clone(),
+            timer: self.timer.clone(),
+            notes: Notes::default(),
+        }
+    }
+}
+
+impl Crossword {
+    /// Take a [snapshot](PuzzleState) of the progress of the player, including their [notes](Self::notes) on the crossword
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordState, Position};
+    ///
+    /// let mut puzzle = crossword!([A B] [C D]);
+    /// let state = CrosswordState::from(&puzzle);
+    /// puzzle.set_cell_note(Position::new(1, 1), "Not E");
+    ///
+    /// let snapshot = puzzle.snapshot(&state);
+    /// assert_eq!(snapshot.notes, puzzle.notes());
+    /// ```
+    pub fn snapshot(&self, state: &CrosswordState) -> PuzzleState {
+        state.snapshot().with_notes(self.notes())
+    }
+
+    /// Continue playing the crossword from a [snapshot](PuzzleState) of the progress of a player
+    ///
+    /// The snapshot has to be taken from a state of the same crossword, so the snapshot should have the same size and block squares.
+    pub fn apply_state(&self, snapshot: PuzzleState) -> Result<CrosswordState, PuzzleStateError> {
+        let squares = self.squares();
+
+        if snapshot.entries.size() != squares.size() {
+            return Err(PuzzleStateError::InvalidSize {
+                found: snapshot.entries.size(),
+                expected: squares.size(),
+            });
+        }
+
+        for (pos, square) in squares.iter_indexed() {
+            let is_playable = snapshot.entries.get_fill(pos).is_some();
+
+            if square.is_some() != is_playable {
+                return Err(PuzzleStateError::MismatchedSquare { pos });
+            }
+        }
+
+        let mut state = CrosswordState::from(self);
+        state.entries = snapshot.entries;
+        state.timer = snapshot.timer;
+
+        Ok(state)
+    }
+
+    /// Continue playing the crossword from a [snapshot](PuzzleState), [restoring](Self::apply_notes) the notes of the player on the crossword as well
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordState, Position};
+    ///
+    /// let mut puzzle = crossword!([A B] [C D]);
+    /// let state = CrosswordState::from(&puzzle);
+    ///
+    /// puzzle.set_cell_note(Position::new(1, 1), "Not E");
+    /// let snapshot = puzzle.snapshot(&state);
+    ///
+    /// let mut other = crossword!([A B] [C D]);
+    /// other.restore_state(snapshot).unwrap();
+    /// assert_eq!(other.cell_note(Position::new(1, 1)), Some("Not E"));
+    /// ```
+    pub fn restore_state(
+        &mut self,
+        mut snapshot: PuzzleState,
+    ) -> Result<CrosswordState, PuzzleStateError> {
+        let notes = std::mem::take(&mut snapshot.notes);
+        let state = self.apply_state(snapshot)?;
+        self.apply_notes(notes);
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Solve;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::crossword;
+
+    #[rstest]
+    #[case::same(crossword!([A B] [C .]), None)]
+    #[case::size(crossword!([A B C]), Some(PuzzleStateError::InvalidSize { found: Size { rows: 2, cols: 2 }, expected: Size { rows: 1, cols: 3 } }))]
+    #[case::blocks(crossword!([A B] [. D]), Some(PuzzleStateError::MismatchedSquare { pos: Position::new(1, 0) }))]
+    fn apply_state(#[case] other: Crossword, #[case] expected: Option<PuzzleStateError>) {
+        let puzzle = crossword!([A B] [C .]);
+        let mut state = CrosswordState::from(&puzzle);
+        state.clear(&Position::new(0, 1));
+
+        let result = other.apply_state(state.snapshot());
+        assert_eq!(result.as_ref().err(), expected.as_ref());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let puzzle = crossword!([A B] [C .]);
+        let mut state = CrosswordState::from(&puzzle);
+        state.clear(&Position::new(0, 1));
+        state.reveal(&Position::new(1, 0));
+
+        let json = serde_json::to_string(&state.snapshot()).expect("snapshot is serialized");
+        let snapshot: PuzzleState = serde_json::from_str(&json).expect("snapshot is deserialized");
+
+        let restored = puzzle
+            .apply_state(snapshot)
+            .expect("snapshot fits the puzzle");
+        assert!(restored.diff_state(&state).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_pencil() {
+        use puzzled_core::Confidence;
+
+        let puzzle = crossword!([A B] [C .]);
+        let mut state = CrosswordState::from(&puzzle);
+        state.enter_pencil(&Position::new(0, 1), crate::Solution::Letter('E'));
+
+        let json = serde_json::to_string(&state.snapshot()).expect("snapshot is serialized");
+        let snapshot: PuzzleState = serde_json::from_str(&json).expect("snapshot is deserialized");
+
+        let restored = puzzle
+            .apply_state(snapshot)
+            .expect("snapshot fits the puzzle");
+        assert_eq!(
+            restored.confidence(&Position::new(0, 1)),
+            Some(Confidence::Pencil)
+        );
+        assert_eq!(
+            restored.confidence(&Position::new(0, 0)),
+            Some(Confidence::Pen)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_locked() {
+        use crate::ClueDirection::*;
+
+        let puzzle = crossword!([A B] [C D] - A: "AB" - A: "CD" - D: "AC" - D: "BD");
+        let mut state = CrosswordState::from(&puzzle);
+        state.lock_clue(&puzzle, (1, Across).into());
+
+        let json = serde_json::to_string(&state.snapshot()).expect("snapshot is serialized");
+        let snapshot: PuzzleState = serde_json::from_str(&json).expect("snapshot is deserialized");
+
+        let mut restored = puzzle
+            .apply_state(snapshot)
+            .expect("snapshot fits the puzzle");
+        assert!(restored.diff_state(&state).is_empty());
+        assert!(!restored.clear(&Position::new(0, 1)));
+        assert!(restored.clear(&Position::new(1, 1)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_notes() {
+        use crate::{ClueDirection::*, ClueId};
+
+        let mut puzzle = crossword!([A B] [C .] - A: "AB" - D: "AC");
+        puzzle.set_cell_note(Position::new(0, 1), "B?");
+        puzzle.set_clue_note((1, Down), "Flagged");
+
+        let snapshot = puzzle.snapshot(&CrosswordState::from(&puzzle));
+        let json = serde_json::to_string(&snapshot).expect("snapshot is serialized");
+        let snapshot: PuzzleState = serde_json::from_str(&json).expect("snapshot is deserialized");
+        assert_eq!(snapshot.notes, puzzle.notes());
+
+        let json = serde_json::to_string(&puzzle).expect("puzzle is serialized");
+        let restored: Crossword = serde_json::from_str(&json).expect("puzzle is deserialized");
+        assert_eq!(restored, puzzle);
+        assert_eq!(restored[ClueId::from((1, Down))].note(), Some("Flagged"));
+    }
+}
@@ -1,7 +1,8 @@
+mod normalize;
 mod solution;
 mod squares;
 
 use puzzled_core::{Cell, Square};
-pub use {solution::*, squares::*};
+pub use {normalize::*, solution::*, squares::*};
 
 pub type CrosswordSquare = Square<Cell<Solution>>;
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+
+use puzzled_core::Word;
+
+use crate::Solution;
+
+/// Normalizes user input before it is [entered](crate::CrosswordState::enter_normalized) as a [solution](Solution)
+///
+/// By default, input is entered as is.
+/// Apps can configure the normalizer to follow their conventions, e.g. [`EntryNormalizer::nyt`] makes `"é"` match a solution of `"E"`.
+/// Multi-letter tokens can be mapped onto a [rebus](Solution::Rebus), such that users can type a shorthand for a longer solution.
+///
+/// ```
+/// use puzzled::crossword::{EntryNormalizer, Solution};
+///
+/// let normalizer = EntryNormalizer::nyt().with_rebus("*", "STAR");
+///
+/// assert_eq!(normalizer.normalize("é"), Solution::Letter('E'));
+/// assert_eq!(normalizer.normalize("café"), Solution::Rebus("CAFE".to_string()));
+/// assert_eq!(normalizer.normalize("*"), Solution::Rebus("STAR".to_string()));
+///
+/// assert!(normalizer.matches(&Solution::from("Ü"), &Solution::Letter('u')));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntryNormalizer {
+    uppercase: bool,
+    strip_diacritics: bool,
+    rebuses: BTreeMap<String, String>,
+}
+
+impl EntryNormalizer {
+    /// Create a normalizer that leaves input unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a normalizer that follows the convention of the New York Times, which uppercases input and ignores diacritics
+    pub fn nyt() -> Self {
+        Self::new().with_uppercase(true).with_strip_diacritics(true)
+    }
+
+    pub fn with_uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    pub fn with_strip_diacritics(mut self, strip_diacritics: bool) -> Self {
+        self.strip_diacritics = strip_diacritics;
+        self
+    }
+
+    /// Map the input `token` onto the `rebus` solution
+    ///
+    /// Tokens are matched after the other normalization steps are applied to both the token and the input
+    pub fn with_rebus<S, T>(mut self, token: S, rebus: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        let token = self.normalize_str(&token.into());
+        let rebus = self.normalize_str(&rebus.into());
+
+        self.rebuses.insert(token, rebus);
+        self
+    }
+
+    /// Normalize the input into the [solution](Solution) it represents
+    pub fn normalize(&self, input: &str) -> Solution {
+        let input = self.normalize_str(input);
+        let input = self.rebuses.get(&input).cloned().unwrap_or(input);
+
+        let mut chars = input.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Solution::Letter(letter),
+            _ => Solution::Rebus(input),
+        }
+    }
+
+    /// Normalize an existing [solution](Solution), e.g. one that was read from a puzzle file
+    pub fn normalize_solution(&self, solution: &Solution) -> Solution {
+        match solution {
+            Solution::Alternatives(alts) => {
+                Solution::alternatives(alts.iter().map(|alt| self.normalize_solution(alt)))
+            }
+            solution => self.normalize(&solution.to_string()),
+        }
+    }
+
+    /// Whether the `entry` matches the `solution` after both have been normalized
+    pub fn matches(&self, entry: &Solution, solution: &Solution) -> bool {
+        self.normalize_solution(entry)
+            .matches(&self.normalize_solution(solution))
+    }
+
+    fn normalize_str(&self, input: &str) -> String {
+        input
+            .trim()
+            .chars()
+            .map(|ch| match self.strip_diacritics {
+                true => strip_diacritic(ch),
+                false => ch,
+            })
+            .flat_map(|ch| match self.uppercase {
+                true => ch.to_uppercase().collect::<Vec<_>>(),
+                false => vec![ch],
+            })
+            .collect()
+    }
+}
+
+/// Find the base letter of a latin letter with a diacritic, e.g. `'é'` becomes `'e'`
+fn strip_diacritic(ch: char) -> char {
+    match ch {
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' => 'A',
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => 'C',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'Ď' | 'Đ' => 'D',
+        'ď' | 'đ' => 'd',
+        'È'..='Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => 'E',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => 'G',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'Ĥ' | 'Ħ' => 'H',
+        'ĥ' | 'ħ' => 'h',
+        'Ì'..='Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => 'I',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'Ĵ' => 'J',
+        'ĵ' => 'j',
+        'Ķ' => 'K',
+        'ķ' => 'k',
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => 'L',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => 'N',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => 'O',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'Ŕ' | 'Ŗ' | 'Ř' => 'R',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => 'S',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'Ţ' | 'Ť' | 'Ŧ' => 'T',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'Ù'..='Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => 'U',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'Ŵ' => 'W',
+        'ŵ' => 'w',
+        'Ý' | 'Ŷ' | 'Ÿ' => 'Y',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'Ź' | 'Ż' | 'Ž' => 'Z',
+        'ź' | 'ż' | 'ž' => 'z',
+        _ => ch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::unchanged(EntryNormalizer::new(), "é", "é")]
+    #[case::uppercase(EntryNormalizer::new().with_uppercase(true), "é", "É")]
+    #[case::strip(EntryNormalizer::new().with_strip_diacritics(true), "é", "e")]
+    #[case::nyt(EntryNormalizer::nyt(), " Crème ", "CREME")]
+    #[case::sharp_s(EntryNormalizer::nyt(), "ß", "SS")]
+    #[case::rebus(EntryNormalizer::nyt().with_rebus("<3", "heart"), "<3", "HEART")]
+    fn normalize(#[case] normalizer: EntryNormalizer, #[case] input: &str, #[case] expected: &str) {
+        assert_eq!(normalizer.normalize(input).to_string(), expected);
+    }
+}
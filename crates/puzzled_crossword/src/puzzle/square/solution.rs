@@ -7,6 +7,22 @@ use puzzled_core::Word;
 /// In almost all cases, solutions consist of a single [letter](Self::Letter).
 /// However, users may define a [rebus](Self::Rebus) to construct a multi-letter solution.
 /// In `*.puz` files, rebuses are defined from the [GRBS and RTBL sections](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki)
+///
+/// Some puzzles (e.g. Schrödinger puzzles) accept multiple [alternatives](Self::Alternatives) in a single square.
+/// Such a solution [matches](Word::matches) each of its alternatives, such that entering any of them is correct.
+/// Equality stays structural, so a solution with alternatives only equals the same alternatives:
+/// ```
+/// use puzzled::core::Word;
+/// use puzzled::crossword::Solution;
+///
+/// let solution = Solution::alternatives(["A", "O"]);
+/// assert!(Solution::Letter('A').matches(&solution));
+/// assert!(Solution::Letter('O').matches(&solution));
+/// assert!(!Solution::Letter('E').matches(&solution));
+///
+/// assert_ne!(solution, Solution::Letter('A'));
+/// assert_eq!(solution.to_string(), "A/O");
+/// ```
 #[derive(Debug, Clone)]
 pub enum Solution {
     /// One-letter solution
@@ -14,13 +30,66 @@ pub enum Solution {
 
     /// Multiple-letter solution, a.k.a. a rebus
     Rebus(String),
+
+    /// Set of acceptable solutions, where the first one is considered the [primary](Self::primary) solution
+    Alternatives(Vec<Solution>),
 }
 
 impl Solution {
+    /// Separator between alternatives when displaying and parsing solutions
+    pub const ALTERNATIVES_SEPARATOR: char = '/';
+
+    /// Create a solution that accepts any of the given alternatives
+    ///
+    /// Nested alternatives are flattened and a single alternative results in just that solution
+    ///
+    /// # Panics
+    /// Panics if no alternatives are given
+    pub fn alternatives<I, S>(alternatives: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Solution>,
+    {
+        let mut solutions: Vec<_> = alternatives
+            .into_iter()
+            .flat_map(|alt| match alt.into() {
+                Solution::Alternatives(alts) => alts,
+                alt => vec![alt],
+            })
+            .collect();
+
+        assert!(
+            !solutions.is_empty(),
+            "Solution requires at least one alternative"
+        );
+
+        match solutions.len() {
+            1 => solutions.remove(0),
+            _ => Solution::Alternatives(solutions),
+        }
+    }
+
+    /// Primary solution, which is the first of the [alternatives](Self::Alternatives) or the solution itself
+    pub fn primary(&self) -> &Solution {
+        match self {
+            Self::Alternatives(alts) => alts.first().expect("Non-empty alternatives"),
+            solution => solution,
+        }
+    }
+
+    /// Iterate over all accepted solutions
+    pub fn iter_alternatives(&self) -> impl Iterator<Item = &Solution> {
+        match self {
+            Self::Alternatives(alts) => alts.iter(),
+            solution => std::slice::from_ref(solution).iter(),
+        }
+    }
+
     pub fn first_letter(&self) -> char {
         match self {
             Self::Letter(letter) => *letter,
             Self::Rebus(rebus) => rebus.chars().next().expect("Non-empty rebus"),
+            Self::Alternatives(_) => self.primary().first_letter(),
         }
     }
 
@@ -33,12 +102,27 @@ impl Solution {
     pub fn is_rebus(&self) -> bool {
         matches!(self, Solution::Rebus(_))
     }
+
+    /// Verify whether the cell accepts multiple solutions
+    pub fn has_alternatives(&self) -> bool {
+        matches!(self, Solution::Alternatives(_))
+    }
 }
 
 impl Word for Solution {
     fn is_word(&self) -> bool {
         true
     }
+
+    fn matches(&self, solution: &Self) -> bool {
+        match (self, solution) {
+            // Any alternative is accepted
+            (entry, Solution::Alternatives(alts)) if !entry.has_alternatives() => {
+                alts.iter().any(|alt| alt == entry)
+            }
+            (entry, solution) => entry == solution,
+        }
+    }
 }
 
 impl PartialEq for Solution {
@@ -46,6 +130,7 @@ impl PartialEq for Solution {
         match (self, other) {
             (Solution::Letter(lhs), Solution::Letter(rhs)) => lhs.eq_ignore_ascii_case(rhs),
             (Solution::Rebus(lhs), Solution::Rebus(rhs)) => lhs.eq_ignore_ascii_case(rhs),
+            (Solution::Alternatives(lhs), Solution::Alternatives(rhs)) => lhs == rhs,
             _ => false,
         }
     }
@@ -58,6 +143,17 @@ impl fmt::Display for Solution {
         match self {
             Self::Letter(letter) => write!(f, "{letter}"),
             Self::Rebus(rebus) => write!(f, "{rebus}"),
+            Self::Alternatives(alts) => {
+                for (idx, alt) in alts.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, "{}", Self::ALTERNATIVES_SEPARATOR)?;
+                    }
+
+                    write!(f, "{alt}")?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -108,10 +204,11 @@ mod serde_impl {
         {
             let mut buf = [0; 4];
 
-            serializer.serialize_str(match self {
-                Solution::Letter(letter) => letter.encode_utf8(&mut buf),
-                Solution::Rebus(rebus) => rebus,
-            })
+            match self {
+                Solution::Letter(letter) => serializer.serialize_str(letter.encode_utf8(&mut buf)),
+                Solution::Rebus(rebus) => serializer.serialize_str(rebus),
+                Solution::Alternatives(alts) => alts.serialize(serializer),
+            }
         }
     }
 
@@ -121,7 +218,68 @@ mod serde_impl {
         where
             D: serde::Deserializer<'de>,
         {
-            Ok(String::deserialize(deserializer)?.into())
+            #[derive(Deserialize)]
+            #[serde(untagged)]
+            enum SerdeSolution {
+                Single(String),
+                Alternatives(Vec<Solution>),
+            }
+
+            match SerdeSolution::deserialize(deserializer)? {
+                SerdeSolution::Single(solution) => Ok(solution.into()),
+                SerdeSolution::Alternatives(alts) if alts.is_empty() => Err(
+                    serde::de::Error::custom("Solution requires at least one alternative"),
+                ),
+                SerdeSolution::Alternatives(alts) => Ok(Solution::alternatives(alts)),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::{
+        arbitrary::Arbitrary,
+        char, collection, prop_oneof,
+        strategy::{BoxedStrategy, Strategy},
+    };
+
+    use crate::Solution;
+
+    /// Arbitrary solutions are mostly uppercase letters, with the occasional rebus or set of alternatives
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl Arbitrary for Solution {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            let letter = char::range('A', 'Z');
+
+            prop_oneof![
+                8 => letter.clone().prop_map(Solution::Letter),
+                1 => "[A-Z]{2,5}".prop_map(Solution::Rebus),
+                1 => collection::vec(letter, 2..=3).prop_map(|letters| {
+                    Solution::alternatives(letters.into_iter().map(Solution::Letter))
+                }),
+            ]
+            .boxed()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use crate::Squares;
+
+        proptest! {
+            #[test]
+            fn squares(squares in any::<Squares>()) {
+                for square in squares.iter().filter_map(|square| square.as_ref()) {
+                    let solution = square.solution.as_ref().expect("Arbitrary cells are solved");
+                    prop_assert!(solution.first_letter().is_ascii_uppercase());
+                }
+            }
         }
     }
 }
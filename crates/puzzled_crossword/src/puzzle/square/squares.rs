@@ -1,44 +1,148 @@
-use std::ops;
+use std::{collections::BTreeMap, ops};
 
-use puzzled_core::{Grid, Offset, Position};
+use puzzled_core::{Grid, Line, LinePosition, Position};
 
-use crate::{ClueDirection, Crossword, CrosswordSquare};
+use crate::{
+    ClueDirection, ClueId, ClueNumbers, Crossword, CrosswordSquare, NumberingScheme,
+    WritingDirection,
+};
 
 pub type Squares = Grid<CrosswordSquare>;
 
 pub trait CrosswordSquares {
-    fn can_clue_start_in_dir(&self, pos: Position, dir: ClueDirection) -> bool;
-    fn find_clue_len(&self, pos: Position, dir: ClueDirection) -> u8;
+    /// Whether a clue read in the given [writing direction](WritingDirection) can start at the position
+    fn can_clue_start_written(
+        &self,
+        pos: Position,
+        dir: ClueDirection,
+        writing: WritingDirection,
+    ) -> bool;
+
+    /// Length of the clue read in the given [writing direction](WritingDirection) from the position
+    fn find_clue_len_written(
+        &self,
+        pos: Position,
+        dir: ClueDirection,
+        writing: WritingDirection,
+    ) -> u8;
+
+    fn can_clue_start_in_dir(&self, pos: Position, dir: ClueDirection) -> bool {
+        self.can_clue_start_written(pos, dir, WritingDirection::LeftToRight)
+    }
+
+    fn find_clue_len(&self, pos: Position, dir: ClueDirection) -> u8 {
+        self.find_clue_len_written(pos, dir, WritingDirection::LeftToRight)
+    }
+
+    /// Start and length of every clue read in the given [writing direction](WritingDirection), identified by the number that the [numbering scheme](NumberingScheme) gives it
+    ///
+    /// Clues that would be numbered past 255, the largest clue number, are left out.
+    fn clue_starts(
+        &self,
+        numbering: NumberingScheme,
+        writing: WritingDirection,
+    ) -> BTreeMap<ClueId, (Position, u8)>;
+
+    /// Number of every square that starts a clue according to [standard](crate::NumberingScheme::Standard) numbering, without needing any clues
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordSquares};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B .]
+    ///     [C D E]
+    ///     [. F G]
+    /// );
+    /// let numbering = puzzle.squares().numbering();
+    ///
+    /// assert_eq!(numbering.data(), &vec![Some(1), Some(2), None, Some(3), None, Some(4), None, Some(5), None]);
+    /// ```
+    fn numbering(&self) -> Grid<Option<u8>>;
 }
 
 impl CrosswordSquares for Grid<CrosswordSquare> {
-    fn can_clue_start_in_dir(&self, pos: Position, dir: ClueDirection) -> bool {
-        let is_blank = |pos: Option<Position>| pos.is_some_and(|p| self[p].as_ref().is_none());
+    fn can_clue_start_written(
+        &self,
+        pos: Position,
+        dir: ClueDirection,
+        writing: WritingDirection,
+    ) -> bool {
+        let reversed = is_reversed(dir, writing);
+        let pos = line_position(pos, dir);
+
+        // Clues start at the first square of every run of filled squares in reading order
+        self.line_run_at(pos, |square| square.is_some())
+            .is_some_and(|run| {
+                let first = if reversed { run.end() - 1 } else { run.start };
+                run.value && first == pos.pos
+            })
+    }
+
+    fn find_clue_len_written(
+        &self,
+        pos: Position,
+        dir: ClueDirection,
+        writing: WritingDirection,
+    ) -> u8 {
+        let reversed = is_reversed(dir, writing);
+        let pos = line_position(pos, dir);
 
-        if is_blank(Some(pos)) {
-            return false;
+        match self.line_run_at(pos, |square| square.is_some()) {
+            Some(run) if run.value && reversed => (pos.pos - run.start + 1) as u8,
+            Some(run) if run.value => (run.end() - pos.pos) as u8,
+            _ => 0,
         }
+    }
+
+    fn clue_starts(
+        &self,
+        numbering: NumberingScheme,
+        writing: WritingDirection,
+    ) -> BTreeMap<ClueId, (Position, u8)> {
+        let mut starts = BTreeMap::new();
+        let mut numbers = ClueNumbers::new(numbering);
 
-        match dir {
-            ClueDirection::Across => pos.col == 0 || is_blank(pos + Offset::LEFT),
-            ClueDirection::Down => pos.row == 0 || is_blank(pos + Offset::UP),
+        for start in writing.positions(self.rows(), self.cols()) {
+            for direction in [ClueDirection::Across, ClueDirection::Down] {
+                if !self.can_clue_start_written(start, direction, writing) {
+                    continue;
+                }
+
+                let Some(num) = numbers.take(direction) else {
+                    continue;
+                };
+
+                let len = self.find_clue_len_written(start, direction, writing);
+                starts.insert((num, direction).into(), (start, len));
+            }
+
+            numbers.next_square();
         }
+
+        starts
     }
 
-    fn find_clue_len(&self, pos: Position, dir: ClueDirection) -> u8 {
-        let offset = match dir {
-            ClueDirection::Across => Offset::RIGHT,
-            ClueDirection::Down => Offset::DOWN,
-        };
+    fn numbering(&self) -> Grid<Option<u8>> {
+        let mut numbering = self.map_ref(|_| None);
+        let starts = self.clue_starts(NumberingScheme::Standard, WritingDirection::LeftToRight);
 
-        (0..)
-            .scan(pos, |acc, _| {
-                let square = self.get_fill(*acc)?;
-                *acc += offset;
+        for (id, (start, _)) in starts {
+            numbering[start] = Some(id.num);
+        }
 
-                Some(square)
-            })
-            .count() as u8
+        numbering
+    }
+}
+
+/// Whether clues in the given direction are read against the order of the line they run along
+fn is_reversed(dir: ClueDirection, writing: WritingDirection) -> bool {
+    dir == ClueDirection::Across && writing.is_rtl()
+}
+
+/// Position on the line that a clue in the given direction runs along
+fn line_position(pos: Position, dir: ClueDirection) -> LinePosition {
+    match dir {
+        ClueDirection::Across => LinePosition::new(Line::Row(pos.row), pos.col),
+        ClueDirection::Down => LinePosition::new(Line::Col(pos.col), pos.row),
     }
 }
 
@@ -1,11 +1,31 @@
+use std::{collections::BTreeSet, mem};
+
 use delegate::delegate;
 use derive_more::{Deref, DerefMut, Display};
-use puzzled_core::{Entry, Grid, Position, Solve, Square, SquareGridState, Timer};
+use puzzled_core::{
+    Confidence, Entry, Grid, Position, Solve, Square, SquareGridState, Timer, Word,
+};
 
-use crate::{ClueId, Crossword, Solution};
+use crate::{
+    CheckPolicy, ClueDirection, ClueId, Crossword, EntryNormalizer, LockState, PuzzleEvent,
+    Solution,
+};
 
 #[derive(Debug, Deref, DerefMut, Display)]
-pub struct CrosswordState(pub SquareGridState<Crossword>);
+#[display("{state}")]
+pub struct CrosswordState {
+    #[deref]
+    #[deref_mut]
+    state: SquareGridState<Crossword>,
+
+    events: Vec<PuzzleEvent>,
+
+    /// Whether the solutions are scrambled, in which case squares cannot be checked or revealed
+    lock: LockState,
+
+    /// When entries are marked as correct or incorrect
+    check: CheckPolicy,
+}
 
 impl CrosswordState {
     pub fn new(
@@ -13,21 +33,379 @@ impl CrosswordState {
         entries: Grid<Square<Entry<Solution>>>,
         timer: Timer,
     ) -> Self {
-        let state = SquareGridState::new(solutions, entries, timer);
-        Self(state)
+        Self {
+            state: SquareGridState::new(solutions, entries, timer),
+            events: Vec::new(),
+            lock: LockState::Unlocked,
+            check: CheckPolicy::default(),
+        }
+    }
+
+    pub fn lock_state(&self) -> LockState {
+        self.lock
+    }
+
+    pub fn with_lock_state(mut self, lock: LockState) -> Self {
+        self.lock = lock;
+        self
+    }
+
+    pub fn check_policy(&self) -> CheckPolicy {
+        self.check
+    }
+
+    pub fn with_check_policy(mut self, check: CheckPolicy) -> Self {
+        self.set_check_policy(check);
+        self
+    }
+
+    /// Change when entries are [checked](CheckPolicy), e.g. when the player toggles "check as you type"
+    ///
+    /// Switching to [`CheckPolicy::Never`] removes the correctness of every square that was already checked.
+    /// ```
+    /// use puzzled::crossword::{crossword, CheckPolicy, CrosswordState, Position, Solution, Solve};
+    ///
+    /// let puzzle = crossword!([A B]);
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// let pos = Position::new(0, 1);
+    ///
+    /// state.enter(&pos, Solution::Letter('C'));
+    /// assert_eq!(state.check(&pos), Some(false));
+    ///
+    /// state.set_check_policy(CheckPolicy::Never);
+    /// assert!(!state.entries.get_fill(pos).unwrap().is_incorrect());
+    /// assert_eq!(state.check(&pos), None);
+    /// ```
+    pub fn set_check_policy(&mut self, check: CheckPolicy) {
+        self.check = check;
+
+        if !check.allows_checks() {
+            for entry in self.state.entries.iter_fills_mut() {
+                entry.reset_correctness();
+            }
+        }
+    }
+
+    /// Mark the entry at the position right away if every entry is [checked immediately](CheckPolicy::Immediate)
+    fn check_entered(&mut self, pos: &Position) {
+        if self.check.is_immediate() {
+            self.check(pos);
+        }
+    }
+
+    /// Whether the entry at the position matches its solution, which is never known while the solutions are [locked](LockState::Locked)
+    pub fn is_correct(&self, pos: Position) -> bool {
+        if self.lock.is_locked() {
+            return false;
+        }
+
+        match (self.entry(&pos), self.solution(&pos)) {
+            (Some(entry), Some(solution)) => entry.matches(solution),
+            _ => false,
+        }
+    }
+
+    /// Whether the square at the position shows its solution, either by being correct or revealed
+    fn shows_solution(&self, pos: Position) -> bool {
+        let is_revealed = self
+            .entries
+            .get_fill(pos)
+            .is_some_and(|entry| entry.is_revealed() || entry.is_initially_revealed());
+
+        is_revealed || self.is_correct(pos)
+    }
+
+    /// Take the [events](PuzzleEvent) of all squares that were entered, cleared or revealed since the last call
+    ///
+    /// The square events are followed by a [`PuzzleEvent::ClueCompleted`] for every clue through the changed squares that is now complete.
+    /// If any square changed and the whole puzzle is complete, [`PuzzleEvent::PuzzleSolved`] is emitted last.
+    /// Note that mutations through the underlying [state](SquareGridState) are not tracked.
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*, CrosswordState, Position, PuzzleEvent, Solution, Solve};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B]
+    ///     [C .]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    /// );
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// for pos in puzzle.squares().positions() {
+    ///     state.clear(&pos);
+    /// }
+    /// state.drain_events(&puzzle);
+    ///
+    /// state.enter(&Position::new(0, 0), Solution::Letter('A'));
+    /// state.reveal(&Position::new(0, 1));
+    ///
+    /// let events = state.drain_events(&puzzle);
+    /// assert_eq!(events[2], PuzzleEvent::ClueCompleted((1, Across).into()));
+    /// assert_eq!(events.len(), 3);
+    ///
+    /// state.enter(&Position::new(1, 0), Solution::Letter('C'));
+    /// assert_eq!(state.drain_events(&puzzle).last(), Some(&PuzzleEvent::PuzzleSolved));
+    /// ```
+    pub fn drain_events(&mut self, crossword: &Crossword) -> Vec<PuzzleEvent> {
+        let mut events = mem::take(&mut self.events);
+        if events.is_empty() {
+            return events;
+        }
+
+        // Find the clues that run through any of the changed squares
+        let clues = crossword.clues();
+        let ids: BTreeSet<ClueId> = events
+            .iter()
+            .filter_map(|event| match event {
+                PuzzleEvent::CellEntered { pos, .. }
+                | PuzzleEvent::CellCleared { pos }
+                | PuzzleEvent::CellRevealed { pos } => Some(*pos),
+                _ => None,
+            })
+            .flat_map(|pos| {
+                [ClueDirection::Across, ClueDirection::Down]
+                    .into_iter()
+                    .filter_map(move |dir| clues.get_clue(pos, dir))
+            })
+            .map(|clue| clue.id())
+            .collect();
+
+        // Clues can only be completed once their solution is known
+        let ids = match self.lock {
+            LockState::Unlocked => ids,
+            LockState::Locked { .. } => BTreeSet::new(),
+        };
+
+        for id in ids {
+            let is_complete = clues
+                .get(id)
+                .is_some_and(|clue| clue.positions().all(|pos| self.shows_solution(pos)));
+
+            if is_complete {
+                events.push(PuzzleEvent::ClueCompleted(id));
+            }
+        }
+
+        if self.is_solved(crossword) {
+            events.push(PuzzleEvent::PuzzleSolved);
+        }
+
+        events
+    }
+
+    /// Whether every square shows its solution
+    ///
+    /// While the solutions are [locked](LockState::Locked), the entries are instead [verified](LockState::verify) against the checksum of the real solution
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordState, LockState, Position, Solution, Solve};
+    ///
+    /// // Scrambled solution of CAT, with the checksum of the real solution
+    /// let puzzle = crossword!([X Y Z]).with_lock_state(LockState::Locked { checksum: 0x4085 });
+    /// let mut state = CrosswordState::from(&puzzle);
+    ///
+    /// // Squares cannot be checked or revealed without the real solution
+    /// assert_eq!(state.check(&Position::new(0, 0)), None);
+    /// assert!(!state.reveal(&Position::new(0, 0)));
+    /// assert!(!state.is_solved(&puzzle));
+    ///
+    /// for (col, letter) in ['C', 'A', 'T'].into_iter().enumerate() {
+    ///     state.enter(&Position::new(0, col), Solution::Letter(letter));
+    /// }
+    /// assert!(state.is_solved(&puzzle));
+    /// ```
+    pub fn is_solved(&self, crossword: &Crossword) -> bool {
+        if self.lock.is_unlocked() {
+            return crossword
+                .squares()
+                .iter_fills_indexed()
+                .all(|(pos, _)| self.shows_solution(pos));
+        }
+
+        // Read the entries column by column, in the same order as the solution was scrambled
+        let squares = crossword.squares();
+        let letters: Option<Vec<u8>> = (0..squares.cols())
+            .flat_map(|col| (0..squares.rows()).map(move |row| Position::new(row, col)))
+            .filter(|&pos| squares.get_fill(pos).is_some())
+            .map(|pos| {
+                self.entry(&pos)
+                    .map(|entry| entry.first_letter().to_ascii_uppercase() as u8)
+            })
+            .collect();
+
+        letters.is_some_and(|letters| self.lock.verify(&letters))
     }
 
     pub fn reveal_clue(&mut self, crossword: &Crossword, id: ClueId) -> bool {
         // Try to get the clue to reveal squares for
-        let Some(clue) = crossword.clues().get(&id) else {
+        let Some(clue) = crossword.clues().get(id) else {
             return false;
         };
 
         // Try reveal all squares that the is positioned in
         clue.positions().all(|pos| self.reveal(&pos))
     }
+
+    /// Enter user input at the given position after [normalizing](EntryNormalizer) it into a [solution](Solution)
+    ///
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordState, EntryNormalizer, Position, Solve};
+    ///
+    /// let puzzle = crossword!([C A F E]);
+    /// let mut state = CrosswordState::from(&puzzle);
+    ///
+    /// let pos = Position::new(0, 3);
+    /// assert!(state.enter_normalized(&pos, "é", &EntryNormalizer::nyt()));
+    /// assert_eq!(state.check(&pos), Some(true));
+    /// ```
+    pub fn enter_normalized(
+        &mut self,
+        pos: &Position,
+        input: &str,
+        normalizer: &EntryNormalizer,
+    ) -> bool {
+        let entry = normalizer.normalize(input);
+        self.enter(pos, entry)
+    }
+
+    /// Pencil in a tentative guess at the given position, which is [entered](Solve::enter) like any other guess
+    ///
+    /// ```
+    /// use puzzled::crossword::{crossword, Confidence, CrosswordState, Position, Solution, Solve};
+    ///
+    /// let puzzle = crossword!([A B] [C D]);
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// state.clear(&Position::new(0, 0));
+    /// state.clear(&Position::new(0, 1));
+    ///
+    /// state.enter_pencil(&Position::new(0, 0), Solution::Letter('A'));
+    /// state.enter_pencil(&Position::new(0, 1), Solution::Letter('E'));
+    /// assert_eq!(state.confidence(&Position::new(0, 0)), Some(Confidence::Pencil));
+    ///
+    /// // Keep the guess that is right and erase the others
+    /// state.enter(&Position::new(0, 0), Solution::Letter('A'));
+    /// assert_eq!(state.clear_pencil(), 1);
+    /// assert_eq!(state.entry(&Position::new(0, 1)), None);
+    ///
+    /// state.enter_pencil(&Position::new(0, 1), Solution::Letter('B'));
+    /// assert_eq!(state.confirm_all_pencil(), 1);
+    /// assert_eq!(state.confidence(&Position::new(0, 1)), Some(Confidence::Pen));
+    /// ```
+    pub fn enter_pencil(&mut self, pos: &Position, entry: Solution) -> bool {
+        let event = PuzzleEvent::CellEntered {
+            pos: *pos,
+            entry: entry.clone(),
+        };
+
+        let entered = self
+            .state
+            .entries
+            .get_fill_mut(*pos)
+            .is_some_and(|square| square.enter_pencil(entry));
+        if entered {
+            self.events.push(event);
+            self.check_entered(pos);
+        }
+
+        entered
+    }
+
+    /// [Confidence] of the entry at the given position, if the square is playable and filled
+    pub fn confidence(&self, pos: &Position) -> Option<Confidence> {
+        self.entries
+            .get_fill(*pos)
+            .filter(|entry| entry.is_filled())
+            .map(Entry::confidence)
+    }
+
+    /// [Confirm](Entry::confirm) every pencilled in guess, returning how many there were
+    pub fn confirm_all_pencil(&mut self) -> usize {
+        self.state
+            .entries
+            .iter_fills_mut()
+            .map(|entry| entry.confirm())
+            .filter(|&was_pencil| was_pencil)
+            .count()
+    }
+
+    /// [Clear](Solve::clear) every pencilled in guess, returning how many were cleared
+    pub fn clear_pencil(&mut self) -> usize {
+        let positions: Vec<_> = self
+            .entries
+            .iter_fills_indexed()
+            .filter(|(_, entry)| entry.is_pencil())
+            .map(|(pos, _)| pos)
+            .collect();
+
+        positions.into_iter().filter(|pos| self.clear(pos)).count()
+    }
+
+    /// Lock all squares of a [clue](crate::Clue) such that they can no longer be entered or cleared.
+    /// Returns whether the clue exists in the puzzle and all its [positions](Position) could be locked, otherwise no square is locked
+    pub fn lock_clue(&mut self, crossword: &Crossword, id: ClueId) -> bool {
+        let Some(positions) = self.clue_squares(crossword, id) else {
+            return false;
+        };
+
+        for pos in positions {
+            self.state.lock(&pos);
+        }
+
+        true
+    }
+
+    /// Unlock all squares of a [clue](crate::Clue) such that they can be entered and cleared again.
+    /// Returns whether the clue exists in the puzzle and all its [positions](Position) could be unlocked, otherwise no square is unlocked
+    pub fn unlock_clue(&mut self, crossword: &Crossword, id: ClueId) -> bool {
+        let Some(positions) = self.clue_squares(crossword, id) else {
+            return false;
+        };
+
+        for pos in positions {
+            self.state.unlock(&pos);
+        }
+
+        true
+    }
+
+    /// Positions of the identified clue, if they are all playable squares of the state
+    fn clue_squares(&self, crossword: &Crossword, id: ClueId) -> Option<Vec<Position>> {
+        let clue = crossword.clues().get(id)?;
+        let positions: Vec<_> = clue.positions().collect();
+
+        positions
+            .iter()
+            .all(|&pos| self.entries.get_fill(pos).is_some())
+            .then_some(positions)
+    }
+
+    /// Find the squares whose entry or [style](puzzled_core::CellStyle) differs from the other state, together with the square in both states
+    ///
+    /// Unlike comparing the states as a whole, this tells which squares changed, e.g. to only redraw those.
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordState, Position, Solve};
+    ///
+    /// let puzzle = crossword!([A B] [C D]);
+    /// let state = CrosswordState::from(&puzzle);
+    ///
+    /// let mut other = CrosswordState::from(&puzzle);
+    /// other.clear(&Position::new(0, 1));
+    /// other.reveal(&Position::new(1, 0));
+    ///
+    /// let positions: Vec<_> = state.diff_state(&other).into_iter().map(|(pos, ..)| pos).collect();
+    /// assert_eq!(positions, vec![Position::new(0, 1), Position::new(1, 0)]);
+    /// ```
+    pub fn diff_state<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> Vec<(Position, &'a CrosswordEntry, &'a CrosswordEntry)> {
+        self.entries.diff_by(&other.entries, |entry, other| {
+            entry == other && entry.as_ref().map(Entry::style) == other.as_ref().map(Entry::style)
+        })
+    }
 }
 
+/// Square of the [entries](SquareGridState::entries) of a [crossword state](CrosswordState)
+pub type CrosswordEntry = Square<Entry<Solution>>;
+
 pub trait CrosswordSolve {
     /// Try to reveal a [clue](crate::Clue) from a given [identifier](ClueId).
     /// Returns whether the clue exists in the puzzle and all its [positions](Position) could be revealed
@@ -58,22 +436,85 @@ impl From<&Crossword> for CrosswordState {
         let timer = Timer::default();
 
         CrosswordState::new(solutions, entries, timer)
+            .with_lock_state(crossword.lock_state())
+            .with_check_policy(crossword.check_policy())
     }
 }
 
 impl Solve<Crossword> for CrosswordState {
     delegate! {
-        to self.0 {
+        to self.state {
             fn solution(&self, pos: &Position) -> Option<&Solution>;
             fn entry(&self, pos: &Position) -> Option<&Solution>;
 
             fn solve(&mut self, pos: &Position, solution: Solution) -> bool;
-            fn enter(&mut self, pos: &Position, entry: Solution) -> bool;
-            fn clear(&mut self, pos: &Position) -> bool;
-            fn reveal(&mut self, pos: &Position) -> bool;
-            fn check(&mut self, pos: &Position) -> Option<bool>;
+        }
+    }
+
+    /// Check the entry at the position, which is not possible while the solutions are [locked](LockState::Locked) or checks are [disabled](CheckPolicy::Never)
+    fn check(&mut self, pos: &Position) -> Option<bool> {
+        if self.lock.is_locked() || !self.check.allows_checks() {
+            return None;
+        }
+
+        self.state.check(pos)
+    }
+
+    fn enter(&mut self, pos: &Position, entry: Solution) -> bool {
+        let event = PuzzleEvent::CellEntered {
+            pos: *pos,
+            entry: entry.clone(),
+        };
 
-            fn guess(&mut self, pos: &Position, guess: Solution) -> bool;
+        let entered = self.state.enter(pos, entry);
+        if entered {
+            self.events.push(event);
+            self.check_entered(pos);
         }
+
+        entered
+    }
+
+    fn clear(&mut self, pos: &Position) -> bool {
+        let cleared = self.state.clear(pos);
+        if cleared {
+            self.events.push(PuzzleEvent::CellCleared { pos: *pos });
+        }
+
+        cleared
+    }
+
+    fn reveal(&mut self, pos: &Position) -> bool {
+        if self.lock.is_locked() {
+            return false;
+        }
+
+        let revealed = self.state.reveal(pos);
+        if revealed {
+            self.events.push(PuzzleEvent::CellRevealed { pos: *pos });
+        }
+
+        revealed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ClueDirection::*, crossword};
+
+    #[test]
+    fn lock_clue() {
+        let puzzle = crossword!([A B] [C D] - A: "AB" - A: "CD" - D: "AC" - D: "BD");
+        let other = crossword!([A B] [C .]);
+
+        // The down clue runs into a block of the other state, so none of its squares is locked
+        let mut state = CrosswordState::from(&other);
+        assert!(!state.lock_clue(&puzzle, (2, Down).into()));
+        assert!(state.entries.iter_fills().all(|entry| !entry.is_locked()));
+
+        let mut state = CrosswordState::from(&puzzle);
+        assert!(state.lock_clue(&puzzle, (2, Down).into()));
+        assert!(!state.clear(&Position::new(1, 1)));
     }
 }
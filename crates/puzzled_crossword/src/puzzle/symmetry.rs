@@ -0,0 +1,227 @@
+use std::fmt;
+
+use puzzled_core::{Position, Square};
+
+use crate::Squares;
+
+/// Symmetry of the black squares in a crossword grid
+///
+/// Most published crosswords use [`Rotational180`](Symmetry::Rotational180) symmetry, such that the grid looks the same when turned upside down.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    /// Grid looks the same after rotating it by 180 degrees
+    #[default]
+    Rotational180,
+
+    /// Grid looks the same after rotating it by 90 degrees (requires a square grid)
+    Rotational90,
+
+    /// Grid looks the same after mirroring its left and right halves
+    MirrorLeftRight,
+
+    /// Grid looks the same after mirroring its top and bottom halves
+    MirrorTopBottom,
+
+    /// Grid looks the same after mirroring it along the main diagonal (requires a square grid)
+    Diagonal,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 5] = [
+        Symmetry::Rotational180,
+        Symmetry::Rotational90,
+        Symmetry::MirrorLeftRight,
+        Symmetry::MirrorTopBottom,
+        Symmetry::Diagonal,
+    ];
+
+    /// Whether the symmetry can be applied to a grid of the given size
+    pub fn supports(&self, rows: usize, cols: usize) -> bool {
+        match self {
+            Symmetry::Rotational90 | Symmetry::Diagonal => rows == cols,
+            _ => true,
+        }
+    }
+
+    /// Find the position that `pos` maps to in a grid of the given size
+    ///
+    /// Returns [`None`] if the position is out of bounds or the symmetry is not [supported](Self::supports) for the grid size
+    pub fn partner(&self, pos: Position, rows: usize, cols: usize) -> Option<Position> {
+        if !self.supports(rows, cols) || pos.row >= rows || pos.col >= cols {
+            return None;
+        }
+
+        let Position { row, col } = pos;
+        let (last_row, last_col) = (rows - 1, cols - 1);
+
+        let partner = match self {
+            Symmetry::Rotational180 => Position::new(last_row - row, last_col - col),
+            Symmetry::Rotational90 => Position::new(col, last_row - row),
+            Symmetry::MirrorLeftRight => Position::new(row, last_col - col),
+            Symmetry::MirrorTopBottom => Position::new(last_row - row, col),
+            Symmetry::Diagonal => Position::new(col, row),
+        };
+
+        Some(partner)
+    }
+
+    /// Find all positions that `pos` maps to by repeatedly applying the symmetry, including `pos` itself
+    pub fn orbit(&self, pos: Position, rows: usize, cols: usize) -> Vec<Position> {
+        let mut orbit = vec![];
+        let mut curr = Some(pos);
+
+        while let Some(next) = curr
+            && !orbit.contains(&next)
+        {
+            orbit.push(next);
+            curr = self.partner(next, rows, cols);
+        }
+
+        orbit
+    }
+}
+
+impl fmt::Display for Symmetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Symmetry::Rotational180 => "180° rotational",
+            Symmetry::Rotational90 => "90° rotational",
+            Symmetry::MirrorLeftRight => "left-right mirror",
+            Symmetry::MirrorTopBottom => "top-bottom mirror",
+            Symmetry::Diagonal => "diagonal",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SymmetryError {
+    #[error("{symmetry} symmetry requires a square grid, found {rows}x{cols}")]
+    UnsupportedSize {
+        symmetry: Symmetry,
+        rows: usize,
+        cols: usize,
+    },
+}
+
+pub trait SymmetricSquares {
+    /// Find the position of the square that mirrors `pos` under the given [symmetry](Symmetry)
+    ///
+    /// ```
+    /// use puzzled::crossword::{crossword, Position, Symmetry, SymmetricSquares};
+    ///
+    /// let puzzle = crossword!(
+    ///     [A B C]
+    ///     [D E F]
+    /// );
+    /// let squares = puzzle.squares();
+    ///
+    /// let pos = Position::new(0, 0);
+    /// assert_eq!(squares.symmetric_partner(pos, Symmetry::Rotational180), Some(Position::new(1, 2)));
+    /// assert_eq!(squares.symmetric_partner(pos, Symmetry::MirrorTopBottom), Some(Position::new(1, 0)));
+    /// assert_eq!(squares.symmetric_partner(pos, Symmetry::Diagonal), None);
+    /// ```
+    fn symmetric_partner(&self, pos: Position, symmetry: Symmetry) -> Option<Position>;
+
+    /// Whether the black squares in the grid follow the given [symmetry](Symmetry)
+    fn is_symmetric(&self, symmetry: Symmetry) -> bool;
+
+    /// Make the black squares in the grid follow the given [symmetry](Symmetry)
+    ///
+    /// Every square whose [partner](Self::symmetric_partner) is black is blacked out as well.
+    /// Returns the positions of the squares that were blacked out
+    ///
+    /// ```
+    /// use puzzled::crossword::{crossword, Position, Symmetry, SymmetricSquares};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [. B C]
+    ///     [D E F]
+    ///     [G H I]
+    /// );
+    ///
+    /// let squares = puzzle.squares_mut();
+    /// let blacked = squares.enforce_symmetry(Symmetry::Rotational180).unwrap();
+    ///
+    /// assert_eq!(blacked, vec![Position::new(2, 2)]);
+    /// assert!(squares.is_symmetric(Symmetry::Rotational180));
+    /// ```
+    fn enforce_symmetry(&mut self, symmetry: Symmetry) -> Result<Vec<Position>, SymmetryError>;
+}
+
+impl SymmetricSquares for Squares {
+    fn symmetric_partner(&self, pos: Position, symmetry: Symmetry) -> Option<Position> {
+        symmetry.partner(pos, self.rows(), self.cols())
+    }
+
+    fn is_symmetric(&self, symmetry: Symmetry) -> bool {
+        self.positions().all(|pos| {
+            self.symmetric_partner(pos, symmetry)
+                .is_some_and(|partner| self.is_fill(pos) == self.is_fill(partner))
+        })
+    }
+
+    fn enforce_symmetry(&mut self, symmetry: Symmetry) -> Result<Vec<Position>, SymmetryError> {
+        let (rows, cols) = (self.rows(), self.cols());
+
+        if !symmetry.supports(rows, cols) {
+            return Err(SymmetryError::UnsupportedSize {
+                symmetry,
+                rows,
+                cols,
+            });
+        }
+
+        let black: Vec<_> = self.positions().filter(|&pos| !self.is_fill(pos)).collect();
+        let mut blacked = vec![];
+
+        for pos in black {
+            for partner in symmetry.orbit(pos, rows, cols) {
+                if self.is_fill(partner) {
+                    self[partner] = Square::new_empty();
+                    blacked.push(partner);
+                }
+            }
+        }
+
+        blacked.sort();
+        Ok(blacked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::rot180(Symmetry::Rotational180, vec![(0, 1), (3, 2)])]
+    #[case::rot90(Symmetry::Rotational90, vec![(0, 1), (1, 3), (3, 2), (2, 0)])]
+    #[case::mirror_lr(Symmetry::MirrorLeftRight, vec![(0, 1), (0, 2)])]
+    #[case::mirror_tb(Symmetry::MirrorTopBottom, vec![(0, 1), (3, 1)])]
+    #[case::diagonal(Symmetry::Diagonal, vec![(0, 1), (1, 0)])]
+    fn orbit(#[case] symmetry: Symmetry, #[case] expected: Vec<(usize, usize)>) {
+        let expected: Vec<_> = expected.into_iter().map(Position::from).collect();
+        let orbit = symmetry.orbit(Position::new(0, 1), 4, 4);
+
+        assert_eq!(orbit, expected);
+    }
+
+    #[rstest]
+    fn orbit_is_symmetric(#[values(3, 4)] size: usize) {
+        for symmetry in Symmetry::ALL {
+            for row in 0..size {
+                for col in 0..size {
+                    let pos = Position::new(row, col);
+                    let orbit = symmetry.orbit(pos, size, size);
+
+                    for other in &orbit {
+                        assert!(symmetry.orbit(*other, size, size).contains(&pos));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use puzzled_core::{CellStyle, Position};
+
+use crate::Crossword;
+
+/// Named group of squares that together form an answer of the puzzle theme
+///
+/// Unlike [clues](crate::Clue), the squares of a theme entry do not need to be next to each other.
+/// A common example are circled letters that spell out a hidden word when read in order.
+/// ```
+/// use puzzled::crossword::{Position, ThemeEntry};
+///
+/// let entry = ThemeEntry::new("Hidden pet", [Position::new(0, 0), Position::new(2, 1), Position::new(1, 2)]);
+///
+/// assert_eq!(entry.name(), "Hidden pet");
+/// assert!(entry.contains(Position::new(2, 1)));
+/// assert!(!entry.contains(Position::new(1, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThemeEntry {
+    name: String,
+    positions: Vec<Position>,
+}
+
+impl ThemeEntry {
+    pub fn new<S, I>(name: S, positions: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = Position>,
+    {
+        Self {
+            name: name.into(),
+            positions: positions.into_iter().collect(),
+        }
+    }
+
+    /// Construct an entry from all [circled](puzzled_core::CellStyle::CIRCLED) squares of the puzzle, read row by row
+    /// ```
+    /// use puzzled::crossword::{crossword, CellStyle, Position, ThemeEntry};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [C A T]
+    ///     [O W L]
+    /// );
+    /// for pos in [Position::new(0, 0), Position::new(1, 1), Position::new(1, 2)] {
+    ///     if let Some(cell) = puzzle.squares_mut()[pos].as_mut() {
+    ///         cell.style |= CellStyle::CIRCLED;
+    ///     }
+    /// }
+    ///
+    /// let entry = ThemeEntry::circled("Bird", &puzzle);
+    /// assert_eq!(entry.answer(&puzzle), Some("CWL".to_string()));
+    /// ```
+    pub fn circled<S: Into<String>>(name: S, crossword: &Crossword) -> Self {
+        let positions = crossword
+            .squares()
+            .iter_fills_indexed()
+            .filter(|(_, cell)| cell.style.contains(CellStyle::CIRCLED))
+            .map(|(pos, _)| pos);
+
+        Self::new(name, positions)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Positions of the squares in the order that they spell out the answer
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.positions.contains(&pos)
+    }
+
+    /// Answer that the solutions of the squares spell out, if every square has a solution
+    pub fn answer(&self, crossword: &Crossword) -> Option<String> {
+        self.positions
+            .iter()
+            .map(|&pos| {
+                let cell = crossword.squares().get_fill(pos)?;
+                cell.solution.as_ref().map(ToString::to_string)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ThemeEntryError {
+    #[error("Theme entry '{name}' has no squares")]
+    Empty { name: String },
+
+    #[error("Theme entry '{name}' contains the square at {pos:?}, which is not playable")]
+    NotPlayable { name: String, pos: Position },
+}
+
+/// # Theme entries
+impl Crossword {
+    /// [Theme entries](ThemeEntry) of the puzzle, in the order that they were added
+    pub fn theme_entries(&self) -> &[ThemeEntry] {
+        &self.theme_entries
+    }
+
+    /// Add an entry to the theme of the puzzle, which should only contain playable squares
+    /// ```
+    /// use puzzled::crossword::{crossword, Position, ThemeEntry, ThemeEntryError};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [C A .]
+    ///     [O W L]
+    /// );
+    ///
+    /// let entry = ThemeEntry::new("Pet", [Position::new(0, 0), Position::new(0, 1), Position::new(1, 2)]);
+    /// assert!(puzzle.add_theme_entry(entry).is_ok());
+    /// assert_eq!(puzzle.theme_entries_at(Position::new(1, 2)).count(), 1);
+    ///
+    /// let entry = ThemeEntry::new("Block", [Position::new(0, 2)]);
+    /// assert_eq!(
+    ///     puzzle.add_theme_entry(entry),
+    ///     Err(ThemeEntryError::NotPlayable { name: "Block".into(), pos: Position::new(0, 2) })
+    /// );
+    /// ```
+    pub fn add_theme_entry(&mut self, entry: ThemeEntry) -> Result<(), ThemeEntryError> {
+        if entry.positions.is_empty() {
+            return Err(ThemeEntryError::Empty { name: entry.name });
+        }
+
+        if let Some(&pos) = entry
+            .positions
+            .iter()
+            .find(|&&pos| self.squares.get_fill(pos).is_none())
+        {
+            return Err(ThemeEntryError::NotPlayable {
+                name: entry.name,
+                pos,
+            });
+        }
+
+        self.theme_entries.push(entry);
+        Ok(())
+    }
+
+    /// Remove the theme entry with the given name, returning it if it existed
+    pub fn remove_theme_entry(&mut self, name: &str) -> Option<ThemeEntry> {
+        let idx = self
+            .theme_entries
+            .iter()
+            .position(|entry| entry.name == name)?;
+
+        Some(self.theme_entries.remove(idx))
+    }
+
+    /// Theme entries that the square at the given position is part of, e.g. to highlight them while rendering
+    pub fn theme_entries_at(&self, pos: Position) -> impl Iterator<Item = &ThemeEntry> {
+        self.theme_entries
+            .iter()
+            .filter(move |entry| entry.contains(pos))
+    }
+}
@@ -0,0 +1,297 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use puzzled_core::{Direction, Position};
+
+use crate::{ClueDirection, Clues, Crossword};
+
+/// Direction in which the across [clues](crate::Clue) of a [crossword](Crossword) are read
+///
+/// Crosswords in right-to-left scripts such as Hebrew and Arabic have their across answers run from right to left.
+/// Hence every across clue starts at the rightmost square of its run and squares are numbered from right to left within each row.
+/// Down clues are read from top to bottom regardless of the writing direction.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WritingDirection {
+    /// Left to right, as in English
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "ltr"))]
+    LeftToRight,
+
+    /// Right to left, as in Hebrew and Arabic
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    RightToLeft,
+}
+
+impl WritingDirection {
+    pub fn is_ltr(&self) -> bool {
+        matches!(self, Self::LeftToRight)
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, Self::RightToLeft)
+    }
+
+    /// Direction in the grid in which a clue in the given direction is read
+    /// ```
+    /// use puzzled::crossword::{ClueDirection, Direction, WritingDirection};
+    ///
+    /// assert_eq!(WritingDirection::RightToLeft.step(ClueDirection::Across), Direction::Left);
+    /// assert_eq!(WritingDirection::RightToLeft.step(ClueDirection::Down), Direction::Down);
+    /// ```
+    pub fn step(&self, direction: ClueDirection) -> Direction {
+        match (self, direction) {
+            (Self::RightToLeft, ClueDirection::Across) => Direction::Left,
+            _ => direction.into(),
+        }
+    }
+
+    /// Compare positions in reading order, i.e. by row and then by column in the writing direction
+    pub fn cmp_positions(&self, a: Position, b: Position) -> Ordering {
+        let order = a.row.cmp(&b.row);
+
+        match self {
+            Self::LeftToRight => order.then(a.col.cmp(&b.col)),
+            Self::RightToLeft => order.then(b.col.cmp(&a.col)),
+        }
+    }
+
+    /// Every position of a grid of the given size in reading order
+    pub fn positions(&self, rows: usize, cols: usize) -> impl Iterator<Item = Position> + use<> {
+        let writing = *self;
+
+        (0..rows).flat_map(move |row| {
+            (0..cols).map(move |col| match writing {
+                Self::LeftToRight => Position::new(row, col),
+                Self::RightToLeft => Position::new(row, cols - 1 - col),
+            })
+        })
+    }
+}
+
+impl fmt::Display for WritingDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::LeftToRight => "ltr",
+            Self::RightToLeft => "rtl",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for WritingDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "ltr" => Ok(Self::LeftToRight),
+            "rtl" => Ok(Self::RightToLeft),
+            _ => Err(format!("Writing direction '{s}' should be ltr or rtl")),
+        }
+    }
+}
+
+/// # Writing direction
+impl Crossword {
+    pub fn writing(&self) -> WritingDirection {
+        self.writing
+    }
+
+    pub fn with_writing(mut self, writing: WritingDirection) -> Self {
+        self.set_writing(writing);
+        self
+    }
+
+    /// Change the writing direction, which moves the start of every across clue to the other end of its squares
+    ///
+    /// Clues keep their text and cover the same squares, but are renumbered in the new reading order.
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection::*, Position, WritingDirection};
+    ///
+    /// let mut puzzle = crossword!(
+    ///     [C A B]
+    ///     [O R E]
+    ///     [T E N]
+    ///     - A: "Taxi"
+    ///     - A: "Mineral"
+    ///     - A: "Number of toes"
+    ///     - D: "Baby bed"
+    ///     - D: "Exist"
+    ///     - D: "Wading bird"
+    /// );
+    /// puzzle.set_writing(WritingDirection::RightToLeft);
+    ///
+    /// let taxi = &puzzle.clues()[&(1, Across).into()];
+    /// assert_eq!(taxi.text(), "Taxi");
+    /// assert_eq!(taxi.start(), Position::new(0, 2));
+    ///
+    /// let bird = &puzzle.clues()[&(1, Down).into()];
+    /// assert_eq!(bird.text(), "Wading bird");
+    /// ```
+    pub fn set_writing(&mut self, writing: WritingDirection) {
+        self.writing = writing;
+
+        let clues: Vec<_> = self.clues.values().cloned().collect();
+        self.clues = Clues::default();
+
+        for clue in clues {
+            let clue = clue.written(writing);
+            self.clues.insert(clue.id(), clue);
+        }
+
+        // Numbers follow the reading order, which has changed
+        self.set_numbering(self.numbering);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_io::TxtPuzzle;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection::*, ClueSpec, CrosswordBuilder, crossword};
+
+    fn puzzle() -> Crossword {
+        crossword!(
+            [ה ח מ ש]
+            [. ב א .]
+        )
+    }
+
+    fn clues(crossword: &Crossword) -> Vec<(u8, ClueDirection, Vec<Position>)> {
+        crossword
+            .clues()
+            .values()
+            .map(|clue| (clue.num(), clue.direction(), clue.positions().collect()))
+            .collect()
+    }
+
+    fn squares<const N: usize>(positions: [(usize, usize); N]) -> Vec<Position> {
+        positions.map(Position::from).to_vec()
+    }
+
+    #[rstest]
+    #[case::ltr(WritingDirection::LeftToRight, [(0, 0), (0, 1), (1, 0), (1, 1)])]
+    #[case::rtl(WritingDirection::RightToLeft, [(0, 1), (0, 0), (1, 1), (1, 0)])]
+    fn positions(#[case] writing: WritingDirection, #[case] expected: [(usize, usize); 4]) {
+        let positions: Vec<_> = writing.positions(2, 2).collect();
+        let expected = expected.map(Position::from);
+
+        assert_eq!(positions, expected);
+        assert!(positions.is_sorted_by(|&a, &b| writing.cmp_positions(a, b) == Ordering::Less));
+    }
+
+    #[test]
+    fn place() {
+        let puzzle = puzzle();
+        let mut puzzle = Crossword::from_squares(puzzle.squares().clone(), puzzle.meta().clone())
+            .with_writing(WritingDirection::RightToLeft);
+
+        puzzle.insert_clues([ClueSpec::across("Joy"), ClueSpec::down("Right column")]);
+        puzzle.insert_clues([
+            ClueSpec::across("Father"),
+            ClueSpec::down("Second column"),
+            ClueSpec::down("Third column"),
+            ClueSpec::down("Left column"),
+        ]);
+
+        assert_eq!(
+            clues(&puzzle),
+            [
+                (1, Across, squares([(0, 3), (0, 2), (0, 1), (0, 0)])),
+                (1, Down, squares([(0, 3)])),
+                (2, Down, squares([(0, 2), (1, 2)])),
+                (3, Down, squares([(0, 1), (1, 1)])),
+                (4, Down, squares([(0, 0)])),
+                (5, Across, squares([(1, 2), (1, 1)])),
+            ]
+        );
+
+        let answer: String = puzzle.clues()[&(1, Across).into()]
+            .positions()
+            .filter_map(|pos| puzzle.squares().get_fill(pos))
+            .filter_map(|cell| cell.solution.as_ref())
+            .map(|solution| solution.primary().to_string())
+            .collect();
+        assert_eq!(answer, "שמחה");
+    }
+
+    #[test]
+    fn flip() {
+        let mut puzzle = crossword!(
+            [C A B]
+            [O R E]
+            - A: "CAB"
+            - A: "ORE"
+            - D: "CO"
+            - D: "AR"
+            - D: "BE"
+        );
+        let original = puzzle.clone();
+
+        puzzle.set_writing(WritingDirection::RightToLeft);
+        assert_eq!(
+            clues(&puzzle),
+            [
+                (1, Across, squares([(0, 2), (0, 1), (0, 0)])),
+                (1, Down, squares([(0, 2), (1, 2)])),
+                (2, Down, squares([(0, 1), (1, 1)])),
+                (3, Down, squares([(0, 0), (1, 0)])),
+                (4, Across, squares([(1, 2), (1, 1), (1, 0)])),
+            ]
+        );
+        assert_eq!(puzzle.clues()[&(1, Down).into()].text(), "BE");
+        assert_eq!(puzzle.clues()[&(4, Across).into()].text(), "ORE");
+
+        puzzle.set_writing(WritingDirection::LeftToRight);
+        assert_eq!(puzzle, original);
+    }
+
+    #[test]
+    fn builder() {
+        let mut builder = CrosswordBuilder::new(2, 3).with_writing(WritingDirection::RightToLeft);
+        builder.set_black(Position::new(1, 2));
+
+        let crossword = builder.build();
+
+        assert_eq!(crossword.writing(), WritingDirection::RightToLeft);
+        assert_eq!(
+            clues(&crossword),
+            [
+                (1, Across, squares([(0, 2), (0, 1), (0, 0)])),
+                (1, Down, squares([(0, 2)])),
+                (2, Down, squares([(0, 1), (1, 1)])),
+                (3, Down, squares([(0, 0), (1, 0)])),
+                (4, Across, squares([(1, 1), (1, 0)])),
+            ]
+        );
+    }
+
+    #[test]
+    fn text() {
+        let puzzle = crossword!(
+            [ה ח מ ש]
+            [. ב א .]
+            - A: "Joy"
+            - A: "Father"
+            - D: "Right column"
+            - D: "Second column"
+            - D: "Third column"
+            - D: "Left column"
+        )
+        .with_writing(WritingDirection::RightToLeft);
+
+        let text = puzzle.write_text();
+        assert!(text.contains("writing: \"rtl\""));
+        assert_eq!(Crossword::read_text(&text).ok(), Some(puzzle));
+    }
+
+    #[test]
+    fn parse() {
+        for writing in [WritingDirection::LeftToRight, WritingDirection::RightToLeft] {
+            assert_eq!(writing.to_string().to_uppercase().parse(), Ok(writing));
+        }
+        assert!("up".parse::<WritingDirection>().is_err());
+    }
+}
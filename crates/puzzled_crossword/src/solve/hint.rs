@@ -0,0 +1,170 @@
+use std::{cmp::Reverse, fmt};
+
+use puzzled_core::{Position, Solve};
+
+use crate::{Clue, ClueId, Crossword, CrosswordState, Solution};
+
+/// Strategy that decides what a [hint](Hint) should point the solver to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HintStrategy {
+    /// Unsolved clue with the most correct letters from its crossing clues
+    #[default]
+    MostCrossed,
+
+    /// First letter of the longest unsolved clue
+    LongestFirstLetter,
+
+    /// First square whose entry is incorrect
+    FirstIncorrect,
+}
+
+/// Suggestion of what to reveal next in a [crossword](Crossword), see [`Crossword::hint`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    /// Reveal the solution of a single square
+    Square { pos: Position, solution: Solution },
+
+    /// Work on (or reveal) a clue
+    Clue(ClueId),
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Square { pos, solution } => write!(f, "{solution} at {pos}"),
+            Self::Clue(id) => write!(f, "Clue {id}"),
+        }
+    }
+}
+
+impl Crossword {
+    /// Suggest what to reveal next according to the given [strategy](HintStrategy)
+    ///
+    /// Returns [`None`] if the puzzle is solved or the strategy has nothing to suggest
+    /// ```
+    /// use puzzled::crossword::{crossword, ClueDirection, ClueId, CrosswordState, Hint, HintStrategy, Position, Solve};
+    ///
+    /// let puzzle = crossword! (
+    ///     [A B]
+    ///     [C D]
+    ///     - A: "AB"
+    ///     - D: "AC"
+    ///     - D: "BD"
+    ///     - A: "CD"
+    /// );
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// for pos in puzzle.squares().positions() {
+    ///     state.clear(&pos);
+    /// }
+    ///
+    /// // Filling in the top row gives the down clues their first letter
+    /// for (col, letter) in ["A", "B"].into_iter().enumerate() {
+    ///     state.enter(&Position::new(0, col), letter.into());
+    /// }
+    ///
+    /// let hint = puzzle.hint(&state, HintStrategy::MostCrossed);
+    /// assert_eq!(hint, Some(Hint::Clue(ClueId::from((1, ClueDirection::Down)))));
+    ///
+    /// let hint = puzzle.hint(&state, HintStrategy::LongestFirstLetter);
+    /// assert_eq!(hint, Some(Hint::Square { pos: Position::new(1, 0), solution: "C".into() }));
+    /// ```
+    pub fn hint(&self, state: &CrosswordState, strategy: HintStrategy) -> Option<Hint> {
+        match strategy {
+            HintStrategy::MostCrossed => most_crossed(self, state),
+            HintStrategy::LongestFirstLetter => longest_first_letter(self, state),
+            HintStrategy::FirstIncorrect => first_incorrect(self, state),
+        }
+    }
+}
+
+fn unsolved_clues<'a>(
+    puzzle: &'a Crossword,
+    state: &'a CrosswordState,
+) -> impl Iterator<Item = &'a Clue> {
+    puzzle
+        .clues()
+        .values()
+        .filter(|clue| !clue.positions().all(|pos| state.is_correct(pos)))
+}
+
+fn most_crossed(puzzle: &Crossword, state: &CrosswordState) -> Option<Hint> {
+    // Prefer clues with the most correct letters, then the fewest missing letters
+    let (_, clue) = unsolved_clues(puzzle, state)
+        .map(|clue| {
+            let correct = clue
+                .positions()
+                .filter(|&pos| state.is_correct(pos))
+                .count();
+            let missing = clue.len() as usize - correct;
+
+            ((Reverse(correct), missing), clue)
+        })
+        .min_by_key(|(rank, _)| *rank)?;
+
+    Some(Hint::Clue(clue.id()))
+}
+
+fn longest_first_letter(puzzle: &Crossword, state: &CrosswordState) -> Option<Hint> {
+    let clue = unsolved_clues(puzzle, state).min_by_key(|clue| Reverse(clue.len()))?;
+
+    let pos = clue.positions().find(|&pos| !state.is_correct(pos))?;
+    let solution = state.solution(&pos)?.clone();
+
+    Some(Hint::Square { pos, solution })
+}
+
+fn first_incorrect(puzzle: &Crossword, state: &CrosswordState) -> Option<Hint> {
+    let pos = puzzle.squares().positions().find(|&pos| {
+        state.entry(&pos).is_some() && state.solution(&pos).is_some() && !state.is_correct(pos)
+    })?;
+    let solution = state.solution(&pos)?.clone();
+
+    Some(Hint::Square { pos, solution })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection, crossword};
+
+    fn state(puzzle: &Crossword, entries: &[(usize, usize, &str)]) -> CrosswordState {
+        let mut state = CrosswordState::from(puzzle);
+
+        for pos in puzzle.squares().positions() {
+            state.clear(&pos);
+        }
+        for &(row, col, entry) in entries {
+            state.enter(&Position::new(row, col), entry.into());
+        }
+
+        state
+    }
+
+    #[rstest]
+    #[case::empty(HintStrategy::MostCrossed, &[], Some(Hint::Clue((1, ClueDirection::Across).into())))]
+    #[case::crossed(HintStrategy::MostCrossed, &[(0, 0, "A"), (1, 0, "C")], Some(Hint::Clue((1, ClueDirection::Across).into())))]
+    #[case::down(HintStrategy::MostCrossed, &[(0, 0, "A"), (0, 1, "B"), (1, 1, "D")], Some(Hint::Clue((1, ClueDirection::Down).into())))]
+    #[case::longest(HintStrategy::LongestFirstLetter, &[(0, 0, "A")], Some(Hint::Square { pos: Position::new(0, 1), solution: "B".into() }))]
+    #[case::no_incorrect(HintStrategy::FirstIncorrect, &[(0, 0, "A")], None)]
+    #[case::incorrect(HintStrategy::FirstIncorrect, &[(0, 0, "A"), (1, 1, "X")], Some(Hint::Square { pos: Position::new(1, 1), solution: "D".into() }))]
+    #[case::solved(HintStrategy::MostCrossed, &[(0, 0, "A"), (0, 1, "B"), (1, 0, "C"), (1, 1, "D")], None)]
+    fn hint(
+        #[case] strategy: HintStrategy,
+        #[case] entries: &[(usize, usize, &str)],
+        #[case] expected: Option<Hint>,
+    ) {
+        let puzzle = crossword!(
+            [A B]
+            [C D]
+            - A: "AB"
+            - D: "AC"
+            - D: "BD"
+            - A: "CD"
+        );
+        let state = state(&puzzle, entries);
+
+        assert_eq!(puzzle.hint(&state, strategy), expected);
+    }
+}
@@ -0,0 +1,13 @@
+mod hint;
+mod navigator;
+mod proof;
+mod replay;
+mod session;
+mod suggest;
+
+pub use hint::*;
+pub use navigator::*;
+pub use proof::*;
+pub use replay::*;
+pub use session::*;
+pub use suggest::*;
@@ -0,0 +1,173 @@
+use puzzled_core::Position;
+
+use crate::{Clue, ClueDirection, Crossword};
+
+/// Cursor of a player in a [crossword](Crossword), which points at a square and the direction in which it is being filled
+///
+/// The navigator only moves between playable squares, and keeps its direction when it moves such that the [current clue](Self::clue) follows the cursor.
+/// ```
+/// use puzzled::crossword::{crossword, ClueDirection::*, Navigator, Position};
+///
+/// let puzzle = crossword!(
+///     [A B]
+///     [C .]
+///     - A: "AB"
+///     - D: "AC"
+/// );
+/// let mut nav = Navigator::new(&puzzle);
+/// assert_eq!(nav.clue(&puzzle).map(|clue| clue.id()), Some((1, Across).into()));
+///
+/// assert!(nav.advance(&puzzle));
+/// assert_eq!(nav.position(), Position::new(0, 1));
+/// assert!(!nav.advance(&puzzle));
+///
+/// assert!(!nav.move_to(&puzzle, Position::new(1, 1)));
+/// assert!(nav.move_to(&puzzle, Position::new(1, 0)));
+/// nav.toggle_direction();
+/// assert_eq!(nav.clue(&puzzle).map(|clue| clue.id()), Some((1, Down).into()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Navigator {
+    pos: Position,
+    direction: ClueDirection,
+}
+
+impl Navigator {
+    /// Start at the first across clue of the crossword, or the top left square if it has no clues
+    pub fn new(crossword: &Crossword) -> Self {
+        let pos = crossword
+            .clues()
+            .iter_across()
+            .next()
+            .map(Clue::start)
+            .unwrap_or_default();
+
+        Self::at(pos, ClueDirection::Across)
+    }
+
+    pub fn at(pos: Position, direction: ClueDirection) -> Self {
+        Self { pos, direction }
+    }
+
+    pub fn position(&self) -> Position {
+        self.pos
+    }
+
+    pub fn direction(&self) -> ClueDirection {
+        self.direction
+    }
+
+    /// Clue that runs through the cursor in its direction
+    pub fn clue<'a>(&self, crossword: &'a Crossword) -> Option<&'a Clue> {
+        crossword.clues().get_clue(self.pos, self.direction)
+    }
+
+    /// Switch between filling across and down
+    pub fn toggle_direction(&mut self) {
+        self.direction = match self.direction {
+            ClueDirection::Across => ClueDirection::Down,
+            ClueDirection::Down => ClueDirection::Across,
+        };
+    }
+
+    pub fn set_direction(&mut self, direction: ClueDirection) {
+        self.direction = direction;
+    }
+
+    /// Move the cursor to the position, returning whether it holds a playable square
+    pub fn move_to(&mut self, crossword: &Crossword, pos: Position) -> bool {
+        if crossword.squares().get_fill(pos).is_none() {
+            return false;
+        }
+
+        self.pos = pos;
+        true
+    }
+
+    /// Move to the next square of the [current clue](Self::clue), e.g. after entering a letter.
+    /// Returns whether the cursor moved, which is not the case at the end of the clue
+    pub fn advance(&mut self, crossword: &Crossword) -> bool {
+        self.step(crossword, 1)
+    }
+
+    /// Move to the previous square of the [current clue](Self::clue), e.g. after clearing a letter.
+    /// Returns whether the cursor moved, which is not the case at the start of the clue
+    pub fn retreat(&mut self, crossword: &Crossword) -> bool {
+        self.step(crossword, -1)
+    }
+
+    fn step(&mut self, crossword: &Crossword, offset: isize) -> bool {
+        let Some(clue) = self.clue(crossword) else {
+            return false;
+        };
+
+        let positions: Vec<_> = clue.positions().collect();
+        let next = positions
+            .iter()
+            .position(|&pos| pos == self.pos)
+            .and_then(|idx| idx.checked_add_signed(offset))
+            .and_then(|idx| positions.get(idx));
+
+        match next {
+            Some(&pos) => {
+                self.pos = pos;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{WritingDirection, crossword};
+
+    #[rstest]
+    #[case::across(
+        ClueDirection::Across,
+        WritingDirection::LeftToRight,
+        Position::new(0, 1)
+    )]
+    #[case::down(
+        ClueDirection::Down,
+        WritingDirection::LeftToRight,
+        Position::new(1, 0)
+    )]
+    #[case::rtl(
+        ClueDirection::Across,
+        WritingDirection::RightToLeft,
+        Position::new(0, 0)
+    )]
+    fn advance(
+        #[case] direction: ClueDirection,
+        #[case] writing: WritingDirection,
+        #[case] expected: Position,
+    ) {
+        let puzzle = crossword!(
+            [A B]
+            [C D]
+            - A: "AB"
+            - D: "AC"
+            - D: "BD"
+            - A: "CD"
+        )
+        .with_writing(writing);
+
+        let start = puzzle
+            .clues()
+            .iter_direction(direction)
+            .next()
+            .unwrap()
+            .start();
+        let mut nav = Navigator::at(start, direction);
+
+        assert!(nav.advance(&puzzle));
+        assert_eq!(nav.position(), expected);
+        assert!(nav.retreat(&puzzle));
+        assert_eq!(nav.position(), start);
+        assert!(!nav.retreat(&puzzle));
+    }
+}
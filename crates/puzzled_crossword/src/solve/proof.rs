@@ -0,0 +1,359 @@
+use std::time::Duration;
+
+use puzzled_core::{CellStyle, Position, Solve, StableHasher, Timer, TimerState};
+
+use crate::{Crossword, CrosswordState, ReplayAction, ReplayEvent, Solution, SolveSession};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProofError {
+    #[error("Proof has {found} bytes, but expected {expected}")]
+    InvalidLength { found: usize, expected: usize },
+
+    #[error("Proof has version {0}, which is not supported")]
+    UnsupportedVersion(u8),
+
+    #[error("Proof does not match its checksum")]
+    InvalidChecksum,
+
+    #[error("Event #{0} of the proof is malformed")]
+    InvalidEvent(usize),
+
+    #[error("Proof was made for a different puzzle")]
+    MismatchedPuzzle,
+
+    #[error("Proof was made for a different nonce than the one that was issued")]
+    MismatchedNonce,
+
+    #[error("Proof has actions without a time, which cannot be verified")]
+    Untimed,
+
+    #[error("Actions of the proof cannot be replayed on the puzzle")]
+    InvalidReplay,
+
+    #[error("Proof does not match the replay of its actions")]
+    MismatchedReplay,
+
+    #[error("Proof does not hold the solution of the puzzle")]
+    Unsolved,
+
+    #[error("Proof cannot be verified while the solution of the puzzle is locked")]
+    Locked,
+}
+
+/// Summary of a finished [solving session](crate::SolveSession) that a server can [verify](crate::SolveSession::verify_proof), e.g. to accept a solve onto a leaderboard
+///
+/// Next to the summary, the proof holds the [replay](crate::SolveSession::export_replay) of the session.
+/// Rather than trusting the summary, the server plays the replay back on a fresh state of the puzzle and only accepts the proof if it solves the puzzle and results in the same summary.
+/// The proof is also bound to a `nonce` that the server issues when the player starts, such that the same proof cannot be submitted twice.
+///
+/// It is exchanged as a blob of [bytes](Self::to_bytes) that ends in a checksum, which only guards against corrupted proofs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveProof {
+    /// [Fingerprint](Crossword::fingerprint) of the puzzle that was solved
+    pub fingerprint: u64,
+
+    /// Value that the server issued for this solve
+    pub nonce: u64,
+
+    /// Hash of the final entries, where revealed squares hold their solution
+    pub grid_hash: u64,
+
+    /// Time at which the last action was made, which is stored with millisecond precision
+    pub elapsed: Duration,
+
+    /// Number of squares that were checked
+    pub checks: u32,
+
+    /// Number of squares that were revealed
+    pub reveals: u32,
+
+    /// Every action of the player, which should all have a time to be verified
+    pub replay: Vec<ReplayEvent>,
+}
+
+impl SolveProof {
+    const VERSION: u8 = 2;
+
+    /// Number of bytes of an encoded proof without any events
+    pub const MIN_LEN: usize = 53;
+
+    /// Time that marks an event without a time
+    const UNTIMED: u64 = u64::MAX;
+
+    /// Encode the proof as its version, fields, events and checksum, where every number is little-endian
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::MIN_LEN);
+        bytes.push(Self::VERSION);
+        bytes.extend(self.fingerprint.to_le_bytes());
+        bytes.extend(self.nonce.to_le_bytes());
+        bytes.extend(self.grid_hash.to_le_bytes());
+        bytes.extend((self.elapsed.as_millis() as u64).to_le_bytes());
+        bytes.extend(self.checks.to_le_bytes());
+        bytes.extend(self.reveals.to_le_bytes());
+
+        bytes.extend((self.replay.len() as u32).to_le_bytes());
+        for event in &self.replay {
+            let elapsed = event
+                .elapsed
+                .map_or(Self::UNTIMED, |e| e.as_millis() as u64);
+
+            bytes.extend((event.pos.row as u32).to_le_bytes());
+            bytes.extend((event.pos.col as u32).to_le_bytes());
+            bytes.extend(elapsed.to_le_bytes());
+
+            match &event.action {
+                ReplayAction::Enter(entry) => {
+                    let entry = entry.to_string();
+
+                    bytes.push(0);
+                    bytes.extend((entry.len() as u16).to_le_bytes());
+                    bytes.extend(entry.as_bytes());
+                }
+                ReplayAction::Clear => bytes.push(1),
+                ReplayAction::Reveal => bytes.push(2),
+                ReplayAction::Check => bytes.push(3),
+            }
+        }
+
+        let checksum = checksum(&bytes);
+        bytes.extend(checksum.to_le_bytes());
+
+        bytes
+    }
+
+    /// Decode a proof that was encoded with [`to_bytes`](Self::to_bytes)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {
+        if bytes.len() < Self::MIN_LEN {
+            return Err(ProofError::InvalidLength {
+                found: bytes.len(),
+                expected: Self::MIN_LEN,
+            });
+        }
+        if bytes[0] != Self::VERSION {
+            return Err(ProofError::UnsupportedVersion(bytes[0]));
+        }
+
+        let (data, sum) = bytes.split_at(bytes.len() - 8);
+        if checksum(data).to_le_bytes() != sum {
+            return Err(ProofError::InvalidChecksum);
+        }
+
+        let mut reader = ProofReader { data, idx: 1 };
+        let fingerprint = reader.u64()?;
+        let nonce = reader.u64()?;
+        let grid_hash = reader.u64()?;
+        let elapsed = Duration::from_millis(reader.u64()?);
+        let checks = reader.u32()?;
+        let reveals = reader.u32()?;
+
+        let count = reader.u32()? as usize;
+        let replay = (0..count)
+            .map(|idx| reader.event(idx))
+            .collect::<Result<_, _>>()?;
+
+        // Every byte before the checksum should belong to the proof
+        if reader.idx != data.len() {
+            return Err(ProofError::InvalidLength {
+                found: bytes.len(),
+                expected: reader.idx + 8,
+            });
+        }
+
+        Ok(Self {
+            fingerprint,
+            nonce,
+            grid_hash,
+            elapsed,
+            checks,
+            reveals,
+            replay,
+        })
+    }
+
+    /// Verify that the proof was made for the crossword and nonce, and that its replay solves the crossword with the same summary
+    ///
+    /// The replay is played on a state where only the [initially revealed](CellStyle::INITIALLY_REVEALED) squares are filled in, such that sessions should start from that state to be verified.
+    pub fn verify(&self, crossword: &Crossword, nonce: u64) -> Result<(), ProofError> {
+        if self.fingerprint != crossword.fingerprint() {
+            return Err(ProofError::MismatchedPuzzle);
+        }
+        if self.nonce != nonce {
+            return Err(ProofError::MismatchedNonce);
+        }
+        if crossword.lock_state().is_locked() {
+            return Err(ProofError::Locked);
+        }
+
+        // Actions should have a time that never goes back
+        let mut last = Duration::ZERO;
+        for event in &self.replay {
+            let elapsed = event.elapsed.ok_or(ProofError::Untimed)?;
+            if elapsed < last {
+                return Err(ProofError::InvalidReplay);
+            }
+
+            last = elapsed;
+        }
+
+        let mut session = SolveSession::new(crossword, Self::start_state(crossword));
+        if session.apply_replay(&self.replay) != self.replay.len() {
+            return Err(ProofError::InvalidReplay);
+        }
+        if !session.state().is_solved(crossword) {
+            return Err(ProofError::Unsolved);
+        }
+
+        match session.proof(nonce) == *self {
+            true => Ok(()),
+            false => Err(ProofError::MismatchedReplay),
+        }
+    }
+
+    /// State of a crossword before the player made any action
+    fn start_state(crossword: &Crossword) -> CrosswordState {
+        let mut state = CrosswordState::from(crossword);
+        state.timer = Timer::new(Duration::ZERO, TimerState::Stopped);
+
+        for (pos, cell) in crossword.squares().iter_fills_indexed() {
+            if !cell.style.contains(CellStyle::INITIALLY_REVEALED) {
+                state.clear(&pos);
+            }
+        }
+
+        state
+    }
+
+    /// Hash the entries of a grid in row-major order, where blocks are [`None`] and empty squares hold no entry
+    pub(crate) fn hash_grid<'a, I>(squares: I) -> u64
+    where
+        I: IntoIterator<Item = Option<Option<&'a Solution>>>,
+    {
+        let mut hasher = StableHasher::new();
+
+        for square in squares {
+            match square {
+                Some(entry) => {
+                    let entry = entry.map(ToString::to_string);
+                    hasher.write_str(&entry.unwrap_or_default().to_uppercase());
+                }
+                None => hasher.write_block(),
+            }
+        }
+
+        hasher.finish()
+    }
+}
+
+/// Reads the fields of an encoded proof in order
+struct ProofReader<'a> {
+    data: &'a [u8],
+    idx: usize,
+}
+
+impl<'a> ProofReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ProofError> {
+        let bytes = self
+            .data
+            .get(self.idx..self.idx + len)
+            .ok_or(ProofError::InvalidLength {
+                found: self.data.len() + 8,
+                expected: self.idx + len + 8,
+            })?;
+
+        self.idx += len;
+        Ok(bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, ProofError> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().expect("Took 8 bytes")))
+    }
+
+    fn u32(&mut self) -> Result<u32, ProofError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().expect("Took 4 bytes")))
+    }
+
+    fn u16(&mut self) -> Result<u16, ProofError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes(bytes.try_into().expect("Took 2 bytes")))
+    }
+
+    fn event(&mut self, idx: usize) -> Result<ReplayEvent, ProofError> {
+        let row = self.u32()? as usize;
+        let col = self.u32()? as usize;
+        let elapsed = self.u64()?;
+
+        let action = match self.take(1)?[0] {
+            0 => {
+                let len = self.u16()? as usize;
+                let entry = std::str::from_utf8(self.take(len)?)
+                    .map_err(|_| ProofError::InvalidEvent(idx))?;
+
+                if entry.is_empty() {
+                    return Err(ProofError::InvalidEvent(idx));
+                }
+
+                ReplayAction::Enter(Solution::from(entry))
+            }
+            1 => ReplayAction::Clear,
+            2 => ReplayAction::Reveal,
+            3 => ReplayAction::Check,
+            _ => return Err(ProofError::InvalidEvent(idx)),
+        };
+
+        let event = ReplayEvent::new(Position::new(row, col), action);
+        Ok(match elapsed {
+            SolveProof::UNTIMED => event,
+            elapsed => event.with_elapsed(Duration::from_millis(elapsed)),
+        })
+    }
+}
+
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write(bytes);
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn proof() -> SolveProof {
+        SolveProof {
+            fingerprint: 0x0123_4567_89ab_cdef,
+            nonce: 7,
+            grid_hash: 42,
+            elapsed: Duration::from_millis(83_250),
+            checks: 3,
+            reveals: 1,
+            replay: vec![
+                ReplayEvent::new(Position::new(0, 1), ReplayAction::Enter("DEF".into()))
+                    .with_elapsed(Duration::from_millis(1_500)),
+                ReplayEvent::new(Position::new(2, 0), ReplayAction::Check),
+            ],
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        let bytes = proof().to_bytes();
+
+        assert_eq!(SolveProof::from_bytes(&bytes), Ok(proof()));
+    }
+
+    #[rstest]
+    #[case::short(&|bytes: &mut Vec<u8>| bytes.truncate(10), ProofError::InvalidLength { found: 10, expected: SolveProof::MIN_LEN })]
+    #[case::version(&|bytes: &mut Vec<u8>| bytes[0] = 1, ProofError::UnsupportedVersion(1))]
+    #[case::tampered(&|bytes: &mut Vec<u8>| bytes[25] ^= 1, ProofError::InvalidChecksum)]
+    fn invalid_bytes(#[case] tamper: &dyn Fn(&mut Vec<u8>), #[case] err: ProofError) {
+        let mut bytes = proof().to_bytes();
+        tamper(&mut bytes);
+
+        assert_eq!(SolveProof::from_bytes(&bytes), Err(err));
+    }
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use puzzled_core::Position;
+
+use crate::Solution;
+
+/// Action of the player on a single square during a [solving session](crate::SolveSession)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ReplayAction {
+    /// [Enter](puzzled_core::Solve::enter) a solution into the square
+    Enter(Solution),
+
+    /// [Clear](puzzled_core::Solve::clear) the entry of the square
+    Clear,
+
+    /// [Reveal](puzzled_core::Solve::reveal) the solution of the square
+    Reveal,
+
+    /// [Check](puzzled_core::Solve::check) the entry of the square
+    Check,
+}
+
+/// [Action](ReplayAction) of a [solving session](crate::SolveSession) that can be [replayed](crate::SolveSession::apply_replay), e.g. to animate how a puzzle was solved
+///
+/// With the `serde` feature, the time of the event is exported in milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayEvent {
+    pub pos: Position,
+    pub action: ReplayAction,
+
+    /// [Elapsed](puzzled_core::Timer::elapsed) time at which the action happened, if the session [recorded it](crate::SolveSession::without_timestamps)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "millis", skip_serializing_if = "Option::is_none")
+    )]
+    pub elapsed: Option<Duration>,
+}
+
+impl ReplayEvent {
+    pub fn new(pos: Position, action: ReplayAction) -> Self {
+        Self {
+            pos,
+            action,
+            elapsed: None,
+        }
+    }
+
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = Some(elapsed);
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+mod millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(elapsed: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        elapsed
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let millis = Option::<u64>::deserialize(deserializer)?;
+
+        Ok(millis.map(Duration::from_millis))
+    }
+}
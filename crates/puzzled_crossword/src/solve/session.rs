@@ -0,0 +1,720 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use puzzled_core::{Grid, Position, Solve, Timer, TimerState};
+
+use crate::{
+    CheckPolicy, ClueId, Crossword, CrosswordState, ProofError, PuzzleEvent, ReplayAction,
+    ReplayEvent, Solution, SolveProof,
+};
+
+/// Playthrough of a [crossword](Crossword) that keeps track of what the player did and when
+///
+/// The session is played like any other [state](CrosswordState) through [`Solve`], while every [event](PuzzleEvent) is recorded with the [elapsed](puzzled_core::Timer::elapsed) time of the state timer.
+/// Afterwards, the records are summarized into [statistics](SolveStats) to analyze how the puzzle was solved.
+/// The actions of the player can also be [exported](Self::export_replay) to replay the session elsewhere.
+/// ```
+/// use std::time::Duration;
+/// use puzzled::crossword::{crossword, ClueDirection::*, CrosswordState, Position, Solution, SolveSession, Solve, Timer, TimerState};
+///
+/// let puzzle = crossword!([A B] - A: "AB");
+/// let mut state = CrosswordState::from(&puzzle);
+/// state.clear(&Position::new(0, 0));
+/// state.clear(&Position::new(0, 1));
+/// state.timer = Timer::new(Duration::ZERO, TimerState::Stopped);
+///
+/// let mut session = SolveSession::new(&puzzle, state);
+/// session.enter(&Position::new(0, 0), Solution::Letter('A'));
+///
+/// // Come back to the puzzle half a minute later
+/// session.state_mut().timer = Timer::new(Duration::from_secs(30), TimerState::Stopped);
+/// session.enter(&Position::new(0, 1), Solution::Letter('C'));
+/// session.check(&Position::new(0, 1));
+/// session.reveal(&Position::new(0, 1));
+///
+/// let stats = session.stats();
+/// assert_eq!(stats.errors, 1);
+/// assert_eq!((stats.checks, stats.reveals), (1, 1));
+/// assert_eq!(stats.clue_times[&(1, Across).into()], Duration::from_secs(30));
+/// ```
+#[derive(Debug)]
+pub struct SolveSession<'a> {
+    crossword: &'a Crossword,
+    state: CrosswordState,
+    records: Vec<(Duration, PuzzleEvent)>,
+    replay: Vec<ReplayEvent>,
+    timestamps: bool,
+    checks: usize,
+    errors: usize,
+}
+
+impl<'a> SolveSession<'a> {
+    pub fn new(crossword: &'a Crossword, state: CrosswordState) -> Self {
+        Self {
+            crossword,
+            state,
+            records: Vec::new(),
+            replay: Vec::new(),
+            timestamps: true,
+            checks: 0,
+            errors: 0,
+        }
+    }
+
+    /// Play the session with a different [check policy](CheckPolicy) than the one of the crossword
+    ///
+    /// Entries that do not match their solution are counted as [errors](SolveStats::errors) regardless of the policy
+    pub fn with_check_policy(mut self, check: CheckPolicy) -> Self {
+        self.state.set_check_policy(check);
+        self
+    }
+
+    /// Leave out the time of every action from the [replay](Self::export_replay), e.g. when the timing of the player should stay private
+    pub fn without_timestamps(mut self) -> Self {
+        self.timestamps = false;
+        self
+    }
+
+    pub fn state(&self) -> &CrosswordState {
+        &self.state
+    }
+
+    /// Mutable access to the state, e.g. to pause its timer
+    ///
+    /// Changes made directly to the state are not recorded by the session
+    pub fn state_mut(&mut self) -> &mut CrosswordState {
+        &mut self.state
+    }
+
+    pub fn into_state(self) -> CrosswordState {
+        self.state
+    }
+
+    /// Events that happened during the session, together with the time they happened at
+    pub fn records(&self) -> &[(Duration, PuzzleEvent)] {
+        &self.records
+    }
+
+    /// Time at which the square at the position was last entered, if it was entered during the session and timestamps are recorded
+    pub fn entered_at(&self, pos: Position) -> Option<Duration> {
+        self.replay
+            .iter()
+            .rev()
+            .find(|event| event.pos == pos && matches!(event.action, ReplayAction::Enter(_)))
+            .and_then(|event| event.elapsed)
+    }
+
+    /// Every action of the player that changed or checked a square, in the order they happened
+    /// ```
+    /// use std::time::Duration;
+    /// use puzzled::crossword::{crossword, CrosswordState, Position, ReplayAction, Solution, SolveSession, Solve, Timer, TimerState};
+    ///
+    /// let puzzle = crossword!([A B] - A: "AB");
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// state.clear(&Position::new(0, 1));
+    /// state.timer = Timer::new(Duration::from_secs(4), TimerState::Stopped);
+    ///
+    /// let mut session = SolveSession::new(&puzzle, state);
+    /// session.enter(&Position::new(0, 1), Solution::Letter('B'));
+    ///
+    /// let replay = session.export_replay();
+    /// assert_eq!(replay[0].action, ReplayAction::Enter(Solution::Letter('B')));
+    /// assert_eq!(replay[0].elapsed, Some(Duration::from_secs(4)));
+    ///
+    /// // Play the session back on a fresh state
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// state.clear(&Position::new(0, 1));
+    ///
+    /// let mut other = SolveSession::new(&puzzle, state);
+    /// assert_eq!(other.apply_replay(&replay), 1);
+    /// assert_eq!(other.stats(), session.stats());
+    /// ```
+    pub fn export_replay(&self) -> Vec<ReplayEvent> {
+        self.replay.clone()
+    }
+
+    /// Perform the actions of a [replay](Self::export_replay) as if the player made them, returning how many of them took effect
+    ///
+    /// Before every action with a time, the timer of the state is [stopped](TimerState::Stopped) at that time, such that the records match those of the original session.
+    /// Actions without a time happen at the current time of the timer.
+    /// Afterwards, the timer continues from the time of the last action in the state it was in before the replay.
+    pub fn apply_replay(&mut self, events: &[ReplayEvent]) -> usize {
+        let timer_state = self.state.timer.state();
+
+        let applied = events
+            .iter()
+            .filter(|event| {
+                if let Some(elapsed) = event.elapsed {
+                    self.state.timer = Timer::new(elapsed, TimerState::Stopped);
+                }
+
+                let pos = &event.pos;
+                match &event.action {
+                    ReplayAction::Enter(entry) => self.enter(pos, entry.clone()),
+                    ReplayAction::Clear => self.clear(pos),
+                    ReplayAction::Reveal => self.reveal(pos),
+                    ReplayAction::Check => self.check(pos).is_some(),
+                }
+            })
+            .count();
+
+        if matches!(timer_state, TimerState::Running) {
+            self.state.timer.start();
+        }
+
+        applied
+    }
+
+    /// Summarize the session into a [proof](SolveProof) for the nonce that the server issued, which the server can [verify](Self::verify_proof) by replaying it
+    ///
+    /// The session should start from a state without entries and [record timestamps](Self::without_timestamps) for the proof to be verified.
+    /// ```
+    /// use puzzled::crossword::{crossword, CrosswordState, Position, ProofError, Solution, SolveSession, Solve};
+    ///
+    /// let puzzle = crossword!([A B] - A: "AB");
+    /// let mut state = CrosswordState::from(&puzzle);
+    /// state.clear(&Position::new(0, 0));
+    /// state.clear(&Position::new(0, 1));
+    ///
+    /// let nonce = 0x5eed;
+    /// let mut session = SolveSession::new(&puzzle, state);
+    /// session.enter(&Position::new(0, 0), Solution::Letter('A'));
+    /// session.enter(&Position::new(0, 1), Solution::Letter('C'));
+    ///
+    /// let proof = session.proof(nonce).to_bytes();
+    /// assert_eq!(SolveSession::verify_proof(&puzzle, &proof, nonce), Err(ProofError::Unsolved));
+    ///
+    /// session.reveal(&Position::new(0, 1));
+    ///
+    /// let proof = session.proof(nonce).to_bytes();
+    /// assert_eq!(SolveSession::verify_proof(&puzzle, &proof, 0xbad), Err(ProofError::MismatchedNonce));
+    ///
+    /// let verified = SolveSession::verify_proof(&puzzle, &proof, nonce)?;
+    /// assert_eq!(verified.reveals, 1);
+    /// # Ok::<(), ProofError>(())
+    /// ```
+    pub fn proof(&self, nonce: u64) -> SolveProof {
+        let stats = self.stats();
+        let entries = self.state.map_entries(|solution| solution);
+        let squares = entries
+            .iter()
+            .map(|square| square.as_ref().map(|entry| entry.entry().copied()));
+        let elapsed = self
+            .replay
+            .iter()
+            .rev()
+            .find_map(|event| event.elapsed)
+            .unwrap_or_default();
+
+        SolveProof {
+            fingerprint: self.crossword.fingerprint(),
+            nonce,
+            grid_hash: SolveProof::hash_grid(squares),
+            elapsed,
+            checks: stats.checks as u32,
+            reveals: stats.reveals as u32,
+            replay: self.export_replay(),
+        }
+    }
+
+    /// Decode a [proof](Self::proof) and verify that replaying it solves the crossword for the issued nonce
+    pub fn verify_proof(
+        crossword: &Crossword,
+        proof: &[u8],
+        nonce: u64,
+    ) -> Result<SolveProof, ProofError> {
+        let proof = SolveProof::from_bytes(proof)?;
+        proof.verify(crossword, nonce)?;
+
+        Ok(proof)
+    }
+
+    /// Summarize the session into [statistics](SolveStats)
+    pub fn stats(&self) -> SolveStats {
+        let squares = self.crossword.squares();
+        let mut fill_order = Grid::<Option<u32>>::new(squares.rows(), squares.cols())
+            .expect("Grid has the same size as the crossword");
+        let mut started: BTreeMap<ClueId, Duration> = BTreeMap::new();
+        let mut clue_times = BTreeMap::new();
+        let mut reveals = 0;
+
+        for (elapsed, event) in &self.records {
+            let pos = match event {
+                PuzzleEvent::CellEntered { pos, .. } => Some(*pos),
+                PuzzleEvent::CellRevealed { pos } => {
+                    reveals += 1;
+                    Some(*pos)
+                }
+                PuzzleEvent::ClueCompleted(id) => {
+                    if let Some(start) = started.get(id) {
+                        clue_times
+                            .entry(*id)
+                            .or_insert(elapsed.saturating_sub(*start));
+                    }
+                    None
+                }
+                _ => None,
+            };
+
+            let Some(pos) = pos else {
+                continue;
+            };
+
+            fill_order[pos] = Some(elapsed.as_secs() as u32);
+            for clue in self.crossword.clues().values() {
+                if clue.positions().any(|clue_pos| clue_pos == pos) {
+                    started.entry(clue.id()).or_insert(*elapsed);
+                }
+            }
+        }
+
+        SolveStats {
+            elapsed: self.state.timer.elapsed(),
+            clue_times,
+            checks: self.checks,
+            reveals,
+            errors: self.errors,
+            fill_order,
+        }
+    }
+
+    fn record(&mut self) {
+        let elapsed = self.state.timer.elapsed();
+        let events = self.state.drain_events(self.crossword);
+
+        self.records
+            .extend(events.into_iter().map(|event| (elapsed, event)));
+    }
+
+    /// Record an action that took effect into the replay
+    fn record_action(&mut self, pos: Position, action: ReplayAction) {
+        let mut event = ReplayEvent::new(pos, action);
+        if self.timestamps {
+            event = event.with_elapsed(self.state.timer.elapsed());
+        }
+
+        self.replay.push(event);
+    }
+}
+
+impl Solve<Crossword> for SolveSession<'_> {
+    fn solution(&self, pos: &Position) -> Option<&Solution> {
+        self.state.solution(pos)
+    }
+
+    fn entry(&self, pos: &Position) -> Option<&Solution> {
+        self.state.entry(pos)
+    }
+
+    /// Set the solution of the square, which is not an action of the player and is therefore not recorded in the [replay](Self::export_replay)
+    fn solve(&mut self, pos: &Position, solution: Solution) -> bool {
+        self.state.solve(pos, solution)
+    }
+
+    /// Enter the entry, counting it as an error if the solution is known and the entry does not match it
+    fn enter(&mut self, pos: &Position, entry: Solution) -> bool {
+        let entered = self.state.enter(pos, entry.clone());
+        if entered {
+            self.record_action(*pos, ReplayAction::Enter(entry));
+        }
+        if entered && self.state.lock_state().is_unlocked() && !self.state.is_correct(*pos) {
+            self.errors += 1;
+        }
+
+        self.record();
+        entered
+    }
+
+    fn clear(&mut self, pos: &Position) -> bool {
+        let cleared = self.state.clear(pos);
+        if cleared {
+            self.record_action(*pos, ReplayAction::Clear);
+        }
+
+        self.record();
+
+        cleared
+    }
+
+    fn reveal(&mut self, pos: &Position) -> bool {
+        let revealed = self.state.reveal(pos);
+        if revealed {
+            self.record_action(*pos, ReplayAction::Reveal);
+        }
+
+        self.record();
+
+        revealed
+    }
+
+    fn check(&mut self, pos: &Position) -> Option<bool> {
+        let result = self.state.check(pos);
+        if result.is_some() {
+            self.record_action(*pos, ReplayAction::Check);
+            self.checks += 1;
+        }
+
+        result
+    }
+}
+
+/// Statistics of a [solving session](SolveSession)
+///
+/// With the `serde` feature, the statistics are exported with clues keyed by their [identifier](ClueId) (such as `"1-A"`) and times in seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveStats {
+    /// Time spent on the puzzle at the end of the session
+    pub elapsed: Duration,
+
+    /// Time between the first square of a clue being filled and the clue being completed, for every completed clue
+    pub clue_times: BTreeMap<ClueId, Duration>,
+
+    /// Number of squares that were checked
+    pub checks: usize,
+
+    /// Number of squares that were revealed
+    pub reveals: usize,
+
+    /// Number of entries that did not match their solution
+    pub errors: usize,
+
+    /// Second at which each square was last filled, or [`None`] if it never was
+    pub fill_order: Grid<Option<u32>>,
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use std::collections::BTreeMap;
+
+    use puzzled_core::Grid;
+    use serde::Serialize;
+
+    use crate::SolveStats;
+
+    #[derive(Serialize)]
+    struct SerdeSolveStats<'a> {
+        elapsed: f64,
+        clue_times: BTreeMap<String, f64>,
+        checks: usize,
+        reveals: usize,
+        errors: usize,
+        fill_order: &'a Grid<Option<u32>>,
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    impl Serialize for SolveStats {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            SerdeSolveStats {
+                elapsed: self.elapsed.as_secs_f64(),
+                clue_times: self
+                    .clue_times
+                    .iter()
+                    .map(|(id, time)| (id.to_string(), time.as_secs_f64()))
+                    .collect(),
+                checks: self.checks,
+                reveals: self.reveals,
+                errors: self.errors,
+                fill_order: &self.fill_order,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Cell, ClueDirection::*, Square, crossword};
+
+    fn at(session: &mut SolveSession, secs: u64) {
+        session.state_mut().timer = Timer::new(Duration::from_secs(secs), TimerState::Stopped);
+    }
+
+    #[test]
+    fn stats() {
+        let puzzle = crossword!(
+            [A B]
+            [C .]
+            - A: "AB"
+            - D: "AC"
+        );
+        let mut state = CrosswordState::from(&puzzle);
+        for pos in puzzle.squares().positions() {
+            state.clear(&pos);
+        }
+
+        let mut session = SolveSession::new(&puzzle, state);
+        at(&mut session, 5);
+        session.enter(&Position::new(0, 0), Solution::Letter('A'));
+        at(&mut session, 12);
+        session.enter(&Position::new(1, 0), Solution::Letter('X'));
+        session.check(&Position::new(1, 0));
+        at(&mut session, 20);
+        session.enter(&Position::new(1, 0), Solution::Letter('C'));
+        at(&mut session, 25);
+        session.reveal(&Position::new(0, 1));
+
+        let stats = session.stats();
+        assert_eq!(stats.elapsed, Duration::from_secs(25));
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.checks, 1);
+        assert_eq!(stats.reveals, 1);
+        assert_eq!(
+            stats.clue_times,
+            BTreeMap::from([
+                ((1, Across).into(), Duration::from_secs(20)),
+                ((1, Down).into(), Duration::from_secs(15)),
+            ])
+        );
+        assert_eq!(stats.fill_order[Position::new(0, 0)], Some(5));
+        assert_eq!(stats.fill_order[Position::new(1, 0)], Some(20));
+        assert_eq!(stats.fill_order[Position::new(1, 1)], None);
+    }
+
+    /// Fresh session of a puzzle of which every square is cleared
+    fn cleared<'a>(puzzle: &'a Crossword) -> SolveSession<'a> {
+        let mut state = CrosswordState::from(puzzle);
+        for pos in puzzle.squares().positions() {
+            state.clear(&pos);
+        }
+
+        SolveSession::new(puzzle, state)
+    }
+
+    #[test]
+    fn replay() {
+        let puzzle = crossword!(
+            [A B]
+            [C .]
+            - A: "AB"
+            - D: "AC"
+        );
+
+        let mut session = cleared(&puzzle);
+        at(&mut session, 5);
+        session.enter(&Position::new(0, 0), Solution::Letter('A'));
+        at(&mut session, 12);
+        session.enter(&Position::new(1, 0), Solution::Letter('X'));
+        session.check(&Position::new(1, 0));
+        session.clear(&Position::new(1, 1));
+        at(&mut session, 20);
+        session.enter(&Position::new(1, 0), Solution::Letter('C'));
+        at(&mut session, 25);
+        session.reveal(&Position::new(0, 1));
+
+        let replay = session.export_replay();
+        assert_eq!(replay.len(), 5);
+        assert_eq!(
+            replay[2],
+            ReplayEvent::new(Position::new(1, 0), ReplayAction::Check)
+                .with_elapsed(Duration::from_secs(12))
+        );
+        assert_eq!(
+            session.entered_at(Position::new(1, 0)),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(session.entered_at(Position::new(0, 1)), None);
+
+        let mut other = cleared(&puzzle);
+        assert_eq!(other.apply_replay(&replay), replay.len());
+        assert_eq!(other.records(), session.records());
+        assert_eq!(other.stats(), session.stats());
+        assert!(other.state().diff_state(session.state()).is_empty());
+        assert_eq!(other.export_replay(), replay);
+    }
+
+    #[rstest]
+    #[case::running(TimerState::Running)]
+    #[case::stopped(TimerState::Stopped)]
+    fn replay_keeps_timer(#[case] timer_state: TimerState) {
+        let puzzle = crossword!([A B] - A: "AB");
+        let replay = [
+            ReplayEvent::new(Position::new(0, 0), ReplayAction::Enter("A".into()))
+                .with_elapsed(Duration::from_secs(30)),
+        ];
+
+        let mut session = cleared(&puzzle);
+        session.state_mut().timer = Timer::new(Duration::from_secs(2), timer_state);
+        assert_eq!(session.apply_replay(&replay), 1);
+
+        let timer = &session.state().timer;
+        assert_eq!(timer.state(), timer_state);
+        assert!(timer.elapsed() >= Duration::from_secs(30));
+    }
+
+    #[test]
+    fn replay_without_timestamps() {
+        let puzzle = crossword!([A B] - A: "AB");
+
+        let mut session = cleared(&puzzle).without_timestamps();
+        at(&mut session, 7);
+        session.enter(&Position::new(0, 0), Solution::Letter('A'));
+
+        let replay = session.export_replay();
+        assert_eq!(
+            replay,
+            vec![ReplayEvent::new(
+                Position::new(0, 0),
+                ReplayAction::Enter(Solution::Letter('A'))
+            )]
+        );
+        assert_eq!(session.entered_at(Position::new(0, 0)), None);
+
+        // Untimed actions happen at the current time of the replaying session
+        let mut other = cleared(&puzzle);
+        at(&mut other, 3);
+        other.apply_replay(&replay);
+        assert_eq!(other.stats().fill_order[Position::new(0, 0)], Some(3));
+    }
+
+    #[test]
+    fn proof() {
+        let puzzle = crossword!(
+            [A B]
+            [C .]
+            - A: "AB"
+            - D: "AC"
+        );
+
+        let mut session = cleared(&puzzle);
+        at(&mut session, 61);
+        session.enter(&Position::new(0, 0), Solution::Letter('a'));
+        session.enter(&Position::new(0, 1), Solution::Letter('B'));
+        session.enter(&Position::new(1, 0), Solution::Letter('C'));
+        session.check(&Position::new(1, 0));
+
+        let nonce = 0x5eed;
+        let proof = session.proof(nonce);
+        assert_eq!(
+            (proof.elapsed, proof.checks, proof.reveals),
+            (Duration::from_secs(61), 1, 0)
+        );
+        assert_eq!(
+            SolveSession::verify_proof(&puzzle, &proof.to_bytes(), nonce),
+            Ok(proof.clone())
+        );
+
+        let other = crossword!([A B] [C D]);
+        assert_eq!(
+            SolveSession::verify_proof(&other, &proof.to_bytes(), nonce),
+            Err(ProofError::MismatchedPuzzle)
+        );
+
+        // Summaries that do not match the replay are rejected, even with a valid checksum
+        let mut faster = proof.clone();
+        faster.elapsed = Duration::from_secs(1);
+        assert_eq!(
+            SolveSession::verify_proof(&puzzle, &faster.to_bytes(), nonce),
+            Err(ProofError::MismatchedReplay)
+        );
+
+        let mut rewound = proof.clone();
+        rewound.replay[2].elapsed = Some(Duration::from_secs(1));
+        assert_eq!(
+            rewound.verify(&puzzle, nonce),
+            Err(ProofError::InvalidReplay)
+        );
+
+        session.clear(&Position::new(0, 1));
+        assert_eq!(
+            session.proof(nonce).verify(&puzzle, nonce),
+            Err(ProofError::Unsolved)
+        );
+
+        let mut untimed = cleared(&puzzle).without_timestamps();
+        untimed.enter(&Position::new(0, 0), Solution::Letter('A'));
+        assert_eq!(
+            untimed.proof(nonce).verify(&puzzle, nonce),
+            Err(ProofError::Untimed)
+        );
+    }
+
+    #[test]
+    fn proof_alternatives() {
+        let mut puzzle = crossword!([A B] - A: "AB");
+        puzzle.squares_mut()[Position::new(0, 0)] =
+            Square::new(Cell::new(Some(Solution::alternatives(["A", "O"]))));
+
+        let mut session = cleared(&puzzle);
+        session.enter(&Position::new(0, 0), Solution::Letter('O'));
+        session.enter(&Position::new(0, 1), Solution::Letter('B'));
+
+        let proof = session.proof(1);
+        assert_eq!(
+            SolveSession::verify_proof(&puzzle, &proof.to_bytes(), 1),
+            Ok(proof)
+        );
+    }
+
+    #[rstest]
+    #[case::never(CheckPolicy::Never, false, None)]
+    #[case::on_demand(CheckPolicy::OnDemand, false, Some(false))]
+    #[case::immediate(CheckPolicy::Immediate, true, Some(false))]
+    fn check_policy(
+        #[case] check: CheckPolicy,
+        #[case] marked: bool,
+        #[case] checked: Option<bool>,
+    ) {
+        let puzzle = crossword!([A B] - A: "AB");
+        let mut state = CrosswordState::from(&puzzle);
+        state.clear(&Position::new(0, 1));
+
+        let pos = Position::new(0, 1);
+        let mut session = SolveSession::new(&puzzle, state).with_check_policy(check);
+        session.enter(&pos, Solution::Letter('C'));
+
+        let is_incorrect = |session: &SolveSession| {
+            let entry = session.state().entries.get_fill(pos);
+            entry.is_some_and(|entry| entry.is_incorrect())
+        };
+        assert_eq!(is_incorrect(&session), marked);
+        assert_eq!(session.check(&pos), checked);
+        assert_eq!(is_incorrect(&session), checked.is_some());
+
+        let stats = session.stats();
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.checks, usize::from(checked.is_some()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn export_json() {
+        let puzzle = crossword!([A B] - A: "AB");
+        let mut state = CrosswordState::from(&puzzle);
+        state.clear(&Position::new(0, 1));
+
+        let mut session = SolveSession::new(&puzzle, state);
+        at(&mut session, 3);
+        session.enter(&Position::new(0, 1), Solution::Letter('B'));
+
+        let json = serde_json::to_value(session.stats()).expect("stats are serialized");
+        assert_eq!(json["clue_times"]["1-A"], 0.0);
+        assert_eq!(json["elapsed"], 3.0);
+        assert_eq!(json["errors"], 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn replay_json() {
+        let events = vec![
+            ReplayEvent::new(
+                Position::new(0, 1),
+                ReplayAction::Enter(Solution::Letter('B')),
+            )
+            .with_elapsed(Duration::from_millis(1500)),
+            ReplayEvent::new(Position::new(0, 1), ReplayAction::Check),
+        ];
+
+        let json = serde_json::to_value(&events).expect("replay is serialized");
+        assert_eq!(json[0]["elapsed"], 1500);
+        assert!(json[1].get("elapsed").is_none());
+
+        let parsed: Vec<ReplayEvent> =
+            serde_json::from_value(json).expect("replay is deserialized");
+        assert_eq!(parsed, events);
+    }
+}
@@ -0,0 +1,272 @@
+use std::{cmp::Reverse, collections::BTreeMap, fmt};
+
+use puzzled_core::Solve;
+
+use crate::{ClueId, Crossword, CrosswordState, Solution};
+
+/// Word from a [word list](WordList) together with how desirable it is as an answer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScoredWord {
+    /// Normalized word, consisting of uppercase letters only
+    pub word: String,
+
+    /// Score of the word, where higher scores are preferred
+    pub score: u32,
+}
+
+impl fmt::Display for ScoredWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{};{}", self.word, self.score)
+    }
+}
+
+/// Collection of candidate answers that [suggestions](suggest) are drawn from
+///
+/// Words are normalized by uppercasing them and dropping everything but letters, such that `"ice cream"` is stored as `"ICECREAM"`.
+/// Lists are commonly distributed with one `WORD;SCORE` per line, which can be read with [`WordList::parse`]:
+/// ```
+/// use puzzled::crossword::WordList;
+///
+/// let words = WordList::parse("ice cream;60\nAREA;40\narea;55\nOREO");
+///
+/// assert_eq!(words.len(), 3);
+/// assert_eq!(words.score("icecream"), Some(60));
+/// assert_eq!(words.score("AREA"), Some(55));
+/// assert_eq!(words.score("OREO"), Some(WordList::DEFAULT_SCORE));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordList {
+    // Words are grouped by their length, since only words of a clue's length can fit
+    words: BTreeMap<usize, BTreeMap<String, u32>>,
+}
+
+impl WordList {
+    /// Score of words that are inserted without one
+    pub const DEFAULT_SCORE: u32 = 50;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a word list with one `WORD` or `WORD;SCORE` per line
+    ///
+    /// Blank lines are skipped and invalid scores fall back to the [default score](Self::DEFAULT_SCORE)
+    pub fn parse(text: &str) -> Self {
+        let mut words = Self::new();
+
+        for line in text.lines() {
+            let (word, score) = match line.rsplit_once(';') {
+                Some((word, score)) => (word, score.trim().parse().ok()),
+                None => (line, None),
+            };
+
+            words.insert(word, score.unwrap_or(Self::DEFAULT_SCORE));
+        }
+
+        words
+    }
+
+    /// Insert a word with the given score and return the score it had before
+    ///
+    /// A word that is already in the list keeps the highest of its scores.
+    /// Words without any letters are ignored
+    pub fn insert(&mut self, word: &str, score: u32) -> Option<u32> {
+        let word = normalize(word);
+        if word.is_empty() {
+            return None;
+        }
+
+        let len = word.chars().count();
+        let scores = self.words.entry(len).or_default();
+        let prev = scores.get(&word).copied();
+
+        scores.insert(word, prev.map_or(score, |prev| prev.max(score)));
+        prev
+    }
+
+    /// Score of a word, which is normalized before it is looked up
+    pub fn score(&self, word: &str) -> Option<u32> {
+        let word = normalize(word);
+        let len = word.chars().count();
+
+        self.words.get(&len)?.get(&word).copied()
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.score(word).is_some()
+    }
+
+    /// Number of words in the list
+    pub fn len(&self) -> usize {
+        self.words.values().map(BTreeMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Iterate over the words of the given length in alphabetical order
+    pub fn iter_len(&self, len: usize) -> impl Iterator<Item = ScoredWord> + '_ {
+        self.words.get(&len).into_iter().flat_map(|scores| {
+            scores.iter().map(|(word, &score)| ScoredWord {
+                word: word.clone(),
+                score,
+            })
+        })
+    }
+}
+
+impl<'a> FromIterator<&'a str> for WordList {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut words = Self::new();
+
+        for word in iter {
+            words.insert(word, Self::DEFAULT_SCORE);
+        }
+
+        words
+    }
+}
+
+impl<'a> FromIterator<(&'a str, u32)> for WordList {
+    fn from_iter<I: IntoIterator<Item = (&'a str, u32)>>(iter: I) -> Self {
+        let mut words = Self::new();
+
+        for (word, score) in iter {
+            words.insert(word, score);
+        }
+
+        words
+    }
+}
+
+fn normalize(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Suggest answers from a [word list](WordList) that fit the letters currently entered in a clue
+///
+/// Every square of the clue that holds a [letter](Solution::Letter) restricts the candidates to words with that letter at the same place, ignoring case.
+/// Empty squares and squares holding a [rebus](Solution::Rebus) match any letter.
+/// Candidates are ranked by their score, where ties are broken alphabetically.
+///
+/// Returns no suggestions if the clue does not exist in the puzzle
+/// ```
+/// use puzzled::crossword::{crossword, suggest, ClueDirection, CrosswordState, Position, Solve, WordList};
+///
+/// let puzzle = crossword! (
+///     [C A T]
+///     - A: "Feline"
+/// );
+/// let mut state = CrosswordState::from(&puzzle);
+/// for pos in puzzle.squares().positions() {
+///     state.clear(&pos);
+/// }
+/// state.enter(&Position::new(0, 1), "a".into());
+///
+/// let words = WordList::from_iter([("CAT", 40), ("BAT", 60), ("COT", 90), ("CART", 90)]);
+/// let suggestions = suggest(&puzzle, &state, (1, ClueDirection::Across).into(), &words);
+///
+/// let words: Vec<_> = suggestions.iter().map(|s| s.word.as_str()).collect();
+/// assert_eq!(words, ["BAT", "CAT"]);
+/// ```
+pub fn suggest(
+    puzzle: &Crossword,
+    state: &CrosswordState,
+    id: ClueId,
+    words: &WordList,
+) -> Vec<ScoredWord> {
+    let Some(clue) = puzzle.clues().get(id) else {
+        return Vec::new();
+    };
+
+    let pattern: Vec<_> = clue
+        .positions()
+        .map(|pos| match state.entry(&pos) {
+            Some(Solution::Letter(letter)) => letter.to_uppercase().next(),
+            _ => None,
+        })
+        .collect();
+
+    let mut suggestions: Vec<_> = words
+        .iter_len(pattern.len())
+        .filter(|candidate| {
+            candidate
+                .word
+                .chars()
+                .zip(&pattern)
+                .all(|(letter, expected)| expected.is_none_or(|expected| letter == expected))
+        })
+        .collect();
+
+    // Words are already alphabetical, so a stable sort keeps ties in that order
+    suggestions.sort_by_key(|candidate| Reverse(candidate.score));
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Position;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{ClueDirection, crossword};
+
+    #[rstest]
+    #[case::empty(&[], (1, ClueDirection::Across), &["CAR", "ACE", "COT"])]
+    #[case::letter(&[(0, 0, "C")], (1, ClueDirection::Across), &["CAR", "COT"])]
+    #[case::lowercase(&[(0, 1, "o")], (1, ClueDirection::Across), &["COT"])]
+    #[case::rebus(&[(0, 1, "OA")], (1, ClueDirection::Across), &["CAR", "ACE", "COT"])]
+    #[case::no_match(&[(0, 0, "Z")], (1, ClueDirection::Across), &[])]
+    #[case::down(&[(0, 0, "C")], (1, ClueDirection::Down), &["CO"])]
+    #[case::missing(&[], (4, ClueDirection::Down), &[])]
+    fn suggest(
+        #[case] entries: &[(usize, usize, &str)],
+        #[case] id: (u8, ClueDirection),
+        #[case] expected: &[&str],
+    ) {
+        let puzzle = crossword!(
+            [C A R]
+            [O . .]
+            - A: "Automobile"
+            - D: "Company, briefly"
+        );
+        let mut state = CrosswordState::from(&puzzle);
+        for pos in puzzle.squares().positions() {
+            state.clear(&pos);
+        }
+        for &(row, col, entry) in entries {
+            state.enter(&Position::new(row, col), entry.into());
+        }
+
+        let words = WordList::from_iter([
+            ("car", 70),
+            ("cot", 50),
+            ("ace", 50),
+            ("co", 20),
+            ("cars", 90),
+        ]);
+        let suggestions: Vec<_> = super::suggest(&puzzle, &state, id.into(), &words)
+            .into_iter()
+            .map(|candidate| candidate.word)
+            .collect();
+
+        assert_eq!(suggestions, expected);
+    }
+
+    #[test]
+    fn keeps_highest_score() {
+        let mut words = WordList::new();
+
+        assert_eq!(words.insert("Area", 40), None);
+        assert_eq!(words.insert("AREA", 30), Some(40));
+        assert_eq!(words.insert("a-r-e-a", 60), Some(40));
+        assert_eq!(words.insert("123", 60), None);
+
+        assert_eq!(words.len(), 1);
+        assert_eq!(words.score("area"), Some(60));
+    }
+}
@@ -45,7 +45,7 @@ impl<'a, T> HandleCustomMotion<CrosswordMotion, GridRenderState, GridMotionState
                 let direction = ClueDirection::from(render.direction);
                 let id = ClueId { num, direction };
 
-                let clue = match custom_state.puzzle.clues().get(&id) {
+                let clue = match custom_state.puzzle.clues().get(id) {
                     Some(c) => c.clone(),
                     None => Clue::new(0, ClueDirection::Across, "", pos, 0),
                 };
@@ -5,7 +5,7 @@ pub(crate) use clue::*;
 use crossterm::event::KeyCode;
 pub(crate) use render::*;
 
-use puzzled_core::{Direction, Puzzle, Solve};
+use puzzled_core::{Puzzle, Solve};
 use puzzled_crossword::{ClueDirection, Crossword, Solution};
 use puzzled_tui::{
     Action, AppCommand, AppContext, AppResolver, Command, EventMode, GridWidget, HandleBaseAction,
@@ -86,7 +86,7 @@ impl AppWidget<CrosswordApp> for CrosswordWidget {
             render: &render_c,
         };
 
-        let grid = solve.0.map_entries(|solution| RenderSolution { solution });
+        let grid = solve.map_entries(|solution| RenderSolution { solution });
 
         let mut grid_widget = GridWidget::<CrosswordApp, _, _>::new(&grid, &cell_state);
         AppWidget::render(&mut grid_widget, area, buf, ctx, &mut state.render);
@@ -127,7 +127,7 @@ impl AppWidget<CrosswordApp> for CrosswordWidget {
                     command,
                     resolver,
                     &mut state.render,
-                    &mut state.solve.0,
+                    &mut *state.solve,
                     &mut custom_state,
                 ) {
                     state.history.execute(action, &mut state.solve);
@@ -139,10 +139,9 @@ impl AppWidget<CrosswordApp> for CrosswordWidget {
             }
             Command::Action { action, .. } => {
                 let pos = state.render.cursor;
-                let dir = match state.render.direction {
-                    Direction::Left | Direction::Right => Direction::Right,
-                    Direction::Up | Direction::Down => Direction::Down,
-                };
+                // Advance in the direction the active clue is read in
+                let clue_dir = ClueDirection::from(state.render.direction);
+                let dir = state.puzzle.writing().step(clue_dir);
 
                 if !matches!(state.render.mode, EventMode::Insert) {
                     return state
@@ -72,7 +72,7 @@ impl<'a> CellRender<CrosswordApp, RenderSquareState<'a>> for Square<Entry<Render
         let symbol = match self.as_ref().and_then(|sq| sq.entry()) {
             Some(render) => match render.solution {
                 Solution::Letter(l) => l.to_string(),
-                sol @ Solution::Rebus(_) => format!("{}…", sol.first_letter()),
+                sol => format!("{}…", sol.first_letter()),
             },
             None => "".to_string(),
         };
@@ -45,7 +45,9 @@ impl AppWidget<CrosswordApp> for FooterWidget {
         ])
         .render(help_line, buf);
 
-        let timer = TimerWidget { timer: state.timer };
+        let timer = TimerWidget {
+            timer: state.timer.clone(),
+        };
         timer.render(timer_line, buf);
 
         let mode = state.mode.to_string();
@@ -17,7 +17,7 @@ use ratatui::{
     widgets::ListState,
 };
 
-use puzzled_crossword::{ClueDirection, Crossword, CrosswordState};
+use puzzled_crossword::{ClueDirection, Crossword, CrosswordState, PuzzleEvent};
 use puzzled_tui::{
     Action, ActionBehavior, ActionHistory, AppCommand, AppContext, AppResolver, Command, EventMode,
     FocusManager, GridRenderState, HandleCommand, HandleMode, Keys, KeysListPopup, KeysTablePopup,
@@ -148,7 +148,7 @@ impl Screen<CrosswordApp> for PuzzleScreen {
 
         let mut footer_state = FooterState {
             mode: self.state.render.mode,
-            timer: self.state.solve.timer,
+            timer: self.state.solve.timer.clone(),
             pause_key,
         };
         self.footer.render(footer, buf, ctx, &mut footer_state);
@@ -236,7 +236,7 @@ impl Screen<CrosswordApp> for PuzzleScreen {
             }
         }
 
-        handled_action
+        let handled = handled_action
             || match self.state.focus.get() {
                 Focus::Crossword => {
                     self.crossword
@@ -248,7 +248,18 @@ impl Screen<CrosswordApp> for PuzzleScreen {
                 Focus::Footer => self
                     .crossword
                     .on_command(command, resolver, ctx, &mut self.state),
+            };
+
+        // Drain the events after every command, such that they do not pile up while solving
+        for event in self.state.solve.drain_events(&self.state.puzzle) {
+            tracing::debug!("Puzzle event: {event:?}");
+
+            if event == PuzzleEvent::PuzzleSolved {
+                self.state.solve.timer.pause();
             }
+        }
+
+        handled
     }
 
     fn on_mode(
@@ -0,0 +1,41 @@
+use puzzled::crossword::Crossword;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+
+use crate::{CrosswordTheme, GridState};
+
+/// Single line with the clue that the cursor of a [grid](crate::GridWidget) is in, such as `12A Capital of France`
+///
+/// The bar shares its state with the grid, such that it always follows the navigator of the view model.
+#[derive(Debug, Clone, Copy)]
+pub struct ClueBarWidget<'a> {
+    crossword: &'a Crossword,
+    theme: CrosswordTheme,
+}
+
+impl<'a> ClueBarWidget<'a> {
+    pub fn new(crossword: &'a Crossword, theme: CrosswordTheme) -> Self {
+        Self { crossword, theme }
+    }
+}
+
+impl StatefulWidget for ClueBarWidget<'_> {
+    type State = GridState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut GridState) {
+        let Some(clue) = state.view.navigator().clue(self.crossword) else {
+            return;
+        };
+
+        let line = format!("{}{} {}", clue.num(), clue.direction(), clue.text());
+        let line_area = Rect::new(area.x, area.y, area.width, area.height.min(1));
+
+        buf.set_style(line_area, self.theme.selection);
+        buf.set_stringn(
+            area.x,
+            area.y,
+            line,
+            area.width as usize,
+            self.theme.selection,
+        );
+    }
+}
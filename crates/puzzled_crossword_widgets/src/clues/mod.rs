@@ -0,0 +1,106 @@
+mod state;
+
+pub use state::*;
+
+use puzzled::crossword::{ClueDirection, Crossword};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+
+use crate::CrosswordTheme;
+
+/// List of the clues of a crossword in a single direction, with one clue on every row
+///
+/// The list scrolls such that the [selected](ClueListState::selected) clue is always visible, and clue texts that do not fit the width are cut off.
+#[derive(Debug, Clone, Copy)]
+pub struct ClueListWidget<'a> {
+    crossword: &'a Crossword,
+    direction: ClueDirection,
+    theme: CrosswordTheme,
+}
+
+impl<'a> ClueListWidget<'a> {
+    pub fn new(crossword: &'a Crossword, direction: ClueDirection, theme: CrosswordTheme) -> Self {
+        Self {
+            crossword,
+            direction,
+            theme,
+        }
+    }
+}
+
+impl StatefulWidget for ClueListWidget<'_> {
+    type State = ClueListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut ClueListState) {
+        let clues: Vec<_> = self
+            .crossword
+            .clues()
+            .iter_direction(self.direction)
+            .collect();
+
+        let height = area.height as usize;
+        let selected = clues
+            .iter()
+            .position(|clue| Some(clue.id()) == state.selected);
+        if let Some(idx) = selected {
+            state.scroll_to(idx, height);
+        }
+
+        let visible = clues.iter().skip(state.offset).take(height);
+        for (row, clue) in (area.y..).zip(visible) {
+            let style = match Some(clue.id()) == state.selected {
+                true => self.theme.selection,
+                false => self.theme.square,
+            };
+
+            let line = format!("{:>3} {}", clue.num(), clue.text());
+            buf.set_style(Rect::new(area.x, row, area.width, 1), style);
+            buf.set_stringn(area.x, row, line, area.width as usize, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled::crossword::crossword;
+
+    use super::*;
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, y)].symbol())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn scrolls_to_selected() {
+        let puzzle = crossword!(
+            [A B C]
+            [D E F]
+            [G H I]
+            - A: "ABC"
+            - A: "DEF"
+            - A: "GHI"
+            - D: "ADG"
+            - D: "BEH"
+            - D: "CFI"
+        );
+
+        let mut state = ClueListState::default();
+        state.select(Some((5, ClueDirection::Across).into()));
+
+        let area = Rect::new(0, 0, 8, 2);
+        let mut buf = Buffer::empty(area);
+        ClueListWidget::new(&puzzle, ClueDirection::Across, CrosswordTheme::default())
+            .render(area, &mut buf, &mut state);
+
+        assert_eq!(state.offset, 1);
+        assert_eq!(row(&buf, 0), "  4 DEF");
+        assert_eq!(row(&buf, 1), "  5 GHI");
+        assert_eq!(
+            buf[(0, 1)].bg,
+            CrosswordTheme::default().selection.bg.unwrap()
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use puzzled::crossword::ClueId;
+
+/// State of a [clue list](crate::ClueListWidget), which keeps the selected clue in view
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClueListState {
+    /// Clue that is highlighted, e.g. the current clue of the navigator
+    pub selected: Option<ClueId>,
+
+    /// Index of the first clue that is drawn
+    pub offset: usize,
+}
+
+impl ClueListState {
+    pub fn select(&mut self, id: Option<ClueId>) {
+        self.selected = id;
+    }
+
+    /// Scroll such that the clue at the index is visible in a list of the given height
+    pub fn scroll_to(&mut self, idx: usize, height: usize) {
+        if idx < self.offset {
+            self.offset = idx;
+        } else if height > 0 && idx >= self.offset + height {
+            self.offset = idx + 1 - height;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::visible(3, 4, 3)]
+    #[case::above(3, 1, 1)]
+    #[case::below(3, 8, 6)]
+    #[case::last_row(3, 5, 3)]
+    fn scroll_to(#[case] offset: usize, #[case] idx: usize, #[case] expected: usize) {
+        let mut state = ClueListState {
+            offset,
+            ..Default::default()
+        };
+        state.scroll_to(idx, 3);
+
+        assert_eq!(state.offset, expected);
+    }
+}
@@ -0,0 +1,94 @@
+mod state;
+
+pub use state::*;
+
+use puzzled::{core::CellStyle, view::CellView};
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::StatefulWidget};
+
+use crate::CrosswordTheme;
+
+/// Number of columns that every square is drawn with
+pub const SQUARE_WIDTH: u16 = 4;
+
+/// Number of rows that every square is drawn with, of which the first holds the clue number and the second the entry
+pub const SQUARE_HEIGHT: u16 = 2;
+
+/// Grid of the squares of a crossword, drawn from the [view model](puzzled::view::GridViewModel) of its [state](GridState)
+///
+/// Squares that do not fit within the area are not drawn.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GridWidget {
+    theme: CrosswordTheme,
+}
+
+impl GridWidget {
+    pub fn new(theme: CrosswordTheme) -> Self {
+        Self { theme }
+    }
+}
+
+impl StatefulWidget for GridWidget {
+    type State = GridState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut GridState) {
+        let cells = state.view.cells();
+        let width = (cells.cols() as u16).saturating_mul(SQUARE_WIDTH);
+        let height = (cells.rows() as u16).saturating_mul(SQUARE_HEIGHT);
+        state.area = Rect::new(area.x, area.y, width, height).intersection(area);
+
+        for (pos, cell) in cells.iter_indexed() {
+            let square = Rect::new(
+                area.x + pos.col as u16 * SQUARE_WIDTH,
+                area.y + pos.row as u16 * SQUARE_HEIGHT,
+                SQUARE_WIDTH,
+                SQUARE_HEIGHT,
+            );
+
+            if square.right() <= area.right() && square.bottom() <= area.bottom() {
+                self.render_square(cell, square, buf);
+            }
+        }
+    }
+}
+
+impl GridWidget {
+    fn render_square(&self, cell: &CellView, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        if cell.is_block {
+            buf.set_style(area, theme.block);
+            return;
+        }
+
+        buf.set_style(area, self.square_style(cell));
+
+        if let Some(number) = cell.number {
+            buf.set_stringn(area.x, area.y, number.to_string(), 3, theme.number);
+        }
+
+        if let Some(glyph) = &cell.glyph {
+            let style = if cell.style.contains(CellStyle::INCORRECT) {
+                theme.incorrect
+            } else if cell.style.contains(CellStyle::REVEALED) {
+                theme.revealed
+            } else if cell.style.contains(CellStyle::PENCIL) {
+                theme.pencil
+            } else {
+                Style::default()
+            };
+
+            let width = (SQUARE_WIDTH - 1) as usize;
+            buf.set_stringn(area.x + 1, area.y + 1, glyph, width, style);
+        }
+    }
+
+    /// Style of the whole square, which shows whether it holds the cursor or is part of the current clue
+    fn square_style(&self, cell: &CellView) -> Style {
+        let theme = &self.theme;
+
+        match (cell.is_cursor, cell.is_selected) {
+            (true, _) => theme.square.patch(theme.cursor),
+            (false, true) => theme.square.patch(theme.selection),
+            (false, false) => theme.square,
+        }
+    }
+}
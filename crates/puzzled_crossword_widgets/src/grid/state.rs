@@ -0,0 +1,63 @@
+use puzzled::{crossword::Position, view::GridViewModel};
+use ratatui::layout::{Position as AppPosition, Rect};
+
+use crate::{SQUARE_HEIGHT, SQUARE_WIDTH};
+
+/// State of a [grid widget](crate::GridWidget), which holds the view model that it draws
+#[derive(Debug)]
+pub struct GridState {
+    pub view: GridViewModel,
+
+    /// Area that the grid was last drawn in
+    pub area: Rect,
+}
+
+impl GridState {
+    pub fn new(view: GridViewModel) -> Self {
+        Self {
+            view,
+            area: Rect::default(),
+        }
+    }
+
+    /// Square that is drawn at the screen position, if any, e.g. to move the cursor on a mouse click
+    pub fn screen_to_puzzle(&self, pos: AppPosition) -> Option<Position> {
+        if !self.area.contains(pos) {
+            return None;
+        }
+
+        let row = ((pos.y - self.area.y) / SQUARE_HEIGHT) as usize;
+        let col = ((pos.x - self.area.x) / SQUARE_WIDTH) as usize;
+        let pos = Position::new(row, col);
+
+        self.view.get(pos).is_some().then_some(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled::crossword::{CrosswordState, Navigator, crossword};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::top_left((10, 5), Some((0, 0)))]
+    #[case::number_row((17, 6), Some((0, 1)))]
+    #[case::bottom_right((17, 8), Some((1, 1)))]
+    #[case::past_grid((18, 5), None)]
+    #[case::outside((9, 5), None)]
+    fn screen_to_puzzle(#[case] pos: (u16, u16), #[case] expected: Option<(usize, usize)>) {
+        let puzzle = crossword!([A B] [C D]);
+        let state = CrosswordState::from(&puzzle);
+        let view = GridViewModel::new(&puzzle, &state, Navigator::new(&puzzle));
+
+        let grid = GridState {
+            view,
+            area: Rect::new(10, 5, 20, 10),
+        };
+
+        let expected = expected.map(Position::from);
+        assert_eq!(grid.screen_to_puzzle(pos.into()), expected);
+    }
+}
@@ -0,0 +1,42 @@
+//! Reusable [ratatui](https://docs.rs/ratatui/latest/ratatui/) widgets for playing [crosswords](puzzled::crossword::Crossword) in the terminal
+//!
+//! The widgets only draw a crossword and are decoupled from any app, such that they can be embedded in any TUI:
+//! - [`GridWidget`] draws the squares of the puzzle from a [view model](puzzled::view::GridViewModel) in its [state](GridState)
+//! - [`ClueListWidget`] draws the clues of a single direction, scrolled to the [selected](ClueListState::select) clue
+//! - [`ClueBarWidget`] draws the clue that the cursor of the grid is in
+//!
+//! Apps handle input themselves by mutating the [crossword state](puzzled::crossword::CrosswordState) and navigator as usual, after which the view model is updated.
+//! ```
+//! use puzzled::{
+//!     crossword::{crossword, CrosswordState, Navigator},
+//!     view::GridViewModel,
+//! };
+//! use puzzled_crossword_widgets::{ClueBarWidget, CrosswordTheme, GridState, GridWidget};
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+//!
+//! let puzzle = crossword!(
+//!     [A B]
+//!     [C .]
+//!     - A: "First letters"
+//!     - D: "Also first letters"
+//! );
+//! let state = CrosswordState::from(&puzzle);
+//! let mut grid = GridState::new(GridViewModel::new(&puzzle, &state, Navigator::new(&puzzle)));
+//!
+//! let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+//! GridWidget::new(CrosswordTheme::default()).render(Rect::new(0, 0, 30, 4), &mut buf, &mut grid);
+//! ClueBarWidget::new(&puzzle, CrosswordTheme::default()).render(Rect::new(0, 4, 30, 1), &mut buf, &mut grid);
+//!
+//! assert_eq!(buf[(1, 1)].symbol(), "A");
+//! assert!(buf.content()[120..].iter().map(|cell| cell.symbol()).collect::<String>().starts_with("1A First letters"));
+//! ```
+
+mod clue_bar;
+mod clues;
+mod grid;
+mod theme;
+
+pub use clue_bar::*;
+pub use clues::*;
+pub use grid::*;
+pub use theme::*;
@@ -0,0 +1,44 @@
+use ratatui::style::{Color, Modifier, Style};
+
+/// Styles that the crossword widgets are drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrosswordTheme {
+    /// Playable squares
+    pub square: Style,
+
+    /// Block squares, which are drawn without content
+    pub block: Style,
+
+    /// Clue numbers in the top left of their squares
+    pub number: Style,
+
+    /// Square that the cursor is on
+    pub cursor: Style,
+
+    /// Squares of the current clue, and the clue itself in the [clue list](crate::ClueListWidget)
+    pub selection: Style,
+
+    /// Entries that are marked as incorrect
+    pub incorrect: Style,
+
+    /// Squares that show their solution after being revealed
+    pub revealed: Style,
+
+    /// Entries that are pencilled in
+    pub pencil: Style,
+}
+
+impl Default for CrosswordTheme {
+    fn default() -> Self {
+        Self {
+            square: Style::default().fg(Color::White),
+            block: Style::default().bg(Color::DarkGray),
+            number: Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+            cursor: Style::default().bg(Color::Yellow).fg(Color::Black),
+            selection: Style::default().bg(Color::Blue),
+            incorrect: Style::default().fg(Color::Red),
+            revealed: Style::default().fg(Color::Magenta),
+            pencil: Style::default().add_modifier(Modifier::DIM),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+#ifndef PUZZLED_H
+#define PUZZLED_H
+
+/* Generated by cbindgen from the puzzled_ffi crate, do not edit manually */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of a call into the library
+ */
+typedef enum PuzzledStatus {
+  /**
+   * Call succeeded
+   */
+  PUZZLED_STATUS_OK = 0,
+  /**
+   * A pointer argument was null
+   */
+  PUZZLED_STATUS_NULL_POINTER,
+  /**
+   * An index or position argument was out of bounds
+   */
+  PUZZLED_STATUS_OUT_OF_BOUNDS,
+  /**
+   * Bytes could not be read as a puzzle
+   */
+  PUZZLED_STATUS_READ_FAILED,
+  /**
+   * Puzzle could not be written
+   */
+  PUZZLED_STATUS_WRITE_FAILED,
+  /**
+   * Library panicked, which is a bug that should be reported
+   */
+  PUZZLED_STATUS_PANICKED,
+} PuzzledStatus;
+
+/**
+ * Direction of a [clue](PuzzledClue)
+ */
+typedef enum PuzzledDirection {
+  PUZZLED_DIRECTION_ACROSS,
+  PUZZLED_DIRECTION_DOWN,
+} PuzzledDirection;
+
+/**
+ * Field of the metadata that is read with [`puzzled_crossword_meta`]
+ */
+typedef enum PuzzledMetaField {
+  PUZZLED_META_FIELD_TITLE,
+  PUZZLED_META_FIELD_AUTHOR,
+  PUZZLED_META_FIELD_COPYRIGHT,
+  PUZZLED_META_FIELD_NOTES,
+} PuzzledMetaField;
+
+/**
+ * Crossword that was parsed by the library, together with the progress of its player
+ *
+ * The layout of the handle is opaque, such that it is only accessed through the `puzzled_crossword_*` functions
+ */
+typedef struct PuzzledCrossword PuzzledCrossword;
+
+/**
+ * Bytes that are owned by the library, such as a written puzzle
+ *
+ * Buffers should be released with [`puzzled_buffer_free`]
+ */
+typedef struct PuzzledBuffer {
+  uint8_t *data;
+  size_t len;
+} PuzzledBuffer;
+
+/**
+ * Placement of a clue within the grid, of which the text is read with [`puzzled_crossword_clue_text`]
+ */
+typedef struct PuzzledClue {
+  uint8_t num;
+  enum PuzzledDirection direction;
+  size_t row;
+  size_t col;
+  uint8_t len;
+} PuzzledClue;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Release the bytes of a buffer that was returned by the library
+ *
+ * # Safety
+ * `buffer` should be returned by the library and not be released before
+ */
+void puzzled_buffer_free(struct PuzzledBuffer buffer);
+
+/**
+ * Parse `*.puz` bytes into a crossword, which is stored in `out` on success
+ *
+ * Issues that can be recovered from, such as invalid checksums, do not fail parsing.
+ * On failure, the reason is available through [`puzzled_last_error`](crate::puzzled_last_error).
+ *
+ * # Safety
+ * `bytes` should point to at least `len` readable bytes and `out` should be a valid pointer to store the crossword in
+ */
+enum PuzzledStatus puzzled_parse_puz(const uint8_t *bytes,
+                                     size_t len,
+                                     struct PuzzledCrossword **out);
+
+/**
+ * Write the crossword and the progress of its player as `*.puz` bytes into `out`
+ *
+ * The bytes should be released with [`puzzled_buffer_free`](crate::puzzled_buffer_free)
+ *
+ * # Safety
+ * `crossword` should be returned by [`puzzled_parse_puz`] and `out` should be a valid pointer to store the bytes in
+ */
+enum PuzzledStatus puzzled_write_puz(const struct PuzzledCrossword *crossword,
+                                     struct PuzzledBuffer *out);
+
+/**
+ * Release a crossword that was returned by [`puzzled_parse_puz`]
+ *
+ * # Safety
+ * `crossword` should be returned by [`puzzled_parse_puz`] and not be released before
+ */
+void puzzled_crossword_free(struct PuzzledCrossword *crossword);
+
+/**
+ * Number of rows in the grid of the crossword, which is `0` for a null crossword
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`]
+ */
+size_t puzzled_crossword_rows(const struct PuzzledCrossword *crossword);
+
+/**
+ * Number of columns in the grid of the crossword, which is `0` for a null crossword
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`]
+ */
+size_t puzzled_crossword_cols(const struct PuzzledCrossword *crossword);
+
+/**
+ * Whether the square at the given position is a block, i.e. not playable
+ *
+ * Positions outside of the grid are considered blocks
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`]
+ */
+bool puzzled_crossword_is_block(const struct PuzzledCrossword *crossword, size_t row, size_t col);
+
+/**
+ * Copy the solution of the square at the given position into `buf`
+ *
+ * Rebuses are copied in full, and squares with alternative solutions separate them by `/`.
+ * Blocks and positions outside of the grid have an empty solution.
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`] and `buf` should be null or point to at least `buf_len` writable bytes
+ */
+size_t puzzled_crossword_solution(const struct PuzzledCrossword *crossword,
+                                  size_t row,
+                                  size_t col,
+                                  char *buf,
+                                  size_t buf_len);
+
+/**
+ * Number of clues in the crossword, which is `0` for a null crossword
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`]
+ */
+size_t puzzled_crossword_clue_count(const struct PuzzledCrossword *crossword);
+
+/**
+ * Store the placement of the clue at the given index into `out`
+ *
+ * Clues are ordered by number, then by direction, such that across clues precede down clues with the same number
+ *
+ * # Safety
+ * `crossword` should be returned by [`puzzled_parse_puz`] and `out` should be a valid pointer to store the clue in
+ */
+enum PuzzledStatus puzzled_crossword_clue(const struct PuzzledCrossword *crossword,
+                                          size_t idx,
+                                          struct PuzzledClue *out);
+
+/**
+ * Copy the text of the clue at the given index into `buf`, which is empty for indices out of bounds
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`] and `buf` should be null or point to at least `buf_len` writable bytes
+ */
+size_t puzzled_crossword_clue_text(const struct PuzzledCrossword *crossword,
+                                   size_t idx,
+                                   char *buf,
+                                   size_t buf_len);
+
+/**
+ * Copy a field of the metadata into `buf`, which is empty if the crossword does not define it
+ *
+ * # Safety
+ * `crossword` should be null or returned by [`puzzled_parse_puz`] and `buf` should be null or point to at least `buf_len` writable bytes
+ */
+size_t puzzled_crossword_meta(const struct PuzzledCrossword *crossword,
+                              enum PuzzledMetaField field,
+                              char *buf,
+                              size_t buf_len);
+
+/**
+ * Copy the message of the last error on the current thread into `buf`
+ *
+ * Returns the length of the message without its terminating `NUL`, which is `0` if no error occurred yet
+ *
+ * # Safety
+ * `buf` should either be null or point to at least `buf_len` writable bytes
+ */
+size_t puzzled_last_error(char *buf,
+                          size_t buf_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PUZZLED_H */
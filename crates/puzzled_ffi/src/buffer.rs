@@ -0,0 +1,46 @@
+use std::ptr;
+
+/// Bytes that are owned by the library, such as a written puzzle
+///
+/// Buffers should be released with [`puzzled_buffer_free`]
+#[repr(C)]
+#[derive(Debug)]
+pub struct PuzzledBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl PuzzledBuffer {
+    pub(crate) fn empty() -> Self {
+        Self {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+}
+
+impl From<Vec<u8>> for PuzzledBuffer {
+    fn from(bytes: Vec<u8>) -> Self {
+        let bytes = Box::into_raw(bytes.into_boxed_slice());
+
+        Self {
+            data: bytes.cast(),
+            len: bytes.len(),
+        }
+    }
+}
+
+/// Release the bytes of a buffer that was returned by the library
+///
+/// # Safety
+/// `buffer` should be returned by the library and not be released before
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_buffer_free(buffer: PuzzledBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+
+    // SAFETY: the buffer was created from a boxed slice of the same length
+    let bytes = ptr::slice_from_raw_parts_mut(buffer.data, buffer.len);
+    drop(unsafe { Box::from_raw(bytes) });
+}
@@ -0,0 +1,453 @@
+use std::{
+    ffi::c_char,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use puzzled_core::Position;
+use puzzled_crossword::{Clue, ClueDirection, Crossword, CrosswordState};
+use puzzled_io::{PuzReader, PuzWriter};
+
+use crate::{PuzzledBuffer, PuzzledStatus, copy_str, fail};
+
+/// Crossword that was parsed by the library, together with the progress of its player
+///
+/// The layout of the handle is opaque, such that it is only accessed through the `puzzled_crossword_*` functions
+pub struct PuzzledCrossword {
+    puzzle: Crossword,
+    state: CrosswordState,
+}
+
+/// Direction of a [clue](PuzzledClue)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzledDirection {
+    Across,
+    Down,
+}
+
+impl From<ClueDirection> for PuzzledDirection {
+    fn from(direction: ClueDirection) -> Self {
+        match direction {
+            ClueDirection::Across => Self::Across,
+            ClueDirection::Down => Self::Down,
+        }
+    }
+}
+
+/// Placement of a clue within the grid, of which the text is read with [`puzzled_crossword_clue_text`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PuzzledClue {
+    pub num: u8,
+    pub direction: PuzzledDirection,
+    pub row: usize,
+    pub col: usize,
+    pub len: u8,
+}
+
+impl From<&Clue> for PuzzledClue {
+    fn from(clue: &Clue) -> Self {
+        let start = clue.start();
+
+        Self {
+            num: clue.num(),
+            direction: clue.direction().into(),
+            row: start.row,
+            col: start.col,
+            len: clue.len(),
+        }
+    }
+}
+
+/// Field of the metadata that is read with [`puzzled_crossword_meta`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzledMetaField {
+    Title,
+    Author,
+    Copyright,
+    Notes,
+}
+
+impl PuzzledCrossword {
+    fn clue(&self, idx: usize) -> Option<&Clue> {
+        self.puzzle.clues().values().nth(idx)
+    }
+
+    fn solution(&self, row: usize, col: usize) -> Option<String> {
+        let square = self.puzzle.squares().get(Position { row, col })?;
+        let solution = square.as_ref()?.solution.as_ref()?;
+
+        Some(solution.to_string())
+    }
+}
+
+/// Parse `*.puz` bytes into a crossword, which is stored in `out` on success
+///
+/// Issues that can be recovered from, such as invalid checksums, do not fail parsing.
+/// On failure, the reason is available through [`puzzled_last_error`](crate::puzzled_last_error).
+///
+/// # Safety
+/// `bytes` should point to at least `len` readable bytes and `out` should be a valid pointer to store the crossword in
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_parse_puz(
+    bytes: *const u8,
+    len: usize,
+    out: *mut *mut PuzzledCrossword,
+) -> PuzzledStatus {
+    if bytes.is_null() || out.is_null() {
+        return fail(
+            PuzzledStatus::NullPointer,
+            "Bytes and output should not be null",
+        );
+    }
+
+    // SAFETY: the caller guarantees that `bytes` holds `len` bytes
+    let bytes = unsafe { slice::from_raw_parts(bytes, len) };
+    let read = panic::catch_unwind(|| PuzReader::default().read(&mut &bytes[..]));
+
+    match read {
+        Ok(Ok((puzzle, state))) => {
+            let crossword = Box::new(PuzzledCrossword { puzzle, state });
+
+            // SAFETY: the caller guarantees that `out` is valid
+            unsafe { *out = Box::into_raw(crossword) };
+            PuzzledStatus::Ok
+        }
+        Ok(Err(err)) => fail(PuzzledStatus::ReadFailed, err),
+        Err(_) => fail(PuzzledStatus::Panicked, "Panicked while parsing the puzzle"),
+    }
+}
+
+/// Write the crossword and the progress of its player as `*.puz` bytes into `out`
+///
+/// The bytes should be released with [`puzzled_buffer_free`](crate::puzzled_buffer_free)
+///
+/// # Safety
+/// `crossword` should be returned by [`puzzled_parse_puz`] and `out` should be a valid pointer to store the bytes in
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_write_puz(
+    crossword: *const PuzzledCrossword,
+    out: *mut PuzzledBuffer,
+) -> PuzzledStatus {
+    // SAFETY: the caller guarantees that `crossword` is valid
+    let Some(crossword) = (unsafe { crossword.as_ref() }) else {
+        return fail(PuzzledStatus::NullPointer, "Crossword should not be null");
+    };
+    if out.is_null() {
+        return fail(PuzzledStatus::NullPointer, "Output should not be null");
+    }
+
+    let write = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut bytes = Vec::new();
+        PuzWriter::new()
+            .write(&mut bytes, &crossword.puzzle, &crossword.state)
+            .map(|_| bytes)
+    }));
+
+    let (buffer, status) = match write {
+        Ok(Ok(bytes)) => (bytes.into(), PuzzledStatus::Ok),
+        Ok(Err(err)) => (
+            PuzzledBuffer::empty(),
+            fail(PuzzledStatus::WriteFailed, err),
+        ),
+        Err(_) => (
+            PuzzledBuffer::empty(),
+            fail(PuzzledStatus::Panicked, "Panicked while writing the puzzle"),
+        ),
+    };
+
+    // SAFETY: the caller guarantees that `out` is valid
+    unsafe { *out = buffer };
+    status
+}
+
+/// Release a crossword that was returned by [`puzzled_parse_puz`]
+///
+/// # Safety
+/// `crossword` should be returned by [`puzzled_parse_puz`] and not be released before
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_free(crossword: *mut PuzzledCrossword) {
+    if !crossword.is_null() {
+        // SAFETY: the crossword was created from a box by `puzzled_parse_puz`
+        drop(unsafe { Box::from_raw(crossword) });
+    }
+}
+
+/// Number of rows in the grid of the crossword, which is `0` for a null crossword
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_rows(crossword: *const PuzzledCrossword) -> usize {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    unsafe { crossword.as_ref() }.map_or(0, |crossword| crossword.puzzle.rows())
+}
+
+/// Number of columns in the grid of the crossword, which is `0` for a null crossword
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_cols(crossword: *const PuzzledCrossword) -> usize {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    unsafe { crossword.as_ref() }.map_or(0, |crossword| crossword.puzzle.cols())
+}
+
+/// Whether the square at the given position is a block, i.e. not playable
+///
+/// Positions outside of the grid are considered blocks
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_is_block(
+    crossword: *const PuzzledCrossword,
+    row: usize,
+    col: usize,
+) -> bool {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    let Some(crossword) = (unsafe { crossword.as_ref() }) else {
+        return true;
+    };
+
+    crossword
+        .puzzle
+        .squares()
+        .get(Position { row, col })
+        .is_none_or(|square| square.is_none())
+}
+
+/// Copy the solution of the square at the given position into `buf`
+///
+/// Rebuses are copied in full, and squares with alternative solutions separate them by `/`.
+/// Blocks and positions outside of the grid have an empty solution.
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`] and `buf` should be null or point to at least `buf_len` writable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_solution(
+    crossword: *const PuzzledCrossword,
+    row: usize,
+    col: usize,
+    buf: *mut c_char,
+    buf_len: usize,
+) -> usize {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    let solution = unsafe { crossword.as_ref() }
+        .and_then(|crossword| crossword.solution(row, col))
+        .unwrap_or_default();
+
+    // SAFETY: the caller guarantees that `buf` holds `buf_len` bytes
+    unsafe { copy_str(&solution, buf, buf_len) }
+}
+
+/// Number of clues in the crossword, which is `0` for a null crossword
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_clue_count(crossword: *const PuzzledCrossword) -> usize {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    unsafe { crossword.as_ref() }.map_or(0, |crossword| crossword.puzzle.clues().len())
+}
+
+/// Store the placement of the clue at the given index into `out`
+///
+/// Clues are ordered by number, then by direction, such that across clues precede down clues with the same number
+///
+/// # Safety
+/// `crossword` should be returned by [`puzzled_parse_puz`] and `out` should be a valid pointer to store the clue in
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_clue(
+    crossword: *const PuzzledCrossword,
+    idx: usize,
+    out: *mut PuzzledClue,
+) -> PuzzledStatus {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    let Some(crossword) = (unsafe { crossword.as_ref() }) else {
+        return fail(PuzzledStatus::NullPointer, "Crossword should not be null");
+    };
+    if out.is_null() {
+        return fail(PuzzledStatus::NullPointer, "Output should not be null");
+    }
+
+    let Some(clue) = crossword.clue(idx) else {
+        return fail(
+            PuzzledStatus::OutOfBounds,
+            format!("Clue {idx} is out of bounds"),
+        );
+    };
+
+    // SAFETY: the caller guarantees that `out` is valid
+    unsafe { *out = clue.into() };
+    PuzzledStatus::Ok
+}
+
+/// Copy the text of the clue at the given index into `buf`, which is empty for indices out of bounds
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`] and `buf` should be null or point to at least `buf_len` writable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_clue_text(
+    crossword: *const PuzzledCrossword,
+    idx: usize,
+    buf: *mut c_char,
+    buf_len: usize,
+) -> usize {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    let text = unsafe { crossword.as_ref() }
+        .and_then(|crossword| crossword.clue(idx))
+        .map_or("", |clue| clue.text());
+
+    // SAFETY: the caller guarantees that `buf` holds `buf_len` bytes
+    unsafe { copy_str(text, buf, buf_len) }
+}
+
+/// Copy a field of the metadata into `buf`, which is empty if the crossword does not define it
+///
+/// # Safety
+/// `crossword` should be null or returned by [`puzzled_parse_puz`] and `buf` should be null or point to at least `buf_len` writable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_crossword_meta(
+    crossword: *const PuzzledCrossword,
+    field: PuzzledMetaField,
+    buf: *mut c_char,
+    buf_len: usize,
+) -> usize {
+    // SAFETY: the caller guarantees that `crossword` is null or valid
+    let value = unsafe { crossword.as_ref() }
+        .and_then(|crossword| {
+            let meta = crossword.puzzle.meta();
+
+            match field {
+                PuzzledMetaField::Title => meta.title(),
+                PuzzledMetaField::Author => meta.author(),
+                PuzzledMetaField::Copyright => meta.copyright(),
+                PuzzledMetaField::Notes => meta.notes(),
+            }
+        })
+        .unwrap_or_default();
+
+    // SAFETY: the caller guarantees that `buf` holds `buf_len` bytes
+    unsafe { copy_str(value, buf, buf_len) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, ptr};
+
+    use super::*;
+    use crate::puzzled_buffer_free;
+
+    fn parse(name: &str) -> *mut PuzzledCrossword {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../puzzled_crossword/puzzles/ok")
+            .join(name);
+        let bytes = fs::read(path).expect("puzzle exists");
+
+        let mut crossword = ptr::null_mut();
+        let status = unsafe { puzzled_parse_puz(bytes.as_ptr(), bytes.len(), &mut crossword) };
+
+        assert_eq!(status, PuzzledStatus::Ok);
+        crossword
+    }
+
+    fn read_string(read: impl Fn(*mut c_char, usize) -> usize) -> String {
+        let len = read(ptr::null_mut(), 0);
+        let mut buf = vec![0 as c_char; len + 1];
+        read(buf.as_mut_ptr(), buf.len());
+
+        buf[..len].iter().map(|&c| c as u8 as char).collect()
+    }
+
+    #[test]
+    fn accessors() {
+        let crossword = parse("mini.puz");
+
+        unsafe {
+            let puzzle = &(*crossword).puzzle;
+            assert_eq!(puzzled_crossword_rows(crossword), puzzle.rows());
+            assert_eq!(puzzled_crossword_cols(crossword), puzzle.cols());
+            assert_eq!(
+                puzzled_crossword_clue_count(crossword),
+                puzzle.clues().len()
+            );
+
+            let title = read_string(|buf, len| {
+                puzzled_crossword_meta(crossword, PuzzledMetaField::Title, buf, len)
+            });
+            assert_eq!(Some(title.as_str()), puzzle.meta().title().or(Some("")));
+
+            let mut clue = PuzzledClue {
+                num: 0,
+                direction: PuzzledDirection::Down,
+                row: 0,
+                col: 0,
+                len: 0,
+            };
+            assert_eq!(
+                puzzled_crossword_clue(crossword, 0, &mut clue),
+                PuzzledStatus::Ok
+            );
+            assert_eq!(clue.num, 1);
+            assert_eq!(clue.direction, PuzzledDirection::Across);
+
+            let text = read_string(|buf, len| puzzled_crossword_clue_text(crossword, 0, buf, len));
+            assert_eq!(&text, puzzle.clues().values().next().unwrap().text());
+
+            let solution = read_string(|buf, len| {
+                puzzled_crossword_solution(crossword, clue.row, clue.col, buf, len)
+            });
+            assert!(!puzzled_crossword_is_block(crossword, clue.row, clue.col));
+            assert!(!solution.is_empty());
+
+            let count = puzzled_crossword_clue_count(crossword);
+            assert_eq!(
+                puzzled_crossword_clue(crossword, count, &mut clue),
+                PuzzledStatus::OutOfBounds
+            );
+
+            puzzled_crossword_free(crossword);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let crossword = parse("mini.puz");
+
+        unsafe {
+            let mut buffer = PuzzledBuffer::empty();
+            assert_eq!(puzzled_write_puz(crossword, &mut buffer), PuzzledStatus::Ok);
+            assert!(buffer.len > 0);
+
+            let mut reread = ptr::null_mut();
+            let status = puzzled_parse_puz(buffer.data, buffer.len, &mut reread);
+            assert_eq!(status, PuzzledStatus::Ok);
+            assert_eq!((*reread).puzzle, (*crossword).puzzle);
+
+            puzzled_buffer_free(buffer);
+            puzzled_crossword_free(reread);
+            puzzled_crossword_free(crossword);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let bytes = b"not a puzzle";
+        let mut crossword = ptr::null_mut();
+
+        unsafe {
+            let status = puzzled_parse_puz(bytes.as_ptr(), bytes.len(), &mut crossword);
+            assert_eq!(status, PuzzledStatus::ReadFailed);
+            assert!(crossword.is_null());
+
+            let message = read_string(|buf, len| crate::puzzled_last_error(buf, len));
+            assert!(!message.is_empty());
+
+            let status = puzzled_parse_puz(ptr::null(), 0, &mut crossword);
+            assert_eq!(status, PuzzledStatus::NullPointer);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! C ABI for reading and writing [crosswords](puzzled_crossword::Crossword) with the [Across Lite `*.puz` format](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki)
+//!
+//! The bindings allow existing C, Swift and Kotlin crossword apps to adopt the parser of this workspace.
+//! A header for the bindings is generated with [cbindgen](https://github.com/mozilla/cbindgen) into `include/puzzled.h`.
+//!
+//! Puzzles are parsed into an opaque [`PuzzledCrossword`] handle, which is released again with [`puzzled_crossword_free`]:
+//! ```c
+//! PuzzledCrossword *puzzle = NULL;
+//! if (puzzled_parse_puz(bytes, len, &puzzle) != PUZZLED_STATUS_OK) {
+//!     char message[256];
+//!     puzzled_last_error(message, sizeof(message));
+//!     return;
+//! }
+//!
+//! char title[128];
+//! puzzled_crossword_meta(puzzle, PUZZLED_META_FIELD_TITLE, title, sizeof(title));
+//!
+//! puzzled_crossword_free(puzzle);
+//! ```
+//!
+//! Strings are copied into buffers of the caller, similar to `snprintf`.
+//! Every string function returns the length of the full string without its terminating `NUL`,
+//! such that callers can detect truncation and retry with a larger buffer.
+
+mod buffer;
+mod crossword;
+mod status;
+
+pub use buffer::*;
+pub use crossword::*;
+pub use status::*;
+
+use std::{ffi::c_char, ptr};
+
+/// Copy a string into a `NUL`-terminated buffer of the caller, truncating it at the last character that fits if the buffer is too small
+///
+/// Returns the length of the full string, excluding the terminating `NUL`
+///
+/// # Safety
+/// `buf` should either be null or point to at least `buf_len` writable bytes
+unsafe fn copy_str(text: &str, buf: *mut c_char, buf_len: usize) -> usize {
+    if !buf.is_null() && buf_len > 0 {
+        let mut len = text.len().min(buf_len - 1);
+        while !text.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        // SAFETY: the caller guarantees that `buf` holds `buf_len > len` bytes
+        unsafe {
+            ptr::copy_nonoverlapping(text.as_ptr().cast::<c_char>(), buf, len);
+            *buf.add(len) = 0;
+        }
+    }
+
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::fits(16, "GOLD RUSH")]
+    #[case::exact(10, "GOLD RUSH")]
+    #[case::truncated(5, "GOLD")]
+    #[case::only_nul(1, "")]
+    fn copy(#[case] buf_len: usize, #[case] expected: &str) {
+        let mut buf = vec![1 as c_char; buf_len];
+        let len = unsafe { copy_str("GOLD RUSH", buf.as_mut_ptr(), buf_len) };
+
+        let copied: Vec<u8> = buf
+            .iter()
+            .take_while(|&&c| c != 0)
+            .map(|&c| c as u8)
+            .collect();
+        assert_eq!(len, 9);
+        assert_eq!(copied, expected.as_bytes());
+    }
+
+    #[rstest]
+    #[case::fits(7, "CAFÉ")]
+    #[case::split(5, "CAF")]
+    #[case::before(4, "CAF")]
+    fn copy_utf8(#[case] buf_len: usize, #[case] expected: &str) {
+        let mut buf = vec![1 as c_char; buf_len];
+        let len = unsafe { copy_str("CAFÉ", buf.as_mut_ptr(), buf_len) };
+
+        let copied: Vec<u8> = buf
+            .iter()
+            .take_while(|&&c| c != 0)
+            .map(|&c| c as u8)
+            .collect();
+        assert_eq!(len, 5);
+        assert_eq!(copied, expected.as_bytes());
+    }
+}
@@ -0,0 +1,52 @@
+use std::{cell::RefCell, ffi::c_char, fmt::Display};
+
+use crate::copy_str;
+
+/// Result of a call into the library
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzledStatus {
+    /// Call succeeded
+    Ok = 0,
+
+    /// A pointer argument was null
+    NullPointer,
+
+    /// An index or position argument was out of bounds
+    OutOfBounds,
+
+    /// Bytes could not be read as a puzzle
+    ReadFailed,
+
+    /// Puzzle could not be written
+    WriteFailed,
+
+    /// Library panicked, which is a bug that should be reported
+    Panicked,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Record the message of the last error on the current thread, returning its status
+pub(crate) fn fail(status: PuzzledStatus, err: impl Display) -> PuzzledStatus {
+    LAST_ERROR.with_borrow_mut(|last| *last = Some(err.to_string()));
+    status
+}
+
+/// Copy the message of the last error on the current thread into `buf`
+///
+/// Returns the length of the message without its terminating `NUL`, which is `0` if no error occurred yet
+///
+/// # Safety
+/// `buf` should either be null or point to at least `buf_len` writable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn puzzled_last_error(buf: *mut c_char, buf_len: usize) -> usize {
+    LAST_ERROR.with_borrow(|last| {
+        let message = last.as_deref().unwrap_or_default();
+
+        // SAFETY: the caller guarantees that `buf` holds `buf_len` bytes
+        unsafe { copy_str(message, buf, buf_len) }
+    })
+}
@@ -0,0 +1,42 @@
+use std::{fs, hint::black_box};
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use puzzled::{
+    crossword::{Crossword, CrosswordState},
+    io::puz::PuzReader,
+};
+
+const PUZZLES: [&str; 2] = ["mini", "out-of-the-frying-pan"];
+
+fn read(c: &mut Criterion) {
+    let reader = PuzReader::new(false);
+    let mut group = c.benchmark_group("read");
+
+    for name in PUZZLES {
+        let path = format!("../puzzled_crossword/puzzles/ok/{name}.puz");
+        let data = fs::read(path).expect("puzzle file exists");
+        group.throughput(Throughput::Bytes(data.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("owned", name), &data, |b, data| {
+            b.iter(|| {
+                let result: (Crossword, CrosswordState) =
+                    reader.read(&mut data.as_slice()).unwrap();
+                black_box(result)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("borrowed", name), &data, |b, data| {
+            b.iter(|| black_box(reader.read_borrowed(data).unwrap()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("checksums", name), &data, |b, data| {
+            let puz = reader.read_borrowed(data).unwrap();
+            b.iter(|| black_box(puz.has_valid_checksums()))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, read);
+criterion_main!(benches);
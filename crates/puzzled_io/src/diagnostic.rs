@@ -0,0 +1,218 @@
+use std::{fmt, ops::Range};
+
+/// How severe a [diagnostic](Diagnostic) is, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Noteworthy, but does not affect the result
+    Info,
+
+    /// Recovered from, such that the result may be incomplete
+    Warning,
+
+    /// Could not be recovered from
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        };
+
+        write!(f, "{severity}")
+    }
+}
+
+macro_rules! diagnostic_codes {
+    ($( $(#[$meta:meta])* $variant:ident => $code:literal ),* $(,)?) => {
+        /// Machine-readable category of a [diagnostic](Diagnostic)
+        ///
+        /// Every code is prefixed by the format it belongs to and is stable across versions,
+        /// such that callers can filter or suppress categories without matching on messages
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum DiagnosticCode {
+            $( $(#[$meta])* $variant, )*
+        }
+
+        impl DiagnosticCode {
+            /// Every diagnostic code in order
+            pub const ALL: &[DiagnosticCode] = &[$( Self::$variant, )*];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $( Self::$variant => $code, )*
+                }
+            }
+        }
+    };
+}
+
+diagnostic_codes! {
+    // *.puz
+    /// Checksum in the file does not match the checksum of its region
+    PuzChecksumMismatch => "PUZ001_CHECKSUM_MISMATCH",
+    /// Fewer checksums than expected were found
+    PuzMissingChecksum => "PUZ002_MISSING_CHECKSUM",
+    /// File does not start with the `ACROSS&DOWN` magic
+    PuzInvalidFileMagic => "PUZ003_INVALID_FILE_MAGIC",
+    /// Extra section has an unknown header
+    PuzInvalidSection => "PUZ004_INVALID_SECTION",
+    /// GRBS refers to a rebus that is not in the RTBL
+    PuzMissingRebus => "PUZ005_MISSING_REBUS",
+    /// RTBL entry could not be read
+    PuzInvalidRebus => "PUZ006_INVALID_REBUS",
+    /// GEXT contains unknown style bits
+    PuzInvalidCellStyle => "PUZ007_INVALID_CELL_STYLE",
+    /// Number of clues does not match the grid
+    PuzInvalidClueCount => "PUZ008_INVALID_CLUE_COUNT",
+    /// Clue could not be placed in the grid
+    PuzMissingClue => "PUZ009_MISSING_CLUE",
+    /// Value does not follow the format of the file
+    PuzFormat => "PUZ010_FORMAT",
+    /// Underlying reader or writer failed
+    PuzIo => "PUZ011_IO",
+    /// String contains bytes that are not valid in its encoding
+    PuzInvalidEncoding => "PUZ012_INVALID_ENCODING",
+
+    // *.nono
+    /// Underlying reader or writer failed
+    NonoIo => "NON001_IO",
+    /// Value does not follow the format of the file
+    NonoFormat => "NON002_FORMAT",
+    /// File does not start with the `NONO` magic
+    NonoInvalidFileMagic => "NON003_INVALID_FILE_MAGIC",
+    /// Version of the file is not supported or newer than the reader
+    NonoUnsupportedVersion => "NON004_UNSUPPORTED_VERSION",
+    /// Header contains flags that are not defined
+    NonoUnknownFlags => "NON005_UNKNOWN_FLAGS",
+    /// File ends before all of its components were read
+    NonoUnexpectedEnd => "NON006_UNEXPECTED_END",
+    /// Checksum in the file does not match the checksum of its data
+    NonoChecksumMismatch => "NON007_CHECKSUM_MISMATCH",
+    /// Palette index is out of bounds
+    NonoInvalidColor => "NON008_INVALID_COLOR",
+    /// String is not valid UTF-8
+    NonoInvalidString => "NON009_INVALID_STRING",
+    /// Rule does not agree with the solution
+    NonoRuleMismatch => "NON010_RULE_MISMATCH",
+    /// File contains data after its checksum
+    NonoTrailingData => "NON011_TRAILING_DATA",
+
+    // *.txt
+    /// Text does not follow the grammar of the puzzle
+    TxtParse => "TXT001_PARSE",
+    /// Metadata property is unknown or has an invalid value
+    TxtInvalidMetaProperty => "TXT002_INVALID_META_PROPERTY",
+    /// Value does not follow the format of the file
+    TxtFormat => "TXT003_FORMAT",
+    /// Underlying reader or writer failed
+    TxtIo => "TXT004_IO",
+    /// Puzzle could not be written as text
+    TxtWrite => "TXT005_WRITE",
+
+    // *.ipuz
+    /// Feature of the puzzle cannot be represented and was left out
+    IpuzUnsupportedFeature => "IPZ001_UNSUPPORTED_FEATURE",
+    /// Clue could not be placed in the grid
+    IpuzMissingClue => "IPZ002_MISSING_CLUE",
+
+    // *.qxw
+    /// Feature of the grid cannot be represented by the puzzle and was left out
+    QxwUnsupportedFeature => "QXW001_UNSUPPORTED_FEATURE",
+    /// Record is not used by the reader
+    QxwIgnoredRecord => "QXW002_IGNORED_RECORD",
+
+    // *.xml
+    /// Feature of the puzzle cannot be represented and was left out
+    XmlUnsupportedFeature => "XML001_UNSUPPORTED_FEATURE",
+    /// Clue could not be placed in the grid
+    XmlMissingClue => "XML002_MISSING_CLUE",
+    /// Number of a clue does not match the numbering of the grid
+    XmlNumberingMismatch => "XML003_NUMBERING_MISMATCH",
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Structured report of something that happened while reading or writing a puzzle
+///
+/// Readers collect diagnostics for the issues they recover from, which callers can filter by [severity](Severity) or [code](DiagnosticCode):
+/// ```
+/// use puzzled_io::{Diagnostic, DiagnosticCode, Severity};
+///
+/// let diagnostics = vec![
+///     Diagnostic::new(Severity::Warning, DiagnosticCode::PuzChecksumMismatch, "Invalid CIB checksum"),
+///     Diagnostic::new(Severity::Warning, DiagnosticCode::PuzInvalidSection, "Unknown section XTRA").with_span(52..56),
+/// ];
+///
+/// let relevant: Vec<_> = diagnostics
+///     .iter()
+///     .filter(|diagnostic| diagnostic.code != DiagnosticCode::PuzChecksumMismatch)
+///     .collect();
+///
+/// assert_eq!(relevant.len(), 1);
+/// assert_eq!(relevant[0].to_string(), "warning[PUZ004_INVALID_SECTION]: Unknown section XTRA");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+
+    /// Byte range of the input that the diagnostic points at, which is empty if it is unknown
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new<S: Into<String>>(severity: Severity, code: DiagnosticCode, message: S) -> Self {
+        Self {
+            severity,
+            code,
+            span: 0..0,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Whether the diagnostic is at least as severe as the given severity
+    pub fn is_at_least(&self, severity: Severity) -> bool {
+        self.severity >= severity
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn unique_codes() {
+        let codes: HashSet<_> = DiagnosticCode::ALL
+            .iter()
+            .map(|code| &code.as_str()[..6])
+            .collect();
+
+        assert_eq!(codes.len(), DiagnosticCode::ALL.len());
+    }
+}
@@ -4,11 +4,18 @@ use crate::puz;
 #[cfg(feature = "image")]
 use crate::image;
 
+#[cfg(feature = "nono")]
+use crate::nono;
+
 #[cfg(feature = "text")]
 use crate::text;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ReadError {
+    #[cfg(feature = "text")]
+    #[error("Text error: {0}")]
+    Text(#[from] text::read::Error),
+
     #[cfg(feature = "puz")]
     #[error("Puz error: {0}")]
     Puz(#[from] puz::read::Error),
@@ -17,6 +24,13 @@ pub enum ReadError {
     #[error("Image error: {0}")]
     Image(#[from] image::read::Error),
 
+    #[cfg(feature = "nono")]
+    #[error("Nono error: {0}")]
+    Nono(#[from] nono::read::Error),
+
+    #[error("Cannot read puzzle from invalid {format} data: {reason}")]
+    InvalidFormat { format: String, reason: String },
+
     #[error("Cannot read puzzle from unsupported format '{format}'")]
     UnsupportedFormat { format: String },
 }
@@ -35,6 +49,10 @@ pub enum WriteError {
     #[error("Image error: {0}")]
     Image(#[from] image::write::Error),
 
+    #[cfg(feature = "nono")]
+    #[error("Nono error: {0}")]
+    Nono(#[from] nono::write::Error),
+
     #[error("Cannot write puzzle with unsupported format '{format}'")]
     UnsupportedFormat { format: String },
 }
@@ -28,6 +28,12 @@ pub enum Error {
         max_size: usize,
     },
 
+    #[error("Only solutions of letters A-Z can be scrambled (found '{0}')")]
+    NotScramblable(char),
+
+    #[error("Solution is already scrambled")]
+    AlreadyScrambled,
+
     // Puzzle specific errors
     #[error("{0}")]
     PuzzleSpecific(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -20,7 +20,7 @@ pub trait ImagePuzzle<S>: Puzzle {
     fn write_image(&self, state: &S) -> write::Result<RgbaImage>;
 
     fn load_image(&self, name: &str) -> crate::image::read::Result<(Self, S)> {
-        let reader = crate::image::ImageReader;
+        let reader = crate::image::ImageReader::default();
 
         let dir = puzzle_dir::<Self>()?;
         let path = dir.join(name).with_extension("puz");
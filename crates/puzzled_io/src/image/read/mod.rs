@@ -9,10 +9,26 @@ use image::{DynamicImage, GenericImageView, ImageReader as BaseImageReader, Rgba
 
 use crate::image::{ImagePuzzle, read};
 
-#[derive(Debug, Default)]
-pub struct ImageReader;
+/// Reads puzzles from the pixels of an image
+///
+/// Images often contain colors that are only slightly different, such as after being compressed.
+/// Puzzles that map colors to a [palette](puzzled_core::Palette) should treat colors within the [color tolerance](Self::with_color_tolerance) as the same color.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImageReader {
+    color_tolerance: u8,
+}
 
 impl ImageReader {
+    /// Treat colors whose [distance](puzzled_core::Color::distance) is at most the given tolerance as the same color
+    pub fn with_color_tolerance(mut self, tolerance: u8) -> Self {
+        self.color_tolerance = tolerance;
+        self
+    }
+
+    pub fn color_tolerance(&self) -> u8 {
+        self.color_tolerance
+    }
+
     pub fn read<P, S>(&self, image: &DynamicImage) -> read::Result<(P, S)>
     where
         P: ImagePuzzle<S>,
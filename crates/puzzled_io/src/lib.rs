@@ -22,10 +22,20 @@ pub mod image;
 #[doc(inline)]
 pub use image::{ImagePuzzle, ImageReader, ImageWriter};
 
+// Nono format
+#[cfg(feature = "nono")]
+pub mod nono;
+
+#[cfg(feature = "nono")]
+#[doc(inline)]
+pub use nono::{NonoPuzzle, NonoReader, NonoWriter};
+
 // Other
+mod diagnostic;
 mod error;
 mod util;
 
+pub use diagnostic::*;
 pub use error::*;
 pub use util::*;
 
@@ -0,0 +1,172 @@
+//! Defines a compact binary format for storing nonograms in `*.nono` files
+//!
+//! The format is modelled after [`*.puz` files](crate::puz), but stores the rules and palette of a puzzle rather than clues.
+//! It is the canonical save format for nonograms, since it can store the [state](NonoState) of a puzzle that is being solved as well.
+//!
+//! # Layout
+//! All numbers are stored in little-endian order
+//!
+//! | Component | Length        | Type        | Description                                                          |
+//! |-----------|---------------|-------------|----------------------------------------------------------------------|
+//! | Magic     | 4             | `[u8; 4]`   | Always `NONO`                                                        |
+//! | Version   | 2             | `u8`, `u8`  | Major and minor version of the format, currently `1.0`               |
+//! | Flags     | 1             | `u8`        | Whether a [solution](NonoFlags::SOLUTION) and [state](NonoFlags::STATE) are included |
+//! | Width     | 2             | `u16`       | Number of columns                                                    |
+//! | Height    | 2             | `u16`       | Number of rows                                                       |
+//! | Palette   | 1 + 8n        | `u8`, ...   | Number of colors, followed by the id and RGBA value of every color   |
+//! | Rules     | variable      |             | Rules of every row and then every column, see below                  |
+//! | Solution  | width*height  | `[u8]`      | Only if flagged, [cross](CROSS) or the palette index of every cell plus 1 |
+//! | State     | width*height + 5 | `[u8]`, `u32`, `u8` | Only if flagged, the entry of every cell followed by the elapsed seconds and state of the timer |
+//! | Strings   | variable      | `str`       | Null-terminated UTF-8 title, author, copyright and notes             |
+//! | Checksum  | 4             | `u32`       | [Checksum](nono_checksum) of all preceding bytes                     |
+//!
+//! Every rule is stored as the number of runs as a `u16`, followed by the palette index (`u8`) and length (`u16`) of every run.
+//! Entries in the state are [blank](BLANK), [crossed out](CROSS) or the palette index of the cell plus 2.
+//!
+//! # Validation
+//! Similar to [`PuzReader`](crate::PuzReader), a [`NonoReader`] can recover from some issues, such as a checksum mismatch or rules that do not agree with the solution.
+//! Strict readers abort on these issues, whereas other readers report them as [diagnostics](crate::Diagnostic).
+//! Issues that make the data unusable, such as a palette index that is out of bounds, are always errors.
+pub mod read;
+pub mod write;
+
+#[doc(inline)]
+pub use read::NonoReader;
+#[doc(inline)]
+pub use write::NonoWriter;
+
+use puzzled_core::{Color, Grid, Metadata, Puzzle, Timer};
+
+/// Magic bytes that every `*.nono` file starts with
+pub const NONO_MAGIC: &[u8; 4] = b"NONO";
+
+/// Major version of the format that is written, where files with a different major version cannot be read
+pub const NONO_MAJOR_VERSION: u8 = 1;
+
+/// Minor version of the format that is written, where files with a newer minor version are read with a warning
+pub const NONO_MINOR_VERSION: u8 = 0;
+
+/// Byte of a solution or entry of a cell that is crossed out
+pub const CROSS: u8 = 0;
+
+/// Byte of an entry of a cell that is not filled out yet
+pub const BLANK: u8 = 1;
+
+/// Which optional components are included in `*.nono` data
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonoFlags(pub u8);
+
+impl NonoFlags {
+    pub const SOLUTION: u8 = 1 << 0;
+    pub const STATE: u8 = 1 << 1;
+
+    pub fn contains(&self, flag: u8) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// Flags that are not defined by the format
+    pub fn unknown(&self) -> u8 {
+        self.0 & !(Self::SOLUTION | Self::STATE)
+    }
+}
+
+/// Color of the palette together with the id that the puzzle uses for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonoColor {
+    pub id: u32,
+    pub color: Color,
+}
+
+/// Run of a rule, which refers to its color by its index in the palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonoRun {
+    pub color: u8,
+    pub len: u16,
+}
+
+/// State of a puzzle that is being solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonoState {
+    pub entries: Grid<u8>,
+    pub timer: Timer,
+}
+
+/// Contents of `*.nono` data, independent of how a puzzle represents them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonoData {
+    pub width: u16,
+    pub height: u16,
+    pub palette: Vec<NonoColor>,
+
+    /// Rules of every row, from top to bottom
+    pub rows: Vec<Vec<NonoRun>>,
+
+    /// Rules of every column, from left to right
+    pub cols: Vec<Vec<NonoRun>>,
+
+    pub solution: Option<Grid<u8>>,
+    pub state: Option<NonoState>,
+    pub meta: Metadata,
+}
+
+impl NonoData {
+    /// Runs of a line of solution bytes, where crossed out cells separate them
+    /// ```
+    /// use puzzled_io::nono::{CROSS, NonoData, NonoRun};
+    ///
+    /// let runs = NonoData::runs(&[1, 1, CROSS, 2, 1]);
+    /// assert_eq!(
+    ///     runs,
+    ///     vec![
+    ///         NonoRun { color: 0, len: 2 },
+    ///         NonoRun { color: 1, len: 1 },
+    ///         NonoRun { color: 0, len: 1 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn runs(line: &[u8]) -> Vec<NonoRun> {
+        let mut runs: Vec<NonoRun> = Vec::new();
+        let mut prev = CROSS;
+
+        for &cell in line {
+            match runs.last_mut() {
+                Some(run) if cell != CROSS && cell == prev => run.len += 1,
+                _ if cell != CROSS => runs.push(NonoRun {
+                    color: cell - 1,
+                    len: 1,
+                }),
+                _ => {}
+            }
+
+            prev = cell;
+        }
+
+        runs
+    }
+}
+
+/// Puzzle that can be read from and written to [`*.nono` data](self)
+pub trait NonoPuzzle<S>: Puzzle + Sized {
+    /// Construct the puzzle and its state from the data that was read
+    fn read_nono(data: NonoData) -> read::Result<(Self, S)>;
+
+    /// Collect the data to write for the puzzle and its state
+    fn nono_data(&self, state: &S) -> write::Result<NonoData>;
+}
+
+/// Checksum of `*.nono` data, which is the [Adler-32](https://en.wikipedia.org/wiki/Adler-32) checksum of its bytes
+/// ```
+/// use puzzled_io::nono::nono_checksum;
+///
+/// assert_eq!(nono_checksum(b"Wikipedia"), 0x11E6_0398);
+/// ```
+pub fn nono_checksum(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + byte as u32) % MOD;
+        (a, (b + a) % MOD)
+    });
+
+    (b << 16) | a
+}
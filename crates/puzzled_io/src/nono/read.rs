@@ -0,0 +1,630 @@
+//! Defines all functionality for reading [`*.nono` data](super) into a [puzzle](NonoPuzzle)
+use std::{fs::File, io, ops::Range, path::Path, str::Utf8Error, time::Duration};
+
+use puzzled_core::{Color, Grid, Line, Metadata, Timer, TimerState};
+use thiserror::Error;
+
+use crate::{
+    Diagnostic, DiagnosticCode, Severity, format,
+    nono::{
+        NONO_MAGIC, NONO_MAJOR_VERSION, NONO_MINOR_VERSION, NonoColor, NonoData, NonoFlags,
+        NonoPuzzle, NonoRun, NonoState, nono_checksum,
+    },
+};
+
+pub type Span = Range<usize>;
+
+#[derive(Debug, Error)]
+#[error("{kind} while reading '{context}'")]
+pub struct Error {
+    /// Where the error occurred
+    pub span: Span,
+    /// What kind of error occurred
+    pub kind: ErrorKind,
+    /// Context for what was currently read when the error occurred
+    pub context: String,
+}
+
+impl Error {
+    pub fn new<C>(context: C, kind: ErrorKind) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            span: Span::default(),
+            kind,
+            context: context.into(),
+        }
+    }
+
+    fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Report the error as a [diagnostic](Diagnostic) of the given severity
+    pub fn to_diagnostic(&self, severity: Severity) -> Diagnostic {
+        Diagnostic::new(severity, self.kind.code(), self.to_string()).with_span(self.span.clone())
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(err: &Error) -> Self {
+        err.to_diagnostic(Severity::Error)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ErrorKind {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Format(#[from] format::Error),
+
+    #[error(
+        "Invalid file magic: .nono files expect 'NONO', but found '{}'",
+        String::from_utf8_lossy(found)
+    )]
+    InvalidFileMagic { found: Vec<u8> },
+
+    #[error("Unsupported version {major}.{minor}, expected {NONO_MAJOR_VERSION}.x")]
+    UnsupportedVersion { major: u8, minor: u8 },
+
+    #[error(
+        "Version {major}.{minor} is newer than {NONO_MAJOR_VERSION}.{NONO_MINOR_VERSION}, so some data may be ignored"
+    )]
+    NewerVersion { major: u8, minor: u8 },
+
+    #[error("Unknown flags {flags:#04x} are set")]
+    UnknownFlags { flags: u8 },
+
+    #[error("Data ended unexpectedly, expected {expected} more byte(s)")]
+    UnexpectedEnd { expected: usize },
+
+    #[error("Invalid checksum '{found:#010x}' found, expected '{expected:#010x}'")]
+    InvalidChecksum { found: u32, expected: u32 },
+
+    #[error("Palette index {index} is out of bounds for a palette of {len} color(s)")]
+    InvalidColor { index: u8, len: usize },
+
+    #[error("String is not valid UTF-8: {0}")]
+    InvalidString(#[from] Utf8Error),
+
+    #[error("Rule of {line:?} does not agree with the solution")]
+    RuleMismatch { line: Line },
+
+    #[error("Found {count} byte(s) after the checksum")]
+    TrailingData { count: usize },
+}
+
+impl ErrorKind {
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::Io(_) => DiagnosticCode::NonoIo,
+            Self::Format(_) => DiagnosticCode::NonoFormat,
+            Self::InvalidFileMagic { .. } => DiagnosticCode::NonoInvalidFileMagic,
+            Self::UnsupportedVersion { .. } | Self::NewerVersion { .. } => {
+                DiagnosticCode::NonoUnsupportedVersion
+            }
+            Self::UnknownFlags { .. } => DiagnosticCode::NonoUnknownFlags,
+            Self::UnexpectedEnd { .. } => DiagnosticCode::NonoUnexpectedEnd,
+            Self::InvalidChecksum { .. } => DiagnosticCode::NonoChecksumMismatch,
+            Self::InvalidColor { .. } => DiagnosticCode::NonoInvalidColor,
+            Self::InvalidString(_) => DiagnosticCode::NonoInvalidString,
+            Self::RuleMismatch { .. } => DiagnosticCode::NonoRuleMismatch,
+            Self::TrailingData { .. } => DiagnosticCode::NonoTrailingData,
+        }
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Reads [puzzles](NonoPuzzle) from [`*.nono` data](super)
+///
+/// Strict readers abort on any issue, whereas other readers recover from a checksum mismatch, a newer minor version, unknown flags,
+/// rules that do not agree with the solution and trailing data by reporting them as [diagnostics](Diagnostic)
+#[derive(Debug, Default)]
+pub struct NonoReader {
+    strict: bool,
+}
+
+impl NonoReader {
+    pub fn new(strict: bool) -> Self {
+        Self { strict }
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn read<R, P, S>(&self, reader: &mut R) -> Result<(P, S)>
+    where
+        R: io::Read,
+        P: NonoPuzzle<S>,
+    {
+        let (puzzle, state, _) = self.read_with_diagnostics(reader)?;
+        Ok((puzzle, state))
+    }
+
+    /// Read a puzzle together with the [diagnostics](Diagnostic) for every issue that was recovered from in non-strict mode
+    pub fn read_with_diagnostics<R, P, S>(&self, reader: &mut R) -> Result<(P, S, Vec<Diagnostic>)>
+    where
+        R: io::Read,
+        P: NonoPuzzle<S>,
+    {
+        let (data, diagnostics) = self.read_data(reader)?;
+        let (puzzle, state) = P::read_nono(data)?;
+
+        Ok((puzzle, state, diagnostics))
+    }
+
+    pub fn read_from_path<R, P, S>(&self, path: R) -> Result<(P, S)>
+    where
+        R: AsRef<Path>,
+        P: NonoPuzzle<S>,
+    {
+        let mut file =
+            File::open(path).map_err(|err| Error::new("Reading file", ErrorKind::Io(err)))?;
+
+        self.read(&mut file)
+    }
+
+    /// Read the raw [data](NonoData) without constructing a puzzle from it
+    pub fn read_data<R>(&self, reader: &mut R) -> Result<(NonoData, Vec<Diagnostic>)>
+    where
+        R: io::Read,
+    {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|err| Error::new("Reading data", ErrorKind::Io(err)))?;
+
+        let mut state = ReadState {
+            strict: self.strict,
+            diagnostics: Vec::new(),
+        };
+        let mut input = Input {
+            bytes: &bytes,
+            pos: 0,
+        };
+
+        // Header
+        let magic = input.slice(NONO_MAGIC.len(), "Magic")?;
+        if magic != NONO_MAGIC {
+            let kind = ErrorKind::InvalidFileMagic {
+                found: magic.to_vec(),
+            };
+            return Err(Error::new("Magic", kind).with_span(0..magic.len()));
+        }
+
+        let (major, minor) = (input.u8("Version")?, input.u8("Version")?);
+        if major != NONO_MAJOR_VERSION {
+            let kind = ErrorKind::UnsupportedVersion { major, minor };
+            return Err(Error::new("Version", kind).with_span(4..6));
+        }
+        if minor > NONO_MINOR_VERSION {
+            let kind = ErrorKind::NewerVersion { major, minor };
+            state.check(Err(Error::new("Version", kind).with_span(4..6)))?;
+        }
+
+        let flags = NonoFlags(input.u8("Flags")?);
+        if flags.unknown() != 0 {
+            let kind = ErrorKind::UnknownFlags {
+                flags: flags.unknown(),
+            };
+            state.check(Err(Error::new("Flags", kind).with_span(6..7)))?;
+        }
+
+        let width = input.u16("Width")?;
+        let height = input.u16("Height")?;
+
+        // Palette
+        let palette_len = input.u8("Palette")? as usize;
+        let palette = (0..palette_len)
+            .map(|_| {
+                let id = input.u32("Palette")?;
+                let [r, g, b, a] = input.array("Palette")?;
+
+                Ok(NonoColor {
+                    id,
+                    color: Color::rgba(r, g, b, a),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Rules
+        let mut read_rules = |count: u16| -> Result<Vec<Vec<NonoRun>>> {
+            (0..count)
+                .map(|_| {
+                    let runs = input.u16("Rules")?;
+
+                    (0..runs)
+                        .map(|_| {
+                            let start = input.pos;
+                            let color = input.u8("Rules")?;
+                            let len = input.u16("Rules")?;
+
+                            check_color(color, palette_len, "Rules", start)?;
+                            Ok(NonoRun { color, len })
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+        let rows = read_rules(height)?;
+        let cols = read_rules(width)?;
+
+        // Solution and state
+        let cell_count = width as usize * height as usize;
+        let mut read_grid = |context: &str, offset: u8| -> Result<Grid<u8>> {
+            let start = input.pos;
+            let cells = input.slice(cell_count, context)?.to_vec();
+
+            for (idx, &cell) in cells.iter().enumerate() {
+                if cell >= offset {
+                    check_color(cell - offset, palette_len, context, start + idx)?;
+                }
+            }
+
+            Ok(Grid::from_vec(cells, width as usize).unwrap_or_default())
+        };
+
+        let solution = match flags.contains(NonoFlags::SOLUTION) {
+            true => Some(read_grid("Solution", 1)?),
+            false => None,
+        };
+        let nono_state = match flags.contains(NonoFlags::STATE) {
+            true => {
+                let entries = read_grid("State", 2)?;
+                let elapsed = input.u32("Timer")?;
+                let timer_state = match input.u8("Timer")? {
+                    0 => TimerState::Running,
+                    _ => TimerState::Stopped,
+                };
+                let timer = Timer::new(Duration::from_secs(elapsed as u64), timer_state);
+
+                Some(NonoState { entries, timer })
+            }
+            false => None,
+        };
+
+        // Strings
+        let mut meta = Metadata::default();
+        if let Some(title) = input.string("Title")? {
+            meta = meta.with_title(title);
+        }
+        if let Some(author) = input.string("Author")? {
+            meta = meta.with_author(author);
+        }
+        if let Some(copyright) = input.string("Copyright")? {
+            meta = meta.with_copyright(copyright);
+        }
+        if let Some(notes) = input.string("Notes")? {
+            meta = meta.with_notes(notes);
+        }
+
+        // Checksum
+        let end = input.pos;
+        let found = input.u32("Checksum")?;
+        let expected = nono_checksum(&bytes[..end]);
+        if found != expected {
+            let kind = ErrorKind::InvalidChecksum { found, expected };
+            state.check(Err(Error::new("Checksum", kind).with_span(end..end + 4)))?;
+        }
+
+        let trailing = bytes.len() - input.pos;
+        if trailing > 0 {
+            let kind = ErrorKind::TrailingData { count: trailing };
+            let span = input.pos..bytes.len();
+            state.check(Err(Error::new("Trailing data", kind).with_span(span)))?;
+        }
+
+        let data = NonoData {
+            width,
+            height,
+            palette,
+            rows,
+            cols,
+            solution,
+            state: nono_state,
+            meta,
+        };
+        validate_rules(&data, &mut state)?;
+
+        Ok((data, state.diagnostics))
+    }
+}
+
+/// Verify that the rules agree with the solution, if the data includes one
+fn validate_rules(data: &NonoData, state: &mut ReadState) -> Result<()> {
+    let Some(solution) = &data.solution else {
+        return Ok(());
+    };
+
+    let rows = data
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(r, rule)| (Line::Row(r), rule));
+    let cols = data
+        .cols
+        .iter()
+        .enumerate()
+        .map(|(c, rule)| (Line::Col(c), rule));
+
+    for (line, rule) in rows.chain(cols) {
+        let cells: Vec<_> = solution.iter_line(line).copied().collect();
+
+        if NonoData::runs(&cells) != *rule {
+            let kind = ErrorKind::RuleMismatch { line };
+            state.check(Err(Error::new("Rules", kind)))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_color(index: u8, len: usize, context: &str, pos: usize) -> Result<()> {
+    if (index as usize) < len {
+        return Ok(());
+    }
+
+    let kind = ErrorKind::InvalidColor { index, len };
+    Err(Error::new(context, kind).with_span(pos..pos + 1))
+}
+
+struct ReadState {
+    strict: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ReadState {
+    /// Abort on an issue in strict mode, or otherwise report it
+    fn check(&mut self, result: Result<()>) -> Result<()> {
+        match result {
+            Err(err) if self.strict => Err(err),
+            Err(err) => {
+                self.diagnostics.push(err.to_diagnostic(Severity::Warning));
+                Ok(())
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+}
+
+/// Bytes that are read from front to back, keeping track of the position for error spans
+struct Input<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Input<'a> {
+    fn slice(&mut self, len: usize, context: &str) -> Result<&'a [u8]> {
+        let end = self.pos + len;
+
+        let Some(slice) = self.bytes.get(self.pos..end) else {
+            let kind = ErrorKind::UnexpectedEnd {
+                expected: end - self.bytes.len(),
+            };
+            return Err(Error::new(context, kind).with_span(self.pos..self.bytes.len()));
+        };
+
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self, context: &str) -> Result<[u8; N]> {
+        let slice = self.slice(N, context)?;
+        Ok(slice.try_into().expect("Slice has length N"))
+    }
+
+    fn u8(&mut self, context: &str) -> Result<u8> {
+        Ok(self.slice(1, context)?[0])
+    }
+
+    fn u16(&mut self, context: &str) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.array(context)?))
+    }
+
+    fn u32(&mut self, context: &str) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.array(context)?))
+    }
+
+    /// Read a null-terminated string, where empty strings are missing
+    fn string(&mut self, context: &str) -> Result<Option<String>> {
+        let start = self.pos;
+        let len = self.bytes[start..]
+            .iter()
+            .position(|&byte| byte == b'\0')
+            .ok_or_else(|| {
+                let kind = ErrorKind::UnexpectedEnd { expected: 1 };
+                Error::new(context, kind).with_span(start..self.bytes.len())
+            })?;
+
+        let bytes = self.slice(len + 1, context)?;
+        let string = std::str::from_utf8(&bytes[..len])
+            .map_err(|err| Error::new(context, err.into()).with_span(start..start + len))?;
+
+        Ok((!string.is_empty()).then(|| string.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use crate::nono::{BLANK, CROSS, NonoWriter};
+
+    use super::*;
+
+    #[fixture]
+    fn data() -> NonoData {
+        let solution = Grid::from_vec(vec![1, 1, CROSS, 2], 2).unwrap();
+        let entries = Grid::from_vec(vec![3, BLANK, CROSS, BLANK], 2).unwrap();
+
+        NonoData {
+            width: 2,
+            height: 2,
+            palette: vec![
+                NonoColor {
+                    id: '1' as u32,
+                    color: Color::rgb(0, 0, 0),
+                },
+                NonoColor {
+                    id: '2' as u32,
+                    color: Color::rgba(255, 0, 0, 128),
+                },
+            ],
+            rows: vec![
+                vec![NonoRun { color: 0, len: 2 }],
+                vec![NonoRun { color: 1, len: 1 }],
+            ],
+            cols: vec![
+                vec![NonoRun { color: 0, len: 1 }],
+                vec![NonoRun { color: 0, len: 1 }, NonoRun { color: 1, len: 1 }],
+            ],
+            solution: Some(solution),
+            state: Some(NonoState {
+                entries,
+                timer: Timer::new(Duration::from_secs(42), TimerState::Stopped),
+            }),
+            meta: Metadata::default()
+                .with_title("Title".to_string())
+                .with_notes("Notes".to_string()),
+        }
+    }
+
+    fn bytes(data: &NonoData) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        NonoWriter::new().write_data(&mut bytes, data).unwrap();
+
+        bytes
+    }
+
+    /// Replace the checksum at the end of the bytes such that it is valid again
+    fn fix_checksum(bytes: &mut [u8]) {
+        let end = bytes.len() - 4;
+        let checksum = nono_checksum(&bytes[..end]);
+
+        bytes[end..].copy_from_slice(&checksum.to_le_bytes());
+    }
+
+    #[rstest]
+    fn round_trip(data: NonoData) {
+        let bytes = bytes(&data);
+        let (read, diagnostics) = NonoReader::new(true).read_data(&mut &bytes[..]).unwrap();
+
+        assert_eq!(read, data);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[rstest]
+    fn round_trip_without_optionals(mut data: NonoData) {
+        data.solution = None;
+        data.state = None;
+        data.meta = Metadata::default();
+
+        let bytes = bytes(&data);
+        let (read, _) = NonoReader::new(true).read_data(&mut &bytes[..]).unwrap();
+
+        assert_eq!(read, data);
+    }
+
+    #[rstest]
+    fn checksum(data: NonoData) {
+        let mut bytes = bytes(&data);
+        *bytes.last_mut().unwrap() ^= 0xFF;
+
+        let err = NonoReader::new(true)
+            .read_data(&mut &bytes[..])
+            .unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::InvalidChecksum { .. }));
+
+        let (read, diagnostics) = NonoReader::new(false).read_data(&mut &bytes[..]).unwrap();
+        assert_eq!(read, data);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, DiagnosticCode::NonoChecksumMismatch);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+
+    #[rstest]
+    fn rule_mismatch(mut data: NonoData) {
+        data.rows[1] = vec![NonoRun { color: 0, len: 1 }];
+        let bytes = bytes(&data);
+
+        let err = NonoReader::new(true)
+            .read_data(&mut &bytes[..])
+            .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            ErrorKind::RuleMismatch { line: Line::Row(1) }
+        ));
+
+        let (_, diagnostics) = NonoReader::new(false).read_data(&mut &bytes[..]).unwrap();
+        let codes: Vec<_> = diagnostics.iter().map(|diag| diag.code).collect();
+        assert_eq!(codes, vec![DiagnosticCode::NonoRuleMismatch]);
+    }
+
+    #[rstest]
+    #[case::newer_version(5, 1, DiagnosticCode::NonoUnsupportedVersion)]
+    #[case::unknown_flags(6, 0x80 | NonoFlags::SOLUTION | NonoFlags::STATE, DiagnosticCode::NonoUnknownFlags)]
+    fn recoverable(
+        data: NonoData,
+        #[case] pos: usize,
+        #[case] byte: u8,
+        #[case] code: DiagnosticCode,
+    ) {
+        let mut bytes = bytes(&data);
+        bytes[pos] = byte;
+        fix_checksum(&mut bytes);
+
+        assert!(NonoReader::new(true).read_data(&mut &bytes[..]).is_err());
+
+        let (_, diagnostics) = NonoReader::new(false).read_data(&mut &bytes[..]).unwrap();
+        let codes: Vec<_> = diagnostics.iter().map(|diag| diag.code).collect();
+        assert_eq!(codes, vec![code]);
+    }
+
+    #[rstest]
+    fn trailing_data(data: NonoData) {
+        let mut bytes = bytes(&data);
+        bytes.extend_from_slice(b"extra");
+
+        let err = NonoReader::new(true)
+            .read_data(&mut &bytes[..])
+            .unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::TrailingData { count: 5 }));
+        assert!(NonoReader::new(false).read_data(&mut &bytes[..]).is_ok());
+    }
+
+    #[rstest]
+    #[case::magic(0, b'X', DiagnosticCode::NonoInvalidFileMagic)]
+    #[case::major_version(4, 2, DiagnosticCode::NonoUnsupportedVersion)]
+    #[case::palette_index(30, 2, DiagnosticCode::NonoInvalidColor)]
+    fn unrecoverable(
+        data: NonoData,
+        #[case] pos: usize,
+        #[case] byte: u8,
+        #[case] code: DiagnosticCode,
+    ) {
+        let mut bytes = bytes(&data);
+        bytes[pos] = byte;
+        fix_checksum(&mut bytes);
+
+        let err = NonoReader::new(false)
+            .read_data(&mut &bytes[..])
+            .unwrap_err();
+        assert_eq!(err.kind.code(), code);
+    }
+
+    #[rstest]
+    fn truncated(data: NonoData) {
+        let bytes = bytes(&data);
+        let err = NonoReader::new(false)
+            .read_data(&mut &bytes[..bytes.len() - 2])
+            .unwrap_err();
+
+        assert!(matches!(err.kind, ErrorKind::UnexpectedEnd { expected: 2 }));
+    }
+}
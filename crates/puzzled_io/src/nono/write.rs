@@ -0,0 +1,235 @@
+//! Defines all functionality for writing a [puzzle](NonoPuzzle) as [`*.nono` data](super)
+use std::io;
+
+use puzzled_core::{Grid, TimerState};
+use thiserror::Error;
+
+use crate::{
+    Diagnostic, DiagnosticCode, Severity, format,
+    nono::{
+        NONO_MAGIC, NONO_MAJOR_VERSION, NONO_MINOR_VERSION, NonoData, NonoFlags, NonoPuzzle,
+        NonoRun, nono_checksum,
+    },
+};
+
+#[derive(Debug, Error)]
+#[error("{kind} while writing '{context}'")]
+pub struct Error {
+    /// What kind of error occurred
+    pub kind: ErrorKind,
+    /// Context for what was currently written when the error occurred
+    pub context: String,
+}
+
+impl Error {
+    pub fn new<C>(context: C, kind: ErrorKind) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            kind,
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ErrorKind {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Format(#[from] format::Error),
+
+    #[error("{size} is too large, at most {max} is allowed")]
+    TooLarge { size: usize, max: usize },
+
+    #[error("Grid of {rows}x{cols} does not match the {height}x{width} puzzle")]
+    InvalidGridSize {
+        rows: usize,
+        cols: usize,
+        width: u16,
+        height: u16,
+    },
+
+    #[error("Palette index {index} is out of bounds for a palette of {len} color(s)")]
+    InvalidColor { index: u8, len: usize },
+
+    #[error("Expected {expected} rules, found {found}")]
+    InvalidRuleCount { found: usize, expected: usize },
+
+    #[error("String contains a null byte")]
+    InvalidString,
+}
+
+impl ErrorKind {
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::Io(_) => DiagnosticCode::NonoIo,
+            Self::Format(_)
+            | Self::TooLarge { .. }
+            | Self::InvalidColor { .. }
+            | Self::InvalidGridSize { .. }
+            | Self::InvalidRuleCount { .. }
+            | Self::InvalidString => DiagnosticCode::NonoFormat,
+        }
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(err: &Error) -> Self {
+        Diagnostic::new(Severity::Error, err.kind.code(), err.to_string())
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Writes [puzzles](NonoPuzzle) as [`*.nono` data](super)
+#[derive(Debug, Default)]
+pub struct NonoWriter;
+
+impl NonoWriter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn write<W, P, S>(&self, writer: &mut W, puzzle: &P, state: &S) -> Result<()>
+    where
+        W: io::Write,
+        P: NonoPuzzle<S>,
+    {
+        let data = puzzle.nono_data(state)?;
+        self.write_data(writer, &data)
+    }
+
+    /// Write the raw [data](NonoData), after verifying that its components agree with each other
+    pub fn write_data<W>(&self, writer: &mut W, data: &NonoData) -> Result<()>
+    where
+        W: io::Write,
+    {
+        let mut bytes = Vec::new();
+
+        // Header
+        let mut flags = 0;
+        if data.solution.is_some() {
+            flags |= NonoFlags::SOLUTION;
+        }
+        if data.state.is_some() {
+            flags |= NonoFlags::STATE;
+        }
+
+        bytes.extend_from_slice(NONO_MAGIC);
+        bytes.extend_from_slice(&[NONO_MAJOR_VERSION, NONO_MINOR_VERSION, flags]);
+        bytes.extend_from_slice(&data.width.to_le_bytes());
+        bytes.extend_from_slice(&data.height.to_le_bytes());
+
+        // Palette
+        let palette_len = check_size("Palette", data.palette.len(), u8::MAX as usize)?;
+        bytes.push(palette_len as u8);
+
+        for color in &data.palette {
+            let rgba = [
+                color.color.red,
+                color.color.green,
+                color.color.blue,
+                color.color.alpha,
+            ];
+
+            bytes.extend_from_slice(&color.id.to_le_bytes());
+            bytes.extend_from_slice(&rgba);
+        }
+
+        // Rules
+        let mut write_rules = |rules: &[Vec<NonoRun>], expected: u16| -> Result<()> {
+            if rules.len() != expected as usize {
+                let kind = ErrorKind::InvalidRuleCount {
+                    found: rules.len(),
+                    expected: expected as usize,
+                };
+                return Err(Error::new("Rules", kind));
+            }
+
+            for rule in rules {
+                let runs = check_size("Rules", rule.len(), u16::MAX as usize)?;
+                bytes.extend_from_slice(&(runs as u16).to_le_bytes());
+
+                for run in rule {
+                    if run.color as usize >= palette_len {
+                        let kind = ErrorKind::InvalidColor {
+                            index: run.color,
+                            len: palette_len,
+                        };
+                        return Err(Error::new("Rules", kind));
+                    }
+
+                    bytes.push(run.color);
+                    bytes.extend_from_slice(&run.len.to_le_bytes());
+                }
+            }
+
+            Ok(())
+        };
+        write_rules(&data.rows, data.height)?;
+        write_rules(&data.cols, data.width)?;
+
+        // Solution and state
+        let mut write_grid = |grid: &Grid<u8>, context: &str| -> Result<()> {
+            if (grid.rows(), grid.cols()) != (data.height as usize, data.width as usize) {
+                let kind = ErrorKind::InvalidGridSize {
+                    rows: grid.rows(),
+                    cols: grid.cols(),
+                    width: data.width,
+                    height: data.height,
+                };
+                return Err(Error::new(context, kind));
+            }
+
+            bytes.extend(grid.iter());
+            Ok(())
+        };
+
+        if let Some(solution) = &data.solution {
+            write_grid(solution, "Solution")?;
+        }
+        if let Some(state) = &data.state {
+            write_grid(&state.entries, "State")?;
+
+            let elapsed = state.timer.elapsed().as_secs();
+            let elapsed = check_size("Timer", elapsed as usize, u32::MAX as usize)?;
+            let running = state.timer.state() == TimerState::Running;
+
+            bytes.extend_from_slice(&(elapsed as u32).to_le_bytes());
+            bytes.push(if running { 0 } else { 1 });
+        }
+
+        // Strings
+        let meta = &data.meta;
+        let strings = [meta.title(), meta.author(), meta.copyright(), meta.notes()];
+
+        for string in strings {
+            let string = string.unwrap_or_default();
+            if string.contains('\0') {
+                return Err(Error::new("Strings", ErrorKind::InvalidString));
+            }
+
+            bytes.extend_from_slice(string.as_bytes());
+            bytes.push(b'\0');
+        }
+
+        // Checksum
+        let checksum = nono_checksum(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        writer
+            .write_all(&bytes)
+            .map_err(|err| Error::new("Writing data", err.into()))
+    }
+}
+
+fn check_size(context: &str, size: usize, max: usize) -> Result<usize> {
+    if size > max {
+        return Err(Error::new(context, ErrorKind::TooLarge { size, max }));
+    }
+
+    Ok(size)
+}
@@ -0,0 +1,33 @@
+//! Stable surface of the `*.puz` format
+//!
+//! The [`puz`](super) module exposes the full layout of the format, such as its [header](super::Header), [grids](super::Grids) and [extra sections](super::Extras), which may change as more of the format is supported.
+//! Items that are re-exported here are the ones that readers and writers of puzzles can rely on across releases.
+//! ```
+//! use puzzled::crossword::{crossword, Crossword, CrosswordState};
+//! use puzzled::io::puz::api::{PuzReader, PuzWriter, ReadOptions};
+//!
+//! let puzzle = crossword!([A B] [C D]);
+//! let state = CrosswordState::from(&puzzle);
+//!
+//! let mut bytes = Vec::new();
+//! PuzWriter.write(&mut bytes, &puzzle, &state).unwrap();
+//!
+//! let reader = PuzReader::with_options(ReadOptions::default());
+//! let (read, _): (Crossword, CrosswordState) = reader.read(&mut bytes.as_slice()).unwrap();
+//!
+//! assert_eq!(read, puzzle);
+//! ```
+
+#[doc(no_inline)]
+pub use super::{BinaryPuzzle, PuzRead, PuzReader, PuzWrite, PuzWriter};
+
+#[doc(no_inline)]
+pub use super::read::{
+    Error as ReadError, ReadCheck, ReadOptions, Result as ReadResult, Strictness,
+};
+
+#[doc(no_inline)]
+pub use super::write::{Error as WriteError, Result as WriteResult, WriteOptions};
+
+#[doc(no_inline)]
+pub use crate::{Diagnostic, DiagnosticCode, Severity, format::Error as FormatError};
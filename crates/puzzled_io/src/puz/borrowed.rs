@@ -0,0 +1,213 @@
+use std::borrow::Cow;
+
+use puzzled_core::Version;
+
+use crate::puz::{
+    Context, Encoding, FILE_MAGIC_BYTES, Header, PuzReader, PuzState, find_cib_checksum,
+    find_region_checksum, read,
+};
+
+/// Sections of `*.puz` data that borrow from the data instead of copying it
+///
+/// Reading with [`PuzReader::read_borrowed`] only splits the data into its sections, such that no string is decoded or allocated until it is requested.
+/// This makes it cheap to scan many files for a few properties, e.g. to index the titles of a puzzle archive.
+/// Strings are given without their trailing `\0` and can be [decoded](Self::decode) with the [encoding](Self::encoding) of the puzzle.
+/// ```
+/// use puzzled::io::puz::PuzReader;
+///
+/// let data = std::fs::read("../puzzled_crossword/puzzles/ok/mini.puz").unwrap();
+/// let puz = PuzReader::new(false).read_borrowed(&data).unwrap();
+///
+/// assert_eq!(puz.solution.len(), puz.width() * puz.height());
+/// assert_eq!(puz.clues.len(), puz.header.clue_count as usize);
+/// assert!(puz.has_valid_checksums());
+/// assert_eq!(puz.decode(puz.title), "mini");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BorrowedPuz<'a> {
+    pub header: Header,
+
+    /// Solution grid, read row by row
+    pub solution: &'a [u8],
+
+    /// State grid, read row by row
+    pub state: &'a [u8],
+
+    pub title: &'a [u8],
+    pub author: &'a [u8],
+    pub copyright: &'a [u8],
+    pub clues: Vec<&'a [u8]>,
+    pub notes: &'a [u8],
+
+    /// Data of the extra sections, which are left unparsed
+    pub extras: &'a [u8],
+}
+
+impl<'a> BorrowedPuz<'a> {
+    pub fn width(&self) -> usize {
+        self.header.width as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.header.height as usize
+    }
+
+    /// Encoding of the strings, as given by the version of the puzzle
+    pub fn encoding(&self) -> Encoding {
+        Encoding::for_version(Version::from_bytes(&self.header.version).ok())
+    }
+
+    /// Decode a string of the puzzle, only allocating if it is not valid UTF-8 already
+    pub fn decode(&self, bytes: &'a [u8]) -> Cow<'a, str> {
+        match (self.encoding(), std::str::from_utf8(bytes)) {
+            (Encoding::Utf8, Ok(str)) => Cow::Borrowed(str),
+            (_, Ok(str)) if str.is_ascii() => Cow::Borrowed(str),
+            (encoding, _) => Cow::Owned(encoding.decode_lossy(bytes)),
+        }
+    }
+
+    /// Whether the [CIB](crate::puz#cib) and [file](crate::puz#file) checksums match the data
+    ///
+    /// The checksums are computed on the borrowed data, where clues are checksummed without their trailing `\0` and the other strings with it if they are not empty
+    pub fn has_valid_checksums(&self) -> bool {
+        let cib_checksum = find_cib_checksum(&self.header.cib_region);
+        if cib_checksum != self.header.cib_checksum {
+            return false;
+        }
+
+        let with_nul = |bytes: &[u8], checksum: u16| match bytes.is_empty() {
+            true => checksum,
+            false => find_region_checksum(&[0], find_region_checksum(bytes, checksum)),
+        };
+
+        let mut checksum = find_region_checksum(self.solution, cib_checksum);
+        checksum = find_region_checksum(self.state, checksum);
+
+        for str in [self.title, self.author, self.copyright] {
+            checksum = with_nul(str, checksum);
+        }
+        for clue in &self.clues {
+            checksum = find_region_checksum(clue, checksum);
+        }
+        checksum = with_nul(self.notes, checksum);
+
+        checksum == self.header.file_checksum
+    }
+}
+
+impl PuzReader {
+    /// Split `*.puz` data into its [sections](BorrowedPuz) without copying the grids and strings
+    ///
+    /// Data without the [file magic](crate::puz::Header) of `*.puz` files is rejected.
+    /// Otherwise, only the header is validated according to the [options](crate::puz::read::ReadOptions) of the reader, while checksums and encodings are left for the caller to [verify](BorrowedPuz::has_valid_checksums) when needed
+    pub fn read_borrowed<'a>(&self, data: &'a [u8]) -> read::Result<BorrowedPuz<'a>> {
+        let mut state = PuzState::new(self.options().clone());
+        let mut rest = data;
+
+        let header = Header::read_from(&mut rest, &mut state)?;
+        if &header.file_magic != FILE_MAGIC_BYTES {
+            let found = String::from_utf8_lossy(&header.file_magic).into_owned();
+            return Err(read::Error::new(
+                "File magic",
+                read::ErrorKind::InvalidFileMagic { found },
+            ));
+        }
+
+        let size = header.width as usize * header.height as usize;
+        let solution = split(&mut rest, size).context("Solution grid")?;
+        let state = split(&mut rest, size).context("State grid")?;
+
+        let title = split_str(&mut rest).context("Title")?;
+        let author = split_str(&mut rest).context("Author")?;
+        let copyright = split_str(&mut rest).context("Copyright")?;
+
+        let mut clues = Vec::with_capacity(header.clue_count as usize);
+        for num in 1..=header.clue_count {
+            clues.push(split_str(&mut rest).context(format!("Clue #{num}"))?);
+        }
+
+        let notes = split_str(&mut rest).context("Notes")?;
+
+        Ok(BorrowedPuz {
+            header,
+            solution,
+            state,
+            title,
+            author,
+            copyright,
+            clues,
+            notes,
+            extras: rest,
+        })
+    }
+}
+
+/// Split off the first `len` bytes of the data
+fn split<'a>(data: &mut &'a [u8], len: usize) -> std::io::Result<&'a [u8]> {
+    if data.len() < len {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+
+    Ok(head)
+}
+
+/// Split off a `\0` terminated string, without its terminator
+fn split_str<'a>(data: &mut &'a [u8]) -> std::io::Result<&'a [u8]> {
+    let Some(end) = data.iter().position(|&byte| byte == b'\0') else {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    };
+
+    let str = split(data, end + 1)?;
+    Ok(&str[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::puz::{Grids, Strings};
+
+    #[rstest]
+    fn same_as_owned(#[files("../puzzled_crossword/puzzles/ok/*.puz")] path: PathBuf) {
+        let data = fs::read(path).expect("puzzle file exists");
+        let puz = PuzReader::new(false)
+            .read_borrowed(&data)
+            .expect("puzzle is split");
+
+        let mut rest = data.as_slice();
+        let header = Header::read_from(&mut rest, &mut PuzState::default()).unwrap();
+        let grids = Grids::read_from(&mut rest, header.width, header.height).unwrap();
+        let strings = Strings::read_from(&mut rest, header.clue_count, puz.encoding()).unwrap();
+
+        assert_eq!(puz.solution, grids.solution.data());
+        assert_eq!(puz.state, grids.state.data());
+        assert_eq!(puz.title, strings.title.bytes(false));
+        assert_eq!(puz.notes, strings.notes.bytes(false));
+        assert_eq!(puz.clues.len(), strings.clues.len());
+        assert_eq!(puz.extras, rest);
+    }
+
+    #[test]
+    fn truncated() {
+        let data = fs::read("../puzzled_crossword/puzzles/ok/mini.puz").unwrap();
+        let result = PuzReader::new(false).read_borrowed(&data[..60]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case::valid("../puzzled_crossword/puzzles/ok/mini.puz", true)]
+    #[case::invalid("../puzzled_crossword/puzzles/ok/alphabet.puz", false)]
+    fn checksums(#[case] path: &str, #[case] is_valid: bool) {
+        let data = fs::read(path).unwrap();
+        let puz = PuzReader::new(false).read_borrowed(&data).unwrap();
+
+        assert_eq!(puz.has_valid_checksums(), is_valid);
+    }
+}
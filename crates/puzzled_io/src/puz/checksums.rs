@@ -1,4 +1,7 @@
-use crate::puz::{ByteStr, Grids, Header, PuzReader, PuzState, PuzWriter, Span, Strings, read};
+use crate::puz::{
+    ByteStr, Encoding, Grids, Header, PuzChecksums, PuzReader, PuzState, PuzWriter, Span, Strings,
+    read::{self, ReadCheck},
+};
 
 #[doc(hidden)]
 pub fn find_region_checksum(region: &[u8], start: u16) -> u16 {
@@ -80,6 +83,149 @@ pub(crate) fn find_mask_checksums(
     ]
 }
 
+/// Checksums of the header for the given sections
+///
+/// The strings are checksummed through `strings_checksum`, which is given the checksum to start from, such that their checksums can be [cached](ChecksumCache)
+pub(crate) fn find_checksums<F>(
+    header: &Header,
+    grids: &Grids,
+    mut strings_checksum: F,
+) -> PuzChecksums
+where
+    F: FnMut(u16) -> u16,
+{
+    let cib = find_cib_checksum(&header.cib_region);
+
+    let solution_region = grids.solution.data();
+    let state_region = grids.state.data();
+
+    let mut file = find_region_checksum(solution_region, cib);
+    file = find_region_checksum(state_region, file);
+    file = strings_checksum(file);
+
+    let masks = find_mask_checksums(
+        cib,
+        find_region_checksum(solution_region, 0),
+        find_region_checksum(state_region, 0),
+        strings_checksum(0),
+    );
+
+    PuzChecksums { file, cib, masks }
+}
+
+/// Cache of the checksums of the [strings](Strings) of a puzzle
+///
+/// The strings make up most of the data of large puzzles, while their checksums only change when one of the strings does.
+/// Puzzles that keep a cache [invalidate](Self::invalidate) it whenever their clues or metadata change, such that writing a slightly modified puzzle only checksums the strings again if they were modified.
+/// The [CIB](crate::puz#cib) and grids are small in comparison and always checksummed.
+///
+/// Since the [file checksum](crate::puz#file) continues from the checksum of the grids, the strings are cached both on their own and for the last checksum they continued from
+/// ```
+/// use puzzled::io::puz::{ByteStr, ChecksumCache, Encoding, Strings, find_strings_checksum};
+///
+/// let mut strings = Strings::default();
+/// strings.title = ByteStr::new(b"Title");
+///
+/// let mut cache = ChecksumCache::default();
+/// assert_eq!(cache.get(Encoding::default(), 0), None);
+///
+/// let checksum = cache.find(&strings, 0);
+/// assert_eq!(checksum, find_strings_checksum(&strings, 0));
+/// assert_eq!(cache.get(Encoding::default(), 0), Some(checksum));
+///
+/// cache.invalidate();
+/// assert!(!cache.is_cached());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChecksumCache {
+    strings: Option<CachedStrings>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CachedStrings {
+    encoding: Encoding,
+    standalone: Option<u16>,
+    chained: Option<(u16, u16)>,
+}
+
+impl ChecksumCache {
+    /// Whether any checksum of the strings is cached
+    pub fn is_cached(&self) -> bool {
+        self.strings.is_some()
+    }
+
+    /// Forget the cached checksums, e.g. because the strings have changed
+    pub fn invalidate(&mut self) {
+        self.strings = None;
+    }
+
+    /// Cached checksum of the strings with the given encoding, continuing from the `start` checksum
+    pub fn get(&self, encoding: Encoding, start: u16) -> Option<u16> {
+        let cached = self.strings.filter(|cached| cached.encoding == encoding)?;
+
+        match cached.chained {
+            Some((chained_start, checksum)) if chained_start == start => Some(checksum),
+            _ if start == 0 => cached.standalone,
+            _ => None,
+        }
+    }
+
+    /// Checksum of the strings continuing from the `start` checksum, which is only computed if it is not cached yet
+    pub fn find(&mut self, strings: &Strings, start: u16) -> u16 {
+        if let Some(checksum) = self.get(strings.encoding, start) {
+            return checksum;
+        }
+
+        let checksum = find_strings_checksum(strings, start);
+        self.insert(strings.encoding, start, checksum);
+
+        checksum
+    }
+
+    /// [Checksums](PuzChecksums) of the header for the given sections, only building the strings if their checksums are not cached
+    pub fn checksums<F>(
+        &mut self,
+        header: &Header,
+        grids: &Grids,
+        encoding: Encoding,
+        build_strings: F,
+    ) -> PuzChecksums
+    where
+        F: FnOnce() -> Strings,
+    {
+        let mut build_strings = Some(build_strings);
+        let mut strings = None;
+
+        find_checksums(header, grids, |start| {
+            if let Some(checksum) = self.get(encoding, start) {
+                return checksum;
+            }
+
+            let strings = strings.get_or_insert_with(|| {
+                let build = build_strings.take().expect("Strings are only built once");
+                build()
+            });
+            self.find(strings, start)
+        })
+    }
+
+    fn insert(&mut self, encoding: Encoding, start: u16, checksum: u16) {
+        let cached = match &mut self.strings {
+            Some(cached) if cached.encoding == encoding => cached,
+            strings => strings.insert(CachedStrings {
+                encoding,
+                standalone: None,
+                chained: None,
+            }),
+        };
+
+        match start {
+            0 => cached.standalone = Some(checksum),
+            _ => cached.chained = Some((start, checksum)),
+        }
+    }
+}
+
 impl PuzReader {
     pub(crate) fn validate_checksums(
         &self,
@@ -88,38 +234,26 @@ impl PuzReader {
         strings: &Strings,
         state: &mut PuzState,
     ) -> read::Result<()> {
+        let checksums =
+            find_checksums(header, grids, |start| find_strings_checksum(strings, start));
+
         // CIB
-        let cib_checksum = find_cib_checksum(&header.cib_region);
-        self.validate_checksum("CIB".to_string(), cib_checksum, header.cib_checksum, state)?;
+        self.validate_checksum("CIB".to_string(), checksums.cib, header.cib_checksum, state)?;
 
         // File
-        let solution_region = grids.solution.data();
-        let state_region = grids.state.data();
-
-        let file_checksum =
-            find_file_checksum(cib_checksum, solution_region, state_region, strings);
-
         self.validate_checksum(
             "File".to_string(),
-            file_checksum,
+            checksums.file,
             header.file_checksum,
             state,
         )?;
 
         // Masks
-        let solution_checksum = find_region_checksum(solution_region, 0);
-        let state_checksum = find_region_checksum(state_region, 0);
-        let strings_checksum = find_strings_checksum(strings, 0);
-
-        let mask_checksums = find_mask_checksums(
-            cib_checksum,
-            solution_checksum,
-            state_checksum,
-            strings_checksum,
-        );
-
-        for (idx, (&found, expected)) in
-            mask_checksums.iter().zip(header.mask_checksums).enumerate()
+        for (idx, (&found, expected)) in checksums
+            .masks
+            .iter()
+            .zip(header.mask_checksums)
+            .enumerate()
         {
             let kind = format!(
                 "{} mask #{}",
@@ -146,33 +280,31 @@ impl PuzReader {
             context,
         });
 
-        state.ok_or_warn(result)
+        state.check(ReadCheck::Checksums, result)
     }
 }
 
 impl PuzWriter {
     pub(crate) fn write_checksums(&self, header: &mut Header, grids: &Grids, strings: &Strings) {
-        // CIB
-        let cib_checksum = find_cib_checksum(&header.cib_region);
-        header.cib_checksum = cib_checksum;
-
-        // File
-        let solution_region = grids.solution.data();
-        let state_region = grids.state.data();
+        self.write_cached_checksums(header, grids, strings, None);
+    }
 
-        header.file_checksum =
-            find_file_checksum(cib_checksum, solution_region, state_region, strings);
+    /// Write the checksums of the sections into the header, reusing the checksums of the strings that are in the cache
+    pub(crate) fn write_cached_checksums(
+        &self,
+        header: &mut Header,
+        grids: &Grids,
+        strings: &Strings,
+        cache: Option<&ChecksumCache>,
+    ) {
+        let checksums = find_checksums(header, grids, |start| {
+            cache
+                .and_then(|cache| cache.get(strings.encoding, start))
+                .unwrap_or_else(|| find_strings_checksum(strings, start))
+        });
 
-        // Masks
-        let solution_checksum = find_region_checksum(solution_region, 0);
-        let state_checksum = find_region_checksum(state_region, 0);
-        let strings_checksum = find_strings_checksum(strings, 0);
-
-        header.mask_checksums = find_mask_checksums(
-            cib_checksum,
-            solution_checksum,
-            state_checksum,
-            strings_checksum,
-        );
+        header.cib_checksum = checksums.cib;
+        header.file_checksum = checksums.file;
+        header.mask_checksums = checksums.masks;
     }
 }
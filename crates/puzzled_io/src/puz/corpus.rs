@@ -0,0 +1,186 @@
+//! Scanning of large collections of `*.puz` files
+//!
+//! [`scan`] only splits every file into its [sections](BorrowedPuz) to collect a few [properties](CorpusEntry) of the puzzle, such that thousands of files can be indexed quickly.
+//! Puzzles are only fully parsed once they are [read](CorpusEntry::read) from their entry.
+//! With the `mmap` feature, files are memory-mapped rather than read into memory.
+//! ```
+//! use puzzled::io::puz::corpus;
+//!
+//! let entries = corpus::scan("../puzzled_crossword/puzzles/ok").unwrap();
+//! let mini = entries
+//!     .map(|entry| entry.unwrap())
+//!     .find(|entry| entry.path.ends_with("mini.puz"))
+//!     .unwrap();
+//!
+//! assert_eq!((mini.title.as_str(), mini.author.as_str()), ("mini", "eevdriet"));
+//! assert_eq!((mini.width, mini.height), (3, 3));
+//! assert!(mini.has_valid_checksums);
+//! ```
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    vec,
+};
+
+use crate::puz::{
+    BinaryPuzzle, BorrowedPuz, PuzReader,
+    read::{self, Error, ErrorKind},
+};
+
+/// Properties of a `*.puz` file that are found without fully parsing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorpusEntry {
+    pub path: PathBuf,
+    pub title: String,
+    pub author: String,
+    pub width: u8,
+    pub height: u8,
+    pub clue_count: u16,
+
+    /// Whether the [checksums](BorrowedPuz::has_valid_checksums) of the file match its data
+    pub has_valid_checksums: bool,
+}
+
+impl CorpusEntry {
+    fn new(path: PathBuf, puz: &BorrowedPuz) -> Self {
+        Self {
+            title: puz.decode(puz.title).into_owned(),
+            author: puz.decode(puz.author).into_owned(),
+            width: puz.header.width,
+            height: puz.header.height,
+            clue_count: puz.header.clue_count,
+            has_valid_checksums: puz.has_valid_checksums(),
+            path,
+        }
+    }
+
+    /// Fully parse the puzzle of the entry
+    /// ```
+    /// use puzzled::{crossword::{Crossword, CrosswordState}, io::puz::{corpus, PuzReader}};
+    ///
+    /// let reader = PuzReader::new(false);
+    /// for entry in corpus::scan("../puzzled_crossword/puzzles/ok").unwrap().flatten() {
+    ///     if entry.has_valid_checksums {
+    ///         let (puzzle, _): (Crossword, CrosswordState) = entry.read(&reader).unwrap();
+    ///         assert_eq!(puzzle.rows(), entry.height as usize);
+    ///     }
+    /// }
+    /// ```
+    pub fn read<P, S>(&self, reader: &PuzReader) -> read::Result<(P, S)>
+    where
+        P: BinaryPuzzle<S>,
+    {
+        reader.read_from_path(&self.path)
+    }
+}
+
+/// Iterator over the [entries](CorpusEntry) of the `*.puz` files in a directory, see [`scan`]
+#[derive(Debug)]
+pub struct Scan {
+    reader: PuzReader,
+    paths: vec::IntoIter<PathBuf>,
+}
+
+impl Iterator for Scan {
+    type Item = read::Result<CorpusEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.paths.next()?;
+        let context = format!("Scanning {}", path.display());
+
+        let entry = map_file(&path)
+            .map_err(|err| Error::new(&context, ErrorKind::Io(err)))
+            .and_then(|data| {
+                let puz = self.reader.read_borrowed(&data)?;
+                Ok(CorpusEntry::new(path, &puz))
+            });
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.paths.size_hint()
+    }
+}
+
+/// Scan all `*.puz` files in the directory and its subdirectories in order of their path
+///
+/// Files that cannot be split into sections yield an error, without stopping the scan
+pub fn scan<P: AsRef<Path>>(dir: P) -> io::Result<Scan> {
+    let mut paths = Vec::new();
+    collect_puz_files(dir.as_ref(), &mut paths)?;
+    paths.sort();
+
+    Ok(Scan {
+        reader: PuzReader::new(false),
+        paths: paths.into_iter(),
+    })
+}
+
+fn collect_puz_files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_puz_files(&path, paths)?;
+        } else if path.extension().is_some_and(|ext| ext == "puz") {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "mmap")]
+fn map_file(path: &Path) -> io::Result<memmap2::Mmap> {
+    let file = fs::File::open(path)?;
+
+    // SAFETY: the map is only read while scanning a single entry, during which the file is not expected to be modified
+    unsafe { memmap2::Mmap::map(&file) }
+}
+
+#[cfg(not(feature = "mmap"))]
+fn map_file(path: &Path) -> io::Result<Vec<u8>> {
+    fs::read(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_dir() {
+        let entries: Vec<_> = scan("../puzzled_crossword/puzzles/ok")
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+
+        let names: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            ["alphabet.puz", "mini.puz", "out-of-the-frying-pan.puz"]
+        );
+
+        let mini = &entries[1];
+        assert!(mini.has_valid_checksums);
+        assert!(!entries[0].has_valid_checksums);
+    }
+
+    #[test]
+    fn scan_errors() {
+        let errors = scan("../puzzled_crossword/puzzles")
+            .unwrap()
+            .filter(Result::is_err)
+            .count();
+        let valid = scan("../puzzled_crossword/puzzles/ok")
+            .unwrap()
+            .filter(Result::is_ok)
+            .count();
+
+        assert!(errors > 0);
+        assert_eq!(valid, 3);
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt;
+
+use puzzled_core::Version;
+
+use crate::puz::windows_1252_to_char;
+
+/// Bytes that are left undefined by Windows-1252
+const WINDOWS_1252_UNDEFINED: [u8; 5] = [0x81, 0x8D, 0x8F, 0x90, 0x9D];
+
+/// Encoding of the [strings](crate::puz::Strings) in `*.puz` data
+///
+/// Across Lite 2.0 stores strings as UTF-8, whereas older versions use Windows-1252.
+/// Readers and writers pick the encoding from the [version](Version) of the puzzle unless it is overridden
+/// ```
+/// use puzzled_core::Version;
+/// use puzzled_io::puz::Encoding;
+///
+/// assert_eq!(Encoding::for_version(Some(Version::new(1, 3))), Encoding::Windows1252);
+/// assert_eq!(Encoding::for_version(Some(Version::new(2, 0))), Encoding::Utf8);
+///
+/// let bytes = Encoding::Windows1252.encode("Café – 2");
+/// assert_eq!(bytes, b"Caf\xe9 \x96 2");
+/// assert_eq!(Encoding::Windows1252.decode(&bytes), Ok("Café – 2".to_string()));
+/// assert_eq!(Encoding::Utf8.decode(&bytes), Err(0xe9));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Encoding {
+    /// Single-byte encoding used before Across Lite 2.0
+    #[default]
+    Windows1252,
+
+    /// Encoding used from Across Lite 2.0 onwards
+    Utf8,
+}
+
+impl Encoding {
+    /// Encoding that puzzles of the given version use, which is [Windows-1252](Self::Windows1252) if the version is unknown
+    pub fn for_version(version: Option<Version>) -> Self {
+        match version {
+            Some(version) if version.supports_utf8() => Self::Utf8,
+            _ => Self::Windows1252,
+        }
+    }
+
+    /// Decode the bytes of a string, without a trailing `\0`
+    ///
+    /// Returns the first byte that is invalid in the encoding if the bytes cannot be decoded
+    pub fn decode(&self, bytes: &[u8]) -> Result<String, u8> {
+        match self {
+            Self::Utf8 => std::str::from_utf8(bytes)
+                .map(str::to_string)
+                .map_err(|err| bytes[err.valid_up_to()]),
+            Self::Windows1252 => match bytes.iter().find(|b| WINDOWS_1252_UNDEFINED.contains(b)) {
+                Some(&byte) => Err(byte),
+                None => Ok(bytes.iter().map(|&b| windows_1252_to_char(b)).collect()),
+            },
+        }
+    }
+
+    /// Decode the bytes of a string, replacing invalid bytes rather than failing
+    pub fn decode_lossy(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Self::Windows1252 => bytes.iter().map(|&b| windows_1252_to_char(b)).collect(),
+        }
+    }
+
+    /// Encode a string, without a trailing `\0`
+    ///
+    /// Characters that cannot be represented in Windows-1252 are replaced by `?`
+    pub fn encode(&self, str: &str) -> Vec<u8> {
+        match self {
+            Self::Utf8 => str.as_bytes().to_vec(),
+            Self::Windows1252 => str
+                .chars()
+                .map(|c| char_to_windows_1252(c).unwrap_or(b'?'))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoding = match self {
+            Self::Windows1252 => "Windows-1252",
+            Self::Utf8 => "UTF-8",
+        };
+
+        write!(f, "{encoding}")
+    }
+}
+
+/// Inverse of [`windows_1252_to_char`], which is [`None`] for characters that Windows-1252 cannot represent
+pub fn char_to_windows_1252(c: char) -> Option<u8> {
+    match u32::from(c) {
+        // ASCII and ISO-8859-1 ranges map directly
+        code @ (0..=0x7F | 0xA0..=0xFF) => Some(code as u8),
+
+        // Windows-1252 specific mappings for the 128-159 range
+        _ => (0x80..=0x9F).find(|&byte| {
+            !WINDOWS_1252_UNDEFINED.contains(&byte) && windows_1252_to_char(byte) == c
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ascii(Encoding::Windows1252, "Theme: .PUZ format")]
+    #[case::latin(Encoding::Windows1252, "Über naïve café")]
+    #[case::specific(Encoding::Windows1252, "“Quoted” — €5")]
+    #[case::utf8(Encoding::Utf8, "Über “quoted” 日本")]
+    fn round_trip(#[case] encoding: Encoding, #[case] str: &str) {
+        let bytes = encoding.encode(str);
+
+        assert_eq!(encoding.decode(&bytes).as_deref(), Ok(str));
+    }
+
+    #[test]
+    fn undefined_bytes() {
+        assert_eq!(Encoding::Windows1252.decode(b"A\x81B"), Err(0x81));
+        assert_eq!(Encoding::Windows1252.encode("日本"), b"??");
+        assert_eq!(char_to_windows_1252('\u{0081}'), None);
+    }
+}
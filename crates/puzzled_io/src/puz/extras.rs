@@ -1,10 +1,16 @@
-use std::{collections::BTreeMap, str::FromStr};
+use std::{collections::BTreeMap, io, str::FromStr};
 
 use crate::puz::{
-    ByteStr, Context, PuzRead, PuzState, PuzWrite, Span, build_string, format, read, write,
+    ByteStr, Context, PuzRead, PuzSidecar, PuzState, PuzWrite, SectionLayout, SectionName, Span,
+    build_string, format,
+    read::{self, ReadCheck, ReadOptions, Strictness},
+    write,
 };
 use puzzled_core::{CellStyle, Grid, Position, Timer};
 
+/// Names of the extra sections that the crate reads itself
+const KNOWN_SECTIONS: [&SectionName; 4] = [b"GRBS", b"RTBL", b"LTIM", b"GEXT"];
+
 /// Grid Rebus (GRBS) type
 ///
 /// The GRBS contains one byte per square in the puzzle [grid](puzzled_core::Grid).
@@ -38,6 +44,10 @@ pub type Ltim = Timer;
 /// - `0x20` means that the square is currently marked incorrect
 /// - `0x40` means that the contents of the square were given
 /// - `0x80` means that the square is circled
+///
+/// Files in the wild also set other, undocumented bits, such as [`0x08`](Extras::GEXT_MARKED) for squares that are marked by the solving software.
+/// These are left out of the [style](CellStyle::GEXT), as the crate tracks other styles with the same bits.
+/// Instead, they are kept in the [raw bits](Extras::gext_raw) of the section such that they survive a round-trip.
 pub type Gext = Grid<CellStyle>;
 
 /// [Extra sections](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki) of the `*.puz` data
@@ -83,7 +93,11 @@ pub type Gext = Grid<CellStyle>;
 /// For a [cell](crate::Cell), refer to [`CellStyle`](crate::CellStyle) to see which styles are currently supported.
 /// Multiple styles can be set at once as style is represented as (partially complete) bit flags.
 ///
-#[derive(Debug, Default)]
+/// ## Custom sections
+/// Any other section can be read and written by registering a [handler](crate::puz::SectionHandler) for it.
+/// Its raw bytes are kept in [`custom`](Self::custom) in the order the sections are read.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Extras {
     /// The [GRBS](Grbs) section
     pub grbs: Option<Grbs>,
@@ -96,9 +110,18 @@ pub struct Extras {
 
     /// The [GEXT](Gext) section
     pub gext: Option<Gext>,
+
+    /// Bits of the [GEXT](Gext) section that are not [styles](CellStyle::GEXT), which are written back together with the styles
+    pub gext_raw: Option<Grid<u8>>,
+
+    /// Raw bytes of the sections that are handled by a [section handler](crate::puz::SectionHandler)
+    pub custom: Vec<(SectionName, Vec<u8>)>,
 }
 
 impl Extras {
+    /// Bit of the [GEXT](Gext) section for squares that are marked (or selected) by the solving software
+    pub const GEXT_MARKED: u8 = 0x08;
+
     pub fn get_rebus(&self, pos: Position) -> Option<&String> {
         let (Some(grbs), Some(rtbl)) = (&self.grbs, &self.rtbl) else {
             return None;
@@ -108,12 +131,36 @@ impl Extras {
         rtbl.get(rebus)
     }
 
+    /// Raw bytes of the custom section with the given name
+    pub fn get_custom(&self, name: &SectionName) -> Option<&[u8]> {
+        self.custom
+            .iter()
+            .find(|(custom, _)| custom == name)
+            .map(|(_, bytes)| bytes.as_slice())
+    }
+
+    /// Set the raw bytes of the custom section with the given name, replacing its previous bytes
+    pub fn set_custom(&mut self, name: SectionName, bytes: Vec<u8>) {
+        match self.custom.iter_mut().find(|(custom, _)| *custom == name) {
+            Some((_, custom)) => *custom = bytes,
+            None => self.custom.push((name, bytes)),
+        }
+    }
+
     pub fn get_style(&self, pos: Position) -> CellStyle {
         match &self.gext {
             None => CellStyle::default(),
             Some(gext) => *gext.get(pos).unwrap_or(&CellStyle::default()),
         }
     }
+
+    /// Whether the square is [marked](Self::GEXT_MARKED) in the [GEXT](Gext) section
+    pub fn is_marked(&self, pos: Position) -> bool {
+        self.gext_raw
+            .as_ref()
+            .and_then(|raw| raw.get(pos))
+            .is_some_and(|&bits| bits & Self::GEXT_MARKED != 0)
+    }
 }
 
 /// # Read
@@ -131,20 +178,68 @@ impl Extras {
         eprintln!("Extras START");
 
         loop {
+            // Keep the raw bytes of every section for lossless readers
+            let mut reader = Recorder::new(reader);
+
             // Try to read a section header
-            let result = reader.read_slice::<4>().context("Extras section header");
-            let Some(header) = state.ok_or_warn(result)? else {
-                break;
+            // Extra sections are optional, so the data may end before any section
+            let header = match reader.read_slice::<4>() {
+                Ok(header) => header,
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err).context("Extras section header"),
             };
 
             eprintln!("Found header '{}'", build_string(&header));
 
             match &header {
                 // Try to read valid sections
-                b"GRBS" => extras.grbs = state.ok_or_warn(Self::read_grbs(reader, size, width))?,
-                b"RTBL" => extras.rtbl = state.ok_or_warn(Self::read_rtbl(reader))?,
-                b"LTIM" => extras.ltim = state.ok_or_warn(Self::read_ltim(reader))?,
-                b"GEXT" => extras.gext = state.ok_or_warn(Self::read_gext(reader, size, width))?,
+                b"GRBS" => {
+                    extras.grbs = state.check(
+                        ReadCheck::ExtraSections,
+                        Self::read_grbs(&mut reader, size, width),
+                    )?
+                }
+                b"RTBL" => {
+                    extras.rtbl =
+                        state.check(ReadCheck::ExtraSections, Self::read_rtbl(&mut reader))?
+                }
+                b"LTIM" => {
+                    extras.ltim =
+                        state.check(ReadCheck::ExtraSections, Self::read_ltim(&mut reader))?
+                }
+                b"GEXT" => {
+                    let gext = Self::read_gext(&mut reader, size, width, state);
+                    if let Some((gext, raw)) = state.check(ReadCheck::ExtraSections, gext)? {
+                        extras.gext = Some(gext);
+                        extras.gext_raw = raw;
+                    }
+                }
+
+                // Pass sections that the caller knows about to their handler
+                header if let Some(handler) = state.section_handler(header) => {
+                    let section = match handler.layout() {
+                        SectionLayout::Grid => reader.read_vec(size),
+                        SectionLayout::String => {
+                            reader.read_byte_str().map(|str| str.bytes(true).to_vec())
+                        }
+                        SectionLayout::Fixed(len) => reader.read_vec(len),
+                    };
+                    let name = build_string(header);
+                    let bytes = section.context(name.clone())?;
+
+                    let result = handler.read(&bytes).map_err(|reason| read::Error {
+                        span: Span::default(),
+                        kind: read::ErrorKind::InvalidCustomSection {
+                            name: name.clone(),
+                            reason,
+                        },
+                        context: name,
+                    });
+
+                    if state.check(ReadCheck::ExtraSections, result)?.is_some() {
+                        extras.set_custom(*header, bytes);
+                    }
+                }
 
                 // Warn against invalid section headers
                 header => {
@@ -155,9 +250,23 @@ impl Extras {
                         },
                         context: context.into(),
                     });
-                    state.ok_or_warn(result)?;
+                    state.check(ReadCheck::ExtraSections, result)?;
+
+                    // Sections do not store their length, so lossless readers keep all remaining data as is
+                    if let Some(sidecar) = &mut state.sidecar {
+                        io::Read::read_to_end(&mut reader, &mut Vec::new()).context(context)?;
+                        sidecar.trailing = reader.bytes;
+                        break;
+                    }
+
+                    continue;
                 }
             }
+
+            if let Some(sidecar) = &mut state.sidecar {
+                let section = reader.bytes.split_off(4);
+                sidecar.sections.push((header, section));
+            }
         }
 
         eprintln!("Extras END");
@@ -243,74 +352,226 @@ impl Extras {
             .context("LTIM")
     }
 
-    fn read_gext<R: PuzRead>(reader: &mut R, size: usize, width: u8) -> read::Result<Gext> {
+    fn read_gext<R: PuzRead>(
+        reader: &mut R,
+        size: usize,
+        width: u8,
+        state: &mut PuzState,
+    ) -> read::Result<(Gext, Option<Grid<u8>>)> {
         let context = "GEXT";
 
-        let bytes = reader.read_vec(size).context(context.to_string())?;
+        let bytes = reader.read_vec(size).context(context)?;
         let bytes = Grid::from_vec(bytes, width as usize).expect("Read correct length");
-        let mut styles = Vec::with_capacity(size);
 
+        // Bits without a known meaning are kept, but may point to a misread section
+        let known = CellStyle::GEXT.bits() | Self::GEXT_MARKED;
         for (pos, &mask) in bytes.iter_indexed() {
-            let Some(style) = CellStyle::from_bits(mask) else {
-                return Err(read::Error {
-                    span: Span::default(),
-                    kind: read::ErrorKind::InvalidCellStyle { pos, mask },
-                    context: context.to_string(),
-                });
-            };
+            if mask & !known != 0 {
+                state.warn(
+                    ReadCheck::ExtraSections,
+                    read::Error {
+                        span: Span::default(),
+                        kind: read::ErrorKind::InvalidCellStyle { pos, mask },
+                        context: context.to_string(),
+                    },
+                );
+            }
+        }
+
+        // Other bits would otherwise be mistaken for the styles that the crate tracks itself
+        let gext = bytes.map_ref(|&mask| CellStyle::from_bits_truncate(mask) & CellStyle::GEXT);
+        let has_raw = bytes
+            .iter()
+            .any(|&mask| mask & !CellStyle::GEXT.bits() != 0);
+        let raw = has_raw.then(|| bytes.map(|mask| mask & !CellStyle::GEXT.bits()));
+
+        Ok((gext, raw))
+    }
+}
 
-            styles.push(style);
+/// Reader that keeps the bytes it reads
+struct Recorder<'r, R> {
+    reader: &'r mut R,
+    bytes: Vec<u8>,
+}
+
+impl<'r, R> Recorder<'r, R> {
+    fn new(reader: &'r mut R) -> Self {
+        Self {
+            reader,
+            bytes: Vec::new(),
         }
+    }
+}
+
+impl<R: io::Read> io::Read for Recorder<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.reader.read(buf)?;
+        self.bytes.extend_from_slice(&buf[..len]);
 
-        let gext = Grid::from_vec(styles, width as usize).expect("Read correct length");
-        Ok(gext)
+        Ok(len)
     }
 }
 
 /// # Write
 impl Extras {
-    pub(crate) fn write_with<W: PuzWrite>(&self, writer: &mut W) -> write::Result<()> {
-        if let Some(grbs) = &self.grbs {
-            writer.write_all(b"GRBS").context("GRBS header")?;
+    /// Raw bytes of every section that is defined, in the order they are written
+    pub fn sections(&self) -> Vec<(SectionName, Vec<u8>)> {
+        let mut sections = Vec::new();
 
-            for (pos, &byte) in grbs.iter_indexed() {
-                let context = format!("Square {pos}");
-                writer.write_u8(byte).context(context)?;
-            }
+        if let Some(grbs) = &self.grbs {
+            sections.push((*b"GRBS", grbs.iter().copied().collect()));
         }
 
         if let Some(rtbl) = &self.rtbl {
-            writer.write_all(b"RTBL").context("RTBL header")?;
-
+            let mut bytes = Vec::new();
             for (num, rebus) in rtbl {
-                let key = format!("{num:02}:{rebus};");
-                let context = format!("Rebus #{num}");
-
-                writer.write_all(key.as_bytes()).context(context)?;
+                bytes.extend(format!("{num:02}:{rebus};").as_bytes());
             }
+            bytes.push(0);
 
-            writer.write_u8(0).context("RTBL EOF bit")?;
+            sections.push((*b"RTBL", bytes));
         }
 
         if let Some(ltim) = &self.ltim {
-            writer.write_all(b"LTIM").context("LTIM header")?;
-
             let secs = ltim.elapsed().as_secs();
             let state: u8 = ltim.state().into();
 
             let format = ByteStr::new(format!("{secs},{state}").as_bytes());
-            writer.write_byte_str(&format).context("LTIM")?;
+            sections.push((*b"LTIM", format.bytes(true).to_vec()));
         }
 
         if let Some(gext) = &self.gext {
-            writer.write_all(b"GEXT").context("GEXT header")?;
+            let mut bytes: Vec<_> = gext
+                .iter()
+                .map(|style| (*style & CellStyle::GEXT).bits())
+                .collect();
+
+            if let Some(raw) = &self.gext_raw {
+                bytes.iter_mut().zip(raw.iter()).for_each(|(byte, bits)| {
+                    *byte |= bits & !CellStyle::GEXT.bits();
+                });
+            }
+
+            sections.push((*b"GEXT", bytes));
+        }
+
+        sections.extend(self.custom.iter().cloned());
+        sections
+    }
+
+    /// Raw bytes of the sections to write for a puzzle with a [sidecar](PuzSidecar)
+    ///
+    /// The sections of the sidecar are kept in their original order and with their original bytes, as long as they agree with the extras.
+    /// Sections that changed are replaced by their new bytes and new sections are added at the end.
+    /// Only the [LTIM](Ltim) section is kept when the extras do not define it, as many puzzles do not write their timer themselves.
+    pub(crate) fn sections_with_sidecar(
+        &self,
+        sidecar: &PuzSidecar,
+        width: u8,
+        height: u8,
+    ) -> Vec<(SectionName, Vec<u8>)> {
+        // Read the kept sections again to compare their meaning with the extras
+        let mut data = Vec::new();
+        for (name, bytes) in &sidecar.sections {
+            if !KNOWN_SECTIONS.contains(&name) {
+                continue;
+            }
+
+            data.extend(name);
+            data.extend(bytes);
+        }
+        let mut state = PuzState::new(ReadOptions::all(Strictness::Ignore));
+        let kept =
+            Extras::read_from(&mut data.as_slice(), width, height, &mut state).unwrap_or_default();
+
+        // Puzzles cannot hold the raw GEXT bits themselves, so they are taken from the kept section
+        let new_sections = match (&self.gext_raw, &kept.gext_raw) {
+            (None, Some(raw)) => Extras {
+                gext_raw: Some(raw.clone()),
+                ..self.clone()
+            }
+            .sections(),
+            _ => self.sections(),
+        };
+        let gext_raw = self.gext_raw.as_ref().or(kept.gext_raw.as_ref());
+
+        let same_rebuses = self.grbs.as_ref().is_some_and(|grbs| {
+            kept.grbs.is_some()
+                && grbs
+                    .positions()
+                    .all(|pos| self.get_rebus(pos) == kept.get_rebus(pos))
+        });
+
+        let mut sections = Vec::new();
+        for (name, bytes) in &sidecar.sections {
+            let new_bytes = new_sections
+                .iter()
+                .find(|(new_name, _)| new_name == name)
+                .map(|(_, bytes)| bytes);
+
+            let unchanged = match name {
+                b"GRBS" | b"RTBL" => same_rebuses,
+                b"LTIM" => self.ltim.is_none() || self.ltim == kept.ltim,
+                b"GEXT" => self.gext == kept.gext && gext_raw == kept.gext_raw.as_ref(),
+                _ => new_bytes == Some(bytes),
+            };
 
-            for (pos, &style) in gext.iter_indexed() {
-                let context = format!("Cell {pos} style");
-                writer.write_u8(style.bits()).context(context)?;
+            match (unchanged, new_bytes) {
+                (true, _) => sections.push((*name, bytes.clone())),
+                (false, Some(new_bytes)) => sections.push((*name, new_bytes.clone())),
+                (false, None) => {}
             }
         }
 
+        for (name, bytes) in new_sections {
+            if sidecar.sections.iter().any(|(kept, _)| *kept == name) {
+                continue;
+            }
+
+            // Missing styles are the same as default styles
+            if &name == b"GEXT" && bytes.iter().all(|&byte| byte == 0) {
+                continue;
+            }
+
+            sections.push((name, bytes));
+        }
+
+        sections
+    }
+
+    pub(crate) fn write_sections<W: PuzWrite>(
+        writer: &mut W,
+        sections: &[(SectionName, Vec<u8>)],
+    ) -> write::Result<()> {
+        for (name, bytes) in sections {
+            let context = build_string(name);
+
+            writer
+                .write_all(name)
+                .context(format!("{context} header"))?;
+            writer.write_all(bytes).context(context)?;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gext_raw() {
+        let data = [b"GEXT".as_slice(), &[0x80, 0x88, 0x01, 0x00]].concat();
+        let mut state = PuzState::new(ReadOptions::all(Strictness::Ignore));
+        let extras = Extras::read_from(&mut data.as_slice(), 2, 2, &mut state)
+            .expect("extras are read correctly");
+
+        assert_eq!(extras.get_style(Position::new(0, 1)), CellStyle::CIRCLED);
+        assert!(extras.is_marked(Position::new(0, 1)));
+        assert!(!extras.is_marked(Position::new(0, 0)));
+
+        assert_eq!(extras.sections(), vec![(*b"GEXT", data[4..].to_vec())]);
+    }
+}
@@ -29,7 +29,8 @@ use puzzled_core::{Grid, GridError, NON_PLAYABLE_CHAR};
 /// ```
 ///
 /// The crate uses a [`Grid<Square>`](crate::Grid<Square>) to store both the solution and state in a single grid.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Grids {
     pub solution: Grid<u8>,
     pub state: Grid<u8>,
@@ -82,9 +83,8 @@ impl Grids {
         }
 
         // Check that non-playable squares match in the layout and state
-        for ((pos, &solution_square), &state_square) in
-            self.solution.iter_indexed().zip(self.state.iter())
-        {
+        for (pos, &solution_square) in &self.solution {
+            let state_square = *self.state.get_checked(pos)?;
             if (solution_square == NON_PLAYABLE_CHAR as u8)
                 != (state_square == NON_PLAYABLE_CHAR as u8)
             {
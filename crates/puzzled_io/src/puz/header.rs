@@ -1,7 +1,12 @@
-use crate::puz::{Context, PuzRead, PuzState, PuzWrite, format, read, write};
+use crate::puz::{
+    Context, PuzRead, PuzState, PuzWrite, format,
+    read::{self, ReadCheck},
+    write,
+};
 use puzzled_core::Version;
 
 pub(crate) const FILE_MAGIC: &str = "ACROSS&DOWN\0";
+pub(crate) const FILE_MAGIC_BYTES: &[u8; 12] = b"ACROSS&DOWN\0";
 
 /// [Header](https://gist.github.com/sliminality/dab21fa834eae0a70193c7cd69c356d5#header) section
 ///
@@ -28,7 +33,8 @@ pub(crate) const FILE_MAGIC: &str = "ACROSS&DOWN\0";
 /// | <span style="color:gray">Unknown Bitmask</span>    | 2      | u16  | A bitmask. Operations unknown. |
 /// | <span style="color:gray">Scrambled Tag</span>      | 2      | u16  | 0 for unscrambled puzzles. Nonzero (often 4) for scrambled puzzles. |
 ///
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     // Components
     pub version: [u8; 4],
@@ -68,6 +74,10 @@ impl Header {
         let count = self.clue_count.to_le_bytes();
         self.cib_region[2] = count[0];
         self.cib_region[3] = count[1];
+
+        let tag = self.scrambled_tag.to_le_bytes();
+        self.cib_region[6] = tag[0];
+        self.cib_region[7] = tag[1];
     }
 }
 
@@ -85,7 +95,8 @@ impl Header {
 
         // Try to parse a valid version, otherwise set empty bits
         let version = reader.read_slice::<4>().context("Version bytes")?;
-        let version = state.ok_or_warn(
+        let version = state.check(
+            ReadCheck::Version,
             Version::from_bytes(&version)
                 .map_err(format::Error::Version)
                 .context("Version"),
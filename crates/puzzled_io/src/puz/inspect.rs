@@ -0,0 +1,476 @@
+//! Inspect the individual regions of `*.puz` data, e.g. to build a hex viewer or to debug files that fail to read
+//!
+//! Unlike [`PuzReader`](crate::puz::PuzReader), [`dump`] never fails.
+//! It splits the data into every [region](Region) that it can find, together with its [span](Span), raw bytes and decoded value.
+//! Regions that store a checksum are marked with whether it matches the checksum computed over the data.
+//! ```
+//! use puzzled::io::puz::{PuzWriter, inspect::{self, ChecksumStatus, RegionValue}};
+//! use puzzled::crossword::{crossword, CrosswordState};
+//!
+//! let puzzle = crossword!(
+//!     [A B]
+//!     [C .]
+//!     - A: "AB"
+//!     - D: "AC"
+//! );
+//! let mut bytes = Vec::new();
+//! PuzWriter::new().write(&mut bytes, &puzzle, &CrosswordState::from(&puzzle)).unwrap();
+//!
+//! let dump = inspect::dump(&bytes);
+//! let width = dump.region("Width").unwrap();
+//!
+//! assert_eq!(width.span, 44..45);
+//! assert_eq!(width.value, RegionValue::Integer(2));
+//! assert_eq!(dump.region("Clue #2").unwrap().value, RegionValue::Text("AC".into()));
+//! assert_eq!(dump.region("File checksum").unwrap().checksum, Some(ChecksumStatus::Valid));
+//! assert!(dump.is_valid());
+//! ```
+use puzzled_core::{Grid, Version};
+
+use crate::puz::{
+    ByteStr, Encoding, Span, Strings, build_string, find_cib_checksum, find_file_checksum,
+    find_mask_checksums, find_region_checksum, find_strings_checksum, windows_1252_to_char,
+};
+
+/// Part of the `*.puz` data that a [region](Region) belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    Header,
+    Grids,
+    Strings,
+    Extras,
+
+    /// Data that could not be assigned to any of the other sections
+    Unknown,
+}
+
+/// Value that the bytes of a [region](Region) decode to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegionValue {
+    /// Little-endian integer
+    Integer(u16),
+
+    /// String without its trailing `\0`
+    Text(String),
+
+    /// Grid of characters, one for every byte
+    Grid(Grid<char>),
+
+    /// Bytes without a known meaning
+    Bytes,
+}
+
+/// Whether the checksum that is stored in a [region](Region) matches the checksum computed over the data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    Valid,
+
+    /// The stored checksum differs from the computed bytes
+    Invalid {
+        computed: Vec<u8>,
+    },
+}
+
+/// Contiguous range of bytes with a single meaning
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub section: Section,
+    pub name: String,
+    pub span: Span,
+    pub bytes: Vec<u8>,
+    pub value: RegionValue,
+
+    /// Status of the checksum if the region stores one
+    pub checksum: Option<ChecksumStatus>,
+}
+
+/// All [regions](Region) of `*.puz` data in the order they appear
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzDump {
+    pub regions: Vec<Region>,
+
+    /// Whether the data ended in the middle of a region
+    pub truncated: bool,
+}
+
+impl PuzDump {
+    /// Find the first region with the given name
+    pub fn region(&self, name: &str) -> Option<&Region> {
+        self.regions.iter().find(|region| region.name == name)
+    }
+
+    /// Returns an iterator over the regions of the given section
+    pub fn section(&self, section: Section) -> impl Iterator<Item = &Region> {
+        self.regions
+            .iter()
+            .filter(move |region| region.section == section)
+    }
+
+    /// Find the region that contains the byte at the given offset
+    pub fn region_at(&self, offset: usize) -> Option<&Region> {
+        self.regions
+            .iter()
+            .find(|region| region.span.contains(&offset))
+    }
+
+    /// Whether the data is complete and all of its checksums are valid
+    pub fn is_valid(&self) -> bool {
+        !self.truncated
+            && self.regions.iter().all(|region| {
+                region
+                    .checksum
+                    .as_ref()
+                    .is_none_or(|c| *c == ChecksumStatus::Valid)
+            })
+    }
+}
+
+/// Split `*.puz` data into its [regions](Region), see the [module documentation](self)
+pub fn dump(data: &[u8]) -> PuzDump {
+    let mut dumper = Dumper {
+        data,
+        offset: 0,
+        dump: PuzDump::default(),
+    };
+    dumper.dump_all();
+
+    dumper.dump
+}
+
+struct Dumper<'a> {
+    data: &'a [u8],
+    offset: usize,
+    dump: PuzDump,
+}
+
+impl<'a> Dumper<'a> {
+    fn dump_all(&mut self) {
+        let Some(size) = self.dump_header() else {
+            return;
+        };
+        let Some(()) = self.dump_grids(size) else {
+            return;
+        };
+        let Some(strings) = self.dump_strings() else {
+            return;
+        };
+
+        self.validate_checksums(&strings);
+        self.dump_extras(size);
+    }
+
+    /// Dump the header, returning the width and height of the puzzle
+    fn dump_header(&mut self) -> Option<(usize, usize)> {
+        use Section::Header;
+
+        self.take_u16(Header, "File checksum")?;
+        self.take(Header, "File magic", 12, text)?;
+        self.take_u16(Header, "CIB checksum")?;
+        self.take(Header, "Masked low checksums", 4, bytes)?;
+        self.take(Header, "Masked high checksums", 4, bytes)?;
+        self.take(Header, "Version", 4, text)?;
+        self.take(Header, "Reserved1C", 14, bytes)?;
+        self.take_u16(Header, "Scrambled checksum")?;
+
+        let width = self.take(Header, "Width", 1, integer)?;
+        let height = self.take(Header, "Height", 1, integer)?;
+        self.take_u16(Header, "Clue count")?;
+        self.take_u16(Header, "Unknown bitmask")?;
+        self.take_u16(Header, "Scrambled tag")?;
+
+        Some((width[0].into(), height[0].into()))
+    }
+
+    fn dump_grids(&mut self, (width, height): (usize, usize)) -> Option<()> {
+        let grid = |bytes: &[u8]| {
+            let chars = bytes
+                .iter()
+                .map(|&byte| windows_1252_to_char(byte))
+                .collect();
+
+            Grid::from_vec(chars, width).map_or(RegionValue::Bytes, RegionValue::Grid)
+        };
+
+        self.take(Section::Grids, "Solution", width * height, grid)?;
+        self.take(Section::Grids, "State", width * height, grid)?;
+
+        Some(())
+    }
+
+    fn dump_strings(&mut self) -> Option<Strings> {
+        let version = self
+            .bytes_of("Version")
+            .and_then(|v| Version::from_bytes(v).ok());
+        let clue_count = self
+            .bytes_of("Clue count")
+            .map_or(0, |b| u16::from_le_bytes([b[0], b[1]]));
+
+        let mut strings = Strings {
+            encoding: Encoding::for_version(version),
+            ..Default::default()
+        };
+        let take_str = |dumper: &mut Self, name: &str| {
+            let encoding = strings.encoding;
+            let bytes = dumper.take_str(Section::Strings, name, |bytes| {
+                RegionValue::Text(encoding.decode_lossy(&bytes[..bytes.len() - 1]))
+            })?;
+
+            Some(ByteStr::new(bytes))
+        };
+
+        let title = take_str(self, "Title")?;
+        let author = take_str(self, "Author")?;
+        let copyright = take_str(self, "Copyright")?;
+
+        let mut clues = Vec::with_capacity(clue_count.into());
+        for num in 1..=clue_count {
+            clues.push(take_str(self, &format!("Clue #{num}"))?);
+        }
+
+        let notes = take_str(self, "Notes")?;
+
+        strings.title = title;
+        strings.author = author;
+        strings.copyright = copyright;
+        strings.clues = clues;
+        strings.notes = notes;
+
+        Some(strings)
+    }
+
+    fn dump_extras(&mut self, (width, height): (usize, usize)) {
+        while self.offset < self.data.len() {
+            let Some(title) = self.take(Section::Extras, "Extra section title", 4, text) else {
+                return;
+            };
+            let title = build_string(title);
+
+            let region = match title.as_str() {
+                "GRBS" | "GEXT" => self.take(Section::Extras, &title, width * height, bytes),
+                "RTBL" | "LTIM" => self.take_str(Section::Extras, &title, |bytes| {
+                    RegionValue::Text(build_string(&bytes[..bytes.len() - 1]))
+                }),
+                _ => {
+                    let len = self.data.len() - self.offset;
+                    self.take(Section::Unknown, "Unknown data", len, bytes)
+                }
+            };
+
+            if region.is_none() {
+                return;
+            }
+        }
+    }
+
+    fn validate_checksums(&mut self, strings: &Strings) {
+        let (Some(cib), Some(solution), Some(state)) = (
+            self.dump.region("Width").map(|region| region.span.start),
+            self.bytes_of("Solution"),
+            self.bytes_of("State"),
+        ) else {
+            return;
+        };
+
+        let cib_checksum = find_cib_checksum(&self.data[cib..cib + 8]);
+        let file_checksum = find_file_checksum(cib_checksum, solution, state, strings);
+        let masks = find_mask_checksums(
+            cib_checksum,
+            find_region_checksum(solution, 0),
+            find_region_checksum(state, 0),
+            find_strings_checksum(strings, 0),
+        );
+
+        self.set_checksum("CIB checksum", &cib_checksum.to_le_bytes());
+        self.set_checksum("File checksum", &file_checksum.to_le_bytes());
+        self.set_checksum("Masked low checksums", &masks[..4]);
+        self.set_checksum("Masked high checksums", &masks[4..]);
+    }
+
+    fn set_checksum(&mut self, name: &str, computed: &[u8]) {
+        let Some(region) = self
+            .dump
+            .regions
+            .iter_mut()
+            .find(|region| region.name == name)
+        else {
+            return;
+        };
+
+        region.checksum = Some(match region.bytes == computed {
+            true => ChecksumStatus::Valid,
+            false => ChecksumStatus::Invalid {
+                computed: computed.to_vec(),
+            },
+        });
+    }
+
+    fn bytes_of(&self, name: &str) -> Option<&'a [u8]> {
+        let span = self.dump.region(name)?.span.clone();
+        Some(&self.data[span])
+    }
+
+    /// Take the next `len` bytes as a region, or the remaining bytes if the data is truncated
+    fn take<F>(&mut self, section: Section, name: &str, len: usize, value: F) -> Option<&'a [u8]>
+    where
+        F: FnOnce(&[u8]) -> RegionValue,
+    {
+        let end = self.offset + len;
+        if end > self.data.len() {
+            self.truncate(section, name);
+            return None;
+        }
+
+        Some(self.push(section, name, end, value))
+    }
+
+    fn take_u16(&mut self, section: Section, name: &str) -> Option<&'a [u8]> {
+        self.take(section, name, 2, integer)
+    }
+
+    /// Take the bytes up to and including the next `\0` as a region
+    fn take_str<F>(&mut self, section: Section, name: &str, value: F) -> Option<&'a [u8]>
+    where
+        F: FnOnce(&[u8]) -> RegionValue,
+    {
+        let Some(len) = self.data[self.offset..].iter().position(|&byte| byte == 0) else {
+            self.truncate(section, name);
+            return None;
+        };
+
+        Some(self.push(section, name, self.offset + len + 1, value))
+    }
+
+    fn push<F>(&mut self, section: Section, name: &str, end: usize, value: F) -> &'a [u8]
+    where
+        F: FnOnce(&[u8]) -> RegionValue,
+    {
+        let span = self.offset..end;
+        let bytes = &self.data[span.clone()];
+        self.offset = end;
+
+        self.dump.regions.push(Region {
+            section,
+            name: name.to_string(),
+            span,
+            bytes: bytes.to_vec(),
+            value: value(bytes),
+            checksum: None,
+        });
+
+        bytes
+    }
+
+    fn truncate(&mut self, section: Section, name: &str) {
+        let end = self.data.len();
+        if self.offset < end {
+            self.push(section, name, end, self::bytes);
+        }
+
+        self.dump.truncated = true;
+    }
+}
+
+fn integer(bytes: &[u8]) -> RegionValue {
+    match *bytes {
+        [byte] => RegionValue::Integer(byte.into()),
+        [low, high] => RegionValue::Integer(u16::from_le_bytes([low, high])),
+        _ => RegionValue::Bytes,
+    }
+}
+
+fn text(bytes: &[u8]) -> RegionValue {
+    RegionValue::Text(build_string(bytes))
+}
+
+fn bytes(_: &[u8]) -> RegionValue {
+    RegionValue::Bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled::{
+        crossword::{CrosswordState, crossword},
+        io::puz::PuzWriter,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn puz() -> Vec<u8> {
+        let puzzle = crossword!(
+            [C A T]
+            [A . O]
+            - A: "Feline"
+            - D: "Taxi"
+            - D: "Also"
+
+            title: "Mini"
+        );
+
+        let mut bytes = Vec::new();
+        PuzWriter::new()
+            .write(&mut bytes, &puzzle, &CrosswordState::from(&puzzle))
+            .unwrap();
+
+        bytes
+    }
+
+    #[test]
+    fn regions() {
+        let bytes = puz();
+        let dump = dump(&bytes);
+
+        // Regions cover all data without gaps
+        let mut offset = 0;
+        for region in &dump.regions {
+            assert_eq!(region.span.start, offset, "{}", region.name);
+            offset = region.span.end;
+        }
+        assert_eq!(offset, bytes.len());
+
+        assert_eq!(
+            dump.region("Title").unwrap().value,
+            RegionValue::Text("Mini".into())
+        );
+        assert_eq!(dump.section(Section::Strings).count(), 7);
+        assert!(matches!(
+            dump.region("Solution").unwrap().value,
+            RegionValue::Grid(ref grid) if grid.cols() == 3
+        ));
+        assert!(dump.is_valid());
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        let mut bytes = puz();
+        let title = dump(&bytes).region("Title").unwrap().span.start;
+        bytes[title] = b'X';
+
+        let dump = dump(&bytes);
+        assert!(matches!(
+            dump.region("File checksum").unwrap().checksum,
+            Some(ChecksumStatus::Invalid { .. })
+        ));
+        assert_eq!(
+            dump.region("CIB checksum").unwrap().checksum,
+            Some(ChecksumStatus::Valid)
+        );
+        assert!(!dump.is_valid());
+    }
+
+    #[rstest]
+    #[case::header(30)]
+    #[case::grids(55)]
+    #[case::strings(64)]
+    fn truncated(#[case] len: usize) {
+        let bytes = puz();
+        let dump = dump(&bytes[..len]);
+
+        assert!(dump.truncated);
+        assert_eq!(dump.regions.last().unwrap().span.end, len);
+        assert_eq!(
+            dump.region_at(len - 1).map(|region| region.span.end),
+            Some(len)
+        );
+    }
+}
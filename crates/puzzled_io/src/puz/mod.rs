@@ -19,9 +19,14 @@
 //! For example, when a [`PuzReader`] encounters an invalid [extra section](self::extra-sections), it should be able to just skip it and create a puzzle anyways.
 //! Streams that support warnings are initialized with a `strict` flag to indicate how to handle warnings.
 //! - If `strict == true`, a warning is treated as an error and the streaming is immediately aborted if one is encountered
-//! - Otherwise, all warnings are collected throughout the streaming process.
-//!   Streams specify a separate `*_with_warnings` to return them to the user along with the streaming result.
-//!   For example, consider [`PuzReader::read`] and [`PuzReader::read_with_warnings`]
+//! - Otherwise, all warnings are collected throughout the streaming process as [diagnostics](crate::Diagnostic).
+//!   Streams specify a separate `*_with_diagnostics` to return them to the user along with the streaming result.
+//!   For example, consider [`PuzReader::read`] and [`PuzReader::read_with_diagnostics`]
+//!
+//! Every diagnostic has a machine-readable [code](crate::DiagnosticCode), such as `PUZ001_CHECKSUM_MISMATCH`, to filter on
+//!
+//! [`PuzReader`] can be configured more precisely with [`ReadOptions`](read::ReadOptions), which decide per category whether to error, warn or ignore.
+//! For example, a reader may be strict on checksums but lenient on malformed extra sections
 //!
 //! # Validating checksums
 //! The main validation technique for `*.puz` files is to *match given checksums with region checksums*.
@@ -46,7 +51,7 @@
 //! }
 //! ```
 //! When `strict` reading is enabled, all checksums need to be valid in order to successfully parse a [puzzle](crate::Crossword).
-//! Otherwise, the user is [warned](Warning) against invalid or missing checksums.
+//! Otherwise, the user is [warned](crate::Diagnostic) against invalid or missing checksums.
 //!
 //! ### CIB
 //! The first checksum is the **CIB** checksum, which is specified in the [header](self#header).
@@ -142,31 +147,59 @@
 //! [PUZ google spec]: https://code.google.com/archive/p/puz/wikis/FileFormat.wiki
 //! [Checksums]: self#validating-checksums
 
+pub mod api;
+pub mod corpus;
+pub mod inspect;
 pub mod read;
 pub mod write;
 
 use std::fs::File;
 
-use puzzled_core::{Grid, Metadata, Puzzle};
-#[doc(inline)]
-pub use read::{PuzRead, PuzReader, Span, build_string, windows_1252_to_char};
+use puzzled_core::{Grid, GridError, Metadata, Puzzle};
 #[doc(inline)]
-pub use write::{
-    PuzSizeCheck, PuzWrite, PuzWriter, WriteStateGrid, WriteStyleGrid, check_puz_size,
+pub use read::{
+    PuzRead, PuzReader, PuzSections, Span, build_string, read_sections, windows_1252_to_char,
 };
+#[doc(inline)]
+pub use write::{PuzWrite, PuzWriter};
+
+// Building blocks for implementing [`BinaryPuzzle`] in the puzzle crates, which are not part of the stable API
+#[doc(hidden)]
+pub use write::{PuzSizeCheck, WriteStateGrid, WriteStyleGrid, check_puz_size};
+
+pub(crate) use read::PuzState;
 
-pub(crate) use read::{PuzState, Warning};
+/// Prevents the extension traits [`PuzRead`] and [`PuzWrite`] from being implemented outside of this crate
+mod sealed {
+    use std::io;
 
+    pub trait SealedRead {}
+    pub trait SealedWrite {}
+
+    impl<R: io::Read + ?Sized> SealedRead for R {}
+    impl<W: io::Write + ?Sized> SealedWrite for W {}
+}
+
+mod borrowed;
 mod checksums;
+mod encoding;
 mod extras;
 mod grids;
 mod header;
+mod scramble;
+mod section;
+mod sidecar;
 mod strings;
 
+pub use borrowed::*;
 pub use checksums::*;
+pub use encoding::*;
 pub use extras::*;
 pub use grids::*;
 pub use header::*;
+pub use scramble::*;
+pub use section::*;
+pub use sidecar::*;
 pub use strings::*;
 
 use crate::{Context, format, puz, puzzle_dir};
@@ -187,7 +220,7 @@ pub trait BinaryPuzzle<S>: Puzzle {
     fn grids(&self, state: &S) -> write::Result<(Grid<u8>, Grid<u8>)>;
     fn extras(&self, state: &S) -> write::Result<Extras>;
 
-    fn clues(&self) -> Vec<ByteStr> {
+    fn clues(&self) -> Vec<String> {
         Vec::new()
     }
 
@@ -195,6 +228,24 @@ pub trait BinaryPuzzle<S>: Puzzle {
         None
     }
 
+    /// Data of the file that the puzzle was [losslessly](read::ReadOptions::with_lossless) read from
+    fn puz_sidecar(&self) -> Option<&PuzSidecar> {
+        None
+    }
+
+    /// Cached checksums of the strings of the puzzle, which the [writer](PuzWriter) reuses instead of checksumming the strings again
+    fn checksum_cache(&self) -> Option<&ChecksumCache> {
+        None
+    }
+
+    /// Checksum of the real solution if the solution of the puzzle is still [scrambled](scramble_solution), such that it is written as scrambled again
+    fn scrambled_checksum(&self) -> Option<u16> {
+        None
+    }
+
+    /// Attach the data of the file that the puzzle was [losslessly](read::ReadOptions::with_lossless) read from, which puzzles without a place for it ignore
+    fn set_puz_sidecar(&mut self, _sidecar: PuzSidecar) {}
+
     fn load_puz(name: &str) -> puz::read::Result<(Self, S)> {
         let reader = PuzReader::new(false);
 
@@ -237,6 +288,12 @@ impl<T> Context<T, read::Error> for format::Result<T> {
     }
 }
 
+impl<T> Context<T, read::Error> for Result<T, GridError> {
+    fn context<S: Into<String>>(self, context: S) -> read::Result<T> {
+        self.map_err(format::Error::Grid).context(context)
+    }
+}
+
 impl<T> Context<T, write::Error> for format::Result<T> {
     fn context<S: Into<String>>(self, context: S) -> write::Result<T> {
         self.map_err(|err| write::Error {
@@ -2,8 +2,8 @@ use puzzled_core::Position;
 use thiserror::Error;
 
 use crate::{
-    format,
-    puz::{Context, FILE_MAGIC, Span},
+    Diagnostic, DiagnosticCode, Severity, format,
+    puz::{Context, Encoding, FILE_MAGIC, Span},
 };
 
 #[derive(Debug, Error)]
@@ -28,6 +28,17 @@ impl Error {
             kind,
         }
     }
+
+    /// Report the error as a [diagnostic](Diagnostic) of the given severity
+    pub fn to_diagnostic(&self, severity: Severity) -> Diagnostic {
+        Diagnostic::new(severity, self.kind.code(), self.to_string()).with_span(self.span.clone())
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(err: &Error) -> Self {
+        err.to_diagnostic(Severity::Error)
+    }
 }
 
 #[derive(Debug, Error)]
@@ -53,12 +64,18 @@ pub enum ErrorKind {
     #[error("Expected to find {expected} clues, found {found}")]
     InvalidClueCount { found: usize, expected: usize },
 
+    #[error("Invalid byte {byte:#04x} in string, which is not valid {encoding}")]
+    InvalidEncoding { byte: u8, encoding: Encoding },
+
     // General
     #[error(
         "Read invalid section header {found}, expected one of 'GRBS', 'RTBL', 'LTIM' or 'GTEXT'"
     )]
     InvalidSection { found: String },
 
+    #[error("Custom section {name} is invalid: {reason}")]
+    InvalidCustomSection { name: String, reason: String },
+
     // GRBS
     #[error("Expected RTBL to include rebus #{rebus} at position {pos:?}, but not found")]
     MissingRebus { pos: Position, rebus: u8 },
@@ -69,11 +86,41 @@ pub enum ErrorKind {
 
     // GEXT
     #[error(
-        "Encountered invalid bitmask {mask} at position {pos:?} in GEXT, only the following bits should be set: 0x10, 0x20, 0x40 and 0x80"
+        "Ignoring undocumented bits of bitmask {mask:#04x} at position {pos:?} in GEXT, only the following bits are known: 0x10, 0x20, 0x40 and 0x80"
     )]
     InvalidCellStyle { pos: Position, mask: u8 },
 }
 
+impl ErrorKind {
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::Io(_) => DiagnosticCode::PuzIo,
+            Self::Format(_) => DiagnosticCode::PuzFormat,
+            Self::InvalidFileMagic { .. } => DiagnosticCode::PuzInvalidFileMagic,
+            Self::InvalidChecksum { .. } => DiagnosticCode::PuzChecksumMismatch,
+            Self::MissingChecksum { .. } => DiagnosticCode::PuzMissingChecksum,
+            Self::MissingClue { .. } => DiagnosticCode::PuzMissingClue,
+            Self::InvalidClueCount { .. } => DiagnosticCode::PuzInvalidClueCount,
+            Self::InvalidSection { .. } | Self::InvalidCustomSection { .. } => {
+                DiagnosticCode::PuzInvalidSection
+            }
+            Self::MissingRebus { .. } => DiagnosticCode::PuzMissingRebus,
+            Self::InvalidRebus { .. } => DiagnosticCode::PuzInvalidRebus,
+            Self::InvalidCellStyle { .. } => DiagnosticCode::PuzInvalidCellStyle,
+            Self::InvalidEncoding { .. } => DiagnosticCode::PuzInvalidEncoding,
+        }
+    }
+
+    /// Number of clues that can still be placed in the puzzle when the error is caused by excess clues
+    pub fn usable_clue_count(&self) -> Option<usize> {
+        match self {
+            Self::MissingClue { id, .. } => Some(usize::from(*id).saturating_sub(1)),
+            Self::InvalidClueCount { found, expected } if found < expected => Some(*found),
+            _ => None,
+        }
+    }
+}
+
 impl<T> Context<T, Error> for std::io::Result<T> {
     fn context<S: Into<String>>(self, context: S) -> Result<T> {
         self.map_err(|err| Error {
@@ -85,6 +132,3 @@ impl<T> Context<T, Error> for std::io::Result<T> {
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
-
-/// [Errors](struct@Error) that can be recovered from when reading in non-strict mode
-pub type Warning = Error;
@@ -2,10 +2,11 @@ use puzzled_core::{Metadata, Version};
 
 use crate::puz::{ByteStr, Header, Strings};
 
+#[doc(hidden)]
 pub fn read_metadata(header: &Header, strings: &Strings) -> Metadata {
     let mut metadata = Metadata::default();
 
-    let str_or = |str: &ByteStr| (!str.is_empty()).then_some(str.to_string());
+    let str_or = |str: &ByteStr| (!str.is_empty()).then(|| strings.decode(str));
 
     if let Some(author) = str_or(&strings.author) {
         metadata = metadata.with_author(author);
@@ -24,5 +25,11 @@ pub fn read_metadata(header: &Header, strings: &Strings) -> Metadata {
         metadata = metadata.with_version(version)
     }
 
+    // Across Lite has no separate fields for the theme note and date, so they are inferred from the other strings
+    let metadata = metadata.with_notepad_conventions();
+
+    #[cfg(feature = "chrono")]
+    let metadata = metadata.with_inferred_date();
+
     metadata
 }
@@ -10,22 +10,32 @@
 //! [PUZ spec]: https://gist.github.com/sliminality/dab21fa834eae0a70193c7cd69c356d5
 mod error;
 mod metadata;
+mod options;
 mod state;
 mod util;
 
 pub use error::*;
 pub use metadata::*;
+pub use options::*;
 pub use util::*;
 
 pub(crate) use state::*;
 
-use crate::puz::{BinaryPuzzle, ByteStr, Extras, Grids, Header, Strings};
+use crate::{
+    Diagnostic,
+    puz::{
+        BinaryPuzzle, ByteStr, Encoding, Extras, Grids, Header, PuzChecksums, PuzWriter, Strings,
+        sealed::SealedRead, unscramble_solution,
+    },
+};
+use puzzled_core::Version;
 use std::{fs::File, io, ops::Range, path::Path};
 
 /// Extension trait for [`Read`](io::Read) to make reading [puzzles](crate::Puz) from a [binary format](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki) easier
 ///
-/// Includes convenience methods for reading a [`u8`], [`u16`], `\0` terminated [`str`] and [`Vec<u8>`] from a generic reader
-pub trait PuzRead: io::Read {
+/// Includes convenience methods for reading a [`u8`], [`u16`], `\0` terminated [`str`] and [`Vec<u8>`] from a generic reader.
+/// The trait is sealed and implemented for every [reader](io::Read)
+pub trait PuzRead: io::Read + SealedRead {
     /// Read a [`u8`]
     fn read_u8(&mut self) -> io::Result<u8> {
         let mut bytes = [0; 1];
@@ -86,14 +96,57 @@ impl<R: io::Read> PuzRead for R {}
 
 pub type Span = Range<usize>;
 
+/// Raw sections of `*.puz` data, as read by [`read_sections`]
+///
+/// With the `serde` feature, the sections can be serialized to inspect a file without building a puzzle from it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzSections {
+    pub header: Header,
+    pub grids: Grids,
+    pub strings: Strings,
+    pub extras: Extras,
+}
+
+/// Read the raw [sections](PuzSections) of `*.puz` data with the [default options](ReadOptions::default)
+/// ```
+/// use puzzled::io::puz::read_sections;
+///
+/// let data = std::fs::read("../puzzled_crossword/puzzles/ok/mini.puz").unwrap();
+/// let sections = read_sections(&data).unwrap();
+///
+/// assert_eq!((sections.header.width, sections.header.height), (3, 3));
+/// assert_eq!(sections.strings.clues.len(), sections.header.clue_count as usize);
+/// ```
+pub fn read_sections(mut bytes: &[u8]) -> Result<PuzSections> {
+    PuzReader::default().read_sections(&mut bytes)
+}
+
+/// Reads [puzzles](BinaryPuzzle) from `*.puz` data
+///
+/// How strictly the data is validated is configured per category through its [options](ReadOptions)
 #[derive(Debug, Default)]
 pub struct PuzReader {
-    strict: bool,
+    options: ReadOptions,
 }
 
 impl PuzReader {
+    /// Create a reader that either aborts on any issue or recovers from all of them
     pub fn new(strict: bool) -> Self {
-        Self { strict }
+        let options = match strict {
+            true => ReadOptions::strict(),
+            false => ReadOptions::lenient(),
+        };
+
+        Self::with_options(options)
+    }
+
+    pub fn with_options(options: ReadOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &ReadOptions {
+        &self.options
     }
 
     pub fn read<R, P, S>(&self, reader: &mut R) -> Result<(P, S)>
@@ -101,30 +154,131 @@ impl PuzReader {
         R: PuzRead,
         P: BinaryPuzzle<S>,
     {
-        let (puzzle, state, _) = self.read_with_warnings(reader)?;
+        let (puzzle, state, _) = self.read_with_diagnostics(reader)?;
         Ok((puzzle, state))
     }
 
-    pub fn read_with_warnings<R, P, S>(&self, reader: &mut R) -> Result<(P, S, Vec<Warning>)>
+    /// Read a puzzle together with the [diagnostics](Diagnostic) for every issue that was recovered from in non-strict mode
+    pub fn read_with_diagnostics<R, P, S>(&self, reader: &mut R) -> Result<(P, S, Vec<Diagnostic>)>
     where
         R: PuzRead,
         P: BinaryPuzzle<S>,
     {
-        let mut read_state = PuzState::new(self.strict);
+        let mut read_state = PuzState::new(self.options.clone());
+        let PuzSections {
+            header,
+            grids,
+            mut strings,
+            extras,
+        } = self.read_sections_with_state(reader, &mut read_state)?;
+
+        let result = P::read_puz(
+            header.clone(),
+            grids.clone(),
+            strings.clone(),
+            extras.clone(),
+        );
+        let (mut puzzle, state) = match result {
+            // Retry without the clues that could not be placed
+            Err(err) if read_state.strictness(ReadCheck::ClueCount) != Strictness::Error => {
+                let Some(count) = err.kind.usable_clue_count() else {
+                    return Err(err);
+                };
+
+                read_state.check::<()>(ReadCheck::ClueCount, Err(err))?;
+                strings.clues.truncate(count);
+
+                P::read_puz(header, grids, strings, extras)?
+            }
+            result => result?,
+        };
+
+        if let Some(sidecar) = read_state.sidecar.take() {
+            puzzle.set_puz_sidecar(sidecar);
+        }
+
+        Ok((puzzle, state, read_state.diagnostics))
+    }
+
+    /// Read the raw [sections](PuzSections) of `*.puz` data without building a puzzle from them
+    ///
+    /// The sections are validated and unscrambled the same way as when [reading](Self::read) a puzzle.
+    pub fn read_sections<R: PuzRead>(&self, reader: &mut R) -> Result<PuzSections> {
+        let mut read_state = PuzState::new(self.options.clone());
+        self.read_sections_with_state(reader, &mut read_state)
+    }
 
+    fn read_sections_with_state<R: PuzRead>(
+        &self,
+        reader: &mut R,
+        read_state: &mut PuzState,
+    ) -> Result<PuzSections> {
         // Read main components
-        let header = Header::read_from(reader, &mut read_state)?;
+        let header = Header::read_from(reader, read_state)?;
+        if let Some(sidecar) = &mut read_state.sidecar {
+            sidecar.read_header(&header);
+        }
         let grids = Grids::read_from(reader, header.width, header.height)?;
-        let strings = Strings::read_from(reader, header.clue_count)?;
+        let version = Version::from_bytes(&header.version).ok();
+        let encoding = (self.options.string_encoding()).unwrap_or(Encoding::for_version(version));
+
+        let strings = Strings::read_from(reader, header.clue_count, encoding)?;
+        strings.validate_encoding(read_state)?;
 
         // Validate checksums
-        self.validate_checksums(&header, &grids, &strings, &mut read_state)?;
+        self.validate_checksums(&header, &grids, &strings, read_state)?;
+        if let Some(sidecar) = &mut read_state.sidecar {
+            let mut computed = header.clone();
+            PuzWriter.write_checksums(&mut computed, &grids, &strings);
+
+            sidecar.computed_checksums = PuzChecksums::from(&computed);
+        }
+
+        // Read the real solution of scrambled puzzles if their key is known
+        let (header, grids) = self.unscramble(header, grids)?;
+        if let Some(sidecar) = &mut read_state.sidecar {
+            sidecar.read_header(&header);
+        }
 
-        // Read extra sections and the actual structure of the puzzle
-        let extras = Extras::read_from(reader, header.width, header.height, &mut read_state)?;
+        // Read extra sections
+        let extras = Extras::read_from(reader, header.width, header.height, read_state)?;
 
-        let (puzzle, state) = P::read_puz(header, grids, strings, extras)?;
-        Ok((puzzle, state, read_state.warnings))
+        Ok(PuzSections {
+            header,
+            grids,
+            strings,
+            extras,
+        })
+    }
+
+    /// Unscramble the solution with the [key](ReadOptions::scramble_key) of the reader, verifying it against the checksum of the real solution
+    fn unscramble(&self, mut header: Header, mut grids: Grids) -> Result<(Header, Grids)> {
+        let Some(key) = self.options.scramble_key() else {
+            return Ok((header, grids));
+        };
+        if header.scrambled_tag == 0 {
+            return Ok((header, grids));
+        }
+
+        let context = "Unscrambling solution";
+        let checksum = unscramble_solution(&mut grids.solution, key)
+            .map_err(|err| Error::new(context, err.into()))?;
+
+        if checksum != header.scrambled_checksum {
+            return Err(Error::new(
+                context,
+                ErrorKind::InvalidChecksum {
+                    found: checksum,
+                    expected: header.scrambled_checksum,
+                },
+            ));
+        }
+
+        header.scrambled_checksum = 0;
+        header.scrambled_tag = 0;
+        header.write_cib();
+
+        Ok((header, grids))
     }
 
     pub fn read_from_path<R, P, S>(&self, path_ref: R) -> Result<(P, S)>
@@ -142,6 +296,9 @@ impl PuzReader {
     }
 }
 
+/// Build a string from bytes whose [encoding](Encoding) is unknown, trying UTF-8 before falling back to Windows-1252
+///
+/// Prefer [`Strings::decode`] for strings of a puzzle, which uses the encoding of its version
 pub fn build_string(bytes: &[u8]) -> String {
     let stripped = bytes.strip_suffix(&[0]).unwrap_or(bytes);
 
@@ -197,3 +354,40 @@ pub fn windows_1252_to_char(byte: u8) -> char {
         160..=255 => byte as char,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn sections_to_json() {
+        let data = std::fs::read("../puzzled_crossword/puzzles/ok/mini.puz").unwrap();
+        let sections = read_sections(&data).unwrap();
+
+        let json = serde_json::to_value(&sections).unwrap();
+        assert_eq!(json["header"]["width"], sections.header.width);
+        assert_eq!(
+            json["strings"]["clues"].as_array().unwrap().len(),
+            sections.strings.clues.len()
+        );
+
+        let read: PuzSections = serde_json::from_value(json).unwrap();
+        assert_eq!(read.header.clue_count, sections.header.clue_count);
+        assert_eq!(read.grids.solution, sections.grids.solution);
+
+        let clues = |strings: &Strings| -> Vec<_> {
+            strings
+                .clues
+                .iter()
+                .map(|clue| strings.decode(clue))
+                .collect()
+        };
+        assert_eq!(clues(&read.strings), clues(&sections.strings));
+    }
+
+    #[test]
+    fn sections_of_invalid_data() {
+        assert!(read_sections(b"not a puzzle").is_err());
+    }
+}
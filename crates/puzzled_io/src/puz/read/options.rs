@@ -0,0 +1,195 @@
+use std::{fmt, sync::Arc};
+
+use crate::puz::{Encoding, ScrambleKey, SectionHandler, SectionHandlers};
+
+/// How a [`PuzReader`](crate::PuzReader) handles an issue it encounters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Strictness {
+    /// Abort reading with the [error](super::Error)
+    Error,
+
+    /// Recover from the issue and report it as a [diagnostic](crate::Diagnostic)
+    Warn,
+
+    /// Recover from the issue without reporting it
+    Ignore,
+}
+
+/// Category of issues that can be recovered from when reading `*.puz` data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadCheck {
+    /// Invalid or missing [checksums](crate::puz#validating-checksums)
+    Checksums,
+
+    /// Malformed or unknown [extra sections](crate::puz::Extras), such as an RTBL with an invalid rebus or a GEXT with undocumented style bits
+    ExtraSections,
+
+    /// Version in the [header](crate::puz::Header) that is not formatted as `x.y`
+    Version,
+
+    /// Strings that contain bytes which are not valid in their [encoding](Encoding)
+    Encoding,
+
+    /// Number of clues that does not agree with the puzzle grid, such that excess clues are dropped
+    ClueCount,
+}
+
+impl fmt::Display for ReadCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let check = match self {
+            Self::Checksums => "checksums",
+            Self::ExtraSections => "extra sections",
+            Self::Version => "version",
+            Self::Encoding => "encoding",
+            Self::ClueCount => "clue count",
+        };
+
+        write!(f, "{check}")
+    }
+}
+
+/// Decides per [category](ReadCheck) how strictly a [`PuzReader`](crate::PuzReader) reads `*.puz` data
+///
+/// By default, every category is [warned](Strictness::Warn) against.
+/// ```
+/// use puzzled_io::puz::read::{ReadCheck, ReadOptions, Strictness};
+///
+/// // Strict on checksums, but lenient on malformed extra sections
+/// let options = ReadOptions::default()
+///     .with_checksums(Strictness::Error)
+///     .with_extra_sections(Strictness::Ignore);
+///
+/// assert_eq!(options.strictness(ReadCheck::Checksums), Strictness::Error);
+/// assert_eq!(options.strictness(ReadCheck::ExtraSections), Strictness::Ignore);
+/// assert_eq!(options.strictness(ReadCheck::Version), Strictness::Warn);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadOptions {
+    checksums: Strictness,
+    extra_sections: Strictness,
+    version: Strictness,
+    encoding: Strictness,
+    clue_count: Strictness,
+
+    string_encoding: Option<Encoding>,
+    section_handlers: SectionHandlers,
+    lossless: bool,
+    scramble_key: Option<ScrambleKey>,
+}
+
+impl ReadOptions {
+    /// Create options that handle every category with the same strictness
+    pub fn all(strictness: Strictness) -> Self {
+        Self {
+            checksums: strictness,
+            extra_sections: strictness,
+            version: strictness,
+            encoding: strictness,
+            clue_count: strictness,
+            string_encoding: None,
+            section_handlers: SectionHandlers::default(),
+            lossless: false,
+            scramble_key: None,
+        }
+    }
+
+    /// Create options that abort reading on any issue
+    pub fn strict() -> Self {
+        Self::all(Strictness::Error)
+    }
+
+    /// Create options that recover from any issue and report it
+    pub fn lenient() -> Self {
+        Self::all(Strictness::Warn)
+    }
+
+    pub fn with_checksums(mut self, strictness: Strictness) -> Self {
+        self.checksums = strictness;
+        self
+    }
+
+    pub fn with_extra_sections(mut self, strictness: Strictness) -> Self {
+        self.extra_sections = strictness;
+        self
+    }
+
+    pub fn with_version(mut self, strictness: Strictness) -> Self {
+        self.version = strictness;
+        self
+    }
+
+    pub fn with_encoding(mut self, strictness: Strictness) -> Self {
+        self.encoding = strictness;
+        self
+    }
+
+    pub fn with_clue_count(mut self, strictness: Strictness) -> Self {
+        self.clue_count = strictness;
+        self
+    }
+
+    /// Read strings with the given encoding, rather than the one that the version of the puzzle specifies
+    pub fn with_string_encoding(mut self, encoding: Encoding) -> Self {
+        self.string_encoding = Some(encoding);
+        self
+    }
+
+    /// Pass the raw bytes of the extra section with the given name to the handler, rather than treating it as an unknown section
+    ///
+    /// # Panics
+    /// Panics if the name is not 4 bytes long
+    pub fn with_section_handler(mut self, name: &str, handler: Arc<dyn SectionHandler>) -> Self {
+        self.section_handlers.insert(name, handler);
+        self
+    }
+
+    /// Keep all data that the puzzle has no place for in a [sidecar](crate::puz::PuzSidecar), such that [`PuzWriter`](crate::puz::PuzWriter) writes valid files back byte for byte
+    ///
+    /// Unknown extra sections do not store their length, so all data from the first unknown section onwards is kept as is.
+    pub fn with_lossless(mut self, lossless: bool) -> Self {
+        self.lossless = lossless;
+        self
+    }
+
+    /// [Unscramble](crate::puz::unscramble_solution) the solution of scrambled puzzles with the given key
+    ///
+    /// Without a key, scrambled puzzles are read with their scrambled solution
+    pub fn with_scramble_key(mut self, key: ScrambleKey) -> Self {
+        self.scramble_key = Some(key);
+        self
+    }
+
+    pub fn scramble_key(&self) -> Option<ScrambleKey> {
+        self.scramble_key
+    }
+
+    pub fn is_lossless(&self) -> bool {
+        self.lossless
+    }
+
+    pub fn section_handlers(&self) -> &SectionHandlers {
+        &self.section_handlers
+    }
+
+    /// Encoding that overrides the one of the puzzle version, if any
+    pub fn string_encoding(&self) -> Option<Encoding> {
+        self.string_encoding
+    }
+
+    /// Strictness that is used for the given category
+    pub fn strictness(&self, check: ReadCheck) -> Strictness {
+        match check {
+            ReadCheck::Checksums => self.checksums,
+            ReadCheck::ExtraSections => self.extra_sections,
+            ReadCheck::Version => self.version,
+            ReadCheck::Encoding => self.encoding,
+            ReadCheck::ClueCount => self.clue_count,
+        }
+    }
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self::lenient()
+    }
+}
@@ -1,31 +1,63 @@
-use crate::puz::{Warning, read};
+use std::sync::Arc;
+
+use crate::{
+    Diagnostic, Severity,
+    puz::{
+        PuzSidecar, SectionHandler, SectionName,
+        read::{self, ReadCheck, ReadOptions, Strictness},
+    },
+};
 
 #[derive(Debug, Default)]
-pub struct PuzState {
-    strict: bool,
-    pub warnings: Vec<Warning>,
+pub(crate) struct PuzState {
+    options: ReadOptions,
+    pub diagnostics: Vec<Diagnostic>,
+
+    /// Data that is kept for lossless readers
+    pub sidecar: Option<PuzSidecar>,
 }
 
 impl PuzState {
-    pub(crate) fn new(strict: bool) -> Self {
+    pub(crate) fn new(options: ReadOptions) -> Self {
         Self {
-            strict,
-            warnings: Vec::new(),
+            sidecar: options.is_lossless().then(PuzSidecar::default),
+            options,
+            diagnostics: Vec::new(),
         }
     }
 
-    pub(crate) fn ok_or_warn<T>(&mut self, result: read::Result<T>) -> read::Result<Option<T>> {
-        match result {
-            // Pass through ok/err with strict mode normally
-            Ok(val) => Ok(Some(val)),
-            Err(err) if self.strict => Err(err),
+    pub(crate) fn strictness(&self, check: ReadCheck) -> Strictness {
+        self.options.strictness(check)
+    }
+
+    pub(crate) fn section_handler(&self, name: &SectionName) -> Option<Arc<dyn SectionHandler>> {
+        self.options.section_handlers().get(name).cloned()
+    }
 
-            // Warn against errors in non-strict mode
-            Err(warning) => {
-                self.warnings.push(warning);
+    /// Handle the result of a check according to its [strictness](Strictness)
+    pub(crate) fn check<T>(
+        &mut self,
+        check: ReadCheck,
+        result: read::Result<T>,
+    ) -> read::Result<Option<T>> {
+        match (result, self.strictness(check)) {
+            (Ok(val), _) => Ok(Some(val)),
+            (Err(err), Strictness::Error) => Err(err),
+
+            // Recover from errors in non-strict mode
+            (Err(err), Strictness::Warn) => {
+                self.diagnostics.push(err.to_diagnostic(Severity::Warning));
 
                 Ok(None)
             }
+            (Err(_), Strictness::Ignore) => Ok(None),
+        }
+    }
+
+    /// Report an issue that can always be recovered from, which is only left out when the check is [ignored](Strictness::Ignore)
+    pub(crate) fn warn(&mut self, check: ReadCheck, err: read::Error) {
+        if self.strictness(check) != Strictness::Ignore {
+            self.diagnostics.push(err.to_diagnostic(Severity::Warning));
         }
     }
 }
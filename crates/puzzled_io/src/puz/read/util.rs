@@ -9,6 +9,7 @@ use crate::{
     },
 };
 
+#[doc(hidden)]
 pub fn read_cell_entries<T, F>(
     grids: &Grids,
     extras: &Extras,
@@ -23,7 +24,8 @@ where
     let mut cells = Vec::with_capacity(cols);
     let mut entries = Vec::with_capacity(cols);
 
-    for ((pos, &solution), &state) in grids.solution.iter_indexed().zip(grids.state.iter()) {
+    for (pos, &solution) in &grids.solution {
+        let state = *grids.state.get_checked(pos).context("State grid")?;
         let style = extras.get_style(pos);
 
         let cell = match windows_1252_to_char(solution) {
@@ -45,6 +47,7 @@ where
     Ok((cells, entries))
 }
 
+#[doc(hidden)]
 pub fn read_square_entries<T, F>(
     grids: &Grids,
     extras: &Extras,
@@ -59,7 +62,8 @@ where
     let mut squares = Vec::with_capacity(cols);
     let mut entries = Vec::with_capacity(cols);
 
-    for ((pos, &solution), &state) in grids.solution.iter_indexed().zip(grids.state.iter()) {
+    for (pos, &solution) in &grids.solution {
+        let state = *grids.state.get_checked(pos).context("State grid")?;
         let style = extras.get_style(pos);
 
         let square = match windows_1252_to_char(solution) {
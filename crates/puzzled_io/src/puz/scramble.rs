@@ -0,0 +1,200 @@
+use std::fmt;
+
+use puzzled_core::{Grid, NON_PLAYABLE_CHAR, Position};
+
+use crate::puz::{find_region_checksum, format};
+
+/// Tag that Across Lite sets in the [header](crate::puz::Header) of scrambled puzzles
+pub const SCRAMBLED_TAG: u16 = 4;
+
+/// Four-digit key that is used to [scramble](scramble_solution) the solution of a puzzle
+///
+/// Across Lite only accepts keys from `1000` to `9999`:
+/// ```
+/// use puzzled_io::puz::ScrambleKey;
+///
+/// let key = ScrambleKey::new(1234).unwrap();
+/// assert_eq!(key.digits(), [1, 2, 3, 4]);
+///
+/// assert!(ScrambleKey::new(999).is_none());
+/// assert!(ScrambleKey::new(10000).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrambleKey(u16);
+
+impl ScrambleKey {
+    pub fn new(key: u16) -> Option<Self> {
+        (1000..=9999).contains(&key).then_some(Self(key))
+    }
+
+    pub fn digits(&self) -> [u8; 4] {
+        let key = self.0;
+
+        [
+            (key / 1000) as u8,
+            (key / 100 % 10) as u8,
+            (key / 10 % 10) as u8,
+            (key % 10) as u8,
+        ]
+    }
+}
+
+impl fmt::Display for ScrambleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Scramble the solution grid of a puzzle in place with the given key
+///
+/// The letters of the solution are read column by column, skipping [non-playable squares](NON_PLAYABLE_CHAR).
+/// Every digit of the key then in turn shifts the letters, rotates them and interleaves their halves.
+/// Only solutions that consist of the letters `A-Z` can be scrambled.
+///
+/// Returns the [checksum](find_region_checksum) of the unscrambled letters, which is stored in the [header](crate::puz::Header)
+/// ```
+/// use puzzled_core::Grid;
+/// use puzzled_io::puz::{ScrambleKey, scramble_solution, unscramble_solution};
+///
+/// let key = ScrambleKey::new(1234).unwrap();
+/// let original = Grid::from_vec(b"CAT.O.RED".to_vec(), 3).unwrap();
+///
+/// let mut solution = original.clone();
+/// scramble_solution(&mut solution, key).unwrap();
+/// assert_ne!(solution, original);
+///
+/// unscramble_solution(&mut solution, key).unwrap();
+/// assert_eq!(solution, original);
+/// ```
+pub fn scramble_solution(solution: &mut Grid<u8>, key: ScrambleKey) -> format::Result<u16> {
+    let positions = letter_positions(solution);
+    let mut letters = read_letters(solution, &positions)?;
+    let checksum = find_region_checksum(&letters, 0);
+
+    for digit in key.digits() {
+        shift(&mut letters, key, true);
+        rotate(&mut letters, digit, true);
+        letters = interleave(&letters);
+    }
+
+    write_letters(solution, &positions, &letters);
+    Ok(checksum)
+}
+
+/// Undo [scrambling](scramble_solution) the solution grid of a puzzle in place with the given key
+///
+/// Returns the [checksum](find_region_checksum) of the unscrambled letters, which can be compared against the one in the [header](crate::puz::Header) to verify the key
+pub fn unscramble_solution(solution: &mut Grid<u8>, key: ScrambleKey) -> format::Result<u16> {
+    let positions = letter_positions(solution);
+    let mut letters = read_letters(solution, &positions)?;
+
+    for digit in key.digits().into_iter().rev() {
+        letters = deinterleave(&letters);
+        rotate(&mut letters, digit, false);
+        shift(&mut letters, key, false);
+    }
+
+    write_letters(solution, &positions, &letters);
+    Ok(find_region_checksum(&letters, 0))
+}
+
+fn letter_positions(solution: &Grid<u8>) -> Vec<Position> {
+    (0..solution.cols())
+        .flat_map(|col| (0..solution.rows()).map(move |row| Position::new(row, col)))
+        .filter(|&pos| solution.get(pos) != Some(&(NON_PLAYABLE_CHAR as u8)))
+        .collect()
+}
+
+fn read_letters(solution: &Grid<u8>, positions: &[Position]) -> format::Result<Vec<u8>> {
+    positions
+        .iter()
+        .map(|&pos| match solution.get(pos) {
+            Some(&letter) if letter.is_ascii_uppercase() => Ok(letter),
+            letter => Err(format::Error::NotScramblable(
+                letter.map_or('\0', |&l| l as char),
+            )),
+        })
+        .collect()
+}
+
+fn write_letters(solution: &mut Grid<u8>, positions: &[Position], letters: &[u8]) {
+    for (&pos, &letter) in positions.iter().zip(letters) {
+        if let Some(square) = solution.get_mut(pos) {
+            *square = letter;
+        }
+    }
+}
+
+fn shift(letters: &mut [u8], key: ScrambleKey, forward: bool) {
+    let digits = key.digits();
+
+    for (idx, letter) in letters.iter_mut().enumerate() {
+        let offset = digits[idx % 4];
+        let offset = if forward { offset } else { 26 - offset };
+
+        *letter = b'A' + (*letter - b'A' + offset) % 26;
+    }
+}
+
+/// Rotate the letters by the digit, leaving them as is if there are too few letters to rotate
+fn rotate(letters: &mut [u8], digit: u8, forward: bool) {
+    let digit = digit as usize;
+
+    match (digit < letters.len(), forward) {
+        (true, true) => letters.rotate_left(digit),
+        (true, false) => letters.rotate_right(digit),
+        (false, _) => {}
+    }
+}
+
+/// Interleave the second half of the letters with the first half, starting with the second
+fn interleave(letters: &[u8]) -> Vec<u8> {
+    let mid = letters.len() / 2;
+    let (front, back) = letters.split_at(mid);
+
+    let mut interleaved: Vec<u8> = back.iter().zip(front).flat_map(|(&b, &f)| [b, f]).collect();
+    if letters.len() % 2 == 1 {
+        interleaved.push(letters[letters.len() - 1]);
+    }
+
+    interleaved
+}
+
+fn deinterleave(letters: &[u8]) -> Vec<u8> {
+    let mid = letters.len() / 2;
+
+    let front = letters.iter().skip(1).step_by(2).take(mid);
+    let back = letters.iter().step_by(2).take(mid);
+
+    let mut deinterleaved: Vec<u8> = front.chain(back).copied().collect();
+    if letters.len() % 2 == 1 {
+        deinterleaved.push(letters[letters.len() - 1]);
+    }
+
+    deinterleaved
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::even(b"ABCDEF", b"DAEBFC")]
+    #[case::odd(b"ABCDEFG", b"DAEBFCG")]
+    fn interleaving(#[case] letters: &[u8], #[case] expected: &[u8]) {
+        let interleaved = interleave(letters);
+
+        assert_eq!(interleaved, expected);
+        assert_eq!(deinterleave(&interleaved), letters);
+    }
+
+    #[test]
+    fn non_letters() {
+        let mut solution = Grid::from_vec(b"AB.1".to_vec(), 2).unwrap();
+        let key = ScrambleKey::new(1234).unwrap();
+
+        assert!(scramble_solution(&mut solution, key).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// Name of an [extra section](crate::puz::Extras), such as `b"GEXT"`
+pub type SectionName = [u8; 4];
+
+/// Decides which bytes after the name of an [extra section](crate::puz::Extras) belong to it
+///
+/// Extra sections do not store their length, so every section needs a known layout to be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionLayout {
+    /// One byte for every square in the puzzle grid, like [GRBS](crate::puz::Grbs) and [GEXT](crate::puz::Gext)
+    Grid,
+
+    /// A `\0` terminated string, like [RTBL](crate::puz::Rtbl) and [LTIM](crate::puz::Ltim)
+    String,
+
+    /// A fixed number of bytes
+    Fixed(usize),
+}
+
+/// Handles an extra section that the crate does not know about itself
+///
+/// Handlers are registered by section name on both the [read](crate::puz::read::ReadOptions::with_section_handler) and [write](crate::puz::write::WriteOptions::with_section_handler) options.
+/// The raw bytes that are passed around are exactly the bytes of the section that follow its name, so including the trailing `\0` of [strings](SectionLayout::String).
+pub trait SectionHandler: Send + Sync {
+    /// Layout of the bytes of the section
+    fn layout(&self) -> SectionLayout;
+
+    /// Handle the raw bytes of a section that was read, returning why they are invalid if they are
+    fn read(&self, bytes: &[u8]) -> Result<(), String>;
+
+    /// Raw bytes of the section to write, or [`None`] to leave out the section
+    fn write(&self) -> Option<Vec<u8>>;
+}
+
+/// [Handler](SectionHandler) that keeps the raw bytes of a section, such that it survives reading and writing a puzzle unchanged
+/// ```
+/// use std::sync::Arc;
+///
+/// use puzzled::crossword::{Crossword, CrosswordState};
+/// use puzzled::io::puz::{
+///     PuzReader, PuzWriter, RawSection, SectionLayout,
+///     read::ReadOptions, write::WriteOptions,
+/// };
+/// # let puzzle = puzzled::crossword::crossword!([A B]);
+/// # let mut data = Vec::new();
+/// # let clue = Arc::new(RawSection::new(SectionLayout::String));
+/// # clue.set_bytes(b"Hello\0".to_vec());
+/// # let options = WriteOptions::default().with_section_handler("CLUE", clue);
+/// # PuzWriter::new().write_with_options(&mut data, &puzzle, &CrosswordState::from(&puzzle), &options).unwrap();
+///
+/// let section = Arc::new(RawSection::new(SectionLayout::String));
+///
+/// // Keep the non-standard CLUE section when reading the data...
+/// let reader = PuzReader::with_options(ReadOptions::default().with_section_handler("CLUE", section.clone()));
+/// let (puzzle, state): (Crossword, CrosswordState) = reader.read(&mut data.as_slice()).unwrap();
+/// assert_eq!(section.bytes(), Some(b"Hello\0".to_vec()));
+///
+/// // ...and write it back afterwards
+/// let options = WriteOptions::default().with_section_handler("CLUE", section);
+/// let mut written = Vec::new();
+/// PuzWriter::new().write_with_options(&mut written, &puzzle, &state, &options).unwrap();
+/// assert_eq!(written, data);
+/// ```
+#[derive(Debug)]
+pub struct RawSection {
+    layout: SectionLayout,
+    bytes: Mutex<Option<Vec<u8>>>,
+}
+
+impl RawSection {
+    pub fn new(layout: SectionLayout) -> Self {
+        Self {
+            layout,
+            bytes: Mutex::new(None),
+        }
+    }
+
+    /// Raw bytes of the section that was last read or set
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        self.bytes.lock().expect("Lock is not poisoned").clone()
+    }
+
+    pub fn set_bytes(&self, bytes: Vec<u8>) {
+        *self.bytes.lock().expect("Lock is not poisoned") = Some(bytes);
+    }
+}
+
+impl SectionHandler for RawSection {
+    fn layout(&self) -> SectionLayout {
+        self.layout
+    }
+
+    fn read(&self, bytes: &[u8]) -> Result<(), String> {
+        self.set_bytes(bytes.to_vec());
+        Ok(())
+    }
+
+    fn write(&self) -> Option<Vec<u8>> {
+        self.bytes()
+    }
+}
+
+/// [Section handlers](SectionHandler) by the name of the section they handle
+#[derive(Clone, Default)]
+pub struct SectionHandlers(BTreeMap<SectionName, Arc<dyn SectionHandler>>);
+
+impl SectionHandlers {
+    /// Register a handler for the section with the given name, replacing any previous handler
+    ///
+    /// # Panics
+    /// Panics if the name is not 4 bytes long
+    pub fn insert(&mut self, name: &str, handler: Arc<dyn SectionHandler>) {
+        let name = name
+            .as_bytes()
+            .try_into()
+            .unwrap_or_else(|_| panic!("Section name '{name}' should be 4 bytes long"));
+
+        self.0.insert(name, handler);
+    }
+
+    pub fn get(&self, name: &SectionName) -> Option<&Arc<dyn SectionHandler>> {
+        self.0.get(name)
+    }
+
+    /// Returns an iterator over the handlers, ordered by the name of their section
+    pub fn iter(&self) -> impl Iterator<Item = (&SectionName, &Arc<dyn SectionHandler>)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for SectionHandlers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.0.keys().map(|name| String::from_utf8_lossy(name)))
+            .finish()
+    }
+}
+
+impl PartialEq for SectionHandlers {
+    /// Handlers are equal if they register the same handlers for the same sections
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|((lhs_name, lhs), (rhs_name, rhs))| {
+                    lhs_name == rhs_name && Arc::ptr_eq(lhs, rhs)
+                })
+    }
+}
+
+impl Eq for SectionHandlers {}
@@ -0,0 +1,82 @@
+use crate::puz::{Header, SectionName};
+
+/// Data of a `*.puz` file that a [puzzle](super::BinaryPuzzle) has no place for itself
+///
+/// [Lossless](super::read::ReadOptions::with_lossless) readers attach the sidecar to the puzzle they read, such that [`PuzWriter`](super::PuzWriter) can write it back verbatim.
+/// As long as the puzzle is not changed in between, a valid file is then written byte for byte as it was read.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzSidecar {
+    /// Reserved bytes of the [header](Header), which often contain uninitialized memory
+    pub reserved: [u8; 14],
+
+    /// Bitmask in the [header](Header) whose meaning is unknown
+    pub unknown_bitmask: u16,
+
+    /// Checksum of the real solution of scrambled puzzles
+    pub scrambled_checksum: u16,
+    pub scrambled_tag: u16,
+
+    /// Checksums of the header as they were read
+    ///
+    /// Other software may compute checksums slightly differently, so these are written back as long as the data they cover is unchanged
+    pub checksums: PuzChecksums,
+
+    /// Checksums that the crate computed over the data that was read, to detect whether the data changed
+    pub computed_checksums: PuzChecksums,
+
+    /// Raw bytes of every [extra section](super::Extras) in the order they were read
+    pub sections: Vec<(SectionName, Vec<u8>)>,
+
+    /// Bytes after the last extra section that could not be read as a section
+    pub trailing: Vec<u8>,
+}
+
+impl PuzSidecar {
+    /// Keep the data of the header that the puzzle does not know about
+    pub(crate) fn read_header(&mut self, header: &Header) {
+        self.reserved = header.reserved;
+        self.unknown_bitmask = u16::from_le_bytes([header.cib_region[4], header.cib_region[5]]);
+        self.scrambled_checksum = header.scrambled_checksum;
+        self.scrambled_tag = header.scrambled_tag;
+        self.checksums = PuzChecksums::from(header);
+    }
+
+    /// Restore the data of the header that the puzzle does not know about
+    pub(crate) fn write_header(&self, header: &mut Header) {
+        header.reserved = self.reserved;
+        header.scrambled_checksum = self.scrambled_checksum;
+        header.scrambled_tag = self.scrambled_tag;
+
+        header.write_cib();
+        [header.cib_region[4], header.cib_region[5]] = self.unknown_bitmask.to_le_bytes();
+    }
+
+    /// Restore the checksums that were read if the header has the same checksums as the data that was read
+    pub(crate) fn write_checksums(&self, header: &mut Header) {
+        if PuzChecksums::from(&*header) == self.computed_checksums {
+            header.file_checksum = self.checksums.file;
+            header.cib_checksum = self.checksums.cib;
+            header.mask_checksums = self.checksums.masks;
+        }
+    }
+}
+
+/// [Checksums](crate::puz#validating-checksums) in the [header](Header) of `*.puz` data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzChecksums {
+    pub file: u16,
+    pub cib: u16,
+    pub masks: [u8; 8],
+}
+
+impl From<&Header> for PuzChecksums {
+    fn from(header: &Header) -> Self {
+        Self {
+            file: header.file_checksum,
+            cib: header.cib_checksum,
+            masks: header.mask_checksums,
+        }
+    }
+}
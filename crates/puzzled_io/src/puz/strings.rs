@@ -2,7 +2,11 @@ use std::fmt;
 
 use puzzled_core::Metadata;
 
-use crate::puz::{Context, PuzRead, PuzWrite, build_string, read, write};
+use crate::puz::{
+    Context, Encoding, PuzRead, PuzState, PuzWrite, build_string,
+    read::{self, ReadCheck},
+    write,
+};
 
 #[derive(Debug, Clone)]
 pub struct ByteStr(Vec<u8>);
@@ -49,6 +53,32 @@ impl Default for ByteStr {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::puz::ByteStr;
+
+    impl Serialize for ByteStr {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.bytes(false).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ByteStr {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let bytes = Vec::<u8>::deserialize(deserializer)?;
+            Ok(ByteStr::new(&bytes))
+        }
+    }
+}
+
 /// [Strings](https://gist.github.com/sliminality/dab21fa834eae0a70193c7cd69c356d5#strings-section) section
 ///
 /// This section ncludes the "metadata" for the [puzzle](crate::Puz).
@@ -65,7 +95,8 @@ impl Default for ByteStr {
 /// | ...         | ... | ... | ... |
 /// | Clue `#n`   | ? | str | Quiet              |
 /// | Notes       | ? | str | http://mywebsite   |
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Strings {
     /// Title of the puzzle
     pub title: ByteStr,
@@ -81,25 +112,45 @@ pub struct Strings {
 
     /// Clues to be placed in the puzzle
     pub clues: Vec<ByteStr>,
+
+    /// Encoding that the strings are stored with
+    pub encoding: Encoding,
 }
 
 /// # Read
 impl Strings {
-    pub fn from_metadata(meta: &Metadata) -> Self {
-        let to_byte_str =
-            |prop: Option<&str>| prop.map(|p| ByteStr::new(p.as_bytes())).unwrap_or_default();
+    pub fn from_metadata(meta: &Metadata, encoding: Encoding) -> Self {
+        let to_byte_str = |prop: Option<&str>| {
+            prop.map(|p| ByteStr::new(&encoding.encode(p)))
+                .unwrap_or_default()
+        };
 
         Strings {
             author: to_byte_str(meta.author()),
             copyright: to_byte_str(meta.copyright()),
-            notes: to_byte_str(meta.notes()),
+            notes: to_byte_str(meta.notepad().as_deref()),
             title: to_byte_str(meta.title()),
 
             clues: Vec::new(),
+            encoding,
         }
     }
 
-    pub(crate) fn read_from<R: PuzRead>(reader: &mut R, clue_count: u16) -> read::Result<Self> {
+    /// Decode one of the strings with the [encoding](Self::encoding) of the section, replacing invalid bytes
+    pub fn decode(&self, str: &ByteStr) -> String {
+        self.encoding.decode_lossy(str.bytes(false))
+    }
+
+    /// Encode a string with the [encoding](Self::encoding) of the section
+    pub fn encode(&self, str: &str) -> ByteStr {
+        ByteStr::new(&self.encoding.encode(str))
+    }
+
+    pub(crate) fn read_from<R: PuzRead>(
+        reader: &mut R,
+        clue_count: u16,
+        encoding: Encoding,
+    ) -> read::Result<Self> {
         let title = reader.read_byte_str().context("Title")?;
         let author = reader.read_byte_str().context("Author")?;
         let copyright = reader.read_byte_str().context("Copyright")?;
@@ -122,8 +173,36 @@ impl Strings {
             copyright,
             notes,
             clues,
+            encoding,
         })
     }
+
+    /// Verify that every string is valid in the [encoding](Self::encoding) of the section
+    pub(crate) fn validate_encoding(&self, state: &mut PuzState) -> read::Result<()> {
+        let strings = [
+            ("Title", &self.title),
+            ("Author", &self.author),
+            ("Copyright", &self.copyright),
+            ("Notes", &self.notes),
+        ]
+        .into_iter()
+        .map(|(context, str)| (context.to_string(), str))
+        .chain(
+            (self.clues.iter().enumerate()).map(|(idx, clue)| (format!("Clue #{}", idx + 1), clue)),
+        );
+
+        for (context, str) in strings {
+            if let Err(byte) = self.encoding.decode(str.bytes(false)) {
+                let kind = read::ErrorKind::InvalidEncoding {
+                    byte,
+                    encoding: self.encoding,
+                };
+                state.check::<()>(ReadCheck::Encoding, Err(read::Error::new(context, kind)))?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// # Write
@@ -146,3 +225,25 @@ impl Strings {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trip() {
+        let strings = Strings {
+            title: ByteStr::new(b"Theme: .PUZ format"),
+            clues: vec![ByteStr::new(b"Cued, in pool"), ByteStr::default()],
+            encoding: Encoding::Utf8,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&strings).unwrap();
+        assert_eq!(json["clues"][1], serde_json::json!([]));
+
+        let read: Strings = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(read.title.bytes(true), b"Theme: .PUZ format\0");
+        assert_eq!(serde_json::to_value(&read).unwrap(), json);
+    }
+}
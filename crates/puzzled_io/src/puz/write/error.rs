@@ -2,7 +2,7 @@ use std::io;
 
 use thiserror::Error;
 
-use crate::{Context, format};
+use crate::{Context, Diagnostic, DiagnosticCode, Severity, format};
 
 #[derive(Debug, Error)]
 #[error("{kind} while writing '{context}'")]
@@ -22,6 +22,21 @@ pub enum ErrorKind {
     Format(#[from] format::Error),
 }
 
+impl ErrorKind {
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::Io(_) => DiagnosticCode::PuzIo,
+            Self::Format(_) => DiagnosticCode::PuzFormat,
+        }
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(err: &Error) -> Self {
+        Diagnostic::new(Severity::Error, err.kind.code(), err.to_string())
+    }
+}
+
 impl<T> Context<T, Error> for io::Result<T> {
     fn context<S: Into<String>>(self, context: S) -> Result<T> {
         self.map_err(|err| Error {
@@ -10,10 +10,12 @@
 //! [PUZ spec]: https://gist.github.com/sliminality/dab21fa834eae0a70193c7cd69c356d5
 
 mod error;
+mod options;
 mod size;
 mod util;
 
 pub use error::*;
+pub use options::*;
 pub use size::*;
 pub use util::*;
 
@@ -22,8 +24,11 @@ use puzzled_core::Metadata;
 use std::io::{self, Write};
 
 use crate::{
-    Context,
-    puz::{BinaryPuzzle, ByteStr, Grids, Header, Strings, write},
+    Context, format,
+    puz::{
+        BinaryPuzzle, ByteStr, ChecksumCache, Encoding, Extras, FILE_MAGIC_BYTES, Grids, Header,
+        PuzChecksums, SCRAMBLED_TAG, Strings, scramble_solution, sealed::SealedWrite, write,
+    },
 };
 
 #[derive(Debug, Default)]
@@ -31,8 +36,9 @@ pub struct PuzWriter;
 
 /// Extension trait for [`Write`] to make writing [puzzles](Crossword) to a [binary format](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki) easier
 ///
-/// Includes convenience methods for writing a [`u8`], [`u16`], [`str`] and [`Option<&str>`]
-pub trait PuzWrite: Write {
+/// Includes convenience methods for writing a [`u8`], [`u16`], [`str`] and [`Option<&str>`].
+/// The trait is sealed and implemented for every [writer](Write)
+pub trait PuzWrite: Write + SealedWrite {
     /// Pad the writer with `pad` 0-bytes
     fn pad(&mut self, pad: usize) -> io::Result<()> {
         self.write_all(&vec![0; pad])
@@ -65,6 +71,21 @@ impl PuzWriter {
     }
 
     pub fn write<W, P, S>(&self, writer: &mut W, puzzle: &P, state: &S) -> Result<()>
+    where
+        W: PuzWrite,
+        P: BinaryPuzzle<S>,
+    {
+        self.write_with_options(writer, puzzle, state, &WriteOptions::default())
+    }
+
+    /// Write the puzzle, deciding on the version, encoding, extra sections and scrambling from the [options](WriteOptions)
+    pub fn write_with_options<W, P, S>(
+        &self,
+        writer: &mut W,
+        puzzle: &P,
+        state: &S,
+        options: &WriteOptions,
+    ) -> Result<()>
     where
         W: PuzWrite,
         P: BinaryPuzzle<S>,
@@ -82,22 +103,104 @@ impl PuzWriter {
         // Construct the individual sections from the puzzle
         let meta = puzzle.metadata();
 
+        let version = options.version(meta.and_then(|m| m.version()));
+
         let mut header = self.build_header(puzzle, clues.len() as u16, &meta);
-        let strings = self.build_strings(clues, &meta);
-        let grids = self.build_grids(puzzle, state)?;
-        let extras = puzzle.extras(state)?;
+        if let Some(version) = version {
+            header.version = version.as_bytes();
+        }
 
-        self.write_checksums(&mut header, &grids, &strings);
+        let encoding = options.string_encoding(version);
+        let strings = self.build_strings_with_encoding(clues, &meta, encoding);
+        let mut grids = self.build_grids(puzzle, state)?;
+
+        let mut extras = puzzle.extras(state)?;
+        for (name, handler) in options.section_handlers().iter() {
+            if let Some(bytes) = handler.write() {
+                extras.set_custom(*name, bytes);
+            }
+        }
+
+        // Restore the data of the file that the puzzle was read from
+        let sidecar = puzzle.puz_sidecar();
+        let mut sections = match sidecar {
+            Some(sidecar) => {
+                sidecar.write_header(&mut header);
+                extras.sections_with_sidecar(sidecar, width as u8, height as u8)
+            }
+            None => extras.sections(),
+        };
+        sections.retain(|(name, _)| options.includes_section(name));
+
+        match (options.scramble_key(), puzzle.scrambled_checksum()) {
+            (Some(_), Some(_)) => {
+                return Err(format::Error::AlreadyScrambled).context("Scrambling solution");
+            }
+            (Some(key), None) => {
+                header.scrambled_checksum =
+                    scramble_solution(&mut grids.solution, key).context("Scrambling solution")?;
+                header.scrambled_tag = SCRAMBLED_TAG;
+                header.write_cib();
+            }
+            (None, Some(checksum)) => {
+                header.scrambled_checksum = checksum;
+                header.scrambled_tag = SCRAMBLED_TAG;
+                header.write_cib();
+            }
+            (None, None) => {}
+        }
+
+        self.write_cached_checksums(&mut header, &grids, &strings, puzzle.checksum_cache());
+        if let Some(sidecar) = sidecar {
+            sidecar.write_checksums(&mut header);
+        }
 
         // Write all sections into the writer
         header.write_with(writer)?;
         grids.write_with(writer)?;
         strings.write_with(writer)?;
-        extras.write_with(writer)?;
+        Extras::write_sections(writer, &sections)?;
+
+        if let Some(sidecar) = sidecar {
+            writer
+                .write_all(&sidecar.trailing)
+                .context("Trailing data")?;
+        }
 
         Ok(())
     }
 
+    /// [Checksums](PuzChecksums) that the puzzle is written with by default, only checksumming its strings if they are not in the cache
+    ///
+    /// Checksums of a [sidecar](crate::puz::PuzSidecar) are not restored, such that the checksums always match the data that is written
+    pub fn checksums<P, S>(
+        &self,
+        puzzle: &P,
+        state: &S,
+        cache: &mut ChecksumCache,
+    ) -> Result<PuzChecksums>
+    where
+        P: BinaryPuzzle<S>,
+    {
+        let clues = puzzle.clues();
+        check_puz_size("Clue count", clues.len(), u16::MAX as usize)?;
+
+        let meta = puzzle.metadata();
+        let mut header = self.build_header(puzzle, clues.len() as u16, &meta);
+        if let Some(checksum) = puzzle.scrambled_checksum() {
+            header.scrambled_checksum = checksum;
+            header.scrambled_tag = SCRAMBLED_TAG;
+            header.write_cib();
+        }
+
+        let grids = self.build_grids(puzzle, state)?;
+        let encoding = Encoding::for_version(meta.and_then(|m| m.version()));
+
+        Ok(cache.checksums(&header, &grids, encoding, || {
+            self.build_strings_with_encoding(clues, &meta, encoding)
+        }))
+    }
+
     pub fn build_header<P, S>(
         &self,
         puzzle: &P,
@@ -108,6 +211,7 @@ impl PuzWriter {
         P: BinaryPuzzle<S>,
     {
         let mut header = Header {
+            file_magic: *FILE_MAGIC_BYTES,
             width: puzzle.width() as u8,
             height: puzzle.height() as u8,
             clue_count,
@@ -138,14 +242,31 @@ impl PuzWriter {
         Ok(grids)
     }
 
-    pub fn build_strings(&self, clues: Vec<ByteStr>, metadata: &Option<&Metadata>) -> Strings {
+    /// Build the strings section, encoding them as the [version](Metadata::version) of the puzzle specifies
+    pub fn build_strings(&self, clues: Vec<String>, metadata: &Option<&Metadata>) -> Strings {
+        let meta = match metadata {
+            Some(m) => m,
+            None => &Metadata::default(),
+        };
+        let encoding = Encoding::for_version(meta.version());
+
+        self.build_strings_with_encoding(clues, metadata, encoding)
+    }
+
+    /// Build the strings section, encoding them with the given [encoding](Encoding)
+    pub fn build_strings_with_encoding(
+        &self,
+        clues: Vec<String>,
+        metadata: &Option<&Metadata>,
+        encoding: Encoding,
+    ) -> Strings {
         let meta = match metadata {
             Some(m) => m,
             None => &Metadata::default(),
         };
 
-        let mut strings = Strings::from_metadata(meta);
-        strings.clues = clues;
+        let mut strings = Strings::from_metadata(meta, encoding);
+        strings.clues = clues.iter().map(|clue| strings.encode(clue)).collect();
 
         strings
     }
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use puzzled_core::Version;
+
+use crate::puz::{Encoding, ScrambleKey, SectionHandler, SectionHandlers, SectionName};
+
+/// Decides how a [`PuzWriter`](crate::PuzWriter) writes `*.puz` data
+///
+/// By default, the version and encoding are taken from the [metadata](puzzled_core::Metadata) of the puzzle,
+/// every extra section that the puzzle defines is written and the solution is left unscrambled.
+/// ```
+/// use puzzled_core::Version;
+/// use puzzled_io::puz::{Encoding, ScrambleKey, write::WriteOptions};
+///
+/// // Write an Across Lite 2.0 file without timer and with a scrambled solution
+/// let options = WriteOptions::default()
+///     .with_version(Version::new(2, 0))
+///     .with_ltim(false)
+///     .with_scramble_key(ScrambleKey::new(1234).unwrap());
+///
+/// assert_eq!(options.string_encoding(None), Encoding::Utf8);
+/// assert!(!options.includes_ltim());
+/// assert!(options.includes_gext());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteOptions {
+    version: Option<Version>,
+    string_encoding: Option<Encoding>,
+
+    ltim: bool,
+    gext: bool,
+    rebus: bool,
+
+    scramble_key: Option<ScrambleKey>,
+    section_handlers: SectionHandlers,
+}
+
+impl WriteOptions {
+    /// Write the given version in the [header](crate::puz::Header), rather than the one of the puzzle
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Write strings with the given encoding, rather than the one that the version specifies
+    pub fn with_string_encoding(mut self, encoding: Encoding) -> Self {
+        self.string_encoding = Some(encoding);
+        self
+    }
+
+    /// Whether to write the [LTIM](crate::puz::Ltim) section
+    pub fn with_ltim(mut self, include: bool) -> Self {
+        self.ltim = include;
+        self
+    }
+
+    /// Whether to write the [GEXT](crate::puz::Gext) section
+    pub fn with_gext(mut self, include: bool) -> Self {
+        self.gext = include;
+        self
+    }
+
+    /// Whether to write the [GRBS](crate::puz::Grbs) and [RTBL](crate::puz::Rtbl) sections, which only make sense together
+    pub fn with_rebus(mut self, include: bool) -> Self {
+        self.rebus = include;
+        self
+    }
+
+    /// [Scramble](crate::puz::scramble_solution) the solution with the given key
+    pub fn with_scramble_key(mut self, key: ScrambleKey) -> Self {
+        self.scramble_key = Some(key);
+        self
+    }
+
+    /// Write the extra section with the given name from the raw bytes of the handler, after all other extra sections
+    ///
+    /// # Panics
+    /// Panics if the name is not 4 bytes long
+    pub fn with_section_handler(mut self, name: &str, handler: Arc<dyn SectionHandler>) -> Self {
+        self.section_handlers.insert(name, handler);
+        self
+    }
+
+    /// Version to write, falling back to the version of the puzzle
+    pub fn version(&self, puzzle_version: Option<Version>) -> Option<Version> {
+        self.version.or(puzzle_version)
+    }
+
+    /// Encoding to write strings with, falling back to the one of the [version](Self::version)
+    pub fn string_encoding(&self, puzzle_version: Option<Version>) -> Encoding {
+        self.string_encoding
+            .unwrap_or_else(|| Encoding::for_version(self.version(puzzle_version)))
+    }
+
+    pub fn includes_ltim(&self) -> bool {
+        self.ltim
+    }
+
+    pub fn includes_gext(&self) -> bool {
+        self.gext
+    }
+
+    pub fn includes_rebus(&self) -> bool {
+        self.rebus
+    }
+
+    /// Whether to write the [extra section](crate::puz::Extras) with the given name
+    pub fn includes_section(&self, name: &SectionName) -> bool {
+        match name {
+            b"GRBS" | b"RTBL" => self.rebus,
+            b"LTIM" => self.ltim,
+            b"GEXT" => self.gext,
+            _ => true,
+        }
+    }
+
+    pub fn scramble_key(&self) -> Option<ScrambleKey> {
+        self.scramble_key
+    }
+
+    pub fn section_handlers(&self) -> &SectionHandlers {
+        &self.section_handlers
+    }
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            version: None,
+            string_encoding: None,
+            ltim: true,
+            gext: true,
+            rebus: true,
+            scramble_key: None,
+            section_handlers: SectionHandlers::default(),
+        }
+    }
+}
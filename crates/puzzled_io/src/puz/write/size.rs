@@ -2,6 +2,7 @@ use puzzled_core::Grid;
 
 use crate::{Context, format, puz::write};
 
+#[doc(hidden)]
 pub trait PuzSizeCheck {
     fn check_puz_size(&self) -> write::Result<()>;
 }
@@ -16,6 +17,7 @@ impl<T> PuzSizeCheck for Grid<T> {
     }
 }
 
+#[doc(hidden)]
 pub fn check_puz_size<K>(kind: K, size: usize, max_size: usize) -> write::Result<()>
 where
     K: Into<String>,
@@ -1,6 +1,9 @@
 use puzzled_core::{Cell, CellStyle, Entry, Grid, MISSING_ENTRY_CHAR, NON_PLAYABLE_CHAR, Square};
 
 // State
+//
+// Omitted cells are written as non-playable squares, since the format has no notion of them
+#[doc(hidden)]
 pub trait WriteStateGrid<T> {
     fn write_state_grid<F>(&self, f: F) -> Grid<u8>
     where
@@ -12,9 +15,10 @@ impl<T> WriteStateGrid<T> for Grid<Option<T>> {
     where
         F: FnMut(&T) -> u8,
     {
-        self.map_ref(|square| match square {
-            None => MISSING_ENTRY_CHAR as u8,
-            Some(solution) => f(solution),
+        self.map_unmasked(|_, square| match square {
+            None => NON_PLAYABLE_CHAR as u8,
+            Some(None) => MISSING_ENTRY_CHAR as u8,
+            Some(Some(solution)) => f(solution),
         })
     }
 }
@@ -24,9 +28,10 @@ impl<T> WriteStateGrid<T> for Grid<Entry<T>> {
     where
         F: FnMut(&T) -> u8,
     {
-        self.map_ref(|entry| match entry.entry() {
-            None => MISSING_ENTRY_CHAR as u8,
-            Some(solution) => f(solution),
+        self.map_unmasked(|_, entry| match entry.map(Entry::entry) {
+            None => NON_PLAYABLE_CHAR as u8,
+            Some(None) => MISSING_ENTRY_CHAR as u8,
+            Some(Some(solution)) => f(solution),
         })
     }
 }
@@ -36,13 +41,15 @@ impl<T> WriteStateGrid<T> for Grid<Square<Option<T>>> {
     where
         F: FnMut(&T) -> u8,
     {
-        self.map_ref(|square| match square.as_ref() {
-            None => NON_PLAYABLE_CHAR as u8,
-            Some(solution) => solution
-                .as_ref()
-                .map(&mut f)
-                .unwrap_or(MISSING_ENTRY_CHAR as u8),
-        })
+        self.map_unmasked(
+            |_, square| match square.and_then(|square| square.as_ref()) {
+                None => NON_PLAYABLE_CHAR as u8,
+                Some(solution) => solution
+                    .as_ref()
+                    .map(&mut f)
+                    .unwrap_or(MISSING_ENTRY_CHAR as u8),
+            },
+        )
     }
 }
 
@@ -51,55 +58,52 @@ impl<T> WriteStateGrid<T> for Grid<Square<Entry<T>>> {
     where
         F: FnMut(&T) -> u8,
     {
-        self.map_ref(|square| match square.as_ref() {
-            None => NON_PLAYABLE_CHAR as u8,
-            Some(entry) => match entry.entry() {
-                Some(solution) => f(solution),
-                _ => MISSING_ENTRY_CHAR as u8,
+        self.map_unmasked(
+            |_, square| match square.and_then(|square| square.as_ref()) {
+                None => NON_PLAYABLE_CHAR as u8,
+                Some(entry) => match entry.entry() {
+                    Some(solution) => f(solution),
+                    _ => MISSING_ENTRY_CHAR as u8,
+                },
             },
-        })
+        )
     }
 }
 
 // Styles
+//
+// Only the GEXT styles are written, where omitted cells have no style but still take up a byte to keep the grid at its full width
+#[doc(hidden)]
 pub trait WriteStyleGrid<T, U> {
     fn write_combined_style(&self, other: &Grid<U>) -> Grid<CellStyle>;
 }
 
 impl<T> WriteStyleGrid<Cell<T>, Entry<T>> for Grid<Cell<T>> {
     fn write_combined_style(&self, entries: &Grid<Entry<T>>) -> Grid<CellStyle> {
-        let styles: Vec<_> = self
-            .iter()
-            .zip(entries.iter())
-            .map(|(cell, entry)| cell.style | entry.style())
-            .collect();
+        self.map_unmasked(|pos, cell| {
+            let puzzle_style = cell.map(|cell| cell.style).unwrap_or_default();
+            let user_style = entries.get(pos).map(Entry::style).unwrap_or_default();
 
-        Grid::from_vec(styles, self.cols())
-            .expect("Constructing GEXT from valid squares and entries")
+            (puzzle_style | user_style) & CellStyle::GEXT
+        })
     }
 }
 
 impl<T> WriteStyleGrid<Square<Cell<T>>, Square<Entry<T>>> for Grid<Square<Cell<T>>> {
     fn write_combined_style(&self, entries: &Grid<Square<Entry<T>>>) -> Grid<CellStyle> {
-        let styles: Vec<_> = self
-            .iter()
-            .zip(entries.iter())
-            .map(|(puzzle_square, entry_square)| {
-                let puzzle_style = puzzle_square
-                    .as_ref()
-                    .map(|sq| sq.style)
-                    .unwrap_or_default();
+        self.map_unmasked(|pos, puzzle_square| {
+            let puzzle_style = puzzle_square
+                .and_then(|sq| sq.as_ref())
+                .map(|sq| sq.style)
+                .unwrap_or_default();
 
-                let user_style = entry_square
-                    .as_ref()
-                    .map(|sq| sq.style())
-                    .unwrap_or_default();
-
-                puzzle_style | user_style
-            })
-            .collect();
+            let user_style = entries
+                .get(pos)
+                .and_then(|sq| sq.as_ref())
+                .map(|sq| sq.style())
+                .unwrap_or_default();
 
-        Grid::from_vec(styles, self.cols())
-            .expect("Constructing GEXT from valid squares and entries")
+            (puzzle_style | user_style) & CellStyle::GEXT
+        })
     }
 }
@@ -0,0 +1,79 @@
+use puzzled_core::{MISSING_ENTRY_CHAR, NON_PLAYABLE_CHAR};
+
+/// Characters that the text format uses to mark parts of a puzzle
+///
+/// The [default](Default) configuration is the format of the puzzle macros, such as `crossword!`.
+/// Other configurations can be used to read and write text dumps from other sources, e.g. grids that mark black squares with `#`:
+/// ```
+/// use puzzled_io::text::FormatConfig;
+///
+/// let config = FormatConfig::default()
+///     .with_block('#')
+///     .with_rebus_delimiters('{', '}');
+///
+/// assert_eq!(config.block(), '#');
+/// assert_eq!(config.missing(), '-');
+/// assert_eq!(config.rebus_delimiters(), Some(('{', '}')));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatConfig {
+    block: char,
+    missing: char,
+    bullet: char,
+    rebus: Option<(char, char)>,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            block: NON_PLAYABLE_CHAR,
+            missing: MISSING_ENTRY_CHAR,
+            bullet: '-',
+            rebus: None,
+        }
+    }
+}
+
+impl FormatConfig {
+    /// Character of a square that cannot be played, such as a black square in a crossword
+    pub fn block(&self) -> char {
+        self.block
+    }
+
+    /// Character of a square whose solution is not known
+    pub fn missing(&self) -> char {
+        self.missing
+    }
+
+    /// Character that starts every item of a list after the grid, such as the clues of a crossword
+    ///
+    /// Lists follow the grid directly, so there is no separate marker for the start of a section.
+    pub fn bullet(&self) -> char {
+        self.bullet
+    }
+
+    /// Characters around solutions of multiple letters, which are written without delimiters if there are none
+    pub fn rebus_delimiters(&self) -> Option<(char, char)> {
+        self.rebus
+    }
+
+    pub fn with_block(mut self, block: char) -> Self {
+        self.block = block;
+        self
+    }
+
+    pub fn with_missing(mut self, missing: char) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    pub fn with_bullet(mut self, bullet: char) -> Self {
+        self.bullet = bullet;
+        self
+    }
+
+    pub fn with_rebus_delimiters(mut self, open: char, close: char) -> Self {
+        self.rebus = Some((open, close));
+        self
+    }
+}
@@ -1,6 +1,9 @@
+mod config;
 pub mod read;
 pub mod write;
 
+pub use config::*;
+
 use std::{fmt::Display, fs, io};
 
 use puzzled_core::Puzzle;
@@ -9,8 +12,19 @@ pub use read::TxtReader;
 use crate::puzzle_dir;
 
 pub trait TxtPuzzle: Puzzle + Display {
-    fn read_text(input: &str) -> read::Result<Self>;
-    fn write_text(&self) -> String;
+    /// Read the puzzle from text that marks its squares as [configured](FormatConfig)
+    fn read_text_with(input: &str, config: &FormatConfig) -> read::Result<Self>;
+
+    /// Write the puzzle as text that marks its squares as [configured](FormatConfig)
+    fn write_text_with(&self, config: &FormatConfig) -> String;
+
+    fn read_text(input: &str) -> read::Result<Self> {
+        Self::read_text_with(input, &FormatConfig::default())
+    }
+
+    fn write_text(&self) -> String {
+        self.write_text_with(&FormatConfig::default())
+    }
 
     fn load_text(name: &str) -> read::Result<Self> {
         let dir = puzzle_dir::<Self>()?;
@@ -3,41 +3,69 @@ use chumsky::{
     extra::Err,
     prelude::{group, just, one_of},
 };
-use puzzled_core::{Cell, CellStyle, Entry, Grid, MISSING_ENTRY_CHAR};
+use puzzled_core::{Cell, CellDecoration, CellStyle, Entry, Grid};
 
-use crate::text::read::{ParseError, grid};
+use crate::text::{
+    FormatConfig,
+    read::{ParseError, grid},
+};
 
 pub fn cell<'a, T, P>(value: P) -> impl Parser<'a, &'a str, Cell<T>, Err<ParseError<'a>>> + Clone
+where
+    P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
+{
+    cell_with(value, FormatConfig::default())
+}
+
+/// Parse a cell whose missing solution is marked as [configured](FormatConfig)
+pub fn cell_with<'a, T, P>(
+    value: P,
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, Cell<T>, Err<ParseError<'a>>> + Clone
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
     group((
-        solution(value.clone()).padded(),
+        solution(value.clone(), config.missing()).padded(),
         cell_style().or_not().padded(),
+        shading().padded(),
     ))
     .padded()
-    .map(|(solution, opt_style)| {
+    .map(|(solution, opt_style, decoration)| {
         let style = opt_style.unwrap_or_default();
 
-        Cell::new_with_style(solution, style)
+        Cell::new_with_style(solution, style).with_decoration(decoration)
     })
 }
 
 pub fn cell_entry<'a, T, P>(
     value: P,
 ) -> impl Parser<'a, &'a str, (Cell<T>, Entry<T>), Err<ParseError<'a>>> + Clone
+where
+    P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
+{
+    cell_entry_with(value, FormatConfig::default())
+}
+
+/// Parse a cell and its entry whose missing solution is marked as [configured](FormatConfig)
+pub fn cell_entry_with<'a, T, P>(
+    value: P,
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, (Cell<T>, Entry<T>), Err<ParseError<'a>>> + Clone
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
     group((
-        solution(value.clone()).padded(),
+        solution(value.clone(), config.missing()).padded(),
         cell_style().or_not().padded(),
+        shading().padded(),
         entry(value.clone()).padded(),
     ))
     .padded()
-    .map(|(solution, opt_style, entry)| {
+    .map(|(solution, opt_style, decoration, entry)| {
+        // Only the initial styles belong to the puzzle, the others are part of the player state
         let style = opt_style.unwrap_or_default();
-        let cell = Cell::new_with_style(solution, style);
+        let cell = Cell::new_with_style(solution, style.initial()).with_decoration(decoration);
         let entry = Entry::new_with_style(entry, style);
 
         (cell, entry)
@@ -45,7 +73,7 @@ where
 }
 
 pub fn cell_style<'a>() -> impl Parser<'a, &'a str, CellStyle, Err<ParseError<'a>>> + Clone {
-    one_of("*@~`!")
+    one_of("*@~`!?")
         .repeated()
         .fold(CellStyle::default(), |style, marker| match marker {
             '*' => style | CellStyle::REVEALED,
@@ -53,41 +81,36 @@ pub fn cell_style<'a>() -> impl Parser<'a, &'a str, CellStyle, Err<ParseError<'a
             '!' => style | CellStyle::INCORRECT,
             '~' => style | CellStyle::PREVIOUSLY_INCORRECT,
             '@' => style | CellStyle::CIRCLED,
-            _ => unreachable!("Only parsed one_of(\"*@~`!\")"),
+            '?' => style | CellStyle::PENCIL,
+            _ => unreachable!("Only parsed one_of(\"*@~`!?\")"),
         })
 }
 
+/// Parse the `%` marker of a [shaded](CellDecoration::shaded) cell, which follows its style markers
+pub fn shading<'a>() -> impl Parser<'a, &'a str, CellDecoration, Err<ParseError<'a>>> + Clone {
+    just('%').or_not().map(|shaded| match shaded {
+        Some(_) => CellDecoration::shaded(),
+        None => CellDecoration::default(),
+    })
+}
+
 pub fn cell_entry_grids<'a, T, P>(
     value: P,
 ) -> impl Parser<'a, &'a str, (Grid<Cell<T>>, Grid<Entry<T>>), Err<ParseError<'a>>>
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
-    grid(cell_entry(value)).map(|cell_entries| {
-        let cols = cell_entries.cols();
-
-        let (cells, entries) = cell_entries.into_iter().fold(
-            (vec![], vec![]),
-            |(mut cells, mut entries), (cell, entry)| {
-                cells.push(cell);
-                entries.push(entry);
-
-                (cells, entries)
-            },
-        );
-
-        let cells = Grid::from_vec(cells, cols).expect("Read cells from grid");
-        let entries = Grid::from_vec(entries, cols).expect("Read entries from grid");
-
-        (cells, entries)
-    })
+    grid(cell_entry(value)).map(Grid::unzip)
 }
 
-fn solution<'a, T, P>(value: P) -> impl Parser<'a, &'a str, Option<T>, Err<ParseError<'a>>> + Clone
+fn solution<'a, T, P>(
+    value: P,
+    missing: char,
+) -> impl Parser<'a, &'a str, Option<T>, Err<ParseError<'a>>> + Clone
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
-    just(MISSING_ENTRY_CHAR).map(|_| None).or(value.map(Some))
+    just(missing).map(|_| None).or(value.map(Some))
 }
 
 fn entry<'a, T, P>(value: P) -> impl Parser<'a, &'a str, Option<T>, Err<ParseError<'a>>> + Clone
@@ -112,12 +135,13 @@ mod tests {
     const _C: CellStyle = CellStyle::CIRCLED;
 
     #[rstest]
-    #[case("-", None, None, _E)]
-    #[case("10", Some(10), None, _E)]
-    #[case("10*", Some(10), None, _R)]
-    #[case("10*@", Some(10), None, _R | _C)]
-    #[case("10*@ (10)", Some(10), Some(10), _R | _C)]
-    #[case("10*@ (22)", Some(10), Some(22), _R | _C)]
+    #[case("-", None, None, _E, _E)]
+    #[case("10", Some(10), None, _E, _E)]
+    #[case("10*", Some(10), None, _E, _R)]
+    #[case("10*@", Some(10), None, _C, _R | _C)]
+    #[case("10*@ (10)", Some(10), Some(10), _C, _R | _C)]
+    #[case("10*@ (22)", Some(10), Some(22), _C, _R | _C)]
+    #[case("10!~ (22)", Some(10), Some(22), _E, _I | _P)]
     // #[case("10 10", Some(10), None, _R | _C)]
     // #[case("10*@ 10", Some(10), None, _R | _C)]
     // zfZTQFQ3h9SL98BK
@@ -125,7 +149,8 @@ mod tests {
         #[case] input: &str,
         #[case] cell_val: Option<usize>,
         #[case] entry_val: Option<usize>,
-        #[case] style: CellStyle,
+        #[case] cell_style: CellStyle,
+        #[case] entry_style: CellStyle,
     ) {
         let value = text::digits::<_, Err<ParseError<'_>>>(10)
             .to_slice()
@@ -138,7 +163,26 @@ mod tests {
             .expect("Parsing should succeed");
 
         assert_eq!(cell_val, cell.solution);
-        assert_eq!(style, cell.style);
+        assert_eq!(cell_style, cell.style);
         assert_eq!(entry_val.as_ref(), entry.entry());
+        assert_eq!(entry_style, entry.style());
+    }
+
+    #[rstest]
+    #[case("10", false)]
+    #[case("10%", true)]
+    #[case("10@% (22)", true)]
+    fn shaded_cells(#[case] input: &str, #[case] shaded: bool) {
+        let value = text::digits::<_, Err<ParseError<'_>>>(10)
+            .to_slice()
+            .from_str::<usize>()
+            .unwrapped();
+
+        let (cell, _) = cell_entry(value)
+            .parse(input)
+            .into_output()
+            .expect("Parsing should succeed");
+
+        assert_eq!(cell.decoration.is_shaded(), shaded);
     }
 }
@@ -1,16 +1,19 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 use chumsky::{error::Rich, span::SimpleSpan};
 
-use crate::format;
+use crate::{Diagnostic, DiagnosticCode, Severity, format};
 
 pub type Span = SimpleSpan<usize>;
 pub type ParseError<'a> = Rich<'a, char>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Found parsing errors: {0:?}")]
-    Parse(Vec<String>),
+    #[error("Found parsing errors: {}", errors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    Parse {
+        errors: Vec<SpannedError>,
+        src: String,
+    },
 
     #[error("Found invalid metadata property \"{found}\": {reason}")]
     InvalidMetaProperty { found: String, reason: String },
@@ -28,4 +31,183 @@ impl Error {
     pub fn format(format: format::Error, span: Span) -> Self {
         Self::Format { format, span }
     }
+
+    /// Collect the errors from parsing the input, locating each of them in the input
+    /// ```
+    /// use chumsky::{Parser, extra::Err, prelude::just};
+    /// use puzzled_io::text::read::{Error, Location, ParseError};
+    ///
+    /// let input = "[A B]\n[C D";
+    /// let errs = just::<_, _, Err<ParseError>>("[A B]\n[C D]").parse(input).into_errors();
+    ///
+    /// let Error::Parse { errors, .. } = Error::parse(input, errs) else {
+    ///     panic!("Expected parsing errors");
+    /// };
+    /// assert_eq!(errors[0].start, Location { line: 2, col: 5 });
+    /// ```
+    pub fn parse<'a>(input: &str, errs: impl IntoIterator<Item = ParseError<'a>>) -> Self {
+        let errors = errs
+            .into_iter()
+            .map(|err| SpannedError::new(input, err.to_string(), *err.span()))
+            .collect();
+
+        Self::Parse {
+            errors,
+            src: input.to_string(),
+        }
+    }
+
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::Parse { .. } => DiagnosticCode::TxtParse,
+            Self::InvalidMetaProperty { .. } => DiagnosticCode::TxtInvalidMetaProperty,
+            Self::Format { .. } => DiagnosticCode::TxtFormat,
+            Self::Io(_) => DiagnosticCode::TxtIo,
+        }
+    }
+
+    /// Report the error as [diagnostics](Diagnostic), where every parsing error becomes a separate diagnostic
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        match self {
+            Self::Parse { errors, .. } => errors.iter().map(Diagnostic::from).collect(),
+            Self::Format { span, .. } => {
+                vec![
+                    Diagnostic::new(Severity::Error, self.code(), self.to_string())
+                        .with_span(span.into_range()),
+                ]
+            }
+            _ => vec![Diagnostic::new(
+                Severity::Error,
+                self.code(),
+                self.to_string(),
+            )],
+        }
+    }
+}
+
+/// Line and column of a character in the input, both starting from 1
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Location {
+    /// Locate the character at the given byte offset in the input
+    ///
+    /// Offsets past the end of the input are located right after its last character
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let before = input.get(..offset).unwrap_or(input);
+
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let col = before[line_start..].chars().count() + 1;
+
+        Self { line, col }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+/// Error that occurred at a [span](Span) of the input, such as a grid row or clue line
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message} at {start}")]
+pub struct SpannedError {
+    /// Description of what went wrong
+    pub message: String,
+
+    /// Byte range of the input that the error points at
+    pub span: Span,
+
+    /// Location of the start of the span
+    pub start: Location,
+
+    /// Location of the end of the span
+    pub end: Location,
+}
+
+impl SpannedError {
+    pub fn new<S: Into<String>>(input: &str, message: S, span: Span) -> Self {
+        Self {
+            message: message.into(),
+            span,
+            start: Location::from_offset(input, span.start),
+            end: Location::from_offset(input, span.end),
+        }
+    }
+}
+
+impl From<&SpannedError> for Diagnostic {
+    fn from(err: &SpannedError) -> Self {
+        Diagnostic::new(Severity::Error, DiagnosticCode::TxtParse, err.to_string())
+            .with_span(err.span.into_range())
+    }
+}
+
+#[cfg(feature = "miette")]
+mod miette_impl {
+    use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+    use super::{Error, Span, SpannedError};
+
+    fn label(span: &Span, message: &str) -> LabeledSpan {
+        LabeledSpan::at(span.start..span.end, message)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "miette")))]
+    impl Diagnostic for SpannedError {
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            Some(Box::new(std::iter::once(label(&self.span, &self.message))))
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "miette")))]
+    impl Diagnostic for Error {
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            match self {
+                Error::Parse { src, .. } => Some(src),
+                _ => None,
+            }
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            match self {
+                Error::Format { format, span } => {
+                    Some(Box::new(std::iter::once(label(span, &format.to_string()))))
+                }
+                _ => None,
+            }
+        }
+
+        fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+            match self {
+                Error::Parse { errors, .. } => {
+                    Some(Box::new(errors.iter().map(|err| err as &dyn Diagnostic)))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::start("[A B]\n[C D]", 0, Location { line: 1, col: 1 })]
+    #[case::same_line("[A B]\n[C D]", 3, Location { line: 1, col: 4 })]
+    #[case::next_line("[A B]\n[C D]", 7, Location { line: 2, col: 2 })]
+    #[case::multibyte("[É B]\n[C D]", 4, Location { line: 1, col: 4 })]
+    #[case::past_end("[A B]", 10, Location { line: 1, col: 6 })]
+    fn location(#[case] input: &str, #[case] offset: usize, #[case] expected: Location) {
+        assert_eq!(Location::from_offset(input, offset), expected);
+    }
 }
@@ -3,17 +3,19 @@ use std::{collections::HashMap, fmt};
 use chumsky::{
     IterParser, Parser,
     extra::Err,
-    prelude::{group, just},
+    prelude::{any, end, group, just},
 };
-use puzzled_core::{Direction, Grid, GridError, SidedGrid};
+use puzzled_core::{Direction, Grid, GridError, OMITTED_CELL_CHAR, SidedGrid};
 
 use crate::text::read::ParseError;
 
+/// Parse a grid of rows, in which cells that are [omitted](Grid::has_cell) are marked with [`OMITTED_CELL_CHAR`]
 pub fn grid<'a, T, P>(value: P) -> impl Parser<'a, &'a str, Grid<T>, Err<ParseError<'a>>>
 where
+    T: Default,
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
-    grid_row(value)
+    grid_row(omitted_cell().map(|_| None).or(value.map(Some)))
         .padded()
         .repeated()
         .at_least(1)
@@ -22,10 +24,23 @@ where
             let col_count = rows.first().map(|r| r.len()).unwrap_or(0);
             let flat = rows.into_iter().flatten().collect();
 
-            Grid::from_vec(flat, col_count).map_err(|err| ParseError::custom(span, err.to_string()))
+            Grid::from_cells(flat, col_count)
+                .map_err(|err| ParseError::custom(span, err.to_string()))
         })
 }
 
+/// Parse a single [`OMITTED_CELL_CHAR`], which should not be the start of a longer value
+fn omitted_cell<'a>() -> impl Parser<'a, &'a str, (), Err<ParseError<'a>>> + Clone {
+    let separator = any()
+        .filter(|ch: &char| ch.is_whitespace() || *ch == ']')
+        .ignored()
+        .or(end());
+
+    just(OMITTED_CELL_CHAR)
+        .then_ignore(separator.rewind())
+        .ignored()
+}
+
 pub fn sided_grid<'a, T, U, V, S>(
     value: V,
     side: S,
@@ -127,6 +142,8 @@ where
         .collect::<Vec<T>>()
         .padded()
         .delimited_by(just('['), just(']'))
+        .labelled("grid row")
+        .as_context()
 }
 
 fn some_or_extend<T>(opt_vec: &mut Option<Vec<T>>, opt_item: Option<T>) {
@@ -144,7 +161,8 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use puzzled_core::grid;
+    use crate::text::read::{Error, Location};
+    use puzzled_core::{Position, grid};
 
     #[rstest]
     #[case("[1 2 3 4 5]", vec![1, 2, 3, 4, 5])]
@@ -180,4 +198,38 @@ mod tests {
 
         assert_eq!(output, grid);
     }
+    #[test]
+    fn test_grid_omitted() {
+        let value = text::digits::<_, Err<ParseError<'_>>>(10)
+            .to_slice()
+            .from_str::<usize>()
+            .unwrapped();
+
+        let grid: Grid<usize> = grid(value)
+            .parse("[1 _ _]\n[2 3 _]\n[4 5 6]")
+            .into_output()
+            .expect("Parsing should succeed");
+
+        assert_eq!(grid.cell_count(), 6);
+        assert!(!grid.has_cell(Position::new(0, 2)));
+        assert_eq!(grid.to_string(), "[ 1 _ _ ]\n[ 2 3 _ ]\n[ 4 5 6 ]");
+    }
+
+    #[rstest]
+    #[case::first_row("[1 x]\n[3 4]", Location { line: 1, col: 4 })]
+    #[case::second_row("[1 2]\n  [3 x]", Location { line: 2, col: 6 })]
+    fn test_grid_error(#[case] input: &str, #[case] expected: Location) {
+        let value = text::digits::<_, Err<ParseError<'_>>>(10)
+            .to_slice()
+            .from_str::<usize>()
+            .unwrapped();
+
+        let errs = grid(value).parse(input).into_errors();
+        let Error::Parse { errors, .. } = Error::parse(input, errs) else {
+            panic!("Parsing should fail");
+        };
+
+        assert_eq!(errors[0].start, expected);
+        assert!(errors[0].message.contains("grid row"));
+    }
 }
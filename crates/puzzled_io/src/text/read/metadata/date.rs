@@ -0,0 +1,17 @@
+use chrono::NaiveDate;
+use chumsky::{Parser, extra::Err, prelude::any};
+
+use crate::text::read::ParseError;
+
+/// Date formatted as `YYYY-MM-DD`
+pub fn date<'a>() -> impl Parser<'a, &'a str, NaiveDate, Err<ParseError<'a>>> + Clone {
+    any()
+        .filter(|c: &char| c.is_ascii_digit() || *c == '-')
+        .repeated()
+        .at_least(1)
+        .to_slice()
+        .try_map(|date: &'a str, span| {
+            date.parse::<NaiveDate>()
+                .map_err(|err| ParseError::custom(span, format!("Invalid date '{date}': {err}")))
+        })
+}
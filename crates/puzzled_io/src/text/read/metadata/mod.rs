@@ -1,6 +1,10 @@
+#[cfg(feature = "chrono")]
+mod date;
 mod timer;
 mod version;
 
+#[cfg(feature = "chrono")]
+pub use date::*;
 pub use timer::*;
 pub use version::*;
 
@@ -8,10 +12,11 @@ use chumsky::{
     IterParser, Parser,
     extra::Err,
     prelude::{choice, just},
+    text,
 };
 use puzzled_core::{Metadata, Timer, Version};
 
-use crate::text::read::{ParseError, quoted_string};
+use crate::text::read::{ParseError, escaped_string};
 
 pub fn metadata_with_timer<'a>()
 -> impl Parser<'a, &'a str, (Metadata, Option<Timer>), Err<ParseError<'a>>> + Clone {
@@ -31,22 +36,29 @@ pub fn metadata_with_timer<'a>()
                     MetaField::Timer(timer_val) => {
                         timer = Some(timer_val);
                     }
+                    #[cfg(feature = "chrono")]
+                    MetaField::Date(date) => {
+                        meta = meta.with_date(date);
+                    }
                     MetaField::String { key, val } => match key {
                         "author" => {
-                            meta = meta.with_author(val.to_string());
+                            meta = meta.with_author(val);
                         }
                         "copyright" => {
-                            meta = meta.with_copyright(val.to_string());
+                            meta = meta.with_copyright(val);
                         }
                         "notes" => {
-                            meta = meta.with_notes(val.to_string());
+                            meta = meta.with_notes(val);
+                        }
+                        "theme_note" => {
+                            meta = meta.with_theme_note(val);
                         }
                         "title" => {
-                            meta = meta.with_title(val.to_string());
+                            meta = meta.with_title(val);
+                        }
+                        key => {
+                            meta.insert_extra(key.to_string(), val);
                         }
-                        key => unreachable!(
-                            "Should have filtered out invalid meta field '{key}' during parsing"
-                        ),
                     },
                 }
             }
@@ -56,9 +68,14 @@ pub fn metadata_with_timer<'a>()
 }
 
 pub enum MetaField<'a> {
-    String { key: &'a str, val: &'a str },
+    String {
+        key: &'a str,
+        val: String,
+    },
     Version(Version),
     Timer(Timer),
+    #[cfg(feature = "chrono")]
+    Date(chrono::NaiveDate),
 }
 
 pub fn meta_field<'a>() -> impl Parser<'a, &'a str, MetaField<'a>, Err<ParseError<'a>>> + Clone {
@@ -68,6 +85,7 @@ pub fn meta_field<'a>() -> impl Parser<'a, &'a str, MetaField<'a>, Err<ParseErro
             meta_str("author"),
             meta_str("copyright"),
             meta_str("notes"),
+            meta_str("theme_note"),
             meta_str("title"),
         ))
         .map(|(key, val)| MetaField::String { key, val }),
@@ -75,16 +93,25 @@ pub fn meta_field<'a>() -> impl Parser<'a, &'a str, MetaField<'a>, Err<ParseErro
         meta_key_val("version", version()).map(MetaField::Version),
         // Timer
         meta_key_val("timer", timer()).map(MetaField::Timer),
+        // Date
+        #[cfg(feature = "chrono")]
+        meta_key_val("date", date()).map(MetaField::Date),
+        // Custom properties
+        text::ident()
+            .padded()
+            .then_ignore(just(':').padded())
+            .then(escaped_string())
+            .map(|(key, val)| MetaField::String { key, val }),
     ))
 }
 
 fn meta_str<'a>(
     key: &'a str,
-) -> impl Parser<'a, &'a str, (&'a str, &'a str), Err<ParseError<'a>>> + Clone {
+) -> impl Parser<'a, &'a str, (&'a str, String), Err<ParseError<'a>>> + Clone {
     just(key)
         .padded()
         .ignore_then(just(':').padded())
-        .ignore_then(quoted_string())
+        .ignore_then(escaped_string())
         .map(move |val| (key, val))
 }
 
@@ -109,9 +136,20 @@ mod tests {
 
     #[rstest]
     #[case("author: \"The New York Times\" title: \"2026-03-07-nyt\"")]
+    #[cfg_attr(feature = "chrono", case("title: \"Gold Rush\" date: 2026-03-07"))]
     fn test_metadata(#[case] input: &str) {
         if let Err(errs) = metadata_with_timer().parse(input).into_result() {
             panic!("Errors: {errs:?}");
         }
     }
+
+    #[test]
+    fn extra() {
+        let input = "title: \"Gold Rush\" editor: \"Will Shortz\" difficulty: \"Easy\"";
+        let (meta, _) = metadata_with_timer().parse(input).unwrap();
+
+        assert_eq!(meta.title(), Some("Gold Rush"));
+        assert_eq!(meta.get_extra("editor"), Some("Will Shortz"));
+        assert_eq!(meta.get_extra("difficulty"), Some("Easy"));
+    }
 }
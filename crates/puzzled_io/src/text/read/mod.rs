@@ -14,23 +14,33 @@ pub use metadata::*;
 pub use square::*;
 pub use util::*;
 
-use crate::text::TxtPuzzle;
+use crate::text::{FormatConfig, TxtPuzzle};
 
 #[derive(Debug, Default)]
 pub struct TxtReader {
     _strict: bool,
+    config: FormatConfig,
 }
 
 impl TxtReader {
     pub fn new(strict: bool) -> Self {
-        Self { _strict: strict }
+        Self {
+            _strict: strict,
+            config: FormatConfig::default(),
+        }
+    }
+
+    /// Read text that marks the squares of the puzzle as [configured](FormatConfig) instead of the default format
+    pub fn with_config(mut self, config: FormatConfig) -> Self {
+        self.config = config;
+        self
     }
 
     pub fn read<P>(&self, input: &str) -> Result<P>
     where
         P: TxtPuzzle,
     {
-        P::read_text(input)
+        P::read_text_with(input, &self.config)
     }
 
     pub fn read_from_path<R, P>(&self, path: R) -> Result<P>
@@ -1,9 +1,12 @@
 use chumsky::{Parser, extra::Err, prelude::just};
-use puzzled_core::{Cell, Entry, Grid, NON_PLAYABLE_CHAR, Square};
+use puzzled_core::{Cell, Entry, Grid, Square};
 
 use crate::{
     SquareEntries,
-    text::read::{ParseError, cell, cell_entry, grid},
+    text::{
+        FormatConfig,
+        read::{ParseError, cell_entry_with, cell_with, grid},
+    },
 };
 
 pub fn square<'a, T, P>(
@@ -12,9 +15,20 @@ pub fn square<'a, T, P>(
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
-    just(NON_PLAYABLE_CHAR)
+    square_with(value, FormatConfig::default())
+}
+
+/// Parse a square that is marked as [configured](FormatConfig)
+pub fn square_with<'a, T, P>(
+    value: P,
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, Square<Cell<T>>, Err<ParseError<'a>>> + Clone
+where
+    P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
+{
+    just(config.block())
         .map(|_| Square::new_empty())
-        .or(cell(value).map(Square::new))
+        .or(cell_with(value, config).map(Square::new))
 }
 
 pub fn square_entry<'a, T, P>(
@@ -23,9 +37,21 @@ pub fn square_entry<'a, T, P>(
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
-    just(NON_PLAYABLE_CHAR)
+    square_entry_with(value, FormatConfig::default())
+}
+
+/// Parse a square and its entry that are marked as [configured](FormatConfig)
+pub fn square_entry_with<'a, T, P>(
+    value: P,
+    config: FormatConfig,
+) -> impl Parser<'a, &'a str, (Square<Cell<T>>, Square<Entry<T>>), Err<ParseError<'a>>> + Clone
+where
+    P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
+{
+    just(config.block())
         .map(|_| (Square::new_empty(), Square::new_empty()))
-        .or(cell_entry(value).map(|(cell, entry)| (Square::new(cell), Square::new(entry))))
+        .or(cell_entry_with(value, config)
+            .map(|(cell, entry)| (Square::new(cell), Square::new(entry))))
 }
 
 pub fn square_entry_grids<'a, T, P>(
@@ -34,22 +60,5 @@ pub fn square_entry_grids<'a, T, P>(
 where
     P: Parser<'a, &'a str, T, Err<ParseError<'a>>> + Clone,
 {
-    grid(square_entry(value)).map(|cell_entries| {
-        let cols = cell_entries.cols();
-
-        let (cells, entries) = cell_entries.into_iter().fold(
-            (vec![], vec![]),
-            |(mut cells, mut entries), (cell, entry)| {
-                cells.push(cell);
-                entries.push(entry);
-
-                (cells, entries)
-            },
-        );
-
-        let cells = Grid::from_vec(cells, cols).expect("Read cells from grid");
-        let entries = Grid::from_vec(entries, cols).expect("Read entries from grid");
-
-        (cells, entries)
-    })
+    grid(square_entry(value)).map(Grid::unzip)
 }
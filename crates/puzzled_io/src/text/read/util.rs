@@ -2,7 +2,7 @@ use chumsky::{
     Parser,
     error::Rich,
     extra::Err,
-    prelude::{just, none_of},
+    prelude::{IterParser, choice, just, none_of, one_of},
     text::ident,
 };
 
@@ -14,6 +14,17 @@ pub fn quoted_string<'a>() -> impl Parser<'a, &'a str, &'a str, Err<ParseError<'
         .then_ignore(just('"'))
 }
 
+/// Quoted string in which quotes and backslashes are escaped with a backslash, as written by [`quoted`](crate::text::write::quoted)
+///
+/// Other backslashes are kept as they are.
+pub fn escaped_string<'a>() -> impl Parser<'a, &'a str, String, Err<ParseError<'a>>> + Clone {
+    let escape = just('\\').ignore_then(one_of("\"\\"));
+
+    just('"')
+        .ignore_then(choice((escape, none_of('"'))).repeated().collect())
+        .then_ignore(just('"'))
+}
+
 /// A case-insensitive variant of chumsky::text::keyword
 pub fn ignore_case_keyword<'a>(
     keyword: &'static str,
@@ -1,3 +1,5 @@
+use crate::{Diagnostic, DiagnosticCode, Severity};
+
 #[derive(Debug, thiserror::Error)]
 #[error("Write error: {0}")]
 pub enum Error {
@@ -5,4 +7,10 @@ pub enum Error {
     Custom,
 }
 
+impl From<&Error> for Diagnostic {
+    fn from(err: &Error) -> Self {
+        Diagnostic::new(Severity::Error, DiagnosticCode::TxtWrite, err.to_string())
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
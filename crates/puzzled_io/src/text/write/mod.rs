@@ -1,3 +1,121 @@
 mod error;
 
 pub use error::*;
+
+use std::fmt::Display;
+
+use puzzled_core::{Cell, Metadata};
+
+use crate::text::FormatConfig;
+
+/// Text of a cell, whose missing solution is marked as [configured](FormatConfig)
+pub fn cell_text<T: Display>(cell: &Cell<T>, config: &FormatConfig) -> String {
+    match &cell.solution {
+        Some(solution) => format!("{solution}{}", cell.style),
+        None => format!("{}{}", config.missing(), cell.style),
+    }
+}
+
+/// Keys of the properties that are read into the fields of the [metadata](Metadata) or the timer of a state
+const KNOWN_KEYS: [&str; 8] = [
+    "title",
+    "author",
+    "copyright",
+    "notes",
+    "theme_note",
+    "date",
+    "version",
+    "timer",
+];
+
+/// Text of the properties of the metadata, with one `key: value` line for every property that is set
+///
+/// Strings are [quoted](quoted) and the [extra](Metadata::extra) properties are written after the known ones, such that the text can be read back with [`metadata_with_timer`](crate::text::read::metadata_with_timer).
+/// Extra properties whose key is not an identifier or is the key of a known property cannot be read back, so they are left out.
+pub fn metadata_text(meta: &Metadata) -> String {
+    let mut text = String::new();
+    let fields = [
+        ("title", meta.title()),
+        ("author", meta.author()),
+        ("copyright", meta.copyright()),
+        ("notes", meta.notes()),
+        ("theme_note", meta.theme_note()),
+    ];
+
+    for (key, val) in fields {
+        if let Some(val) = val {
+            text += &format!("{key}: {}\n", quoted(val));
+        }
+    }
+    #[cfg(feature = "chrono")]
+    if let Some(date) = meta.date() {
+        text += &format!("date: {date}\n");
+    }
+    for (key, val) in meta.extra() {
+        if is_identifier(key) && !KNOWN_KEYS.contains(&key.as_str()) {
+            text += &format!("{key}: {}\n", quoted(val));
+        }
+    }
+    if let Some(version) = meta.version() {
+        text += &format!("version: {version}\n");
+    }
+
+    text
+}
+
+/// Quote the text, escaping its quotes and backslashes such that it can be read back with [`escaped_string`](crate::text::read::escaped_string)
+pub fn quoted(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+
+    for ch in text.chars() {
+        if matches!(ch, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(ch);
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Whether the key can be read as the key of an extra property, i.e. it starts with a letter or underscore followed by letters, digits and underscores
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    chars
+        .next()
+        .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::Parser;
+
+    use super::*;
+    use crate::text::read::metadata_with_timer;
+
+    #[test]
+    fn metadata_round_trip() {
+        let meta = Metadata::default()
+            .with_title(r#"The "Big" \ Small"#.to_string())
+            .with_extra("editor".to_string(), "Will \"W\" Shortz".to_string());
+
+        let text = metadata_text(&meta);
+        let (read, _) = metadata_with_timer().parse(&text).unwrap();
+
+        assert_eq!(read, meta);
+    }
+
+    #[test]
+    fn unreadable_extra_keys() {
+        let meta = Metadata::default()
+            .with_title("Mini".to_string())
+            .with_extra("title".to_string(), "Other".to_string())
+            .with_extra("two words".to_string(), "Left out".to_string())
+            .with_extra("editor".to_string(), "Kept".to_string());
+
+        assert_eq!(metadata_text(&meta), "title: \"Mini\"\neditor: \"Kept\"\n");
+    }
+}
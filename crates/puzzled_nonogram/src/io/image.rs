@@ -1,5 +1,5 @@
 use image::{DynamicImage, Pixel, Rgba};
-use puzzled_core::{Cell, Color, Metadata};
+use puzzled_core::{Cell, Color, ColorId, Metadata, Palette};
 use puzzled_io::{
     Context, ImagePuzzle, ImageReader, format,
     image::{
@@ -29,7 +29,8 @@ impl ImagePuzzle<NonogramState> for Nonogram {
         image: &DynamicImage,
         reader: &ImageReader,
     ) -> read::Result<(Self, NonogramState)> {
-        let mut colors = Colors::default();
+        let mut palette = Palette::default();
+        let tolerance = reader.color_tolerance();
 
         let mut read_pixel = |rgba: Rgba<u8>| {
             let [r, g, b, a] = rgba.0;
@@ -39,22 +40,20 @@ impl ImagePuzzle<NonogramState> for Nonogram {
                 // Ignore fully filled/empty pixels
                 [0, 0, 0] | [255, 255, 255] => Fill::Blank,
 
-                _ => {
-                    let idx = colors
-                        .values()
-                        .position(|&col| col == color)
-                        .unwrap_or(colors.len()) as u32;
-                    let fill = Fill::Color(idx);
-
-                    colors.insert(fill, color);
-                    fill
-                }
+                // Similar colors share the first color of the palette that they are similar to
+                _ => Fill::Color(palette.insert_similar(color, tolerance) as ColorId),
             };
 
             Ok(Cell::new(Some(fill)))
         };
 
         let fills = reader.read_grid(image, &mut read_pixel)?;
+        let colors = palette
+            .iter()
+            .enumerate()
+            .map(|(idx, &color)| (Fill::Color(idx as ColorId), color))
+            .collect();
+        let colors = Colors(colors);
         let metadata = Metadata::default();
 
         let nonogram = Nonogram::new(fills, colors, metadata);
@@ -83,3 +82,28 @@ impl ImagePuzzle<NonogramState> for Nonogram {
         write_grid_image(state.solutions(), &mut write_fill)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use image::{DynamicImage, Rgba, RgbaImage};
+    use puzzled_io::ImageReader;
+    use rstest::rstest;
+
+    use crate::{Nonogram, NonogramState};
+
+    #[rstest]
+    #[case::exact(0, 3)]
+    #[case::similar(4, 2)]
+    #[case::all(u8::MAX, 1)]
+    fn color_tolerance(#[case] tolerance: u8, #[case] expected: usize) {
+        let pixels = [[200, 0, 0, 255], [203, 2, 0, 255], [0, 0, 200, 255]];
+        let image = RgbaImage::from_fn(3, 1, |x, _| Rgba(pixels[x as usize]));
+
+        let reader = ImageReader::default().with_color_tolerance(tolerance);
+        let (nonogram, _): (Nonogram, NonogramState) = reader
+            .read(&DynamicImage::ImageRgba8(image))
+            .expect("image is read correctly");
+
+        assert_eq!(nonogram.colors().len(), expected);
+    }
+}
@@ -3,6 +3,9 @@ mod text;
 #[cfg(feature = "puz")]
 mod puz;
 
+#[cfg(feature = "nono")]
+mod nono;
+
 #[cfg(feature = "image")]
 pub mod image;
 
@@ -33,11 +36,20 @@ where
             Ok(nonogram)
         }
 
+        #[cfg(feature = "nono")]
+        "nono" => {
+            use puzzled_io::NonoReader;
+
+            let reader = NonoReader::default();
+            let (nonogram, _) = reader.read_from_path(path)?;
+            Ok(nonogram)
+        }
+
         #[cfg(feature = "image")]
         "png" | "jpg" | "jpeg" => {
             use puzzled_io::ImageReader;
 
-            let reader = ImageReader;
+            let reader = ImageReader::default();
             let (nonogram, _) = reader.read_from_path(path)?;
             Ok(nonogram)
         }
@@ -0,0 +1,305 @@
+use std::collections::BTreeMap;
+
+use puzzled_core::{Cell, Color, Entry, Grid, Line};
+use puzzled_io::{
+    format,
+    nono::{
+        BLANK, CROSS, NonoColor, NonoData, NonoPuzzle, NonoRun, NonoState,
+        read::{self, ErrorKind},
+        write,
+    },
+};
+
+use crate::{Colors, Fill, Nonogram, NonogramState, Rule, Rules, Run};
+
+impl NonoPuzzle<NonogramState> for Nonogram {
+    fn read_nono(data: NonoData) -> read::Result<(Self, NonogramState)> {
+        let rows = data.height as usize;
+        let cols = data.width as usize;
+
+        // The reader verified that every palette index is in bounds
+        let color = |idx: u8| Fill::Color(data.palette[idx as usize].id);
+
+        let fills = match &data.solution {
+            Some(solution) => solution.map_ref(|&byte| {
+                let fill = match byte {
+                    CROSS => Fill::Cross,
+                    byte => color(byte - 1),
+                };
+
+                Cell::new(Some(fill))
+            }),
+            None => Grid::new(rows, cols).map_err(format_err)?,
+        };
+
+        let colors = data
+            .palette
+            .iter()
+            .map(|color| (Fill::Color(color.id), color.color))
+            .collect();
+
+        // Rules are stored separately from the solution, such that puzzles without one can still be solved
+        let mut rules = BTreeMap::new();
+        let lines = data
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(r, rule)| (Line::Row(r), rule, cols));
+        let lines = lines.chain(
+            data.cols
+                .iter()
+                .enumerate()
+                .map(|(c, rule)| (Line::Col(c), rule, rows)),
+        );
+
+        for (line, runs, line_len) in lines {
+            let runs = runs
+                .iter()
+                .map(|run| Run::new(color(run.color), run.len as usize))
+                .collect();
+
+            rules.insert(line, Rule::new(runs, line_len));
+        }
+
+        let mut nonogram = Nonogram::new(fills, Colors::new(colors), data.meta);
+        *nonogram.rules_mut() = Rules::new(rules, rows, cols).map_err(format_err)?;
+
+        let state = match data.state {
+            Some(NonoState { entries, timer }) => {
+                let entries = entries.map_ref(|&byte| match byte {
+                    BLANK => Entry::new(None),
+                    CROSS => Entry::new(Some(Fill::Cross)),
+                    byte => Entry::new(Some(color(byte - 2))),
+                });
+                let solutions = nonogram.fills().map_ref(|cell| cell.solution);
+
+                NonogramState::new(solutions, entries, timer)
+            }
+            None => NonogramState::from(&nonogram),
+        };
+
+        Ok((nonogram, state))
+    }
+
+    fn nono_data(&self, state: &NonogramState) -> write::Result<NonoData> {
+        let width = check_len("Width", self.cols())?;
+        let height = check_len("Height", self.rows())?;
+
+        // Every color that is used by the puzzle needs to be in the palette, even if it is not styled
+        let mut palette: BTreeMap<Fill, Color> = self
+            .colors()
+            .iter()
+            .filter(|(fill, _)| fill.is_color())
+            .map(|(fill, color)| (*fill, *color))
+            .collect();
+
+        let used = (self.fills().iter())
+            .filter_map(|cell| cell.solution)
+            .chain(
+                state
+                    .entries()
+                    .iter()
+                    .filter_map(|entry| entry.entry().copied()),
+            );
+
+        for fill in used.filter(Fill::is_color) {
+            palette.entry(fill).or_insert(Color::rgb(0, 0, 0));
+        }
+
+        // Entries offset palette indices by 2, which should still fit in a byte
+        let max = u8::MAX as usize - 2;
+        if palette.len() > max {
+            let kind = write::ErrorKind::TooLarge {
+                size: palette.len(),
+                max,
+            };
+            return Err(write::Error::new("Palette", kind));
+        }
+
+        let index = |fill: Fill| {
+            palette
+                .keys()
+                .position(|&color| color == fill)
+                .unwrap_or_default() as u8
+        };
+
+        let empty = Rule::default();
+        let rules = |line: Line| -> write::Result<Vec<NonoRun>> {
+            let rule = self.rules().get(&line).unwrap_or(&empty);
+
+            rule.runs()
+                .iter()
+                .map(|run| {
+                    Ok(NonoRun {
+                        color: index(run.fill),
+                        len: check_len("Rules", run.count)?,
+                    })
+                })
+                .collect()
+        };
+
+        let rows = (0..self.rows())
+            .map(|r| rules(Line::Row(r)))
+            .collect::<write::Result<_>>()?;
+        let cols = (0..self.cols())
+            .map(|c| rules(Line::Col(c)))
+            .collect::<write::Result<_>>()?;
+
+        // The solution is only stored if it is known for every cell, where omitted cells are crossed out as they can never be filled
+        let solution = self
+            .fills()
+            .map_unmasked(|_, cell| cell.map_or(Some(Fill::Cross), |cell| cell.solution));
+        let is_known = solution.iter().all(Option::is_some);
+        let solution = is_known.then(|| {
+            solution.map(|fill| match fill {
+                Some(fill @ Fill::Color(_)) => index(fill) + 1,
+                _ => CROSS,
+            })
+        });
+
+        let entries = state
+            .entries()
+            .map_unmasked(|_, entry| match entry.map(Entry::entry) {
+                Some(Some(&fill @ Fill::Color(_))) => index(fill) + 2,
+                Some(Some(Fill::Cross)) | None => CROSS,
+                Some(Some(Fill::Blank) | None) => BLANK,
+            });
+
+        let palette = palette
+            .into_iter()
+            .filter_map(|(fill, color)| match fill {
+                Fill::Color(id) => Some(NonoColor { id, color }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(NonoData {
+            width,
+            height,
+            palette,
+            rows,
+            cols,
+            solution,
+            state: Some(NonoState {
+                entries,
+                timer: state.state.timer.clone(),
+            }),
+            meta: self.meta().clone(),
+        })
+    }
+}
+
+fn check_len(context: &str, len: usize) -> write::Result<u16> {
+    u16::try_from(len).map_err(|_| {
+        let kind = write::ErrorKind::TooLarge {
+            size: len,
+            max: u16::MAX as usize,
+        };
+        write::Error::new(context, kind)
+    })
+}
+
+fn format_err<E>(err: E) -> read::Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let err = format::Error::PuzzleSpecific(Box::new(err));
+    read::Error::new("Nonogram", ErrorKind::Format(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::{BitGrid, Position};
+    use puzzled_io::{NonoReader, NonoWriter};
+
+    use super::*;
+    use crate::nonogram;
+
+    #[test]
+    fn round_trip() {
+        let puzzle = nonogram!(
+            [1 x 2]
+            [x 2 1]
+            - 1: "#C81414"
+            - 2: "#1414C8"
+        );
+        let mut state = NonogramState::from(&puzzle);
+        state.state.entries[Position::new(0, 0)] = Entry::new(Some(Fill::Color('1' as u32)));
+        state.state.entries[Position::new(1, 0)] = Entry::new(Some(Fill::Cross));
+
+        let mut bytes = Vec::new();
+        NonoWriter::new()
+            .write(&mut bytes, &puzzle, &state)
+            .unwrap();
+
+        let (read, read_state): (Nonogram, NonogramState) =
+            NonoReader::new(true).read(&mut &bytes[..]).unwrap();
+
+        assert_eq!(read.rules(), puzzle.rules());
+        let colors = |puzzle: &Nonogram| -> Vec<_> {
+            (puzzle.colors().iter())
+                .filter(|(fill, _)| fill.is_color())
+                .map(|(fill, color)| (*fill, *color))
+                .collect()
+        };
+        assert_eq!(colors(&read), colors(&puzzle));
+        assert_eq!(read.fills(), puzzle.fills());
+        assert_eq!(read_state.entries(), state.entries());
+    }
+
+    #[test]
+    fn without_solution() {
+        let mut puzzle = nonogram!(
+            [1 x]
+            [x 1]
+        );
+        puzzle.fills_mut()[Position::new(0, 1)].solution = None;
+        let state = NonogramState::from(&puzzle);
+
+        let mut bytes = Vec::new();
+        NonoWriter::new()
+            .write(&mut bytes, &puzzle, &state)
+            .unwrap();
+
+        let (read, _): (Nonogram, NonogramState) =
+            NonoReader::new(true).read(&mut &bytes[..]).unwrap();
+
+        assert_eq!(read.rules(), puzzle.rules());
+        assert!(read.fills().iter().all(|cell| cell.solution.is_none()));
+    }
+
+    #[test]
+    fn masked() {
+        let puzzle = nonogram!(
+            [1 1]
+            [x 1]
+        );
+        let mask = BitGrid::from_fn(2, 2, |pos| pos != Position::new(1, 0)).unwrap();
+        let fills = puzzle.fills().clone().with_mask(mask).unwrap();
+        let puzzle = Nonogram::new(fills, puzzle.colors().clone(), puzzle.meta().clone());
+        let state = NonogramState::from(&puzzle);
+
+        let mut bytes = Vec::new();
+        NonoWriter::new()
+            .write(&mut bytes, &puzzle, &state)
+            .unwrap();
+
+        let (read, _): (Nonogram, NonogramState) =
+            NonoReader::new(true).read(&mut &bytes[..]).unwrap();
+
+        // Omitted cells cannot be represented, so they are written as crossed out
+        for line in [Line::Row(0), Line::Row(1), Line::Col(0), Line::Col(1)] {
+            let runs =
+                |puzzle: &Nonogram| puzzle.rules().get(&line).map(|rule| rule.runs().to_vec());
+            assert_eq!(runs(&read), runs(&puzzle));
+        }
+        assert_eq!(
+            read.fills()[Position::new(1, 0)].solution,
+            Some(Fill::Cross)
+        );
+        assert_eq!(
+            read.fills()[Position::new(1, 1)],
+            puzzle.fills()[Position::new(1, 1)]
+        );
+    }
+}
@@ -50,12 +50,12 @@ impl BinaryPuzzle<NonogramState> for Nonogram {
         self.fills().rows()
     }
 
-    fn clues(&self) -> Vec<ByteStr> {
+    fn clues(&self) -> Vec<String> {
+        // Only fill colors are stored, in the same order as they are read back
         self.colors()
-            .values()
-            .map(|color| format!("{color:?}"))
-            .chain(std::iter::empty())
-            .map(|str| ByteStr::new(str.as_bytes()))
+            .iter()
+            .filter(|(fill, _)| fill.is_color())
+            .map(|(_, color)| color.to_hex())
             .collect()
     }
 
@@ -157,3 +157,42 @@ fn read_colors(fills: &impl Fills, strings: &Strings) -> read::Result<Colors> {
 
     Ok(colors)
 }
+
+#[cfg(test)]
+mod tests {
+    use puzzled_io::{PuzReader, PuzWriter};
+
+    use super::*;
+    use crate::nonogram;
+
+    #[test]
+    fn colors_round_trip() {
+        let puzzle = nonogram!(
+            [1 x 2]
+            [x 2 1]
+            - 1: "#C81414"
+            - 2: "#1414C8"
+        );
+        let state = NonogramState::from(&puzzle);
+
+        let mut bytes = Vec::new();
+        PuzWriter::new().write(&mut bytes, &puzzle, &state).unwrap();
+
+        let (read, _): (Nonogram, NonogramState) =
+            PuzReader::default().read(&mut &bytes[..]).unwrap();
+
+        let colors: Vec<_> = read
+            .colors()
+            .iter()
+            .filter(|(fill, _)| fill.is_color())
+            .collect();
+        let expected: Vec<_> = puzzle
+            .colors()
+            .iter()
+            .filter(|(fill, _)| fill.is_color())
+            .collect();
+
+        assert_eq!(colors, expected);
+        assert_eq!(read.rules(), puzzle.rules());
+    }
+}
@@ -38,8 +38,8 @@ where
             })
             .collect();
 
-        ids.dedup();
         ids.sort();
+        ids.dedup();
 
         ids
     }
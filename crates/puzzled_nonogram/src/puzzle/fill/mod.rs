@@ -230,3 +230,39 @@ mod serde_impl {
         }
     }
 }
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::{
+        arbitrary::Arbitrary,
+        prop_oneof,
+        strategy::{BoxedStrategy, Just, Strategy},
+    };
+
+    use crate::{ColorId, Fill};
+
+    impl Fill {
+        /// Strategy that generates [crosses](Fill::Cross) and up to the given number of [colors](Fill::Color), i.e. the fills of a solved cell
+        ///
+        /// At least a single color is always generated
+        #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+        pub fn solved_strategy(colors: ColorId) -> impl Strategy<Value = Self> + Clone {
+            prop_oneof![Just(Fill::Cross), (1..=colors.max(1)).prop_map(Fill::Color),]
+        }
+    }
+
+    /// The parameters are the number of colors to generate, where `0` generates a single color like [`any::<Fill>()`](proptest::prelude::any)
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl Arbitrary for Fill {
+        type Parameters = ColorId;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(colors: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                1 => Just(Fill::Blank),
+                4 => Fill::solved_strategy(colors),
+            ]
+            .boxed()
+        }
+    }
+}
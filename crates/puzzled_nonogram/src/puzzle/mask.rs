@@ -0,0 +1,287 @@
+use std::{fmt, ops::Range};
+
+use bitvec::{bitvec, vec::BitVec};
+
+use crate::Fill;
+
+/// Mask over the cells of a single line, where every set bit marks a cell that has some property
+///
+/// Masks are typically used to track which cells have (or may have) a certain [fill](Fill), so that the overlap between lines and rules can be computed with bit operations.
+/// ```
+/// use puzzled::nonogram::{Fill, LineMask};
+///
+/// let c = Fill::Color(1);
+/// let fills = [c, c, Fill::Cross, Fill::Blank, c];
+///
+/// let colored = LineMask::from_fills(&fills, c);
+/// let crossed = LineMask::from_fills(&fills, Fill::Cross);
+/// let unknown = !(&colored | &crossed);
+///
+/// assert_eq!(unknown.iter_ones().collect::<Vec<_>>(), vec![3]);
+/// assert_eq!(colored.leading_ones(), 2);
+/// assert_eq!(colored.runs().collect::<Vec<_>>(), vec![0..2, 4..5]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct LineMask(BitVec);
+
+impl LineMask {
+    /// Mask of the given length without any cells set
+    pub fn new(len: usize) -> Self {
+        Self(bitvec![0; len])
+    }
+
+    /// Mask of the given length with all cells set
+    pub fn full(len: usize) -> Self {
+        Self(bitvec![1; len])
+    }
+
+    /// Mask of the cells that have the given fill
+    pub fn from_fills(fills: &[Fill], fill: Fill) -> Self {
+        fills.iter().map(|&other| other == fill).collect()
+    }
+
+    /// Line in which the set cells have the given fill and all other cells are [blank](Fill::Blank)
+    pub fn to_fills(&self, fill: Fill) -> Vec<Fill> {
+        self.0
+            .iter()
+            .map(|bit| if *bit { fill } else { Fill::Blank })
+            .collect()
+    }
+
+    /// Number of cells in the line
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether the cell at the given index is set, or [`None`] if it lies outside of the line
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        self.0.get(idx).map(|bit| *bit)
+    }
+
+    /// Set or unset the cell at the given index
+    ///
+    /// # Panics
+    /// Panics if the index lies outside of the line
+    pub fn set(&mut self, idx: usize, value: bool) {
+        self.0.set(idx, value);
+    }
+
+    /// Number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.0.count_ones()
+    }
+
+    /// Whether any of the cells is set
+    pub fn any(&self) -> bool {
+        self.0.any()
+    }
+
+    /// Whether none of the cells are set
+    pub fn none(&self) -> bool {
+        self.0.not_any()
+    }
+
+    /// Iterate over the indices of the set cells
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter_ones()
+    }
+
+    /// Cells that are set in either mask
+    pub fn union(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    /// Cells that are set in both masks
+    pub fn intersection(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    /// Cells that are not set in the mask
+    pub fn negation(&self) -> Self {
+        !self
+    }
+
+    /// Whether every set cell is also set in the other mask
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.iter_ones()
+            .all(|idx| other.get(idx).is_some_and(|bit| bit))
+    }
+
+    /// Number of set cells at the start of the line
+    pub fn leading_ones(&self) -> usize {
+        self.0.leading_ones()
+    }
+
+    /// Number of set cells at the end of the line
+    pub fn trailing_ones(&self) -> usize {
+        self.0.trailing_ones()
+    }
+
+    /// Number of unset cells at the start of the line
+    pub fn leading_zeros(&self) -> usize {
+        self.0.leading_zeros()
+    }
+
+    /// Number of unset cells at the end of the line
+    pub fn trailing_zeros(&self) -> usize {
+        self.0.trailing_zeros()
+    }
+
+    /// Iterate over the ranges of consecutive set cells
+    pub fn runs(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let mut idx = 0;
+
+        std::iter::from_fn(move || {
+            let rest = &self.0[idx..];
+            let start = idx + rest.first_one()?;
+            let len = self.0[start..].leading_ones();
+
+            idx = start + len;
+            Some(start..idx)
+        })
+    }
+
+    /// Retrieve the underlying bits of the mask
+    pub fn bits(&self) -> &BitVec {
+        &self.0
+    }
+}
+
+impl From<BitVec> for LineMask {
+    fn from(bits: BitVec) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<&[Fill]> for LineMask {
+    /// Mask of the cells that are colored
+    fn from(fills: &[Fill]) -> Self {
+        fills
+            .iter()
+            .map(|fill| matches!(fill, Fill::Color(_)))
+            .collect()
+    }
+}
+
+impl FromIterator<bool> for LineMask {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl fmt::Display for LineMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.0.iter() {
+            write!(f, "{}", u8::from(*bit))?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl std::ops::$op<&LineMask> for &LineMask {
+            type Output = LineMask;
+
+            fn $fn(self, rhs: &LineMask) -> LineMask {
+                let mut mask = self.clone();
+                std::ops::$op_assign::$fn_assign(&mut mask.0, &rhs.0);
+
+                mask
+            }
+        }
+
+        impl std::ops::$op for LineMask {
+            type Output = LineMask;
+
+            fn $fn(self, rhs: LineMask) -> LineMask {
+                std::ops::$op::$fn(&self, &rhs)
+            }
+        }
+
+        impl std::ops::$op_assign<&LineMask> for LineMask {
+            fn $fn_assign(&mut self, rhs: &LineMask) {
+                std::ops::$op_assign::$fn_assign(&mut self.0, &rhs.0);
+            }
+        }
+
+        impl std::ops::$op_assign for LineMask {
+            fn $fn_assign(&mut self, rhs: LineMask) {
+                std::ops::$op_assign::$fn_assign(&mut self.0, &rhs.0);
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl std::ops::Not for &LineMask {
+    type Output = LineMask;
+
+    fn not(self) -> LineMask {
+        LineMask(!self.0.clone())
+    }
+}
+
+impl std::ops::Not for LineMask {
+    type Output = LineMask;
+
+    fn not(self) -> LineMask {
+        LineMask(!self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn mask(bits: &str) -> LineMask {
+        bits.chars().map(|ch| ch == '1').collect()
+    }
+
+    #[rstest]
+    #[case::empty("", vec![])]
+    #[case::none("000", vec![])]
+    #[case::full("111", vec![0..3])]
+    #[case::edges("1001", vec![0..1, 3..4])]
+    #[case::middle("01101110", vec![1..3, 4..7])]
+    fn runs(#[case] bits: &str, #[case] expected: Vec<Range<usize>>) {
+        assert_eq!(mask(bits).runs().collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case("1100", "1010")]
+    #[case("0000", "1111")]
+    #[case("1011", "0110")]
+    fn operations(#[case] lhs: &str, #[case] rhs: &str) {
+        let (lhs, rhs) = (mask(lhs), mask(rhs));
+
+        let union = lhs.union(&rhs);
+        let intersection = lhs.intersection(&rhs);
+
+        assert!(lhs.is_subset(&union) && rhs.is_subset(&union));
+        assert!(intersection.is_subset(&lhs) && intersection.is_subset(&rhs));
+        assert!((&lhs & &lhs.negation()).none());
+        assert_eq!(!union, &lhs.negation() & &rhs.negation());
+    }
+
+    #[test]
+    fn fills_round_trip() {
+        let c = Fill::Color(1);
+        let fills = [c, Fill::Blank, c, c];
+
+        let mask = LineMask::from_fills(&fills, c);
+        assert_eq!(mask, LineMask::from(fills.as_slice()));
+        assert_eq!(mask.to_fills(c), fills);
+        assert_eq!((mask.leading_ones(), mask.trailing_ones()), (1, 2));
+    }
+}
@@ -2,20 +2,26 @@ mod cell;
 mod colors;
 mod fill;
 mod find;
+mod mask;
 mod rule;
 mod run;
+mod shaped;
+mod topology;
 
 use std::fmt;
 
 use derive_more::{Index, IndexMut};
-use puzzled_core::{Cell, Grid, Metadata, Position, Puzzle};
+use puzzled_core::{Cell, Grid, Line, Metadata, Position, Puzzle};
 
 pub use cell::*;
 pub use colors::*;
 pub use fill::*;
 pub use find::*;
+pub use mask::*;
 pub use rule::*;
 pub use run::*;
+pub use shaped::*;
+pub use topology::*;
 
 #[derive(Debug, Index, IndexMut)]
 pub struct Nonogram {
@@ -97,9 +103,162 @@ impl Nonogram {
         self.fills.rows()
     }
 
+    /// [Topology](Topology) of the rows and columns of the grid
+    pub fn topology(&self) -> SquareTopology {
+        SquareTopology::new(self.rows(), self.cols())
+    }
+
     pub fn meta(&self) -> &Metadata {
         &self.meta
     }
+
+    pub fn meta_mut(&mut self) -> &mut Metadata {
+        &mut self.meta
+    }
+
+    /// Whether the colored runs of a line exactly match its rule, where a line without a rule is never solved
+    pub fn is_line_solved(&self, line: Line) -> bool {
+        let Some(rule) = self.rules.get(&line) else {
+            return false;
+        };
+
+        let fills = self
+            .fills
+            .iter_line(line)
+            .map(|cell| cell.solution.unwrap_or_default());
+
+        rule.is_satisfied_by(fills)
+    }
+
+    /// Whether every [line is solved](Self::is_line_solved)
+    /// ```
+    /// use puzzled::nonogram::{nonogram, Fill, Position};
+    ///
+    /// let mut puzzle = nonogram!(
+    ///     [1 x]
+    ///     [x 1]
+    /// );
+    /// assert!(puzzle.is_solved());
+    ///
+    /// puzzle[Position::new(0, 0)].solution = Some(Fill::Cross);
+    /// assert!(!puzzle.is_solved());
+    /// ```
+    pub fn is_solved(&self) -> bool {
+        let rows = (0..self.rows()).map(Line::Row);
+        let cols = (0..self.cols()).map(Line::Col);
+
+        rows.chain(cols).all(|line| self.is_line_solved(line))
+    }
+
+    /// Cross out the empty cells of a line once it [is solved](Self::is_line_solved)
+    ///
+    /// Returns the positions of the crossed out cells, which is empty if the line is not solved (yet)
+    /// ```
+    /// use puzzled::nonogram::{nonogram, Fill, Line, Position};
+    ///
+    /// let mut puzzle = nonogram!(
+    ///     [1 1 x 1]
+    ///     [x x x x]
+    /// );
+    /// puzzle[Position::new(0, 2)].solution = Some(Fill::Blank);
+    ///
+    /// assert_eq!(puzzle.auto_cross_line(Line::Row(0)), vec![Position::new(0, 2)]);
+    /// assert_eq!(puzzle[Position::new(0, 2)].solution, Some(Fill::Cross));
+    ///
+    /// // The last column misses its colored cell
+    /// puzzle[Position::new(0, 3)].solution = None;
+    /// assert!(puzzle.auto_cross_line(Line::Col(3)).is_empty());
+    /// ```
+    pub fn auto_cross_line(&mut self, line: Line) -> Vec<Position> {
+        if !self.is_line_solved(line) {
+            return Vec::new();
+        }
+
+        let mut crossed = Vec::new();
+
+        for (pos, cell) in self.fills.iter_indexed_line_mut(line) {
+            if matches!(cell.solution, None | Some(Fill::Blank)) {
+                cell.solution = Some(Fill::Cross);
+                crossed.push(pos);
+            }
+        }
+
+        crossed
+    }
+
+    /// [Transpose](Grid::transpose) the puzzle, such that its row rules become column rules and vice versa
+    /// ```
+    /// use puzzled::nonogram::{nonogram, Line};
+    ///
+    /// let puzzle = nonogram!(
+    ///     [1 1 1]
+    ///     [- - 1]
+    /// );
+    /// let rules = puzzle.rules().clone();
+    ///
+    /// let puzzle = puzzle.transpose();
+    /// assert_eq!((puzzle.rows(), puzzle.cols()), (3, 2));
+    /// assert_eq!(puzzle.rules()[&Line::Col(0)], rules[&Line::Row(0)]);
+    /// assert_eq!(puzzle.rules()[&Line::Row(2)], rules[&Line::Col(2)]);
+    /// ```
+    pub fn transpose(self) -> Self {
+        self.transform(Grid::transpose, |line, _, _| match line {
+            Line::Row(row) => (Line::Col(row), false),
+            Line::Col(col) => (Line::Row(col), false),
+        })
+    }
+
+    /// [Rotate](Grid::rotate_cw) the puzzle a quarter turn clockwise
+    pub fn rotate_cw(self) -> Self {
+        self.transform(Grid::rotate_cw, |line, rows, _| match line {
+            Line::Row(row) => (Line::Col(rows - 1 - row), false),
+            Line::Col(col) => (Line::Row(col), true),
+        })
+    }
+
+    /// [Rotate](Grid::rotate_ccw) the puzzle a quarter turn counterclockwise
+    pub fn rotate_ccw(self) -> Self {
+        self.transform(Grid::rotate_ccw, |line, _, cols| match line {
+            Line::Row(row) => (Line::Col(row), true),
+            Line::Col(col) => (Line::Row(cols - 1 - col), false),
+        })
+    }
+
+    /// [Mirror](Grid::flip_horizontal) the puzzle horizontally
+    pub fn flip_horizontal(self) -> Self {
+        self.transform(Grid::flip_horizontal, |line, _, cols| match line {
+            Line::Row(row) => (Line::Row(row), true),
+            Line::Col(col) => (Line::Col(cols - 1 - col), false),
+        })
+    }
+
+    /// [Mirror](Grid::flip_vertical) the puzzle vertically
+    pub fn flip_vertical(self) -> Self {
+        self.transform(Grid::flip_vertical, |line, rows, _| match line {
+            Line::Row(row) => (Line::Row(rows - 1 - row), false),
+            Line::Col(col) => (Line::Col(col), true),
+        })
+    }
+
+    /// Transform the fills with `transform` and move the rules along, where `target` finds the new line for an old line given the old number of rows and columns
+    fn transform<F, G>(self, transform: F, target: G) -> Self
+    where
+        F: FnOnce(Grid<Cell<Fill>>) -> Grid<Cell<Fill>>,
+        G: Fn(Line, usize, usize) -> (Line, bool),
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let fills = transform(self.fills);
+        let rules = self
+            .rules
+            .remap(fills.rows(), fills.cols(), |line| target(line, rows, cols));
+
+        Self {
+            fills,
+            rules,
+            colors: self.colors,
+            meta: self.meta,
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -174,8 +333,28 @@ mod serde_impl {
 #[cfg(test)]
 mod tests {
     use puzzled_core::{CellStyle, Position};
+    use rstest::rstest;
+
+    use crate::{Nonogram, Rules, nonogram};
+
+    #[rstest]
+    #[case::transpose(Nonogram::transpose)]
+    #[case::rotate_cw(Nonogram::rotate_cw)]
+    #[case::rotate_ccw(Nonogram::rotate_ccw)]
+    #[case::flip_horizontal(Nonogram::flip_horizontal)]
+    #[case::flip_vertical(Nonogram::flip_vertical)]
+    fn transform(#[case] apply: fn(Nonogram) -> Nonogram) {
+        let puzzle = nonogram!(
+            [1 1 - 2]
+            [- 1 2 2]
+            [1 - - 1]
+        );
 
-    use crate::nonogram;
+        let puzzle = apply(puzzle);
+        let expected = Rules::from_fills(puzzle.fills());
+
+        assert_eq!(puzzle.rules(), &expected);
+    }
 
     #[test]
     fn nonogram() {
@@ -115,6 +115,21 @@ impl Rule {
     pub fn runs(&self) -> &Vec<Run> {
         &self.runs
     }
+
+    /// Whether the colored runs of the fills exactly match the runs of the rule
+    pub fn is_satisfied_by<I>(&self, fills: I) -> bool
+    where
+        I: IntoIterator<Item = Fill>,
+    {
+        Runs::new(fills.into_iter(), true).eq(self.runs.iter().copied())
+    }
+
+    /// Rule for the same line read in the opposite direction
+    pub fn reversed(&self) -> Self {
+        let runs = self.runs.iter().rev().copied().collect();
+
+        Self::new(runs, self.line_len)
+    }
 }
 
 #[cfg(feature = "serde")]
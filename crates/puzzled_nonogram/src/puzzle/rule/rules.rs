@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use derive_more::Deref;
-use puzzled_core::{Cell, Grid, Line};
+use puzzled_core::{Cell, Grid, Line, StableHasher};
 
 use crate::{Fill, Rule};
 
@@ -102,32 +102,186 @@ impl Rules {
         self.iter().filter(|(line, _)| line.is_col())
     }
 
-    #[cfg(feature = "serde")]
-    pub(crate) fn from_serde(data: SerdeRules, rows: usize, cols: usize) -> Self {
-        let rules = data
+    /// Stable hash of the size and runs of every line, which identifies the puzzle regardless of its colors, metadata and state
+    /// ```
+    /// use puzzled::nonogram::nonogram;
+    ///
+    /// let puzzle = nonogram!(
+    ///     [1 1 -]
+    ///     [- 1 1]
+    /// );
+    /// let other = nonogram!(
+    ///     [1 1 -]
+    ///     [- - 1]
+    /// );
+    ///
+    /// assert_eq!(puzzle.rules().fingerprint(), puzzle.rules().clone().fingerprint());
+    /// assert_ne!(puzzle.rules().fingerprint(), other.rules().fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        hasher.write_u64(self.rows as u64);
+        hasher.write_u64(self.cols as u64);
+
+        for (line, rule) in self.iter() {
+            let (kind, idx) = match line {
+                Line::Row(row) => (b'R', row),
+                Line::Col(col) => (b'C', col),
+            };
+            hasher.write_u8(kind);
+            hasher.write_u64(*idx as u64);
+            hasher.write_u64(rule.len() as u64);
+
+            for run in rule.runs() {
+                hasher.write_u64(run.fill.as_key() as u64);
+                hasher.write_u64(run.count as u64);
+            }
+        }
+
+        hasher.finish()
+    }
+
+    /// Move every rule onto a new line for a grid of the given size
+    ///
+    /// The `target` determines the new line of every old line and whether it should be read in reverse
+    pub(crate) fn remap<F>(self, rows: usize, cols: usize, target: F) -> Self
+    where
+        F: Fn(Line) -> (Line, bool),
+    {
+        let rules = self
+            .rules
             .into_iter()
-            .map(|(line, runs)| {
-                let line_len = match line {
-                    Line::Row(_) => cols,
-                    Line::Col(_) => rows,
-                };
-                let rule = Rule::new(runs, line_len);
-
-                (line, rule)
+            .map(|(line, rule)| match target(line) {
+                (line, true) => (line, rule.reversed()),
+                (line, false) => (line, rule),
             })
             .collect();
 
         Self { rules, rows, cols }
     }
 
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_serde(data: SerdeRules, rows: usize, cols: usize) -> Self {
+        let row_rules = data
+            .rows
+            .into_iter()
+            .enumerate()
+            .map(|(row, runs)| (Line::Row(row), Rule::new(runs, cols)));
+        let col_rules = data
+            .cols
+            .into_iter()
+            .enumerate()
+            .map(|(col, runs)| (Line::Col(col), Rule::new(runs, rows)));
+
+        let rules = row_rules.chain(col_rules).collect();
+
+        Self { rules, rows, cols }
+    }
+
     #[cfg(feature = "serde")]
     pub(crate) fn to_serde(&self) -> SerdeRules {
-        self.rules
-            .iter()
-            .map(|(line, rule)| (*line, rule.runs.clone()))
-            .collect()
+        let runs = |(_, rule): (&Line, &Rule)| rule.runs.clone();
+
+        SerdeRules {
+            rows: self.iter_rows().map(runs).collect(),
+            cols: self.iter_cols().map(runs).collect(),
+        }
     }
 }
 
+/// Rules are serialized as lists of row and column rules, as formats such as JSON only allow strings as keys
 #[cfg(feature = "serde")]
-pub(crate) type SerdeRules = BTreeMap<Line, crate::SerdeRule>;
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct SerdeRules {
+    rows: Vec<crate::SerdeRule>,
+    cols: Vec<crate::SerdeRule>,
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::{
+        arbitrary::Arbitrary,
+        collection::SizeRange,
+        strategy::{BoxedStrategy, Strategy},
+    };
+    use puzzled_core::{Cell, Grid};
+
+    use crate::{ColorId, Fill, Rules};
+
+    /// Largest number of rows and columns of [arbitrary](Arbitrary) rules
+    const MAX_ARBITRARY_LEN: usize = 8;
+
+    impl Rules {
+        /// Strategy that generates rules within the given number of rows and columns, using up to the given number of colors
+        ///
+        /// The rules are derived from a random solution, such that they always have at least one solution
+        /// ```
+        /// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+        /// use puzzled::nonogram::{NonogramSolver, Rules};
+        ///
+        /// let strategy = Rules::strategy(5, 5, 1);
+        /// let rules = strategy.new_tree(&mut TestRunner::default()).unwrap().current();
+        ///
+        /// let mut solver = NonogramSolver::default();
+        /// assert!(solver.difficulty(&rules).is_ok());
+        /// ```
+        #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+        pub fn strategy(
+            rows: impl Into<SizeRange>,
+            cols: impl Into<SizeRange>,
+            colors: ColorId,
+        ) -> impl Strategy<Value = Self> {
+            Grid::strategy(rows, cols, Fill::solved_strategy(colors)).prop_map(|solution| {
+                let fills = solution.map(|fill| Cell::new(Some(fill)));
+                Rules::from_fills(&fills)
+            })
+        }
+    }
+
+    /// The parameters are the number of colors to generate, where `0` generates a single color like [`any::<Rules>()`](proptest::prelude::any)
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    impl Arbitrary for Rules {
+        type Parameters = ColorId;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(colors: Self::Parameters) -> Self::Strategy {
+            let len = 1..=MAX_ARBITRARY_LEN;
+            Rules::strategy(len.clone(), len, colors).boxed()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+        use puzzled_core::{Cell, Grid};
+
+        use crate::{NonogramSolver, Rules};
+
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(64))]
+
+            #[test]
+            fn solvable(rules in any::<Rules>()) {
+                let mut solver = NonogramSolver::default();
+                prop_assert!(solver.difficulty(&rules).is_ok());
+            }
+
+            #[test]
+            fn sound(rules in any_with::<Rules>(2)) {
+                let mut solver = NonogramSolver::default();
+                let mut grid = Grid::new(rules.rows(), rules.cols()).unwrap();
+                prop_assert!(solver.search(&rules, &mut grid).is_some());
+
+                // Every rule should be satisfied by the found solution, including lines that guesses completed
+                let solution = grid.map(Cell::new);
+                prop_assert_eq!(Rules::from_fills(&solution), rules);
+            }
+
+            #[test]
+            fn sizes(rules in any_with::<Rules>(3)) {
+                prop_assert_eq!(rules.iter_rows().count(), rules.rows());
+                prop_assert_eq!(rules.iter_cols().count(), rules.cols());
+            }
+        }
+    }
+}
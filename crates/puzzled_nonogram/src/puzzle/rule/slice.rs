@@ -1,6 +1,6 @@
 use std::ops::{Bound, Index, RangeBounds};
 
-use crate::{Rule, Run};
+use crate::{Fill, LineMask, Rule, Run};
 
 pub struct RunsSlice<'a> {
     runs: &'a [Run],
@@ -52,6 +52,101 @@ impl Rule {
     }
 }
 
+impl Rule {
+    /// Split the rule before the run at `run_idx`, such that the first rule holds the runs before it and the second rule the others
+    ///
+    /// The runs of the first rule are packed at the start of the line, including the gap they need before the runs of the second rule.
+    /// The second rule gets the remaining cells of the line, such that [merging](Self::merge) the rules gives back the original rule.
+    /// ```
+    /// use puzzled::nonogram::{Fill, Rule, Run};
+    ///
+    /// let c = Fill::Color(1);
+    /// let rule = Rule::new(vec![Run::new(c, 2), Run::new(c, 1), Run::new(c, 3)], 10);
+    ///
+    /// let (start, end) = rule.split_at(1);
+    /// assert_eq!((start.runs().len(), start.line_len()), (1, 3));
+    /// assert_eq!((end.runs().len(), end.line_len()), (2, 7));
+    ///
+    /// assert_eq!(start.merge(&end), rule);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `run_idx` is larger than the number of runs
+    pub fn split_at(&self, run_idx: usize) -> (Rule, Rule) {
+        let (start, end) = self.runs.split_at(run_idx);
+
+        // Runs of the same fill cannot touch, so the first rule should end with a gap
+        let gap = match (start.last(), end.first()) {
+            (Some(prev), Some(next)) if prev.fill == next.fill => 1,
+            _ => 0,
+        };
+
+        let mut start = Rule::new(start.to_vec(), 0);
+        start.line_len = (start.len() + gap).min(self.line_len);
+
+        let end = Rule::new(end.to_vec(), self.line_len - start.line_len);
+
+        (start, end)
+    }
+
+    /// Rule for the line that continues this rule's line with the other rule's line
+    ///
+    /// If the runs at the seam have the same fill, they should be separated by a gap which is not added to the line.
+    pub fn merge(&self, other: &Rule) -> Rule {
+        let runs = self.runs.iter().chain(&other.runs).copied().collect();
+
+        Rule::new(runs, self.line_len + other.line_len)
+    }
+
+    /// Remove the runs of a settled prefix of the line, giving the rule for the remaining cells
+    ///
+    /// The prefix marks the cells at the start of the line that have the given fill, where all other cells of the prefix are crossed out.
+    /// As the prefix is settled, a run that ends at its last cell is complete.
+    /// If the next run has the same fill, the cell after the prefix should then be crossed out and is not part of the remaining line.
+    /// Returns [`None`] if the runs of the prefix do not match the first runs of the rule, or the remaining runs do not fit after it.
+    /// ```
+    /// use puzzled::nonogram::{Fill, LineMask, Rule, Run};
+    ///
+    /// let c = Fill::Color(1);
+    /// let rule = Rule::new(vec![Run::new(c, 2), Run::new(c, 1), Run::new(c, 3)], 10);
+    ///
+    /// let prefix = LineMask::from_fills(&[c, c, Fill::Cross, Fill::Cross], c);
+    /// let rest = rule.trim_satisfied(&prefix, c).unwrap();
+    /// assert_eq!(rest, Rule::new(vec![Run::new(c, 1), Run::new(c, 3)], 6));
+    ///
+    /// let prefix = LineMask::from_fills(&[c, c], c);
+    /// let rest = rule.trim_satisfied(&prefix, c).unwrap();
+    /// assert_eq!(rest, Rule::new(vec![Run::new(c, 1), Run::new(c, 3)], 7));
+    ///
+    /// let prefix = LineMask::from_fills(&[c, Fill::Cross], c);
+    /// assert_eq!(rule.trim_satisfied(&prefix, c), None);
+    /// ```
+    pub fn trim_satisfied(&self, prefix: &LineMask, fill: Fill) -> Option<Rule> {
+        let cells: Vec<_> = prefix.runs().collect();
+        let (satisfied, rest) = self.runs.split_at_checked(cells.len())?;
+
+        let matches = satisfied
+            .iter()
+            .zip(&cells)
+            .all(|(run, cells)| run.fill == fill && run.count == cells.len());
+
+        if !matches {
+            return None;
+        }
+
+        // A run that ends at the last cell of the prefix cannot touch the next run of the same fill
+        let gap = match (prefix.trailing_ones() > 0, rest.first()) {
+            (true, Some(next)) if next.fill == fill => 1,
+            _ => 0,
+        };
+
+        let line_len = self.line_len.checked_sub(prefix.len() + gap)?;
+        let rest = Rule::new(rest.to_vec(), line_len);
+
+        (rest.len() <= line_len).then_some(rest)
+    }
+}
+
 impl Index<u16> for Rule {
     type Output = Run;
 
@@ -59,3 +154,50 @@ impl Index<u16> for Rule {
         &self.runs[idx as usize]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const C1: Fill = Fill::Color(1);
+    const C2: Fill = Fill::Color(2);
+
+    fn rule(runs: &[(Fill, usize)], line_len: usize) -> Rule {
+        Rule::new(runs.iter().map(|&run| Run::from(run)).collect(), line_len)
+    }
+
+    #[rstest]
+    #[case::start(0, 0, 8)]
+    #[case::same_fill(1, 3, 5)]
+    #[case::other_fill(2, 4, 4)]
+    #[case::end(3, 6, 2)]
+    fn split_at(#[case] run_idx: usize, #[case] start_len: usize, #[case] end_len: usize) {
+        let rule = rule(&[(C1, 2), (C1, 1), (C2, 2)], 8);
+        let (start, end) = rule.split_at(run_idx);
+
+        assert_eq!((start.line_len(), end.line_len()), (start_len, end_len));
+        assert_eq!(start.runs().len() + end.runs().len(), rule.runs().len());
+        assert!(start.len() <= start.line_len() && end.len() <= end.line_len());
+        assert_eq!(start.merge(&end), rule);
+    }
+
+    #[rstest]
+    #[case::empty("", C1, Some(rule(&[(C1, 2), (C1, 1), (C2, 1)], 8)))]
+    #[case::crossed("00", C1, Some(rule(&[(C1, 2), (C1, 1), (C2, 1)], 6)))]
+    #[case::run("0110", C1, Some(rule(&[(C1, 1), (C2, 1)], 4)))]
+    #[case::open_run("011", C1, Some(rule(&[(C1, 1), (C2, 1)], 4)))]
+    #[case::open_run_other_fill("01101", C1, Some(rule(&[(C2, 1)], 3)))]
+    #[case::all_of_fill("011010", C1, Some(rule(&[(C2, 1)], 2)))]
+    #[case::no_room("01101000", C1, None)]
+    #[case::short_run("0100", C1, None)]
+    #[case::other_fill("011", C2, None)]
+    #[case::extra_run("110101", C1, None)]
+    fn trim_satisfied(#[case] prefix: &str, #[case] fill: Fill, #[case] expected: Option<Rule>) {
+        let rule = rule(&[(C1, 2), (C1, 1), (C2, 1)], 8);
+        let prefix: LineMask = prefix.chars().map(|ch| ch == '1').collect();
+
+        assert_eq!(rule.trim_satisfied(&prefix, fill), expected);
+    }
+}
@@ -1,75 +1,42 @@
+use puzzled_core::LineRuns;
+
 use crate::{Fill, Run};
 
+/// Iterator over the [runs](Run) of a line of fills, which optionally skips runs that are not colored
+///
+/// The run-length encoding itself is shared with other puzzles through [`LineRuns`]
 #[derive(Debug, Clone)]
 pub struct Runs<I>
 where
-    I: Iterator,
+    I: Iterator<Item = Fill>,
 {
-    iter: I,
-    curr: Option<Run>,
-
+    runs: LineRuns<I>,
     skip_non_colored: bool,
-    finished: bool,
 }
 
 impl<I> Runs<I>
 where
     I: Iterator<Item = Fill>,
-    I::Item: Into<Fill>,
 {
-    pub fn new(mut iter: I, skip_non_colored: bool) -> Self {
-        let curr = iter.next().map(|fill| Run { fill, count: 1 });
-
+    pub fn new(iter: I, skip_non_colored: bool) -> Self {
         Self {
-            iter,
-            curr,
+            runs: LineRuns::new(iter),
             skip_non_colored,
-            finished: false,
         }
     }
 }
 
 impl<I> Iterator for Runs<I>
 where
-    I: Iterator,
-    I::Item: Into<Fill>,
+    I: Iterator<Item = Fill>,
 {
     type Item = Run;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.finished {
-            return None;
-        }
-
-        let mut curr = self.curr.take()?;
-        let should_yield =
-            |curr: Run| !self.skip_non_colored || matches!(curr.fill, Fill::Color(_));
-
-        while let Some(fill) = self.iter.next() {
-            let fill: Fill = fill.into();
-
-            // Continue the current run
-            if fill == curr.fill {
-                curr.count += 1;
-            } else {
-                // Start a new run from the next fill
-                self.curr = Some(Run { fill, count: 1 });
-
-                // Yield a colored run..
-                if should_yield(curr) {
-                    return Some(curr);
-                }
-                // .. or skip to the next one
-                else {
-                    return self.next();
-                }
-            }
-        }
-
-        // Out of fills -> finish last colored run
-        self.finished = true;
-
-        should_yield(curr).then_some(curr)
+        self.runs
+            .by_ref()
+            .find(|run| !self.skip_non_colored || run.value.is_color())
+            .map(|run| Run::new(run.value, run.len))
     }
 }
 
@@ -6,7 +6,7 @@ use std::fmt::Debug;
 
 use crate::Fill;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Run {
     pub fill: Fill,
     pub count: usize,
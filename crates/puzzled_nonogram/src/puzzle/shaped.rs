@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use puzzled_core::{Grid, Position};
+
+use crate::{Fill, Rule, Topology};
+
+/// Nonogram whose cells and lines follow an arbitrary [topology](Topology), such as a [hexagon](crate::HexTopology) or [triangle](crate::TriangularTopology)
+///
+/// Fills are stored in a [grid](Grid) with the size of the topology, of which only the [positions](Topology::positions) of the topology are used.
+/// Lines without a rule are expected to be empty.
+/// ```
+/// use puzzled::nonogram::{Fill, Grid, HexTopology, NonogramSolver, ShapedNonogram};
+///
+/// let fills = Grid::from_vec(vec![
+///     Fill::Cross, Fill::Color(1), Fill::Color(1),
+///     Fill::Color(1), Fill::Cross, Fill::Color(1),
+///     Fill::Color(1), Fill::Color(1), Fill::Cross,
+/// ], 3)?;
+/// let puzzle = ShapedNonogram::from_fills(HexTopology::new(2), &fills);
+///
+/// let solved = NonogramSolver::default().solve_shaped(&puzzle)?;
+/// assert!(puzzle.is_solved(&solved));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapedNonogram<T: Topology> {
+    topology: T,
+    rules: BTreeMap<T::Line, Rule>,
+}
+
+impl<T: Topology> ShapedNonogram<T> {
+    pub fn new(topology: T, rules: BTreeMap<T::Line, Rule>) -> Self {
+        Self { topology, rules }
+    }
+
+    /// Derive the rule of every line from a solution, where positions outside of the topology are ignored
+    pub fn from_fills(topology: T, fills: &Grid<Fill>) -> Self {
+        let rules = topology
+            .lines()
+            .into_iter()
+            .map(|line| {
+                let fills = topology
+                    .line_positions(line)
+                    .into_iter()
+                    .map(|pos| fills[pos]);
+
+                (line, Rule::from_fills(fills))
+            })
+            .collect();
+
+        Self { topology, rules }
+    }
+
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+
+    pub fn rules(&self) -> &BTreeMap<T::Line, Rule> {
+        &self.rules
+    }
+
+    pub fn rules_mut(&mut self) -> &mut BTreeMap<T::Line, Rule> {
+        &mut self.rules
+    }
+
+    /// Whether the colored runs of every line exactly match its rule, where unknown cells are considered empty
+    pub fn is_solved(&self, fills: &Grid<Option<Fill>>) -> bool {
+        let empty = Rule::default();
+
+        self.topology.lines().into_iter().all(|line| {
+            let rule = self.rules.get(&line).unwrap_or(&empty);
+            let fills = self
+                .topology
+                .line_positions(line)
+                .into_iter()
+                .map(|pos: Position| fills[pos].unwrap_or_default());
+
+            rule.is_satisfied_by(fills)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{HexTopology, NonogramSolver, SquareTopology, TriangularTopology};
+
+    const X: Fill = Fill::Cross;
+    const A: Fill = Fill::Color(1);
+    const B: Fill = Fill::Color(2);
+
+    #[rstest]
+    #[case::square(SquareTopology::new(2, 3), vec![A, X, B, A, A, B])]
+    #[case::hex(HexTopology::new(2), vec![X, A, B, A, A, X, B, A, X])]
+    #[case::triangular(TriangularTopology::new(3), vec![A, X, X, X, X, A, B, B, X, X, X, A, B, A, A])]
+    fn solve<T: Topology>(#[case] topology: T, #[case] fills: Vec<Fill>) {
+        let fills = Grid::from_vec(fills, topology.cols()).unwrap();
+        let puzzle = ShapedNonogram::from_fills(topology, &fills);
+
+        let solved = NonogramSolver::default().solve_shaped(&puzzle).unwrap();
+
+        assert!(puzzle.is_solved(&solved));
+        for pos in puzzle.topology().positions() {
+            assert!(solved[pos].is_some(), "{pos:?} was not solved");
+        }
+
+        let (traced, trace) = NonogramSolver::default()
+            .solve_shaped_with_trace(&puzzle)
+            .unwrap();
+
+        assert_eq!(traced, solved);
+        assert!(!trace.is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+use puzzled_core::Position;
+
+use crate::Topology;
+
+/// Line of a [hexagonal nonogram](HexTopology) along one of its three axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HexLine {
+    /// Cells of the same row, from left to right
+    Row(usize),
+
+    /// Cells of the same column, from top to bottom
+    Col(usize),
+
+    /// Cells on the same diagonal from the top right to the bottom left
+    Diagonal(usize),
+}
+
+/// Hexagon-shaped nonogram of hexagonal cells, which has lines along three axes
+///
+/// Cells are stored in axial coordinates in a grid of `2 * side - 1` rows and columns, of which the top left and bottom right corners are unused.
+/// Each cell has up to six neighbors, two along every axis.
+/// ```
+/// use puzzled::nonogram::{HexLine, HexTopology, Position, Topology};
+///
+/// let topology = HexTopology::new(2);
+/// assert_eq!(topology.positions().count(), 7);
+///
+/// // The center cell touches every other cell
+/// let center = Position::new(1, 1);
+/// assert_eq!(topology.neighbors(center).len(), 6);
+/// assert_eq!(
+///     topology.lines_through(center),
+///     vec![HexLine::Row(1), HexLine::Col(1), HexLine::Diagonal(1)]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexTopology {
+    side: usize,
+}
+
+impl HexTopology {
+    /// Create a hexagon with `side` cells along each of its six sides
+    pub fn new(side: usize) -> Self {
+        Self { side }
+    }
+
+    pub fn side(&self) -> usize {
+        self.side
+    }
+
+    /// Number of rows, columns and diagonals
+    fn len(&self) -> usize {
+        (2 * self.side).saturating_sub(1)
+    }
+
+    /// Index of the diagonal that a position is on, where the top left corner is outside of the hexagon
+    fn diagonal(&self, pos: Position) -> Option<usize> {
+        (pos.row + pos.col).checked_sub(self.side.saturating_sub(1))
+    }
+}
+
+impl Topology for HexTopology {
+    type Line = HexLine;
+
+    fn rows(&self) -> usize {
+        self.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.len()
+    }
+
+    fn contains(&self, pos: Position) -> bool {
+        pos.within(self.len(), self.len())
+            && self
+                .diagonal(pos)
+                .is_some_and(|diagonal| diagonal < self.len())
+    }
+
+    fn lines(&self) -> Vec<HexLine> {
+        let len = self.len();
+        let rows = (0..len).map(HexLine::Row);
+        let cols = (0..len).map(HexLine::Col);
+        let diagonals = (0..len).map(HexLine::Diagonal);
+
+        rows.chain(cols).chain(diagonals).collect()
+    }
+
+    fn line_positions(&self, line: HexLine) -> Vec<Position> {
+        let len = self.len();
+        let positions: Vec<_> = match line {
+            HexLine::Row(row) => (0..len).map(|col| Position::new(row, col)).collect(),
+            HexLine::Col(col) => (0..len).map(|row| Position::new(row, col)).collect(),
+            HexLine::Diagonal(diagonal) => {
+                let sum = diagonal + self.side.saturating_sub(1);
+
+                (0..len)
+                    .filter_map(|row| Some(Position::new(row, sum.checked_sub(row)?)))
+                    .collect()
+            }
+        };
+
+        positions
+            .into_iter()
+            .filter(|&pos| self.contains(pos))
+            .collect()
+    }
+
+    fn lines_through(&self, pos: Position) -> Vec<HexLine> {
+        let diagonal = self.diagonal(pos).unwrap_or_default();
+
+        vec![
+            HexLine::Row(pos.row),
+            HexLine::Col(pos.col),
+            HexLine::Diagonal(diagonal),
+        ]
+    }
+
+    fn neighbors(&self, pos: Position) -> Vec<Position> {
+        let Position { row, col } = pos;
+        let candidates = [
+            col.checked_sub(1).map(|col| Position::new(row, col)),
+            Some(Position::new(row, col + 1)),
+            row.checked_sub(1).map(|row| Position::new(row, col)),
+            Some(Position::new(row + 1, col)),
+            row.checked_sub(1).map(|row| Position::new(row, col + 1)),
+            col.checked_sub(1).map(|col| Position::new(row + 1, col)),
+        ];
+
+        candidates
+            .into_iter()
+            .flatten()
+            .filter(|&pos| self.contains(pos))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::puzzle::topology::tests::check_consistent;
+
+    #[rstest]
+    #[case::single(1, 1)]
+    #[case::small(2, 7)]
+    #[case::large(4, 37)]
+    fn consistent(#[case] side: usize, #[case] cells: usize) {
+        let topology = HexTopology::new(side);
+
+        assert_eq!(topology.positions().count(), cells);
+        check_consistent(&topology);
+    }
+
+    #[test]
+    fn lines() {
+        let topology = HexTopology::new(2);
+        let positions = |line| topology.line_positions(line);
+
+        assert_eq!(
+            positions(HexLine::Row(0)),
+            [(0, 1), (0, 2)].map(Position::from)
+        );
+        assert_eq!(
+            positions(HexLine::Col(0)),
+            [(1, 0), (2, 0)].map(Position::from)
+        );
+        assert_eq!(
+            positions(HexLine::Diagonal(1)),
+            [(0, 2), (1, 1), (2, 0)].map(Position::from)
+        );
+    }
+}
@@ -0,0 +1,171 @@
+mod hex;
+mod triangular;
+
+pub use hex::*;
+pub use triangular::*;
+
+use std::fmt;
+
+use puzzled_core::{BitGrid, Line, Position};
+
+/// Geometry of the cells of a nonogram and the lines that they form
+///
+/// Cells are stored in a rectangular [grid](puzzled_core::Grid) of [`Self::rows`] by [`Self::cols`], of which the topology may only use some positions.
+/// Every line visits its cells in reading order, such that consecutive cells of a line are [neighbors](Self::neighbors).
+/// Since the line solver only ever looks at a single line at a time, any topology can be [solved](crate::NonogramSolver::solve_shaped) the same way.
+pub trait Topology {
+    type Line: Copy + Ord + fmt::Debug;
+
+    /// Number of rows of the grid that stores the cells
+    fn rows(&self) -> usize;
+
+    /// Number of columns of the grid that stores the cells
+    fn cols(&self) -> usize;
+
+    /// Whether the position is a cell of the topology
+    fn contains(&self, pos: Position) -> bool;
+
+    /// Every line that a rule can be given for
+    fn lines(&self) -> Vec<Self::Line>;
+
+    /// Positions of the cells of a line in reading order
+    fn line_positions(&self, line: Self::Line) -> Vec<Position>;
+
+    /// Every line that the cell at the given position is part of
+    fn lines_through(&self, pos: Position) -> Vec<Self::Line>;
+
+    /// Cells that share an edge with the cell at the given position
+    fn neighbors(&self, pos: Position) -> Vec<Position>;
+
+    /// Whether the bit of every cell of the line is set, e.g. to find lines whose cells are all known
+    fn is_line_full(&self, bits: &BitGrid, line: Self::Line) -> bool {
+        self.line_positions(line)
+            .into_iter()
+            .all(|pos| bits.get(pos) == Some(true))
+    }
+
+    /// Every position of a cell in row order
+    fn positions(&self) -> impl Iterator<Item = Position> {
+        let cols = self.cols();
+
+        (0..self.rows() * cols)
+            .map(move |idx| Position::from_row_order(idx, cols))
+            .filter(|&pos| self.contains(pos))
+    }
+}
+
+/// Regular nonogram of square cells, whose lines are its rows and columns
+/// ```
+/// use puzzled::nonogram::{Line, Position, SquareTopology, Topology};
+///
+/// let topology = SquareTopology::new(2, 3);
+///
+/// assert_eq!(topology.lines().len(), 5);
+/// assert_eq!(topology.lines_through(Position::new(1, 2)), vec![Line::Row(1), Line::Col(2)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SquareTopology {
+    rows: usize,
+    cols: usize,
+}
+
+impl SquareTopology {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+}
+
+impl Topology for SquareTopology {
+    type Line = Line;
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn contains(&self, pos: Position) -> bool {
+        pos.within(self.rows, self.cols)
+    }
+
+    fn lines(&self) -> Vec<Line> {
+        let rows = (0..self.rows).map(Line::Row);
+        let cols = (0..self.cols).map(Line::Col);
+
+        rows.chain(cols).collect()
+    }
+
+    fn line_positions(&self, line: Line) -> Vec<Position> {
+        match line {
+            Line::Row(row) => (0..self.cols).map(|col| Position::new(row, col)).collect(),
+            Line::Col(col) => (0..self.rows).map(|row| Position::new(row, col)).collect(),
+        }
+    }
+
+    fn lines_through(&self, pos: Position) -> Vec<Line> {
+        vec![Line::Row(pos.row), Line::Col(pos.col)]
+    }
+
+    fn neighbors(&self, pos: Position) -> Vec<Position> {
+        let Position { row, col } = pos;
+        let candidates = [
+            row.checked_sub(1).map(|row| Position::new(row, col)),
+            Some(Position::new(row + 1, col)),
+            col.checked_sub(1).map(|col| Position::new(row, col)),
+            Some(Position::new(row, col + 1)),
+        ];
+
+        candidates
+            .into_iter()
+            .flatten()
+            .filter(|&pos| self.contains(pos))
+            .collect()
+    }
+
+    fn is_line_full(&self, bits: &BitGrid, line: Line) -> bool {
+        bits.is_line_full(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Every topology should visit its cells in lines of neighbors and have each cell in exactly the lines through it
+    pub(crate) fn check_consistent<T: Topology>(topology: &T) {
+        for line in topology.lines() {
+            let positions = topology.line_positions(line);
+
+            for pos in &positions {
+                assert!(topology.contains(*pos), "{pos:?} of {line:?} is not a cell");
+                assert!(topology.lines_through(*pos).contains(&line));
+            }
+            for pair in positions.windows(2) {
+                assert!(
+                    topology.neighbors(pair[0]).contains(&pair[1]),
+                    "{:?} and {:?} of {line:?} are not neighbors",
+                    pair[0],
+                    pair[1]
+                );
+            }
+        }
+
+        for pos in topology.positions() {
+            for neighbor in topology.neighbors(pos) {
+                assert!(topology.neighbors(neighbor).contains(&pos));
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::single(1, 1)]
+    #[case::wide(2, 5)]
+    #[case::tall(4, 3)]
+    fn consistent(#[case] rows: usize, #[case] cols: usize) {
+        check_consistent(&SquareTopology::new(rows, cols));
+    }
+}
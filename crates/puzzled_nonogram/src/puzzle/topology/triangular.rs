@@ -0,0 +1,172 @@
+use puzzled_core::Position;
+
+use crate::Topology;
+
+/// Line of a [triangular nonogram](TriangularTopology), which zigzags between two parallel grid lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TriangularLine {
+    /// Cells of the same row, from left to right
+    Row(usize),
+
+    /// Cells running parallel to the left side, from the top down
+    Left(usize),
+
+    /// Cells running parallel to the right side, from the top down
+    Right(usize),
+}
+
+/// Triangle-shaped nonogram of triangular cells, also known as a trid, which has lines along three axes
+///
+/// Row `r` holds `2 * r + 1` cells, which alternately point up and down starting with an upward cell.
+/// These are stored in a grid of `side` rows and `2 * side - 1` columns, of which the top right corner is unused.
+/// Every cell has up to three neighbors: the cells to its left and right and the cell below or above it when it points up or down.
+/// ```
+/// use puzzled::nonogram::{Position, Topology, TriangularLine, TriangularTopology};
+///
+/// let topology = TriangularTopology::new(3);
+/// assert_eq!(topology.positions().count(), 9);
+///
+/// assert_eq!(
+///     topology.line_positions(TriangularLine::Left(0)),
+///     [(0, 0), (1, 1), (1, 0), (2, 1), (2, 0)].map(Position::from)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriangularTopology {
+    side: usize,
+}
+
+impl TriangularTopology {
+    /// Create a triangle of `side` rows, such that `side` upward cells line each of its sides
+    pub fn new(side: usize) -> Self {
+        Self { side }
+    }
+
+    pub fn side(&self) -> usize {
+        self.side
+    }
+
+    /// Whether the cell at the position points up, such that its neighbor across the horizontal edge is below it
+    pub fn points_up(pos: Position) -> bool {
+        pos.col.is_multiple_of(2)
+    }
+}
+
+impl Topology for TriangularTopology {
+    type Line = TriangularLine;
+
+    fn rows(&self) -> usize {
+        self.side
+    }
+
+    fn cols(&self) -> usize {
+        (2 * self.side).saturating_sub(1)
+    }
+
+    fn contains(&self, pos: Position) -> bool {
+        pos.row < self.side && pos.col <= 2 * pos.row
+    }
+
+    fn lines(&self) -> Vec<TriangularLine> {
+        let rows = (0..self.side).map(TriangularLine::Row);
+        let left = (0..self.side).map(TriangularLine::Left);
+        let right = (0..self.side).map(TriangularLine::Right);
+
+        rows.chain(left).chain(right).collect()
+    }
+
+    fn line_positions(&self, line: TriangularLine) -> Vec<Position> {
+        let positions: Vec<_> = match line {
+            TriangularLine::Row(row) => (0..=2 * row).map(|col| Position::new(row, col)).collect(),
+
+            // Every row holds a downward cell followed by an upward cell to its left
+            TriangularLine::Left(idx) => (idx..self.side)
+                .flat_map(|row| [Position::new(row, 2 * idx + 1), Position::new(row, 2 * idx)])
+                .collect(),
+
+            // Every row holds a downward cell followed by an upward cell to its right
+            TriangularLine::Right(idx) => (idx..self.side)
+                .flat_map(|row| {
+                    let up = 2 * (row - idx);
+                    let down = up.checked_sub(1).map(|col| Position::new(row, col));
+
+                    down.into_iter().chain([Position::new(row, up)])
+                })
+                .collect(),
+        };
+
+        positions
+            .into_iter()
+            .filter(|&pos| self.contains(pos))
+            .collect()
+    }
+
+    fn lines_through(&self, pos: Position) -> Vec<TriangularLine> {
+        let Position { row, col } = pos;
+
+        vec![
+            TriangularLine::Row(row),
+            TriangularLine::Left(col / 2),
+            TriangularLine::Right(row.saturating_sub(col.div_ceil(2))),
+        ]
+    }
+
+    fn neighbors(&self, pos: Position) -> Vec<Position> {
+        let Position { row, col } = pos;
+        let across = if Self::points_up(pos) {
+            Some(Position::new(row + 1, col + 1))
+        } else {
+            row.checked_sub(1).map(|row| Position::new(row, col - 1))
+        };
+
+        let candidates = [
+            col.checked_sub(1).map(|col| Position::new(row, col)),
+            Some(Position::new(row, col + 1)),
+            across,
+        ];
+
+        candidates
+            .into_iter()
+            .flatten()
+            .filter(|&pos| self.contains(pos))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::puzzle::topology::tests::check_consistent;
+
+    #[rstest]
+    #[case::single(1, 1)]
+    #[case::small(2, 4)]
+    #[case::large(5, 25)]
+    fn consistent(#[case] side: usize, #[case] cells: usize) {
+        let topology = TriangularTopology::new(side);
+
+        assert_eq!(topology.positions().count(), cells);
+        check_consistent(&topology);
+    }
+
+    #[test]
+    fn lines() {
+        let topology = TriangularTopology::new(3);
+        let positions = |line| topology.line_positions(line);
+
+        assert_eq!(
+            positions(TriangularLine::Row(1)),
+            [(1, 0), (1, 1), (1, 2)].map(Position::from)
+        );
+        assert_eq!(
+            positions(TriangularLine::Left(2)),
+            [(2, 4)].map(Position::from)
+        );
+        assert_eq!(
+            positions(TriangularLine::Right(0)),
+            [(0, 0), (1, 1), (1, 2), (2, 3), (2, 4)].map(Position::from)
+        );
+    }
+}
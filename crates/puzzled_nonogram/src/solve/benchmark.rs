@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use puzzled_core::{Cell, Grid, Metadata, Solver};
+
+use crate::{Colors, Fill, Nonogram, NonogramSolver, NonogramState, Rules};
+
+/// Known difficulty of a puzzle in a [benchmark corpus](SolverBenchmark)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BenchmarkDifficulty {
+    /// Solvable by line logic alone
+    Easy,
+
+    /// Requires guessing cells when line logic gets stuck
+    Medium,
+
+    /// Has multiple solutions, so line logic alone cannot decide between them
+    Hard,
+}
+
+/// Puzzle from a [benchmark corpus](SolverBenchmark)
+#[derive(Debug)]
+pub struct BenchmarkEntry {
+    pub name: String,
+    pub difficulty: BenchmarkDifficulty,
+    pub nonogram: Nonogram,
+}
+
+/// Timings and search statistics from solving a single [benchmark entry](BenchmarkEntry)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchmarkResult {
+    pub name: String,
+    pub difficulty: BenchmarkDifficulty,
+
+    /// Wall-clock time spent by the solver
+    pub elapsed: Duration,
+
+    /// Number of search nodes the solver visited, see [`NonogramSolver::nodes`]
+    pub nodes: usize,
+
+    /// Number of single lines the solver solved, see [`NonogramSolver::line_solves`]
+    pub line_solves: usize,
+
+    /// Whether the solver found a solution that satisfies all rules of the puzzle
+    pub solved: bool,
+}
+
+/// Corpus of nonograms to compare the performance of [solvers](NonogramSolver) across changes
+///
+/// ```
+/// use puzzled::nonogram::SolverBenchmark;
+///
+/// let results = SolverBenchmark::corpus().run();
+/// assert!(results.iter().all(|result| result.solved));
+/// ```
+#[derive(Debug, Default)]
+pub struct SolverBenchmark {
+    entries: Vec<BenchmarkEntry>,
+}
+
+/// Bundled corpus with filled cells as `1`/`2` (colors) and empty cells as `.`
+const CORPUS: &[(&str, BenchmarkDifficulty, &[&str])] = &[
+    (
+        "cross",
+        BenchmarkDifficulty::Easy,
+        &["..1..", "..1..", "11111", "..1..", "..1.."],
+    ),
+    (
+        "heart",
+        BenchmarkDifficulty::Easy,
+        &[
+            ".11.11.", "1111111", "1111111", "1111111", ".11111.", "..111..", "...1...",
+        ],
+    ),
+    (
+        "flag",
+        BenchmarkDifficulty::Easy,
+        &[
+            "11..222222",
+            "11..222222",
+            "11........",
+            "1111111111",
+            "11........",
+            "11..222222",
+            "11..222222",
+        ],
+    ),
+    (
+        "smiley",
+        BenchmarkDifficulty::Medium,
+        &[
+            "..1111..", ".1....1.", "1.1..1.1", "1......1", "1.1..1.1", "1..11..1", ".1....1.",
+            "..1111..",
+        ],
+    ),
+    (
+        "spiral",
+        BenchmarkDifficulty::Easy,
+        &[
+            "1111111111",
+            ".........1",
+            "11111111.1",
+            "1......1.1",
+            "1.1111.1.1",
+            "1.1..1.1.1",
+            "1.1....1.1",
+            "1.111111.1",
+            "1........1",
+            "1111111111",
+        ],
+    ),
+    (
+        "checkerboard",
+        BenchmarkDifficulty::Hard,
+        &["1.1.1.", ".1.1.1", "1.1.1.", ".1.1.1", "1.1.1.", ".1.1.1"],
+    ),
+];
+
+impl SolverBenchmark {
+    /// Create an empty benchmark
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a benchmark from the bundled corpus of nonograms with known difficulty
+    pub fn corpus() -> Self {
+        CORPUS
+            .iter()
+            .fold(Self::new(), |benchmark, &(name, difficulty, rows)| {
+                let nonogram = nonogram_from_rows(rows);
+
+                benchmark.with_entry(name, difficulty, nonogram)
+            })
+    }
+
+    /// Add a nonogram to the benchmark
+    pub fn with_entry<S>(
+        mut self,
+        name: S,
+        difficulty: BenchmarkDifficulty,
+        nonogram: Nonogram,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.entries.push(BenchmarkEntry {
+            name: name.into(),
+            difficulty,
+            nonogram,
+        });
+
+        self
+    }
+
+    pub fn entries(&self) -> &[BenchmarkEntry] {
+        &self.entries
+    }
+
+    /// Solve every nonogram in the benchmark with a fresh [solver](NonogramSolver) and collect the results
+    pub fn run(&self) -> Vec<BenchmarkResult> {
+        self.entries.iter().map(run_entry).collect()
+    }
+}
+
+fn run_entry(entry: &BenchmarkEntry) -> BenchmarkResult {
+    let nonogram = &entry.nonogram;
+
+    let mut solver = NonogramSolver::default();
+    let mut state = NonogramState::from(nonogram);
+
+    let start = Instant::now();
+    let result = solver.solve(nonogram, &mut state);
+    let elapsed = start.elapsed();
+
+    let solved = result.is_ok_and(|solution| {
+        let fills = solution.map(|fill| Cell::new(Some(fill)));
+
+        Rules::from_fills(&fills) == *nonogram.rules()
+    });
+
+    BenchmarkResult {
+        name: entry.name.clone(),
+        difficulty: entry.difficulty,
+        elapsed,
+        nodes: solver.nodes(),
+        line_solves: solver.line_solves(),
+        solved,
+    }
+}
+
+fn nonogram_from_rows(rows: &[&str]) -> Nonogram {
+    let cols = rows.first().map_or(0, |row| row.len());
+    let fills = rows
+        .iter()
+        .flat_map(|row| row.chars())
+        .map(|ch| Cell::new(Some(Fill::decode_char(ch).expect("Corpus fills are valid"))))
+        .collect();
+
+    let fills = Grid::from_vec(fills, cols).expect("Corpus rows have equal lengths");
+
+    Nonogram::new(fills, Colors::default(), Metadata::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpus_is_solved() {
+        let results = SolverBenchmark::corpus().run();
+
+        assert_eq!(results.len(), CORPUS.len());
+
+        for result in results {
+            assert!(result.solved, "{} should be solved", result.name);
+
+            // Line logic solves the easy puzzles on its own, while the others need search nodes to guess cells
+            let guessed = result.nodes > 1;
+            assert_eq!(
+                guessed,
+                result.difficulty != BenchmarkDifficulty::Easy,
+                "{} took {} nodes",
+                result.name,
+                result.nodes
+            );
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use crate::{Fill, Rule, Run, solve_line};
+
+type LineKey = (Vec<Run>, Vec<Option<Fill>>);
+type SolvedLine = Option<Vec<Option<Fill>>>;
+
+/// Memoizes [line solves](solve_line) by the runs of a rule and the current cells of the line
+///
+/// Backtracking repeatedly propagates lines that are identical to ones solved before, such that their results can be reused.
+/// The cache holds at most [`capacity`](Self::capacity) lines and evicts the least recently used half when it is full.
+/// ```
+/// use puzzled::nonogram::{Fill, LineCache, Rule, Run};
+///
+/// let rule = Rule::new(vec![Run::new(Fill::Color(1), 2)], 3);
+/// let mut cache = LineCache::new(16);
+///
+/// let solved = cache.solve(&rule, &[None, None, None]);
+/// assert_eq!(solved, Some(vec![None, Some(Fill::Color(1)), None]));
+///
+/// cache.solve(&rule, &[None, None, None]);
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineCache {
+    entries: HashMap<LineKey, (SolvedLine, u64)>,
+    capacity: usize,
+    tick: u64,
+
+    hits: usize,
+    misses: usize,
+}
+
+impl LineCache {
+    /// Default number of lines that a cache holds
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Create a cache that holds at most `capacity` lines, where a capacity of 0 disables caching
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::with_capacity(capacity.min(Self::DEFAULT_CAPACITY)),
+            capacity,
+            tick: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Solve the line through the cache, see [`solve_line`]
+    pub fn solve(&mut self, rule: &Rule, cells: &[Option<Fill>]) -> SolvedLine {
+        self.solve_with(rule, cells, solve_line)
+    }
+
+    /// Solve the line through the cache, where lines that are not cached yet are solved with `solve`
+    ///
+    /// Since lines are cached by their rule and cells only, the cache should always be used with the same way of solving lines.
+    pub fn solve_with<F>(&mut self, rule: &Rule, cells: &[Option<Fill>], solve: F) -> SolvedLine
+    where
+        F: FnOnce(&Rule, &[Option<Fill>]) -> SolvedLine,
+    {
+        self.tick += 1;
+
+        let key = (rule.runs().clone(), cells.to_vec());
+
+        if let Some((solved, used)) = self.entries.get_mut(&key) {
+            self.hits += 1;
+            *used = self.tick;
+
+            return solved.clone();
+        }
+
+        self.misses += 1;
+        let solved = solve(rule, cells);
+
+        if self.capacity > 0 {
+            if self.entries.len() >= self.capacity {
+                self.evict();
+            }
+
+            self.entries.insert(key, (solved.clone(), self.tick));
+        }
+
+        solved
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of lines currently in the cache
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of solves that were answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of solves that had to be computed
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Remove all cached lines, keeping the hit and miss counts
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drop the least recently used half of the entries
+    fn evict(&mut self) {
+        let mut used: Vec<_> = self.entries.values().map(|(_, used)| *used).collect();
+        let mid = used.len() / 2;
+        let (_, &mut threshold, _) = used.select_nth_unstable(mid);
+
+        self.entries.retain(|_, (_, used)| *used > threshold);
+    }
+}
+
+impl Default for LineCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const C: Fill = Fill::Color(1);
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let rule = Rule::new(vec![Run::new(C, 1)], 3);
+        let lines = [
+            [Some(C), None, None],
+            [None, Some(C), None],
+            [None, None, Some(C)],
+            [None, None, None],
+        ];
+
+        let mut cache = LineCache::new(3);
+        cache.solve(&rule, &lines[0]);
+        cache.solve(&rule, &lines[1]);
+        cache.solve(&rule, &lines[2]);
+
+        // Use the first line again, such that the second one is evicted first
+        cache.solve(&rule, &lines[0]);
+        cache.solve(&rule, &lines[3]);
+        assert!(cache.len() <= cache.capacity());
+
+        cache.solve(&rule, &lines[0]);
+        assert_eq!(cache.hits(), 2);
+
+        cache.solve(&rule, &lines[1]);
+        assert_eq!(cache.hits(), 2);
+    }
+
+    #[test]
+    fn disabled() {
+        let rule = Rule::new(vec![Run::new(C, 1)], 1);
+        let mut cache = LineCache::new(0);
+
+        assert_eq!(cache.solve(&rule, &[None]), Some(vec![Some(C)]));
+        assert_eq!(cache.solve(&rule, &[None]), Some(vec![Some(C)]));
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (0, 2, 0));
+    }
+}
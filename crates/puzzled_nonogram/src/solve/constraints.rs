@@ -24,8 +24,8 @@ impl NonogramState {
         let left = fit_forwards(runs, line_len);
         let right = fit_backwards(runs, line_len);
 
-        let mut required_cross = bitvec![1; line_len];
-        let mut optional_cross = bitvec![1; line_len];
+        let mut required_cross = LineMask::full(line_len);
+        let mut optional_cross = LineMask::full(line_len);
 
         // Generate the constraints if they do not yet exists, otherwise return early
         let constraints = match self.constraints.entry(Line::Row(0)) {
@@ -38,8 +38,8 @@ impl NonogramState {
             let (required, optional) = find_filled(runs, line_len, color, &left, &right);
 
             // Eliminate cells that may be filled for all crossed out cells
-            required_cross &= !optional.clone();
-            optional_cross &= !required.clone();
+            required_cross &= optional.negation();
+            optional_cross &= required.negation();
 
             // Register the must be filled cells for the color
             let constraint = LineMaskConstraint { required, optional };
@@ -88,7 +88,11 @@ fn find_filled(
         let mut run_must = bitvec![1; n];
         let mut has_any = false;
 
-        for start in 0..=n.saturating_sub(len) {
+        for (start, &fits_prefix) in prefixes[r]
+            .iter()
+            .enumerate()
+            .take(n.saturating_sub(len) + 1)
+        {
             // Determine where the placed run must end (include gap for same fill)
             let mut end = start + len;
 
@@ -97,7 +101,7 @@ fn find_filled(
             }
 
             // Set all cells in the placement for a valid prefix/suffix pair
-            if prefixes[r][start] && suffixes[r + 1][end.min(n)] {
+            if fits_prefix && suffixes[r + 1][end.min(n)] {
                 has_any = true;
 
                 let mut mask = bitvec![0; n];
@@ -115,7 +119,7 @@ fn find_filled(
         }
     }
 
-    (must_be_filled, maybe_filled)
+    (must_be_filled.into(), maybe_filled.into())
 }
 
 /// Fit runs in a line going forwards
@@ -298,7 +302,7 @@ mod tests {
         let suffixes = fit_backwards(&runs, line_len);
         let (mask, _) = find_filled(&runs, line_len, C, &prefixes, &suffixes);
 
-        assert_eq!(mask, expected);
+        assert_eq!(mask.bits(), &expected);
     }
 
     #[traced_test]
@@ -320,6 +324,6 @@ mod tests {
         let suffixes = fit_backwards(&runs, line_len);
         let (_, mask) = find_filled(&runs, line_len, C, &prefixes, &suffixes);
 
-        assert_eq!(mask, expected);
+        assert_eq!(mask.bits(), &expected);
     }
 }
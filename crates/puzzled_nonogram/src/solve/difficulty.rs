@@ -0,0 +1,242 @@
+use std::{collections::BTreeMap, fmt};
+
+use puzzled_core::{Grid, Line, SolverError};
+
+use crate::{Fill, HintReason, NonogramSolver, Rule, Rules, solve_line};
+
+/// Grade of a [difficulty](NonogramDifficulty), ordered from easiest to hardest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DifficultyGrade {
+    /// Solvable by [simple boxes and spaces](HintReason::SimpleBoxes) alone
+    Trivial,
+
+    /// At most half of the cells need [forcing](HintReason::Forcing)
+    Easy,
+
+    /// More than half of the cells need [forcing](HintReason::Forcing)
+    Medium,
+
+    /// Needs a single level of guessing
+    Hard,
+
+    /// Needs nested guesses
+    Expert,
+}
+
+impl fmt::Display for DifficultyGrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grade = match self {
+            Self::Trivial => "trivial",
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard",
+            Self::Expert => "expert",
+        };
+
+        write!(f, "{grade}")
+    }
+}
+
+/// Techniques that were required to solve a nonogram, see [`NonogramSolver::difficulty`]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NonogramDifficulty {
+    /// Number of cells that were deduced by every [technique](HintReason) before guessing
+    pub techniques: BTreeMap<HintReason, usize>,
+
+    /// Number of cells that were left after line logic got stuck
+    pub guessed_cells: usize,
+
+    /// Most nested guesses that were needed to reach the solution
+    pub guess_depth: usize,
+
+    /// Total number of guesses that were made, including the ones that led to a contradiction
+    pub guesses: usize,
+}
+
+impl NonogramDifficulty {
+    /// Number of cells that were deduced by the technique
+    pub fn cells(&self, technique: HintReason) -> usize {
+        self.techniques.get(&technique).copied().unwrap_or_default()
+    }
+
+    /// Hardest technique that was needed before guessing, if any cell could be deduced
+    pub fn hardest_technique(&self) -> Option<HintReason> {
+        self.techniques
+            .iter()
+            .filter(|(_, cells)| **cells > 0)
+            .map(|(&technique, _)| technique)
+            .max()
+    }
+
+    /// Score that orders puzzles by difficulty
+    ///
+    /// The percentage of cells that needed [forcing](HintReason::Forcing) or guessing counts up to 100 points,
+    /// and every level of nested guessing adds another 100 points
+    pub fn score(&self) -> u32 {
+        let deduced: usize = self.techniques.values().sum();
+        let total = deduced + self.guessed_cells;
+
+        let hard = self.cells(HintReason::Forcing) + self.guessed_cells;
+        let share = (100 * hard).checked_div(total).unwrap_or_default();
+
+        (share + 100 * self.guess_depth) as u32
+    }
+
+    pub fn grade(&self) -> DifficultyGrade {
+        match self.guess_depth {
+            0 => {}
+            1 => return DifficultyGrade::Hard,
+            _ => return DifficultyGrade::Expert,
+        }
+
+        match self.score() {
+            0 => DifficultyGrade::Trivial,
+            1..=50 => DifficultyGrade::Easy,
+            _ => DifficultyGrade::Medium,
+        }
+    }
+}
+
+impl NonogramSolver {
+    /// Solve a nonogram from its rules alone while recording which techniques were needed
+    ///
+    /// Lines are first solved on their own, which deduces [simple boxes](HintReason::SimpleBoxes) and [spaces](HintReason::SimpleSpaces).
+    /// The remaining cells are deduced by [forcing](HintReason::Forcing) them from the cells that are already known,
+    /// after which the solver falls back to guessing.
+    ///
+    /// Returns an error if the rules do not have a solution
+    /// ```
+    /// use puzzled::nonogram::{nonogram, DifficultyGrade, NonogramSolver};
+    ///
+    /// let puzzle = nonogram!(
+    ///     [1 1 1]
+    ///     [1 x 1]
+    ///     [1 1 1]
+    /// );
+    ///
+    /// let mut solver = NonogramSolver::default();
+    /// let difficulty = solver.difficulty(puzzle.rules()).unwrap();
+    ///
+    /// assert_eq!(difficulty.guess_depth, 0);
+    /// assert_eq!(difficulty.grade(), DifficultyGrade::Trivial);
+    /// ```
+    pub fn difficulty(&mut self, rules: &Rules) -> Result<NonogramDifficulty, SolverError<String>> {
+        let mut difficulty = NonogramDifficulty::default();
+        let mut grid: Grid<Option<Fill>> = Grid::new(rules.rows(), rules.cols())
+            .map_err(|err| SolverError::CannotFinalize(err.to_string()))?;
+
+        // Simple boxes and spaces only use the rule of a line
+        let empty = Rule::default();
+        let lines: Vec<_> = (0..grid.rows())
+            .map(Line::Row)
+            .chain((0..grid.cols()).map(Line::Col))
+            .collect();
+
+        for &line in &lines {
+            let rule = rules.get(&line).unwrap_or(&empty);
+            let cells = vec![None; grid.line_len(line)];
+            let solved = solve_line(rule, &cells).ok_or(SolverError::Stuck)?;
+
+            for (cell, fill) in grid.iter_line_mut(line).zip(solved) {
+                let Some(fill) = fill else {
+                    continue;
+                };
+
+                match cell {
+                    Some(existing) if *existing != fill => return Err(SolverError::Stuck),
+                    Some(_) => {}
+                    None => {
+                        let technique = match fill {
+                            Fill::Cross => HintReason::SimpleSpaces,
+                            _ => HintReason::SimpleBoxes,
+                        };
+                        *difficulty.techniques.entry(technique).or_default() += 1;
+                        *cell = Some(fill);
+                    }
+                }
+            }
+        }
+
+        // Forcing uses the cells that were deduced so far
+        let unknown = |grid: &Grid<Option<Fill>>| grid.iter().filter(|cell| cell.is_none()).count();
+        let before = unknown(&grid);
+
+        if !self.propagate(rules, &mut grid) {
+            return Err(SolverError::Stuck);
+        }
+
+        let after = unknown(&grid);
+        if before > after {
+            difficulty
+                .techniques
+                .insert(HintReason::Forcing, before - after);
+        }
+
+        // Guess the cells that line logic could not deduce
+        if after > 0 {
+            let nodes = self.nodes();
+            difficulty.guessed_cells = after;
+            difficulty.guess_depth = self.search(rules, &mut grid).ok_or(SolverError::Stuck)?;
+            difficulty.guesses = self.nodes() - nodes - 1;
+        }
+
+        Ok(difficulty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{BenchmarkDifficulty, SolverBenchmark, nonogram};
+
+    #[test]
+    fn corpus() {
+        let mut forced = false;
+
+        for entry in SolverBenchmark::corpus().entries() {
+            let mut solver = NonogramSolver::default();
+            let difficulty = solver.difficulty(entry.nonogram.rules()).unwrap();
+
+            // Only the easy puzzles are solvable without guessing
+            let is_easy = entry.difficulty == BenchmarkDifficulty::Easy;
+            assert_eq!(difficulty.guess_depth == 0, is_easy, "{}", entry.name);
+            assert_eq!(
+                difficulty.grade() < DifficultyGrade::Hard,
+                is_easy,
+                "{}",
+                entry.name
+            );
+
+            forced |= difficulty.hardest_technique() == Some(HintReason::Forcing);
+        }
+
+        assert!(forced, "Some puzzle in the corpus needs forcing");
+    }
+
+    #[test]
+    fn guessing() {
+        // Both diagonals satisfy the rules, so a guess is needed
+        let puzzle = nonogram!(
+            [1 x]
+            [x 1]
+        );
+
+        let mut solver = NonogramSolver::default();
+        let difficulty = solver.difficulty(puzzle.rules()).unwrap();
+
+        assert_eq!(difficulty.guessed_cells, 4);
+        assert_eq!(difficulty.guess_depth, 1);
+        assert_eq!(difficulty.grade(), DifficultyGrade::Hard);
+    }
+
+    #[rstest]
+    #[case::trivial(NonogramDifficulty { techniques: BTreeMap::from([(HintReason::SimpleBoxes, 4)]), ..Default::default() }, DifficultyGrade::Trivial)]
+    #[case::easy(NonogramDifficulty { techniques: BTreeMap::from([(HintReason::SimpleBoxes, 3), (HintReason::Forcing, 1)]), ..Default::default() }, DifficultyGrade::Easy)]
+    #[case::medium(NonogramDifficulty { techniques: BTreeMap::from([(HintReason::SimpleBoxes, 1), (HintReason::Forcing, 3)]), ..Default::default() }, DifficultyGrade::Medium)]
+    #[case::expert(NonogramDifficulty { guessed_cells: 4, guess_depth: 2, guesses: 3, ..Default::default() }, DifficultyGrade::Expert)]
+    fn grades(#[case] difficulty: NonogramDifficulty, #[case] expected: DifficultyGrade) {
+        assert_eq!(difficulty.grade(), expected);
+    }
+}
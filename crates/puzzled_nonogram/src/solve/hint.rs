@@ -0,0 +1,225 @@
+use std::fmt;
+
+use puzzled_core::{Grid, Line, Position};
+
+use crate::{Fill, Nonogram, NonogramSolver, NonogramState, Rule};
+
+/// Kind of reasoning that is needed to deduce a [hint](Hint), ordered from easiest to hardest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HintReason {
+    /// Cell is colored in every placement of the runs in an otherwise empty line, i.e. the runs overlap
+    SimpleBoxes,
+
+    /// Cell cannot be reached by any run of the line, so it is crossed out
+    SimpleSpaces,
+
+    /// Cell is forced by the cells that are already filled in on the line
+    Forcing,
+}
+
+impl fmt::Display for HintReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::SimpleBoxes => "simple boxes",
+            Self::SimpleSpaces => "simple spaces",
+            Self::Forcing => "forcing",
+        };
+
+        write!(f, "{reason}")
+    }
+}
+
+/// Next cell that can be deduced from a single line of a [nonogram](Nonogram), see [`NonogramSolver::hint`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hint {
+    /// Position of the deducible cell
+    pub pos: Position,
+
+    /// Fill that the cell should have
+    pub fill: Fill,
+
+    /// Line from which the cell can be deduced
+    pub line: Line,
+
+    /// Reasoning that deduces the cell
+    pub reason: HintReason,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Position { row, col } = self.pos;
+        let line = match self.line {
+            Line::Row(row) => format!("row {}", row + 1),
+            Line::Col(col) => format!("column {}", col + 1),
+        };
+        let action = match self.fill {
+            Fill::Cross => "crossed out",
+            _ => "filled",
+        };
+
+        write!(
+            f,
+            "Cell ({}, {}) can be {action} from {line} by {}",
+            row + 1,
+            col + 1,
+            self.reason
+        )
+    }
+}
+
+impl NonogramSolver {
+    /// Find the logically easiest cell that can be deduced next from the current entries
+    ///
+    /// Every line is solved with the entries as the known cells and the deduced cells are ranked by their [reason](HintReason).
+    /// Ties are broken by the line that deduces the most cells, such that the hint points to the most productive line.
+    /// Lines whose entries contradict their rule are skipped.
+    ///
+    /// Returns [`None`] if no cell can be deduced without guessing
+    /// ```
+    /// use puzzled::nonogram::{nonogram, HintReason, NonogramSolver, NonogramState, Position};
+    ///
+    /// let puzzle = nonogram!(
+    ///     [x 1 1 1 x]
+    ///     [x x 1 x x]
+    /// );
+    /// let mut state = NonogramState::from(&puzzle);
+    /// state.entries.iter_mut().for_each(|entry| _ = entry.clear());
+    ///
+    /// let mut solver = NonogramSolver::default();
+    /// let hint = solver.hint(&puzzle, &state).unwrap();
+    ///
+    /// assert_eq!(hint.pos, Position::new(0, 2));
+    /// assert_eq!(Some(hint.fill), puzzle[hint.pos].solution);
+    /// assert_eq!(hint.reason, HintReason::SimpleBoxes);
+    /// ```
+    pub fn hint(&mut self, puzzle: &Nonogram, state: &NonogramState) -> Option<Hint> {
+        let known = state.entries().map_ref(|entry| match entry.entry() {
+            None | Some(Fill::Blank) => None,
+            Some(fill) => Some(*fill),
+        });
+
+        let empty = Rule::default();
+        let lines = (0..known.rows())
+            .map(Line::Row)
+            .chain((0..known.cols()).map(Line::Col));
+
+        lines
+            .filter_map(|line| {
+                let rule = puzzle.rules().get(&line).unwrap_or(&empty);
+                self.line_hint(&known, rule, line)
+            })
+            .min_by_key(|(hint, deduced)| (hint.reason, usize::MAX - deduced))
+            .map(|(hint, _)| hint)
+    }
+
+    /// Find the easiest hint on a line together with the number of cells the line deduces
+    fn line_hint(
+        &mut self,
+        known: &Grid<Option<Fill>>,
+        rule: &Rule,
+        line: Line,
+    ) -> Option<(Hint, usize)> {
+        let cells: Vec<_> = known.iter_line(line).copied().collect();
+        let solved = self.cache.solve(rule, &cells)?;
+
+        let is_empty = cells.iter().all(Option::is_none);
+        let deduced: Vec<_> = cells
+            .iter()
+            .zip(solved)
+            .enumerate()
+            .filter_map(|(idx, (cell, fill))| match (cell, fill) {
+                (None, Some(fill)) => Some((idx, fill)),
+                _ => None,
+            })
+            .collect();
+
+        let hint = deduced
+            .iter()
+            .map(|&(idx, fill)| {
+                let reason = match fill {
+                    Fill::Cross => HintReason::SimpleSpaces,
+                    _ if is_empty => HintReason::SimpleBoxes,
+                    _ => HintReason::Forcing,
+                };
+                let pos = match line {
+                    Line::Row(row) => Position::new(row, idx),
+                    Line::Col(col) => Position::new(idx, col),
+                };
+
+                Hint {
+                    pos,
+                    fill,
+                    line,
+                    reason,
+                }
+            })
+            .min_by_key(|hint| hint.reason)?;
+
+        Some((hint, deduced.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Run, nonogram};
+
+    const C: Option<Fill> = Some(Fill::Color(1));
+    const X: Option<Fill> = Some(Fill::Cross);
+    const U: Option<Fill> = None;
+
+    #[rstest]
+    #[case::overlap(4, vec![U, U, U, U, U, U], Some((2, HintReason::SimpleBoxes, 2)))]
+    #[case::spaces(2, vec![U, C, U, U, U], Some((3, HintReason::SimpleSpaces, 2)))]
+    #[case::forcing(2, vec![X, C, U, X], Some((2, HintReason::Forcing, 1)))]
+    #[case::nothing(1, vec![U, U, U], None)]
+    #[case::contradiction(2, vec![U, X, U], None)]
+    fn line_hint(
+        #[case] run: usize,
+        #[case] cells: Vec<Option<Fill>>,
+        #[case] expected: Option<(usize, HintReason, usize)>,
+    ) {
+        let rule = Rule::new(vec![Run::new(Fill::Color(1), run)], cells.len());
+        let known = Grid::from_vec(cells, 1).unwrap().transpose();
+
+        let mut solver = NonogramSolver::default();
+        let hint = solver
+            .line_hint(&known, &rule, Line::Row(0))
+            .map(|(hint, deduced)| (hint.pos.col, hint.reason, deduced));
+
+        assert_eq!(hint, expected);
+    }
+
+    #[test]
+    fn easiest_line() {
+        let puzzle = nonogram!(
+            [1 1 x 1]
+            [x x x x]
+        );
+        let mut state = NonogramState::from(&puzzle);
+        state.entries.iter_mut().for_each(|entry| _ = entry.clear());
+
+        let mut solver = NonogramSolver::default();
+        let hint = solver
+            .hint(&puzzle, &state)
+            .expect("Puzzle has deducible cells");
+
+        assert_eq!(hint.line, Line::Row(0));
+        assert_eq!(hint.pos, Position::new(0, 0));
+        assert_eq!(hint.reason, HintReason::SimpleBoxes);
+    }
+
+    #[test]
+    fn no_hint_when_solved() {
+        let puzzle = nonogram!(
+            [1 x]
+            [x 1]
+        );
+        let state = NonogramState::from(&puzzle);
+
+        let mut solver = NonogramSolver::default();
+        assert_eq!(solver.hint(&puzzle, &state), None);
+    }
+}
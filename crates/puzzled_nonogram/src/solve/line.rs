@@ -0,0 +1,150 @@
+use crate::{Fill, Rule};
+
+/// Deduce as many cells of a line as possible from its rule
+///
+/// All placements of the rule's runs that are consistent with the current `cells` are considered.
+/// A cell is set in the returned line if it has the same fill in every one of those placements, otherwise it keeps its current value.
+/// Unknown cells are represented by [`None`] and empty cells are deduced as [`Fill::Cross`].
+///
+/// Returns [`None`] if the runs cannot be placed in the line at all
+pub fn solve_line(rule: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>> {
+    let runs = rule.runs();
+    let n = cells.len();
+    let m = runs.len();
+
+    let allows = |idx: usize, fill: Fill| cells[idx].is_none_or(|cell| cell == fill);
+
+    // Offset right after placing run r at start (including the gap to the next run) if possible
+    let place = |r: usize, start: usize| -> Option<usize> {
+        let run = runs[r];
+        let end = start + run.count;
+
+        if end > n || !(start..end).all(|idx| allows(idx, run.fill)) {
+            return None;
+        }
+
+        // Leave a gap for adjacent runs of the same fill
+        if r + 1 < m && runs[r + 1].fill == run.fill {
+            return (end < n && allows(end, Fill::Cross)).then_some(end + 1);
+        }
+
+        Some(end)
+    };
+
+    // fits[offset][r]: runs[r..m] fit in cells[offset..n]
+    let mut fits = vec![vec![false; m + 1]; n + 1];
+    fits[n][m] = true;
+
+    for offset in (0..n).rev() {
+        for r in 0..=m {
+            // Option 1: cross out the cell
+            let crossed = allows(offset, Fill::Cross) && fits[offset + 1][r];
+
+            // Option 2: place run r starting at the cell
+            let placed = || r < m && place(r, offset).is_some_and(|next| fits[next][r + 1]);
+
+            fits[offset][r] = crossed || placed();
+        }
+    }
+
+    if !fits[0][0] {
+        return None;
+    }
+
+    // Walk all valid placements from the start of the line and record the fills each cell can take
+    let mut reachable = vec![vec![false; m + 1]; n + 1];
+    reachable[0][0] = true;
+
+    let mut options: Vec<Vec<Fill>> = vec![Vec::new(); n];
+    let mut add = |idx: usize, fill: Fill| {
+        if !options[idx].contains(&fill) {
+            options[idx].push(fill);
+        }
+    };
+
+    for offset in 0..n {
+        for r in 0..=m {
+            if !reachable[offset][r] || !fits[offset][r] {
+                continue;
+            }
+
+            if allows(offset, Fill::Cross) && fits[offset + 1][r] {
+                add(offset, Fill::Cross);
+                reachable[offset + 1][r] = true;
+            }
+
+            if r < m
+                && let Some(next) = place(r, offset)
+                && fits[next][r + 1]
+            {
+                let run = runs[r];
+                let end = offset + run.count;
+
+                (offset..end).for_each(|idx| add(idx, run.fill));
+                (end..next).for_each(|idx| add(idx, Fill::Cross));
+
+                reachable[next][r + 1] = true;
+            }
+        }
+    }
+
+    let line = cells
+        .iter()
+        .zip(options)
+        .map(|(&cell, options)| match options[..] {
+            [fill] => Some(fill),
+            _ => cell,
+        })
+        .collect();
+
+    Some(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Run;
+    use rstest::rstest;
+
+    const X: Option<Fill> = Some(Fill::Cross);
+    const C: Option<Fill> = Some(Fill::Color(1));
+    const C2: Option<Fill> = Some(Fill::Color(2));
+    const U: Option<Fill> = None;
+
+    fn rule(runs: Vec<(Fill, usize)>, line_len: usize) -> Rule {
+        let runs: Vec<Run> = runs.into_iter().map(Run::from).collect();
+
+        Rule::new(runs, line_len)
+    }
+
+    #[rstest]
+    #[case::empty(vec![], vec![U, U, U], vec![X, X, X])]
+    #[case::exact_fit(vec![(Fill::Color(1), 1), (Fill::Color(1), 1)], vec![U, U, U], vec![C, X, C])]
+    #[case::overlap(vec![(Fill::Color(1), 4)], vec![U, U, U, U, U, U], vec![U, U, C, C, U, U])]
+    #[case::no_overlap(vec![(Fill::Color(1), 1)], vec![U, U, U], vec![U, U, U])]
+    #[case::known_cell(vec![(Fill::Color(1), 2)], vec![U, C, U, U, U], vec![U, C, U, X, X])]
+    #[case::colors(vec![(Fill::Color(1), 1), (Fill::Color(2), 2)], vec![U, U, U], vec![C, C2, C2])]
+    fn test_solve_line(
+        #[case] runs: Vec<(Fill, usize)>,
+        #[case] cells: Vec<Option<Fill>>,
+        #[case] expected: Vec<Option<Fill>>,
+    ) {
+        let rule = rule(runs, cells.len());
+        let line = solve_line(&rule, &cells).expect("Line should be solvable");
+
+        assert_eq!(line, expected);
+    }
+
+    #[rstest]
+    #[case::too_long(vec![(Fill::Color(1), 4)], vec![U, U, U])]
+    #[case::crossed(vec![(Fill::Color(1), 2)], vec![U, X, U])]
+    #[case::extra_fill(vec![], vec![U, C, U])]
+    fn test_solve_line_contradiction(
+        #[case] runs: Vec<(Fill, usize)>,
+        #[case] cells: Vec<Option<Fill>>,
+    ) {
+        let rule = rule(runs, cells.len());
+
+        assert_eq!(solve_line(&rule, &cells), None);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::BTreeSet;
+
+use puzzled_core::{Grid, Line, Position};
+
+use crate::{Fill, Nonogram, NonogramSolver, NonogramState, Rule};
+
+/// Mistakes in the current entries of a [nonogram](Nonogram), see [`NonogramSolver::mistakes`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mistakes {
+    /// Lines whose entries contradict their rule, either on their own or together with the entries of the crossing lines
+    pub lines: BTreeSet<Line>,
+
+    /// Entries that are proven wrong by solving the rules with line logic
+    pub cells: BTreeSet<Position>,
+}
+
+impl Mistakes {
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.cells.is_empty()
+    }
+}
+
+impl NonogramSolver {
+    /// Find the lines and cells whose current entries cannot be part of a solution
+    ///
+    /// Every line is first solved with only its own entries as the known cells.
+    /// If none of them contradict their rule, the entries are propagated across the puzzle and the line that first fails to be solved is reported instead,
+    /// as the entries in the lines crossing it are what make it contradict its rule.
+    ///
+    /// Independently of the lines, entries that differ from a cell that line logic deduces from the rules alone are reported as wrong cells.
+    /// Cells that can only be found by guessing are never reported, such that a wrong cell is always a mistake.
+    /// ```
+    /// use puzzled::nonogram::{nonogram, Fill, Line, NonogramSolver, NonogramState, Position, Solve};
+    ///
+    /// let puzzle = nonogram!(
+    ///     [1 1 x]
+    ///     [x 1 1]
+    /// );
+    /// let mut state = NonogramState::from(&puzzle);
+    /// state.entries.iter_mut().for_each(|entry| _ = entry.clear());
+    /// state.enter(&Position::new(0, 1), Fill::Cross);
+    ///
+    /// let mut solver = NonogramSolver::default();
+    /// let mistakes = solver.mistakes(&puzzle, &state);
+    ///
+    /// assert!(mistakes.lines.contains(&Line::Row(0)));
+    /// assert!(mistakes.lines.contains(&Line::Col(1)));
+    /// assert!(mistakes.cells.contains(&Position::new(0, 1)));
+    /// ```
+    pub fn mistakes(&mut self, puzzle: &Nonogram, state: &NonogramState) -> Mistakes {
+        let rules = puzzle.rules();
+        let known = state.entries().map_ref(|entry| match entry.entry() {
+            None | Some(Fill::Blank) => None,
+            Some(fill) => Some(*fill),
+        });
+
+        let empty = Rule::default();
+        let mut lines: BTreeSet<_> = (0..known.rows())
+            .map(Line::Row)
+            .chain((0..known.cols()).map(Line::Col))
+            .filter(|&line| {
+                let rule = rules.get(&line).unwrap_or(&empty);
+                let cells: Vec<_> = known.iter_line(line).copied().collect();
+
+                self.cache.solve(rule, &cells).is_none()
+            })
+            .collect();
+
+        if lines.is_empty()
+            && let Some(line) = self.contradiction(rules, &mut known.clone())
+        {
+            lines.insert(line);
+        }
+
+        // Only compare with the cells that follow from the rules, as the entries may lead to another solution
+        let mut deduced = known.map_ref(|_| None);
+        let cells = match self.contradiction(rules, &mut deduced) {
+            Some(_) => BTreeSet::new(),
+            None => wrong_cells(&known, &deduced),
+        };
+
+        Mistakes { lines, cells }
+    }
+}
+
+fn wrong_cells(known: &Grid<Option<Fill>>, deduced: &Grid<Option<Fill>>) -> BTreeSet<Position> {
+    known
+        .iter_indexed()
+        .filter_map(|(pos, entry)| match (entry, deduced[pos]) {
+            (Some(entry), Some(fill)) if *entry != fill => Some(pos),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Solve;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::nonogram;
+
+    #[rstest]
+    #[case::none(vec![], vec![], vec![])]
+    #[case::single_line(
+        vec![(0, 1, 'x')],
+        vec![Line::Row(0)],
+        vec![(0, 1)]
+    )]
+    #[case::crossing_lines(
+        vec![(0, 0, '1'), (1, 2, 'x')],
+        vec![Line::Col(0)],
+        vec![]
+    )]
+    fn mistakes(
+        #[case] entries: Vec<(usize, usize, char)>,
+        #[case] lines: Vec<Line>,
+        #[case] cells: Vec<(usize, usize)>,
+    ) {
+        // Both the first and last column can be filled in the first row, such that line logic cannot deduce them
+        let puzzle = nonogram!(
+            [1 1 x]
+            [x 1 1]
+            [x x x]
+        );
+        let mut state = NonogramState::from(&puzzle);
+        state.entries.iter_mut().for_each(|entry| _ = entry.clear());
+
+        for (row, col, fill) in entries {
+            let fill = Fill::decode_char(fill).unwrap();
+            state.enter(&Position::new(row, col), fill);
+        }
+
+        let mut solver = NonogramSolver::default();
+        let mistakes = solver.mistakes(&puzzle, &state);
+
+        let cells: BTreeSet<_> = cells
+            .into_iter()
+            .map(|(row, col)| Position::new(row, col))
+            .collect();
+
+        assert_eq!(mistakes.lines, lines.into_iter().collect());
+        assert_eq!(mistakes.cells, cells);
+    }
+}
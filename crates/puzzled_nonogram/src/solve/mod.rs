@@ -1,9 +1,27 @@
+mod benchmark;
+mod cache;
 mod constraints;
+mod difficulty;
+mod hint;
+mod line;
+mod mistakes;
 mod solver;
 mod state;
+mod strategy;
+mod trace;
+mod uniqueness;
 mod validate;
 
+pub use benchmark::*;
+pub use cache::*;
 pub use constraints::*;
+pub use difficulty::*;
+pub use hint::*;
+pub use line::*;
+pub use mistakes::*;
 pub use solver::*;
 pub use state::*;
+pub use strategy::*;
+pub use trace::*;
+pub use uniqueness::*;
 pub use validate::*;
@@ -1,2 +1,437 @@
-#[derive(Debug, Default)]
-pub struct NonogramSolver {}
+use std::collections::{BTreeMap, VecDeque};
+
+use puzzled_core::{BitGrid, Grid, Line, Position, Solve, Solver, SolverError, SolverStats};
+
+use crate::{
+    CompleteLine, Fill, LineCache, LineStrategy, Nonogram, NonogramState, Rule, Rules,
+    ShapedNonogram, SolveTrace, SquareTopology, Topology, TraceStep,
+    solve::strategy::apply_strategies,
+};
+
+/// Fills of a solved [shaped nonogram](ShapedNonogram) together with the [trace](SolveTrace) of the solve
+type TracedSolve<L> = (Grid<Option<Fill>>, SolveTrace<L>);
+
+/// Line-based nonogram solver that falls back to backtracking when line logic gets stuck
+///
+/// Every line is solved by applying its [strategies](LineStrategy) in order, which by default only deduces [complete lines](CompleteLine).
+/// Solved lines are memoized in a [cache](LineCache), whose effectiveness can be inspected through [`Solver::stats`]
+#[derive(Debug)]
+pub struct NonogramSolver {
+    pub(crate) nodes: usize,
+    line_solves: usize,
+    pub(crate) cache: LineCache,
+    strategies: Vec<Box<dyn LineStrategy>>,
+    trace: Option<SolveTrace>,
+}
+
+impl Default for NonogramSolver {
+    fn default() -> Self {
+        Self {
+            nodes: 0,
+            line_solves: 0,
+            cache: LineCache::default(),
+            strategies: vec![Box::new(CompleteLine)],
+            trace: None,
+        }
+    }
+}
+
+impl Solver<Nonogram, NonogramState> for NonogramSolver {
+    type Error = SolverError<String>;
+
+    fn solve(
+        &mut self,
+        puzzle: &Nonogram,
+        state: &mut NonogramState,
+    ) -> Result<Grid<Fill>, Self::Error> {
+        let mut grid = puzzle.fills().map_ref(|_| None);
+
+        if let Some(trace) = &mut self.trace {
+            *trace = SolveTrace::new();
+        }
+
+        if self.search(puzzle.rules(), &mut grid).is_none() {
+            return Err(SolverError::Stuck);
+        }
+
+        for (pos, fill) in grid.iter_indexed() {
+            if let Some(fill) = fill {
+                state.solve(&pos, *fill);
+            }
+        }
+
+        self.try_finalize(state)
+    }
+
+    fn try_finalize(&self, state: &NonogramState) -> Result<Grid<Fill>, Self::Error> {
+        let solutions = state.solutions();
+        let fills: Option<Vec<_>> = solutions.iter().copied().collect();
+
+        let fills = fills.ok_or_else(|| {
+            SolverError::CannotFinalize("Expected all fills to be set in the solution".to_string())
+        })?;
+
+        Grid::from_vec(fills, solutions.cols())
+            .map_err(|err| SolverError::CannotFinalize(err.to_string()))
+    }
+
+    fn stats(&self) -> SolverStats {
+        SolverStats {
+            nodes: self.nodes,
+            propagations: self.line_solves,
+            cache_hits: self.cache.hits(),
+            cache_misses: self.cache.misses(),
+        }
+    }
+}
+
+impl NonogramSolver {
+    /// Limit the number of solved lines that are [cached](LineCache), where 0 disables caching
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = LineCache::new(capacity);
+        self
+    }
+
+    /// Solve lines with the given strategies in order instead of the current ones
+    ///
+    /// Lines that every strategy gets stuck on are left for guessing, such that the solver still finds a solution with incomplete strategies.
+    pub fn with_strategies<I>(mut self, strategies: I) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn LineStrategy>>,
+    {
+        self.strategies = strategies.into_iter().collect();
+        self.cache.clear();
+        self
+    }
+
+    /// Apply the given strategy after the current ones
+    pub fn with_strategy<S>(mut self, strategy: S) -> Self
+    where
+        S: LineStrategy + 'static,
+    {
+        self.strategies.push(Box::new(strategy));
+        self.cache.clear();
+        self
+    }
+
+    pub fn strategies(&self) -> &[Box<dyn LineStrategy>] {
+        &self.strategies
+    }
+
+    /// Record every step of the next [solve](Solver::solve) into a [trace](SolveTrace)
+    ///
+    /// Tracing bypasses the [cache](LineCache), as cached lines do not know which strategies deduced their cells.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(SolveTrace::new());
+        self
+    }
+
+    /// Trace of the last solve, or [`None`] if tracing is disabled
+    pub fn trace(&self) -> Option<&SolveTrace> {
+        self.trace.as_ref()
+    }
+
+    pub fn cache(&self) -> &LineCache {
+        &self.cache
+    }
+
+    /// Number of search nodes visited since the solver was created
+    ///
+    /// Every (partial) grid the solver propagates counts as a node, so a puzzle that is solvable by line logic alone takes a single node
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Number of times a single line was solved since the solver was created
+    pub fn line_solves(&self) -> usize {
+        self.line_solves
+    }
+
+    /// Solve a [nonogram of any shape](ShapedNonogram), returning its fills where positions outside of its [topology](Topology) are [`None`]
+    ///
+    /// The steps are not recorded in the [trace](Self::trace) of the solver, as its lines are those of a square grid.
+    /// Use [`solve_shaped_with_trace`](Self::solve_shaped_with_trace) to trace the lines of the topology.
+    pub fn solve_shaped<T: Topology>(
+        &mut self,
+        puzzle: &ShapedNonogram<T>,
+    ) -> Result<Grid<Option<Fill>>, SolverError<String>> {
+        self.solve_shaped_in(puzzle, None)
+    }
+
+    /// Solve a [nonogram of any shape](ShapedNonogram) like [`solve_shaped`](Self::solve_shaped), together with the [trace](SolveTrace) of every step in the lines of its [topology](Topology)
+    ///
+    /// Like any traced solve, the [cache](LineCache) is bypassed.
+    pub fn solve_shaped_with_trace<T: Topology>(
+        &mut self,
+        puzzle: &ShapedNonogram<T>,
+    ) -> Result<TracedSolve<T::Line>, SolverError<String>> {
+        let mut trace = SolveTrace::new();
+        let grid = self.solve_shaped_in(puzzle, Some(&mut trace))?;
+
+        Ok((grid, trace))
+    }
+
+    fn solve_shaped_in<T: Topology>(
+        &mut self,
+        puzzle: &ShapedNonogram<T>,
+        trace: Option<&mut SolveTrace<T::Line>>,
+    ) -> Result<Grid<Option<Fill>>, SolverError<String>> {
+        let topology = puzzle.topology();
+        let mut grid = Grid::new(topology.rows(), topology.cols())
+            .map_err(|err| SolverError::CannotFinalize(err.to_string()))?;
+        let index = LineIndex::new(topology, &grid);
+
+        self.search_in(topology, &index, puzzle.rules(), &mut grid, trace)
+            .ok_or(SolverError::Stuck)?;
+
+        Ok(grid)
+    }
+
+    /// Search for a solution, returning how many nested guesses were needed to reach it
+    pub(crate) fn search(&mut self, rules: &Rules, grid: &mut Grid<Option<Fill>>) -> Option<usize> {
+        let topology = SquareTopology::new(grid.rows(), grid.cols());
+        let index = LineIndex::new(&topology, grid);
+        let mut trace = self.trace.take();
+
+        let depth = self.search_in(&topology, &index, rules, grid, trace.as_mut());
+        self.trace = trace;
+
+        depth
+    }
+
+    fn search_in<T: Topology>(
+        &mut self,
+        topology: &T,
+        index: &LineIndex<T::Line>,
+        rules: &BTreeMap<T::Line, Rule>,
+        grid: &mut Grid<Option<Fill>>,
+        mut trace: Option<&mut SolveTrace<T::Line>>,
+    ) -> Option<usize> {
+        self.nodes += 1;
+
+        if self
+            .contradiction_in(index, rules, grid, trace.as_deref_mut())
+            .is_some()
+        {
+            return None;
+        }
+
+        // Guess the first cell that could not be deduced
+        let Some(pos) = topology.positions().find(|&pos| grid[pos].is_none()) else {
+            return Some(0);
+        };
+
+        for fill in candidates_in(topology, rules, pos) {
+            let mut guess = grid.clone();
+            guess[pos] = Some(fill);
+
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(TraceStep::Guess { pos, fill });
+            }
+
+            if let Some(depth) =
+                self.search_in(topology, index, rules, &mut guess, trace.as_deref_mut())
+            {
+                *grid = guess;
+                return Some(depth + 1);
+            }
+
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(TraceStep::Backtrack { pos });
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn propagate(&mut self, rules: &Rules, grid: &mut Grid<Option<Fill>>) -> bool {
+        self.contradiction(rules, grid).is_none()
+    }
+
+    /// Deduce as many cells as possible with line logic, returning the first line that cannot be solved anymore
+    pub(crate) fn contradiction(
+        &mut self,
+        rules: &Rules,
+        grid: &mut Grid<Option<Fill>>,
+    ) -> Option<Line> {
+        let topology = SquareTopology::new(grid.rows(), grid.cols());
+        let index = LineIndex::new(&topology, grid);
+
+        self.contradiction_in(&index, rules, grid, None)
+    }
+
+    fn contradiction_in<L: Copy + Ord>(
+        &mut self,
+        index: &LineIndex<L>,
+        rules: &BTreeMap<L, Rule>,
+        grid: &mut Grid<Option<Fill>>,
+        mut trace: Option<&mut SolveTrace<L>>,
+    ) -> Option<L> {
+        let empty = Rule::default();
+
+        // Keep track of the deduced cells to find the lines that are completely known
+        let mut known = BitGrid::new(grid.rows(), grid.cols()).expect("Grid has a valid size");
+        for (pos, cell) in grid.iter_indexed() {
+            known.set(pos, cell.is_some());
+        }
+
+        let mut frontier = VecDeque::new();
+
+        // Only validate lines that are already known, e.g. by guesses, but revisit them as soon as a crossing line deduces one of their cells
+        for &line in &index.lines {
+            let positions = &index.positions[&line];
+            if !is_full(&known, positions) {
+                frontier.push_back(line);
+                continue;
+            }
+
+            let rule = rules.get(&line).unwrap_or(&empty);
+
+            if violates(rule, positions.iter().map(|&pos| grid[pos])) {
+                if let Some(trace) = trace {
+                    trace.push(TraceStep::Contradiction { line });
+                }
+
+                return Some(line);
+            }
+        }
+
+        while let Some(line) = frontier.pop_front() {
+            self.line_solves += 1;
+
+            let rule = rules.get(&line).unwrap_or(&empty);
+            let positions = &index.positions[&line];
+            let cells: Vec<_> = positions.iter().map(|&pos| grid[pos]).collect();
+
+            let solved = match is_full(&known, positions) {
+                // Lines that crossing lines completed are validated, as strategies may miss their contradictions
+                true => (!violates(rule, cells.iter().copied())).then_some(cells),
+                false => match trace.as_deref_mut() {
+                    Some(trace) => apply_strategies(
+                        &mut self.strategies,
+                        rule,
+                        &cells,
+                        |strategy, before, after| {
+                            let cells: Vec<_> = positions
+                                .iter()
+                                .zip(before.iter().zip(after))
+                                .filter_map(|(&pos, (before, after))| match (before, after) {
+                                    (None, &Some(fill)) => Some((pos, fill)),
+                                    _ => None,
+                                })
+                                .collect();
+
+                            if !cells.is_empty() {
+                                let strategy = strategy.to_string();
+                                trace.push(TraceStep::Deduction {
+                                    line,
+                                    strategy,
+                                    cells,
+                                });
+                            }
+                        },
+                    ),
+                    None => {
+                        let strategies = &mut self.strategies;
+
+                        self.cache.solve_with(rule, &cells, |rule, cells| {
+                            apply_strategies(strategies, rule, cells, |_, _, _| {})
+                        })
+                    }
+                },
+            };
+
+            let Some(solved) = solved else {
+                if let Some(trace) = trace {
+                    trace.push(TraceStep::Contradiction { line });
+                }
+
+                return Some(line);
+            };
+
+            // Revisit the crossing lines of every newly deduced cell
+            for (&pos, fill) in positions.iter().zip(solved) {
+                if grid[pos] == fill {
+                    continue;
+                }
+
+                grid[pos] = fill;
+                known.set(pos, fill.is_some());
+
+                for &crossing in &index.through[pos] {
+                    if crossing != line && !frontier.contains(&crossing) {
+                        frontier.push_back(crossing);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Positions of every line of a [topology](Topology) and the lines through every position, which are looked up for every line that is solved
+struct LineIndex<L> {
+    lines: Vec<L>,
+    positions: BTreeMap<L, Vec<Position>>,
+    through: Grid<Vec<L>>,
+}
+
+impl<L: Copy + Ord> LineIndex<L> {
+    fn new<T: Topology<Line = L>>(topology: &T, grid: &Grid<Option<Fill>>) -> Self {
+        let lines = topology.lines();
+        let positions = lines
+            .iter()
+            .map(|&line| (line, topology.line_positions(line)))
+            .collect();
+        let through = grid.map_ref_indexed(|pos, _| match topology.contains(pos) {
+            true => topology.lines_through(pos),
+            false => Vec::new(),
+        });
+
+        Self {
+            lines,
+            positions,
+            through,
+        }
+    }
+}
+
+/// Whether the bit of every position is set
+fn is_full(bits: &BitGrid, positions: &[Position]) -> bool {
+    positions.iter().all(|&pos| bits.get(pos) == Some(true))
+}
+
+/// Whether a line that is completely known violates its rule
+fn violates(rule: &Rule, cells: impl Iterator<Item = Option<Fill>>) -> bool {
+    let fills: Vec<_> = cells.map(|cell| cell.expect("Line is known")).collect();
+
+    !rule.is_satisfied_by(fills)
+}
+
+pub(crate) fn candidates(rules: &Rules, pos: Position) -> Vec<Fill> {
+    let topology = SquareTopology::new(rules.rows(), rules.cols());
+
+    candidates_in(&topology, rules, pos)
+}
+
+/// Fills to guess for a cell, which are the colors of the first line through it followed by a cross
+fn candidates_in<T: Topology>(
+    topology: &T,
+    rules: &BTreeMap<T::Line, Rule>,
+    pos: Position,
+) -> Vec<Fill> {
+    let mut fills = vec![];
+
+    let line = topology.lines_through(pos).into_iter().next();
+    if let Some(rule) = line.and_then(|line| rules.get(&line)) {
+        for run in rule.runs() {
+            if !fills.contains(&run.fill) {
+                fills.push(run.fill);
+            }
+        }
+    }
+
+    fills.push(Fill::Cross);
+    fills
+}
@@ -1,13 +1,12 @@
 use std::collections::{BTreeMap, VecDeque};
 
-use bitvec::{bitvec, vec::BitVec};
+use delegate::delegate;
 use derive_more::{Deref, DerefMut};
-use puzzled_core::{Entry, Grid, GridState, Line, LinePosition, Timer};
+use puzzled_core::{Entry, Grid, GridState, Line, LinePosition, Position, Solve, Timer};
 
-use crate::{Fill, LineMaskConstraint, LineValidation, Nonogram};
+use crate::{Fill, LineMask, LineMaskConstraint, LineValidation, Nonogram};
 
 pub(crate) type LineMap<T> = BTreeMap<Line, T>;
-pub(crate) type LineMask = BitVec;
 
 #[derive(Debug, Deref, DerefMut)]
 pub struct NonogramState {
@@ -104,8 +103,7 @@ impl NonogramState {
         }
 
         // Set the current fill
-        let empty_mask = bitvec![0; line_len];
-        let mask = masks.entry(curr).or_insert(empty_mask);
+        let mask = masks.entry(curr).or_insert_with(|| LineMask::new(line_len));
 
         mask.set(pos, true);
     }
@@ -137,7 +135,7 @@ impl NonogramState {
             };
 
             // Fill is invalid if it's not placed on one of the optional cells
-            if !(optional.clone() & mask).any() {
+            if !optional.intersection(mask).any() {
                 tracing::info!("Invalid fill for {line:?}");
                 tracing::info!("\tRequired bits: {required}");
                 tracing::info!("\tOptional bits: {optional}");
@@ -163,29 +161,19 @@ impl From<&Nonogram> for NonogramState {
     }
 }
 
-// impl Solve for NonogramState {
-//     type Puzzle = Nonogram;
-//     type Value = Fill;
-//     type Position = Position;
-//     type Error = String;
-//
-//     delegate! {
-//         to self.state {
-//             fn solve(&mut self, pos: &Self::Position, solution: Self::Value) -> bool;
-//             fn enter(&mut self, pos: &Self::Position, entry: Self::Value) -> bool;
-//             fn reveal(&mut self, pos: &Self::Position) -> bool;
-//             fn check(&mut self, pos: &Self::Position) -> Option<bool>;
-//
-//             fn reveal_all(&mut self);
-//             fn check_all(&mut self);
-//
-//             fn enter_checked(&mut self, pos: &Self::Position, entry: Self::Value) -> Option<bool>;
-//
-//             fn guess(&mut self, pos: &Self::Position, guess: Self::Value) -> bool;
-//
-//             fn guess_checked(&mut self, pos: &Self::Position, guess: Self::Value) -> Option<bool>;
-//
-//             fn try_finalize(&self) -> Result<Grid<Fill>, Self::Error>;
-//         }
-//     }
-// }
+impl Solve<Nonogram> for NonogramState {
+    delegate! {
+        to self.state {
+            fn solution(&self, pos: &Position) -> Option<&Fill>;
+            fn entry(&self, pos: &Position) -> Option<&Fill>;
+
+            fn solve(&mut self, pos: &Position, solution: Fill) -> bool;
+            fn enter(&mut self, pos: &Position, entry: Fill) -> bool;
+            fn clear(&mut self, pos: &Position) -> bool;
+            fn reveal(&mut self, pos: &Position) -> bool;
+            fn check(&mut self, pos: &Position) -> Option<bool>;
+
+            fn guess(&mut self, pos: &Position, guess: Fill) -> bool;
+        }
+    }
+}
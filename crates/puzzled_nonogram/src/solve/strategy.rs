@@ -0,0 +1,260 @@
+use std::fmt;
+
+use crate::{Fill, Rule, solve_line};
+
+/// Technique that deduces cells of a single line from its rule, which the [solver](crate::NonogramSolver) applies to every line it propagates
+///
+/// Strategies receive the current cells of the line, where unknown cells are [`None`], and return the line with the cells they could deduce.
+/// Known cells should be kept as they are, and [`None`] should be returned if the rule cannot be satisfied at all.
+/// A strategy does not have to be complete: the solver passes the line on to its next strategy and falls back to guessing when all of them get stuck.
+/// ```
+/// use puzzled::nonogram::{
+///     nonogram, Fill, LineStrategy, NonogramSolver, NonogramState, Overlap, Rule, Solver,
+/// };
+///
+/// /// Cross out every cell of a line without runs
+/// #[derive(Debug)]
+/// struct EmptyLine;
+///
+/// impl LineStrategy for EmptyLine {
+///     fn deduce(&mut self, rule: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>> {
+///         if !rule.is_empty() {
+///             return Some(cells.to_vec());
+///         }
+///
+///         cells
+///             .iter()
+///             .map(|&cell| match cell {
+///                 Some(Fill::Color(_)) => None,
+///                 _ => Some(Some(Fill::Cross)),
+///             })
+///             .collect()
+///     }
+/// }
+///
+/// let puzzle = nonogram!(
+///     [1 1 1]
+///     [x x x]
+///     [x 1 1]
+/// );
+/// let mut solver = NonogramSolver::default().with_strategies([
+///     Box::new(EmptyLine) as Box<dyn LineStrategy>,
+///     Box::new(Overlap),
+/// ]);
+///
+/// let mut state = NonogramState::from(&puzzle);
+/// assert!(solver.solve(&puzzle, &mut state).is_ok());
+/// ```
+pub trait LineStrategy: fmt::Debug {
+    /// Deduce cells of the line, returning [`None`] if the cells contradict the rule
+    fn deduce(&mut self, rule: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>>;
+
+    /// Name of the strategy in a [trace](crate::SolveTrace), which defaults to the name of its type without its path and generics
+    fn name(&self) -> &str {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+
+        name.rsplit("::").next().unwrap_or(name)
+    }
+}
+
+/// Deduce every cell that has the same fill in all placements of the runs, see [`solve_line`]
+///
+/// This is the default strategy of the [solver](crate::NonogramSolver), as it deduces as much as possible from a single line.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CompleteLine;
+
+impl LineStrategy for CompleteLine {
+    fn deduce(&mut self, rule: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>> {
+        solve_line(rule, cells)
+    }
+}
+
+/// Fill the cells that a run covers both when all runs are pushed to the start and to the end of the line
+///
+/// This is the classic first step of solving a nonogram by hand, which ignores the known cells of the line apart from checking that they agree.
+/// Lines without runs are crossed out entirely.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Overlap;
+
+impl LineStrategy for Overlap {
+    fn deduce(&mut self, rule: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>> {
+        let len = cells.len();
+        if rule.len() > len {
+            return None;
+        }
+
+        let mut line = cells.to_vec();
+        let mut deduce = |idx: usize, fill: Fill| match line[idx] {
+            None => {
+                line[idx] = Some(fill);
+                true
+            }
+            Some(cell) => cell == fill,
+        };
+
+        if rule.is_empty() {
+            return (0..len).all(|idx| deduce(idx, Fill::Cross)).then_some(line);
+        }
+
+        // Start of every run when the runs are packed at the start of the line
+        let mut starts = Vec::with_capacity(rule.runs().len());
+        let mut offset = 0;
+
+        for (idx, run) in rule.runs().iter().enumerate() {
+            if idx > 0 && rule.runs()[idx - 1].fill == run.fill {
+                offset += 1;
+            }
+
+            starts.push(offset);
+            offset += run.count;
+        }
+
+        // Packing the runs at the end moves every run by the same amount of slack
+        let slack = len - rule.len();
+
+        for (run, start) in rule.runs().iter().zip(starts) {
+            let overlap = (start + slack)..(start + run.count);
+
+            if !overlap.into_iter().all(|idx| deduce(idx, run.fill)) {
+                return None;
+            }
+        }
+
+        Some(line)
+    }
+}
+
+/// Apply the strategies in order until the line is complete, after which it is verified against its rule
+///
+/// After every strategy, `observe` is called with its name and the line before and after applying it.
+pub(crate) fn apply_strategies<F>(
+    strategies: &mut [Box<dyn LineStrategy>],
+    rule: &Rule,
+    cells: &[Option<Fill>],
+    mut observe: F,
+) -> Option<Vec<Option<Fill>>>
+where
+    F: FnMut(&str, &[Option<Fill>], &[Option<Fill>]),
+{
+    let mut line = cells.to_vec();
+
+    for strategy in strategies {
+        if line.iter().all(Option::is_some) {
+            break;
+        }
+
+        let deduced = strategy.deduce(rule, &line)?;
+        observe(strategy.name(), &line, &deduced);
+
+        line = deduced;
+    }
+
+    // Incomplete strategies may not notice that the line contradicts its rule
+    if line.iter().all(Option::is_some) {
+        let fills = line.iter().map(|cell| cell.unwrap_or_default());
+
+        if !rule.is_satisfied_by(fills) {
+            return None;
+        }
+    }
+
+    Some(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use puzzled_core::{Cell, Solver};
+
+    use crate::{NonogramSolver, NonogramState, Rules, Run, nonogram};
+
+    const X: Option<Fill> = Some(Fill::Cross);
+    const C: Option<Fill> = Some(Fill::Color(1));
+    const C2: Option<Fill> = Some(Fill::Color(2));
+    const U: Option<Fill> = None;
+
+    fn rule(runs: Vec<(Fill, usize)>, line_len: usize) -> Rule {
+        Rule::new(runs.into_iter().map(Run::from).collect(), line_len)
+    }
+
+    #[rstest]
+    #[case::empty(vec![], vec![U, U, U], Some(vec![X, X, X]))]
+    #[case::overlap(vec![(Fill::Color(1), 4)], vec![U, U, U, U, U, U], Some(vec![U, U, C, C, U, U]))]
+    #[case::exact_fit(vec![(Fill::Color(1), 1), (Fill::Color(1), 1)], vec![U, U, U], Some(vec![C, U, C]))]
+    #[case::colors(vec![(Fill::Color(1), 2), (Fill::Color(2), 2)], vec![U, U, U, U, U], Some(vec![U, C, U, C2, U]))]
+    #[case::too_long(vec![(Fill::Color(1), 4)], vec![U, U, U], None)]
+    #[case::conflict(vec![(Fill::Color(1), 3)], vec![U, X, U, U], None)]
+    fn overlap(
+        #[case] runs: Vec<(Fill, usize)>,
+        #[case] cells: Vec<Option<Fill>>,
+        #[case] expected: Option<Vec<Option<Fill>>>,
+    ) {
+        let rule = rule(runs, cells.len());
+
+        assert_eq!(Overlap.deduce(&rule, &cells), expected);
+    }
+
+    /// Strategy that never deduces anything
+    #[derive(Debug)]
+    struct Nothing;
+
+    impl LineStrategy for Nothing {
+        fn deduce(&mut self, _: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>> {
+            Some(cells.to_vec())
+        }
+    }
+
+    /// Strategy that wraps another one, to give a type with generics
+    #[derive(Debug)]
+    struct Wrapped<S>(S);
+
+    impl<S: LineStrategy> LineStrategy for Wrapped<S> {
+        fn deduce(&mut self, rule: &Rule, cells: &[Option<Fill>]) -> Option<Vec<Option<Fill>>> {
+            self.0.deduce(rule, cells)
+        }
+    }
+
+    #[rstest]
+    #[case::plain(&Overlap, "Overlap")]
+    #[case::generic(&Wrapped(Overlap), "Wrapped")]
+    fn name(#[case] strategy: &dyn LineStrategy, #[case] expected: &str) {
+        assert_eq!(strategy.name(), expected);
+    }
+
+    #[test]
+    fn verifies_complete_lines() {
+        let rule = rule(vec![(Fill::Color(1), 2)], 3);
+        let mut strategies: Vec<Box<dyn LineStrategy>> = vec![Box::new(Nothing)];
+
+        assert_eq!(
+            apply_strategies(&mut strategies, &rule, &[C, X, C], |_, _, _| {}),
+            None
+        );
+        assert_eq!(
+            apply_strategies(&mut strategies, &rule, &[C, C, X], |_, _, _| {}),
+            Some(vec![C, C, X])
+        );
+    }
+
+    #[rstest]
+    #[case::nothing(NonogramSolver::default().with_strategies([]).with_strategy(Nothing))]
+    #[case::overlap(NonogramSolver::default().with_strategies([]).with_strategy(Overlap))]
+    #[case::ordered(NonogramSolver::default().with_strategies([]).with_strategy(Overlap).with_strategy(CompleteLine))]
+    fn solves_with(#[case] mut solver: NonogramSolver) {
+        let puzzle = nonogram!(
+            [1 1 x 2]
+            [x 1 2 2]
+            [1 x x 1]
+        );
+
+        let solution = solver
+            .solve(&puzzle, &mut NonogramState::from(&puzzle))
+            .unwrap();
+
+        let solved = Rules::from_fills(&solution.map(|fill| Cell::new(Some(fill))));
+        assert_eq!(&solved, puzzle.rules());
+    }
+}
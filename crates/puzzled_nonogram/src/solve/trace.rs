@@ -0,0 +1,215 @@
+use puzzled_core::{Grid, Line, Position};
+
+use crate::Fill;
+
+/// Single step that the [solver](crate::NonogramSolver) took while solving a nonogram
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum TraceStep<L = Line> {
+    /// A [strategy](crate::LineStrategy) deduced cells of a line from its rule
+    Deduction {
+        line: L,
+        strategy: String,
+        cells: Vec<(Position, Fill)>,
+    },
+
+    /// Every line got stuck, so the solver guessed the fill of a cell
+    Guess { pos: Position, fill: Fill },
+
+    /// A line can no longer satisfy its rule, such that the current guess is wrong
+    Contradiction { line: L },
+
+    /// The guess of the cell led to a contradiction, undoing every step since that guess
+    Backtrack { pos: Position },
+}
+
+/// Log of every deduction, guess and backtrack of a solve, which can be replayed to step through the logical solution
+///
+/// Traces are recorded by a solver [with tracing enabled](crate::NonogramSolver::with_trace) and serialized with the `serde` feature.
+/// ```
+/// use puzzled::nonogram::{nonogram, NonogramSolver, NonogramState, Solver, TraceStep};
+///
+/// let puzzle = nonogram!(
+///     [1 1 1]
+///     [x x 1]
+/// );
+///
+/// let mut solver = NonogramSolver::default().with_trace();
+/// let solution = solver.solve(&puzzle, &mut NonogramState::from(&puzzle))?;
+///
+/// let trace = solver.trace().unwrap();
+/// assert!(trace.steps().iter().all(|step| matches!(step, TraceStep::Deduction { .. })));
+///
+/// let grids = trace.replay(puzzle.fills().map_ref(|_| None));
+/// assert_eq!(grids.last().unwrap(), &solution.map(Some));
+/// # Ok::<(), puzzled::nonogram::SolverError<String>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SolveTrace<L = Line> {
+    steps: Vec<TraceStep<L>>,
+}
+
+impl<L> Default for SolveTrace<L> {
+    fn default() -> Self {
+        Self { steps: Vec::new() }
+    }
+}
+
+impl<L> SolveTrace<L> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn steps(&self) -> &[TraceStep<L>] {
+        &self.steps
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn push(&mut self, step: TraceStep<L>) {
+        self.steps.push(step);
+    }
+
+    /// Number of guesses, including the ones that were backtracked
+    pub fn guesses(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step, TraceStep::Guess { .. }))
+            .count()
+    }
+
+    /// Apply the steps to the starting grid of the solve, giving the grid after every step
+    ///
+    /// Backtracking restores the grid from right before the guess that it undoes.
+    pub fn replay(&self, start: Grid<Option<Fill>>) -> Vec<Grid<Option<Fill>>> {
+        let mut grid = start;
+        let mut guesses = Vec::new();
+        let mut grids = Vec::with_capacity(self.steps.len());
+
+        for step in &self.steps {
+            match step {
+                TraceStep::Deduction { cells, .. } => {
+                    for &(pos, fill) in cells {
+                        grid[pos] = Some(fill);
+                    }
+                }
+                TraceStep::Guess { pos, fill } => {
+                    guesses.push(grid.clone());
+                    grid[*pos] = Some(*fill);
+                }
+                TraceStep::Contradiction { .. } => {}
+                TraceStep::Backtrack { .. } => {
+                    if let Some(before) = guesses.pop() {
+                        grid = before;
+                    }
+                }
+            }
+
+            grids.push(grid.clone());
+        }
+
+        grids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Solver;
+
+    use super::*;
+    use crate::{NonogramSolver, NonogramState, nonogram};
+
+    /// Puzzle with two solutions, which line logic alone cannot solve
+    fn puzzle() -> crate::Nonogram {
+        nonogram!(
+            [1 x]
+            [x 1]
+        )
+    }
+
+    #[test]
+    fn guesses() {
+        let puzzle = puzzle();
+        let mut solver = NonogramSolver::default().with_trace();
+        let solution = solver
+            .solve(&puzzle, &mut NonogramState::from(&puzzle))
+            .unwrap();
+
+        let trace = solver.trace().unwrap();
+        assert_eq!(trace.guesses(), 1);
+        assert!(matches!(trace.steps()[0], TraceStep::Guess { .. }));
+        assert!(
+            matches!(&trace.steps()[1], TraceStep::Deduction { strategy, .. } if strategy == "CompleteLine")
+        );
+
+        let grids = trace.replay(puzzle.fills().map_ref(|_| None));
+        assert_eq!(grids.len(), trace.len());
+        assert_eq!(grids.last(), Some(&solution.map(Some)));
+    }
+
+    #[test]
+    fn backtracks() {
+        let mut trace = SolveTrace::new();
+        let pos = Position::new(0, 0);
+        let other = Position::new(0, 1);
+
+        trace.push(TraceStep::Guess {
+            pos,
+            fill: Fill::Cross,
+        });
+        trace.push(TraceStep::Deduction {
+            line: Line::Row(0),
+            strategy: "CompleteLine".to_string(),
+            cells: vec![(other, Fill::Color(1))],
+        });
+        trace.push(TraceStep::Contradiction { line: Line::Col(1) });
+        trace.push(TraceStep::Backtrack { pos });
+        trace.push(TraceStep::Guess {
+            pos,
+            fill: Fill::Color(1),
+        });
+
+        let start = Grid::new(1, 2).unwrap();
+        let grids = trace.replay(start.clone());
+
+        assert_eq!(grids[2][other], Some(Fill::Color(1)));
+        assert_eq!(grids[3], start);
+        assert_eq!(grids[4][pos], Some(Fill::Color(1)));
+    }
+
+    #[test]
+    fn disabled() {
+        let puzzle = puzzle();
+        let mut solver = NonogramSolver::default();
+        solver
+            .solve(&puzzle, &mut NonogramState::from(&puzzle))
+            .unwrap();
+
+        assert!(solver.trace().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let puzzle = puzzle();
+        let mut solver = NonogramSolver::default().with_trace();
+        solver
+            .solve(&puzzle, &mut NonogramState::from(&puzzle))
+            .unwrap();
+
+        let trace = solver.trace().unwrap();
+        let json = serde_json::to_string(trace).unwrap();
+
+        assert!(json.starts_with("[{\"guess\""));
+        assert_eq!(serde_json::from_str::<SolveTrace>(&json).unwrap(), *trace);
+    }
+}
@@ -0,0 +1,153 @@
+use puzzled_core::Grid;
+
+use crate::{Fill, NonogramSolver, Rules, solve::solver::candidates};
+
+/// Whether the [rules](Rules) of a nonogram lead to a single solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Uniqueness {
+    /// The rules contradict each other, so no solution exists
+    Unsolvable,
+
+    /// The rules have exactly one solution
+    Unique(Grid<Fill>),
+
+    /// The rules have at least two different solutions, of which two are given
+    Ambiguous(Grid<Fill>, Grid<Fill>),
+}
+
+impl Uniqueness {
+    pub fn is_unique(&self) -> bool {
+        matches!(self, Uniqueness::Unique(_))
+    }
+
+    /// Solution of the rules, which is only defined when it is [unique](Uniqueness::Unique)
+    pub fn solution(&self) -> Option<&Grid<Fill>> {
+        match self {
+            Uniqueness::Unique(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+impl NonogramSolver {
+    /// Check whether the rules of a nonogram have exactly one solution
+    ///
+    /// The search stops as soon as a second solution is found, so ambiguous puzzles are not fully explored
+    /// ```
+    /// use puzzled::nonogram::{nonogram, NonogramSolver, Uniqueness};
+    ///
+    /// let unique = nonogram!(
+    ///     [1 1 1]
+    ///     [1 x 1]
+    ///     [1 1 1]
+    /// );
+    /// let ambiguous = nonogram!(
+    ///     [1 x]
+    ///     [x 1]
+    /// );
+    ///
+    /// let mut solver = NonogramSolver::default();
+    /// assert!(solver.check_uniqueness(unique.rules()).is_unique());
+    /// assert!(matches!(solver.check_uniqueness(ambiguous.rules()), Uniqueness::Ambiguous(..)));
+    /// ```
+    pub fn check_uniqueness(&mut self, rules: &Rules) -> Uniqueness {
+        let Ok(grid) = Grid::new(rules.rows(), rules.cols()) else {
+            return Uniqueness::Unsolvable;
+        };
+
+        let mut solutions = Vec::with_capacity(2);
+        self.collect_solutions(rules, grid, &mut solutions, 2);
+
+        let mut solutions = solutions.into_iter();
+        match (solutions.next(), solutions.next()) {
+            (None, _) => Uniqueness::Unsolvable,
+            (Some(solution), None) => Uniqueness::Unique(solution),
+            (Some(first), Some(second)) => Uniqueness::Ambiguous(first, second),
+        }
+    }
+
+    fn collect_solutions(
+        &mut self,
+        rules: &Rules,
+        mut grid: Grid<Option<Fill>>,
+        solutions: &mut Vec<Grid<Fill>>,
+        limit: usize,
+    ) {
+        self.nodes += 1;
+
+        if !self.propagate(rules, &mut grid) {
+            return;
+        }
+
+        let Some(pos) = grid.positions().find(|&pos| grid[pos].is_none()) else {
+            solutions.push(grid.map(Option::unwrap_or_default));
+            return;
+        };
+
+        for fill in candidates(rules, pos) {
+            if solutions.len() >= limit {
+                return;
+            }
+
+            let mut guess = grid.clone();
+            guess[pos] = Some(fill);
+
+            self.collect_solutions(rules, guess, solutions, limit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use puzzled_core::Line;
+
+    use crate::{Rule, Run, nonogram};
+
+    #[test]
+    fn unique() {
+        let puzzle = nonogram!(
+            [1 1 1]
+            [1 x x]
+            [1 x x]
+        );
+
+        let mut solver = NonogramSolver::default();
+        let uniqueness = solver.check_uniqueness(puzzle.rules());
+        let expected = puzzle.fills().map_ref(|cell| cell.solution.unwrap());
+
+        assert_eq!(uniqueness, Uniqueness::Unique(expected));
+    }
+
+    #[test]
+    fn ambiguous() {
+        let puzzle = nonogram!(
+            [1 x]
+            [x 1]
+        );
+
+        let mut solver = NonogramSolver::default();
+        let Uniqueness::Ambiguous(first, second) = solver.check_uniqueness(puzzle.rules()) else {
+            panic!("Expected the diagonal to be ambiguous");
+        };
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn unsolvable() {
+        let fill = Fill::Color('1' as u32);
+        let mut rules = BTreeMap::from([(Line::Row(0), Rule::new(vec![Run::new(fill, 3)], 3))]);
+
+        // Columns without runs can not contain the filled row
+        for col in 0..3 {
+            rules.insert(Line::Col(col), Rule::new(vec![], 3));
+        }
+        let rules = Rules::new_with_default_missing(rules, 3, 3);
+
+        let mut solver = NonogramSolver::default();
+        assert_eq!(solver.check_uniqueness(&rules), Uniqueness::Unsolvable);
+    }
+}
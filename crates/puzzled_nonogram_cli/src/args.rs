@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+/// Solve, check and generate nonograms without starting the TUI
+#[derive(Debug, Parser)]
+#[command(version, about, long_about = None)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve a puzzle from its rules and print the solution
+    Solve { input: PathBuf },
+
+    /// Check whether the rules of a puzzle lead to exactly one solution
+    Check { input: PathBuf },
+
+    /// Generate a puzzle whose solution is derived from an image
+    Generate {
+        #[arg(long)]
+        from_image: PathBuf,
+
+        /// Maximum number of colors to reduce the image to, not counting the background
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=9))]
+        colors: u8,
+
+        /// Number of columns of the puzzle, which scales the image while keeping its aspect ratio
+        #[arg(long)]
+        cols: Option<u32>,
+
+        /// Write the puzzle to a `*.nono` or `*.puz` file or a `*.png` image instead of printing it
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
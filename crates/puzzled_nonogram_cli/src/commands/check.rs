@@ -0,0 +1,43 @@
+use std::{path::Path, time::Instant};
+
+use puzzled_core::Solver;
+use puzzled_nonogram::{NonogramSolver, Uniqueness};
+
+use crate::{Error, Result, commands::read_nonogram};
+
+/// Check whether the rules of the puzzle lead to exactly one solution
+///
+/// For ambiguous puzzles, the number of cells that differ between two of their solutions is printed as well
+pub fn check(input: &Path) -> Result<()> {
+    let puzzle = read_nonogram(input)?;
+
+    let start = Instant::now();
+    let mut solver = NonogramSolver::default();
+    let uniqueness = solver.check_uniqueness(puzzle.rules());
+    let elapsed = start.elapsed();
+
+    let nodes = solver.stats().nodes;
+
+    match uniqueness {
+        Uniqueness::Unique(_) => {
+            println!("unique ({nodes} nodes in {elapsed:.2?})");
+            Ok(())
+        }
+        Uniqueness::Ambiguous(first, second) => {
+            let diff = first
+                .iter()
+                .zip(second.iter())
+                .filter(|(a, b)| a != b)
+                .count();
+
+            println!(
+                "ambiguous ({nodes} nodes in {elapsed:.2?}), {diff} cell(s) differ between two solutions"
+            );
+            Err(Error::Ambiguous)
+        }
+        Uniqueness::Unsolvable => {
+            println!("unsolvable ({nodes} nodes in {elapsed:.2?})");
+            Err(Error::Unsolvable)
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use image::{DynamicImage, Rgba, imageops::FilterType};
+use puzzled_core::{Cell, Color, Grid, Metadata};
+use puzzled_io::{ImageWriter, NonoWriter, PuzWriter};
+use puzzled_nonogram::{Colors, Fill, Nonogram, NonogramSolver, NonogramState, Uniqueness};
+
+use crate::{Error, Result, commands::format_solution};
+
+/// Number of rounds used to refine the palette of an image
+const PALETTE_ROUNDS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerateOptions {
+    /// Maximum number of colors in the puzzle
+    pub colors: u8,
+
+    /// Number of columns in the puzzle, which defaults to the width of the image
+    pub cols: Option<u32>,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            colors: 1,
+            cols: None,
+        }
+    }
+}
+
+/// Generate a puzzle from the image at the input path and report whether it has a unique solution
+///
+/// The puzzle is printed unless an output path is given, in which case it is written as a `*.nono` or `*.puz` file or a `*.png` image
+pub fn generate(input: &Path, options: &GenerateOptions, output: Option<&Path>) -> Result<()> {
+    let image = image::open(input)?;
+
+    let meta = match input.file_stem().and_then(|stem| stem.to_str()) {
+        Some(title) => Metadata::default().with_title(title.to_string()),
+        None => Metadata::default(),
+    };
+    let puzzle = nonogram_from_image(&image, options, meta);
+
+    match NonogramSolver::default().check_uniqueness(puzzle.rules()) {
+        Uniqueness::Unique(_) => {}
+        Uniqueness::Ambiguous(..) => {
+            eprintln!("warning: the generated puzzle has more than one solution")
+        }
+        Uniqueness::Unsolvable => unreachable!("Rules derived from fills are always solvable"),
+    }
+
+    let Some(output) = output else {
+        let solution = puzzle
+            .fills()
+            .map_ref(|cell| cell.solution.unwrap_or_default());
+
+        print!("{}", format_solution(&solution));
+        return Ok(());
+    };
+
+    let state = NonogramState::from(&puzzle);
+    let extension = output.extension().and_then(|ext| ext.to_str());
+
+    match extension.map(str::to_ascii_lowercase).as_deref() {
+        Some("nono") => {
+            let mut bytes = Vec::new();
+            NonoWriter::new()
+                .write(&mut bytes, &puzzle, &state)
+                .map_err(|err| Error::Write {
+                    path: output.to_path_buf(),
+                    err: err.into(),
+                })?;
+
+            fs::write(output, bytes)?;
+        }
+        Some("puz") => {
+            let mut bytes = Vec::new();
+            PuzWriter::new()
+                .write(&mut bytes, &puzzle, &state)
+                .map_err(|err| Error::Write {
+                    path: output.to_path_buf(),
+                    err: err.into(),
+                })?;
+
+            fs::write(output, bytes)?;
+        }
+        Some("png") => {
+            let image = ImageWriter
+                .write(&puzzle, &state)
+                .map_err(|err| Error::Write {
+                    path: output.to_path_buf(),
+                    err: err.into(),
+                })?;
+
+            image.save(output)?;
+        }
+        _ => return Err(Error::UnsupportedOutput(output.to_path_buf())),
+    }
+
+    Ok(())
+}
+
+/// Derive a puzzle from an image, where every pixel becomes a cell
+///
+/// Transparent and nearly white pixels are crossed out, and the other pixels are reduced to the requested number of colors
+pub fn nonogram_from_image(
+    image: &DynamicImage,
+    options: &GenerateOptions,
+    meta: Metadata,
+) -> Nonogram {
+    let image = match options.cols {
+        Some(cols) if cols != image.width() => {
+            let cols = cols.max(1);
+            let rows = (image.height() as f64 * cols as f64 / image.width() as f64).round() as u32;
+
+            image.resize_exact(cols, rows.max(1), FilterType::Triangle)
+        }
+        _ => image.clone(),
+    };
+    let image = image.to_rgba8();
+
+    let pixels: Vec<_> = image
+        .pixels()
+        .filter(|pixel| !is_background(pixel))
+        .map(rgb)
+        .collect();
+    let palette = palette(&pixels, options.colors as usize);
+
+    let fills: Vec<_> = image
+        .pixels()
+        .map(|pixel| {
+            let fill = match is_background(pixel) {
+                true => Fill::Cross,
+                false => color_fill(nearest(&palette, rgb(pixel))),
+            };
+
+            Cell::new(Some(fill))
+        })
+        .collect();
+    let fills = Grid::from_vec(fills, image.width() as usize).expect("Image has a valid size");
+
+    let colors = palette
+        .iter()
+        .enumerate()
+        .map(|(idx, &[r, g, b])| (color_fill(idx), Color::rgb(r as u8, g as u8, b as u8)))
+        .collect();
+
+    Nonogram::new(fills, Colors::new(colors), meta)
+}
+
+fn is_background(pixel: &Rgba<u8>) -> bool {
+    let [r, g, b, a] = pixel.0;
+
+    a < 128 || r.min(g).min(b) >= 224
+}
+
+fn rgb(pixel: &Rgba<u8>) -> [f64; 3] {
+    let [r, g, b, _] = pixel.0;
+
+    [r as f64, g as f64, b as f64]
+}
+
+/// Fill of the color at the index of the palette, which are numbered from `1` like in the text format
+fn color_fill(idx: usize) -> Fill {
+    let ch = char::from_digit(idx as u32 + 1, 10).expect("Palette has at most 9 colors");
+
+    Fill::Color(ch as u32)
+}
+
+fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+fn nearest(palette: &[[f64; 3]], color: [f64; 3]) -> usize {
+    palette
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(**a, color).total_cmp(&distance(**b, color)))
+        .map(|(idx, _)| idx)
+        .unwrap_or_default()
+}
+
+/// Reduce the colors of the pixels to a palette of at most `size` colors with k-means
+///
+/// The palette starts out with the most common colors, which are first rounded to ignore small differences between pixels
+fn palette(pixels: &[[f64; 3]], size: usize) -> Vec<[f64; 3]> {
+    let mut buckets: HashMap<[u8; 3], (usize, [f64; 3])> = HashMap::new();
+
+    for &pixel in pixels {
+        let key = pixel.map(|channel| channel as u8 >> 5);
+        let (count, sum) = buckets.entry(key).or_default();
+
+        *count += 1;
+        (0..3).for_each(|channel| sum[channel] += pixel[channel]);
+    }
+
+    let mut buckets: Vec<_> = buckets.into_iter().collect();
+    buckets.sort_by(|(a_key, (a, _)), (b_key, (b, _))| b.cmp(a).then(a_key.cmp(b_key)));
+
+    let mut palette: Vec<_> = buckets
+        .into_iter()
+        .take(size)
+        .map(|(_, (count, sum))| sum.map(|channel| channel / count as f64))
+        .collect();
+
+    for _ in 0..PALETTE_ROUNDS {
+        let mut sums = vec![(0, [0.0; 3]); palette.len()];
+
+        for &pixel in pixels {
+            let (count, sum) = &mut sums[nearest(&palette, pixel)];
+
+            *count += 1;
+            (0..3).for_each(|channel| sum[channel] += pixel[channel]);
+        }
+
+        palette = sums
+            .into_iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, sum)| sum.map(|channel| channel / count as f64))
+            .collect();
+    }
+
+    palette
+}
+
+#[cfg(test)]
+mod tests {
+    use image::RgbaImage;
+    use rstest::rstest;
+
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([200, 20, 20, 255]);
+    const DARK_RED: Rgba<u8> = Rgba([180, 30, 25, 255]);
+    const BLUE: Rgba<u8> = Rgba([20, 20, 200, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    fn image() -> DynamicImage {
+        let image = RgbaImage::from_fn(4, 2, |x, y| match (x, y) {
+            (0, _) => RED,
+            (1, _) => DARK_RED,
+            (2, 0) => BLUE,
+            _ => WHITE,
+        });
+
+        DynamicImage::ImageRgba8(image)
+    }
+
+    #[rstest]
+    #[case::single(1, 1)]
+    #[case::merged(2, 2)]
+    #[case::limited(9, 3)]
+    fn palette_size(#[case] colors: u8, #[case] expected: usize) {
+        let options = GenerateOptions { colors, cols: None };
+        let puzzle = nonogram_from_image(&image(), &options, Metadata::default());
+
+        let used: Vec<_> = puzzle
+            .colors()
+            .keys()
+            .filter(|fill| fill.is_color())
+            .collect();
+        assert_eq!(used.len(), expected);
+    }
+
+    #[test]
+    fn background() {
+        let puzzle =
+            nonogram_from_image(&image(), &GenerateOptions::default(), Metadata::default());
+        let solution = puzzle
+            .fills()
+            .map_ref(|cell| cell.solution.unwrap_or_default());
+
+        assert_eq!(format_solution(&solution), "1 1 1 .\n1 1 . .\n");
+    }
+
+    #[test]
+    fn resize() {
+        let options = GenerateOptions {
+            cols: Some(2),
+            ..Default::default()
+        };
+        let puzzle = nonogram_from_image(&image(), &options, Metadata::default());
+
+        assert_eq!((puzzle.rows(), puzzle.cols()), (1, 2));
+    }
+}
@@ -0,0 +1,75 @@
+mod check;
+mod generate;
+mod solve;
+
+pub use check::*;
+pub use generate::*;
+pub use solve::*;
+
+use std::path::Path;
+
+use puzzled_core::Grid;
+use puzzled_nonogram::{Fill, Nonogram, read_puzzle_from_path};
+
+use crate::{Command, Error, Result};
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Solve { input } => solve(&input),
+        Command::Check { input } => check(&input),
+        Command::Generate {
+            from_image,
+            colors,
+            cols,
+            output,
+        } => {
+            let options = GenerateOptions { colors, cols };
+            generate(&from_image, &options, output.as_deref())
+        }
+    }
+}
+
+pub fn read_nonogram(path: &Path) -> Result<Nonogram> {
+    read_puzzle_from_path(path).map_err(|err| Error::Read {
+        path: path.to_path_buf(),
+        err,
+    })
+}
+
+/// Format a solution with a row of characters per line, where crossed out cells are shown as `.`
+pub fn format_solution(solution: &Grid<Fill>) -> String {
+    let mut text = String::new();
+
+    for row in solution.iter_rows() {
+        let chars: Vec<_> = row
+            .map(|fill| match fill {
+                Fill::Blank | Fill::Cross => '.',
+                fill => char::try_from(*fill).unwrap_or(fill.symbol()),
+            })
+            .map(String::from)
+            .collect();
+
+        text += &chars.join(" ");
+        text.push('\n');
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_nonogram::nonogram;
+
+    use super::*;
+
+    #[test]
+    fn format() {
+        let puzzle = nonogram!(
+            [1 x 2]
+            [x 1 x]
+        );
+        let solution = puzzle.fills().map_ref(|cell| cell.solution.unwrap());
+
+        assert_eq!(format_solution(&solution), "1 . 2\n. 1 .\n");
+    }
+}
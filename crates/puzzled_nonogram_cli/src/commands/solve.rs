@@ -0,0 +1,35 @@
+use std::{path::Path, time::Instant};
+
+use puzzled_nonogram::{NonogramSolver, Uniqueness};
+
+use crate::{
+    Error, Result,
+    commands::{format_solution, read_nonogram},
+};
+
+/// Solve the puzzle from its rules alone and print its solution along with how long solving took
+///
+/// Puzzles that do not have exactly one solution are reported instead of picking one of their solutions
+pub fn solve(input: &Path) -> Result<()> {
+    let puzzle = read_nonogram(input)?;
+
+    let start = Instant::now();
+    let uniqueness = NonogramSolver::default().check_uniqueness(puzzle.rules());
+    let elapsed = start.elapsed();
+
+    match uniqueness {
+        Uniqueness::Unique(solution) => {
+            print!("{}", format_solution(&solution));
+            println!("Solved in {elapsed:.2?}");
+            Ok(())
+        }
+        Uniqueness::Ambiguous(..) => {
+            println!("ambiguous ({elapsed:.2?})");
+            Err(Error::Ambiguous)
+        }
+        Uniqueness::Unsolvable => {
+            println!("unsolvable ({elapsed:.2?})");
+            Err(Error::Unsolvable)
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use puzzled_io::{ReadError, WriteError};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not read {}: {err}", path.display())]
+    Read { path: PathBuf, err: ReadError },
+
+    #[error("Could not write {}: {err}", path.display())]
+    Write { path: PathBuf, err: WriteError },
+
+    #[error(transparent)]
+    Image(#[from] image::ImageError),
+
+    #[error("Cannot write a puzzle to {}, use a *.puz or *.png file", .0.display())]
+    UnsupportedOutput(PathBuf),
+
+    #[error("Puzzle has more than one solution")]
+    Ambiguous,
+
+    #[error("Puzzle has no solution")]
+    Unsolvable,
+}
@@ -0,0 +1,22 @@
+mod args;
+mod commands;
+mod error;
+
+pub use args::*;
+pub use error::*;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match commands::run(args.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -36,6 +36,21 @@ impl ActionEngine {
                 self.mode = Mode::Normal;
             }
 
+            // Validate all lines against their rules
+            Action::Check => state.check_rules(),
+
+            // Point out the next deducible cell
+            Action::Hint => state.show_hint(),
+
+            // Highlight the lines and cells that contradict the rules
+            Action::ToggleMistakes => state.toggle_mistakes(),
+
+            // Stop counting the time spent on the puzzle
+            Action::Pause => state.toggle_pause(),
+
+            // Remap the puzzle colors to colors the terminal can show
+            Action::OpenPalette => state.open_palette(),
+
             // History management
             Action::Undo if is_normal => return self.history.undo(state),
             Action::Redo if is_normal => return self.history.redo(state),
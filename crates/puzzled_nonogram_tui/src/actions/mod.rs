@@ -83,6 +83,11 @@ pub enum Action {
     Redo,
 
     // Other
+    Check,
+    Hint,
+    ToggleMistakes,
+    Pause,
+    OpenPalette,
     SwitchAxis,
     SampleFill,
     SwitchFill,
@@ -104,10 +109,9 @@ impl Action {
 
         match self {
             // Commands
-            Quit | Undo | Redo | CenterViewport | BottomViewport | TopViewport | SwitchAxis
-            | FocusLeft | FocusDown | FocusRight | FocusUp | SampleFill | SwitchFill => {
-                ActionKind::Command
-            }
+            Quit | Undo | Redo | Check | Hint | ToggleMistakes | Pause | OpenPalette
+            | CenterViewport | BottomViewport | TopViewport | SwitchAxis | FocusLeft
+            | FocusDown | FocusRight | FocusUp | SampleFill | SwitchFill => ActionKind::Command,
 
             // Operators
             Fill | Cross | DeleteSingle | Delete | Measure => ActionKind::Operator,
@@ -7,4 +7,5 @@ pub enum Focus {
     RulesTop,
 
     Footer,
+    MiniMap,
 }
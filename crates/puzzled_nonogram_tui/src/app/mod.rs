@@ -3,6 +3,7 @@ mod layout;
 mod load;
 mod mode;
 mod pos;
+mod save;
 mod selection;
 mod state;
 
@@ -10,27 +11,27 @@ pub use focus::*;
 pub use load::*;
 pub use mode::*;
 pub use pos::*;
+pub use save::*;
 pub use selection::*;
 pub use state::*;
 
 use crossterm::{
-    event::{self as t_event, EnableMouseCapture, Event},
+    event::{self as t_event, EnableFocusChange, EnableMouseCapture, Event, KeyCode, KeyEventKind},
     execute,
     terminal::EnterAlternateScreen,
 };
-use puzzled_nonogram::{Nonogram, NonogramSolver};
+use puzzled_nonogram::NonogramSolver;
 use ratatui::{
     DefaultTerminal, Frame,
     layout::{Margin, Position, Rect},
-    style::{Color, Style},
     widgets::{FrameExt, Scrollbar, ScrollbarOrientation, ScrollbarState},
 };
 use std::time::Duration;
 
 use crate::{
     ActionEngine, ActionInput, ActionOutcome, ActionResult, AppEvent, ColRulesWidget,
-    ComputeLayout, Config, EventEngine, FooterWidget, HandleAction, MiniMapWidget, PuzzleStyle,
-    PuzzleWidget, Result, RowRulesWidget,
+    CompletionWidget, ComputeLayout, Config, EventEngine, FooterWidget, HandleAction,
+    MiniMapWidget, PaletteWidget, PuzzleStyle, PuzzleWidget, Result, RowRulesWidget,
 };
 
 const POLL_DURATION: Duration = Duration::from_millis(30);
@@ -51,11 +52,13 @@ pub struct App {
     rules_top: ColRulesWidget,
     footer: FooterWidget,
     minimap: MiniMapWidget,
+    completion: CompletionWidget,
+    palette: PaletteWidget,
 }
 
 impl App {
-    pub fn new(puzzle: Nonogram, style: PuzzleStyle, config: Config) -> Self {
-        let rules = puzzle.rules().clone();
+    pub fn new(save: SaveState, style: PuzzleStyle, config: Config) -> Self {
+        let rules = save.puzzle.rules().clone();
         let rules_left = RowRulesWidget::new(
             "Rules [Rows]".to_string(),
             rules.iter_rows().map(|(_, rule)| rule.clone()).collect(),
@@ -66,7 +69,10 @@ impl App {
             rules.iter_cols().map(|(_, rule)| rule.clone()).collect(),
         );
 
-        let state = AppState::new(puzzle, rules, style, config.settings);
+        let mut state = AppState::new(save, rules, style, config.settings);
+        state.puzzle.palette = config.palette;
+        state.theme = config.theme;
+
         let events = EventEngine::new(config.actions.clone(), TICK_DURATION);
 
         Self {
@@ -80,6 +86,19 @@ impl App {
             rules_top,
             footer: FooterWidget,
             minimap: MiniMapWidget,
+            completion: CompletionWidget,
+            palette: PaletteWidget,
+        }
+    }
+
+    /// Progress on the puzzle to keep for the next session
+    pub fn into_save(mut self) -> SaveState {
+        self.state.puzzle.timer.pause();
+
+        SaveState {
+            puzzle: self.state.puzzle.puzzle,
+            timer: self.state.puzzle.timer,
+            mistakes: self.state.mistake_count,
         }
     }
 
@@ -99,6 +118,28 @@ impl App {
             if t_event::poll(POLL_DURATION)? {
                 // Read the terminal event
                 let event = t_event::read()?;
+
+                // Only count the time while the app is in focus
+                match event {
+                    Event::FocusGained => self.state.set_active(true),
+                    Event::FocusLost => self.state.set_active(false),
+                    _ => {}
+                }
+
+                // The completion dialog takes all key presses while it is open
+                if self.state.completion.is_some() {
+                    if matches!(self.handle_completion(&event), ActionOutcome::Exit) {
+                        break;
+                    }
+                    continue;
+                }
+
+                // As does the palette dialog
+                if self.state.palette_dialog.is_some() {
+                    self.handle_palette(&event);
+                    continue;
+                }
+
                 let app_event = AppEvent::new(event);
 
                 // See whether the application handles it and whether it needs action
@@ -122,6 +163,43 @@ impl App {
         self.exit()
     }
 
+    fn handle_completion(&mut self, event: &Event) -> ActionOutcome {
+        let Event::Key(key) = event else {
+            return ActionOutcome::Ignored;
+        };
+        if key.kind != KeyEventKind::Press {
+            return ActionOutcome::Ignored;
+        }
+
+        match key.code {
+            KeyCode::Char('e') => self.state.export_image(),
+            KeyCode::Char('q') => return ActionOutcome::Exit,
+            KeyCode::Esc | KeyCode::Enter => self.state.completion = None,
+            _ => return ActionOutcome::Ignored,
+        }
+
+        ActionOutcome::Consumed
+    }
+
+    fn handle_palette(&mut self, event: &Event) {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.state.select_palette_color(true),
+            KeyCode::Char('k') | KeyCode::Up => self.state.select_palette_color(false),
+            KeyCode::Char('l') | KeyCode::Right => self.state.change_palette_color(Some(true)),
+            KeyCode::Char('h') | KeyCode::Left => self.state.change_palette_color(Some(false)),
+            KeyCode::Char('r') => self.state.change_palette_color(None),
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => self.state.close_palette(),
+            _ => {}
+        }
+    }
+
     fn handle_with_engine(&mut self, input: ActionInput) -> ActionResult {
         let focus = self.resolve_focus(&input);
 
@@ -142,6 +220,10 @@ impl App {
                 self.actions
                     .handle_action_with(&self.footer, input.clone(), &mut self.state)
             }
+            Focus::MiniMap => {
+                self.actions
+                    .handle_action_with(&self.minimap, input.clone(), &mut self.state)
+            }
         }?;
 
         // If a focus change is requested,
@@ -152,6 +234,13 @@ impl App {
             self.state.switch_focus(input);
         }
 
+        // Keep the highlighted mistakes in sync with the fills
+        if self.state.mistake_highlight != MistakeHighlight::Off {
+            self.state.update_mistakes();
+        }
+
+        self.state.check_completion();
+
         Ok(outcome)
     }
 
@@ -171,13 +260,21 @@ impl App {
             if self.state.footer.area.contains(pos) {
                 return Focus::Footer;
             }
+            if self.state.minimap.area.contains(pos) {
+                return Focus::MiniMap;
+            }
         }
 
         self.state.focus
     }
 
     fn init(&self) -> Result<()> {
-        execute!(std::io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        execute!(
+            std::io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableFocusChange
+        )?;
 
         Ok(())
     }
@@ -207,11 +304,13 @@ impl App {
 
         frame.render_stateful_widget_ref(&self.footer, self.state.footer.area, &mut self.state);
         frame.render_stateful_widget_ref(&self.minimap, self.state.minimap.area, &mut self.state);
+        frame.render_stateful_widget_ref(&self.palette, frame.area(), &mut self.state);
+        frame.render_stateful_widget_ref(&self.completion, frame.area(), &mut self.state);
     }
 
     fn draw_puzzle_scrollbars(&mut self, frame: &mut Frame, area: Rect) {
         // Common properties for both scrollbars
-        let style = Style::default().fg(Color::Gray);
+        let style = self.state.theme.muted();
         let vp = &self.state.puzzle.viewport;
 
         // Display scrollbar to scroll through puzzle rows
@@ -0,0 +1,172 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use puzzled_core::{Timer, TimerState};
+use puzzled_io::ImageWriter;
+use puzzled_nonogram::{Fill, Nonogram, NonogramState};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result, dirs};
+
+/// Progress on a puzzle that is kept between sessions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveState {
+    pub puzzle: Nonogram,
+
+    /// Time spent on the puzzle in all previous sessions
+    #[serde(default)]
+    pub timer: Timer,
+
+    /// Number of fills that were proven wrong when they were made
+    #[serde(default)]
+    pub mistakes: usize,
+}
+
+impl SaveState {
+    /// Start playing the puzzle from scratch
+    pub fn new(mut puzzle: Nonogram) -> Self {
+        // Loaded puzzles still hold their solution, which the rules have already been made from
+        for cell in puzzle.fills_mut().iter_mut() {
+            cell.solution = None;
+        }
+
+        Self {
+            puzzle,
+            timer: Timer::new(Duration::ZERO, TimerState::Running),
+            mistakes: 0,
+        }
+    }
+}
+
+/// Path to the progress on the puzzle, which is keyed by the [fingerprint](puzzled_nonogram::Rules::fingerprint) of its rules
+pub fn save_path(puzzle: &Nonogram) -> Option<PathBuf> {
+    let file = format!("{:016x}.json", puzzle.rules().fingerprint());
+
+    Some(dirs()?.data_dir().join("saves").join(file))
+}
+
+/// Load the saved progress on the puzzle, if there is any
+pub fn load_progress(puzzle: &Nonogram) -> Option<SaveState> {
+    let path = save_path(puzzle)?;
+    let contents = fs::read_to_string(&path).ok()?;
+
+    match serde_json::from_str(&contents) {
+        Ok(saved) => Some(saved),
+        Err(err) => {
+            tracing::warn!("Ignoring invalid save {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+pub fn save_progress(save: &SaveState) -> Result<()> {
+    let Some(path) = save_path(&save.puzzle) else {
+        return Err(Error::Custom(
+            "Couldn't find a directory to save progress in".to_string(),
+        ));
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let contents = serde_json::to_string(save)
+        .map_err(|err| Error::Custom(format!("Couldn't save progress: {err}")))?;
+    fs::write(path, contents)?;
+
+    Ok(())
+}
+
+/// Export the fills of the puzzle as an image in the data directory of the app, returning its path
+///
+/// The image is named after the title of the puzzle if it has one, and otherwise after its [save](save_path)
+pub fn export_image(puzzle: &Nonogram) -> Result<PathBuf> {
+    let dir = dirs()
+        .map(|dirs| dirs.data_dir().join("exports"))
+        .ok_or_else(|| Error::Custom("Couldn't find a directory to export to".to_string()))?;
+    fs::create_dir_all(&dir)?;
+
+    let path = dir.join(export_name(puzzle));
+
+    // Only colored cells have a color to write, so leave the others transparent
+    let mut state = NonogramState::from(puzzle);
+    for solution in state.solutions.iter_mut() {
+        if !solution.is_some_and(|fill| fill.is_color()) {
+            *solution = None;
+        }
+    }
+
+    let image = ImageWriter
+        .write(puzzle, &state)
+        .map_err(|err| Error::Custom(format!("Couldn't export image: {err}")))?;
+    image
+        .save(&path)
+        .map_err(|err| Error::Custom(format!("Couldn't export image: {err}")))?;
+
+    Ok(path)
+}
+
+/// File name of the exported image, where the title only keeps its alphanumerics, `-` and `_` such that it cannot leave the export directory
+fn export_name(puzzle: &Nonogram) -> String {
+    let title = puzzle
+        .meta()
+        .title()
+        .unwrap_or_default()
+        .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    match title.is_empty() {
+        true => format!("{:016x}.png", puzzle.rules().fingerprint()),
+        false => format!("{title}.png"),
+    }
+}
+
+/// Fraction of the cells that are filled in or crossed out
+pub fn completion(puzzle: &Nonogram) -> f64 {
+    let fills = puzzle.fills();
+    if fills.area() == 0 {
+        return 0.0;
+    }
+
+    let count = fills
+        .iter()
+        .filter(|cell| cell.solution.is_some_and(|fill| fill != Fill::Blank))
+        .count();
+
+    count as f64 / fills.area() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_nonogram::nonogram;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::title("Stairs", "Stairs.png")]
+    #[case::punctuation("Mr. Smith", "Mr-Smith.png")]
+    #[case::path("../../etc/passwd", "etc-passwd.png")]
+    #[case::separators("a_b-c", "a_b-c.png")]
+    fn sanitized_export_name(#[case] title: &str, #[case] name: &str) {
+        let mut puzzle = nonogram!(
+            [1 1 -]
+            [- 1 1]
+        );
+        let meta = puzzle.meta().clone().with_title(title.to_string());
+        *puzzle.meta_mut() = meta;
+
+        assert_eq!(export_name(&puzzle), name);
+    }
+
+    #[test]
+    fn untitled_export_name() {
+        let mut puzzle = nonogram!([1 -]);
+        let meta = puzzle.meta().clone().with_title("..".to_string());
+        *puzzle.meta_mut() = meta;
+
+        let name = format!("{:016x}.png", puzzle.rules().fingerprint());
+        assert_eq!(export_name(&puzzle), name);
+    }
+}
@@ -1,15 +1,48 @@
-use puzzled_nonogram::{Fill, Nonogram, NonogramSolver, Order, Rules};
+use std::time::{Duration, Instant};
+
+use puzzled_core::TimerState;
+use puzzled_nonogram::{
+    Fill, Line, LineValidation, Mistakes, NonogramSolver, NonogramState, Order, Position, Rules,
+};
 use ratatui::layout::Position as AppPosition;
 
 use crate::{
-    Action, ActionInput, Focus, FooterState, MiniMapState, PuzzleState, PuzzleStyle, RuleState,
-    Selection, Settings,
+    Action, ActionInput, CONFIG_PATH, CompletionState, Focus, FooterState, MiniMapState,
+    PaletteState, PuzzleState, PuzzleStyle, RuleState, SaveState, Selection, Settings, Theme,
+    export_image,
 };
 
+/// How long the results of checking the rules are highlighted
+const CHECK_DURATION: Duration = Duration::from_secs(3);
+
+/// Which mistakes are highlighted while solving, cycled through by [toggling](AppState::toggle_mistakes)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MistakeHighlight {
+    #[default]
+    Off,
+
+    /// Highlight the rules of lines whose entries contradict them
+    Lines,
+
+    /// Highlight the contradicting lines as well as the cells that are proven wrong
+    Cells,
+}
+
+impl MistakeHighlight {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Lines,
+            Self::Lines => Self::Cells,
+            Self::Cells => Self::Off,
+        }
+    }
+}
+
 pub struct AppState {
     // Common
     pub settings: Settings,
     pub focus: Focus,
+    pub theme: Theme,
 
     // Widget specific
     pub puzzle: PuzzleState,
@@ -19,19 +52,33 @@ pub struct AppState {
     pub minimap: MiniMapState,
 
     pub solver: NonogramSolver,
+
+    pub mistake_highlight: MistakeHighlight,
+    pub mistakes: Mistakes,
+
+    /// Number of fills that were proven wrong when they were made
+    pub mistake_count: usize,
+
+    /// Dialog that is shown once the puzzle is solved, until it is closed
+    pub completion: Option<CompletionState>,
+    pub completed: bool,
+
+    /// Dialog to remap the puzzle colors, while it is open
+    pub palette_dialog: Option<PaletteState>,
 }
 
 impl AppState {
-    pub fn new(puzzle: Nonogram, rules: Rules, style: PuzzleStyle, settings: Settings) -> Self {
+    pub fn new(save: SaveState, rules: Rules, style: PuzzleStyle, settings: Settings) -> Self {
         let start_fill = Fill::Color(1);
 
-        let solver = NonogramSolver {};
+        let solver = NonogramSolver::default();
 
         Self {
             settings,
             solver,
-            puzzle: PuzzleState::new(puzzle, style, start_fill),
+            puzzle: PuzzleState::new(save.puzzle, style, start_fill).with_timer(save.timer),
             focus: Focus::default(),
+            theme: Theme::default(),
             rules_left: RuleState::new(
                 rules.iter_rows().map(|(_, rule)| rule.clone()).collect(),
                 Order::Rows,
@@ -42,6 +89,12 @@ impl AppState {
             ),
             minimap: MiniMapState::default(),
             footer: FooterState::new(),
+            mistake_highlight: MistakeHighlight::default(),
+            mistakes: Mistakes::default(),
+            mistake_count: save.mistakes,
+            completion: None,
+            completed: false,
+            palette_dialog: None,
         }
     }
 
@@ -77,6 +130,248 @@ impl AppState {
         }
     }
 
+    /// Validate every line of the puzzle against its rule
+    ///
+    /// Violating lines are highlighted in the rule widgets and a summary is shown in the footer
+    pub fn check_rules(&mut self) {
+        let puzzle = &self.puzzle.puzzle;
+        let mut state = NonogramState::from(puzzle);
+
+        let until = Instant::now() + CHECK_DURATION;
+        let mut solved = 0;
+
+        for rule_state in [&mut self.rules_left, &mut self.rules_top] {
+            rule_state.violations.clear();
+            rule_state.violations_until = Some(until);
+        }
+
+        let rows = (0..puzzle.rows()).map(Line::Row);
+        let cols = (0..puzzle.cols()).map(Line::Col);
+
+        for line in rows.chain(cols) {
+            let validation = self.solver.validate(puzzle, &mut state, line);
+
+            if matches!(validation, LineValidation::Solved) {
+                solved += 1;
+            }
+            if validation.is_valid() {
+                continue;
+            }
+
+            let (rule_state, idx) = match line {
+                Line::Row(row) => (&mut self.rules_left, row),
+                Line::Col(col) => (&mut self.rules_top, col),
+            };
+            rule_state.violations.insert(idx, validation);
+        }
+
+        let row_violations = self.rules_left.violations.len();
+        let col_violations = self.rules_top.violations.len();
+
+        let message = match row_violations + col_violations {
+            0 => format!("All lines are valid ({solved} solved)"),
+            _ => {
+                format!("{row_violations} row(s) and {col_violations} column(s) violate their rule")
+            }
+        };
+
+        tracing::info!("[Check] {message}");
+        self.footer.set_message(message, CHECK_DURATION);
+    }
+
+    /// Move the cursor to the next cell that can be deduced and explain how in the footer
+    pub fn show_hint(&mut self) {
+        let puzzle = &self.puzzle.puzzle;
+        let state = NonogramState::from(puzzle);
+
+        let message = match self.solver.hint(puzzle, &state) {
+            Some(hint) => {
+                let cursor = AppPosition::new(hint.pos.col as u16, hint.pos.row as u16);
+
+                self.focus = Focus::Puzzle;
+                self.puzzle.cursor = cursor;
+                self.puzzle.keep_cursor_visible(cursor);
+
+                hint.to_string()
+            }
+            None => "No cell can be deduced without guessing".to_string(),
+        };
+
+        tracing::info!("[Hint] {message}");
+        self.footer.set_message(message, CHECK_DURATION);
+    }
+
+    /// Cycle through [highlighting](MistakeHighlight) the mistakes in the puzzle
+    pub fn toggle_mistakes(&mut self) {
+        self.mistake_highlight = self.mistake_highlight.next();
+        self.update_mistakes();
+
+        let message = match self.mistake_highlight {
+            MistakeHighlight::Off => "Stopped highlighting mistakes".to_string(),
+            MistakeHighlight::Lines => "Highlighting lines that contradict the rules".to_string(),
+            MistakeHighlight::Cells => {
+                format!(
+                    "Highlighting contradicting lines and {} wrong cell(s)",
+                    self.mistakes.cells.len()
+                )
+            }
+        };
+
+        tracing::info!("[Mistakes] {message}");
+        self.footer.set_message(message, CHECK_DURATION);
+    }
+
+    /// Find the mistakes in the current fills again if they are highlighted
+    pub fn update_mistakes(&mut self) {
+        self.mistakes = match self.mistake_highlight {
+            MistakeHighlight::Off => Mistakes::default(),
+            _ => {
+                let puzzle = &self.puzzle.puzzle;
+                let state = NonogramState::from(puzzle);
+
+                self.solver.mistakes(puzzle, &state)
+            }
+        };
+
+        self.rules_left.mistakes.clear();
+        self.rules_top.mistakes.clear();
+
+        for line in &self.mistakes.lines {
+            match *line {
+                Line::Row(row) => self.rules_left.mistakes.insert(row),
+                Line::Col(col) => self.rules_top.mistakes.insert(col),
+            };
+        }
+    }
+
+    /// Count the fills at the positions that line logic proves to be wrong
+    pub fn count_mistakes(&mut self, positions: &[Position]) {
+        let puzzle = &self.puzzle.puzzle;
+        let state = NonogramState::from(puzzle);
+        let mistakes = self.solver.mistakes(puzzle, &state);
+
+        self.mistake_count += positions
+            .iter()
+            .filter(|pos| mistakes.cells.contains(pos))
+            .count();
+    }
+
+    /// Stop the timer and show the completion dialog the first time the puzzle is solved
+    pub fn check_completion(&mut self) {
+        if self.completed || !self.puzzle.puzzle.is_solved() {
+            return;
+        }
+
+        let timer = &mut self.puzzle.timer;
+        timer.pause();
+
+        tracing::info!("[Completion] Solved in {:?}", timer.elapsed());
+        self.completed = true;
+        self.completion = Some(CompletionState::new(timer.elapsed(), self.mistake_count));
+    }
+
+    /// Export the puzzle as an image from the completion dialog
+    pub fn export_image(&mut self) {
+        let message = match export_image(&self.puzzle.puzzle) {
+            Ok(path) => format!("Exported to {}", path.display()),
+            Err(err) => err.to_string(),
+        };
+        tracing::info!("[Export] {message}");
+
+        if let Some(completion) = &mut self.completion {
+            completion.message = Some(message);
+        }
+    }
+
+    /// Pause or resume the timer, which stays paused once the puzzle is solved
+    pub fn toggle_pause(&mut self) {
+        if self.completed {
+            return;
+        }
+
+        let timer = &mut self.puzzle.timer;
+        timer.toggle();
+
+        let message = match timer.state() {
+            TimerState::Running => "Resumed the timer",
+            TimerState::Stopped => "Paused the timer",
+        };
+        self.footer.set_message(message.to_string(), CHECK_DURATION);
+    }
+
+    /// Pause the timer while the app is out of focus
+    pub fn set_active(&mut self, active: bool) {
+        if self.completed {
+            return;
+        }
+
+        match active {
+            true => self.puzzle.timer.start(),
+            false => self.puzzle.timer.pause(),
+        }
+    }
+
+    /// Colored fills of the puzzle, which are the ones the [palette](crate::Palette) applies to
+    pub fn palette_fills(&self) -> Vec<Fill> {
+        self.puzzle
+            .puzzle
+            .colors()
+            .keys()
+            .filter(|fill| matches!(fill, Fill::Color(_)))
+            .copied()
+            .collect()
+    }
+
+    pub fn open_palette(&mut self) {
+        self.palette_dialog = Some(PaletteState::default());
+    }
+
+    /// Move the selection of the palette dialog to the next or previous color
+    pub fn select_palette_color(&mut self, forwards: bool) {
+        let count = self.palette_fills().len();
+
+        if let Some(dialog) = &mut self.palette_dialog {
+            dialog.select(forwards, count);
+        }
+    }
+
+    /// Draw the selected color with the next or previous terminal color, or reset it when `None` is passed
+    pub fn change_palette_color(&mut self, forwards: Option<bool>) {
+        let fills = self.palette_fills();
+        let Some(dialog) = &mut self.palette_dialog else {
+            return;
+        };
+        let Some(color) = dialog
+            .fill(&fills)
+            .and_then(|fill| self.puzzle.puzzle.colors().get(&fill))
+        else {
+            return;
+        };
+
+        let palette = &mut self.puzzle.palette;
+        match forwards {
+            Some(forwards) => palette.cycle(color, forwards),
+            None => palette.set(color, None),
+        }
+        dialog.changed = true;
+    }
+
+    /// Close the palette dialog, saving the palette to the config if it was changed
+    pub fn close_palette(&mut self) {
+        let Some(dialog) = self.palette_dialog.take() else {
+            return;
+        };
+        if !dialog.changed {
+            return;
+        }
+
+        let message = match self.puzzle.palette.save(CONFIG_PATH.as_ref()) {
+            Ok(()) => "Saved the palette".to_string(),
+            Err(err) => err.to_string(),
+        };
+        self.footer.set_message(message, CHECK_DURATION);
+    }
+
     pub fn switch_focus(&mut self, input: ActionInput) {
         let action = input.action;
         let focus = match (self.focus, action) {
@@ -86,10 +381,12 @@ impl AppState {
             (Focus::Puzzle, Action::FocusLeft) => Focus::RulesLeft,
             (Focus::Puzzle, Action::FocusUp) => Focus::RulesTop,
             (Focus::RulesLeft, Action::FocusRight) => Focus::Puzzle,
-            (Focus::RulesLeft, Action::FocusUp) => Focus::RulesTop,
+            (Focus::RulesLeft, Action::FocusUp) => Focus::MiniMap,
             // (Focus::RulesLeft, Action::FocusDown) => Focus::RulesTop,
             (Focus::RulesTop, Action::FocusDown) => Focus::Puzzle,
             (Focus::RulesTop, Action::FocusLeft) => Focus::RulesLeft,
+            (Focus::MiniMap, Action::FocusRight) => Focus::RulesTop,
+            (Focus::MiniMap, Action::FocusDown) => Focus::RulesLeft,
             _ => {
                 tracing::debug!(
                     "Unknown focus request encountered with action {action:?} (prev focus {:?})",
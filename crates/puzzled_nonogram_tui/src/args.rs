@@ -1,15 +1,19 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::PuzzleStyle;
+use crate::{PuzzleFormat, PuzzleStyle, SaveState, load_progress, load_puzzle};
 use clap::Parser;
-use puzzled_nonogram::{Nonogram, read_puzzle_from_path};
 
 use crate::Result;
 
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
 pub struct Args {
-    pub file: PathBuf,
+    /// Puzzle to play, or pick one from the configured puzzles directory if none is given
+    pub file: Option<PathBuf>,
+
+    /// Format of the file, which is detected from its extension or contents by default
+    #[arg(short, long, value_enum)]
+    pub format: Option<PuzzleFormat>,
 
     #[arg(short = 'x', long, default_value_t = 'X')]
     pub fill_char: char,
@@ -29,8 +33,10 @@ impl Args {
         PuzzleStyle::default()
     }
 
-    pub fn parse_puzzle(&self) -> Result<Nonogram> {
-        let nonogram = read_puzzle_from_path(&self.file)?;
-        Ok(nonogram)
+    /// Load the puzzle at the path, resuming the saved progress on it if there is any
+    pub fn parse_puzzle(&self, path: &Path) -> Result<SaveState> {
+        let puzzle = load_puzzle(path, self.format)?;
+
+        Ok(load_progress(&puzzle).unwrap_or_else(|| SaveState::new(puzzle)))
     }
 }
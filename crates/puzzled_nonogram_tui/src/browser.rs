@@ -0,0 +1,213 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crossterm::event::{self as t_event, Event, KeyCode, KeyEventKind};
+use ratatui::{
+    DefaultTerminal, Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState},
+};
+
+use crate::{PuzzleFormat, Result, completion, load_progress, load_puzzle};
+
+/// Puzzle that is found by the [browser](Browser)
+#[derive(Debug)]
+pub struct BrowserEntry {
+    pub path: PathBuf,
+    pub title: String,
+    pub rows: usize,
+    pub cols: usize,
+
+    /// [Completion](completion) of the saved progress on the puzzle, if any
+    pub completion: Option<f64>,
+}
+
+/// Screen to pick a puzzle from a directory, which is shown when no puzzle is passed on the command line
+#[derive(Debug)]
+pub struct Browser {
+    dir: PathBuf,
+    entries: Vec<BrowserEntry>,
+    list: ListState,
+}
+
+impl Browser {
+    /// Find every puzzle in the directory and its subdirectories, skipping files that cannot be loaded
+    pub fn scan(dir: &Path) -> Result<Self> {
+        let mut paths = Vec::new();
+        collect_files(dir, &mut paths)?;
+        paths.sort();
+
+        let entries: Vec<_> = paths
+            .into_iter()
+            .filter(|path| PuzzleFormat::from_path(path).is_some())
+            .filter_map(|path| match load_puzzle(&path, None) {
+                Ok(puzzle) => {
+                    let title = puzzle
+                        .meta()
+                        .title()
+                        .map(str::to_string)
+                        .unwrap_or_else(|| {
+                            let stem = path.file_stem().unwrap_or_default();
+                            stem.to_string_lossy().into_owned()
+                        });
+                    let completion = load_progress(&puzzle).map(|saved| completion(&saved.puzzle));
+
+                    Some(BrowserEntry {
+                        title,
+                        rows: puzzle.rows(),
+                        cols: puzzle.cols(),
+                        completion,
+                        path,
+                    })
+                }
+                Err(err) => {
+                    tracing::warn!("Skipping {}: {err}", path.display());
+                    None
+                }
+            })
+            .collect();
+
+        let mut list = ListState::default();
+        if !entries.is_empty() {
+            list.select_first();
+        }
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            entries,
+            list,
+        })
+    }
+
+    pub fn entries(&self) -> &[BrowserEntry] {
+        &self.entries
+    }
+
+    /// Show the puzzles until one is picked, or [`None`] is returned if the user quits
+    pub fn run(&mut self, term: &mut DefaultTerminal) -> Result<Option<PathBuf>> {
+        loop {
+            term.draw(|frame| self.render(frame))?;
+
+            let Event::Key(key) = t_event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                KeyCode::Char('j') | KeyCode::Down => self.list.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => self.list.select_previous(),
+                KeyCode::Char('g') | KeyCode::Home => self.list.select_first(),
+                KeyCode::Char('G') | KeyCode::End => self.list.select_last(),
+                KeyCode::Enter => {
+                    let selected = self.list.selected().and_then(|idx| self.entries.get(idx));
+
+                    if let Some(entry) = selected {
+                        return Ok(Some(entry.path.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        let [list_area, help_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+
+        let title_width = self
+            .entries
+            .iter()
+            .map(|entry| entry.title.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let items: Vec<_> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let size = format!("{}x{}", entry.rows, entry.cols);
+                let completion = match entry.completion {
+                    Some(completion) => format!("{:>3.0}%", completion * 100.0),
+                    None => "   -".to_string(),
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{:<title_width$}  ", entry.title)),
+                    Span::styled(format!("{size:>7}  "), Style::default().fg(Color::Gray)),
+                    Span::raw(completion),
+                ]))
+            })
+            .collect();
+
+        let block = Block::bordered().title(format!(" Puzzles in {} ", self.dir.display()));
+        let list = match items.is_empty() {
+            true => List::new([ListItem::new("No puzzles found")]).block(block),
+            false => List::new(items)
+                .block(block)
+                .highlight_style(Style::default().bold().reversed())
+                .highlight_symbol("> "),
+        };
+        frame.render_stateful_widget(list, list_area, &mut self.list);
+
+        let help = "j/k: move  <Enter>: play  q: quit";
+        frame.render_widget(Line::from(help).fg(Color::Gray).centered(), help_area);
+    }
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_nonogram::nonogram;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn scan() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("sub")).unwrap();
+
+        let puzzle = nonogram!(
+            [1 1 -]
+            [- 1 1]
+        );
+        fs::write(
+            dir.join("sub/stairs.json"),
+            serde_json::to_string(&puzzle).unwrap(),
+        )
+        .unwrap();
+        fs::write(dir.join("broken.json"), "{}").unwrap();
+        fs::write(dir.join("notes.txt"), "Not a puzzle").unwrap();
+
+        let browser = Browser::scan(dir).unwrap();
+        let entries: Vec<_> = browser
+            .entries()
+            .iter()
+            .map(|entry| (entry.title.as_str(), entry.rows, entry.cols))
+            .collect();
+
+        // Puzzles without a title are named after their file
+        assert_eq!(entries, [("stairs", 2, 3)]);
+    }
+}
@@ -1,8 +1,18 @@
+mod palette;
 mod parser;
+mod theme;
+
+pub use palette::*;
+pub use theme::*;
+
+use std::path::PathBuf;
 
 use serde::Deserialize;
 
-use crate::{EventTrie, PuzzleStyle};
+use crate::{EventTrie, PuzzleStyle, dirs};
+
+/// Path to the config file, relative to where the app is run from
+pub const CONFIG_PATH: &str = "config.toml";
 
 #[derive(Debug)]
 pub struct Config {
@@ -10,12 +20,32 @@ pub struct Config {
     pub actions: EventTrie,
 
     pub styles: PuzzleStyle,
+    pub palette: Palette,
+    pub theme: Theme,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Settings {
     #[serde(default)]
     pub rule_display: RuleDisplay,
+
+    /// Cross out the remaining cells of a line as soon as its colored cells match its rule
+    #[serde(default)]
+    pub auto_cross: bool,
+
+    /// Directory that the puzzle browser shows puzzles from
+    #[serde(default)]
+    pub puzzles_dir: Option<PathBuf>,
+}
+
+impl Settings {
+    /// [Configured](Self::puzzles_dir) directory of puzzles, which defaults to a `puzzles` directory in the data directory of the app
+    pub fn puzzles_dir(&self) -> Option<PathBuf> {
+        match &self.puzzles_dir {
+            Some(dir) => Some(dir.clone()),
+            None => Some(dirs()?.data_dir().join("puzzles")),
+        }
+    }
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -26,3 +56,16 @@ pub enum RuleDisplay {
 
     TryMax,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_default_config() {
+        let config: Config = toml::from_str(include_str!("../../config.toml")).unwrap();
+
+        assert_eq!(config.theme, Theme::default());
+        assert_eq!(config.palette, Palette::default());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use puzzled_core::Color as PuzzleColor;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// Colors that puzzle colors can be remapped to, which are shown by every terminal
+pub const TERMINAL_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Terminal colors to draw puzzle colors with instead of their own
+///
+/// Overrides are keyed by the [hex](PuzzleColor::to_hex) value of the puzzle color, such that they apply to every puzzle that uses it
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Palette {
+    overrides: BTreeMap<String, Color>,
+}
+
+impl Palette {
+    /// Color to draw the puzzle color with
+    pub fn color(&self, color: &PuzzleColor) -> Color {
+        self.get(color)
+            .unwrap_or(Color::Rgb(color.red, color.green, color.blue))
+    }
+
+    /// Color to draw text with on top of the puzzle color, whichever is most readable
+    pub fn text_color(&self, color: &PuzzleColor) -> Color {
+        let dark = match self.get(color) {
+            Some(Color::Black | Color::Red | Color::Blue | Color::Magenta | Color::DarkGray) => {
+                true
+            }
+            Some(Color::Rgb(red, green, blue)) => {
+                PuzzleColor::rgb(red, green, blue).text_color() == PuzzleColor::WHITE
+            }
+            Some(_) => false,
+            None => color.text_color() == PuzzleColor::WHITE,
+        };
+
+        match dark {
+            true => Color::White,
+            false => Color::Black,
+        }
+    }
+
+    /// Override of the puzzle color, if there is any
+    pub fn get(&self, color: &PuzzleColor) -> Option<Color> {
+        self.overrides.get(&color.to_hex()).copied()
+    }
+
+    /// Draw the puzzle color with another color, or with itself again if `None` is passed
+    pub fn set(&mut self, color: &PuzzleColor, new: Option<Color>) {
+        match new {
+            Some(new) => self.overrides.insert(color.to_hex(), new),
+            None => self.overrides.remove(&color.to_hex()),
+        };
+    }
+
+    /// Override the puzzle color with the [next](TERMINAL_COLORS) terminal color, going back to the color itself after the last one
+    pub fn cycle(&mut self, color: &PuzzleColor, forwards: bool) {
+        let count = TERMINAL_COLORS.len();
+        let pos = self
+            .get(color)
+            .and_then(|current| TERMINAL_COLORS.iter().position(|&c| c == current));
+
+        let next = match (pos, forwards) {
+            (None, true) => Some(0),
+            (None, false) => Some(count - 1),
+            (Some(pos), true) => (pos + 1 < count).then_some(pos + 1),
+            (Some(pos), false) => pos.checked_sub(1),
+        };
+
+        self.set(color, next.map(|pos| TERMINAL_COLORS[pos]));
+    }
+
+    /// Write the overrides to the `[palette]` table of the config file, keeping the rest of the file as is
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = fs::read_to_string(path)?;
+        let table = toml::to_string(self)
+            .map_err(|err| Error::Custom(format!("Couldn't save palette: {err}")))?;
+
+        fs::write(path, replace_table(&contents, "palette", &table))?;
+        Ok(())
+    }
+}
+
+/// Replace the body of the table with the given name, appending the table if the contents don't have it yet
+fn replace_table(contents: &str, name: &str, body: &str) -> String {
+    let header = format!("[{name}]");
+    let mut result = String::new();
+    let mut lines = contents.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.trim() != header {
+            result.push_str(line);
+            result.push('\n');
+            continue;
+        }
+
+        // Skip the old body up to the next table
+        while lines
+            .peek()
+            .is_some_and(|line| !line.trim_start().starts_with('['))
+        {
+            lines.next();
+        }
+
+        result.push_str(&format!("{header}\n{body}\n"));
+        return result + &lines.map(|line| format!("{line}\n")).collect::<String>();
+    }
+
+    if !result.is_empty() && !result.ends_with("\n\n") {
+        result.push('\n');
+    }
+    result + &format!("{header}\n{body}")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::append("[settings]\na = 1\n", "[settings]\na = 1\n\n[palette]\nb = 2\n")]
+    #[case::replace(
+        "[palette]\nb = 1\nc = 3\n\n[styles]\nd = 4\n",
+        "[palette]\nb = 2\n\n[styles]\nd = 4\n"
+    )]
+    #[case::empty("", "[palette]\nb = 2\n")]
+    fn replace_palette(#[case] contents: &str, #[case] expected: &str) {
+        assert_eq!(replace_table(contents, "palette", "b = 2\n"), expected);
+    }
+
+    #[test]
+    fn cycle_through_terminal_colors() {
+        let orange = PuzzleColor::rgb(255, 165, 0);
+        let mut palette = Palette::default();
+
+        palette.cycle(&orange, true);
+        assert_eq!(palette.color(&orange), Color::Black);
+
+        palette.cycle(&orange, false);
+        assert_eq!(palette.get(&orange), None);
+        assert_eq!(palette.color(&orange), Color::Rgb(255, 165, 0));
+
+        palette.cycle(&orange, false);
+        assert_eq!(palette.color(&orange), Color::White);
+    }
+
+    #[test]
+    fn round_trip_through_toml() {
+        let mut palette = Palette::default();
+        palette.set(&PuzzleColor::rgb(255, 165, 0), Some(Color::LightRed));
+        palette.set(&PuzzleColor::rgb(0, 0, 128), Some(Color::Blue));
+
+        let contents = toml::to_string(&palette).unwrap();
+        assert_eq!(toml::from_str::<Palette>(&contents).unwrap(), palette);
+    }
+}
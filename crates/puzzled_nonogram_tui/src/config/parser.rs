@@ -6,7 +6,7 @@ use serde::{
     de::{self},
 };
 
-use crate::{Action, AppEvent, Config, EventTrie, PuzzleStyle, Settings};
+use crate::{Action, AppEvent, Config, EventTrie, Palette, PuzzleStyle, Settings, Theme};
 
 impl<'de> Deserialize<'de> for Config {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -17,12 +17,16 @@ impl<'de> Deserialize<'de> for Config {
 
         let settings = raw_actions.settings;
         let styles = raw_actions.styles;
+        let palette = raw_actions.palette;
+        let theme = raw_actions.theme;
         let actions = parse_action_groups(raw_actions.actions).map_err(de::Error::custom)?;
 
         Ok(Config {
             actions,
             styles,
             settings,
+            palette,
+            theme,
         })
     }
 }
@@ -34,6 +38,12 @@ struct RawActions {
     #[serde(default)]
     pub styles: PuzzleStyle,
 
+    #[serde(default)]
+    pub palette: Palette,
+
+    #[serde(default)]
+    pub theme: Theme,
+
     pub settings: Settings,
 }
 
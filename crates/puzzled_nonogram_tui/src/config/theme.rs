@@ -0,0 +1,102 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+
+/// Colors that the widgets are drawn with, apart from the colors of the puzzle itself
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Border of the widget that has focus
+    pub focused: Color,
+
+    /// Borders of the widgets that don't have focus
+    pub unfocused: Color,
+
+    /// Regular text, such as the stats in the footer
+    pub text: Color,
+
+    /// Text that explains other text, such as labels and help lines
+    pub muted: Color,
+
+    /// Lines between the blocks of the puzzle grid
+    pub divider: Color,
+
+    /// Blank and crossed out cells on the row and column of the cursor
+    pub cursor: Color,
+
+    /// Cells that are selected in visual mode
+    pub selection: Color,
+
+    /// Blank cells
+    pub blank: Color,
+
+    /// Crossed out cells
+    pub cross: Color,
+
+    /// Rules of lines that are solved
+    pub solved: Color,
+
+    /// Rules of lines that contradict their entries
+    pub invalid: Color,
+
+    /// Cells that are proven wrong
+    pub mistake: Color,
+
+    /// Outline of the viewport on the minimap
+    pub viewport: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            focused: Color::White,
+            unfocused: Color::Gray,
+            text: Color::White,
+            muted: Color::Gray,
+            divider: Color::DarkGray,
+            cursor: Color::White,
+            selection: Color::LightCyan,
+            blank: Color::DarkGray,
+            cross: Color::Gray,
+            solved: Color::Green,
+            invalid: Color::Red,
+            mistake: Color::Red,
+            viewport: Color::White,
+        }
+    }
+}
+
+impl Theme {
+    /// Style of the border around a widget, which stands out while the widget has focus
+    pub fn border(&self, is_focused: bool) -> Style {
+        match is_focused {
+            true => Style::default()
+                .fg(self.focused)
+                .add_modifier(Modifier::BOLD),
+            false => Style::default()
+                .fg(self.unfocused)
+                .add_modifier(Modifier::DIM),
+        }
+    }
+
+    pub fn text(&self) -> Style {
+        Style::default().fg(self.text)
+    }
+
+    pub fn muted(&self) -> Style {
+        Style::default().fg(self.muted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_theme() {
+        let theme: Theme = toml::from_str("focused = \"yellow\"\nsolved = \"#00ff00\"").unwrap();
+
+        assert_eq!(theme.focused, Color::Yellow);
+        assert_eq!(theme.solved, Color::Rgb(0, 255, 0));
+        assert_eq!(theme.unfocused, Theme::default().unfocused);
+    }
+}
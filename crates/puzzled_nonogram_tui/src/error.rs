@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+
+use crate::PuzzleFormat;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("{0}")]
@@ -8,6 +12,19 @@ pub enum Error {
 
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error("Could not read '{}' as {format}: {err}", path.display())]
+    Read {
+        path: PathBuf,
+        format: PuzzleFormat,
+        err: puzzled_io::ReadError,
+    },
+
+    #[error("Could not detect the format of '{}', pass one of {formats} with --format", path.display())]
+    UnknownFormat { path: PathBuf, formats: String },
+
+    #[error("Cannot load '{}', as {format} files are not supported yet", path.display())]
+    UnsupportedFormat { path: PathBuf, format: String },
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
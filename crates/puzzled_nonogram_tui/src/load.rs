@@ -0,0 +1,207 @@
+use std::{fmt, fs::File, io::Read, path::Path};
+
+use clap::ValueEnum;
+use puzzled_io::{ImageReader, NonoReader, PuzReader, ReadError, nono::NONO_MAGIC};
+use puzzled_nonogram::{Nonogram, NonogramState};
+
+use crate::{Error, Result};
+
+/// Format of a nonogram file that can be loaded into the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PuzzleFormat {
+    /// Across Lite `*.puz` files
+    Puz,
+
+    /// Native binary `*.nono` files
+    Nono,
+
+    /// Images where every pixel is a cell
+    Image,
+
+    /// Serialized nonograms in `*.json` files
+    Json,
+}
+
+impl PuzzleFormat {
+    pub const ALL: [PuzzleFormat; 4] = [Self::Puz, Self::Nono, Self::Image, Self::Json];
+
+    /// Bytes that *.puz files have after their checksum
+    const PUZ_MAGIC: &[u8] = b"ACROSS&DOWN\0";
+
+    /// Number of bytes that are needed to [detect](Self::from_bytes) every format
+    const SNIFF_LEN: usize = 14;
+
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Puz => &["puz"],
+            Self::Nono => &["nono"],
+            Self::Image => &["png", "jpg", "jpeg", "gif", "bmp", "webp"],
+            Self::Json => &["json"],
+        }
+    }
+
+    /// Find the format from the extension of a path, ignoring its casing
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let ext = path.as_ref().extension()?.to_str()?;
+
+        Self::ALL.into_iter().find(|format| {
+            format
+                .extensions()
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(ext))
+        })
+    }
+
+    /// Find the format from the first bytes of a file
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let is_image = [&b"\x89PNG"[..], b"\xFF\xD8\xFF", b"GIF8", b"BM"]
+            .iter()
+            .any(|magic| bytes.starts_with(magic))
+            || (bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP"));
+
+        if bytes.starts_with(NONO_MAGIC) {
+            Some(Self::Nono)
+        } else if bytes
+            .get(2..)
+            .is_some_and(|rest| rest.starts_with(Self::PUZ_MAGIC))
+        {
+            Some(Self::Puz)
+        } else if is_image {
+            Some(Self::Image)
+        } else if bytes.trim_ascii_start().starts_with(b"{") {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+
+    /// Names of all formats, as they are passed to `--format`
+    fn names() -> String {
+        let names: Vec<_> = Self::ALL.iter().map(ToString::to_string).collect();
+        names.join(", ")
+    }
+}
+
+impl fmt::Display for PuzzleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Puz => "puz",
+            Self::Nono => "nono",
+            Self::Image => "image",
+            Self::Json => "json",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// Load the nonogram at the path, detecting its format from its extension or contents if none is given
+pub fn load_puzzle(path: &Path, format: Option<PuzzleFormat>) -> Result<Nonogram> {
+    let format = match format {
+        Some(format) => format,
+        None => detect_format(path)?,
+    };
+    tracing::info!("Loading {} as {format}", path.display());
+
+    let read_err = |err: ReadError| Error::Read {
+        path: path.to_path_buf(),
+        format,
+        err,
+    };
+
+    let nonogram = match format {
+        PuzzleFormat::Puz => {
+            let (nonogram, _): (Nonogram, NonogramState) = PuzReader::default()
+                .read_from_path(path)
+                .map_err(|err| read_err(err.into()))?;
+            nonogram
+        }
+        PuzzleFormat::Nono => {
+            let (nonogram, _): (Nonogram, NonogramState) = NonoReader::default()
+                .read_from_path(path)
+                .map_err(|err| read_err(err.into()))?;
+            nonogram
+        }
+        PuzzleFormat::Image => {
+            let (nonogram, _): (Nonogram, NonogramState) = ImageReader::default()
+                .read_from_path(path)
+                .map_err(|err| read_err(err.into()))?;
+            nonogram
+        }
+        PuzzleFormat::Json => {
+            let file = File::open(path)?;
+            serde_json::from_reader(file).map_err(|err| {
+                Error::Custom(format!(
+                    "Could not read '{}' as {format}: {err}",
+                    path.display()
+                ))
+            })?
+        }
+    };
+
+    Ok(nonogram)
+}
+
+fn detect_format(path: &Path) -> Result<PuzzleFormat> {
+    if let Some(format) = PuzzleFormat::from_path(path) {
+        return Ok(format);
+    }
+
+    // Fall back on the contents for files without a (known) extension
+    let mut bytes = Vec::with_capacity(PuzzleFormat::SNIFF_LEN);
+    File::open(path)?
+        .take(PuzzleFormat::SNIFF_LEN as u64)
+        .read_to_end(&mut bytes)?;
+
+    if let Some(format) = PuzzleFormat::from_bytes(&bytes) {
+        return Ok(format);
+    }
+
+    // Recognize formats that have no reader yet, to not suggest that another format would work
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase);
+
+    match ext.as_deref() {
+        Some("non") => Err(Error::UnsupportedFormat {
+            path: path.to_path_buf(),
+            format: "*.non".to_string(),
+        }),
+        Some("xml") => Err(Error::UnsupportedFormat {
+            path: path.to_path_buf(),
+            format: "webpbn XML".to_string(),
+        }),
+        _ => Err(Error::UnknownFormat {
+            path: path.to_path_buf(),
+            formats: PuzzleFormat::names(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::nono(b"NONO\x01\x00", Some(PuzzleFormat::Nono))]
+    #[case::puz(b"\x12\x34ACROSS&DOWN\0", Some(PuzzleFormat::Puz))]
+    #[case::png(b"\x89PNG\r\n\x1a\n", Some(PuzzleFormat::Image))]
+    #[case::webp(b"RIFF\0\0\0\0WEBPVP8 ", Some(PuzzleFormat::Image))]
+    #[case::json(b"  {\"rows\": 1", Some(PuzzleFormat::Json))]
+    #[case::text(b"[1 1 -]", None)]
+    fn from_bytes(#[case] bytes: &[u8], #[case] expected: Option<PuzzleFormat>) {
+        assert_eq!(PuzzleFormat::from_bytes(bytes), expected);
+    }
+
+    #[rstest]
+    #[case::lower("puzzles/duck.nono", Some(PuzzleFormat::Nono))]
+    #[case::upper("puzzles/duck.JPG", Some(PuzzleFormat::Image))]
+    #[case::unknown("puzzles/duck.non", None)]
+    #[case::missing("puzzles/duck", None)]
+    fn from_path(#[case] path: &str, #[case] expected: Option<PuzzleFormat>) {
+        assert_eq!(PuzzleFormat::from_path(path), expected);
+    }
+}
@@ -2,24 +2,29 @@
 mod actions;
 mod app;
 mod args;
+mod browser;
 mod config;
 mod error;
 mod events;
+mod load;
 mod log;
 mod widgets;
 
 pub use actions::*;
 pub use app::*;
 pub use args::*;
+pub use browser::*;
 pub use config::*;
 pub use error::*;
 pub use events::*;
+pub use load::*;
 pub use log::*;
 pub use widgets::*;
 
 use std::path::Path;
 
 use clap::Parser;
+use ratatui::DefaultTerminal;
 
 fn main() -> Result<()> {
     let args = Args::parse();
@@ -27,7 +32,7 @@ fn main() -> Result<()> {
 
     tracing::info!("Starting app");
 
-    let path = Path::new("config.toml");
+    let path = Path::new(CONFIG_PATH);
     if !path.exists() {
         return Err(Error::Custom("Couldn't config file".to_string()));
     }
@@ -36,20 +41,42 @@ fn main() -> Result<()> {
     let config: Config = toml::from_str(&contents)
         .map_err(|err| Error::Custom(format!("Couldn't parse config file: {err}")))?;
 
-    let nonogram = args.parse_puzzle()?;
+    let mut term = ratatui::init();
+    let result = run(&args, config, &mut term);
+    ratatui::restore();
+
+    result
+}
+
+fn run(args: &Args, config: Config, term: &mut DefaultTerminal) -> Result<()> {
+    // Let the user pick a puzzle if none is passed
+    let path = match &args.file {
+        Some(path) => path.clone(),
+        None => {
+            let dir = config
+                .settings
+                .puzzles_dir()
+                .ok_or_else(|| Error::Custom("Couldn't find a puzzles directory".to_string()))?;
+
+            match Browser::scan(&dir)?.run(term)? {
+                Some(path) => path,
+                None => return Ok(()),
+            }
+        }
+    };
+
+    let save = args.parse_puzzle(&path)?;
 
     let style = PuzzleStyle {
         grid_size: config.styles.grid_size,
         ..Default::default()
     };
 
-    let mut term = ratatui::init();
-    let mut app = App::new(nonogram, style, config);
+    let mut app = App::new(save, style, config);
 
-    if let Err(err) = app.run(&mut term) {
+    if let Err(err) = app.run(term) {
         tracing::error!("{err:#?}");
     }
-    ratatui::restore();
 
-    Ok(())
+    save_progress(&app.into_save())
 }
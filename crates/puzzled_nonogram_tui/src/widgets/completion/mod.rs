@@ -0,0 +1,62 @@
+mod state;
+
+pub use state::*;
+
+use ratatui::{
+    layout::Alignment,
+    prelude::{Buffer, Rect},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph, StatefulWidgetRef, Widget},
+};
+
+use crate::{AppState, centered_rect, format_duration};
+
+/// Dialog that is shown once the puzzle is solved
+#[derive(Debug, Copy, Clone)]
+pub struct CompletionWidget;
+
+impl StatefulWidgetRef for &CompletionWidget {
+    type State = AppState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut AppState) {
+        let Some(completion) = &state.completion else {
+            return;
+        };
+
+        let area = centered_rect(50, 40, area);
+        let label = state.theme.muted();
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Time      ", label),
+                Span::raw(format_duration(completion.time)),
+            ]),
+            Line::from(vec![
+                Span::styled("Mistakes  ", label),
+                Span::raw(completion.mistakes.to_string()),
+            ]),
+            Line::default(),
+        ];
+
+        if let Some(message) = &completion.message {
+            lines.push(Line::from(message.as_str()));
+            lines.push(Line::default());
+        }
+
+        lines.push(Line::styled(
+            "e: export image  q: quit  <Esc>: close",
+            label,
+        ));
+
+        let block = Block::bordered()
+            .title(" Solved! ")
+            .title_alignment(Alignment::Center)
+            .border_style(state.theme.border(true));
+
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct CompletionState {
+    /// Time it took to solve the puzzle
+    pub time: Duration,
+
+    /// Number of fills that were proven wrong when they were made
+    pub mistakes: usize,
+
+    /// Outcome of the last export of the puzzle image
+    pub message: Option<String>,
+}
+
+impl CompletionState {
+    pub fn new(time: Duration, mistakes: usize) -> Self {
+        Self {
+            time,
+            mistakes,
+            message: None,
+        }
+    }
+}
@@ -3,17 +3,18 @@ mod state;
 
 pub use state::*;
 
+use puzzled_core::TimerState;
 use puzzled_nonogram::{Fill, Order};
 use ratatui::{
     layout::Alignment,
     prelude::{Buffer, Rect},
-    style::{Color, Style},
+    style::Style,
     symbols,
     text::{Line, Span},
     widgets::{LineGauge, StatefulWidgetRef, Widget},
 };
 
-use crate::{AppState, ColorsExt, Focus, MotionRange, PuzzleState, Region, x_aligned};
+use crate::{AppState, ColorsExt, Focus, MotionRange, Region, format_duration, x_aligned};
 
 #[derive(Debug)]
 pub struct FooterWidget;
@@ -42,7 +43,9 @@ impl StatefulWidgetRef for &FooterWidget {
 }
 
 impl FooterWidget {
-    fn create_fill_spans(&self, fill: Fill, state: &PuzzleState) -> Vec<(Span<'_>, Option<Fill>)> {
+    fn create_fill_spans(&self, fill: Fill, app_state: &AppState) -> Vec<(Span<'_>, Option<Fill>)> {
+        let state = &app_state.puzzle;
+        let theme = &app_state.theme;
         let colors = state.puzzle.colors();
         let mut spans: Vec<(Span, Option<Fill>)> = Vec::new();
 
@@ -51,7 +54,10 @@ impl FooterWidget {
             _ => Style::default(),
         };
 
-        let color = colors.get_style(fill).fg.expect("Foreground should be set");
+        let color = colors
+            .get_style(fill, &state.palette, theme)
+            .fg
+            .expect("Foreground should be set");
         style = style.underline_color(color);
 
         // Color brush itself
@@ -62,7 +68,17 @@ impl FooterWidget {
         // Id of the color
         let key = char::try_from(fill).expect("Fill {fill:?} should define a id char");
 
-        let span = Span::styled(key.to_string(), style.fg(Color::White));
+        // Colors show their id on top of themselves, in whichever text color is most readable
+        let key_style = match (fill, colors.get(&fill)) {
+            (Fill::Color(_), Some(color)) => {
+                let palette = &state.palette;
+
+                style.bg(palette.color(color)).fg(palette.text_color(color))
+            }
+            _ => style.fg(theme.text),
+        };
+
+        let span = Span::styled(key.to_string(), key_style);
         spans.push((span, Some(fill)));
 
         spans
@@ -82,7 +98,7 @@ impl FooterWidget {
         let fills: Vec<_> = colors.keys().collect();
 
         for (f, &fill) in fills.iter().enumerate() {
-            fill_spans.extend(self.create_fill_spans(*fill, &state.puzzle));
+            fill_spans.extend(self.create_fill_spans(*fill, state));
 
             if f != fills.len() - 1 {
                 fill_spans.push((Span::raw(" "), None));
@@ -151,8 +167,7 @@ impl FooterWidget {
             .puzzle
             .puzzle
             .colors()
-            .get(&fill)
-            .map(|c| Color::Rgb(c.red, c.green, c.blue))
+            .get_color(fill, &state.puzzle.palette)
             .expect("Current fill {fill:?} should have a defined color");
 
         let order = state.puzzle.motion_order;
@@ -160,7 +175,7 @@ impl FooterWidget {
             Order::Rows => "↔",
             Order::Cols => "↕",
         };
-        let axis_span = Span::styled(order_symbol.to_string(), Style::default().fg(Color::White));
+        let axis_span = Span::styled(order_symbol.to_string(), state.theme.text());
 
         let fill_repeat = 3;
         Line::from(vec![
@@ -212,7 +227,7 @@ impl FooterWidget {
     }
 
     fn render_stats(&self, area: Rect, buf: &mut Buffer, state: &mut AppState) {
-        let style = Style::default().fg(Color::White);
+        let style = state.theme.text();
         let cursor = state.puzzle.cursor;
 
         // Left
@@ -221,8 +236,12 @@ impl FooterWidget {
             .render(area, buf);
 
         // Middle
-        let selection_span = self.selection_span(state).into_centered_line();
-        selection_span.render(area, buf);
+        // Show the latest message instead of the selection while it is active
+        let middle_span = match state.footer.message() {
+            Some(message) => Span::styled(message.to_string(), style),
+            None => self.selection_span(state),
+        };
+        middle_span.into_centered_line().render(area, buf);
 
         // Right
         // Show the dimensions of the puzzle
@@ -240,7 +259,7 @@ impl FooterWidget {
 
     fn selection_span(&self, state: &mut AppState) -> Span<'_> {
         let cursor = state.cursor();
-        let style = Style::default().fg(Color::White);
+        let style = state.theme.text();
         let range = state.selection().range();
 
         let row_rule = &state.rules_left.rules[cursor.y as usize];
@@ -297,17 +316,13 @@ impl FooterWidget {
         buf: &mut Buffer,
         state: &mut AppState,
     ) {
-        let time = state.puzzle.start_time.elapsed();
-        let secs = time.as_secs();
-        let time_str = format!(
-            "{:02}:{:02}:{:02}",
-            (secs / 3600).rem_euclid(60),
-            (secs / 60).rem_euclid(60),
-            secs.rem_euclid(60)
-        );
-
-        let style = Style::default().fg(Color::Gray);
-        let span = Span::styled(time_str, style);
+        let timer = &state.puzzle.timer;
+        let mut time_str = format_duration(timer.elapsed());
+        if timer.state() == TimerState::Stopped {
+            time_str.push_str(" (paused)");
+        }
+
+        let span = Span::styled(time_str, state.theme.muted());
 
         Line::from(span).alignment(alignment).render(area, buf);
     }
@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use puzzled_nonogram::{Fill, Order};
 use ratatui::layout::Rect;
 
@@ -9,10 +11,28 @@ pub struct FooterState {
     pub fill_regions: Vec<Region<Fill>>,
 
     pub area: Rect,
+
+    message: Option<(String, Instant)>,
 }
 
 impl FooterState {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Show a message in the footer for the given duration
+    pub fn set_message<S>(&mut self, message: S, duration: Duration)
+    where
+        S: Into<String>,
+    {
+        self.message = Some((message.into(), Instant::now() + duration));
+    }
+
+    /// Message to show in the footer, if it has not expired yet
+    pub fn message(&self) -> Option<&str> {
+        self.message
+            .as_ref()
+            .filter(|(_, until)| Instant::now() < *until)
+            .map(|(message, _)| message.as_str())
+    }
 }
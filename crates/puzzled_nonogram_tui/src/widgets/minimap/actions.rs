@@ -0,0 +1,91 @@
+use crossterm::event::Event;
+use puzzled_nonogram::Position;
+use ratatui::layout::Position as AppPosition;
+
+use crate::{
+    Action, ActionInput, ActionOutcome, ActionResult, AppState, Error, HandleAction, MiniMapWidget,
+    MotionRange, Result,
+};
+
+impl HandleAction for &MiniMapWidget {
+    fn handle_motion(
+        &self,
+        input: ActionInput,
+        state: &mut AppState,
+    ) -> Result<(ActionOutcome, Option<MotionRange>)> {
+        let event = input.event;
+        let action = input.action;
+        let count = input.repeat.unwrap_or(1) as usize;
+
+        let puzzle = &state.puzzle;
+        let (rows, cols) = (puzzle.puzzle.rows(), puzzle.puzzle.cols());
+        let scroll = puzzle.scroll;
+
+        // Keys move the viewport a page at a time
+        let vp = &puzzle.viewport;
+        let page_rows = count * vp.visible_rows() as usize;
+        let page_cols = count * vp.visible_cols() as usize;
+
+        match action {
+            Action::MoveLeft | Action::ScrollLeft => state.puzzle.scroll_to(Position {
+                col: scroll.col.saturating_sub(page_cols),
+                ..scroll
+            }),
+            Action::MoveRight | Action::ScrollRight => state.puzzle.scroll_to(Position {
+                col: scroll.col + page_cols,
+                ..scroll
+            }),
+            Action::MoveUp | Action::ScrollUp => state.puzzle.scroll_to(Position {
+                row: scroll.row.saturating_sub(page_rows),
+                ..scroll
+            }),
+            Action::MoveDown | Action::ScrollDown => state.puzzle.scroll_to(Position {
+                row: scroll.row + page_rows,
+                ..scroll
+            }),
+
+            // Jump to the edges of the puzzle
+            Action::JumpRowStart => state.puzzle.scroll_to(Position { col: 0, ..scroll }),
+            Action::JumpRowEnd => state.puzzle.scroll_to(Position {
+                col: cols,
+                ..scroll
+            }),
+            Action::JumpColStart => state.puzzle.scroll_to(Position { row: 0, ..scroll }),
+            Action::JumpColEnd => state.puzzle.scroll_to(Position {
+                row: rows,
+                ..scroll
+            }),
+
+            // Center the viewport on the clicked cell
+            Action::Click | Action::Drag => {
+                let Event::Mouse(mouse) = *event else {
+                    return Err(Error::Custom(format!(
+                        "Found invalid event {event:?} for {action:?}"
+                    )));
+                };
+
+                let pos = AppPosition::new(mouse.column, mouse.row);
+                if let Some(pos) = state.minimap.screen_to_puzzle(pos, rows, cols) {
+                    state.puzzle.center_on(pos);
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok((ActionOutcome::Consumed, None))
+    }
+
+    fn handle_command(&self, input: ActionInput, _state: &mut AppState) -> ActionResult {
+        let action = input.action;
+
+        if matches!(
+            action,
+            Action::FocusDown | Action::FocusUp | Action::FocusLeft | Action::FocusRight
+        ) {
+            return Ok(ActionOutcome::LoseFocus);
+        }
+
+        Ok(ActionOutcome::Consumed)
+    }
+}
@@ -1,6 +1,7 @@
+mod actions;
 mod state;
 
-use puzzled_nonogram::Fill;
+use puzzled_nonogram::{Fill, Nonogram};
 pub use state::*;
 
 use ratatui::{
@@ -8,12 +9,15 @@ use ratatui::{
     style::Color,
     symbols::Marker,
     widgets::{
-        StatefulWidgetRef, Widget,
-        canvas::{Canvas, Points},
+        Block, StatefulWidgetRef, Widget,
+        canvas::{Canvas, Points, Rectangle},
     },
 };
 
-use crate::AppState;
+use crate::{AppState, ColorsExt, Focus, MistakeHighlight};
+
+/// Number of cells in each direction of the blocks that the completion is shown for, if the puzzle has no grid
+const HEATMAP_SIZE: usize = 5;
 
 #[derive(Debug, Copy, Clone)]
 pub struct MiniMapWidget;
@@ -22,43 +26,133 @@ impl StatefulWidgetRef for &MiniMapWidget {
     type State = AppState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = &state.theme;
+        let border_style = theme.border(matches!(state.focus, Focus::MiniMap));
+
+        let block = Block::bordered().title(" Map ").border_style(border_style);
+        let map_area = block.inner(area);
+        block.render(area, buf);
+        state.minimap.map_area = map_area;
+
         let puzzle = &state.puzzle.puzzle;
         let colors = puzzle.colors();
+        let palette = &state.puzzle.palette;
+        let (rows, cols) = (puzzle.rows(), puzzle.cols());
         let cell_width = state.puzzle.style.cell_width;
         let cell_height = state.puzzle.style.cell_height;
 
+        let block_size = state.puzzle.style.grid_size.unwrap_or(HEATMAP_SIZE);
+        let completion = block_completion(puzzle, block_size);
+        let show_errors = matches!(state.mistake_highlight, MistakeHighlight::Cells);
+        let vp = &state.puzzle.viewport;
+
         Canvas::default()
-            .x_bounds([0.0, (cell_width * puzzle.cols()) as f64])
-            .y_bounds([0.0, (cell_height * puzzle.rows()) as f64])
-            .marker(Marker::Braille)
+            .x_bounds([0.0, (cell_width * cols) as f64])
+            .y_bounds([0.0, (cell_height * rows) as f64])
+            .marker(Marker::HalfBlock)
             .paint(|ctx| {
-                for (r, row) in puzzle.fills().iter_rows().enumerate() {
-                    let y_start = cell_height * (puzzle.rows() - r);
-
-                    for (c, cell) in row.enumerate() {
-                        let x_start = cell_width * c;
-                        let fill = cell.solution.unwrap_or_default();
-
-                        if matches!(fill, Fill::Color(_))
-                            && let Some(c) = colors.get(&fill)
-                        {
-                            let coords: Vec<_> = (x_start..x_start + cell_width)
-                                .flat_map(move |x| {
-                                    (y_start..y_start + cell_height)
-                                        .map(move |y| (x as f64, y as f64))
-                                })
-                                .collect();
-
-                            let points = Points {
-                                coords: &coords,
-                                color: Color::Rgb(c.red, c.green, c.blue),
-                            };
-
-                            ctx.draw(&points);
-                        }
-                    }
+                for (pos, cell) in puzzle.fills().iter_indexed() {
+                    let fill = cell.solution.unwrap_or_default();
+
+                    // Wrong cells stand out over their fill, while the other cells are shaded by how complete their block is
+                    let color = if show_errors && state.mistakes.cells.contains(&pos) {
+                        theme.mistake
+                    } else if let Fill::Color(_) = fill
+                        && let Some(color) = colors.get_color(fill, palette)
+                    {
+                        color
+                    } else {
+                        heat(completion[pos.row / block_size][pos.col / block_size])
+                    };
+
+                    // Flip the rows, as the canvas starts from its bottom
+                    let x_start = cell_width * pos.col;
+                    let y_start = cell_height * (rows - 1 - pos.row);
+
+                    let coords: Vec<_> = (x_start..x_start + cell_width)
+                        .flat_map(move |x| {
+                            (y_start..y_start + cell_height).map(move |y| (x as f64, y as f64))
+                        })
+                        .collect();
+
+                    ctx.draw(&Points {
+                        coords: &coords,
+                        color,
+                    });
                 }
+
+                // Outline the part of the puzzle that is visible in the viewport
+                ctx.layer();
+                ctx.draw(&Rectangle {
+                    x: (cell_width * vp.col_start as usize) as f64,
+                    y: (cell_height * (rows - vp.row_end as usize)) as f64,
+                    width: (cell_width * vp.visible_cols() as usize).saturating_sub(1) as f64,
+                    height: (cell_height * vp.visible_rows() as usize).saturating_sub(1) as f64,
+                    color: theme.viewport,
+                });
             })
-            .render(area, buf);
+            .render(map_area, buf);
+    }
+}
+
+/// Fraction of the cells that are filled in or crossed out for every block of `size` by `size` cells
+fn block_completion(puzzle: &Nonogram, size: usize) -> Vec<Vec<f64>> {
+    let block_rows = puzzle.rows().div_ceil(size);
+    let block_cols = puzzle.cols().div_ceil(size);
+
+    let mut done = vec![vec![0usize; block_cols]; block_rows];
+    let mut total = vec![vec![0usize; block_cols]; block_rows];
+
+    for (pos, cell) in puzzle.fills().iter_indexed() {
+        let (row, col) = (pos.row / size, pos.col / size);
+
+        total[row][col] += 1;
+        if cell.solution.is_some_and(|fill| fill != Fill::Blank) {
+            done[row][col] += 1;
+        }
+    }
+
+    done.into_iter()
+        .zip(total)
+        .map(|(done, total)| {
+            done.into_iter()
+                .zip(total)
+                .map(|(done, total)| done as f64 / total as f64)
+                .collect()
+        })
+        .collect()
+}
+
+/// Shade of an unfilled cell, going from red for an untouched block to green for a completed block
+fn heat(completion: f64) -> Color {
+    let completion = completion.clamp(0.0, 1.0);
+
+    let red = 20 + (50.0 * (1.0 - completion)) as u8;
+    let green = 20 + (50.0 * completion) as u8;
+
+    Color::Rgb(red, green, 20)
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_nonogram::{Position, nonogram};
+
+    use super::*;
+
+    #[test]
+    fn completion_per_block() {
+        let mut puzzle = nonogram!(
+            [1 1 x]
+            [x 1 1]
+            [x x 1]
+        );
+        for cell in puzzle.fills_mut().iter_mut() {
+            cell.solution = None;
+        }
+        puzzle[Position::new(0, 0)].solution = Some(Fill::Cross);
+        puzzle[Position::new(2, 2)].solution = Some(Fill::Blank);
+
+        let completion = block_completion(&puzzle, 2);
+        assert_eq!(completion, [[0.25, 0.0], [0.0, 0.0]]);
     }
 }
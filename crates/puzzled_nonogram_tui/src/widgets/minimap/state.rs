@@ -1,6 +1,50 @@
-use ratatui::layout::Rect;
+use puzzled_nonogram::Position;
+use ratatui::layout::{Position as AppPosition, Rect};
 
 #[derive(Debug, Default)]
 pub struct MiniMapState {
     pub area: Rect,
+
+    /// Area within the borders that the puzzle is drawn in
+    pub map_area: Rect,
+}
+
+impl MiniMapState {
+    /// Cell of a puzzle with the given dimensions that is drawn at the screen position, if any
+    pub fn screen_to_puzzle(&self, pos: AppPosition, rows: usize, cols: usize) -> Option<Position> {
+        let area = self.map_area;
+        if !area.contains(pos) {
+            return None;
+        }
+
+        // The puzzle is stretched over the full map
+        let col = (pos.x - area.x) as usize * cols / area.width as usize;
+        let row = (pos.y - area.y) as usize * rows / area.height as usize;
+
+        Some(Position { row, col })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::top_left((10, 5), Some((0, 0)))]
+    #[case::bottom_right((29, 14), Some((9, 38)))]
+    #[case::center((20, 10), Some((5, 20)))]
+    #[case::outside((30, 5), None)]
+    fn screen_to_puzzle(#[case] pos: (u16, u16), #[case] expected: Option<(usize, usize)>) {
+        let state = MiniMapState {
+            map_area: Rect::new(10, 5, 20, 10),
+            ..Default::default()
+        };
+
+        let pos = state.screen_to_puzzle(pos.into(), 10, 40);
+        let expected = expected.map(|(row, col)| Position { row, col });
+
+        assert_eq!(pos, expected);
+    }
 }
@@ -1,17 +1,23 @@
+mod completion;
 mod footer;
 mod layout;
 mod minimap;
+mod palette;
 mod puzzle;
 mod region;
 mod rules;
 
 use puzzled_nonogram::{Colors, Fill};
+
+use crate::{Palette, Theme};
 use ratatui::style::{Color, Modifier};
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
+pub use completion::*;
 pub use footer::*;
 pub use layout::*;
 pub use minimap::*;
+pub use palette::*;
 pub use puzzle::*;
 pub use region::*;
 pub use rules::*;
@@ -48,23 +54,42 @@ pub fn x_aligned(area: Rect, width: u16, alignment: Alignment) -> u16 {
     }
 }
 
+/// Format a duration as hours, minutes and seconds
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    )
+}
+
 pub trait ColorsExt {
-    fn get_style(&self, fill: Fill) -> Style;
+    /// Color to draw the fill with, taking the overrides of the palette into account
+    fn get_color(&self, fill: Fill, palette: &Palette) -> Option<Color>;
+
+    fn get_style(&self, fill: Fill, palette: &Palette, theme: &Theme) -> Style;
 }
 
 impl ColorsExt for Colors {
-    fn get_style(&self, fill: Fill) -> Style {
+    fn get_color(&self, fill: Fill, palette: &Palette) -> Option<Color> {
+        self.get(&fill).map(|color| palette.color(color))
+    }
+
+    fn get_style(&self, fill: Fill, palette: &Palette, theme: &Theme) -> Style {
         let style = Style::default();
 
         match fill {
-            Fill::Blank => style.fg(Color::DarkGray).add_modifier(Modifier::DIM),
-            Fill::Cross => style.fg(Color::Gray),
+            Fill::Blank => style.fg(theme.blank).add_modifier(Modifier::DIM),
+            Fill::Cross => style.fg(theme.cross),
             col @ Fill::Color(_) => {
                 let color = self
-                    .get(&col)
+                    .get_color(col, palette)
                     .unwrap_or_else(|| panic!("Color for fill {col:?} should be set"));
 
-                style.fg(Color::Rgb(color.red, color.green, color.blue))
+                style.fg(color)
             }
         }
     }
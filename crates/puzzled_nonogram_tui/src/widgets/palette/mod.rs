@@ -0,0 +1,85 @@
+mod state;
+
+pub use state::*;
+
+use ratatui::{
+    layout::Alignment,
+    prelude::{Buffer, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph, StatefulWidgetRef, Widget},
+};
+
+use crate::{AppState, centered_rect};
+
+/// Dialog to remap the colors of the puzzle to colors the terminal can show
+#[derive(Debug, Copy, Clone)]
+pub struct PaletteWidget;
+
+impl StatefulWidgetRef for &PaletteWidget {
+    type State = AppState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut AppState) {
+        let Some(dialog) = &state.palette_dialog else {
+            return;
+        };
+
+        let area = centered_rect(50, 60, area);
+        let label = state.theme.muted();
+        let palette = &state.puzzle.palette;
+
+        let mut lines = Vec::new();
+
+        for (idx, fill) in state.palette_fills().into_iter().enumerate() {
+            let Some(color) = state.puzzle.puzzle.colors().get(&fill) else {
+                continue;
+            };
+            let key = char::try_from(fill).unwrap_or('?');
+
+            // Show the original color next to the color it is drawn with
+            let original = Style::default().bg(Color::Rgb(color.red, color.green, color.blue));
+            let drawn = Style::default().bg(palette.color(color));
+            let name = match palette.get(color) {
+                Some(new) => new.to_string(),
+                None => "original".to_string(),
+            };
+
+            let mut spans = vec![
+                Span::raw(format!("{key}  ")),
+                Span::styled("  ", original),
+                Span::styled(format!(" {:<9} ", color.to_hex()), label),
+                Span::raw("→ "),
+                Span::styled("  ", drawn),
+                Span::raw(format!(" {name:<12}")),
+            ];
+
+            if idx == dialog.selected {
+                for span in &mut spans {
+                    span.style = span.style.add_modifier(Modifier::BOLD);
+                }
+                spans.insert(0, Span::raw("> "));
+            } else {
+                spans.insert(0, Span::raw("  "));
+            }
+
+            lines.push(Line::from(spans));
+        }
+
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            "j/k: select  h/l: change  r: reset  <Esc>: save",
+            label,
+        ));
+
+        let block = Block::bordered()
+            .title(" Palette ")
+            .title_alignment(Alignment::Center)
+            .border_style(state.theme.border(true));
+
+        Clear.render(area, buf);
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(block)
+            .render(area, buf);
+    }
+}
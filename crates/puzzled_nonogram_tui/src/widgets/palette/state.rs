@@ -0,0 +1,29 @@
+use puzzled_nonogram::Fill;
+
+#[derive(Debug, Default)]
+pub struct PaletteState {
+    /// Index of the selected color among the colored fills of the puzzle
+    pub selected: usize,
+
+    /// Whether the palette was changed since the dialog was opened
+    pub changed: bool,
+}
+
+impl PaletteState {
+    /// Selected fill among the given colored fills
+    pub fn fill(&self, fills: &[Fill]) -> Option<Fill> {
+        fills.get(self.selected).copied()
+    }
+
+    /// Move the selection up or down, wrapping around at both ends
+    pub fn select(&mut self, forwards: bool, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        self.selected = match forwards {
+            true => (self.selected + 1) % count,
+            false => (self.selected + count - 1) % count,
+        };
+    }
+}
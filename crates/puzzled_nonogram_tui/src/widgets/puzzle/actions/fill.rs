@@ -1,49 +1,91 @@
-use puzzled_nonogram::{Fill, Position};
+use std::collections::BTreeSet;
+
+use puzzled_nonogram::{Fill, Line, Position};
 
 use crate::{ActionOutcome, ActionResult, AppState, UndoAction};
 
 #[derive(Debug, Clone)]
 pub struct FillAction {
     changes: Vec<CellChange>,
+
+    /// Cells that were [crossed out automatically](puzzled_nonogram::Nonogram::auto_cross_line) after the changes
+    crossed: Vec<CellChange>,
 }
 
 impl FillAction {
     pub fn new(changes: Vec<CellChange>) -> Self {
-        Self { changes }
+        Self {
+            changes,
+            crossed: Vec::new(),
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct CellChange {
     pos: Position,
-    before: Fill,
+
+    /// Fill before the change, where [`None`] means the cell was not touched yet
+    before: Option<Fill>,
     after: Fill,
 }
 
 impl CellChange {
-    pub fn new(pos: Position, before: Fill, after: Fill) -> Self {
+    pub fn new(pos: Position, before: Option<Fill>, after: Fill) -> Self {
         Self { pos, before, after }
     }
 }
 
 impl UndoAction for FillAction {
     fn execute(&mut self, state: &mut AppState) -> ActionResult {
-        for _change in &self.changes {
-            let _puzzle = &mut state.puzzle.puzzle;
+        let puzzle = &mut state.puzzle.puzzle;
+        let mut lines = BTreeSet::new();
+
+        for change in &self.changes {
+            puzzle[change.pos].solution = Some(change.after);
 
-            // Then update the cell state in the solver
-            // TODO: put back update cell state.solver.update_cell(puzzle, change.pos, change.after);
+            lines.insert(Line::Row(change.pos.row));
+            lines.insert(Line::Col(change.pos.col));
         }
 
+        self.crossed.clear();
+
+        if state.settings.auto_cross {
+            for line in lines {
+                // Remember the fills before crossing, as untouched and blank cells are both crossed out
+                let before: Vec<_> = puzzle
+                    .fills()
+                    .iter_indexed_line(line)
+                    .map(|(pos, cell)| (pos, cell.solution))
+                    .collect();
+                let crossed = puzzle.auto_cross_line(line);
+
+                self.crossed.extend(
+                    before
+                        .into_iter()
+                        .filter(|(pos, _)| crossed.contains(pos))
+                        .map(|(pos, fill)| CellChange::new(pos, fill, Fill::Cross)),
+                );
+            }
+        }
+
+        let filled: Vec<_> = self
+            .changes
+            .iter()
+            .filter(|change| change.after != Fill::Blank)
+            .map(|change| change.pos)
+            .collect();
+        state.count_mistakes(&filled);
+
         Ok(ActionOutcome::Consumed)
     }
 
     fn undo(&mut self, state: &mut AppState) -> ActionResult {
-        for _change in &self.changes {
-            let _puzzle = &mut state.puzzle.puzzle;
+        let puzzle = &mut state.puzzle.puzzle;
 
-            // Then update the cell state in the solver
-            // TODO: put back update cell state.solver.update_cell(puzzle, change.pos, change.before);
+        // Revert the crossed out cells first, as they were changed after the fills
+        for change in self.changes.iter().chain(&self.crossed).rev() {
+            puzzle[change.pos].solution = change.before;
         }
 
         Ok(ActionOutcome::Consumed)
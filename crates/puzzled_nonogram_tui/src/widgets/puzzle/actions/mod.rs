@@ -3,7 +3,9 @@ mod fill;
 pub use fill::*;
 
 use crossterm::event::{Event, KeyCode};
-use puzzled_nonogram::{Fill, FillsFind, FindDirection, LinePosition, Nonogram, Position};
+use puzzled_nonogram::{
+    Direction, Fill, FillsFind, FindDirection, LinePosition, Nonogram, Position,
+};
 use ratatui::layout::Position as AppPosition;
 
 use crate::{
@@ -57,34 +59,27 @@ impl HandleAction for &PuzzleWidget {
         let mut cmd: Option<ActionResult> = None;
 
         // Bounds
-        let max_row = puzzle.rows() - 1;
-        let max_col = puzzle.cols() - 1;
+        let (rows, cols) = (puzzle.rows(), puzzle.cols());
+        let max_row = rows - 1;
+        let max_col = cols - 1;
         let vp = &state.puzzle.viewport;
 
         // Positions
         let pos: Position = app_to_puzzle(state.puzzle.cursor);
-        let col = pos.col;
-        let row = pos.row;
         let axis_pos = pos.with_order(state.puzzle.motion_order);
 
         let end: Position = match action {
             // Moves
-            Action::MoveLeft | Action::ScrollLeft => Position {
-                col: col.saturating_sub(count),
-                ..pos
-            },
-            Action::MoveRight | Action::ScrollRight => Position {
-                col: (col + count).min(max_col),
-                ..pos
-            },
-            Action::MoveUp | Action::ScrollUp => Position {
-                row: row.saturating_sub(count),
-                ..pos
-            },
-            Action::MoveDown | Action::ScrollDown => Position {
-                row: (row + count).min(max_row),
-                ..pos
-            },
+            Action::MoveLeft | Action::ScrollLeft => {
+                pos.step_clamped(Direction::Left, count, rows, cols)
+            }
+            Action::MoveRight | Action::ScrollRight => {
+                pos.step_clamped(Direction::Right, count, rows, cols)
+            }
+            Action::MoveUp | Action::ScrollUp => pos.step_clamped(Direction::Up, count, rows, cols),
+            Action::MoveDown | Action::ScrollDown => {
+                pos.step_clamped(Direction::Down, count, rows, cols)
+            }
 
             // Fill finds
             Action::FindFillForwards if fill.is_some() => puzzle
@@ -314,10 +309,10 @@ fn handle_fills(fill: Fill, range: Option<MotionRange>, state: &AppState) -> Act
 
     for pos in range.positions(&bounds) {
         let pos = app_to_puzzle(pos);
-        let before = state.puzzle.puzzle[pos].solution.unwrap_or_default();
+        let before = state.puzzle.puzzle[pos].solution;
 
         // Only record actual changes
-        if before == fill {
+        if before.unwrap_or_default() == fill {
             continue;
         }
 
@@ -12,11 +12,11 @@ use puzzled_nonogram::{Fill, NonogramCell};
 use ratatui::{
     buffer::Buffer,
     layout::{Position as AppPosition, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::StatefulWidgetRef,
 };
 
-use crate::{AppState, ColorsExt, Focus, app_to_puzzle, safe_draw_str};
+use crate::{AppState, ColorsExt, Focus, MistakeHighlight, app_to_puzzle, safe_draw_str};
 
 #[derive(Debug, Copy, Clone)]
 pub struct PuzzleWidget;
@@ -34,7 +34,7 @@ impl PuzzleWidget {
     fn draw_puzzle(&self, buf: &mut Buffer, app_state: &AppState) {
         let state = &app_state.puzzle;
         let cell_width = state.style.cell_width;
-        let div_style = Style::default().fg(Color::DarkGray);
+        let div_style = Style::default().fg(app_state.theme.divider);
 
         // Determine which rows and columns to display
         // Keep track of which positions to draw in the viewport
@@ -114,10 +114,7 @@ impl PuzzleWidget {
     }
 
     fn draw_borders(&self, area: Rect, buf: &mut Buffer, state: &AppState) {
-        let mut style = Style::default().fg(Color::Gray).dim();
-        if matches!(state.focus, Focus::Puzzle) {
-            style = style.fg(Color::White).not_dim().bold();
-        }
+        let style = state.theme.border(matches!(state.focus, Focus::Puzzle));
 
         // Corners
         let x_start = area.x;
@@ -155,14 +152,22 @@ impl PuzzleWidget {
         state: &AppState,
     ) -> Style {
         let fill = cell.solution.unwrap_or_default();
+        let theme = &state.theme;
         let colors = state.puzzle.puzzle.colors();
-        let mut style = colors.get_style(fill);
+        let mut style = colors.get_style(fill, &state.puzzle.palette, theme);
+
+        // Cells that are proven wrong
+        if matches!(state.mistake_highlight, MistakeHighlight::Cells)
+            && state.mistakes.cells.contains(&app_to_puzzle(pos))
+        {
+            style = style.bg(theme.mistake);
+        }
 
         // Active line
         if matches!(state.focus, Focus::Puzzle) {
             if pos.x == state.puzzle.cursor.x || pos.y == state.puzzle.cursor.y {
                 if !matches!(fill, Fill::Color(_)) {
-                    style = style.fg(Color::White);
+                    style = style.fg(theme.cursor);
                 }
 
                 // Active cell
@@ -173,7 +178,7 @@ impl PuzzleWidget {
 
             // // Visual selection
             if is_selected {
-                style = style.fg(Color::LightCyan).add_modifier(Modifier::BOLD)
+                style = style.fg(theme.selection).add_modifier(Modifier::BOLD)
             }
         }
 
@@ -1,9 +1,10 @@
-use std::time::Instant;
+use std::time::Duration;
 
+use puzzled_core::{Timer, TimerState};
 use puzzled_nonogram::{Fill, Nonogram, Order, Position};
 use ratatui::layout::{Position as AppPosition, Rect, Size};
 
-use crate::{PuzzleStyle, Selection, Viewport};
+use crate::{Palette, PuzzleStyle, Selection, Viewport};
 
 #[derive(Debug)]
 pub struct PuzzleState {
@@ -11,7 +12,11 @@ pub struct PuzzleState {
 
     pub style: PuzzleStyle,
 
-    pub start_time: Instant,
+    /// Terminal colors to draw the puzzle colors with
+    pub palette: Palette,
+
+    /// Time spent solving the puzzle, which only runs while the app is in focus
+    pub timer: Timer,
 
     /// Selected area of the viewport
     pub selection: Selection,
@@ -41,9 +46,10 @@ impl PuzzleState {
             puzzle,
             style,
             fill,
+            palette: Palette::default(),
 
             selection: Selection::empty(order),
-            start_time: Instant::now(),
+            timer: Timer::new(Duration::ZERO, TimerState::Running),
             cursor: AppPosition::default(),
             area: Rect::default(),
             viewport: Viewport::default(),
@@ -51,6 +57,14 @@ impl PuzzleState {
             motion_order: order,
         }
     }
+
+    /// Continue the timer from a previous session
+    pub fn with_timer(mut self, mut timer: Timer) -> Self {
+        timer.start();
+        self.timer = timer;
+        self
+    }
+
     pub fn bounds(&self) -> Rect {
         let width = self.puzzle.cols();
         let height = self.puzzle.rows();
@@ -225,6 +239,47 @@ impl PuzzleState {
         tracing::info!("\tScroll after: {scroll:?}");
     }
 
+    /// Scroll the viewport as close to having `scroll` as its top-left cell as the puzzle allows
+    ///
+    /// The cursor is moved along to the nearest visible cell, such that the next motion does not scroll back
+    pub fn scroll_to(&mut self, scroll: Position) {
+        let vp = &self.viewport;
+        let max_row = self
+            .puzzle
+            .rows()
+            .saturating_sub(vp.visible_rows() as usize);
+        let max_col = self
+            .puzzle
+            .cols()
+            .saturating_sub(vp.visible_cols() as usize);
+
+        self.scroll = Position {
+            row: scroll.row.min(max_row),
+            col: scroll.col.min(max_col),
+        };
+        self.update_viewport();
+
+        let vp = &self.viewport;
+        self.cursor.x = self
+            .cursor
+            .x
+            .clamp(vp.col_start, vp.col_end.saturating_sub(1));
+        self.cursor.y = self
+            .cursor
+            .y
+            .clamp(vp.row_start, vp.row_end.saturating_sub(1));
+    }
+
+    /// [Scroll](Self::scroll_to) the viewport such that the cell is in its center
+    pub fn center_on(&mut self, pos: Position) {
+        let vp = &self.viewport;
+
+        self.scroll_to(Position {
+            row: pos.row.saturating_sub(vp.visible_rows() as usize / 2),
+            col: pos.col.saturating_sub(vp.visible_cols() as usize / 2),
+        });
+    }
+
     pub fn size(&self) -> Size {
         let cols = self.puzzle.cols();
         let rows = self.puzzle.rows();
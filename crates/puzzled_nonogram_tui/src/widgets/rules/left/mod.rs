@@ -1,10 +1,10 @@
 mod actions;
 
-use puzzled_nonogram::{Fill, Line, LineValidation, Rule, Run};
+use puzzled_nonogram::{Fill, Line, Rule, Run};
 use ratatui::{
     layout::Alignment,
     prelude::{Buffer, Rect},
-    style::{Color, Style},
+    style::Style,
     text::{Line as TextLine, Span},
     widgets::{Block, Borders, Paragraph, StatefulWidgetRef, TitlePosition, Widget},
 };
@@ -21,10 +21,7 @@ impl StatefulWidgetRef for &RowRulesWidget {
     type State = AppState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut AppState) {
-        let mut style = Style::default().fg(Color::Gray).dim();
-        if matches!(state.focus, Focus::RulesLeft) {
-            style = style.fg(Color::White).not_dim().bold();
-        }
+        let style = state.theme.border(matches!(state.focus, Focus::RulesLeft));
 
         self.draw(area, buf, state);
 
@@ -65,8 +62,7 @@ impl RowRulesWidget {
             let row = row as usize;
             let rule = &self.rules[row];
             let line = Line::Row(row);
-            // TODO: add back validation getter let validation = state.solver[line];
-            let validation = LineValidation::Valid;
+            let validation = state.rules_left.validation(row);
 
             let info = RuleInfo {
                 rule,
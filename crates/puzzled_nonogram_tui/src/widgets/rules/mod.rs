@@ -11,10 +11,10 @@ pub use top::*;
 use puzzled_nonogram::{Fill, Line, LineValidation, Rule};
 use ratatui::{
     layout::Position,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
 };
 
-use crate::{AppState, Focus};
+use crate::{AppState, ColorsExt, Focus};
 
 pub struct RuleInfo<'a> {
     rule: &'a Rule,
@@ -29,8 +29,7 @@ pub fn run_style(info: &RuleInfo, fill: Fill, idx: u16, state: &AppState) -> Sty
     let colors = state.puzzle.puzzle.colors();
 
     let color = colors
-        .get(&fill)
-        .map(|c| Color::Rgb(c.red, c.green, c.blue))
+        .get_color(fill, &state.puzzle.palette)
         .expect("Fill {fill:?} should have a defined color");
 
     let base = Style::default().fg(color);
@@ -38,12 +37,12 @@ pub fn run_style(info: &RuleInfo, fill: Fill, idx: u16, state: &AppState) -> Sty
     let mut style = match validation {
         // Cross out solved lines
         LineValidation::Solved => base
-            .fg(Color::DarkGray)
+            .fg(state.theme.blank)
             .add_modifier(Modifier::DIM | Modifier::CROSSED_OUT),
 
         // Shade invalid rules in red
         v if !v.is_valid() => base
-            .fg(Color::Red)
+            .fg(state.theme.invalid)
             .add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
 
         _ => base,
@@ -90,11 +89,12 @@ pub fn status_info(info: &RuleInfo, state: &AppState) -> (Style, char) {
     } = info;
 
     let cursor = state.puzzle.cursor;
-    let base = Style::default().fg(Color::White);
+    let theme = &state.theme;
+    let base = theme.text();
 
     let mut style = match validation {
-        LineValidation::Solved => base.fg(Color::Green),
-        val if !val.is_valid() => base.fg(Color::Red),
+        LineValidation::Solved => base.fg(theme.solved),
+        val if !val.is_valid() => base.fg(theme.invalid),
         _ => base,
     };
 
@@ -1,4 +1,9 @@
-use puzzled_nonogram::{Fill, Order, Position, Rule};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::Instant,
+};
+
+use puzzled_nonogram::{Fill, LineValidation, Order, Position, Rule};
 use ratatui::layout::{Position as AppPosition, Rect};
 
 use crate::{Region, RuleDisplay, Selection, puzzle_to_app};
@@ -19,6 +24,13 @@ pub struct RuleState {
     pub overflow_area: Rect,
 
     pub fill_regions: Vec<Region<Fill>>,
+
+    /// Lines that violated their rule during the last check, highlighted until the given instant
+    pub violations: BTreeMap<usize, LineValidation>,
+    pub violations_until: Option<Instant>,
+
+    /// Lines whose entries contradict the rules while mistakes are highlighted
+    pub mistakes: BTreeSet<usize>,
 }
 
 impl RuleState {
@@ -60,6 +72,22 @@ impl RuleState {
             .unwrap_or_default()
     }
 
+    /// Validation of the rule at the given index from the last check, if it is still highlighted
+    ///
+    /// Falls back on whether the line is one of the highlighted [mistakes](Self::mistakes)
+    pub fn validation(&self, idx: usize) -> LineValidation {
+        let checked = match self.violations_until {
+            Some(until) if Instant::now() < until => self.violations.get(&idx).copied(),
+            _ => None,
+        };
+
+        match checked {
+            Some(validation) => validation,
+            None if self.mistakes.contains(&idx) => LineValidation::Invalid,
+            None => LineValidation::Valid,
+        }
+    }
+
     pub fn follow_puzzle_cursor(&mut self, cursor: Position) {
         let cursor = match self.order {
             Order::Rows => {
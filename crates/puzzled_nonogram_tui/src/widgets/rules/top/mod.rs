@@ -1,10 +1,10 @@
 mod actions;
 
-use puzzled_nonogram::{Fill, Line, LineValidation, Rule, Run};
+use puzzled_nonogram::{Fill, Line, Rule, Run};
 use ratatui::{
     layout::{Alignment, Position},
     prelude::{Buffer, Rect},
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Paragraph, StatefulWidgetRef, TitlePosition, Widget},
 };
 
@@ -28,10 +28,7 @@ impl StatefulWidgetRef for &ColRulesWidget {
             ..area
         };
 
-        let mut style = Style::default().fg(Color::DarkGray).dim();
-        if matches!(state.focus, Focus::RulesTop) {
-            style = style.fg(Color::White).not_dim().bold();
-        }
+        let style = state.theme.border(matches!(state.focus, Focus::RulesTop));
 
         let block = Rect {
             x: area.x + 2,
@@ -78,8 +75,7 @@ impl ColRulesWidget {
 
             let rule = &self.rules[col];
             let line = Line::Col(col);
-            // TODO: add back validation getter let validation = state.solver[line];
-            let validation = LineValidation::Valid;
+            let validation = state.rules_top.validation(col);
 
             let info = RuleInfo {
                 rule,
@@ -44,6 +44,10 @@ impl Skyscrapers {
     pub fn meta(&self) -> &Metadata {
         &self.meta
     }
+
+    pub fn meta_mut(&mut self) -> &mut Metadata {
+        &mut self.meta
+    }
 }
 
 impl Puzzle for Skyscrapers {
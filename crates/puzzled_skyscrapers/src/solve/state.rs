@@ -22,7 +22,7 @@ impl SkyscraperState {
             state: GridState {
                 solutions,
                 entries,
-                timer,
+                timer: timer.clone(),
             },
             timer,
             _frontier: VecDeque::default(),
@@ -0,0 +1,6 @@
+mod sdk;
+
+#[cfg(feature = "text")]
+mod text;
+
+pub use sdk::*;
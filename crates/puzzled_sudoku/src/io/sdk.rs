@@ -0,0 +1,318 @@
+//! Reading and writing sudokus in the `*.sdk` format of SadMan Sudoku and the single-line format of SudokuPad
+//!
+//! An `*.sdk` file starts with optional `#` directives for its metadata, followed by a row of digits per line where `.` marks a missing digit:
+//! ```text
+//! #ASomeone
+//! #DEasy sudoku
+//! 1..4
+//! ..1.
+//! .1..
+//! 4..1
+//! ```
+//! The single-line format lists all rows directly after each other, e.g. `1..4..1..1..4..1`, and is what most online tools (such as SudokuPad) import and export.
+use std::{fs, io, path::Path};
+
+use puzzled_core::{Grid, Metadata};
+
+use crate::{Digit, Sudoku, SudokuError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SdkError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Invalid character '{ch}' at {line}:{col}")]
+    InvalidChar { ch: char, line: usize, col: usize },
+
+    #[error("Row at line {line} has {found} cells, expected {expected}")]
+    InvalidRowLength {
+        line: usize,
+        found: usize,
+        expected: usize,
+    },
+
+    #[error("Found {found} cells on a single line, which do not form a square sudoku")]
+    InvalidCellCount { found: usize },
+
+    #[error("Unknown directive '#{directive}' at line {line}")]
+    UnknownDirective { directive: char, line: usize },
+
+    #[error("Expected at least one row of digits")]
+    Empty,
+
+    #[error("{0}")]
+    Sudoku(#[from] SudokuError),
+}
+
+pub type Result<T> = core::result::Result<T, SdkError>;
+
+/// Reads [sudokus](Sudoku) from `*.sdk` and single-line data
+///
+/// Strict readers only accept `.` and `0` besides digits and reject unknown directives.
+/// Other readers skip them, together with the `|`, `+` and `-` characters that are often used to draw the boxes.
+/// ```
+/// use puzzled::sudoku::SdkReader;
+///
+/// let reader = SdkReader::default();
+///
+/// let puzzle = reader.read("#DMini\n1..4\n..1.\n.1..\n4..1\n").unwrap();
+/// assert_eq!(puzzle.meta().title(), Some("Mini"));
+///
+/// let line = reader.read("1..4..1..1..4..1").unwrap();
+/// assert_eq!(line.givens(), puzzle.givens());
+/// ```
+#[derive(Debug, Default)]
+pub struct SdkReader {
+    strict: bool,
+}
+
+impl SdkReader {
+    pub fn new(strict: bool) -> Self {
+        Self { strict }
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn read(&self, input: &str) -> Result<Sudoku> {
+        let mut meta = Metadata::default();
+        let mut rows: Vec<(usize, Vec<Option<Digit>>)> = Vec::new();
+
+        for (idx, line) in input.lines().enumerate() {
+            let line_nr = idx + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.eq_ignore_ascii_case("[puzzle]") {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix('#') {
+                meta = self.read_directive(directive, line_nr, meta)?;
+                continue;
+            }
+
+            let row = self.read_row(line, line_nr)?;
+            if !row.is_empty() {
+                rows.push((line_nr, row));
+            }
+        }
+
+        let givens = match rows.as_slice() {
+            [] => return Err(SdkError::Empty),
+            [(_, cells)] if cells.len() > 1 => {
+                let size = cells.len().isqrt();
+                if size * size != cells.len() {
+                    return Err(SdkError::InvalidCellCount { found: cells.len() });
+                }
+
+                Grid::from_vec(cells.clone(), size).expect("Cells form a square")
+            }
+            rows => {
+                let expected = rows.len();
+                let mut cells = Vec::with_capacity(expected * expected);
+
+                for (line, row) in rows {
+                    if row.len() != expected {
+                        return Err(SdkError::InvalidRowLength {
+                            line: *line,
+                            found: row.len(),
+                            expected,
+                        });
+                    }
+
+                    cells.extend(row);
+                }
+
+                Grid::from_vec(cells, expected).expect("Rows form a square")
+            }
+        };
+
+        Ok(Sudoku::new(givens, meta)?)
+    }
+
+    pub fn read_from_path<P>(&self, path: P) -> Result<Sudoku>
+    where
+        P: AsRef<Path>,
+    {
+        let input = fs::read_to_string(path)?;
+        self.read(&input)
+    }
+
+    fn read_directive(&self, directive: &str, line: usize, meta: Metadata) -> Result<Metadata> {
+        let mut chars = directive.chars();
+        let Some(kind) = chars.next() else {
+            return Ok(meta);
+        };
+        let value = chars.as_str().trim().to_string();
+
+        let meta = match kind {
+            'A' => meta.with_author(value),
+            'D' => meta.with_title(value),
+            'C' => match meta.notes() {
+                Some(notes) => {
+                    let notes = format!("{notes}\n{value}");
+                    meta.with_notes(notes)
+                }
+                None => meta.with_notes(value),
+            },
+            // Publication date, source, level and URL
+            'B' | 'S' | 'L' | 'U' => meta,
+            directive if self.strict => {
+                return Err(SdkError::UnknownDirective { directive, line });
+            }
+            _ => meta,
+        };
+
+        Ok(meta)
+    }
+
+    fn read_row(&self, line: &str, line_nr: usize) -> Result<Vec<Option<Digit>>> {
+        let mut row = Vec::with_capacity(line.len());
+
+        for (idx, ch) in line.chars().enumerate() {
+            match ch {
+                '.' | '0' => row.push(None),
+                '1'..='9' => row.push(Digit::try_from(ch).ok()),
+                ' ' | '|' | '+' | '-' if !self.strict => {}
+                ch => {
+                    return Err(SdkError::InvalidChar {
+                        ch,
+                        line: line_nr,
+                        col: idx + 1,
+                    });
+                }
+            }
+        }
+
+        Ok(row)
+    }
+}
+
+/// Writes [sudokus](Sudoku) as `*.sdk` or single-line data
+/// ```
+/// use puzzled::sudoku::{SdkWriter, sudoku};
+///
+/// let puzzle = sudoku!(
+///     [1 - - 4]
+///     [- - 1 -]
+///     [- 1 - -]
+///     [4 - - 1]
+///
+///     title: "Mini"
+/// );
+///
+/// let writer = SdkWriter;
+/// assert_eq!(writer.write(&puzzle), "#DMini\n1..4\n..1.\n.1..\n4..1\n");
+/// assert_eq!(writer.write_line(&puzzle), "1004001001004001");
+/// ```
+#[derive(Debug, Default)]
+pub struct SdkWriter;
+
+impl SdkWriter {
+    /// Write the metadata as directives, followed by a row of digits per line
+    pub fn write(&self, sudoku: &Sudoku) -> String {
+        let mut text = String::new();
+
+        let meta = sudoku.meta();
+        if let Some(author) = meta.author() {
+            text += &format!("#A{author}\n");
+        }
+        if let Some(title) = meta.title() {
+            text += &format!("#D{title}\n");
+        }
+        for note in meta.notes().into_iter().flat_map(str::lines) {
+            text += &format!("#C{note}\n");
+        }
+
+        for row in sudoku.givens().iter_rows() {
+            text.extend(row.map(|given| given_char(*given, '.')));
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Write all rows on a single line, where missing digits are written as `0`
+    pub fn write_line(&self, sudoku: &Sudoku) -> String {
+        sudoku
+            .givens()
+            .iter()
+            .map(|given| given_char(*given, '0'))
+            .collect()
+    }
+
+    pub fn write_to_path<P>(&self, sudoku: &Sudoku, path: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        fs::write(path, self.write(sudoku))
+    }
+}
+
+fn given_char(given: Option<Digit>, missing: char) -> char {
+    match given {
+        Some(digit) => char::from(b'0' + digit.get()),
+        None => missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::sudoku;
+
+    #[rstest]
+    fn read(#[files("puzzles/ok/*.sdk")] path: PathBuf) {
+        let reader = SdkReader::new(true);
+        let puzzle = reader.read_from_path(path).expect("Puzzle is valid");
+
+        assert!(puzzle.conflicts().is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let puzzle = sudoku!(
+            [1 - - 4]
+            [- - 1 -]
+            [- 1 - -]
+            [4 - - 1]
+
+            title: "Mini"
+            author: "Puzzled"
+            notes: "First line"
+        );
+
+        let writer = SdkWriter;
+        let reader = SdkReader::new(true);
+
+        assert_eq!(reader.read(&writer.write(&puzzle)).unwrap(), puzzle);
+        assert_eq!(
+            reader.read(&writer.write_line(&puzzle)).unwrap().givens(),
+            puzzle.givens()
+        );
+    }
+
+    #[rstest]
+    #[case::boxes("1.|.4\n..|1.\n--+--\n.1|..\n4.|.1")]
+    #[case::unknown_directive("#Xsomething\n1..4\n..1.\n.1..\n4..1")]
+    fn lenient(#[case] input: &str) {
+        assert!(SdkReader::new(false).read(input).is_ok());
+        assert!(SdkReader::new(true).read(input).is_err());
+    }
+
+    #[rstest]
+    #[case::empty("#DNothing", "Expected at least one row")]
+    #[case::char("1..4\n..x.\n.1..\n4..1", "Invalid character 'x' at 2:3")]
+    #[case::row_length("1..4\n..1\n.1..\n4..1", "Row at line 2 has 3 cells")]
+    #[case::cell_count("1..4..1..1..4..", "Found 15 cells")]
+    #[case::not_square("1..4\n..1.", "Row at line 1 has 4 cells")]
+    fn invalid(#[case] input: &str, #[case] message: &str) {
+        let err = SdkReader::new(false).read(input).unwrap_err();
+        assert!(err.to_string().starts_with(message), "{err}");
+    }
+}
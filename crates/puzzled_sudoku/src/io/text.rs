@@ -0,0 +1,125 @@
+use chumsky::{
+    Parser,
+    extra::Err,
+    prelude::{any, group},
+};
+use puzzled_io::{
+    format,
+    text::{
+        FormatConfig, TxtPuzzle,
+        read::{self, ParseError, Span, cell_with, grid, metadata_with_timer},
+        write::{cell_text, metadata_text},
+    },
+};
+
+use crate::{Digit, Sudoku};
+
+pub fn digit<'a>() -> impl Parser<'a, &'a str, Digit, Err<ParseError<'a>>> + Clone {
+    any().try_map(|ch: char, span| {
+        Digit::try_from(ch).map_err(|err| ParseError::custom(span, err.to_string()))
+    })
+}
+
+impl TxtPuzzle for Sudoku {
+    fn read_text_with(input: &str, config: &FormatConfig) -> read::Result<Sudoku> {
+        let cells = grid(cell_with(digit(), *config));
+        let (cells, (meta, _timer)) = group((cells, metadata_with_timer()))
+            .parse(input)
+            .into_result()
+            .map_err(|errs| read::Error::parse(input, errs))?;
+
+        let givens = cells.map(|cell| cell.solution);
+
+        Sudoku::new(givens, meta).map_err(|err| {
+            let span = Span::from(0..input.len());
+            read::Error::format(format::Error::PuzzleSpecific(Box::new(err)), span)
+        })
+    }
+
+    fn write_text_with(&self, config: &FormatConfig) -> String {
+        let givens = self
+            .givens()
+            .map_ref(|given| cell_text(&puzzled_core::Cell::new(*given), config));
+        let mut text = format!("{givens}\n");
+
+        text += &metadata_text(self.meta());
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use puzzled_io::TxtReader;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::sudoku;
+
+    #[rstest]
+    #[case("1", 1)]
+    #[case("9", 9)]
+    fn parse_digit(#[case] input: &str, #[case] expected: u8) {
+        let digit = digit().parse(input).unwrap();
+        assert_eq!(digit.get(), expected);
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("a")]
+    fn parse_invalid_digit(#[case] input: &str) {
+        assert!(digit().parse(input).has_errors());
+    }
+
+    #[rstest]
+    fn read(#[files("puzzles/ok/*.txt")] path: PathBuf) {
+        let reader = TxtReader::new(false);
+        let _puzzle: Sudoku = reader.read_from_path(path).expect("Puzzle is valid");
+    }
+
+    #[test]
+    fn round_trip() {
+        let puzzle = sudoku!(
+            [1 - - 4]
+            [- - 1 -]
+            [- 1 - -]
+            [4 - - 1]
+
+            title: "Mini"
+            author: "Puzzled"
+        );
+
+        let text = puzzle.write_text();
+        let read = Sudoku::read_text(&text).expect("Written text is valid");
+
+        assert_eq!(read, puzzle);
+    }
+
+    #[test]
+    fn config() {
+        let puzzle = sudoku!(
+            [1 - - 4]
+            [- - 1 -]
+            [- 1 - -]
+            [4 - - 1]
+        );
+        let config = FormatConfig::default().with_missing('.');
+
+        let text = puzzle.write_text_with(&config);
+        assert!(text.starts_with("[ 1 . . 4 ]"));
+
+        let read = Sudoku::read_text_with(&text, &config).expect("Written text is valid");
+        assert_eq!(read, puzzle);
+    }
+
+    #[test]
+    fn not_square() {
+        let result = Sudoku::read_text("[1 - -]\n[- - 1]");
+        assert!(matches!(
+            result,
+            Err(puzzled_io::text::read::Error::Format { .. })
+        ));
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+//! Read, write and solve [sudokus](https://en.wikipedia.org/wiki/Sudoku)
+//!
+//! A [`Sudoku`] is either constructed directly from its [givens](Sudoku::givens) or read from `*.sdk` and single-line data with an [`SdkReader`].
+//!
+//! ```
+//! use puzzled::sudoku::{SudokuSolver, Uniqueness, sudoku};
+//!
+//! let puzzle = sudoku!(
+//!     [- - 3 -]
+//!     [3 - - 2]
+//!     [- - - 3]
+//!     [- 3 1 -]
+//! );
+//!
+//! let mut solver = SudokuSolver::default();
+//! assert!(solver.check_uniqueness(&puzzle).is_unique());
+//! ```
+//!
+//! # Features
+#![doc = document_features::document_features!()]
+
+mod io;
+mod puzzle;
+mod solve;
+
+#[doc(hidden)]
+pub use puzzled_core::*;
+
+#[doc(inline)]
+pub use {io::*, puzzle::*, solve::*};
+
+#[cfg(feature = "macros")]
+mod macros;
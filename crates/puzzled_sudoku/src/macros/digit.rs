@@ -0,0 +1,24 @@
+/// Macro for constructing an optional [`Digit`](crate::Digit) inline, where `-` means the digit is missing
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! digit {
+    () => {
+        None
+    };
+
+    (-) => {
+        None
+    };
+
+    ($digit:literal) => {
+        Some($crate::Digit::new($digit).expect("Digit should be between 1 and 9"))
+    };
+
+    ($($invalid:tt)*) => {
+        compile_error!(concat!(
+            "Invalid Digit representation '",
+            stringify!($($invalid)*),
+            "', use one of: -, 1, 2, ..., 9"
+        ));
+    };
+}
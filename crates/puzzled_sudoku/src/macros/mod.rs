@@ -0,0 +1,2 @@
+mod digit;
+mod sudoku;
@@ -0,0 +1,44 @@
+/// Macro for constructing a [`Sudoku`](crate::Sudoku) inline
+///
+/// Every row of the grid lists its givens, where `-` marks a cell that should be solved
+/// ```
+/// use puzzled::sudoku::sudoku;
+///
+/// let puzzle = sudoku!(
+///     [1 - - 4]
+///     [- - 1 -]
+///     [- 1 - -]
+///     [4 - - 1]
+///
+///     title: "Mini"
+/// );
+/// assert_eq!(puzzle.size(), 4);
+/// assert_eq!(puzzle.meta().title(), Some("Mini"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! sudoku {
+    (
+        // Grid definition
+        [$($x0:tt)+] $( [$($x:tt)+])*
+
+        // Metadata
+        $( $meta_key:ident : $meta_value:literal )*
+    ) => {{
+        // Add givens
+        let givens = $crate::grid![
+            [$( $crate::digit!($x0) ),+]
+            $(, [$( $crate::digit!($x) ),+] )*
+        ];
+
+        // Add metadata
+        let meta = $crate::metadata!($( $meta_key : $meta_value),*);
+
+        // Create puzzle
+        $crate::Sudoku::new(givens, meta).expect("Sudoku should be square with at most 9 rows")
+    }};
+
+    ($($invalid:tt)*) => {
+        compile_error!("Invalid sudoku, define it as rows of digits such as [1 - 3 -] followed by metadata such as title: \"...\"");
+    };
+}
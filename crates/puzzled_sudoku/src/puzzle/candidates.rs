@@ -0,0 +1,99 @@
+use std::fmt;
+
+use crate::{Digit, MAX_DIGIT};
+
+/// Set of [digits](Digit) that can still be placed in a cell
+/// ```
+/// use puzzled::sudoku::{Candidates, Digit};
+///
+/// let one = Digit::new(1).unwrap();
+/// let two = Digit::new(2).unwrap();
+///
+/// let mut candidates = Candidates::all(4);
+/// assert_eq!(candidates.len(), 4);
+///
+/// candidates.remove(one);
+/// assert!(!candidates.contains(one));
+/// assert_eq!(candidates.iter().next(), Some(two));
+///
+/// let single = Candidates::single(two);
+/// assert_eq!(single.get_single(), Some(two));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Candidates(u16);
+
+impl Candidates {
+    /// No candidates, which means the cell cannot be filled
+    pub const NONE: Self = Self(0);
+
+    /// All digits that can be placed in a sudoku of the given size
+    pub fn all(size: usize) -> Self {
+        let size = size.min(MAX_DIGIT as usize);
+        Self((1 << size) - 1)
+    }
+
+    pub fn single(digit: Digit) -> Self {
+        Self(1 << digit.index())
+    }
+
+    pub fn contains(&self, digit: Digit) -> bool {
+        self.0 & (1 << digit.index()) != 0
+    }
+
+    /// Add the digit, returning whether it was not a candidate yet
+    pub fn insert(&mut self, digit: Digit) -> bool {
+        let inserted = !self.contains(digit);
+        self.0 |= 1 << digit.index();
+
+        inserted
+    }
+
+    /// Remove the digit, returning whether it was a candidate
+    pub fn remove(&mut self, digit: Digit) -> bool {
+        let removed = self.contains(digit);
+        self.0 &= !(1 << digit.index());
+
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The only candidate, if exactly one remains
+    pub fn get_single(&self) -> Option<Digit> {
+        match self.len() {
+            1 => self.iter().next(),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Digit> + '_ {
+        Digit::all(MAX_DIGIT as usize).filter(|&digit| self.contains(digit))
+    }
+}
+
+impl FromIterator<Digit> for Candidates {
+    fn from_iter<I: IntoIterator<Item = Digit>>(iter: I) -> Self {
+        let mut candidates = Candidates::NONE;
+        for digit in iter {
+            candidates.insert(digit);
+        }
+
+        candidates
+    }
+}
+
+impl fmt::Display for Candidates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for digit in self.iter() {
+            write!(f, "{digit}")?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -0,0 +1,128 @@
+use std::{fmt, str::FromStr};
+
+use puzzled_core::Word;
+
+/// Largest digit that can be placed in a sudoku, which limits its size to 9x9
+pub const MAX_DIGIT: u8 = 9;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DigitError {
+    #[error("Tried to construct from {0}, only 1 through {MAX_DIGIT} are allowed")]
+    OutOfRange(u8),
+
+    #[error("Cannot construct digit from {0:?}, only 1 through {MAX_DIGIT} are allowed")]
+    InvalidText(String),
+}
+
+/// Digit that is placed in a cell of a [sudoku](crate::Sudoku)
+/// ```
+/// use puzzled::sudoku::Digit;
+///
+/// let digit = Digit::new(5).unwrap();
+/// assert_eq!(digit.get(), 5);
+/// assert_eq!(digit.to_string(), "5");
+///
+/// assert!(Digit::new(0).is_err());
+/// assert_eq!("7".parse::<Digit>(), Digit::new(7));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digit(u8);
+
+impl Digit {
+    pub fn new(digit: u8) -> Result<Self, DigitError> {
+        match digit {
+            1..=MAX_DIGIT => Ok(Self(digit)),
+            digit => Err(DigitError::OutOfRange(digit)),
+        }
+    }
+
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+
+    /// Zero-based index of the digit, e.g. to look it up in [candidates](crate::Candidates)
+    pub fn index(&self) -> usize {
+        self.0 as usize - 1
+    }
+
+    /// All digits that can be placed in a sudoku of the given size
+    pub fn all(size: usize) -> impl Iterator<Item = Digit> {
+        (1..=size.min(MAX_DIGIT as usize) as u8).map(Digit)
+    }
+}
+
+impl fmt::Display for Digit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Digit> for u8 {
+    fn from(digit: Digit) -> Self {
+        digit.0
+    }
+}
+
+impl TryFrom<u8> for Digit {
+    type Error = DigitError;
+
+    fn try_from(digit: u8) -> Result<Self, Self::Error> {
+        Digit::new(digit)
+    }
+}
+
+impl TryFrom<char> for Digit {
+    type Error = DigitError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch.to_digit(10) {
+            Some(digit) => Digit::new(digit as u8),
+            None => Err(DigitError::InvalidText(ch.to_string())),
+        }
+    }
+}
+
+impl FromStr for Digit {
+    type Err = DigitError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut chars = value.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Digit::try_from(ch),
+            _ => Err(DigitError::InvalidText(value.to_string())),
+        }
+    }
+}
+
+impl Word for Digit {
+    fn is_word(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use crate::Digit;
+
+    impl Serialize for Digit {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Digit {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let digit = u8::deserialize(deserializer)?;
+            Digit::new(digit).map_err(D::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,322 @@
+mod candidates;
+mod digit;
+
+use std::fmt;
+
+pub use candidates::*;
+pub use digit::*;
+
+use derive_more::{Index, IndexMut};
+use puzzled_core::{Cell, Grid, Metadata, Position, Puzzle};
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum SudokuError {
+    #[error("Sudoku should be square, found {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+
+    #[error("Sudoku of size {size} is not supported, at most {MAX_DIGIT} is allowed")]
+    TooLarge { size: usize },
+
+    #[error("Given {digit} at {pos} does not fit in a sudoku of size {size}")]
+    InvalidGiven {
+        pos: Position,
+        digit: Digit,
+        size: usize,
+    },
+
+    #[error("Solution of {rows}x{cols} does not match the {size}x{size} sudoku")]
+    InvalidSolutionSize {
+        rows: usize,
+        cols: usize,
+        size: usize,
+    },
+
+    #[error("Solution contradicts the given {digit} at {pos}")]
+    SolutionMismatch { pos: Position, digit: Digit },
+}
+
+/// Dimensions of the boxes that a [sudoku](Sudoku) is divided into
+///
+/// Every box contains every digit exactly once, so a box has as many cells as the sudoku has rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoxSize {
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl BoxSize {
+    /// Box size of a sudoku with the given number of rows, which is as close to a square as possible
+    /// ```
+    /// use puzzled::sudoku::BoxSize;
+    ///
+    /// assert_eq!(BoxSize::for_size(9), BoxSize { rows: 3, cols: 3 });
+    /// assert_eq!(BoxSize::for_size(6), BoxSize { rows: 2, cols: 3 });
+    /// assert_eq!(BoxSize::for_size(4), BoxSize { rows: 2, cols: 2 });
+    /// ```
+    pub fn for_size(size: usize) -> Self {
+        let rows = (1..=size)
+            .take_while(|rows| rows * rows <= size)
+            .filter(|rows| size.is_multiple_of(*rows))
+            .last()
+            .unwrap_or(1);
+
+        Self {
+            rows,
+            cols: size / rows,
+        }
+    }
+}
+
+/// Sudoku puzzle, consisting of the digits that are given at the start and optionally its solution
+#[derive(Debug, Clone, PartialEq, Eq, Index, IndexMut)]
+pub struct Sudoku {
+    // State
+    #[index]
+    #[index_mut]
+    givens: Grid<Option<Digit>>,
+    solution: Option<Grid<Digit>>,
+    boxes: BoxSize,
+
+    // Metadata
+    meta: Metadata,
+}
+
+impl Sudoku {
+    pub fn new(givens: Grid<Option<Digit>>, meta: Metadata) -> Result<Self, SudokuError> {
+        let (rows, cols) = (givens.rows(), givens.cols());
+        if rows != cols {
+            return Err(SudokuError::NotSquare { rows, cols });
+        }
+        if rows > MAX_DIGIT as usize {
+            return Err(SudokuError::TooLarge { size: rows });
+        }
+
+        for (pos, given) in givens.iter_indexed() {
+            if let Some(digit) = *given
+                && digit.get() as usize > rows
+            {
+                return Err(SudokuError::InvalidGiven {
+                    pos,
+                    digit,
+                    size: rows,
+                });
+            }
+        }
+
+        Ok(Self {
+            boxes: BoxSize::for_size(rows),
+            givens,
+            solution: None,
+            meta,
+        })
+    }
+
+    /// Number of rows and columns, which is also the largest digit of the sudoku
+    pub fn size(&self) -> usize {
+        self.givens.rows()
+    }
+
+    pub fn box_size(&self) -> BoxSize {
+        self.boxes
+    }
+
+    pub fn givens(&self) -> &Grid<Option<Digit>> {
+        &self.givens
+    }
+
+    pub fn givens_mut(&mut self) -> &mut Grid<Option<Digit>> {
+        &mut self.givens
+    }
+
+    pub fn solution(&self) -> Option<&Grid<Digit>> {
+        self.solution.as_ref()
+    }
+
+    /// Set the solution of the sudoku, which should agree with its givens
+    pub fn set_solution(&mut self, solution: Grid<Digit>) -> Result<(), SudokuError> {
+        let size = self.size();
+        if (solution.rows(), solution.cols()) != (size, size) {
+            return Err(SudokuError::InvalidSolutionSize {
+                rows: solution.rows(),
+                cols: solution.cols(),
+                size,
+            });
+        }
+
+        for (pos, given) in self.givens.iter_indexed() {
+            if let Some(digit) = *given
+                && solution[pos] != digit
+            {
+                return Err(SudokuError::SolutionMismatch { pos, digit });
+            }
+        }
+
+        self.solution = Some(solution);
+        Ok(())
+    }
+
+    pub fn meta(&self) -> &Metadata {
+        &self.meta
+    }
+
+    pub fn meta_mut(&mut self) -> &mut Metadata {
+        &mut self.meta
+    }
+
+    /// Index of the box that contains the position, counting from the top left box in reading order
+    pub fn box_index(&self, pos: Position) -> usize {
+        let boxes_per_row = self.size() / self.boxes.cols;
+
+        (pos.row / self.boxes.rows) * boxes_per_row + pos.col / self.boxes.cols
+    }
+
+    /// Groups of positions that should each contain every digit exactly once, i.e. all rows, columns and boxes
+    pub fn units(&self) -> Vec<Vec<Position>> {
+        let size = self.size();
+        let mut units = Vec::with_capacity(3 * size);
+
+        units.extend((0..size).map(|row| (0..size).map(|col| Position::new(row, col)).collect()));
+        units.extend((0..size).map(|col| (0..size).map(|row| Position::new(row, col)).collect()));
+
+        let mut boxes = vec![Vec::with_capacity(size); size];
+        for pos in self.givens.positions() {
+            boxes[self.box_index(pos)].push(pos);
+        }
+        units.extend(boxes);
+
+        units
+    }
+
+    /// Positions that share a row, column or box with the given position, excluding the position itself
+    pub fn peers(&self, pos: Position) -> Vec<Position> {
+        let box_idx = self.box_index(pos);
+
+        self.givens
+            .positions()
+            .filter(|&other| {
+                other != pos
+                    && (other.row == pos.row
+                        || other.col == pos.col
+                        || self.box_index(other) == box_idx)
+            })
+            .collect()
+    }
+
+    /// Candidates of every cell that do not conflict with the givens of its peers
+    /// ```
+    /// use puzzled::sudoku::{Digit, sudoku};
+    ///
+    /// let puzzle = sudoku!(
+    ///     [1 - - -]
+    ///     [- - 2 -]
+    ///     [- 3 - -]
+    ///     [- - - -]
+    /// );
+    /// let candidates = puzzle.candidates();
+    ///
+    /// let digits = |row, col| -> Vec<u8> {
+    ///     candidates[(row, col)].iter().map(|digit| digit.get()).collect()
+    /// };
+    /// assert_eq!(digits(0, 0), vec![1]);
+    /// assert_eq!(digits(0, 1), vec![2, 4]);
+    /// assert_eq!(digits(3, 3), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn candidates(&self) -> Grid<Candidates> {
+        let size = self.size();
+
+        self.givens.map_ref_indexed(|pos, given| match given {
+            Some(digit) => Candidates::single(*digit),
+            None => {
+                let mut candidates = Candidates::all(size);
+                for peer in self.peers(pos) {
+                    if let Some(digit) = self.givens[peer] {
+                        candidates.remove(digit);
+                    }
+                }
+
+                candidates
+            }
+        })
+    }
+
+    /// Positions of the givens that share a row, column or box with an equal given
+    pub fn conflicts(&self) -> Vec<Position> {
+        self.givens
+            .iter_indexed()
+            .filter(|(pos, given)| {
+                given.is_some_and(|digit| {
+                    self.peers(*pos)
+                        .into_iter()
+                        .any(|peer| self.givens[peer] == Some(digit))
+                })
+            })
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+}
+
+impl fmt::Display for Sudoku {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.givens.map_ref(|given| Cell::new(*given)))?;
+        writeln!(f, "{}", self.meta)?;
+
+        Ok(())
+    }
+}
+
+impl Puzzle for Sudoku {
+    const NAME: &'static str = "Sudoku";
+
+    type Solution = Grid<Digit>;
+    type Position = Position;
+    type Value = Digit;
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use puzzled_core::{Grid, Metadata};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use crate::{Digit, Sudoku};
+
+    #[derive(Serialize, Deserialize)]
+    struct SerdeSudoku {
+        givens: Grid<Option<Digit>>,
+        solution: Option<Grid<Digit>>,
+        meta: Metadata,
+    }
+
+    impl Serialize for Sudoku {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            SerdeSudoku {
+                givens: self.givens.clone(),
+                solution: self.solution.clone(),
+                meta: self.meta.clone(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Sudoku {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let SerdeSudoku {
+                givens,
+                solution,
+                meta,
+            } = SerdeSudoku::deserialize(deserializer)?;
+
+            let mut sudoku = Sudoku::new(givens, meta).map_err(D::Error::custom)?;
+            if let Some(solution) = solution {
+                sudoku.set_solution(solution).map_err(D::Error::custom)?;
+            }
+
+            Ok(sudoku)
+        }
+    }
+}
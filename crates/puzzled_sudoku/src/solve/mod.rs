@@ -0,0 +1,7 @@
+mod solver;
+mod state;
+mod uniqueness;
+
+pub use solver::*;
+pub use state::*;
+pub use uniqueness::*;
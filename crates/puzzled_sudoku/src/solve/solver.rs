@@ -0,0 +1,276 @@
+use puzzled_core::{Grid, Solve, Solver, SolverError, SolverStats};
+
+use crate::{Candidates, Digit, Sudoku, SudokuState};
+
+/// Solves sudokus by propagating constraints between cells, and guessing digits when propagation gets stuck
+///
+/// Propagation removes the digit of every solved cell from its peers and places a digit in a cell when no other cell of its row, column or box can hold it.
+/// Guesses are made for the cell with the fewest candidates and undone when they lead to a contradiction.
+/// ```
+/// use puzzled::sudoku::{Puzzle, SudokuSolver, SudokuState, sudoku};
+///
+/// let puzzle = sudoku!(
+///     [- - 3 -]
+///     [3 - - 2]
+///     [- - - 3]
+///     [- 3 1 -]
+/// );
+///
+/// let mut solver = SudokuSolver::default();
+/// let solution = puzzle.solve_with::<_, SudokuState>(&mut solver).unwrap();
+/// assert_eq!(solution[(0, 0)].get(), 4);
+/// ```
+#[derive(Debug, Default)]
+pub struct SudokuSolver {
+    nodes: usize,
+    propagations: usize,
+}
+
+impl Solver<Sudoku, SudokuState> for SudokuSolver {
+    type Error = SolverError<String>;
+
+    fn solve(
+        &mut self,
+        puzzle: &Sudoku,
+        state: &mut SudokuState,
+    ) -> Result<Grid<Digit>, Self::Error> {
+        let mut solutions = Vec::with_capacity(1);
+        self.collect_solutions(puzzle, &mut solutions, 1);
+
+        let Some(solution) = solutions.pop() else {
+            return Err(SolverError::Stuck);
+        };
+
+        for (pos, digit) in solution.iter_indexed() {
+            state.solve(&pos, *digit);
+        }
+
+        self.try_finalize(state)
+    }
+
+    fn try_finalize(&self, state: &SudokuState) -> Result<Grid<Digit>, Self::Error> {
+        let solutions = state.solutions();
+        let digits: Option<Vec<_>> = solutions.iter().copied().collect();
+
+        let digits = digits.ok_or_else(|| {
+            SolverError::CannotFinalize("Expected all digits to be set in the solution".to_string())
+        })?;
+
+        Grid::from_vec(digits, solutions.cols())
+            .map_err(|err| SolverError::CannotFinalize(err.to_string()))
+    }
+
+    fn stats(&self) -> SolverStats {
+        SolverStats {
+            nodes: self.nodes,
+            propagations: self.propagations,
+            ..Default::default()
+        }
+    }
+}
+
+impl SudokuSolver {
+    /// Number of search nodes visited since the solver was created
+    ///
+    /// Every (partial) grid the solver propagates counts as a node, so a sudoku that is solvable without guessing takes a single node
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Find up to `limit` solutions of the sudoku
+    pub(crate) fn collect_solutions(
+        &mut self,
+        puzzle: &Sudoku,
+        solutions: &mut Vec<Grid<Digit>>,
+        limit: usize,
+    ) {
+        let layout = Layout::new(puzzle);
+        let cells = puzzle.candidates().iter().copied().collect();
+
+        self.search(&layout, cells, solutions, limit);
+    }
+
+    fn search(
+        &mut self,
+        layout: &Layout,
+        mut cells: Vec<Candidates>,
+        solutions: &mut Vec<Grid<Digit>>,
+        limit: usize,
+    ) {
+        self.nodes += 1;
+
+        if !self.propagate(layout, &mut cells) {
+            return;
+        }
+
+        // Guess the digit of the cell with the fewest candidates, or finish when all cells are solved
+        let guess = cells
+            .iter()
+            .enumerate()
+            .filter(|(_, candidates)| candidates.len() > 1)
+            .min_by_key(|(_, candidates)| candidates.len());
+
+        let Some((idx, candidates)) = guess else {
+            let digits = cells.iter().filter_map(Candidates::get_single).collect();
+            let solution = Grid::from_vec(digits, layout.size).expect("Sudoku is square");
+
+            solutions.push(solution);
+            return;
+        };
+
+        for digit in candidates.iter() {
+            let mut next = cells.clone();
+            next[idx] = Candidates::single(digit);
+
+            self.search(layout, next, solutions, limit);
+            if solutions.len() >= limit {
+                return;
+            }
+        }
+    }
+
+    /// Remove candidates until no more progress can be made, returning whether the cells are still consistent
+    fn propagate(&mut self, layout: &Layout, cells: &mut [Candidates]) -> bool {
+        loop {
+            self.propagations += 1;
+            let mut changed = false;
+
+            // Remove the digits of solved cells from their peers
+            for (idx, peers) in layout.peers.iter().enumerate() {
+                let Some(digit) = cells[idx].get_single() else {
+                    continue;
+                };
+
+                for &peer in peers {
+                    if cells[peer].remove(digit) {
+                        if cells[peer].is_empty() {
+                            return false;
+                        }
+                        changed = true;
+                    }
+                }
+            }
+
+            // Place digits that fit in a single cell of a unit
+            for unit in &layout.units {
+                for digit in Digit::all(layout.size) {
+                    let mut places = unit.iter().filter(|&&idx| cells[idx].contains(digit));
+
+                    match (places.next(), places.next()) {
+                        (None, _) => return false,
+                        (Some(&idx), None) if cells[idx].len() > 1 => {
+                            cells[idx] = Candidates::single(digit);
+                            changed = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if !changed {
+                return true;
+            }
+        }
+    }
+}
+
+/// Units and peers of a sudoku, referring to cells by their index in row order
+struct Layout {
+    size: usize,
+    units: Vec<Vec<usize>>,
+    peers: Vec<Vec<usize>>,
+}
+
+impl Layout {
+    fn new(puzzle: &Sudoku) -> Self {
+        let size = puzzle.size();
+        let index = |pos: puzzled_core::Position| pos.row * size + pos.col;
+
+        let units = puzzle
+            .units()
+            .into_iter()
+            .map(|unit| unit.into_iter().map(index).collect())
+            .collect();
+        let peers = puzzle
+            .givens()
+            .positions()
+            .map(|pos| puzzle.peers(pos).into_iter().map(index).collect())
+            .collect();
+
+        Self { size, units, peers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzled_core::Puzzle;
+
+    use super::*;
+    use crate::sudoku;
+
+    #[test]
+    fn solve() {
+        let puzzle = sudoku!(
+            [5 3 - - 7 - - - -]
+            [6 - - 1 9 5 - - -]
+            [- 9 8 - - - - 6 -]
+            [8 - - - 6 - - - 3]
+            [4 - - 8 - 3 - - 1]
+            [7 - - - 2 - - - 6]
+            [- 6 - - - - 2 8 -]
+            [- - - 4 1 9 - - 5]
+            [- - - - 8 - - 7 9]
+        );
+
+        let mut solver = SudokuSolver::default();
+        let solution = puzzle
+            .solve_with::<_, SudokuState>(&mut solver)
+            .expect("Sudoku is solvable");
+
+        let first_row: Vec<_> = (0..9).map(|col| solution[(0, col)].get()).collect();
+        assert_eq!(first_row, vec![5, 3, 4, 6, 7, 8, 9, 1, 2]);
+        assert_eq!(solver.nodes(), 1);
+    }
+
+    #[test]
+    fn solve_with_search() {
+        // Requires guessing, as propagation alone gets stuck
+        let puzzle = sudoku!(
+            [8 - - - - - - - -]
+            [- - 3 6 - - - - -]
+            [- 7 - - 9 - 2 - -]
+            [- 5 - - - 7 - - -]
+            [- - - - 4 5 7 - -]
+            [- - - 1 - - - 3 -]
+            [- - 1 - - - - 6 8]
+            [- - 8 5 - - - 1 -]
+            [- 9 - - - - 4 - -]
+        );
+
+        let mut solver = SudokuSolver::default();
+        let solution = puzzle
+            .solve_with::<_, SudokuState>(&mut solver)
+            .expect("Sudoku is solvable");
+
+        let mut solved = puzzle.clone();
+        solved
+            .set_solution(solution)
+            .expect("Solution agrees with the givens");
+        assert!(solver.nodes() > 1);
+    }
+
+    #[test]
+    fn conflicting_givens() {
+        let puzzle = sudoku!(
+            [1 1 - -]
+            [- - - -]
+            [- - - -]
+            [- - - -]
+        );
+
+        let mut solver = SudokuSolver::default();
+        let result = puzzle.solve_with::<_, SudokuState>(&mut solver);
+
+        assert!(matches!(result, Err(SolverError::Stuck)));
+    }
+}
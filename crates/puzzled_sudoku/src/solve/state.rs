@@ -0,0 +1,62 @@
+use delegate::delegate;
+use puzzled_core::{CellStyle, Entry, Grid, GridState, Position, Solve, Timer};
+
+use crate::{Digit, Sudoku};
+
+#[derive(Debug)]
+pub struct SudokuState {
+    pub state: GridState<Sudoku>,
+}
+
+impl SudokuState {
+    pub fn new(solutions: Grid<Option<Digit>>, entries: Grid<Entry<Digit>>, timer: Timer) -> Self {
+        Self {
+            state: GridState::new(solutions, entries, timer),
+        }
+    }
+
+    pub fn solutions(&self) -> &Grid<Option<Digit>> {
+        &self.state.solutions
+    }
+
+    pub fn entries(&self) -> &Grid<Entry<Digit>> {
+        &self.state.entries
+    }
+}
+
+impl From<&Sudoku> for SudokuState {
+    /// Start solving the sudoku, where the givens are revealed and [locked](Entry::lock) from the start
+    fn from(sudoku: &Sudoku) -> Self {
+        let givens = sudoku.givens();
+
+        let solutions = match sudoku.solution() {
+            Some(solution) => solution.map_ref(|digit| Some(*digit)),
+            None => givens.clone(),
+        };
+        let entries = givens.map_ref(|given| match given {
+            Some(digit) => {
+                let mut entry = Entry::new_with_style(Some(*digit), CellStyle::INITIALLY_REVEALED);
+                entry.lock();
+                entry
+            }
+            None => Entry::new(None),
+        });
+
+        SudokuState::new(solutions, entries, Timer::default())
+    }
+}
+
+impl Solve<Sudoku> for SudokuState {
+    delegate! {
+        to self.state {
+            fn solution(&self, pos: &Position) -> Option<&Digit>;
+            fn entry(&self, pos: &Position) -> Option<&Digit>;
+
+            fn solve(&mut self, pos: &Position, solution: Digit) -> bool;
+            fn enter(&mut self, pos: &Position, entry: Digit) -> bool;
+            fn clear(&mut self, pos: &Position) -> bool;
+            fn reveal(&mut self, pos: &Position) -> bool;
+            fn check(&mut self, pos: &Position) -> Option<bool>;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use puzzled_core::Grid;
+
+use crate::{Digit, Sudoku, SudokuSolver};
+
+/// Whether the givens of a [sudoku](Sudoku) lead to a single solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Uniqueness {
+    /// The givens contradict each other, so no solution exists
+    Unsolvable,
+
+    /// The givens have exactly one solution
+    Unique(Grid<Digit>),
+
+    /// The givens have at least two different solutions, of which two are given
+    Ambiguous(Grid<Digit>, Grid<Digit>),
+}
+
+impl Uniqueness {
+    pub fn is_unique(&self) -> bool {
+        matches!(self, Uniqueness::Unique(_))
+    }
+
+    /// Solution of the sudoku, which is only defined when it is [unique](Uniqueness::Unique)
+    pub fn solution(&self) -> Option<&Grid<Digit>> {
+        match self {
+            Uniqueness::Unique(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+impl SudokuSolver {
+    /// Check whether the givens of a sudoku have exactly one solution
+    ///
+    /// The search stops as soon as a second solution is found, so ambiguous sudokus are not fully explored
+    /// ```
+    /// use puzzled::sudoku::{SudokuSolver, Uniqueness, sudoku};
+    ///
+    /// let unique = sudoku!(
+    ///     [- - 3 -]
+    ///     [3 - - 2]
+    ///     [- - - 3]
+    ///     [- 3 1 -]
+    /// );
+    /// let ambiguous = sudoku!(
+    ///     [1 - - -]
+    ///     [- - - -]
+    ///     [- - - -]
+    ///     [- - - -]
+    /// );
+    ///
+    /// let mut solver = SudokuSolver::default();
+    /// assert!(solver.check_uniqueness(&unique).is_unique());
+    /// assert!(matches!(solver.check_uniqueness(&ambiguous), Uniqueness::Ambiguous(..)));
+    /// ```
+    pub fn check_uniqueness(&mut self, puzzle: &Sudoku) -> Uniqueness {
+        let mut solutions = Vec::with_capacity(2);
+        self.collect_solutions(puzzle, &mut solutions, 2);
+
+        let mut solutions = solutions.into_iter();
+        match (solutions.next(), solutions.next()) {
+            (None, _) => Uniqueness::Unsolvable,
+            (Some(solution), None) => Uniqueness::Unique(solution),
+            (Some(first), Some(second)) => Uniqueness::Ambiguous(first, second),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::sudoku;
+
+    #[test]
+    fn unique() {
+        let puzzle = sudoku!(
+            [- - 3 -]
+            [3 - - 2]
+            [- - - 3]
+            [- 3 1 -]
+        );
+
+        let uniqueness = SudokuSolver::default().check_uniqueness(&puzzle);
+        let solution = uniqueness.solution().expect("Sudoku is unique");
+
+        let first_row: Vec<_> = (0..4).map(|col| solution[(0, col)].get()).collect();
+        assert_eq!(first_row, vec![4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn ambiguous() {
+        let puzzle = sudoku!(
+            [1 2 - -]
+            [3 4 - -]
+            [- - - -]
+            [- - - -]
+        );
+
+        let Uniqueness::Ambiguous(first, second) =
+            SudokuSolver::default().check_uniqueness(&puzzle)
+        else {
+            panic!("Sudoku should be ambiguous");
+        };
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn unsolvable() {
+        let puzzle = sudoku!(
+            [1 2 - -]
+            [- - 3 -]
+            [- - - -]
+            [- - - 4]
+        );
+
+        // The top right cell cannot hold 1, 2 (row), 3 (box) or 4 (column)
+        let uniqueness = SudokuSolver::default().check_uniqueness(&puzzle);
+        assert_eq!(uniqueness, Uniqueness::Unsolvable);
+    }
+}
@@ -0,0 +1,18 @@
+//! WebAssembly bindings for reading, playing and writing [crosswords](puzzled_crossword::Crossword) from JavaScript
+//!
+//! The bindings are built with [`wasm-bindgen`](https://rustwasm.github.io/docs/wasm-bindgen/), e.g. through `wasm-pack build crates/puzzled_wasm`.
+//! Web solvers parse the bytes of an [Across Lite `*.puz` file](https://code.google.com/archive/p/puz/wikis/FileFormat.wiki) into a [`JsPuzzle`],
+//! which keeps track of the entries of the player and is exported again as `*.puz` or [ipuz](http://ipuz.org/):
+//! ```js
+//! import { parsePuz } from "puzzled_wasm";
+//!
+//! const puzzle = parsePuz(new Uint8Array(await file.arrayBuffer()));
+//! puzzle.enter(0, 0, "c");
+//!
+//! console.log(puzzle.title, puzzle.check(0, 0));
+//! const bytes = puzzle.toPuz();
+//! ```
+
+mod puzzle;
+
+pub use puzzle::*;
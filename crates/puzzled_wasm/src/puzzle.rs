@@ -0,0 +1,249 @@
+use puzzled_core::{Position, Solve};
+use puzzled_crossword::{Clue, Crossword, CrosswordState, EntryNormalizer};
+use puzzled_io::{PuzReader, PuzWriter, ReadError, WriteError};
+use wasm_bindgen::prelude::*;
+
+/// Parse the bytes of a `*.puz` file into a puzzle
+///
+/// Issues that can be recovered from, such as invalid checksums, do not fail parsing
+#[wasm_bindgen(js_name = parsePuz)]
+pub fn parse_puz(bytes: &[u8]) -> Result<JsPuzzle, JsError> {
+    Ok(JsPuzzle::from_puz(bytes)?)
+}
+
+/// Crossword together with the entries of its player
+#[wasm_bindgen]
+pub struct JsPuzzle {
+    puzzle: Crossword,
+    state: CrosswordState,
+    normalizer: EntryNormalizer,
+}
+
+/// Clue of a [puzzle](JsPuzzle) with its placement in the grid
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsClue {
+    clue: Clue,
+}
+
+impl JsPuzzle {
+    pub(crate) fn from_puz(bytes: &[u8]) -> Result<Self, ReadError> {
+        let (puzzle, state) = PuzReader::default().read(&mut &bytes[..])?;
+
+        Ok(Self {
+            puzzle,
+            state,
+            normalizer: EntryNormalizer::nyt(),
+        })
+    }
+
+    pub(crate) fn write_puz(&self) -> Result<Vec<u8>, WriteError> {
+        let mut bytes = Vec::new();
+        PuzWriter::new().write(&mut bytes, &self.puzzle, &self.state)?;
+
+        Ok(bytes)
+    }
+}
+
+#[wasm_bindgen]
+impl JsPuzzle {
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.puzzle.rows()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        self.puzzle.cols()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn title(&self) -> Option<String> {
+        self.puzzle.meta().title().map(str::to_string)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn author(&self) -> Option<String> {
+        self.puzzle.meta().author().map(str::to_string)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn copyright(&self) -> Option<String> {
+        self.puzzle.meta().copyright().map(str::to_string)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn notes(&self) -> Option<String> {
+        self.puzzle.meta().notes().map(str::to_string)
+    }
+
+    /// Clues ordered by number, with across clues before down clues of the same number
+    #[wasm_bindgen(getter)]
+    pub fn clues(&self) -> Vec<JsClue> {
+        self.puzzle
+            .clues()
+            .values()
+            .map(|clue| JsClue { clue: clue.clone() })
+            .collect()
+    }
+
+    /// Whether the square is a block, which includes positions outside of the grid
+    #[wasm_bindgen(js_name = isBlock)]
+    pub fn is_block(&self, row: usize, col: usize) -> bool {
+        self.puzzle
+            .squares()
+            .get(Position { row, col })
+            .is_none_or(|square| square.is_none())
+    }
+
+    /// Number of the clues that start in the square, if any
+    pub fn number(&self, row: usize, col: usize) -> Option<u8> {
+        self.puzzle.clues().get_num(Position { row, col })
+    }
+
+    /// Solution of the square, where alternative solutions are separated by `/`
+    pub fn solution(&self, row: usize, col: usize) -> Option<String> {
+        let solution = self.state.solution(&Position { row, col })?;
+        Some(solution.to_string())
+    }
+
+    /// Entry of the player in the square, if any
+    pub fn entry(&self, row: usize, col: usize) -> Option<String> {
+        let entry = self.state.entry(&Position { row, col })?;
+        Some(entry.to_string())
+    }
+
+    /// Enter input of the player in the square, which is uppercased and stripped of diacritics.
+    /// Returns whether the entry was changed
+    pub fn enter(&mut self, row: usize, col: usize, input: &str) -> bool {
+        let pos = Position { row, col };
+        self.state.enter_normalized(&pos, input, &self.normalizer)
+    }
+
+    /// Clear the entry of the square, returning whether it was changed
+    pub fn clear(&mut self, row: usize, col: usize) -> bool {
+        self.state.clear(&Position { row, col })
+    }
+
+    /// Reveal the solution of the square, returning whether the square is playable
+    pub fn reveal(&mut self, row: usize, col: usize) -> bool {
+        self.state.reveal(&Position { row, col })
+    }
+
+    /// Check whether the entry in the square is correct, which is `undefined` for empty squares
+    pub fn check(&mut self, row: usize, col: usize) -> Option<bool> {
+        self.state.check(&Position { row, col })
+    }
+
+    /// Whether every playable square is entered or revealed correctly
+    #[wasm_bindgen(js_name = isSolved)]
+    pub fn is_solved(&self) -> bool {
+        self.state.is_solved(&self.puzzle)
+    }
+
+    /// Write the puzzle and the entries of the player as the bytes of a `*.puz` file
+    #[wasm_bindgen(js_name = toPuz)]
+    pub fn to_puz(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.write_puz()?)
+    }
+
+    /// Write the puzzle as an [ipuz](http://ipuz.org/) document
+    #[wasm_bindgen(js_name = toIpuz)]
+    pub fn to_ipuz(&self) -> String {
+        self.puzzle.to_ipuz().to_string()
+    }
+}
+
+#[wasm_bindgen]
+impl JsClue {
+    #[wasm_bindgen(getter)]
+    pub fn num(&self) -> u8 {
+        self.clue.num()
+    }
+
+    /// Direction of the clue, which is either `"A"` or `"D"`
+    #[wasm_bindgen(getter)]
+    pub fn direction(&self) -> String {
+        self.clue.direction().to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn text(&self) -> String {
+        self.clue.text().clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn row(&self) -> usize {
+        self.clue.start().row
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn col(&self) -> usize {
+        self.clue.start().col
+    }
+
+    /// Number of squares the clue occupies
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> u8 {
+        self.clue.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::*;
+
+    fn mini() -> JsPuzzle {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../puzzled_crossword/puzzles/ok/mini.puz");
+        let bytes = fs::read(path).expect("puzzle exists");
+
+        JsPuzzle::from_puz(&bytes).expect("puzzle is valid")
+    }
+
+    #[test]
+    fn entries() {
+        let mut puzzle = mini();
+        let clue = puzzle.clues().remove(0);
+        let (row, col) = (clue.row(), clue.col());
+
+        let solution = puzzle.solution(row, col).unwrap();
+        assert!(!puzzle.is_solved());
+
+        assert!(puzzle.enter(row, col, &solution.to_lowercase()));
+        assert_eq!(puzzle.entry(row, col), Some(solution));
+        assert_eq!(puzzle.check(row, col), Some(true));
+
+        assert!(puzzle.clear(row, col));
+        assert_eq!(puzzle.entry(row, col), None);
+    }
+
+    #[test]
+    fn solved() {
+        let mut puzzle = mini();
+
+        for row in 0..puzzle.rows() {
+            for col in 0..puzzle.cols() {
+                if let Some(solution) = puzzle.solution(row, col) {
+                    puzzle.enter(row, col, &solution);
+                }
+            }
+        }
+
+        assert!(puzzle.is_solved());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut puzzle = mini();
+        puzzle.enter(0, 0, "x");
+
+        let bytes = puzzle.write_puz().unwrap();
+        let reread = JsPuzzle::from_puz(&bytes).unwrap();
+
+        assert_eq!(reread.puzzle, puzzle.puzzle);
+        assert_eq!(reread.entry(0, 0), Some("X".to_string()));
+    }
+}